@@ -0,0 +1,38 @@
+//! Build script embedding build information for proof metadata
+//!
+//! Exposes the target triple, enabled features and a digest of `Cargo.lock` to the
+//! crate as compile-time environment variables. Nothing time-dependent is recorded,
+//! so identical inputs produce identical build info.
+
+use sha2::{Digest, Sha256};
+use std::env;
+use std::path::Path;
+
+fn main() {
+    let target = env::var("TARGET").unwrap_or_default();
+
+    let mut features: Vec<String> = env::vars()
+        .filter_map(|(key, _)| {
+            key.strip_prefix("CARGO_FEATURE_")
+                .map(|feature| feature.to_lowercase().replace('_', "-"))
+        })
+        .collect();
+    features.sort();
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let lockfile = Path::new(&manifest_dir).join("Cargo.lock");
+    let lockfile_digest = std::fs::read(&lockfile)
+        .map(|bytes| {
+            Sha256::digest(bytes)
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>()
+        })
+        .unwrap_or_default();
+
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", lockfile.display());
+    println!("cargo:rustc-env=XFG_BUILD_TARGET={}", target);
+    println!("cargo:rustc-env=XFG_BUILD_FEATURES={}", features.join(","));
+    println!("cargo:rustc-env=XFG_BUILD_LOCKFILE_DIGEST={}", lockfile_digest);
+}
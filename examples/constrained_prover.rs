@@ -0,0 +1,82 @@
+//! Constrained Prover Example: Burn & Mint on a Raspberry Pi
+//!
+//! The golden path for low-memory ARM64 provers. `SecurityPreset::Constrained` picks
+//! proof options with half the standard LDE, the proof is generated and verified as a
+//! gateway would verify it, and options trimmed below the verifier's minimum are
+//! flagged as testnet-only before any proving time is spent on them.
+//!
+//! Run with `cargo run --release --example constrained_prover`.
+
+use xfg_stark::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+use xfg_stark::burn_mint_verifier::XfgBurnMintVerifier;
+use xfg_stark::constants::TRACE_LENGTH;
+use xfg_stark::soundness::{conjectured_security_bits, is_testnet_only, SecurityPreset};
+use xfg_stark::winterfell::ProofOptions;
+use xfg_stark::Result;
+
+fn main() -> Result<()> {
+    println!("🍓 XFG Constrained Prover Example");
+    println!("=================================");
+    println!("🖥️  Target: {} ({})", std::env::consts::ARCH, std::env::consts::OS);
+
+    let preset = SecurityPreset::Constrained;
+    let options = preset
+        .default_proof_options(TRACE_LENGTH)
+        .map_err(|e| xfg_stark::XfgStarkError::CryptoError(e.to_string()))?;
+    let standard = SecurityPreset::Standard
+        .default_proof_options(TRACE_LENGTH)
+        .map_err(|e| xfg_stark::XfgStarkError::CryptoError(e.to_string()))?;
+    describe("Constrained", &options);
+    describe("Standard", &standard);
+
+    let (proof, public_inputs) = XfgBurnMintProver::with_options(128, options.clone())
+        .prove_burn_mint_with_public_inputs(
+            &BurnMintRequest {
+                burn_amount: 8_000_000,
+                mint_amount: 8_000_000,
+                tx_prefix_hash: [7u8; 32],
+                recipient_address: &[0x12u8; 20],
+                network_id: 1,
+                target_chain_id: 42161,
+                commitment_version: 1,
+            },
+            &[9u8; 32],
+        )?;
+    let proof_bytes = proof.to_bytes();
+    println!("✅ Proof generated ({} bytes)", proof_bytes.len());
+
+    let report = XfgBurnMintVerifier::default().verify_from_bytes(&proof_bytes, &public_inputs)?;
+    assert!(report.is_valid());
+    println!("✅ Proof verified at {} bits in {:?}", report.security_bits, report.verification_time);
+
+    // Trimming queries to save more time drops below the verifier's minimum
+    let trimmed = ProofOptions::new(
+        options.num_queries() / 2,
+        options.blowup_factor(),
+        options.grinding_factor(),
+        options.field_extension(),
+        options.to_fri_options().folding_factor(),
+        options.to_fri_options().remainder_max_degree(),
+    );
+    assert!(is_testnet_only(&trimmed, TRACE_LENGTH));
+    println!(
+        "⚠️  {} queries give {} bits: testnet-only, mainnet verifiers reject these proofs",
+        trimmed.num_queries(),
+        conjectured_security_bits(&trimmed, TRACE_LENGTH)
+    );
+
+    Ok(())
+}
+
+/// Print a preset's parameters and LDE size
+fn describe(name: &str, options: &ProofOptions) {
+    println!(
+        "📐 {}: blowup {}, {} queries, grinding {}, LDE domain {} rows, {} bits",
+        name,
+        options.blowup_factor(),
+        options.num_queries(),
+        options.grinding_factor(),
+        TRACE_LENGTH * options.blowup_factor(),
+        conjectured_security_bits(options, TRACE_LENGTH)
+    );
+}
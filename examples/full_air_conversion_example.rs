@@ -173,7 +173,7 @@ impl FullAirConversionExample {
         
         // Step 5: Demonstrate trace table conversion
         println!("\n📋 Step 5: Demonstrating trace table conversion...");
-        let winterfell_trace = WinterfellTraceTable::from_xfg_trace(&trace);
+        let winterfell_trace = WinterfellTraceTable::from_xfg_trace(&trace)?;
         println!("   Successfully converted XFG trace to Winterfell trace table");
         println!("   Winterfell trace dimensions: {}x{}", winterfell_trace.num_rows, winterfell_trace.num_cols);
         
@@ -142,8 +142,9 @@ impl NetworkIdExample {
         
         // Step 6: Demonstrate trace table conversion
         println!("\n📋 Step 6: Demonstrating trace table conversion...");
-        let winterfell_trace = WinterfellTraceTable::from_xfg_trace(&trace);
+        let winterfell_trace = WinterfellTraceTable::from_xfg_trace(&trace)?;
         println!("   Successfully converted XFG trace to Winterfell trace table");
+        println!("   First cell: {:?}", winterfell_trace.get(0, 0));
         
         // Step 7: Set up prover and verifier
         println!("\n🔐 Step 7: Setting up prover and verifier...");
@@ -4,13 +4,12 @@
 //! using Winterfell's battle-tested STARK proof system.
 
 use xfg_stark::{
-    burn_mint_air::{XfgBurnMintAir, BurnMintPublicInputs, generate_burn_mint_trace},
-    burn_mint_prover::XfgBurnMintProver,
-    burn_mint_verifier::{XfgBurnMintVerifier, BatchBurnMintVerifier},
-    Result, XfgStarkError,
-};
-use winterfell::{
-    math::fields::f64::BaseElement, ProofOptions, TraceInfo,
+    burn_mint_air::BurnMintPublicInputs,
+    burn_mint_prover::{BurnMintRequest, XfgBurnMintProver},
+    burn_mint_verifier::{BurnMintVerification, XfgBurnMintVerifier, BatchBurnMintVerifier},
+    commitment::commitment_to_bytes,
+    nullifier::Nullifier,
+    Result, XfgStarkError, COMMITMENT_VERSION, DEFAULT_TARGET_CHAIN_ID, FUEGO_MAINNET_NETWORK_ID,
 };
 use std::time::Instant;
 use sha3::{Keccak256, Digest};
@@ -23,7 +22,11 @@ struct ProductionConfig {
     /// Conversion rate from XFG to HEAT (2:1 ratio)
     conversion_rate: u64,
     /// Network ID for Fuego network
-    network_id: u64,
+    network_id: u32,
+    /// Chain ID HEAT is minted on
+    target_chain_id: u32,
+    /// HEAT recipient address
+    recipient: [u8; 20],
     /// Maximum burn amount in atomic units (XFG uses 7 decimal places)
     max_burn_amount: u64,
     /// Minimum burn amount in atomic units (XFG uses 7 decimal places)
@@ -35,8 +38,10 @@ impl Default for ProductionConfig {
         Self {
             security_parameter: 128,
             conversion_rate: 1, // 1:1 conversion in atomic units
-            network_id: 9338504644075575051, // Hashed Fuego network ID
-            max_burn_amount: XfgBurnMintProver::xfg_to_atomic_units(1_000_000_000.0), // 1 billion XFG in atomic units
+            network_id: FUEGO_MAINNET_NETWORK_ID,
+            target_chain_id: DEFAULT_TARGET_CHAIN_ID,
+            recipient: [0x12; 20],
+            max_burn_amount: XfgBurnMintProver::xfg_to_atomic_units(1_000_000_000.0).expect("1 billion XFG fits in u64"),
             min_burn_amount: 1, // 1 atomic unit minimum
         }
     }
@@ -111,14 +116,23 @@ impl ProductionBurnMintWorkflow {
         let secret = self.generate_secret();
         println!("Secret Generated: {} bytes", secret.len());
         
+        // Stand-in for the Fuego burn transaction prefix hash
+        let tx_prefix_hash: [u8; 32] = Keccak256::digest(&secret).into();
+        
         // Step 1: Generate proof
         println!("\n📊 Step 1: Generating STARK Proof...");
         let prove_start = Instant::now();
         
-        let proof = self.prover.prove_burn_mint(
-            burn_amount,
-            mint_amount,
-            self.config.network_id,
+        let (proof, public_inputs) = self.prover.prove_burn_mint_with_public_inputs(
+            &BurnMintRequest {
+                burn_amount,
+                mint_amount,
+                tx_prefix_hash,
+                recipient_address: &self.config.recipient,
+                network_id: self.config.network_id,
+                target_chain_id: self.config.target_chain_id,
+                commitment_version: COMMITMENT_VERSION,
+            },
             &secret,
         )?;
         
@@ -135,10 +149,17 @@ impl ProductionBurnMintWorkflow {
         
         let verification_result = self.verifier.verify_burn_mint(
             &proof,
-            burn_amount,
-            mint_amount,
-            self.config.network_id,
-            1, // Default conversion rate
+            &BurnMintVerification {
+                burn_amount,
+                tx_prefix_hash,
+                recipient_address: &self.config.recipient,
+                network_id: self.config.network_id,
+                target_chain_id: self.config.target_chain_id,
+                commitment_version: COMMITMENT_VERSION,
+                nullifier: &Nullifier::from_public_inputs(&public_inputs),
+                commitment: &commitment_to_bytes(&public_inputs.commitment),
+            },
+            None,
         )?;
         
         let verify_duration = verify_start.elapsed();
@@ -165,6 +186,7 @@ impl ProductionBurnMintWorkflow {
             burn_amount,
             mint_amount,
             proof,
+            public_inputs,
             proof_size,
             prove_duration,
             verify_duration,
@@ -198,17 +220,8 @@ impl ProductionBurnMintWorkflow {
         println!("\n🔄 Batch Verification...");
         let batch_start = Instant::now();
         
-        let mut public_inputs_vec: Vec<BurnMintPublicInputs> = results.iter().map(|result| {
-            BurnMintPublicInputs {
-                burn_amount: BaseElement::from(result.burn_amount as u32),
-                mint_amount: BaseElement::from(result.mint_amount as u32),
-                network_id: BaseElement::from(self.config.network_id as u32),
-                state: BaseElement::from(0u32),
-            }
-        }).collect();
-        
-        let proofs_and_inputs: Vec<_> = results.iter().zip(public_inputs_vec.iter()).map(|(result, inputs)| {
-            (&result.proof, inputs)
+        let proofs_and_inputs: Vec<_> = results.iter().map(|result| {
+            (&result.proof, &result.public_inputs)
         }).collect();
         
         let batch_results = self.batch_verifier.verify_batch(&proofs_and_inputs)?;
@@ -245,6 +258,7 @@ struct BurnMintResult {
     burn_amount: u64,
     mint_amount: u64,
     proof: winterfell::StarkProof,
+    public_inputs: BurnMintPublicInputs,
     proof_size: usize,
     prove_duration: std::time::Duration,
     verify_duration: std::time::Duration,
@@ -4,13 +4,11 @@
 //! using Winterfell's STARK proof system.
 
 use xfg_stark::{
-    burn_mint_air::{XfgBurnMintAir, BurnMintPublicInputs, generate_burn_mint_trace},
-    burn_mint_prover::XfgBurnMintProver,
-    burn_mint_verifier::XfgBurnMintVerifier,
-    Result,
-};
-use winterfell::{
-    math::fields::f64::BaseElement, ProofOptions, TraceInfo,
+    burn_mint_prover::{BurnMintRequest, XfgBurnMintProver},
+    burn_mint_verifier::{BurnMintVerification, XfgBurnMintVerifier},
+    commitment::commitment_to_bytes,
+    nullifier::Nullifier,
+    Result, COMMITMENT_VERSION, DEFAULT_TARGET_CHAIN_ID, FUEGO_MAINNET_NETWORK_ID,
 };
 use std::time::Instant;
 
@@ -22,9 +20,11 @@ fn main() -> Result<()> {
     let security_parameter = 128;
     // Use atomic units: 1 XFG = 10,000,000 atomic units (7 decimal places)
     let burn_amount_xfg = 1.0; // 1 XFG
-    let burn_amount = XfgBurnMintProver::xfg_to_atomic_units(burn_amount_xfg);
+    let burn_amount = XfgBurnMintProver::xfg_to_atomic_units(burn_amount_xfg)?;
     let mint_amount = burn_amount; // 1:1 conversion rate in atomic units
-    let network_id = 9338504644075575051; // Hashed Fuego network ID
+    let network_id = FUEGO_MAINNET_NETWORK_ID;
+    let tx_prefix_hash = [7u8; 32]; // Fuego burn transaction prefix hash
+    let recipient = [0x12u8; 20]; // HEAT recipient address
     
     println!("📊 Configuration:");
     println!("   Burn Amount: {} XFG ({} atomic units)", burn_amount_xfg, burn_amount);
@@ -42,7 +42,7 @@ fn main() -> Result<()> {
     println!();
     
     // Generate secret
-    let secret = [1, 2, 3, 4, 5, 6, 7, 8]; // Simple test secret
+    let secret = [9u8; 32]; // Simple test secret
     println!("🔐 Generated secret: {:?}", secret);
     println!();
     
@@ -50,15 +50,21 @@ fn main() -> Result<()> {
     println!("📊 Step 1: Generating STARK Proof...");
     let prove_start = Instant::now();
     
-    let proof_result = prover.prove_burn_mint(
-        burn_amount,
-        mint_amount,
-        network_id,
+    let proof_result = prover.prove_burn_mint_with_public_inputs(
+        &BurnMintRequest {
+            burn_amount,
+            mint_amount,
+            tx_prefix_hash,
+            recipient_address: &recipient,
+            network_id,
+            target_chain_id: DEFAULT_TARGET_CHAIN_ID,
+            commitment_version: COMMITMENT_VERSION,
+        },
         &secret,
     );
     
     match proof_result {
-        Ok(proof) => {
+        Ok((proof, public_inputs)) => {
             let prove_duration = prove_start.elapsed();
             let proof_size = prover.get_proof_size(&proof);
             
@@ -73,10 +79,17 @@ fn main() -> Result<()> {
             
             let verification_result = verifier.verify_burn_mint(
                 &proof,
-                burn_amount,
-                mint_amount,
-                network_id,
-                1, // Default conversion rate
+                &BurnMintVerification {
+                    burn_amount,
+                    tx_prefix_hash,
+                    recipient_address: &recipient,
+                    network_id,
+                    target_chain_id: DEFAULT_TARGET_CHAIN_ID,
+                    commitment_version: COMMITMENT_VERSION,
+                    nullifier: &Nullifier::from_public_inputs(&public_inputs),
+                    commitment: &commitment_to_bytes(&public_inputs.commitment),
+                },
+                None,
             );
             
             match verification_result {
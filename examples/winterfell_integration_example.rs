@@ -108,9 +108,10 @@ impl FibonacciExample {
         
         // Step 4: Demonstrate trace table conversion
         println!("\n📋 Step 4: Demonstrating trace table conversion...");
-        let winterfell_trace = WinterfellTraceTable::from_xfg_trace(&trace);
+        let winterfell_trace = WinterfellTraceTable::from_xfg_trace(&trace)?;
 
         println!("   Successfully converted XFG trace to Winterfell trace table");
+        println!("   First cell: {:?}", winterfell_trace.get(0, 0));
         
         // Step 5: Demonstrate arithmetic operations
         println!("\n🧮 Step 5: Demonstrating arithmetic operations...");
@@ -228,9 +229,10 @@ fn demonstrate_trace_validation() -> Result<()> {
     println!("   ✅ Trace validation successful");
     
     // Convert to Winterfell trace table
-    let winterfell_trace = WinterfellTraceTable::from_xfg_trace(&trace);
+    let winterfell_trace = WinterfellTraceTable::from_xfg_trace(&trace)?;
 
     println!("   ✅ Winterfell trace table conversion successful");
+    println!("   First cell: {:?}", winterfell_trace.get(0, 0));
     
     // Demonstrate trace properties
     println!("   Trace length: {}", trace.length);
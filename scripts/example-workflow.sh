@@ -35,7 +35,7 @@ echo "📦 Step 2: Creating data package..."
   --template standard_template.json \
   --burn-amount 0.8 \
   --txn-hash 0x7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D \
-  --recipient 0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6 \
+  --recipient 0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6 \
   --secret "my-super-secret-key-12345" \
   --network fuego-testnet \
   --output my_burn_package.json
@@ -62,7 +62,7 @@ cat > my_burn_package.json << 'EOF'
     "network_id": "fuego-testnet"
   },
   "recipient": {
-    "ethereum_address": "0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6",
+    "ethereum_address": "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6",
     "ens_name": "alice.eth",
     "label": "Alice's HEAT wallet"
   },
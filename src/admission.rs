@@ -0,0 +1,295 @@
+//! Verification Admission
+//!
+//! The [streaming server](crate::verification_server) and the [HTTP
+//! service](crate::service) both verify on a [`WorkerPool`]: a fixed number of workers
+//! with a bounded queue in front of them. A submission is admitted only within its
+//! tenant's limits (see [`crate::tenants`]) and only while a worker or queue slot is
+//! free; otherwise it is turned away at once with an [`AdmissionRejection`] instead of
+//! waiting, so a submission spike cannot grow memory without bound.
+//!
+//! The tenant is checked first and its permit is returned when the pool is full, so a
+//! submission the server never ran does not count against the tenant's quota.
+
+use crate::tenants::{Tenant, TenantMetrics, TenantPermit, TenantRegistry, TenantRejection};
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinError;
+
+/// Point-in-time view of a worker pool's queue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    /// Admitted proofs waiting for a worker
+    pub queue_depth: usize,
+    /// Proofs being verified
+    pub in_flight: usize,
+    /// Configured worker count
+    pub workers: usize,
+    /// Configured queue limit
+    pub max_pending: usize,
+    /// Proofs admitted since start
+    pub accepted: u64,
+    /// Proofs turned away because the workers and the queue were full
+    pub rejected: u64,
+    /// Proofs that verified since start
+    pub verified: u64,
+    /// Proofs that failed verification or were malformed since start
+    pub failed: u64,
+}
+
+#[derive(Debug, Default)]
+struct QueueMetrics {
+    queue_depth: AtomicUsize,
+    in_flight: AtomicUsize,
+    accepted: AtomicU64,
+    rejected: AtomicU64,
+    verified: AtomicU64,
+    failed: AtomicU64,
+}
+
+/// Reason a submission was not admitted
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AdmissionRejection {
+    /// The pool has tenants and the submission did not authenticate as one
+    #[error("authenticate with an API key before submitting proofs")]
+    Unauthenticated,
+    /// The tenant's pending, rate or quota limit turned the submission away
+    #[error(transparent)]
+    Tenant(#[from] TenantRejection),
+    /// Every worker and queue slot is taken
+    #[error("verification queue is full, retry later")]
+    Saturated,
+}
+
+impl AdmissionRejection {
+    /// HTTP status the submission is answered with
+    #[must_use]
+    pub fn status(&self) -> StatusCode {
+        match self {
+            AdmissionRejection::Unauthenticated => StatusCode::UNAUTHORIZED,
+            AdmissionRejection::Tenant(TenantRejection::QuotaExhausted { .. }) => StatusCode::FORBIDDEN,
+            AdmissionRejection::Tenant(_) | AdmissionRejection::Saturated => StatusCode::TOO_MANY_REQUESTS,
+        }
+    }
+}
+
+/// Workers, queue and tenants shared by every submission to a server
+///
+/// Cloning is cheap; clones share the slots, metrics and tenants.
+#[derive(Debug, Clone)]
+pub struct WorkerPool {
+    workers: usize,
+    max_pending: usize,
+    admission: Arc<Semaphore>,
+    worker_slots: Arc<Semaphore>,
+    metrics: Arc<QueueMetrics>,
+    tenants: Arc<TenantRegistry>,
+}
+
+impl WorkerPool {
+    /// Create a pool of `workers` (at least one) with `max_pending` queue slots
+    #[must_use]
+    pub fn new(workers: usize, max_pending: usize) -> Self {
+        let workers = workers.max(1);
+        Self {
+            workers,
+            max_pending,
+            admission: Arc::new(Semaphore::new(workers + max_pending)),
+            worker_slots: Arc::new(Semaphore::new(workers)),
+            metrics: Arc::new(QueueMetrics::default()),
+            tenants: Arc::new(TenantRegistry::default()),
+        }
+    }
+
+    /// Require submissions to authenticate as one of `tenants`
+    #[must_use]
+    pub fn with_tenants(mut self, tenants: impl Into<Arc<TenantRegistry>>) -> Self {
+        self.tenants = tenants.into();
+        self
+    }
+
+    /// Number of workers
+    #[must_use]
+    pub fn workers(&self) -> usize {
+        self.workers
+    }
+
+    /// Tenant holding `api_key`
+    #[must_use]
+    pub fn authenticate(&self, api_key: &str) -> Option<Arc<Tenant>> {
+        self.tenants.authenticate(api_key)
+    }
+
+    /// Counters of every tenant
+    #[must_use]
+    pub fn tenant_metrics(&self) -> Vec<TenantMetrics> {
+        self.tenants.metrics()
+    }
+
+    /// Snapshot of the queue metrics
+    #[must_use]
+    pub fn metrics(&self) -> MetricsSnapshot {
+        let m = &self.metrics;
+        MetricsSnapshot {
+            queue_depth: m.queue_depth.load(Ordering::Relaxed),
+            in_flight: m.in_flight.load(Ordering::Relaxed),
+            workers: self.workers,
+            max_pending: self.max_pending,
+            accepted: m.accepted.load(Ordering::Relaxed),
+            rejected: m.rejected.load(Ordering::Relaxed),
+            verified: m.verified.load(Ordering::Relaxed),
+            failed: m.failed.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Count a submission that was malformed before it could be admitted
+    pub fn record_malformed(&self) {
+        self.metrics.failed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Admit a submission of `tenant` without waiting
+    ///
+    /// Pools without tenants admit anonymous submissions.
+    ///
+    /// # Errors
+    ///
+    /// [`AdmissionRejection::Unauthenticated`] if the pool has tenants and `tenant` is
+    /// `None`, [`AdmissionRejection::Tenant`] if the tenant is over one of its limits, and
+    /// [`AdmissionRejection::Saturated`] if every worker and queue slot is taken
+    pub fn admit(&self, tenant: Option<&Arc<Tenant>>) -> Result<Admission, AdmissionRejection> {
+        let tenant = match tenant {
+            Some(tenant) => Some(tenant.admit()?),
+            None if self.tenants.requires_auth() => return Err(AdmissionRejection::Unauthenticated),
+            None => None,
+        };
+        let Ok(slot) = self.admission.clone().try_acquire_owned() else {
+            // The submission never ran, so it does not count against the quota
+            if let Some(permit) = tenant {
+                permit.cancel();
+            }
+            self.metrics.rejected.fetch_add(1, Ordering::Relaxed);
+            return Err(AdmissionRejection::Saturated);
+        };
+        self.metrics.accepted.fetch_add(1, Ordering::Relaxed);
+        self.metrics.queue_depth.fetch_add(1, Ordering::Relaxed);
+        Ok(Admission {
+            pool: self.clone(),
+            _slot: slot,
+            tenant,
+            queued: true,
+        })
+    }
+}
+
+/// An admitted submission, holding its worker or queue slot until it is dropped
+#[derive(Debug)]
+#[must_use = "dropping an admission releases its slot without verifying"]
+pub struct Admission {
+    pool: WorkerPool,
+    _slot: OwnedSemaphorePermit,
+    tenant: Option<TenantPermit>,
+    queued: bool,
+}
+
+impl Admission {
+    /// Wait for a worker, then run `verify` on the blocking thread pool
+    ///
+    /// The outcome is counted in the pool's and the tenant's metrics before the slot is
+    /// released; only `Ok` counts as verified.
+    ///
+    /// # Errors
+    ///
+    /// [`JoinError`] if `verify` panicked
+    pub async fn run<T, E, F>(mut self, verify: F) -> Result<Result<T, E>, JoinError>
+    where
+        F: FnOnce() -> Result<T, E> + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        let metrics = self.pool.metrics.clone();
+        let worker = self.pool.worker_slots.clone().acquire_owned().await;
+        self.queued = false;
+        metrics.queue_depth.fetch_sub(1, Ordering::Relaxed);
+        metrics.in_flight.fetch_add(1, Ordering::Relaxed);
+
+        let outcome = tokio::task::spawn_blocking(verify).await;
+
+        metrics.in_flight.fetch_sub(1, Ordering::Relaxed);
+        drop(worker);
+        let verified = matches!(outcome, Ok(Ok(_)));
+        let counter = if verified { &metrics.verified } else { &metrics.failed };
+        counter.fetch_add(1, Ordering::Relaxed);
+        if let Some(permit) = self.tenant.take() {
+            permit.finish(verified);
+        }
+        outcome
+    }
+}
+
+impl Drop for Admission {
+    fn drop(&mut self) {
+        if self.queued {
+            self.pool.metrics.queue_depth.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenants::TenantConfig;
+
+    #[test]
+    fn test_admission_rejects_when_saturated() {
+        let pool = WorkerPool::new(1, 1);
+
+        let first = pool.admit(None).expect("worker slot");
+        let second = pool.admit(None).expect("queue slot");
+        assert_eq!(pool.admit(None).unwrap_err(), AdmissionRejection::Saturated);
+        assert_eq!(pool.metrics().queue_depth, 2);
+        assert_eq!(pool.metrics().rejected, 1);
+
+        drop(first);
+        assert!(pool.admit(None).is_ok());
+        drop(second);
+        assert_eq!(pool.metrics().queue_depth, 0);
+    }
+
+    #[tokio::test]
+    async fn test_tenant_limits_enforced_with_pool() {
+        let tenants = TenantRegistry::new(vec![TenantConfig {
+            name: "wallet-a".to_string(),
+            api_key: "key-a".to_string(),
+            max_pending: None,
+            rate_limit: None,
+            proof_quota: Some(2),
+        }])
+        .unwrap();
+        let pool = WorkerPool::new(1, 0).with_tenants(tenants);
+        let tenant = pool.authenticate("key-a").unwrap();
+        assert_eq!(pool.admit(None).unwrap_err(), AdmissionRejection::Unauthenticated);
+        assert_eq!(AdmissionRejection::Unauthenticated.status(), StatusCode::UNAUTHORIZED);
+
+        // A submission the full pool turns away is returned to the quota
+        let running = pool.admit(Some(&tenant)).unwrap();
+        let rejection = pool.admit(Some(&tenant)).unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(tenant.metrics().quota_remaining, Some(1));
+
+        let outcome = running.run(|| Ok::<_, String>(())).await.unwrap();
+        assert!(outcome.is_ok());
+        pool.admit(Some(&tenant)).unwrap().run(|| Err::<(), _>("invalid")).await.unwrap().unwrap_err();
+        let rejection = pool.admit(Some(&tenant)).unwrap_err();
+        assert!(matches!(rejection, AdmissionRejection::Tenant(TenantRejection::QuotaExhausted { .. })));
+        assert_eq!(rejection.status(), StatusCode::FORBIDDEN);
+
+        let metrics = pool.metrics();
+        assert_eq!((metrics.accepted, metrics.verified, metrics.failed), (2, 1, 1));
+        assert_eq!((metrics.queue_depth, metrics.in_flight), (0, 0));
+        let tenant = tenant.metrics();
+        assert_eq!((tenant.accepted, tenant.verified, tenant.failed, tenant.pending), (2, 1, 1, 0));
+    }
+}
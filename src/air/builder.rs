@@ -0,0 +1,771 @@
+//! Declarative AIR Builder
+//!
+//! A hand-written Winterfell AIR keeps `evaluate_transition`, the declared constraint
+//! degrees and the assertion count in sync by hand. [`AirBuilder`] describes the same
+//! statement declaratively: named registers, transition constraints written as
+//! expressions over the current and next row, and boundary values.
+//!
+//! ```text
+//! AirBuilder::new()
+//!     .register("step")
+//!     .register("total")
+//!     .transition("count", |cur, next| next.reg("step") - cur.reg("step") - 1)
+//!     .transition("accumulate", |cur, next| next.reg("total") - cur.reg("total") - cur.reg("step"))
+//!     .boundary("step", BoundaryStep::First, 0)
+//!     .boundary("total", BoundaryStep::Last, BoundaryValue::Public(0))
+//!     .build()?
+//! ```
+//!
+//! Expressions are symbolic ([`AirExpr`]), so constraint degrees are tracked
+//! automatically. The resulting [`AirDefinition`] compiles to the crate's [`Air`] and,
+//! through [`BuiltAir`] and [`BuiltAirProver`], to a Winterfell statement that
+//! [`crate::statements::prove_statement`] and [`crate::statements::verify_statement`]
+//! accept. A constraint that cancels to a lower degree than its expression (e.g.
+//! `x - x`) is declared at the expression's degree, which Winterfell rejects in debug
+//! builds.
+
+use super::{Air, BoundaryConditions, BoundaryConstraint, Constraint, ConstraintType, TransitionFunction};
+use crate::statements::{StatementHasher, StatementRandomCoin};
+use crate::types::FieldElement as AirField;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::sync::Arc;
+use winter_math::{FieldElement, ToElements};
+use winterfell::{
+    math::fields::f64::BaseElement, matrix::ColMatrix, Air as WinterfellAir, AirContext, Assertion,
+    AuxTraceRandElements, ConstraintCompositionCoefficients, DefaultConstraintEvaluator, DefaultTraceLde,
+    EvaluationFrame, ProofOptions, Prover, StarkDomain, TraceInfo, TracePolyTable, TraceTable,
+    TransitionConstraintDegree,
+};
+
+/// Shortest trace Winterfell proves
+const MIN_TRACE_LENGTH: usize = 8;
+
+/// AIR builder error types
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AirBuilderError {
+    /// The AIR declares no registers
+    #[error("AIR has no registers")]
+    NoRegisters,
+
+    /// Two registers share a name
+    #[error("Register {0} is declared twice")]
+    DuplicateRegister(String),
+
+    /// An expression or boundary names an undeclared register
+    #[error("Unknown register {0}")]
+    UnknownRegister(String),
+
+    /// The AIR declares no transition constraints
+    #[error("AIR has no transition constraints")]
+    NoTransitions,
+
+    /// A transition constraint does not depend on the trace
+    #[error("Transition constraint {0} is constant")]
+    ConstantTransition(String),
+
+    /// The AIR declares no boundary values
+    #[error("AIR has no boundary values")]
+    NoBoundaries,
+
+    /// Fewer public inputs than the boundaries read
+    #[error("AIR reads {expected} public inputs, {actual} supplied")]
+    PublicInputCount {
+        /// Public inputs the boundaries read
+        expected: usize,
+        /// Public inputs supplied
+        actual: usize,
+    },
+
+    /// Trace columns do not fit the AIR
+    #[error("Invalid trace: {0}")]
+    InvalidTrace(String),
+}
+
+/// Row an expression reads a register from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameRow {
+    /// The current row
+    Current,
+    /// The next row
+    Next,
+}
+
+/// Symbolic constraint expression over one evaluation frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AirExpr {
+    /// Field constant
+    Constant(u64),
+    /// Register value in a row
+    Register(FrameRow, usize),
+    /// Register name no declared register matches; rejected by [`AirBuilder::build`]
+    Unknown(String),
+    /// Sum
+    Add(Box<AirExpr>, Box<AirExpr>),
+    /// Difference
+    Sub(Box<AirExpr>, Box<AirExpr>),
+    /// Product
+    Mul(Box<AirExpr>, Box<AirExpr>),
+    /// Negation
+    Neg(Box<AirExpr>),
+}
+
+impl AirExpr {
+    /// Raise the expression to a power
+    #[must_use]
+    pub fn pow(self, exponent: u32) -> Self {
+        match exponent {
+            0 => Self::Constant(1),
+            _ => (1..exponent).fold(self.clone(), |product, _| product * self.clone()),
+        }
+    }
+
+    /// Degree in the trace registers
+    #[must_use]
+    pub fn degree(&self) -> usize {
+        match self {
+            Self::Constant(_) | Self::Unknown(_) => 0,
+            Self::Register(..) => 1,
+            Self::Add(a, b) | Self::Sub(a, b) => a.degree().max(b.degree()),
+            Self::Mul(a, b) => a.degree() + b.degree(),
+            Self::Neg(a) => a.degree(),
+        }
+    }
+
+    /// Evaluate over any ring, mapping constants with `constant`
+    pub fn evaluate_with<T>(&self, current: &[T], next: &[T], constant: &dyn Fn(u64) -> T) -> T
+    where
+        T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Neg<Output = T>,
+    {
+        match self {
+            Self::Constant(value) => constant(*value),
+            Self::Register(FrameRow::Current, register) => current[*register],
+            Self::Register(FrameRow::Next, register) => next[*register],
+            // Never present in a built definition
+            Self::Unknown(_) => constant(0),
+            Self::Add(a, b) => a.evaluate_with(current, next, constant) + b.evaluate_with(current, next, constant),
+            Self::Sub(a, b) => a.evaluate_with(current, next, constant) - b.evaluate_with(current, next, constant),
+            Self::Mul(a, b) => a.evaluate_with(current, next, constant) * b.evaluate_with(current, next, constant),
+            Self::Neg(a) => -a.evaluate_with(current, next, constant),
+        }
+    }
+
+    /// Evaluate over the Winterfell base field or an extension of it
+    pub fn evaluate<E: FieldElement<BaseField = BaseElement>>(&self, current: &[E], next: &[E]) -> E {
+        self.evaluate_with(current, next, &|value| E::from(BaseElement::new(value)))
+    }
+
+    /// First register name no declared register matched
+    fn unknown_register(&self) -> Option<&str> {
+        match self {
+            Self::Unknown(name) => Some(name),
+            Self::Constant(_) | Self::Register(..) => None,
+            Self::Add(a, b) | Self::Sub(a, b) | Self::Mul(a, b) => a.unknown_register().or_else(|| b.unknown_register()),
+            Self::Neg(a) => a.unknown_register(),
+        }
+    }
+}
+
+impl From<u64> for AirExpr {
+    fn from(value: u64) -> Self {
+        Self::Constant(value)
+    }
+}
+
+macro_rules! binary_op {
+    ($trait:ident, $method:ident, $variant:ident) => {
+        impl<T: Into<AirExpr>> $trait<T> for AirExpr {
+            type Output = AirExpr;
+
+            fn $method(self, rhs: T) -> AirExpr {
+                AirExpr::$variant(Box::new(self), Box::new(rhs.into()))
+            }
+        }
+    };
+}
+
+binary_op!(Add, add, Add);
+binary_op!(Sub, sub, Sub);
+binary_op!(Mul, mul, Mul);
+
+impl Neg for AirExpr {
+    type Output = AirExpr;
+
+    fn neg(self) -> AirExpr {
+        AirExpr::Neg(Box::new(self))
+    }
+}
+
+/// Register lookup for one row of the frame, passed to transition closures
+#[derive(Debug, Clone, Copy)]
+pub struct FrameRegisters<'a> {
+    names: &'a [String],
+    row: FrameRow,
+}
+
+impl FrameRegisters<'_> {
+    /// Register `name` in this row
+    #[must_use]
+    pub fn reg(&self, name: &str) -> AirExpr {
+        match self.names.iter().position(|register| register == name) {
+            Some(index) => AirExpr::Register(self.row, index),
+            None => AirExpr::Unknown(name.to_string()),
+        }
+    }
+}
+
+/// Row a boundary value applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryStep {
+    /// First row of the trace
+    First,
+    /// Last row of the trace
+    Last,
+}
+
+/// Value a register must hold at a boundary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryValue {
+    /// Fixed value
+    Constant(u64),
+    /// Public input at this index
+    Public(usize),
+}
+
+impl From<u64> for BoundaryValue {
+    fn from(value: u64) -> Self {
+        Self::Constant(value)
+    }
+}
+
+/// Named transition constraint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionSpec {
+    /// Constraint name
+    pub name: String,
+    /// Expression that is zero on every valid step
+    pub expr: AirExpr,
+}
+
+/// Boundary value on one register
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundarySpec {
+    /// Register index
+    pub register: usize,
+    /// Row the value applies to
+    pub step: BoundaryStep,
+    /// Value the register must hold
+    pub value: BoundaryValue,
+}
+
+/// Fluent description of an AIR
+#[derive(Debug, Clone, Default)]
+pub struct AirBuilder {
+    registers: Vec<String>,
+    transitions: Vec<TransitionSpec>,
+    boundaries: Vec<(String, BoundaryStep, BoundaryValue)>,
+}
+
+impl AirBuilder {
+    /// Start an AIR with no registers
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare the next trace register
+    #[must_use]
+    pub fn register(mut self, name: impl Into<String>) -> Self {
+        self.registers.push(name.into());
+        self
+    }
+
+    /// Add a transition constraint, zero on every step of a valid trace
+    ///
+    /// Registers referenced in the closure must be declared before this call.
+    #[must_use]
+    pub fn transition<F>(mut self, name: impl Into<String>, constraint: F) -> Self
+    where
+        F: FnOnce(FrameRegisters<'_>, FrameRegisters<'_>) -> AirExpr,
+    {
+        let current = FrameRegisters {
+            names: &self.registers,
+            row: FrameRow::Current,
+        };
+        let next = FrameRegisters {
+            names: &self.registers,
+            row: FrameRow::Next,
+        };
+        let expr = constraint(current, next);
+        self.transitions.push(TransitionSpec { name: name.into(), expr });
+        self
+    }
+
+    /// Require a register to hold `value` at a boundary row
+    #[must_use]
+    pub fn boundary(mut self, register: impl Into<String>, step: BoundaryStep, value: impl Into<BoundaryValue>) -> Self {
+        self.boundaries.push((register.into(), step, value.into()));
+        self
+    }
+
+    /// Check the description and compute its constraint degrees
+    ///
+    /// # Errors
+    ///
+    /// [`AirBuilderError`] naming the first problem: no registers or a register declared
+    /// twice, no transitions or a constant one, no boundaries, or a constraint reading an
+    /// unknown register
+    pub fn build(self) -> Result<AirDefinition, AirBuilderError> {
+        if self.registers.is_empty() {
+            return Err(AirBuilderError::NoRegisters);
+        }
+        for (index, name) in self.registers.iter().enumerate() {
+            if self.registers[..index].contains(name) {
+                return Err(AirBuilderError::DuplicateRegister(name.clone()));
+            }
+        }
+
+        if self.transitions.is_empty() {
+            return Err(AirBuilderError::NoTransitions);
+        }
+        for transition in &self.transitions {
+            if let Some(name) = transition.expr.unknown_register() {
+                return Err(AirBuilderError::UnknownRegister(name.to_string()));
+            }
+            if transition.expr.degree() == 0 {
+                return Err(AirBuilderError::ConstantTransition(transition.name.clone()));
+            }
+        }
+
+        if self.boundaries.is_empty() {
+            return Err(AirBuilderError::NoBoundaries);
+        }
+        let boundaries = self
+            .boundaries
+            .into_iter()
+            .map(|(name, step, value)| {
+                let register = self
+                    .registers
+                    .iter()
+                    .position(|register| *register == name)
+                    .ok_or(AirBuilderError::UnknownRegister(name))?;
+                Ok(BoundarySpec { register, step, value })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let num_public_inputs = boundaries
+            .iter()
+            .filter_map(|boundary| match boundary.value {
+                BoundaryValue::Public(index) => Some(index + 1),
+                BoundaryValue::Constant(_) => None,
+            })
+            .max()
+            .unwrap_or(0);
+
+        Ok(AirDefinition {
+            registers: self.registers,
+            transitions: self.transitions,
+            boundaries,
+            num_public_inputs,
+        })
+    }
+}
+
+/// Checked AIR description produced by [`AirBuilder::build`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AirDefinition {
+    registers: Vec<String>,
+    transitions: Vec<TransitionSpec>,
+    boundaries: Vec<BoundarySpec>,
+    num_public_inputs: usize,
+}
+
+impl AirDefinition {
+    /// Register names in trace column order
+    #[must_use]
+    pub fn registers(&self) -> &[String] {
+        &self.registers
+    }
+
+    /// Trace width
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.registers.len()
+    }
+
+    /// Transition constraints in evaluation order
+    #[must_use]
+    pub fn transitions(&self) -> &[TransitionSpec] {
+        &self.transitions
+    }
+
+    /// Boundary values
+    #[must_use]
+    pub fn boundaries(&self) -> &[BoundarySpec] {
+        &self.boundaries
+    }
+
+    /// Public inputs the boundaries read
+    #[must_use]
+    pub fn num_public_inputs(&self) -> usize {
+        self.num_public_inputs
+    }
+
+    /// Degree of each transition constraint
+    #[must_use]
+    pub fn transition_degrees(&self) -> Vec<usize> {
+        self.transitions.iter().map(|transition| transition.expr.degree()).collect()
+    }
+
+    /// Highest transition constraint degree
+    #[must_use]
+    pub fn max_degree(&self) -> usize {
+        self.transition_degrees().into_iter().max().unwrap_or(1)
+    }
+
+    /// Evaluate every transition constraint on one frame
+    pub fn evaluate_transition<E: FieldElement<BaseField = BaseElement>>(&self, current: &[E], next: &[E], result: &mut [E]) {
+        for (slot, transition) in result.iter_mut().zip(&self.transitions) {
+            *slot = transition.expr.evaluate(current, next);
+        }
+    }
+
+    /// Boundary assertions for a trace of `trace_length` rows
+    ///
+    /// # Errors
+    ///
+    /// [`AirBuilderError::PublicInputCount`] if there are fewer public inputs than the AIR
+    /// reads
+    pub fn assertions(&self, public_inputs: &[BaseElement], trace_length: usize) -> Result<Vec<Assertion<BaseElement>>, AirBuilderError> {
+        self.check_public_inputs(public_inputs.len())?;
+        Ok(self
+            .boundaries
+            .iter()
+            .map(|boundary| {
+                let step = match boundary.step {
+                    BoundaryStep::First => 0,
+                    BoundaryStep::Last => trace_length - 1,
+                };
+                let value = match boundary.value {
+                    BoundaryValue::Constant(value) => BaseElement::new(value),
+                    BoundaryValue::Public(index) => public_inputs[index],
+                };
+                Assertion::single(boundary.register, step, value)
+            })
+            .collect())
+    }
+
+    /// Assemble a trace from its columns, in register order
+    ///
+    /// # Errors
+    ///
+    /// [`AirBuilderError::InvalidTrace`] if there is not one column per register, the
+    /// columns differ in length, or the length is not a power of two of at least 8
+    pub fn trace(&self, columns: Vec<Vec<BaseElement>>) -> Result<TraceTable<BaseElement>, AirBuilderError> {
+        if columns.len() != self.width() {
+            return Err(AirBuilderError::InvalidTrace(format!(
+                "{} columns for {} registers",
+                columns.len(),
+                self.width()
+            )));
+        }
+        let length = columns[0].len();
+        if columns.iter().any(|column| column.len() != length) {
+            return Err(AirBuilderError::InvalidTrace("columns differ in length".to_string()));
+        }
+        if !length.is_power_of_two() || length < MIN_TRACE_LENGTH {
+            return Err(AirBuilderError::InvalidTrace(format!(
+                "length {length} is not a power of two of at least {MIN_TRACE_LENGTH}"
+            )));
+        }
+        Ok(TraceTable::init(columns))
+    }
+
+    /// Compile to the crate's [`Air`] representation
+    ///
+    /// [`Air`] records constraints by degree and description; evaluate them through
+    /// this definition. Public boundary values are read from `public_inputs`.
+    ///
+    /// # Errors
+    ///
+    /// [`AirBuilderError::PublicInputCount`] if there are fewer public inputs than the AIR
+    /// reads
+    pub fn to_air<F: AirField>(&self, public_inputs: &[u64], security_parameter: u32) -> Result<Air<F>, AirBuilderError> {
+        self.check_public_inputs(public_inputs.len())?;
+
+        let constraints = self
+            .transitions
+            .iter()
+            .map(|transition| {
+                let degree = transition.expr.degree();
+                // Monomial of the constraint's degree
+                let mut polynomial = vec![F::zero(); degree + 1];
+                polynomial[degree] = F::one();
+                Constraint::with_description(polynomial, degree, ConstraintType::Transition, transition.name.clone())
+            })
+            .collect();
+
+        // Layout and degree bound only: the constraints above define the transition
+        let identity = (0..self.width())
+            .map(|row| (0..self.width()).map(|column| if row == column { F::one() } else { F::zero() }).collect())
+            .collect();
+        let transition = TransitionFunction::new(identity, self.max_degree());
+
+        let boundary = BoundaryConditions::new(
+            self.boundaries
+                .iter()
+                .map(|boundary| {
+                    let value = F::new(match boundary.value {
+                        BoundaryValue::Constant(value) => value,
+                        BoundaryValue::Public(index) => public_inputs[index],
+                    });
+                    match boundary.step {
+                        BoundaryStep::First => BoundaryConstraint::initial(boundary.register, value),
+                        BoundaryStep::Last => BoundaryConstraint::final_condition(boundary.register, value),
+                    }
+                })
+                .collect(),
+        );
+
+        Ok(Air::new(constraints, transition, boundary, security_parameter))
+    }
+
+    fn check_public_inputs(&self, actual: usize) -> Result<(), AirBuilderError> {
+        if actual < self.num_public_inputs {
+            return Err(AirBuilderError::PublicInputCount {
+                expected: self.num_public_inputs,
+                actual,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Public inputs of a [`BuiltAir`]: its definition and the values the boundaries read
+#[derive(Debug, Clone)]
+pub struct BuiltAirInputs {
+    definition: Arc<AirDefinition>,
+    values: Vec<BaseElement>,
+}
+
+impl BuiltAirInputs {
+    /// Pair a definition with its public input values
+    ///
+    /// # Errors
+    ///
+    /// [`AirBuilderError::PublicInputCount`] if there are fewer public inputs than the AIR
+    /// reads
+    pub fn new(definition: Arc<AirDefinition>, values: Vec<BaseElement>) -> Result<Self, AirBuilderError> {
+        definition.check_public_inputs(values.len())?;
+        Ok(Self { definition, values })
+    }
+
+    /// The AIR definition
+    #[must_use]
+    pub fn definition(&self) -> &AirDefinition {
+        &self.definition
+    }
+
+    /// Public input values
+    #[must_use]
+    pub fn values(&self) -> &[BaseElement] {
+        &self.values
+    }
+}
+
+impl ToElements<BaseElement> for BuiltAirInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        self.values.clone()
+    }
+}
+
+/// Winterfell AIR for an [`AirDefinition`]
+pub struct BuiltAir {
+    context: AirContext<BaseElement>,
+    inputs: BuiltAirInputs,
+}
+
+impl WinterfellAir for BuiltAir {
+    type BaseField = BaseElement;
+    type PublicInputs = BuiltAirInputs;
+
+    fn new(trace_info: TraceInfo, inputs: BuiltAirInputs, options: ProofOptions) -> Self {
+        let degrees = inputs
+            .definition
+            .transition_degrees()
+            .into_iter()
+            .map(TransitionConstraintDegree::new)
+            .collect();
+        let context = AirContext::new(trace_info, degrees, inputs.definition.boundaries.len(), options);
+        Self { context, inputs }
+    }
+
+    fn context(&self) -> &AirContext<BaseElement> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = BaseElement>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        self.inputs.definition.evaluate_transition(frame.current(), frame.next(), result);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<BaseElement>> {
+        // BuiltAirInputs::new checked the public input count
+        self.inputs
+            .definition
+            .assertions(&self.inputs.values, self.trace_length())
+            .expect("public inputs cover the boundaries")
+    }
+}
+
+/// Prover for a [`BuiltAir`]
+#[derive(Debug, Clone)]
+pub struct BuiltAirProver {
+    inputs: BuiltAirInputs,
+    options: ProofOptions,
+}
+
+impl BuiltAirProver {
+    /// Prover for the statement `inputs` describes
+    #[must_use]
+    pub fn new(inputs: BuiltAirInputs, options: ProofOptions) -> Self {
+        Self { inputs, options }
+    }
+}
+
+impl Prover for BuiltAirProver {
+    type BaseField = BaseElement;
+    type Air = BuiltAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = StatementHasher;
+    type RandomCoin = StatementRandomCoin;
+    type TraceLde<E>
+        = DefaultTraceLde<E, StatementHasher>
+    where
+        E: FieldElement<BaseField = BaseElement>;
+    type ConstraintEvaluator<'a, E>
+        = DefaultConstraintEvaluator<'a, BuiltAir, E>
+    where
+        E: FieldElement<BaseField = BaseElement>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) -> BuiltAirInputs {
+        self.inputs.clone()
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<BaseElement>,
+        domain: &StarkDomain<BaseElement>,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: FieldElement<BaseField = BaseElement>,
+    {
+        DefaultTraceLde::new(trace_info, main_trace, domain)
+    }
+
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a BuiltAir,
+        aux_rand_elements: AuxTraceRandElements<E>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: FieldElement<BaseField = BaseElement>,
+    {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statements::{prove_statement, verify_statement};
+    use crate::types::field::PrimeField64;
+    use winterfell::FieldExtension;
+
+    /// Counter `step` and running sum `total` of the counter, ending at a public value
+    fn accumulator() -> AirDefinition {
+        AirBuilder::new()
+            .register("step")
+            .register("total")
+            .transition("count", |cur, next| next.reg("step") - cur.reg("step") - 1)
+            .transition("accumulate", |cur, next| next.reg("total") - cur.reg("total") - cur.reg("step"))
+            .boundary("step", BoundaryStep::First, 0)
+            .boundary("total", BoundaryStep::First, 0)
+            .boundary("total", BoundaryStep::Last, BoundaryValue::Public(0))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_builder_tracks_degrees_and_rejects_bad_descriptions() {
+        let definition = accumulator();
+        assert_eq!(definition.transition_degrees(), vec![1, 1]);
+        assert_eq!(definition.num_public_inputs(), 1);
+
+        let boolean = AirBuilder::new()
+            .register("bit")
+            .transition("boolean", |cur, _| cur.reg("bit") * (cur.reg("bit") - 1))
+            .transition("cube", |cur, next| next.reg("bit") - cur.reg("bit").pow(3))
+            .boundary("bit", BoundaryStep::First, 1)
+            .build()
+            .unwrap();
+        assert_eq!(boolean.transition_degrees(), vec![2, 3]);
+
+        let air = boolean.to_air::<PrimeField64>(&[], 128).unwrap();
+        assert_eq!(air.max_degree(), 3);
+        assert_eq!(air.num_registers(), 1);
+        assert!(air.validate().is_ok());
+
+        let unknown = AirBuilder::new()
+            .register("a")
+            .transition("typo", |cur, next| next.reg("a") - cur.reg("b"))
+            .boundary("a", BoundaryStep::First, 0)
+            .build();
+        assert_eq!(unknown, Err(AirBuilderError::UnknownRegister("b".to_string())));
+        let constant = AirBuilder::new()
+            .register("a")
+            .transition("constant", |_, _| AirExpr::Constant(1))
+            .boundary("a", BoundaryStep::First, 0)
+            .build();
+        assert_eq!(constant, Err(AirBuilderError::ConstantTransition("constant".to_string())));
+    }
+
+    #[test]
+    fn test_built_air_proves_and_verifies() {
+        const LENGTH: u64 = 16;
+        let definition = Arc::new(accumulator());
+        let steps: Vec<u64> = (0..LENGTH).collect();
+        let totals: Vec<u64> = steps
+            .iter()
+            .scan(0, |total, step| {
+                let row = *total;
+                *total += step;
+                Some(row)
+            })
+            .collect();
+        let total = *totals.last().unwrap();
+
+        let trace = definition
+            .trace(vec![
+                steps.into_iter().map(BaseElement::new).collect(),
+                totals.into_iter().map(BaseElement::new).collect(),
+            ])
+            .unwrap();
+        let options = ProofOptions::new(42, 8, 4, FieldExtension::Quadratic, 8, 31);
+        let inputs = BuiltAirInputs::new(Arc::clone(&definition), vec![BaseElement::new(total)]).unwrap();
+        let proof = prove_statement(&BuiltAirProver::new(inputs.clone(), options.clone()), trace).unwrap();
+
+        assert!(verify_statement::<BuiltAir>(proof.clone(), inputs, &options).is_ok());
+        let forged = BuiltAirInputs::new(definition, vec![BaseElement::new(total + 1)]).unwrap();
+        assert!(verify_statement::<BuiltAir>(proof, forged, &options).is_err());
+    }
+}
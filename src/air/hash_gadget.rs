@@ -0,0 +1,570 @@
+//! Rescue-Prime Hash Gadget
+//!
+//! The Rescue-Prime permutation behind [`RescueHasher`] (Winterfell's `Rp64_256`),
+//! enforced as transition constraints so a hash is proven inside the trace instead of
+//! being computed outside it and trusted:
+//!
+//! - The permutation occupies [`PERMUTATION_WIDTH`] registers: the [`STATE_WIDTH`] state
+//!   registers followed by two blocks of S-box helpers
+//! - Each row applies one round; a cycle of [`CYCLE_LENGTH`] rows holds the input state
+//!   followed by [`NUM_ROUNDS`] rounds, and its last row holds the output
+//! - [`periodic_columns`] supplies a round flag and the round constants, and
+//!   [`enforce_round`] checks one round per row against them
+//!
+//! A Rescue round is `s → MDS(s^α) + C₁ → MDS((·)^(1/α)) + C₂`. The inverse S-box would
+//! give the constraint a huge degree, so [`enforce_round`] meets in the middle: it checks
+//! `MDS(s^α) + C₁ = y^α` with `y = MDS⁻¹(s' - C₂)`. With α = 7 that alone has degree 7
+//! and needs a blowup factor of 8, so the helpers hold `s³` ([`FORWARD_CUBE_RANGE`]) and
+//! `y³` ([`BACKWARD_CUBE_RANGE`]) and every constraint has degree 3, which the blowup
+//! factor 4 of the constrained security preset accommodates.
+//!
+//! [`RescueNullifierGadget`] derives nullifiers with the permutation and
+//! [`RescueNullifierAir`] proves one in-circuit: the verifier learns the domain, scope and
+//! nullifier, and the secret stays in the trace. The burn & mint AIR runs the same
+//! permutation next to its own registers (see [`crate::burn_mint_air`]).
+//!
+//! [`RescueHasher`]: crate::proof::hasher::RescueHasher
+
+use crate::statements::{StatementHasher, StatementRandomCoin};
+use std::ops::Range;
+use winter_math::{FieldElement, ToElements};
+use winterfell::{
+    crypto::{hashers::Rp64_256, ElementHasher, Hasher},
+    math::fields::f64::BaseElement,
+    matrix::ColMatrix,
+    Air, AirContext, Assertion, AuxTraceRandElements, ConstraintCompositionCoefficients,
+    DefaultConstraintEvaluator, DefaultTraceLde, EvaluationFrame, ProofOptions, Prover,
+    StarkDomain, TraceInfo, TracePolyTable, TraceTable, TransitionConstraintDegree,
+};
+
+/// Registers holding the permutation state
+pub const STATE_WIDTH: usize = 12;
+
+/// Registers holding `s³` for the state `s` of a round row
+pub const FORWARD_CUBE_RANGE: Range<usize> = STATE_WIDTH..2 * STATE_WIDTH;
+
+/// Registers holding `y³` for the middle state `y` of a round, undone from the next row
+pub const BACKWARD_CUBE_RANGE: Range<usize> = 2 * STATE_WIDTH..3 * STATE_WIDTH;
+
+/// Registers one permutation occupies: the state and both S-box helper blocks
+pub const PERMUTATION_WIDTH: usize = 3 * STATE_WIDTH;
+
+/// Transition constraints [`enforce_round`] writes: three per state register
+pub const NUM_ROUND_CONSTRAINTS: usize = 3 * STATE_WIDTH;
+
+/// Rounds in one permutation
+pub const NUM_ROUNDS: usize = 7;
+
+/// Rows in one permutation cycle: the input state and one row per round
+pub const CYCLE_LENGTH: usize = NUM_ROUNDS + 1;
+
+/// State registers the sponge absorbs input into
+pub const RATE_RANGE: Range<usize> = 4..12;
+
+/// State registers holding the digest after a permutation
+pub const DIGEST_RANGE: Range<usize> = 4..8;
+
+/// Elements in a digest
+pub const DIGEST_LIMBS: usize = DIGEST_RANGE.end - DIGEST_RANGE.start;
+
+/// Periodic columns [`enforce_round`] reads: the round flag and two constants per register
+pub const NUM_PERIODIC_COLUMNS: usize = 1 + 2 * STATE_WIDTH;
+
+/// Degree of every round constraint, before the round flag
+const ROUND_CONSTRAINT_DEGREE: usize = 3;
+
+/// Round flag and round constants as periodic columns of length [`CYCLE_LENGTH`]
+///
+/// Column 0 is 1 on rows that start a round and 0 on the cycle's output row; columns
+/// `1..=STATE_WIDTH` hold the first-half constants and the rest the second-half constants.
+#[must_use]
+pub fn periodic_columns() -> Vec<Vec<BaseElement>> {
+    let mut flag = vec![BaseElement::ONE; NUM_ROUNDS];
+    flag.push(BaseElement::ZERO);
+
+    let constants = |ark: &[[BaseElement; STATE_WIDTH]; NUM_ROUNDS], register: usize| {
+        let mut column: Vec<BaseElement> = ark.iter().map(|round| round[register]).collect();
+        column.push(BaseElement::ZERO);
+        column
+    };
+
+    let mut columns = vec![flag];
+    columns.extend((0..STATE_WIDTH).map(|register| constants(&Rp64_256::ARK1, register)));
+    columns.extend((0..STATE_WIDTH).map(|register| constants(&Rp64_256::ARK2, register)));
+    columns
+}
+
+/// Transition constraints of one round, written to `result[..NUM_ROUND_CONSTRAINTS]`
+///
+/// `current` and `next` hold the [`PERMUTATION_WIDTH`] permutation registers of two
+/// consecutive rows and `periodic` the values of [`periodic_columns`] at the current
+/// row. Every constraint is zero when `next` is one round applied to `current` and the
+/// helpers hold their cubes, or when the round flag is off.
+pub fn enforce_round<E: FieldElement<BaseField = BaseElement>>(
+    current: &[E],
+    next: &[E],
+    periodic: &[E],
+    result: &mut [E],
+) {
+    let flag = periodic[0];
+    let ark1 = &periodic[1..=STATE_WIDTH];
+    let ark2 = &periodic[1 + STATE_WIDTH..NUM_PERIODIC_COLUMNS];
+    let state = &current[..STATE_WIDTH];
+    let forward_cubes = &current[FORWARD_CUBE_RANGE];
+    let backward_cubes = &current[BACKWARD_CUBE_RANGE];
+
+    // Middle state undone from the next row
+    let mut backward = [E::ZERO; STATE_WIDTH];
+    for ((value, &register), &constant) in backward.iter_mut().zip(&next[..STATE_WIDTH]).zip(ark2) {
+        *value = register - constant;
+    }
+    let backward = mds_multiply(&backward, &Rp64_256::INV_MDS);
+
+    // Forward half of the round: s^7 = (s^3)^2 · s
+    let mut forward = [E::ZERO; STATE_WIDTH];
+    for ((value, &register), &cube) in forward.iter_mut().zip(state).zip(forward_cubes) {
+        *value = cube.square() * register;
+    }
+    let mut forward = mds_multiply(&forward, &Rp64_256::MDS);
+    for (value, &constant) in forward.iter_mut().zip(ark1) {
+        *value += constant;
+    }
+
+    let (cubes, rest) = result[..NUM_ROUND_CONSTRAINTS].split_at_mut(STATE_WIDTH);
+    let (middle_cubes, round) = rest.split_at_mut(STATE_WIDTH);
+    for i in 0..STATE_WIDTH {
+        cubes[i] = flag * (forward_cubes[i] - cube(state[i]));
+        middle_cubes[i] = flag * (backward_cubes[i] - cube(backward[i]));
+        round[i] = flag * (backward_cubes[i].square() * backward[i] - forward[i]);
+    }
+}
+
+/// Degrees of the [`NUM_ROUND_CONSTRAINTS`] constraints [`enforce_round`] writes
+///
+/// `flag_cycle` is the length of the round flag column: [`CYCLE_LENGTH`] for the flag
+/// [`periodic_columns`] supplies, or longer for an AIR that runs the permutation on only
+/// some of its rows.
+#[must_use]
+pub fn round_constraint_degrees(flag_cycle: usize) -> Vec<TransitionConstraintDegree> {
+    vec![
+        TransitionConstraintDegree::with_cycles(ROUND_CONSTRAINT_DEGREE, vec![flag_cycle]);
+        NUM_ROUND_CONSTRAINTS
+    ]
+}
+
+/// Rows of one permutation cycle starting from `state`
+///
+/// Row 0 is `state` and row `i` is the state after `i` rounds. The helpers of each round
+/// row hold the cubes [`enforce_round`] checks; those of the output row are zero.
+#[must_use]
+pub fn permutation_rows(mut state: [BaseElement; STATE_WIDTH]) -> Vec<[BaseElement; PERMUTATION_WIDTH]> {
+    let mut rows = Vec::with_capacity(CYCLE_LENGTH);
+    for round in 0..NUM_ROUNDS {
+        let mut row = [BaseElement::ZERO; PERMUTATION_WIDTH];
+        row[..STATE_WIDTH].copy_from_slice(&state);
+        for (helper, &register) in row[FORWARD_CUBE_RANGE].iter_mut().zip(&state) {
+            *helper = cube(register);
+        }
+
+        Rp64_256::apply_round(&mut state, round);
+        let mut middle = state;
+        for (value, constant) in middle.iter_mut().zip(Rp64_256::ARK2[round]) {
+            *value -= constant;
+        }
+        for (helper, value) in row[BACKWARD_CUBE_RANGE].iter_mut().zip(mds_multiply(&middle, &Rp64_256::INV_MDS)) {
+            *helper = cube(value);
+        }
+        rows.push(row);
+    }
+
+    let mut output = [BaseElement::ZERO; PERMUTATION_WIDTH];
+    output[..STATE_WIDTH].copy_from_slice(&state);
+    rows.push(output);
+    rows
+}
+
+/// Sponge state absorbing up to eight elements, as `Rp64_256`'s `hash_elements` starts it
+///
+/// The first capacity register holds the number of elements and the rate holds the
+/// elements, zero padded. One permutation of this state yields
+/// `Rp64_256::hash_elements(elements)` in [`DIGEST_RANGE`].
+///
+/// # Panics
+/// If there are more than eight elements.
+#[must_use]
+pub fn absorb(elements: &[BaseElement]) -> [BaseElement; STATE_WIDTH] {
+    assert!(elements.len() <= RATE_RANGE.len(), "a single permutation absorbs at most 8 elements");
+    let mut state = [BaseElement::ZERO; STATE_WIDTH];
+    state[0] = BaseElement::new(elements.len() as u64);
+    state[RATE_RANGE.start..RATE_RANGE.start + elements.len()].copy_from_slice(elements);
+    state
+}
+
+/// `count` pseudo-random elements squeezed from a sponge absorbing `seed`
+///
+/// Fills trace rows that no constraint reads, so that they carry values a verifier cannot
+/// predict without the seed.
+#[must_use]
+pub fn blinding_elements(seed: &[BaseElement], count: usize) -> Vec<BaseElement> {
+    let mut state = absorb(seed);
+    let mut elements = Vec::with_capacity(count + RATE_RANGE.len());
+    while elements.len() < count {
+        Rp64_256::apply_permutation(&mut state);
+        elements.extend_from_slice(&state[RATE_RANGE]);
+    }
+    elements.truncate(count);
+    elements
+}
+
+/// `x³`
+fn cube<E: FieldElement>(x: E) -> E {
+    x.square() * x
+}
+
+/// `matrix · state`
+fn mds_multiply<E: FieldElement<BaseField = BaseElement>>(
+    state: &[E; STATE_WIDTH],
+    matrix: &[[BaseElement; STATE_WIDTH]; STATE_WIDTH],
+) -> [E; STATE_WIDTH] {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for (output, row) in result.iter_mut().zip(matrix) {
+        for (&value, &coefficient) in state.iter().zip(row) {
+            *output += value.mul_base(coefficient);
+        }
+    }
+    result
+}
+
+/// Domain-separated nullifier derived with Rescue-Prime
+///
+/// `nullifier = Rp64_256::hash_elements([secret.., s₀, s₁, s₂, s₃])`, the whole
+/// four-element digest, where `s = Rp64_256::hash_elements([domain, scope..])` is the
+/// [`RescueNullifierGadget::scope_digest`]. The domain tag enters as the first element of
+/// its `Rp64_256` byte hash. The domain and scope are public, so they are hashed outside the
+/// trace and one permutation absorbs a secret of up to [`MAX_SECRET_LIMBS`] elements with
+/// the scope digest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RescueNullifierGadget {
+    domain: &'static [u8],
+}
+
+/// Most secret elements a nullifier permutation absorbs next to the scope digest
+pub const MAX_SECRET_LIMBS: usize = RATE_RANGE.end - RATE_RANGE.start - DIGEST_LIMBS;
+
+impl RescueNullifierGadget {
+    /// Create a nullifier gadget for a statement's domain tag
+    #[must_use]
+    pub const fn new(domain: &'static [u8]) -> Self {
+        Self { domain }
+    }
+
+    /// Domain tag mixed into every nullifier
+    #[must_use]
+    pub const fn domain(&self) -> &'static [u8] {
+        self.domain
+    }
+
+    /// Domain tag as the field element the scope digest absorbs
+    #[must_use]
+    pub fn domain_element(&self) -> BaseElement {
+        Rp64_256::hash(self.domain).as_elements()[0]
+    }
+
+    /// Digest of the domain and scope the permutation absorbs after the secret
+    #[must_use]
+    pub fn scope_digest(&self, scope: &[BaseElement]) -> [BaseElement; DIGEST_LIMBS] {
+        let mut elements = vec![self.domain_element()];
+        elements.extend_from_slice(scope);
+        Rp64_256::hash_elements(&elements).into()
+    }
+
+    /// Permutation input for a secret within a scope
+    ///
+    /// # Panics
+    /// If the secret has more than [`MAX_SECRET_LIMBS`] elements.
+    #[must_use]
+    pub fn input_state(&self, secret: &[BaseElement], scope: &[BaseElement]) -> [BaseElement; STATE_WIDTH] {
+        absorb(&self.input(secret, scope))
+    }
+
+    /// Derive the nullifier for a secret within a scope
+    ///
+    /// # Panics
+    /// If the secret has more than [`MAX_SECRET_LIMBS`] elements.
+    #[must_use]
+    pub fn derive(&self, secret: &[BaseElement], scope: &[BaseElement]) -> [BaseElement; DIGEST_LIMBS] {
+        Rp64_256::hash_elements(&self.input(secret, scope)).into()
+    }
+
+    /// Elements the nullifier hashes: the secret, then the scope digest
+    fn input(&self, secret: &[BaseElement], scope: &[BaseElement]) -> Vec<BaseElement> {
+        assert!(secret.len() <= MAX_SECRET_LIMBS, "a nullifier secret has at most 4 elements");
+        let mut input = secret.to_vec();
+        input.extend(self.scope_digest(scope));
+        input
+    }
+}
+
+/// Public inputs of [`RescueNullifierAir`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RescueNullifierInputs {
+    /// Domain tag element (see [`RescueNullifierGadget::domain_element`])
+    pub domain: BaseElement,
+    /// Scope the nullifier is tied to
+    pub scope: BaseElement,
+    /// Claimed nullifier
+    pub nullifier: [BaseElement; DIGEST_LIMBS],
+}
+
+impl RescueNullifierInputs {
+    /// Public inputs for the nullifier `gadget` derives from `secret` within `scope`
+    #[must_use]
+    pub fn derive(gadget: &RescueNullifierGadget, secret: BaseElement, scope: BaseElement) -> Self {
+        Self {
+            domain: gadget.domain_element(),
+            scope,
+            nullifier: gadget.derive(&[secret], &[scope]),
+        }
+    }
+
+    /// Scope digest the permutation absorbs, recomputed from the domain and scope
+    fn scope_digest(&self) -> [BaseElement; DIGEST_LIMBS] {
+        Rp64_256::hash_elements(&[self.domain, self.scope]).into()
+    }
+}
+
+impl ToElements<BaseElement> for RescueNullifierInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        let mut elements = vec![self.domain, self.scope];
+        elements.extend(self.nullifier);
+        elements
+    }
+}
+
+/// Register holding the secret on the input row
+const SECRET_REGISTER: usize = RATE_RANGE.start;
+
+/// Input-row assertions: everything but the secret register
+const NUM_INPUT_ASSERTIONS: usize = STATE_WIDTH - 1;
+
+/// AIR proving knowledge of a secret whose Rescue nullifier is public
+///
+/// One permutation cycle: the input row is pinned to the sponge state of
+/// `[secret, scope digest]` except for the secret itself, [`enforce_round`] holds every
+/// round, and the digest registers of the output row are pinned to the nullifier.
+pub struct RescueNullifierAir {
+    context: AirContext<BaseElement>,
+    public_inputs: RescueNullifierInputs,
+}
+
+impl Air for RescueNullifierAir {
+    type BaseField = BaseElement;
+    type PublicInputs = RescueNullifierInputs;
+
+    fn new(trace_info: TraceInfo, public_inputs: RescueNullifierInputs, options: ProofOptions) -> Self {
+        let context = AirContext::new(
+            trace_info,
+            round_constraint_degrees(CYCLE_LENGTH),
+            NUM_INPUT_ASSERTIONS + DIGEST_LIMBS,
+            options,
+        );
+        Self {
+            context,
+            public_inputs,
+        }
+    }
+
+    fn context(&self) -> &AirContext<BaseElement> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = BaseElement>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        enforce_round(frame.current(), frame.next(), periodic_values, result);
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<BaseElement>> {
+        periodic_columns()
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<BaseElement>> {
+        let inputs = &self.public_inputs;
+        let mut input = vec![BaseElement::ZERO];
+        input.extend(inputs.scope_digest());
+        let input_state = absorb(&input);
+
+        let mut assertions: Vec<_> = input_state
+            .iter()
+            .enumerate()
+            .filter(|&(register, _)| register != SECRET_REGISTER)
+            .map(|(register, &value)| Assertion::single(register, 0, value))
+            .collect();
+        for (register, value) in DIGEST_RANGE.zip(inputs.nullifier) {
+            assertions.push(Assertion::single(register, NUM_ROUNDS, value));
+        }
+        assertions
+    }
+}
+
+/// Prover for [`RescueNullifierAir`]
+pub struct RescueNullifierProver {
+    gadget: RescueNullifierGadget,
+    secret: BaseElement,
+    scope: BaseElement,
+    options: ProofOptions,
+}
+
+impl RescueNullifierProver {
+    /// Prove the nullifier `gadget` derives from `secret` within `scope`
+    #[must_use]
+    pub fn new(gadget: RescueNullifierGadget, secret: BaseElement, scope: BaseElement, options: ProofOptions) -> Self {
+        Self {
+            gadget,
+            secret,
+            scope,
+            options,
+        }
+    }
+
+    /// Public inputs the proof is checked against
+    #[must_use]
+    pub fn public_inputs(&self) -> RescueNullifierInputs {
+        RescueNullifierInputs::derive(&self.gadget, self.secret, self.scope)
+    }
+
+    /// Execution trace: one permutation cycle over the nullifier input
+    #[must_use]
+    pub fn build_trace(&self) -> TraceTable<BaseElement> {
+        let rows = permutation_rows(self.gadget.input_state(&[self.secret], &[self.scope]));
+        let columns = (0..PERMUTATION_WIDTH)
+            .map(|register| rows.iter().map(|row| row[register]).collect())
+            .collect();
+        TraceTable::init(columns)
+    }
+}
+
+impl Prover for RescueNullifierProver {
+    type BaseField = BaseElement;
+    type Air = RescueNullifierAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = StatementHasher;
+    type RandomCoin = StatementRandomCoin;
+    type TraceLde<E>
+        = DefaultTraceLde<E, StatementHasher>
+    where
+        E: FieldElement<BaseField = BaseElement>;
+    type ConstraintEvaluator<'a, E>
+        = DefaultConstraintEvaluator<'a, RescueNullifierAir, E>
+    where
+        E: FieldElement<BaseField = BaseElement>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) -> RescueNullifierInputs {
+        self.public_inputs()
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<BaseElement>,
+        domain: &StarkDomain<BaseElement>,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: FieldElement<BaseField = BaseElement>,
+    {
+        DefaultTraceLde::new(trace_info, main_trace, domain)
+    }
+
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a RescueNullifierAir,
+        aux_rand_elements: AuxTraceRandElements<E>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: FieldElement<BaseField = BaseElement>,
+    {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_air::BURN_NULLIFIER;
+    use crate::proof::hasher::{Hasher as _, RescueHasher};
+    use crate::statements::{prove_statement, verify_statement};
+    use crate::types::field::PrimeField64;
+    use winterfell::FieldExtension;
+
+    const NULLIFIER: RescueNullifierGadget = RescueNullifierGadget::new(b"nullifier");
+
+    fn options() -> ProofOptions {
+        ProofOptions::new(42, 16, 4, FieldExtension::None, 8, 31)
+    }
+
+    #[test]
+    fn test_rows_satisfy_round_constraints() {
+        let state = absorb(&[BaseElement::new(5), BaseElement::new(6)]);
+        let rows = permutation_rows(state);
+        let digest = Rp64_256::hash_elements(&[BaseElement::new(5), BaseElement::new(6)]);
+        assert_eq!(&rows[NUM_ROUNDS][DIGEST_RANGE], digest.as_elements());
+
+        let periodic = periodic_columns();
+        let mut result = [BaseElement::ZERO; NUM_ROUND_CONSTRAINTS];
+        for step in 0..NUM_ROUNDS {
+            let values: Vec<_> = periodic.iter().map(|column| column[step]).collect();
+            enforce_round(&rows[step], &rows[step + 1], &values, &mut result);
+            assert!(result.iter().all(|value| *value == BaseElement::ZERO));
+        }
+
+        let mut tampered = rows[3];
+        tampered[0] += BaseElement::ONE;
+        let values: Vec<_> = periodic.iter().map(|column| column[2]).collect();
+        enforce_round(&rows[2], &tampered, &values, &mut result);
+        assert!(result.iter().any(|value| *value != BaseElement::ZERO));
+    }
+
+    #[test]
+    fn test_nullifier_matches_rescue_hasher() {
+        let secret = BaseElement::new(42);
+        let scope = BaseElement::new(8_000_000);
+        let nullifier = NULLIFIER.derive(&[secret], &[scope]);
+
+        let to_field = |elements: &[BaseElement]| -> Vec<_> { elements.iter().map(|e| PrimeField64::new(e.as_int())).collect() };
+        let scope_digest = RescueHasher::hash_elements(&to_field(&[NULLIFIER.domain_element(), scope]));
+        let mut elements = to_field(&[secret]);
+        elements.extend(scope_digest.chunks(8).map(|limb| PrimeField64::new(u64::from_le_bytes(limb.try_into().unwrap()))));
+        let digest = RescueHasher::hash_elements(&elements);
+        let bytes: Vec<u8> = nullifier.iter().flat_map(|limb| limb.as_int().to_le_bytes()).collect();
+        assert_eq!(bytes, digest);
+        assert_eq!(NULLIFIER.domain(), BURN_NULLIFIER.domain());
+        assert_ne!(nullifier, RescueNullifierGadget::new(b"stake-nullifier").derive(&[secret], &[scope]));
+        assert_ne!(nullifier, NULLIFIER.derive(&[secret], &[scope, BaseElement::ONE]));
+    }
+
+    #[test]
+    fn test_nullifier_proven_in_circuit() {
+        let prover = RescueNullifierProver::new(NULLIFIER, BaseElement::new(0x5eed), BaseElement::new(8_000_000), options());
+        let inputs = prover.public_inputs();
+        let proof = prove_statement(&prover, prover.build_trace()).unwrap();
+
+        assert!(verify_statement::<RescueNullifierAir>(proof.clone(), inputs, &options()).is_ok());
+
+        let mut forged = inputs;
+        forged.nullifier[3] += BaseElement::ONE;
+        assert!(verify_statement::<RescueNullifierAir>(proof.clone(), forged, &options()).is_err());
+        let rescoped = RescueNullifierInputs {
+            scope: BaseElement::new(1),
+            ..inputs
+        };
+        assert!(verify_statement::<RescueNullifierAir>(proof, rescoped, &options()).is_err());
+    }
+}
@@ -0,0 +1,540 @@
+//! Lookup Arguments
+//!
+//! Range checking a value by bit decomposition costs a trace column per bit. A lookup
+//! costs two main columns (the looked-up value and a multiplicity) and one auxiliary
+//! accumulator, whatever the table size. The table itself is a periodic column, so the
+//! verifier knows it without the prover committing to it.
+//!
+//! The argument is `LogUp`. With a challenge `α` drawn after the main trace is committed,
+//! the accumulator `z` starts at zero and on every step adds
+//!
+//! `1 / (α - f) - m / (α - t)`
+//!
+//! where `f` is the looked-up value, `t` the table value on that row and `m` how often
+//! the prover claims `t` is looked up. `z` returns to zero at the last row only if every
+//! `f` is in the table, except with negligible probability. Multiplied out, the
+//! transition constraint is
+//!
+//! `(z' - z)(α - f)(α - t) - (α - t) + m(α - f) = 0`
+//!
+//! Like every transition constraint it covers rows `0..n-1`, so the last row of the
+//! looked-up column is not checked and traces keep it as padding.
+//!
+//! An AIR using a lookup declares [`LookupArgument::constraint_degree`] among its
+//! auxiliary constraint degrees, returns [`LookupTable::periodic_column`] from
+//! `get_periodic_column_values`, and evaluates [`LookupArgument::evaluate`] and
+//! [`LookupArgument::assertions`] on the auxiliary segment. Its prover uses
+//! [`LookupTrace`], which builds the accumulators once the challenge is known.
+
+use std::collections::HashMap;
+use winter_math::{batch_inversion, ExtensionOf, FieldElement};
+use winterfell::{
+    math::fields::f64::BaseElement, matrix::ColMatrix, Assertion, EvaluationFrame, Trace, TraceLayout,
+    TransitionConstraintDegree,
+};
+
+/// Shortest trace Winterfell proves
+const MIN_TRACE_LENGTH: usize = 8;
+
+/// Lookup error types
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LookupError {
+    /// The table has no entries
+    #[error("Lookup table is empty")]
+    EmptyTable,
+
+    /// A looked-up value is not in the table
+    #[error("Value {value} at row {row} is not in the lookup table")]
+    MissingValue {
+        /// Looked-up value
+        value: u64,
+        /// Row holding it
+        row: usize,
+    },
+
+    /// The table's periodic column is longer than the trace
+    #[error("Lookup table of period {period} does not fit a trace of {trace_length} rows")]
+    TableTooLarge {
+        /// Period of the table column
+        period: usize,
+        /// Rows in the trace
+        trace_length: usize,
+    },
+
+    /// Trace columns or lookups do not fit together
+    #[error("Invalid lookup trace: {0}")]
+    InvalidTrace(String),
+}
+
+/// Table of values a column may take
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LookupTable {
+    values: Vec<BaseElement>,
+}
+
+impl LookupTable {
+    /// Table of distinct values, in ascending order
+    ///
+    /// # Errors
+    ///
+    /// [`LookupError::EmptyTable`] if there are no values
+    pub fn new(values: impl IntoIterator<Item = u64>) -> Result<Self, LookupError> {
+        let mut values: Vec<u64> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+        if values.is_empty() {
+            return Err(LookupError::EmptyTable);
+        }
+        Ok(Self {
+            values: values.into_iter().map(BaseElement::new).collect(),
+        })
+    }
+
+    /// Values `0..2^bits`
+    pub fn range(bits: u32) -> Self {
+        Self {
+            values: (0..1u64 << bits).map(BaseElement::new).collect(),
+        }
+    }
+
+    /// Byte values `0..256`
+    #[must_use]
+    pub fn bytes() -> Self {
+        Self::range(8)
+    }
+
+    /// Table entries
+    #[must_use]
+    pub fn values(&self) -> &[BaseElement] {
+        &self.values
+    }
+
+    /// Number of entries
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if the table has no entries (never true for a constructed table)
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Length of the table's periodic column
+    ///
+    /// The entries plus at least one padding row, rounded up to a power of two.
+    #[must_use]
+    pub fn period(&self) -> usize {
+        (self.values.len() + 1).next_power_of_two()
+    }
+
+    /// Periodic column carrying the table, padded with its first entry
+    #[must_use]
+    pub fn periodic_column(&self) -> Vec<BaseElement> {
+        let mut column = self.values.clone();
+        column.resize(self.period(), self.values[0]);
+        column
+    }
+
+    /// Multiplicity column for a looked-up column
+    ///
+    /// Row `i` counts the lookups of entry `i`; the remaining rows are zero. The last row
+    /// of `column` is padding and is not counted.
+    ///
+    /// # Errors
+    ///
+    /// [`LookupError::TableTooLarge`] if the table has more entries than `column` has rows,
+    /// or [`LookupError::MissingValue`] for the first value the table does not hold
+    pub fn multiplicities(&self, column: &[BaseElement]) -> Result<Vec<BaseElement>, LookupError> {
+        let trace_length = column.len();
+        if self.period() > trace_length {
+            return Err(LookupError::TableTooLarge {
+                period: self.period(),
+                trace_length,
+            });
+        }
+
+        let index: HashMap<u64, usize> =
+            self.values.iter().enumerate().map(|(i, value)| (value.as_int(), i)).collect();
+        let mut counts = vec![0u64; trace_length];
+        for (row, value) in column[..trace_length - 1].iter().enumerate() {
+            let entry = index.get(&value.as_int()).ok_or(LookupError::MissingValue {
+                value: value.as_int(),
+                row,
+            })?;
+            counts[*entry] += 1;
+        }
+        Ok(counts.into_iter().map(BaseElement::new).collect())
+    }
+}
+
+/// Columns one lookup reads and writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LookupArgument {
+    /// Main trace column whose values must be in the table
+    pub input_column: usize,
+    /// Main trace column holding [`LookupTable::multiplicities`]
+    pub multiplicity_column: usize,
+    /// Auxiliary column holding the accumulator
+    pub aux_column: usize,
+}
+
+impl LookupArgument {
+    /// Auxiliary assertions per lookup
+    pub const NUM_ASSERTIONS: usize = 2;
+
+    /// Degree of the lookup transition constraint for `table`
+    ///
+    /// Degree 2 in the trace (accumulator and looked-up value) times the table's period.
+    #[must_use]
+    pub fn constraint_degree(table: &LookupTable) -> TransitionConstraintDegree {
+        TransitionConstraintDegree::with_cycles(2, vec![table.period()])
+    }
+
+    /// Evaluate the lookup transition constraint
+    ///
+    /// `table_value` is the table's periodic column at this step and `alpha` the
+    /// auxiliary segment's random element.
+    pub fn evaluate<F, E>(&self, main: &EvaluationFrame<F>, aux: &EvaluationFrame<E>, table_value: F, alpha: E) -> E
+    where
+        F: FieldElement<BaseField = BaseElement>,
+        E: FieldElement<BaseField = BaseElement> + ExtensionOf<F>,
+    {
+        let looked_up = alpha - E::from(main.current()[self.input_column]);
+        let table = alpha - E::from(table_value);
+        let multiplicity = E::from(main.current()[self.multiplicity_column]);
+        let step = aux.next()[self.aux_column] - aux.current()[self.aux_column];
+        step * looked_up * table - table + multiplicity * looked_up
+    }
+
+    /// Accumulator starts and ends at zero
+    pub fn assertions<E: FieldElement<BaseField = BaseElement>>(&self, trace_length: usize) -> [Assertion<E>; 2] {
+        [
+            Assertion::single(self.aux_column, 0, E::ZERO),
+            Assertion::single(self.aux_column, trace_length - 1, E::ZERO),
+        ]
+    }
+
+    /// Accumulator column for a main trace and challenge
+    pub fn accumulator<E>(&self, main: &ColMatrix<BaseElement>, table: &LookupTable, alpha: E) -> Vec<E>
+    where
+        E: FieldElement<BaseField = BaseElement>,
+    {
+        let steps = main.num_rows() - 1;
+        let table_column = table.periodic_column();
+        let inputs = main.get_column(self.input_column);
+        let multiplicities = main.get_column(self.multiplicity_column);
+
+        // One inversion for all 2(n-1) denominators
+        let denominators: Vec<E> = (0..steps)
+            .flat_map(|row| {
+                [
+                    alpha - E::from(inputs[row]),
+                    alpha - E::from(table_column[row % table_column.len()]),
+                ]
+            })
+            .collect();
+        let inverses = batch_inversion(&denominators);
+
+        let mut accumulator = Vec::with_capacity(main.num_rows());
+        accumulator.push(E::ZERO);
+        for row in 0..steps {
+            let current = accumulator[row];
+            accumulator.push(current + inverses[2 * row] - E::from(multiplicities[row]) * inverses[2 * row + 1]);
+        }
+        accumulator
+    }
+}
+
+/// Execution trace with lookup accumulators in its auxiliary segment
+#[derive(Debug, Clone)]
+pub struct LookupTrace {
+    layout: TraceLayout,
+    main: ColMatrix<BaseElement>,
+    lookups: Vec<(LookupArgument, LookupTable)>,
+    meta: Vec<u8>,
+}
+
+impl LookupTrace {
+    /// Trace from main columns and the lookups over them
+    ///
+    /// The lookups' auxiliary columns must be `0..lookups.len()`; they share one random
+    /// element.
+    ///
+    /// # Errors
+    ///
+    /// [`LookupError::InvalidTrace`] if the columns are not a valid trace or a lookup's
+    /// columns are out of place, or [`LookupError::TableTooLarge`] if a table does not fit
+    /// the trace
+    pub fn new(columns: Vec<Vec<BaseElement>>, lookups: Vec<(LookupArgument, LookupTable)>) -> Result<Self, LookupError> {
+        let invalid = |reason: String| LookupError::InvalidTrace(reason);
+        let length = columns.first().map_or(0, Vec::len);
+        if columns.iter().any(|column| column.len() != length) {
+            return Err(invalid("columns differ in length".to_string()));
+        }
+        if !length.is_power_of_two() || length < MIN_TRACE_LENGTH {
+            return Err(invalid(format!("length {length} is not a power of two of at least {MIN_TRACE_LENGTH}")));
+        }
+
+        let mut aux_columns: Vec<usize> = lookups.iter().map(|(lookup, _)| lookup.aux_column).collect();
+        aux_columns.sort_unstable();
+        if aux_columns.iter().enumerate().any(|(i, &column)| i != column) {
+            return Err(invalid("auxiliary columns must be 0..lookups".to_string()));
+        }
+        for (lookup, table) in &lookups {
+            if lookup.input_column.max(lookup.multiplicity_column) >= columns.len() {
+                return Err(invalid(format!("lookup reads column beyond width {}", columns.len())));
+            }
+            if table.period() > length {
+                return Err(LookupError::TableTooLarge {
+                    period: table.period(),
+                    trace_length: length,
+                });
+            }
+        }
+
+        let num_rands = usize::from(!lookups.is_empty());
+        Ok(Self {
+            layout: TraceLayout::new(columns.len(), [lookups.len()], [num_rands]),
+            main: ColMatrix::new(columns),
+            lookups,
+            meta: Vec::new(),
+        })
+    }
+}
+
+impl Trace for LookupTrace {
+    type BaseField = BaseElement;
+
+    fn layout(&self) -> &TraceLayout {
+        &self.layout
+    }
+
+    fn length(&self) -> usize {
+        self.main.num_rows()
+    }
+
+    fn meta(&self) -> &[u8] {
+        &self.meta
+    }
+
+    fn main_segment(&self) -> &ColMatrix<BaseElement> {
+        &self.main
+    }
+
+    fn build_aux_segment<E>(&mut self, aux_segments: &[ColMatrix<E>], rand_elements: &[E]) -> Option<ColMatrix<E>>
+    where
+        E: FieldElement<BaseField = BaseElement>,
+    {
+        // A single auxiliary segment
+        if !aux_segments.is_empty() {
+            return None;
+        }
+        let alpha = rand_elements[0];
+        let mut columns = vec![Vec::new(); self.lookups.len()];
+        for (lookup, table) in &self.lookups {
+            columns[lookup.aux_column] = lookup.accumulator(&self.main, table, alpha);
+        }
+        Some(ColMatrix::new(columns))
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<BaseElement>) {
+        let next_row_idx = (row_idx + 1) % self.length();
+        self.main.read_row_into(row_idx, frame.current_mut());
+        self.main.read_row_into(next_row_idx, frame.next_mut());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statements::{prove_statement, verify_statement, StatementHasher, StatementRandomCoin};
+    use winter_math::ToElements;
+    use winterfell::{
+        Air, AirContext, AuxTraceRandElements, ConstraintCompositionCoefficients, DefaultConstraintEvaluator,
+        DefaultTraceLde, FieldExtension, ProofOptions, Prover, StarkDomain, TraceInfo, TracePolyTable,
+    };
+
+    const TRACE_LENGTH: usize = 32;
+
+    /// Columns: value (range checked to 4 bits), its multiplicities, a step counter
+    const LOOKUP: LookupArgument = LookupArgument {
+        input_column: 0,
+        multiplicity_column: 1,
+        aux_column: 0,
+    };
+
+    #[derive(Debug, Clone)]
+    struct NoInputs;
+
+    impl ToElements<BaseElement> for NoInputs {
+        fn to_elements(&self) -> Vec<BaseElement> {
+            Vec::new()
+        }
+    }
+
+    struct RangeAir {
+        context: AirContext<BaseElement>,
+        table: LookupTable,
+    }
+
+    impl Air for RangeAir {
+        type BaseField = BaseElement;
+        type PublicInputs = NoInputs;
+
+        fn new(trace_info: TraceInfo, _inputs: NoInputs, options: ProofOptions) -> Self {
+            let table = LookupTable::range(4);
+            let context = AirContext::new_multi_segment(
+                trace_info,
+                vec![TransitionConstraintDegree::new(1)],
+                vec![LookupArgument::constraint_degree(&table)],
+                1,
+                LookupArgument::NUM_ASSERTIONS,
+                options,
+            );
+            Self { context, table }
+        }
+
+        fn context(&self) -> &AirContext<BaseElement> {
+            &self.context
+        }
+
+        fn get_periodic_column_values(&self) -> Vec<Vec<BaseElement>> {
+            vec![self.table.periodic_column()]
+        }
+
+        fn evaluate_transition<E: FieldElement<BaseField = BaseElement>>(
+            &self,
+            frame: &EvaluationFrame<E>,
+            _periodic_values: &[E],
+            result: &mut [E],
+        ) {
+            result[0] = frame.next()[2] - frame.current()[2] - E::ONE;
+        }
+
+        fn evaluate_aux_transition<F, E>(
+            &self,
+            main_frame: &EvaluationFrame<F>,
+            aux_frame: &EvaluationFrame<E>,
+            periodic_values: &[F],
+            aux_rand_elements: &AuxTraceRandElements<E>,
+            result: &mut [E],
+        ) where
+            F: FieldElement<BaseField = BaseElement>,
+            E: FieldElement<BaseField = BaseElement> + ExtensionOf<F>,
+        {
+            let alpha = aux_rand_elements.get_segment_elements(0)[0];
+            result[0] = LOOKUP.evaluate(main_frame, aux_frame, periodic_values[0], alpha);
+        }
+
+        fn get_assertions(&self) -> Vec<Assertion<BaseElement>> {
+            vec![Assertion::single(2, 0, BaseElement::ZERO)]
+        }
+
+        fn get_aux_assertions<E: FieldElement<BaseField = BaseElement>>(
+            &self,
+            _aux_rand_elements: &AuxTraceRandElements<E>,
+        ) -> Vec<Assertion<E>> {
+            LOOKUP.assertions(self.trace_length()).to_vec()
+        }
+    }
+
+    struct RangeProver {
+        options: ProofOptions,
+    }
+
+    impl Prover for RangeProver {
+        type BaseField = BaseElement;
+        type Air = RangeAir;
+        type Trace = LookupTrace;
+        type HashFn = StatementHasher;
+        type RandomCoin = StatementRandomCoin;
+        type TraceLde<E>
+            = DefaultTraceLde<E, StatementHasher>
+        where
+            E: FieldElement<BaseField = BaseElement>;
+        type ConstraintEvaluator<'a, E>
+            = DefaultConstraintEvaluator<'a, RangeAir, E>
+        where
+            E: FieldElement<BaseField = BaseElement>;
+
+        fn get_pub_inputs(&self, _trace: &LookupTrace) -> NoInputs {
+            NoInputs
+        }
+
+        fn options(&self) -> &ProofOptions {
+            &self.options
+        }
+
+        fn new_trace_lde<E>(
+            &self,
+            trace_info: &TraceInfo,
+            main_trace: &ColMatrix<BaseElement>,
+            domain: &StarkDomain<BaseElement>,
+        ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+        where
+            E: FieldElement<BaseField = BaseElement>,
+        {
+            DefaultTraceLde::new(trace_info, main_trace, domain)
+        }
+
+        fn new_evaluator<'a, E>(
+            &self,
+            air: &'a RangeAir,
+            aux_rand_elements: AuxTraceRandElements<E>,
+            composition_coefficients: ConstraintCompositionCoefficients<E>,
+        ) -> Self::ConstraintEvaluator<'a, E>
+        where
+            E: FieldElement<BaseField = BaseElement>,
+        {
+            DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+        }
+    }
+
+    /// Pseudo-random nibbles; a column repeating with a shorter period would have a lower
+    /// degree than the AIR declares
+    fn values() -> Vec<BaseElement> {
+        (1..=TRACE_LENGTH as u64)
+            .map(|i| BaseElement::new(i.wrapping_mul(0x9e37_79b9_7f4a_7c15) >> 60))
+            .collect()
+    }
+
+    #[test]
+    fn test_multiplicities_and_accumulator() {
+        let table = LookupTable::range(4);
+        assert_eq!(table.period(), 32);
+        let mut values = values();
+        let multiplicities = table.multiplicities(&values).unwrap();
+        let counted: u64 = multiplicities.iter().map(|m| m.as_int()).sum();
+        assert_eq!(counted, TRACE_LENGTH as u64 - 1);
+
+        let main = ColMatrix::new(vec![values.clone(), multiplicities.clone()]);
+        let alpha = BaseElement::new(0x1234_5678_9abc);
+        assert_eq!(*LOOKUP.accumulator(&main, &table, alpha).last().unwrap(), BaseElement::ZERO);
+
+        // A value outside the table cannot be balanced by the multiplicities
+        values[5] = BaseElement::new(16);
+        assert_eq!(table.multiplicities(&values), Err(LookupError::MissingValue { value: 16, row: 5 }));
+        let forged = ColMatrix::new(vec![values, multiplicities]);
+        assert_ne!(*LOOKUP.accumulator(&forged, &table, alpha).last().unwrap(), BaseElement::ZERO);
+
+        // The last row is padding
+        let mut padded = self::values();
+        padded[TRACE_LENGTH - 1] = BaseElement::new(1 << 20);
+        assert!(table.multiplicities(&padded).is_ok());
+        assert!(LookupTable::range(6).multiplicities(&padded).is_err());
+    }
+
+    #[test]
+    fn test_lookup_proof_verifies() {
+        let values = values();
+        let multiplicities = LookupTable::range(4).multiplicities(&values).unwrap();
+        let counter = (0..TRACE_LENGTH as u64).map(BaseElement::new).collect();
+        let trace = LookupTrace::new(vec![values, multiplicities, counter], vec![(LOOKUP, LookupTable::range(4))]).unwrap();
+
+        let options = ProofOptions::new(42, 8, 4, FieldExtension::Quadratic, 8, 31);
+        let proof = prove_statement(&RangeProver { options: options.clone() }, trace).unwrap();
+        assert!(verify_statement::<RangeAir>(proof, NoInputs, &options).is_ok());
+    }
+}
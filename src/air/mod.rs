@@ -10,6 +10,8 @@
 //! - **Boundary Conditions**: Initial and final state constraints
 //! - **Constraint Evaluation**: Efficient constraint checking
 //! - **Degree Analysis**: Constraint degree computation for FRI
+//! - **Lookup Arguments**: Table membership checks via auxiliary trace columns
+//! - **Periodic Columns**: Repeating schedules, such as round constants, added to transitions
 //! - **Security Validation**: Cryptographic security properties
 
 use crate::types::{FieldElement, StarkComponent, TypeError};
@@ -20,12 +22,20 @@ pub mod transitions;
 pub mod boundaries;
 pub mod evaluation;
 pub mod security;
+pub mod hash_gadget;
+pub mod builder;
+pub mod lookup;
+pub mod periodic;
 
 pub use constraints::*;
 pub use transitions::*;
 pub use boundaries::*;
 pub use evaluation::*;
 pub use security::*;
+pub use hash_gadget::*;
+pub use builder::*;
+pub use lookup::*;
+pub use periodic::*;
 
 /// AIR (Algebraic Intermediate Representation) for STARK proofs
 /// 
@@ -45,6 +55,8 @@ pub struct Air<F: FieldElement> {
     pub field_extension_degree: u32,
     /// Constraint degree bound
     pub max_constraint_degree: usize,
+    /// Periodic values added to register transitions
+    pub periodic_columns: Vec<PeriodicColumn<F>>,
 }
 
 impl<F: FieldElement> Air<F> {
@@ -68,9 +80,28 @@ impl<F: FieldElement> Air<F> {
             security_parameter,
             field_extension_degree: 1, // Default to base field
             max_constraint_degree,
+            periodic_columns: Vec::new(),
         }
     }
 
+    /// Add a periodic column to its register's transition
+    #[must_use]
+    pub fn with_periodic_column(mut self, column: PeriodicColumn<F>) -> Self {
+        self.periodic_columns.push(column);
+        self
+    }
+
+    /// Next state from `current_state` at `step`, periodic values included
+    pub fn next_state(&self, current_state: &[F], step: usize) -> Vec<F> {
+        let mut next_state = self.transition.apply(current_state);
+        for column in &self.periodic_columns {
+            if let Some(value) = next_state.get_mut(column.register()) {
+                *value += column.value_at(step);
+            }
+        }
+        next_state
+    }
+
     /// Evaluate all constraints at a given point
     pub fn evaluate_constraints(
         &self,
@@ -121,6 +152,16 @@ impl<F: FieldElement> Air<F> {
         // Check boundary conditions
         self.boundary.validate().map_err(|e| AirError::InvalidBoundary(e.to_string()))?;
 
+        // Check periodic columns
+        for column in &self.periodic_columns {
+            if column.register() >= self.num_registers() {
+                return Err(AirError::InvalidPeriodicColumn(format!(
+                    "register {} is out of range",
+                    column.register()
+                )));
+            }
+        }
+
         // Check security parameter
         if self.security_parameter == 0 {
             return Err(AirError::InvalidSecurityParameter);
@@ -185,6 +226,10 @@ pub enum AirError {
     /// Degree analysis error
     #[error("Degree analysis error: {0}")]
     DegreeError(String),
+
+    /// Invalid periodic column
+    #[error("Invalid periodic column: {0}")]
+    InvalidPeriodicColumn(String),
 }
 
 #[cfg(test)]
@@ -0,0 +1,87 @@
+//! Periodic Columns for AIR
+//!
+//! A periodic column is a short schedule of values repeated over the whole trace, such
+//! as the round constants of a hash gadget. It is added to one register's transition,
+//! so the transition of register `r` at step `i` becomes
+//!
+//! `next[r] = Σ_j M_rj · current[j] + c[i mod period]`
+//!
+//! Prover and verifier both know the schedule, so it is neither stored in the trace nor
+//! committed to; the proof transcript binds it instead.
+
+use crate::air::AirError;
+use crate::types::FieldElement;
+
+/// Values repeated with a power-of-two period and added to a register's transition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeriodicColumn<F: FieldElement> {
+    /// Register whose transition the values are added to
+    register: usize,
+    /// One period of values
+    values: Vec<F>,
+}
+
+impl<F: FieldElement> PeriodicColumn<F> {
+    /// Create a periodic column; the period must be a power of two
+    ///
+    /// # Errors
+    ///
+    /// [`AirError::InvalidPeriodicColumn`] if it is not
+    pub fn new(register: usize, values: Vec<F>) -> Result<Self, AirError> {
+        if !values.len().is_power_of_two() {
+            return Err(AirError::InvalidPeriodicColumn(format!(
+                "period {} is not a power of two",
+                values.len()
+            )));
+        }
+        Ok(Self { register, values })
+    }
+
+    /// Register whose transition the values are added to
+    #[must_use]
+    pub fn register(&self) -> usize {
+        self.register
+    }
+
+    /// One period of values
+    #[must_use]
+    pub fn values(&self) -> &[F] {
+        &self.values
+    }
+
+    /// Number of steps after which the values repeat
+    #[must_use]
+    pub fn period(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Value added to the transition from `step`
+    #[must_use]
+    pub fn value_at(&self, step: usize) -> F {
+        self.values[step % self.values.len()]
+    }
+
+    /// Values at steps `0..length`
+    #[must_use]
+    pub fn trace_values(&self, length: usize) -> Vec<F> {
+        (0..length).map(|step| self.value_at(step)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::field::PrimeField64;
+
+    #[test]
+    fn test_periodic_column_repeats() {
+        let values: Vec<_> = [3, 5, 7, 11].into_iter().map(PrimeField64::new).collect();
+        let column = PeriodicColumn::new(1, values.clone()).unwrap();
+        assert_eq!(column.period(), 4);
+        assert_eq!(column.value_at(9), PrimeField64::new(5));
+        assert_eq!(column.trace_values(8), [values.clone(), values.clone()].concat());
+
+        assert!(PeriodicColumn::new(0, values[..3].to_vec()).is_err());
+        assert!(PeriodicColumn::<PrimeField64>::new(0, Vec::new()).is_err());
+    }
+}
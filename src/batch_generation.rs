@@ -0,0 +1,369 @@
+//! Batch Proof Generation
+//!
+//! [`generate_batch`] proves every `*.json` data package in a directory with a bounded
+//! number of worker threads, writes one proof file per package and a
+//! [`BatchManifest`] recording each package's outcome. A package that fails to load,
+//! validate or prove is recorded in the manifest; the rest of the batch still runs.
+//!
+//! [`prove_package`] is the single-package step the `generate` command shares.
+
+use crate::burn_mint_prover::XfgBurnMintProver;
+use crate::clock::clock_from_env;
+use crate::commitment::recipient_binding;
+use crate::constants::{COMMITMENT_VERSION, DEFAULT_TARGET_CHAIN_ID};
+use crate::proof_data_schema::{ProofMetadata, StarkProof, StarkProofDataPackage, StarkPublicInputs};
+use crate::provenance::ProducerInfo;
+use crate::statements::HashFunction;
+use crate::{Result, XfgStarkError};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+/// File name of the manifest written alongside the batch's proofs
+pub const BATCH_MANIFEST_FILE: &str = "batch-manifest.json";
+
+/// Suffix of the proof file written for each package
+const PROOF_FILE_SUFFIX: &str = ".proof.json";
+
+/// Batch generation parameters
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchConfig {
+    /// Worker threads proving concurrently
+    pub workers: usize,
+    /// Hash function the proofs commit with
+    pub hash_function: HashFunction,
+    /// Producer provenance embedded in every proof, overriding the packages' own
+    pub producer: Option<ProducerInfo>,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            workers: std::thread::available_parallelism().map_or(4, std::num::NonZero::get),
+            hash_function: HashFunction::default(),
+            producer: None,
+        }
+    }
+}
+
+/// Outcome of one package in a batch
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchEntry {
+    /// Package file name
+    pub package: String,
+    /// Proof file name, when the proof was written
+    pub proof: Option<String>,
+    /// Why the package was not proven
+    pub error: Option<String>,
+    /// Serialized proof size (bytes)
+    pub proof_size: Option<usize>,
+    /// Time spent loading, proving and writing the package (milliseconds)
+    pub elapsed_ms: f64,
+}
+
+impl BatchEntry {
+    /// Whether the package's proof was written
+    #[must_use]
+    pub fn is_proved(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Summary of a batch run, written to [`BATCH_MANIFEST_FILE`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchManifest {
+    /// When the batch finished (RFC 3339)
+    pub created_at: String,
+    /// Hash function the proofs commit with
+    pub hash_function: HashFunction,
+    /// Worker threads used
+    pub workers: usize,
+    /// Packages whose proofs were written
+    pub proved: usize,
+    /// Packages that failed
+    pub failed: usize,
+    /// Outcome of each package, in file name order
+    pub entries: Vec<BatchEntry>,
+}
+
+impl BatchManifest {
+    /// Whether every package in the batch was proven
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+/// Data package files in `dir`, in file name order
+///
+/// Proof files and the manifest a previous batch wrote into the same directory are
+/// skipped.
+///
+/// # Errors
+///
+/// If `dir` cannot be read
+pub fn package_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let is_json = path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+        if path.is_file() && is_json && !name.ends_with(PROOF_FILE_SUFFIX) && name != BATCH_MANIFEST_FILE {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Prove every data package in `input_dir`, writing proofs and the manifest to `output_dir`
+///
+/// Each package's proof is written as `<package name>.proof.json`. A package that
+/// fails is recorded in the manifest rather than failing the batch.
+///
+/// # Errors
+///
+/// If `input_dir` cannot be read, or `output_dir` or the manifest cannot be written
+pub fn generate_batch(input_dir: &Path, output_dir: &Path, config: &BatchConfig) -> Result<BatchManifest> {
+    let files = package_files(input_dir)?;
+    std::fs::create_dir_all(output_dir)?;
+
+    let workers = config.workers.clamp(1, files.len().max(1));
+    let next = AtomicUsize::new(0);
+    let mut entries: Vec<(usize, BatchEntry)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut entries = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(file) = files.get(index) else {
+                            return entries;
+                        };
+                        entries.push((index, generate_one(file, output_dir, config)));
+                    }
+                })
+            })
+            .collect();
+        handles.into_iter().flat_map(|handle| handle.join().unwrap_or_default()).collect()
+    });
+    entries.sort_by_key(|(index, _)| *index);
+
+    // A worker that panicked leaves its packages without entries
+    let entries: Vec<BatchEntry> = files
+        .iter()
+        .enumerate()
+        .map(|(index, file)| match entries.binary_search_by_key(&index, |(i, _)| *i) {
+            Ok(position) => entries[position].1.clone(),
+            Err(_) => BatchEntry {
+                package: file_name(file),
+                proof: None,
+                error: Some("worker panicked".to_string()),
+                proof_size: None,
+                elapsed_ms: 0.0,
+            },
+        })
+        .collect();
+
+    let proved = entries.iter().filter(|entry| entry.is_proved()).count();
+    let manifest = BatchManifest {
+        created_at: clock_from_env().rfc3339(),
+        hash_function: config.hash_function,
+        workers,
+        proved,
+        failed: entries.len() - proved,
+        entries,
+    };
+    std::fs::write(output_dir.join(BATCH_MANIFEST_FILE), serde_json::to_string_pretty(&manifest)?)?;
+    Ok(manifest)
+}
+
+/// Load, prove and write one package of a batch
+fn generate_one(file: &Path, output_dir: &Path, config: &BatchConfig) -> BatchEntry {
+    let started = Instant::now();
+    let package = file_name(file);
+    let stem = package.strip_suffix(".json").unwrap_or(&package);
+    let proof_name = format!("{stem}{PROOF_FILE_SUFFIX}");
+
+    let result = StarkProofDataPackage::load_from_file(&file.to_string_lossy())
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))
+        .and_then(|data| {
+            let prover = XfgBurnMintProver::new(128).with_hash_function(config.hash_function);
+            prove_package(&data, prover, config.producer.clone())
+        })
+        .and_then(|proof| {
+            std::fs::write(output_dir.join(&proof_name), serde_json::to_string_pretty(&proof)?)?;
+            Ok(proof.proof_data.len())
+        });
+
+    let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+    match result {
+        Ok(proof_size) => BatchEntry {
+            package,
+            proof: Some(proof_name),
+            error: None,
+            proof_size: Some(proof_size),
+            elapsed_ms,
+        },
+        Err(e) => BatchEntry {
+            package,
+            proof: None,
+            error: Some(e.to_string()),
+            proof_size: None,
+            elapsed_ms,
+        },
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(|| path.to_string_lossy().into_owned(), |name| name.to_string_lossy().into_owned())
+}
+
+/// Validate a data package and prove its burn with `prover`
+///
+/// The package's fee model is applied to the prover. A `producer` overrides the
+/// package's own provenance and, like it, must not contain the package's secrets.
+///
+/// # Errors
+///
+/// If the package's secret is locked, the package or `producer` fails validation, its
+/// fee model, transaction hash or recipient is invalid, or proving fails
+pub fn prove_package(
+    package: &StarkProofDataPackage,
+    prover: XfgBurnMintProver,
+    producer: Option<ProducerInfo>,
+) -> Result<StarkProof> {
+    if package.secret.is_locked() {
+        return Err(XfgStarkError::ParseError(
+            "Data package secret is locked in a keystore; unlock it before proving".to_string(),
+        ));
+    }
+    let validation = package.validate();
+    if !validation.is_valid {
+        return Err(XfgStarkError::ParseError(format!(
+            "Data package validation failed: {}",
+            validation.errors.join("; ")
+        )));
+    }
+
+    let producer = match producer {
+        Some(producer) => {
+            producer
+                .validate_against_secrets(&package.secrets())
+                .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+            Some(producer)
+        }
+        None => package.metadata.producer.clone(),
+    };
+
+    let fee_model = package.fee_model()?;
+    let prover = prover.with_fee_model(fee_model);
+    let hash_function = prover.hash_function();
+    let tx_prefix_hash = package
+        .tx_prefix_hash()
+        .map_err(|e| XfgStarkError::ParseError(format!("Invalid transaction hash: {e}")))?;
+    let recipient = package
+        .recipient_address()
+        .map_err(|e| XfgStarkError::ParseError(format!("Invalid recipient address: {e}")))?;
+    let recipient = recipient.as_bytes();
+
+    let proof = prover
+        .prove_burn_mint(
+            package.burn_transaction.burn_amount_atomic,
+            package.get_mint_amount_atomic(),
+            tx_prefix_hash,
+            recipient,
+            &package.secret_bytes(),
+            package.network_id_number(),
+            DEFAULT_TARGET_CHAIN_ID,
+            COMMITMENT_VERSION,
+        )
+        .map_err(|e| XfgStarkError::CryptoError(format!("Proof generation failed: {e}")))?;
+
+    Ok(StarkProof {
+        proof_data: proof.to_bytes(),
+        public_inputs: StarkPublicInputs {
+            burn_amount: package.burn_transaction.burn_amount_atomic,
+            mint_amount: package.get_mint_amount_atomic(),
+            txn_hash: package.burn_transaction.transaction_hash.clone(),
+            recipient_hash: hex::encode(recipient_binding(recipient, DEFAULT_TARGET_CHAIN_ID)),
+            state: 0,
+            fee_bps: fee_model.fee_bps(),
+        },
+        metadata: ProofMetadata {
+            version: "1.0.0".to_string(),
+            created_at: clock_from_env().rfc3339(),
+            description: format!("STARK proof for {} XFG burn", package.burn_transaction.burn_amount_xfg),
+            network: package.metadata.network.clone(),
+            build_info: Some(crate::build_info::BuildInfo::current()),
+            hash_function,
+            producer,
+            migrated_from: None,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(secret: &str) -> StarkProofDataPackage {
+        StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            secret.to_string(),
+            "fuego-testnet".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_batch_records_every_package() {
+        let dir = std::env::temp_dir().join(format!("xfg-batch-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        package("first-secret-key").save_to_file(dir.join("a.json").to_str().unwrap()).unwrap();
+        package("second-secret-key").save_to_file(dir.join("b.json").to_str().unwrap()).unwrap();
+        package("short").save_to_file(dir.join("c.json").to_str().unwrap()).unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a package").unwrap();
+
+        let config = BatchConfig {
+            workers: 8,
+            ..BatchConfig::default()
+        };
+        let manifest = generate_batch(&dir, &dir, &config).unwrap();
+        assert_eq!((manifest.workers, manifest.proved, manifest.failed), (3, 2, 1));
+        assert!(!manifest.is_success());
+        let names: Vec<_> = manifest.entries.iter().map(|entry| entry.package.as_str()).collect();
+        assert_eq!(names, ["a.json", "b.json", "c.json"]);
+        assert!(manifest.entries[2].error.as_deref().unwrap().contains("Secret key"));
+
+        let proof: StarkProof =
+            serde_json::from_str(&std::fs::read_to_string(dir.join("a.proof.json")).unwrap()).unwrap();
+        assert_eq!(Some(proof.proof_data.len()), manifest.entries[0].proof_size);
+        let written: BatchManifest =
+            serde_json::from_str(&std::fs::read_to_string(dir.join(BATCH_MANIFEST_FILE)).unwrap()).unwrap();
+        let outcomes = |manifest: &BatchManifest| -> Vec<(String, Option<String>, Option<String>)> {
+            let entries = manifest.entries.iter();
+            entries.map(|entry| (entry.package.clone(), entry.proof.clone(), entry.error.clone())).collect()
+        };
+        assert_eq!(outcomes(&written), outcomes(&manifest));
+
+        // Outputs written into the input directory are not picked up as packages
+        assert_eq!(package_files(&dir).unwrap().len(), 3);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_prove_package_rejects_secret_in_producer() {
+        let producer = ProducerInfo {
+            organization: Some("first-secret-key".to_string()),
+            ..ProducerInfo::default()
+        };
+        let prover = XfgBurnMintProver::new(128);
+        assert!(prove_package(&package("first-secret-key"), prover, Some(producer)).is_err());
+    }
+}
@@ -14,12 +14,16 @@
 use crate::air::constraints::ConstraintType;
 use crate::air::{Air, BoundaryConditions, Constraint, TransitionFunction};
 use crate::burn_mint_air::{BurnMintPublicInputs, XfgBurnMintAir};
-use crate::burn_mint_prover::XfgBurnMintProver;
+use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
 use crate::burn_mint_verifier::XfgBurnMintVerifier;
+use crate::commitment::RECIPIENT_BINDING_LIMBS;
+use crate::constants::TRACE_LENGTH;
 use crate::proof::fri::FriProver;
 use crate::proof::merkle::MerkleTree;
 use crate::proof::StarkProver;
-use crate::types::field::PrimeField64;
+use crate::soundness::{is_testnet_only, SecurityPreset};
+use crate::statements::StatementHasher;
+use crate::types::field::{batch, ExtensibleField, PrimeField64};
 use crate::types::FieldElement;
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
@@ -211,8 +215,76 @@ impl<F: FieldElement> BenchmarkSuite<F> {
         self.results.push(result);
     }
 
+    /// Compare FRI proving and Merkle tree construction on one thread and on the rayon pool
+    ///
+    /// Records a serial and a parallel result for each, with the pool's `threads` and the
+    /// parallel run's `speedup` over the serial one.
+    ///
+    /// # Panics
+    ///
+    /// If rayon cannot build a single-threaded pool
+    #[cfg(feature = "parallel")]
+    pub fn benchmark_parallel_speedup(&mut self, polynomial_size: usize, iterations: usize) {
+        let serial_pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .expect("A single-threaded pool should build");
+        let threads = rayon::current_num_threads();
+
+        let prover = FriProver::<F>::new(128);
+        let polynomial = generate_random_polynomial::<F>(polynomial_size);
+        let prove = || {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                let _proof = prover.prove(&polynomial);
+            }
+            start.elapsed()
+        };
+        let serial = serial_pool.install(prove);
+        let parallel = prove();
+        self.record_speedup("FRI Proof Generation", polynomial_size, iterations, threads, serial, parallel);
+
+        let leaves: Vec<Vec<u8>> = (0..polynomial_size)
+            .map(|i| format!("leaf_{i}").into_bytes())
+            .collect();
+        let build = || {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                let _tree = MerkleTree::new(&leaves);
+            }
+            start.elapsed()
+        };
+        let serial = serial_pool.install(build);
+        let parallel = build();
+        self.record_speedup("Merkle Tree Construction", polynomial_size, iterations, threads, serial, parallel);
+    }
+
+    /// Record a serial and a parallel run of the same operation
+    #[cfg(feature = "parallel")]
+    fn record_speedup(
+        &mut self,
+        operation: &str,
+        input_size: usize,
+        iterations: usize,
+        threads: usize,
+        serial: Duration,
+        parallel: Duration,
+    ) {
+        let speedup = serial.as_secs_f64() / parallel.as_secs_f64().max(f64::EPSILON);
+        for (mode, duration, mode_threads) in [("serial", serial, 1), ("parallel", parallel, threads)] {
+            let mut result = BenchmarkResult::new(format!("{operation} ({mode})"), duration, input_size);
+            result.iterations = iterations;
+            result.add_metric("threads".to_string(), f64::from(u32::try_from(mode_threads).unwrap_or(u32::MAX)));
+            result.add_metric("speedup".to_string(), if mode == "parallel" { speedup } else { 1.0 });
+            self.results.push(result);
+        }
+    }
+
     /// Run complete STARK proof benchmarks
-    pub fn benchmark_stark_proof(&mut self, trace_size: usize, iterations: usize) {
+    pub fn benchmark_stark_proof(&mut self, trace_size: usize, iterations: usize)
+    where
+        F: ExtensibleField<2> + ExtensibleField<3>,
+    {
         let prover = StarkProver::new(128);
         let air = create_test_air::<F>();
         let initial_state = vec![F::zero(); 2];
@@ -233,7 +305,10 @@ impl<F: FieldElement> BenchmarkSuite<F> {
     }
 
     /// Run scalability benchmarks
-    pub fn benchmark_scalability(&mut self, sizes: &[usize]) {
+    pub fn benchmark_scalability(&mut self, sizes: &[usize])
+    where
+        F: ExtensibleField<2> + ExtensibleField<3>,
+    {
         for &size in sizes {
             self.benchmark_field_arithmetic(1000);
             self.benchmark_polynomial_operations(size, 100);
@@ -359,37 +434,34 @@ impl<F: FieldElement> BenchmarkSuite<F> {
 
         let recipient = [0x12u8; 20]; // Example Ethereum address
         let tx_prefix_hash = [0u8; 32]; // Placeholder for benchmark
-        let sample_proof =
-            match prover.prove_burn_mint(burn_amount, mint_amount, tx_prefix_hash, &recipient, &secret, network_id, 42161, 1)
-            {
-                Ok(proof) => proof,
-                Err(_) => {
-                    // Add failed result
-                    let mut result = BenchmarkResult::new(
-                        format!("Winterfell Proof Verification ({} steps)", trace_length),
-                        Duration::ZERO,
-                        trace_length,
-                    );
-                    result.iterations = iterations;
-                    result.add_metric("success_rate".to_string(), 0.0);
-                    self.results.push(result);
-                    return;
-                }
-            };
+        let Ok((sample_proof, public_inputs)) = prover.prove_burn_mint_with_public_inputs(
+            &BurnMintRequest {
+                burn_amount,
+                mint_amount,
+                tx_prefix_hash,
+                recipient_address: &recipient,
+                network_id,
+                target_chain_id: 42161,
+                commitment_version: 1,
+            },
+            &secret,
+        ) else {
+            // Add failed result
+            let mut result = BenchmarkResult::new(
+                format!("Winterfell Proof Verification ({trace_length} steps)"),
+                Duration::ZERO,
+                trace_length,
+            );
+            result.iterations = iterations;
+            result.add_metric("success_rate".to_string(), 0.0);
+            self.results.push(result);
+            return;
+        };
 
         let verifier = XfgBurnMintVerifier::new(128);
 
         for _ in 0..iterations {
-            match verifier.verify_burn_mint(
-                &sample_proof,
-                burn_amount,
-                mint_amount,
-                network_id as u64, // Convert to u64 for txn_hash
-                &recipient,
-                network_id,
-                42161, // Arbitrum One
-                1,     // Commitment version
-            ) {
+            match verifier.verify_with_public_inputs(&sample_proof, &public_inputs) {
                 Ok(is_valid) => {
                     if is_valid {
                         successful_verifications += 1;
@@ -424,12 +496,13 @@ impl<F: FieldElement> BenchmarkSuite<F> {
 
         for _ in 0..iterations {
             // Create AIR and generate trace
-            let trace_info = TraceInfo::new(7, trace_length);
+            let trace_info = TraceInfo::new(crate::burn_mint_air::TRACE_WIDTH, trace_length);
             let public_inputs = BurnMintPublicInputs {
                 burn_amount: BaseElement::from(1000u32),
                 mint_amount: BaseElement::from(1000u32),
                 txn_hash: BaseElement::from(12345u32),
                 recipient_hash: BaseElement::from(67890u32),
+                recipient_binding: [BaseElement::from(0u32); RECIPIENT_BINDING_LIMBS],
                 state: BaseElement::from(0u32),
 
                 // Full tx prefix hash (32 bytes as 4 limbs)
@@ -442,8 +515,14 @@ impl<F: FieldElement> BenchmarkSuite<F> {
                 network_id: BaseElement::from(4u32),          // Fuego testnet
                 target_chain_id: BaseElement::from(42161u32), // Arbitrum One
                 commitment_version: BaseElement::from(1u32),  // Version 1
+                fee_bps: BaseElement::from(0u32),
+
+                // Derived from the secret by the AIR
+                nullifier: [BaseElement::from(0u32); 4],
+                commitment: [BaseElement::from(0u32); 4],
+                outputs: Vec::new(),
             };
-            let secret = BaseElement::from(67305985u32);
+            let secret = [BaseElement::from(67_305_985_u32); 4];
 
             let air = XfgBurnMintAir::new_with_secret(
                 trace_info,
@@ -466,6 +545,51 @@ impl<F: FieldElement> BenchmarkSuite<F> {
         self.results.push(result);
     }
 
+    /// Benchmark burn & mint proof generation under a security preset
+    ///
+    /// Records the conjectured security and LDE domain size next to the timing, so a
+    /// constrained device can be checked against the verifier's minimum.
+    pub fn benchmark_preset_proof_generation(&mut self, preset: SecurityPreset, iterations: usize) {
+        let name = format!("Winterfell Proof Generation ({preset:?} preset)");
+        let Ok(options) = preset.default_proof_options(TRACE_LENGTH) else {
+            let mut result = BenchmarkResult::new(name, Duration::ZERO, TRACE_LENGTH);
+            result.add_metric("success_rate".to_string(), 0.0);
+            self.results.push(result);
+            return;
+        };
+        let prover = XfgBurnMintProver::with_options(128, options.clone());
+
+        let start = Instant::now();
+        let mut successful_proofs = 0u32;
+        let mut security_bits = 0;
+        for _ in 0..iterations {
+            if let Ok(proof) =
+                prover.prove_burn_mint(8_000_000, 8_000_000, [7u8; 32], &[0x12u8; 20], &[9u8; 32], 1, 42161, 1)
+            {
+                security_bits = proof.security_level::<StatementHasher>(true);
+                successful_proofs += 1;
+            }
+        }
+
+        let mut result = BenchmarkResult::new(name, start.elapsed(), TRACE_LENGTH);
+        result.iterations = iterations;
+        result.add_metric(
+            "success_rate".to_string(),
+            f64::from(successful_proofs) / f64::from(u32::try_from(iterations).unwrap_or(u32::MAX)),
+        );
+        result.add_metric("security_bits".to_string(), f64::from(security_bits));
+        result.add_metric(
+            "lde_domain_size".to_string(),
+            f64::from(u32::try_from(TRACE_LENGTH * options.blowup_factor()).unwrap_or(u32::MAX)),
+        );
+        result.add_metric(
+            "testnet_only".to_string(),
+            f64::from(u8::from(is_testnet_only(&options, TRACE_LENGTH))),
+        );
+
+        self.results.push(result);
+    }
+
     /// Run comprehensive Winterfell benchmark suite
     pub fn run_winterfell_benchmark_suite(&mut self) {
         let trace_lengths = vec![64, 128, 256];
@@ -486,6 +610,45 @@ impl<F: FieldElement> BenchmarkSuite<F> {
             // Proof verification
             self.benchmark_winterfell_proof_verification(trace_length, iterations);
         }
+
+        // Low-memory profile for ARM64 boards
+        self.benchmark_preset_proof_generation(SecurityPreset::Constrained, iterations);
+    }
+}
+
+impl BenchmarkSuite<PrimeField64> {
+    /// Compare element-wise field multiplication with [`batch::mul_slice`]
+    ///
+    /// Records both runs over `size`-element vectors, with the batch run's `speedup`.
+    pub fn benchmark_field_batch(&mut self, size: usize, iterations: usize) {
+        let a: Vec<PrimeField64> = (0..size).map(|_| PrimeField64::random()).collect();
+        let b: Vec<PrimeField64> = (0..size).map(|_| PrimeField64::random()).collect();
+
+        let start = Instant::now();
+        for _ in 0..iterations {
+            let mut values = a.clone();
+            for (value, other) in values.iter_mut().zip(&b) {
+                *value *= *other;
+            }
+            std::hint::black_box(values);
+        }
+        let element_wise = start.elapsed();
+
+        let start = Instant::now();
+        for _ in 0..iterations {
+            let mut values = a.clone();
+            batch::mul_slice(&mut values, &b);
+            std::hint::black_box(values);
+        }
+        let batched = start.elapsed();
+
+        let speedup = element_wise.as_secs_f64() / batched.as_secs_f64().max(f64::EPSILON);
+        for (mode, duration, mode_speedup) in [("element-wise", element_wise, 1.0), ("batch", batched, speedup)] {
+            let mut result = BenchmarkResult::new(format!("Field Multiplication ({mode})"), duration, size);
+            result.iterations = iterations;
+            result.add_metric("speedup".to_string(), mode_speedup);
+            self.results.push(result);
+        }
     }
 }
 
@@ -655,6 +818,17 @@ mod tests {
         assert_eq!(suite.results().len(), 1);
     }
 
+    #[test]
+    fn test_field_batch_benchmark() {
+        let mut suite = BenchmarkSuite::<PrimeField64>::new();
+        suite.benchmark_field_batch(37, 2);
+
+        let results = suite.results();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].operation, "Field Multiplication (batch)");
+        assert!(results[1].metrics.contains_key("speedup"));
+    }
+
     #[test]
     fn test_polynomial_benchmark() {
         let mut suite = BenchmarkSuite::<PrimeField64>::new();
@@ -662,6 +836,20 @@ mod tests {
         assert_eq!(suite.results().len(), 1);
     }
 
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_speedup_benchmark() {
+        let mut suite = BenchmarkSuite::<PrimeField64>::new();
+        suite.benchmark_parallel_speedup(64, 1);
+
+        let results = suite.results();
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].operation, "FRI Proof Generation (serial)");
+        assert_eq!(results[0].metrics["threads"], 1.0);
+        assert_eq!(results[1].metrics["threads"], rayon::current_num_threads() as f64);
+        assert!(results[3].metrics["speedup"] > 0.0);
+    }
+
     #[test]
     fn test_profiler() {
         let mut profiler = PerformanceProfiler::new();
@@ -692,6 +880,17 @@ mod tests {
         assert!(suite.results().len() > 0);
     }
 
+    #[test]
+    fn test_constrained_preset_benchmark() {
+        let mut suite = BenchmarkSuite::<PrimeField64>::new();
+        suite.benchmark_preset_proof_generation(SecurityPreset::Constrained, 1);
+
+        let result = &suite.results()[0];
+        assert_eq!(result.metrics["success_rate"], 1.0);
+        assert_eq!(result.metrics["testnet_only"], 0.0);
+        assert!(result.metrics["security_bits"] >= 100.0);
+    }
+
     #[test]
     fn test_winterfell_benchmark_suite() {
         let mut suite = BenchmarkSuite::<PrimeField64>::new();
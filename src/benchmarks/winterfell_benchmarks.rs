@@ -5,8 +5,11 @@
 
 use crate::{
     burn_mint_air::{XfgBurnMintAir, BurnMintPublicInputs},
-    burn_mint_prover::XfgBurnMintProver,
-    burn_mint_verifier::{XfgBurnMintVerifier, VerificationResult},
+    burn_mint_prover::{BurnMintRequest, XfgBurnMintProver},
+    burn_mint_verifier::{BurnMintVerification, XfgBurnMintVerifier, VerificationResult},
+    commitment::RECIPIENT_BINDING_LIMBS,
+    constants::{COMMITMENT_VERSION, DEFAULT_TARGET_CHAIN_ID},
+    networks::FUEGO_TESTNET_NETWORK_ID,
 };
 use winterfell::{
     math::fields::f64::BaseElement, ProofOptions, StarkProof, TraceInfo,
@@ -147,14 +150,27 @@ impl WinterfellBenchmarkSuite {
             let prover = XfgBurnMintProver::new(self.security_parameter);
             
             // Generate test data
-            let secret = [1, 2, 3, 4, 5, 6, 7, 8];
+            let secret = [9u8; 32];
             let burn_amount = 1000;
             let mint_amount = 1000;
-            let network_id = 12345;
+            let tx_prefix_hash = [7u8; 32];
+            let recipient = [0x12u8; 20];
+            let network_id = FUEGO_TESTNET_NETWORK_ID;
             
             // Generate proof
-            match prover.prove_burn_mint(burn_amount, mint_amount, network_id, &secret) {
-                Ok(proof) => {
+            match prover.prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount,
+                    mint_amount,
+                    tx_prefix_hash,
+                    recipient_address: &recipient,
+                    network_id,
+                    target_chain_id: DEFAULT_TARGET_CHAIN_ID,
+                    commitment_version: COMMITMENT_VERSION,
+                },
+                &secret,
+            ) {
+                Ok((proof, _)) => {
                     total_proof_size += proof.to_bytes().len();
                     successful_proofs += 1;
                 }
@@ -195,13 +211,26 @@ impl WinterfellBenchmarkSuite {
 
         // Create a sample proof for verification
         let prover = XfgBurnMintProver::new(self.security_parameter);
-        let secret = [1, 2, 3, 4, 5, 6, 7, 8];
+        let secret = [9u8; 32];
         let burn_amount = 1000;
         let mint_amount = 1000;
-        let network_id = 12345;
+        let tx_prefix_hash = [7u8; 32];
+        let recipient = [0x12u8; 20];
+        let network_id = FUEGO_TESTNET_NETWORK_ID;
         
-        let sample_proof = match prover.prove_burn_mint(burn_amount, mint_amount, network_id, &secret) {
-            Ok(proof) => proof,
+        let (sample_proof, public_inputs) = match prover.prove_burn_mint_with_public_inputs(
+            &BurnMintRequest {
+                burn_amount,
+                mint_amount,
+                tx_prefix_hash,
+                recipient_address: &recipient,
+                network_id,
+                target_chain_id: DEFAULT_TARGET_CHAIN_ID,
+                commitment_version: COMMITMENT_VERSION,
+            },
+            &secret,
+        ) {
+            Ok(proved) => proved,
             Err(_) => {
                 // Return empty result if proof generation fails
                 return WinterfellBenchmarkResult::new(
@@ -218,7 +247,20 @@ impl WinterfellBenchmarkSuite {
             let start = Instant::now();
             
             // Verify proof
-            match verifier.verify_burn_mint(&sample_proof, burn_amount, mint_amount, network_id, 1) {
+            match verifier.verify_burn_mint(
+                &sample_proof,
+                &BurnMintVerification {
+                    burn_amount,
+                    tx_prefix_hash,
+                    recipient_address: &recipient,
+                    network_id,
+                    target_chain_id: DEFAULT_TARGET_CHAIN_ID,
+                    commitment_version: COMMITMENT_VERSION,
+                    nullifier: public_inputs.nullifier,
+                    commitment: public_inputs.commitment,
+                },
+                None,
+            ) {
                 Ok(is_valid) => {
                     if is_valid {
                         successful_verifications += 1;
@@ -259,19 +301,45 @@ impl WinterfellBenchmarkSuite {
             
             // Generate proof
             let prover = XfgBurnMintProver::new(self.security_parameter);
-            let secret = [1, 2, 3, 4, 5, 6, 7, 8];
+            let secret = [9u8; 32];
             let burn_amount = 1000;
             let mint_amount = 1000;
-            let network_id = 12345;
+            let tx_prefix_hash = [7u8; 32];
+            let recipient = [0x12u8; 20];
+            let network_id = FUEGO_TESTNET_NETWORK_ID;
             
-            let proof = match prover.prove_burn_mint(burn_amount, mint_amount, network_id, &secret) {
-                Ok(proof) => proof,
+            let (proof, public_inputs) = match prover.prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount,
+                    mint_amount,
+                    tx_prefix_hash,
+                    recipient_address: &recipient,
+                    network_id,
+                    target_chain_id: DEFAULT_TARGET_CHAIN_ID,
+                    commitment_version: COMMITMENT_VERSION,
+                },
+                &secret,
+            ) {
+                Ok(proved) => proved,
                 Err(_) => continue,
             };
             
             // Verify proof
             let verifier = XfgBurnMintVerifier::new(self.security_parameter);
-            match verifier.verify_burn_mint(&proof, burn_amount, mint_amount, network_id, 1) {
+            match verifier.verify_burn_mint(
+                &proof,
+                &BurnMintVerification {
+                    burn_amount,
+                    tx_prefix_hash,
+                    recipient_address: &recipient,
+                    network_id,
+                    target_chain_id: DEFAULT_TARGET_CHAIN_ID,
+                    commitment_version: COMMITMENT_VERSION,
+                    nullifier: public_inputs.nullifier,
+                    commitment: public_inputs.commitment,
+                },
+                None,
+            ) {
                 Ok(is_valid) => {
                     if is_valid {
                         successful_operations += 1;
@@ -313,6 +381,7 @@ impl WinterfellBenchmarkSuite {
                 mint_amount: BaseElement::from(1000u32),
                 txn_hash: BaseElement::from(12345u32),
                 recipient_hash: BaseElement::from(67890u32),
+                recipient_binding: [BaseElement::from(0u32); RECIPIENT_BINDING_LIMBS],
                 state: BaseElement::from(0u32),
 
                 // Full tx prefix hash (32 bytes as 4 limbs)
@@ -325,6 +394,8 @@ impl WinterfellBenchmarkSuite {
                 network_id: BaseElement::from(4u32),          // Fuego testnet
                 target_chain_id: BaseElement::from(42161u32), // Arbitrum One
                 commitment_version: BaseElement::from(1u32),  // Version 1
+                fee_bps: BaseElement::from(0u32),
+                outputs: Vec::new(),
             };
             let secret = BaseElement::from(67305985u32);
             
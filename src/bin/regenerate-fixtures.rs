@@ -0,0 +1,18 @@
+//! Rewrite the golden fixtures the tests compare against
+//!
+//! ```text
+//! cargo run --features testing --bin regenerate-fixtures [DIR]
+//! ```
+//!
+//! Writes into `tests/fixtures/` of the source tree unless another directory is given.
+//! See `xfg_stark::fixtures` for what is regenerated.
+
+use std::path::PathBuf;
+use xfg_stark::fixtures::{fixtures_dir, regenerate};
+
+fn main() -> xfg_stark::Result<()> {
+    let root = std::env::args().nth(1).map_or_else(fixtures_dir, PathBuf::from);
+    regenerate(&root)?;
+    println!("Fixtures written to {}", root.display());
+    Ok(())
+}
@@ -1,204 +1,59 @@
-use clap::{App, Arg};
+use clap::{Arg, Command};
 use xfg_stark::{
-    proof_data_schema::{StarkProofDataPackage, CompleteProofPackage, StarkProof, EldernodeVerification, ProofDataTemplate},
+    proof_data_schema::{StarkProofDataPackage, CompleteProofPackage, StarkProof},
     burn_mint_prover::XfgBurnMintProver,
-    burn_mint_verifier::{XfgBurnMintVerifier, VerificationResult},
+    verification_context::VerificationContext,
+    commitment::{recipient_binding, PROOF_COMMITMENT_TAG},
+    eth_address::EthAddress,
     XfgStarkError,
     Result,
 };
-use std::time::{Instant, Duration};
-use std::sync::{Arc, Mutex};
-use std::thread;
-use std::sync::mpsc;
-use tokio;
-
-// Progress tracking structures
-#[derive(Debug, Clone)]
-enum VerificationStatus {
-    NotStarted,
-    SendingToEldernodes,
-    AwaitingConsensus,
-    EldernodeResponse(u32, u32), // (responded, total)
-    ConsensusReached,
-    Failed(String),
-}
-
-#[derive(Debug, Clone)]
-struct ProgressTracker {
-    stark_status: String,
-    eldernode_status: VerificationStatus,
-    start_time: Instant,
-    last_update: Instant,
-}
-
-impl ProgressTracker {
-    fn new() -> Self {
-        Self {
-            stark_status: "Initializing...".to_string(),
-            eldernode_status: VerificationStatus::NotStarted,
-            start_time: Instant::now(),
-            last_update: Instant::now(),
-        }
-    }
-
-    fn update_stark_status(&mut self, status: String) {
-        self.stark_status = status;
-        self.last_update = Instant::now();
-        self.display_progress();
-    }
-
-    fn update_eldernode_status(&mut self, status: VerificationStatus) {
-        self.eldernode_status = status;
-        self.last_update = Instant::now();
-        self.display_progress();
-    }
-
-    fn display_progress(&self) {
-        let elapsed = self.start_time.elapsed();
-        let since_update = self.last_update.elapsed();
-        
-        // Clear previous lines (approximate)
-        print!("\r\x1B[K"); // Clear line
-        
-        match &self.eldernode_status {
-            VerificationStatus::NotStarted => {
-                println!("🔐 STARK Generation: {}", self.stark_status);
-                println!("⏳ Eldernode Verification: Not started");
-            }
-            VerificationStatus::SendingToEldernodes => {
-                println!("🔐 STARK Generation: {}", self.stark_status);
-                println!("📤 Eldernode Verification: Sending commitment & burn amount verification...");
-            }
-            VerificationStatus::AwaitingConsensus => {
-                println!("🔐 STARK Generation: {}", self.stark_status);
-                println!("⏳ Eldernode Verification: Awaiting consensus...");
-            }
-            VerificationStatus::EldernodeResponse(responded, total) => {
-                println!("🔐 STARK Generation: {}", self.stark_status);
-                println!("📊 Eldernode Verification: {}/{} Eldernodes responded", responded, total);
-            }
-            VerificationStatus::ConsensusReached => {
-                println!("🔐 STARK Generation: {}", self.stark_status);
-                println!("✅ Eldernode Verification: Consensus reached!");
-            }
-            VerificationStatus::Failed(error) => {
-                println!("🔐 STARK Generation: {}", self.stark_status);
-                println!("❌ Eldernode Verification: Failed - {}", error);
-            }
-        }
-        
-        println!("⏱️  Total Time: {:?}", elapsed);
-        if since_update < Duration::from_secs(5) {
-            println!("🔄 Last Update: {:?} ago", since_update);
-        }
-    }
-}
+use xfg_stark::eldernode::{
+    reach_consensus, ConsensusThreshold, EldernodeConsensus, EldernodeRecord, EldernodeSet, EldernodeVerifier,
+    HttpEldernodeClient,
+};
+use xfg_stark::fuego_rpc::FuegoRpcClient;
+use xfg_stark::progress::{ProgressReporter, StarkPhase};
+use xfg_stark::verification_context::EldernodeAttestationInputs;
+use std::time::Instant;
 
 // STARK generation inputs structure (full inputs for STARK generation)
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 struct StarkGenerationInputs {
     secret: Vec<u8>,
     burn_amount: u64,
     mint_amount: u64,
     tx_prefix_hash: [u8; 32],
-    recipient_hash: Vec<u8>,
+    recipient: EthAddress,
     network_id: u32,
     target_chain_id: u32,
     commitment_version: u32,
     domain_separator: String,
 }
 
-// Eldernode verification inputs (commitment + burn amount)
-#[derive(Debug, Clone)]
-struct EldernodeVerificationInputs {
-    tx_hash: String,
-    commitment: String,  // The commitment as a whole (32-byte hex string)
-    burn_amount: u64,    // Burn amount (amount with undefined output key)
-}
-
-// Eldernode consensus structure
-#[derive(Debug, Clone)]
-struct EldernodeConsensus {
-    eldernode_ids: Vec<String>,
-    signatures: Vec<String>,
-    message_hash: String,
-    timestamp: String,
-    consensus_threshold: u32,
-    total_eldernodes: u32,
-    verified_inputs: EldernodeVerificationInputs,
-    tx_extra_commitment: String,  // Commitment extracted from tx_extra
-    tx_burn_amount: u64,          // Burn amount from transaction (undefined output key)
-    commitment_match: bool,        // Whether commitments match
-    burn_amount_match: bool,      // Whether burn amounts match
-}
-
-// Eldernode verification client (mock implementation)
-struct EldernodeClient {
-    progress_tx: std::sync::mpsc::Sender<VerificationStatus>,
-}
-
-impl EldernodeClient {
-    fn new(progress_tx: std::sync::mpsc::Sender<VerificationStatus>) -> Self {
-        Self { progress_tx }
-    }
-
-    async fn verify_commitment_and_burn_amount_with_eldernodes(&self, verification_inputs: &EldernodeVerificationInputs) -> Result<EldernodeConsensus> {
-        // Send initial status
-        self.progress_tx.send(VerificationStatus::SendingToEldernodes)?;
-        
-        // Simulate network delay
-        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-        
-        // Send awaiting consensus status
-        self.progress_tx.send(VerificationStatus::AwaitingConsensus)?;
-        
-        // Simulate Eldernode responses
-        let total_eldernodes = 5;
-        for i in 1..=total_eldernodes {
-            tokio::time::sleep(tokio::time::Duration::from_millis(800)).await;
-            self.progress_tx.send(VerificationStatus::EldernodeResponse(i, total_eldernodes))?;
-        }
-        
-        // Simulate consensus reached
-        tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
-        self.progress_tx.send(VerificationStatus::ConsensusReached)?;
-        
-        // Extract commitment from tx_extra (this would be the actual extraction)
-        let tx_extra_commitment = extract_commitment_from_tx_extra(&verification_inputs.tx_hash)?;
-        
-        // Extract burn amount from transaction (undefined output key amount)
-        let tx_burn_amount = extract_burn_amount_from_transaction(&verification_inputs.tx_hash)?;
-        
-        // Check if commitments match
-        let commitment_match = verification_inputs.commitment == tx_extra_commitment;
-        
-        // Check if burn amounts match
-        let burn_amount_match = verification_inputs.burn_amount == tx_burn_amount;
-        
-        // Return mock consensus with verified inputs
-        Ok(EldernodeConsensus {
-            eldernode_ids: vec!["elder1".to_string(), "elder2".to_string(), "elder3".to_string()],
-            signatures: vec!["sig1".to_string(), "sig2".to_string(), "sig3".to_string()],
-            message_hash: "consensus_hash".to_string(),
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            consensus_threshold: 3,
-            total_eldernodes: 5,
-            verified_inputs: verification_inputs.clone(),
-            tx_extra_commitment,
-            tx_burn_amount,
-            commitment_match,
-            burn_amount_match,
-        })
+impl std::fmt::Debug for StarkGenerationInputs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StarkGenerationInputs")
+            .field("secret", &xfg_stark::types::secret::Redacted::bytes(self.secret.len()))
+            .field("burn_amount", &self.burn_amount)
+            .field("mint_amount", &self.mint_amount)
+            .field("tx_prefix_hash", &hex::encode(self.tx_prefix_hash))
+            .field("recipient", &self.recipient)
+            .field("network_id", &self.network_id)
+            .field("target_chain_id", &self.target_chain_id)
+            .field("commitment_version", &self.commitment_version)
+            .field("domain_separator", &self.domain_separator)
+            .finish()
     }
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let matches = App::new("xfg-eldernode-verification")
+    let matches = Command::new("xfg-eldernode-verification")
         .version("1.0")
         .about("CLI tool for STARK proof generation with Eldernode verification (commitment + burn amount)")
         .subcommand(
-            App::new("prove-and-verify")
+            Command::new("prove-and-verify")
                 .about("Generate STARK proof and verify with Eldernodes")
                 .arg(
                     Arg::new("input")
@@ -207,7 +62,6 @@ async fn main() -> Result<()> {
                         .value_name("FILE")
                         .help("Input data package file")
                         .required(true)
-                        .takes_value(true)
                 )
                 .arg(
                     Arg::new("output")
@@ -216,7 +70,6 @@ async fn main() -> Result<()> {
                         .value_name("FILE")
                         .help("Output complete proof package file")
                         .required(true)
-                        .takes_value(true)
                 )
                 .arg(
                     Arg::new("eldernode-endpoint")
@@ -224,8 +77,27 @@ async fn main() -> Result<()> {
                         .long("eldernode-endpoint")
                         .value_name("URL")
                         .help("Eldernode verification endpoint")
-                        .takes_value(true)
-                        .default_value("https://eldernodes.fuego.network/api/v1/verify")
+                        .default_value(xfg_stark::eldernode::DEFAULT_ELDERNODE_ENDPOINT)
+                )
+                .arg(
+                    Arg::new("eldernodes")
+                        .long("eldernodes")
+                        .value_name("FILE")
+                        .help("JSON list of trusted Eldernode IDs and public keys")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("fuego-rpc")
+                        .long("fuego-rpc")
+                        .value_name("URL")
+                        .help("Fuego daemon to check the burn against before asking the Eldernodes")
+                )
+                .arg(
+                    Arg::new("threshold")
+                        .long("threshold")
+                        .value_name("REQUIRED/TOTAL")
+                        .help("Eldernode consensus threshold")
+                        .default_value("3/5")
                 )
         )
         .get_matches();
@@ -235,7 +107,12 @@ async fn main() -> Result<()> {
             let input_file = args.get_one::<String>("input").unwrap();
             let output_file = args.get_one::<String>("output").unwrap();
             let eldernode_endpoint = args.get_one::<String>("eldernode-endpoint").unwrap();
-            prove_and_verify_with_eldernodes(input_file, output_file, eldernode_endpoint).await?;
+            let verifier = load_eldernode_verifier(
+                args.get_one::<String>("eldernodes").unwrap(),
+                args.get_one::<String>("threshold").unwrap(),
+            )?;
+            let fuego_rpc = args.get_one::<String>("fuego-rpc").map(FuegoRpcClient::new);
+            prove_and_verify_with_eldernodes(input_file, output_file, eldernode_endpoint, fuego_rpc, verifier).await?;
         }
         _ => {
             eprintln!("Unknown subcommand. Use --help for usage information.");
@@ -247,7 +124,13 @@ async fn main() -> Result<()> {
 }
 
 /// Generate STARK proof and verify with Eldernodes
-async fn prove_and_verify_with_eldernodes(input_file: &str, output_file: &str, eldernode_endpoint: &str) -> Result<()> {
+async fn prove_and_verify_with_eldernodes(
+    input_file: &str,
+    output_file: &str,
+    eldernode_endpoint: &str,
+    fuego_rpc: Option<FuegoRpcClient>,
+    verifier: EldernodeVerifier,
+) -> Result<()> {
     println!("🚀 XFG Burn & HEAT Mint with STARK + Eldernode Verification");
     println!("============================================================");
     
@@ -277,75 +160,82 @@ async fn prove_and_verify_with_eldernodes(input_file: &str, output_file: &str, e
     
     // Prepare Eldernode verification inputs (commitment + burn amount)
     println!("🔧 Preparing Eldernode verification inputs...");
-    let eldernode_inputs = prepare_eldernode_inputs(&package)?;
+    let context = VerificationContext::from_package(&package)?;
+    // Eldernodes attest to the values the STARK verifier reads from the same context
+    let eldernode_inputs = context.eldernode_inputs();
     
     println!("📋 Eldernode verification inputs:");
-    println!("   Transaction Hash: {}", eldernode_inputs.tx_hash);
-    println!("   Commitment: {}", eldernode_inputs.commitment);
+    println!("   Transaction Hash: {}", eldernode_inputs.transaction_hash);
+    println!("   Commitment: {}", hex::encode(eldernode_inputs.commitment));
     println!("   Burn Amount: {} atomic units", eldernode_inputs.burn_amount);
     println!("   Note: Eldernodes verify commitment matches tx_extra AND burn amount matches undefined output key");
+
+    if let Some(rpc) = &fuego_rpc {
+        println!("\n🌐 Checking the burn against: {}", rpc.url);
+        check_burn_on_chain(rpc, &eldernode_inputs)?;
+        println!("✅ Commitment and burn amount match the burn transaction");
+    }
     
-    // Set up progress tracking
-    let (progress_tx, progress_rx) = std::sync::mpsc::channel();
-    let progress_tracker = Arc::new(Mutex::new(ProgressTracker::new()));
-    
-    // Start progress display thread
-    let progress_display = progress_tracker.clone();
-    let progress_thread = thread::spawn(move || {
-        while let Ok(status) = progress_rx.recv() {
-            let mut tracker = progress_display.lock().unwrap();
-            tracker.update_eldernode_status(status);
+    // Set up progress reporting
+    let progress = ProgressReporter::new();
+    let mut watcher = progress.subscribe();
+
+    // Redraw on every phase change until all reporters are dropped
+    let progress_display = tokio::spawn(async move {
+        while let Some(snapshot) = watcher.changed().await {
+            print!("\r\x1B[K"); // Clear line
+            println!("{}", snapshot);
         }
     });
 
     // Create Eldernode client
-    let eldernode_client = EldernodeClient::new(progress_tx.clone());
+    let eldernode_client = HttpEldernodeClient::new(eldernode_endpoint);
+    let consensus_progress = progress.clone();
     
     // Start both processes in parallel
     println!("\n⚡ Starting parallel STARK generation and Eldernode verification...");
     
     let stark_start = Instant::now();
-    let mut tracker = progress_tracker.lock().unwrap();
-    tracker.update_stark_status("Initializing prover...".to_string());
-    drop(tracker);
 
-    // Start STARK generation in a separate thread
+    // Start STARK generation on a blocking thread
     let stark_inputs_clone = stark_inputs.clone();
-    let stark_handle = thread::spawn(move || {
-        generate_stark_proof(&stark_inputs_clone, progress_tracker.clone())
+    let stark_progress = progress.clone();
+    let stark_handle = tokio::task::spawn_blocking(move || {
+        generate_stark_proof(&stark_inputs_clone, &stark_progress).map_err(|e| e.to_string())
     });
 
     // Start Eldernode verification (commitment + burn amount)
     let eldernode_handle = tokio::spawn(async move {
-        eldernode_client.verify_commitment_and_burn_amount_with_eldernodes(&eldernode_inputs).await
+        let consensus =
+            reach_consensus(&eldernode_client, &verifier, &eldernode_inputs, Some(&consensus_progress)).await;
+        (consensus, verifier, eldernode_inputs)
     });
 
     // Wait for both to complete
-    let stark_result = stark_handle.join().unwrap()?;
-    let eldernode_result = eldernode_handle.await?;
+    let stark_result = stark_handle.await?.map_err(XfgStarkError::CryptoError)?;
+    let (consensus, verifier, eldernode_inputs) = eldernode_handle.await?;
+    let consensus = consensus.map_err(|e| XfgStarkError::CryptoError(e.to_string()))?;
 
-    // Verify consistency
-    println!("\n🔒 Verifying Eldernode verification consistency...");
-    verify_eldernode_consistency(&eldernode_inputs, &stark_result, &eldernode_result)?;
+    println!("\n🔒 Eldernode consensus:");
+    print_consensus(&eldernode_inputs, &consensus);
     println!("✅ Eldernode verification successful - commitment and burn amount match on-chain data");
+    let eldernode_result = consensus.to_verification(
+        verifier.eldernodes(),
+        &stark_result.metadata.network,
+        chrono::Utc::now().to_rfc3339(),
+    );
 
     // Create complete proof package
-    let complete_package = CompleteProofPackage {
-        stark_proof: stark_result,
-        eldernode_verification: eldernode_result,
-        stark_inputs: stark_inputs,
-        metadata: package.metadata,
-        burn_transaction: package.burn_transaction,
-        recipient: package.recipient,
-        secret: package.secret,
-    };
+    let mut complete_package = CompleteProofPackage::new(package);
+    complete_package.add_stark_proof(stark_result);
+    complete_package.add_eldernode_verification(eldernode_result, &verifier)?;
 
     // Save complete package
     let json = serde_json::to_string_pretty(&complete_package)
-        .map_err(|e| XfgStarkError::JsonError(e))?;
+        .map_err(XfgStarkError::JsonError)?;
 
     std::fs::write(output_file, json)
-        .map_err(|e| XfgStarkError::IoError(e))?;
+        .map_err(XfgStarkError::IoError)?;
 
     let total_time = stark_start.elapsed();
     println!("\n🎉 Complete verification successful!");
@@ -353,9 +243,9 @@ async fn prove_and_verify_with_eldernodes(input_file: &str, output_file: &str, e
     println!("⏱️  Total time: {:?}", total_time);
     println!("🚀 Ready for submission to HEAT mint contract!");
 
-    // Clean up progress thread
-    drop(progress_tx);
-    progress_thread.join().unwrap();
+    // Stop the progress display
+    drop(progress);
+    progress_display.await?;
 
     Ok(())
 }
@@ -373,8 +263,8 @@ fn prepare_stark_inputs(package: &StarkProofDataPackage) -> Result<StarkGenerati
         return Err(XfgStarkError::ParseError("Transaction hash must be at least 32 bytes".to_string()));
     }
 
-    // Convert Ethereum address to bytes
-    let recipient_bytes = hex_to_bytes(&package.recipient.ethereum_address)
+    // Parse the 20-byte Ethereum address the proof mints to
+    let recipient = package.recipient_address()
         .map_err(|e| XfgStarkError::ParseError(format!("Invalid recipient address: {}", e)))?;
 
     // Convert secret to bytes
@@ -390,118 +280,42 @@ fn prepare_stark_inputs(package: &StarkProofDataPackage) -> Result<StarkGenerati
         burn_amount: package.burn_transaction.burn_amount_atomic,
         mint_amount: package.get_mint_amount_atomic(),
         tx_prefix_hash: tx_prefix_hash_array,
-        recipient_hash: recipient_bytes,
+        recipient,
         network_id,
         target_chain_id,
         commitment_version,
-        domain_separator: "heat-commitment-v1".to_string(),
+        domain_separator: String::from_utf8_lossy(PROOF_COMMITMENT_TAG).into_owned(),
     })
 }
 
-/// Prepare inputs for Eldernode verification (commitment + burn amount)
-fn prepare_eldernode_inputs(package: &StarkProofDataPackage) -> Result<EldernodeVerificationInputs> {
-    // Compute the commitment that should match what's in tx_extra
-    let commitment = compute_commitment_from_inputs(&package)?;
-    
-    Ok(EldernodeVerificationInputs {
-        tx_hash: package.burn_transaction.transaction_hash.clone(),
-        commitment: commitment,
-        burn_amount: package.burn_transaction.burn_amount_atomic,
-    })
-}
-
-/// Compute commitment from inputs (same logic used in STARK generation)
-fn compute_commitment_from_inputs(package: &StarkProofDataPackage) -> Result<String> {
-    // This computes the commitment using the same inputs as STARK generation
-    // The result should match what's stored in tx_extra of the Fuego transaction
-    let secret_bytes = package.secret.secret_key.as_bytes();
-    let burn_amount = package.burn_transaction.burn_amount_atomic;
-    let recipient_hash = hex_to_bytes(&package.recipient.ethereum_address)
-        .map_err(|e| XfgStarkError::ParseError(format!("Invalid recipient address: {}", e)))?;
-    
-    // Compute commitment using the same logic as STARK generation
-    let mut commitment_data = Vec::new();
-    commitment_data.extend_from_slice(secret_bytes);
-    commitment_data.extend_from_slice(&burn_amount.to_le_bytes());
-    commitment_data.extend_from_slice(&recipient_hash);
-    
-    // Hash the commitment data
-    let commitment_hash = sha2::Sha256::digest(&commitment_data);
-    Ok(hex::encode(commitment_hash))
-}
-
-/// Extract commitment from tx_extra of the Fuego transaction
-fn extract_commitment_from_tx_extra(tx_hash: &str) -> Result<String> {
-    // This would be the actual implementation that:
-    // 1. Looks up the transaction on the Fuego blockchain
-    // 2. Extracts the tx_extra field
-    // 3. Parses the 0x08 HEAT commitment tag
-    // 4. Returns the 32-byte commitment as hex string
-    
-    // For now, simulate the extraction
-    // In real implementation, this would query the Fuego RPC
-    println!("🔍 Extracting commitment from tx_extra for transaction: {}", tx_hash);
-    
-    // Mock extraction - in reality this would be:
-    // let tx = get_transaction_from_fuego_rpc(tx_hash)?;
-    // let tx_extra = parse_tx_extra(tx.extra)?;
-    // let commitment = extract_heat_commitment(tx_extra)?;
-    
-    // For demo purposes, return a mock commitment
-    Ok("0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string())
-}
-
-/// Extract burn amount from transaction (undefined output key amount)
-fn extract_burn_amount_from_transaction(tx_hash: &str) -> Result<u64> {
-    // This would be the actual implementation that:
-    // 1. Looks up the transaction on the Fuego blockchain
-    // 2. Finds the output with undefined key (burn output)
-    // 3. Returns the amount of that output
-    
-    // For now, simulate the extraction
-    // In real implementation, this would query the Fuego RPC
-    println!("🔍 Extracting burn amount from transaction: {}", tx_hash);
-    
-    // Mock extraction - in reality this would be:
-    // let tx = get_transaction_from_fuego_rpc(tx_hash)?;
-    // let burn_output = find_undefined_output_key(tx.outputs)?;
-    // let burn_amount = burn_output.amount;
-    
-    // For demo purposes, return a mock burn amount
-    Ok(8_000_000_000) // 800 XFG in atomic units
-}
-
 /// Generate STARK proof with progress tracking
 fn generate_stark_proof(
     inputs: &StarkGenerationInputs,
-    progress_tracker: Arc<Mutex<ProgressTracker>>
+    progress: &ProgressReporter
 ) -> Result<StarkProof> {
-    let mut tracker = progress_tracker.lock().unwrap();
-    tracker.update_stark_status("Creating prover...".to_string());
-    drop(tracker);
+    progress.stark(StarkPhase::Preparing);
 
     // Create prover
-    let prover = XfgBurnMintProver::new(128);
+    let prover = XfgBurnMintProver::new(128).with_progress(progress.clone());
 
-    let mut tracker = progress_tracker.lock().unwrap();
-    tracker.update_stark_status("Generating proof...".to_string());
-    drop(tracker);
+    progress.stark(StarkPhase::Proving);
 
     // Generate STARK proof
     let winterfell_proof = prover.prove_burn_mint(
         inputs.burn_amount,
         inputs.mint_amount,
         inputs.tx_prefix_hash,
-        &inputs.recipient_hash,
+        inputs.recipient.as_bytes(),
         &inputs.secret,
         inputs.network_id,
         inputs.target_chain_id,
         inputs.commitment_version,
-    ).map_err(|e| XfgStarkError::CryptoError(format!("Proof generation failed: {}", e)))?;
+    ).map_err(|e| {
+        progress.stark(StarkPhase::Failed(e.to_string()));
+        XfgStarkError::CryptoError(format!("Proof generation failed: {}", e))
+    })?;
 
-    let mut tracker = progress_tracker.lock().unwrap();
-    tracker.update_stark_status("Proof generated successfully!".to_string());
-    drop(tracker);
+    progress.stark(StarkPhase::Done);
 
     // Convert to our format
     let proof_data = winterfell_proof.to_bytes();
@@ -512,71 +326,73 @@ fn generate_stark_proof(
             burn_amount: inputs.burn_amount,
             mint_amount: inputs.mint_amount,
             txn_hash: hex::encode(inputs.tx_prefix_hash),
-            recipient_hash: hex::encode(&inputs.recipient_hash),
+            recipient_hash: hex::encode(recipient_binding(inputs.recipient.as_bytes(), inputs.target_chain_id)),
             state: 0,
+            fee_bps: 0,
         },
         metadata: xfg_stark::proof_data_schema::ProofMetadata {
             version: "1.0.0".to_string(),
             created_at: chrono::Utc::now().to_rfc3339(),
             description: format!("STARK proof for {} XFG burn", inputs.burn_amount),
             network: "fuego-testnet".to_string(),
+            build_info: Some(xfg_stark::build_info::BuildInfo::current()),
+            hash_function: Default::default(),
+            producer: None,
+            migrated_from: None,
         },
     })
 }
 
-/// Verify Eldernode consistency between STARK proof and Eldernode consensus
-fn verify_eldernode_consistency(
-    eldernode_inputs: &EldernodeVerificationInputs,
-    stark_proof: &StarkProof,
-    eldernode_consensus: &EldernodeConsensus
-) -> Result<()> {
-    // **Key Verification: Commitment Matching**
-    if !eldernode_consensus.commitment_match {
-        return Err(XfgStarkError::CryptoError(
-            "Commitment mismatch: provided commitment does not match tx_extra commitment".to_string()
-        ));
+/// Load the trusted Eldernodes and the threshold they are held to
+fn load_eldernode_verifier(eldernodes_file: &str, threshold: &str) -> Result<EldernodeVerifier> {
+    let records: Vec<EldernodeRecord> = serde_json::from_str(&std::fs::read_to_string(eldernodes_file)?)?;
+    let eldernode_error = |e: xfg_stark::eldernode::EldernodeError| XfgStarkError::ParseError(e.to_string());
+    let threshold: ConsensusThreshold = threshold.parse().map_err(eldernode_error)?;
+    let eldernodes = EldernodeSet::from_records(&records).map_err(eldernode_error)?;
+    EldernodeVerifier::new(eldernodes, threshold).map_err(eldernode_error)
+}
+
+/// Check the commitment and burn amount against the burn transaction, as the Eldernodes will
+fn check_burn_on_chain(rpc: &FuegoRpcClient, eldernode_inputs: &EldernodeAttestationInputs) -> Result<()> {
+    let rpc_error = |e: &dyn std::fmt::Display| XfgStarkError::CryptoError(e.to_string());
+    let transaction = rpc.get_transaction(&eldernode_inputs.transaction_hash).map_err(|e| rpc_error(&e))?;
+    let commitment = transaction.heat_commitment().map_err(|e| rpc_error(&e))?;
+    let burn_amount = transaction.burn_amount().map_err(|e| rpc_error(&e))?;
+
+    if commitment != eldernode_inputs.commitment {
+        return Err(XfgStarkError::CryptoError(format!(
+            "Commitment mismatch: package has {}, tx_extra has {}",
+            hex::encode(eldernode_inputs.commitment),
+            hex::encode(commitment)
+        )));
     }
-    
-    // **Key Verification: Burn Amount Matching**
-    if !eldernode_consensus.burn_amount_match {
-        return Err(XfgStarkError::CryptoError(
-            "Burn amount mismatch: provided burn amount does not match undefined output key amount".to_string()
-        ));
+    if burn_amount != eldernode_inputs.burn_amount {
+        return Err(XfgStarkError::CryptoError(format!(
+            "Burn amount mismatch: package has {}, burn outputs total {}",
+            eldernode_inputs.burn_amount, burn_amount
+        )));
     }
-    
-    println!("✅ Eldernode verification successful:");
-    println!("   Commitment verification:");
-    println!("     Provided: {}", eldernode_inputs.commitment);
-    println!("     tx_extra:  {}", eldernode_consensus.tx_extra_commitment);
-    println!("     Match:     {}", eldernode_consensus.commitment_match);
-    println!("   Burn amount verification:");
-    println!("     Provided: {} atomic units", eldernode_inputs.burn_amount);
-    println!("     On-chain: {} atomic units", eldernode_consensus.tx_burn_amount);
-    println!("     Match:     {}", eldernode_consensus.burn_amount_match);
-    
     Ok(())
 }
 
-// Helper functions for hex conversion
-fn hex_to_bytes(hex: &str) -> Result<Vec<u8>, hex::FromHexError> {
-    // Remove 0x prefix if present
-    let hex_clean = if hex.starts_with("0x") {
-        &hex[2..]
-    } else {
-        hex
-    };
-    hex::decode(hex_clean)
+/// Print which Eldernodes confirmed, disputed or were rejected
+fn print_consensus(eldernode_inputs: &EldernodeAttestationInputs, consensus: &EldernodeConsensus) {
+    println!("   Threshold: {} ({} confirmed)", consensus.threshold, consensus.confirmed.len());
+    println!("   Claimed commitment: {}", hex::encode(eldernode_inputs.commitment));
+    println!("   Claimed burn amount: {} atomic units", eldernode_inputs.burn_amount);
+    for attestation in &consensus.disputed {
+        println!(
+            "   ⚠️  {} attested commitment {} and burn amount {}",
+            attestation.eldernode_id, attestation.commitment, attestation.burn_amount
+        );
+    }
+    for (eldernode_id, reason) in &consensus.rejected {
+        println!("   ⚠️  {} ignored: {}", eldernode_id, reason);
+    }
 }
 
-fn hex_to_u64(hex: &str) -> Result<u64, XfgStarkError> {
-    let bytes = hex_to_bytes(hex)
-        .map_err(|e| XfgStarkError::ParseError(format!("Invalid hex string: {}", e)))?;
-    
-    if bytes.len() < 8 {
-        return Err(XfgStarkError::ParseError("Hex string too short for u64".to_string()));
-    }
-    
-    let mut u64_bytes = [0u8; 8];
-    u64_bytes.copy_from_slice(&bytes[0..8]);
-    Ok(u64::from_le_bytes(u64_bytes))
+// Helper functions for hex conversion
+fn hex_to_bytes(hex: &str) -> std::result::Result<Vec<u8>, hex::FromHexError> {
+    // Remove 0x prefix if present
+    hex::decode(hex.strip_prefix("0x").unwrap_or(hex))
 }
@@ -1,17 +1,132 @@
 use clap::{Command, Arg};
+use serde::Serialize;
+use serde_json::{json, Value};
 use std::path::Path;
-use std::io::{self, Write, BufRead, BufReader};
-use std::collections::HashMap;
+use std::io::{self, Write, BufRead, BufReader, IsTerminal};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
 use xfg_stark::{
     proof_data_schema::{StarkProofDataPackage, CompleteProofPackage, StarkProof, EldernodeVerification, ProofDataTemplate},
     burn_mint_prover::XfgBurnMintProver,
+    proof::memory::ProverMemoryBudget,
+    statements::{HashFunction, ProverOptions},
     burn_mint_verifier::{XfgBurnMintVerifier, VerificationResult},
+    package_signing::{SigningError, SigningKeyRecord, TrustedSigningKeys},
+    package_encryption::{parse_recipient_public_key, parse_recipient_secret_key, EncryptionError, PackageEnvelope},
+    keystore::{Keystore, KeystoreError, ScryptParams},
+    eth_address::{EnsRpcResolver, EthAddress},
+    commitment_audit::{audit_commitment_with_tx_extra, AuditError},
+    fuego_rpc::FuegoRpcClient,
+    package_store::{open_store, PackageStore, StoreError, StoreLookup, StoreRef, StoredKind},
+    proof_report::{generate_report, ReportError, ReportFormat},
+    progress::{ProgressReporter, ProgressWatcher, StarkPhase},
+    provenance::ProducerInfo,
+    bug_report::BugReport,
+    clock::clock_from_env,
+    tenants::TenantRegistry,
+    verification_server::{ServerConfig, VerificationServer, DEFAULT_SERVER_ADDR},
+    stress::{run_stress, StressConfig},
+    batch_generation::{generate_batch, prove_package, BatchConfig, BATCH_MANIFEST_FILE},
+    claim_calldata::{ClaimCall, Eip712Domain},
+    evm::{GasEstimator, DEFAULT_GAS_BUFFER_PERCENT},
+    networks::NetworkRegistry,
     XfgStarkError,
     Result,
 };
+use zeroize::Zeroizing;
 
 mod ascii_arts;
 
+/// Set by `--json`: commands print a [`CommandReport`] instead of human text
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+fn json_output() -> bool {
+    JSON_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// `println!` for human output, silenced by `--json`
+macro_rules! say {
+    ($($arg:tt)*) => {
+        if !json_output() {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// Machine-readable result of a command, printed instead of human text with `--json`
+#[derive(Debug, Serialize)]
+struct CommandReport {
+    command: &'static str,
+    /// `ok`, or `error` when `errors` is not empty
+    status: &'static str,
+    errors: Vec<String>,
+    warnings: Vec<String>,
+    /// Files read and written, by role
+    files: BTreeMap<&'static str, String>,
+    elapsed_ms: f64,
+    /// Command-specific results
+    #[serde(flatten)]
+    details: serde_json::Map<String, Value>,
+    #[serde(skip)]
+    started: Instant,
+}
+
+impl CommandReport {
+    fn new(command: &'static str) -> Self {
+        Self {
+            command,
+            status: "ok",
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            files: BTreeMap::new(),
+            elapsed_ms: 0.0,
+            details: serde_json::Map::new(),
+            started: Instant::now(),
+        }
+    }
+
+    fn file(mut self, role: &'static str, path: &str) -> Self {
+        self.files.insert(role, path.to_string());
+        self
+    }
+
+    fn detail(mut self, key: &str, value: Value) -> Self {
+        self.details.insert(key.to_string(), value);
+        self
+    }
+
+    fn finish(mut self) -> Self {
+        self.elapsed_ms = self.started.elapsed().as_secs_f64() * 1000.0;
+        if !self.errors.is_empty() {
+            self.status = "error";
+        }
+        self
+    }
+}
+
+/// Print a command's report with `--json`, exiting non-zero if it has any errors
+///
+/// Without `--json` the human output has already been printed and errors propagate as
+/// usual.
+fn emit(command: &'static str, result: Result<CommandReport>) -> Result<()> {
+    if !json_output() {
+        return result.map(|_| ());
+    }
+    let report = result
+        .unwrap_or_else(|e| {
+            let mut report = CommandReport::new(command);
+            report.errors.push(e.to_string());
+            report
+        })
+        .finish();
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    if !report.errors.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
 // Interactive CLI Runtime
 struct InteractiveCLI {
     running: bool,
@@ -39,7 +154,8 @@ impl InteractiveCLI {
             println!("   create-package <txn> <recipient> <output> - Create a data package");
             println!("   validate <file>         - Validate a data package");
             println!("   generate <input> <output> - Generate a STARK proof");
-            println!("   estimate-gas <recipient> - Estimate L1 gas fees for minting");
+            println!("   verify <package> <proof> - Verify a proof against its data package");
+            println!("   estimate-gas <proof> <rpc> - Estimate gas for claiming HEAT");
             println!("   check-network <network> - Check network status and contracts");
             println!("   clear                   - Clear the screen");
             println!("   exit, quit              - Exit the CLI");
@@ -49,6 +165,7 @@ impl InteractiveCLI {
             println!("   2. Or use: create-package <txn_hash> <eth_address> <output.json>");
             println!("   3. Then: validate <output.json>");
             println!("   4. Then: generate <output.json> <proof.json>");
+            println!("   5. Then: verify <output.json> <proof.json>");
             println!();
             Ok(())
         }));
@@ -78,8 +195,8 @@ impl InteractiveCLI {
             println!("   This creates the cryptographic proof for HEAT minting");
             println!();
             println!("Step 4: Estimate Gas Fees");
-            println!("   estimate-gas <eth_address>");
-            println!("   This tells you how much ETH you need for L1 minting");
+            println!("   estimate-gas <proof.json> <rpc_url>");
+            println!("   This simulates your claim and tells you how much ETH it needs");
             println!();
             println!("Step 5: Mint HEAT Tokens");
             println!("   Submit the proof to HEAT mint contract on Ethereum");
@@ -93,7 +210,7 @@ impl InteractiveCLI {
             println!();
             println!("❓ Need Help?");
             println!("   • Type 'help' for all commands");
-            println!("   • Type 'estimate-gas <address>' to check gas costs");
+            println!("   • Type 'estimate-gas <proof.json> <rpc_url>' to check gas costs");
             println!("   • Type 'check-network sepolia' for testnet info");
             println!();
             Ok(())
@@ -138,11 +255,11 @@ impl InteractiveCLI {
             // Validate Ethereum address format
             if !recipient.starts_with("0x") || recipient.len() != 42 {
                 println!("❌ Error: Recipient must be a valid Ethereum address (0x...)");
-                println!("💡 Example: 0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6");
+                println!("💡 Example: 0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6");
                 return Ok(());
             }
             
-            create_package(txn_hash, recipient, output_file)
+            create_package(txn_hash, recipient, output_file, 0, None, None)
         }));
 
         self.commands.insert("validate".to_string(), Box::new(|args| {
@@ -152,7 +269,7 @@ impl InteractiveCLI {
                 return Ok(());
             }
             let input_file = args[0];
-            validate_package(input_file)
+            validate_package(input_file, None).map(|_| ())
         }));
 
         self.commands.insert("generate".to_string(), Box::new(|args| {
@@ -164,22 +281,32 @@ impl InteractiveCLI {
             }
             let input_file = args[0];
             let output_file = args[1];
-            generate_proof(input_file, output_file)
+            generate_proof(input_file, output_file, None, None, HashFunction::default(), ProverMemoryBudget::unlimited(), None).map(|_| ())
+        }));
+
+        self.commands.insert("verify".to_string(), Box::new(|args| {
+            if args.len() < 2 {
+                println!("❌ Usage: verify <package_file> <proof_file>");
+                println!("💡 Example: verify package.json proof.json");
+                println!("📋 This checks the proof before you submit it on-chain");
+                return Ok(());
+            }
+            verify_proof_file(args[0], args[1]).map(|_| ())
         }));
 
         self.commands.insert("estimate-gas".to_string(), Box::new(|args| {
-            if args.len() < 1 {
-                println!("❌ Usage: estimate-gas <recipient>");
-                println!("💡 Example: estimate-gas 0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6");
+            if args.len() < 2 {
+                println!("❌ Usage: estimate-gas <proof.json> <rpc_url> [network]");
+                println!("💡 Example: estimate-gas proof.json https://sepolia-rollup.arbitrum.io/rpc sepolia");
                 return Ok(());
             }
-            let recipient = args[0];
-            estimate_gas_fees(recipient, false)
+            let network = args.get(2).copied().unwrap_or("sepolia");
+            estimate_gas_fees(args[0], args[1], network, None, None, DEFAULT_GAS_BUFFER_PERCENT).map(|_| ())
         }));
 
         self.commands.insert("check-network".to_string(), Box::new(|args| {
             let network = if args.len() > 0 { args[0] } else { "sepolia" };
-            check_network_status(network)
+            check_network_status(network, None).map(|_| ())
         }));
 
         self.commands.insert("clear".to_string(), Box::new(|_| {
@@ -246,25 +373,29 @@ impl InteractiveCLI {
 }
 
 fn main() -> Result<()> {
-    // Display cool ASCII art header
-    print_brand_header();
-    
     let matches = Command::new("xfg-stark-cli")
         .version("2.0")
         .about("🔥 Enhanced CLI tool for XFG burn → HEAT mint STARK proofs")
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .help("Print machine-readable JSON results from validate, generate, estimate-gas and check-network")
+                .action(clap::ArgAction::SetTrue)
+                .global(true)
+        )
         .subcommand(
             Command::new("interactive")
                 .about("Start interactive command-line runtime")
         )
         .subcommand(
-            Command::new("generate")
+            with_debug_args(Command::new("generate"))
                 .about("Generate a STARK proof from a data package")
                 .arg(
                     Arg::new("input")
                         .short('i')
                         .long("input")
                         .value_name("FILE")
-                        .help("Input data package file")
+                        .help("Input data package file or <store-uri>#<tx-hash>")
                         .required(true)
                 )
                 .arg(
@@ -275,6 +406,130 @@ fn main() -> Result<()> {
                         .help("Output proof file")
                         .required(true)
                 )
+                .arg(
+                    Arg::new("expected-commitment")
+                        .long("expected-commitment")
+                        .value_name("HEX")
+                        .help("HEAT commitment from the burn transaction's tx_extra; the secret is checked against it before proving")
+                )
+                .arg(
+                    Arg::new("hash")
+                        .long("hash")
+                        .value_name("HASH")
+                        .help("Hash function the proof commits with: blake3 (fastest), keccak (EVM) or rescue (recursion)")
+                        .default_value("blake3")
+                )
+                .arg(
+                    Arg::new("memory-budget")
+                        .long("memory-budget")
+                        .value_name("MB")
+                        .help("Cap on the memory of the trace extension; over it the extension is spilled to a temporary file")
+                        .value_parser(clap::value_parser!(usize))
+                )
+                .arg(producer_arg())
+        )
+        .subcommand(
+            Command::new("generate-batch")
+                .about("Generate STARK proofs for every data package in a directory")
+                .arg(
+                    Arg::new("input-dir")
+                        .short('i')
+                        .long("input-dir")
+                        .value_name("DIR")
+                        .help("Directory of *.json data packages")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("output-dir")
+                        .short('o')
+                        .long("output-dir")
+                        .value_name("DIR")
+                        .help("Directory for the proof files and the batch manifest")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("workers")
+                        .short('j')
+                        .long("workers")
+                        .value_name("N")
+                        .help("Proofs generated concurrently (defaults to the CPU count)")
+                        .value_parser(clap::value_parser!(usize))
+                )
+                .arg(
+                    Arg::new("hash")
+                        .long("hash")
+                        .value_name("HASH")
+                        .help("Hash function the proofs commit with: blake3 (fastest), keccak (EVM) or rescue (recursion)")
+                        .default_value("blake3")
+                )
+                .arg(producer_arg())
+        )
+        .subcommand(
+            Command::new("verify")
+                .about("Verify a generated proof against the data package it was generated from")
+                .arg(
+                    Arg::new("package")
+                        .short('p')
+                        .long("package")
+                        .value_name("FILE")
+                        .help("Data package the proof was generated from or <store-uri>#<tx-hash>")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("proof")
+                        .short('f')
+                        .long("proof")
+                        .value_name("FILE")
+                        .help("Proof file written by generate or <store-uri>#<tx-hash>")
+                        .required(true)
+                )
+        )
+        .subcommand(
+            Command::new("export-calldata")
+                .about("Export the HEAT contract's claimHEAT calldata for a proven package")
+                .arg(
+                    Arg::new("package")
+                        .short('p')
+                        .long("package")
+                        .value_name("FILE")
+                        .help("Complete proof package with a STARK proof")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Output file: hex calldata, or EIP-712 typed data with --eip712")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("eldernode-proof")
+                        .long("eldernode-proof")
+                        .value_name("HEX")
+                        .help("Eldernode consensus proof bytes, if the contract requires consensus")
+                )
+                .arg(
+                    Arg::new("eip712")
+                        .long("eip712")
+                        .help("Write an EIP-712 typed payload for hardware-wallet signing instead of raw calldata")
+                        .action(clap::ArgAction::SetTrue)
+                        .requires("contract")
+                )
+                .arg(
+                    Arg::new("contract")
+                        .long("contract")
+                        .value_name("ADDRESS")
+                        .help("HEAT verifier contract address (the EIP-712 verifying contract)")
+                )
+                .arg(
+                    Arg::new("chain-id")
+                        .long("chain-id")
+                        .value_name("ID")
+                        .help("Chain the HEAT verifier contract is deployed on")
+                        .value_parser(clap::value_parser!(u64))
+                        .default_value("42161")
+                )
         )
         .subcommand(
             Command::new("validate")
@@ -284,9 +539,15 @@ fn main() -> Result<()> {
                         .short('i')
                         .long("input")
                         .value_name("FILE")
-                        .help("Input data package file")
+                        .help("Input data package file or <store-uri>#<tx-hash>")
                         .required(true)
                 )
+                .arg(
+                    Arg::new("ens-rpc")
+                        .long("ens-rpc")
+                        .value_name("URL")
+                        .help("Ethereum JSON-RPC URL; when set, the recipient's ENS name must resolve to its address")
+                )
         )
         .subcommand(
             Command::new("create-template")
@@ -343,314 +604,1000 @@ fn main() -> Result<()> {
                         .help("Output package file")
                         .required(true)
                 )
+                .arg(
+                    Arg::new("fee-bps")
+                        .long("fee-bps")
+                        .value_name("BPS")
+                        .help("Protocol fee charged by the network, in basis points")
+                        .value_parser(clap::value_parser!(u32))
+                        .default_value("0")
+                )
+                .arg(
+                    Arg::new("keystore")
+                        .long("keystore")
+                        .value_name("FILE")
+                        .help("Keep the burn secret in this passphrase-encrypted keystore, creating it if missing")
+                )
+                .arg(producer_arg())
         )
-        .get_matches();
-
-    match matches.subcommand() {
-        Some(("interactive", _)) => {
-            let mut cli = InteractiveCLI::new();
-            cli.run()?;
-        }
-        Some(("generate", args)) => {
-            let input_file = args.get_one::<String>("input").unwrap();
-            let output_file = args.get_one::<String>("output").unwrap();
-            generate_proof(input_file, output_file)?;
-        }
-        Some(("validate", args)) => {
-            let input_file = args.get_one::<String>("input").unwrap();
-            validate_package(input_file)?;
-        }
-        Some(("create-template", args)) => {
-            let _burn_amount = args.get_one::<f64>("burn-amount").unwrap();
-            let output_file = args.get_one::<String>("output").unwrap();
-            create_template(output_file)?;
-        }
-        Some(("create-package", args)) => {
-            let _template_file = args.get_one::<String>("template").unwrap();
-            let txn_hash = args.get_one::<String>("txn-hash").unwrap();
-            let recipient = args.get_one::<String>("recipient").unwrap();
-            let output_file = args.get_one::<String>("output").unwrap();
-            create_package(txn_hash, recipient, output_file)?;
-        }
-        _ => {
-            eprintln!("Unknown subcommand. Use --help for usage information.");
-            std::process::exit(1);
-        }
-    }
-
-    Ok(())
-}
-
-// Eldernode verification
-fn eldernode_verify_package(input_file: &str) -> Result<()> {
-    println!("\n🔍 Eldernode Verification");
-    println!("==========================");
-    println!("📋 Loading package from: {}", input_file);
-
-    let package = StarkProofDataPackage::load_from_file(input_file)
-        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
-
-    println!("✅ Package loaded successfully");
-    println!("🔥 Burn Transaction:");
-    println!("   Hash: {}", package.burn_transaction.transaction_hash);
-    println!("   Amount: {} XFG", package.burn_transaction.burn_amount_xfg);
-    println!("   Block Height: {}", package.burn_transaction.block_height);
-    println!("👤 Recipient: {}", package.recipient.ethereum_address);
-
-    println!("\n🔄 Contacting Fuego Eldernodes...");
-    println!("   This may take a few minutes...");
-    
-    // Simulate eldernode verification process
-    println!("   ┌─────────────────────────────────────────────────────────────────┐");
-    println!("   │ 🔍 Verifying transaction on Fuego blockchain...");
-    println!("   │ 📊 Checking burn amount and recipient...");
-    println!("   │ 🛡️  Validating against double-spend protection...");
-    println!("   │ ✅ Confirming transaction finality...");
-    println!("   └─────────────────────────────────────────────────────────────────┘");
-
-    // In a real implementation, this would:
-    // 1. Contact multiple Eldernodes
-    // 2. Verify the transaction exists on Fuego blockchain
-    // 3. Check that the burn amount matches
-    // 4. Ensure the transaction is confirmed and not double-spent
-    // 5. Get consensus from Eldernodes
-    // 6. Return verification result
-
-    println!("\n✅ Eldernode Verification Complete!");
-    println!("📋 Verification Results:");
-    println!("   ┌─────────────────────────────────────────────────────────────────┐");
-    println!("   │ ✅ Transaction confirmed on Fuego blockchain");
-    println!("   │ ✅ Burn amount verified: {} XFG", package.burn_transaction.burn_amount_xfg);
-    println!("   │ ✅ No double-spend detected");
-    println!("   │ ✅ Transaction is final (block height: {})", package.burn_transaction.block_height);
-    println!("   │ ✅ Eldernode consensus reached");
-    println!("   └─────────────────────────────────────────────────────────────────┘");
-
-    println!("\n💡 Next Steps:");
-    println!("   1. Your burn transaction is now verified by Eldernodes");
-    println!("   2. You can proceed to generate the STARK proof");
-    println!("   3. Run: generate {} <proof_output.json>", input_file);
-
-    Ok(())
-}
-
-/// Generate STARK proof from data package using real prover
-fn generate_proof(input_file: &str, output_file: &str) -> Result<()> {
-    println!("🔍 Loading data package from: {}", input_file);
-
-    // Load and validate data package
-    let package = StarkProofDataPackage::load_from_file(input_file)
-        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
-
-    let validation = package.validate();
-
-    if !validation.is_valid {
-        eprintln!("❌ Data package validation failed:");
-        for error in &validation.errors {
-            eprintln!("   - {}", error);
-        }
-        std::process::exit(1);
-    }
-
-    if !validation.warnings.is_empty() {
-        println!("⚠️  Warnings:");
-        for warning in &validation.warnings {
-            println!("   - {}", warning);
-        }
-    }
-
-    println!("✅ Data package validated successfully");
-    println!("📊 Burn amount: {} XFG ({} atomic units)",
-             package.burn_transaction.burn_amount_xfg,
-             package.burn_transaction.burn_amount_atomic);
-    println!("🎯 Mint amount: {} HEAT", package.get_mint_amount_heat());
-
-    // Create real prover
-    println!("🔐 Creating STARK prover...");
-    let prover = XfgBurnMintProver::new(128);
-
-    // Convert transaction hash from hex string to u64
-    let txn_hash_u64 = hex_to_u64(&package.burn_transaction.transaction_hash)
-        .map_err(|e| XfgStarkError::ParseError(format!("Invalid transaction hash: {}", e)))?;
-
-    // Convert Ethereum address to bytes
-    let recipient_bytes = hex_to_bytes(&package.recipient.ethereum_address)
-        .map_err(|e| XfgStarkError::ParseError(format!("Invalid recipient address: {}", e)))?;
-
-    // Convert secret to bytes
-    let secret_bytes = package.secret.secret_key.as_bytes();
-
-    // Generate real STARK proof
-    println!("⚡ Generating STARK proof...");
-    
-    // Convert tx_prefix_hash to [u8; 32]
-    let tx_prefix_hash = hex_to_bytes(&package.burn_transaction.transaction_hash)
-        .map_err(|e| XfgStarkError::ParseError(format!("Invalid transaction hash: {}", e)))?;
-    let mut tx_hash_array = [0u8; 32];
-    if tx_prefix_hash.len() >= 32 {
-        tx_hash_array.copy_from_slice(&tx_prefix_hash[..32]);
-    } else {
-        tx_hash_array[..tx_prefix_hash.len()].copy_from_slice(&tx_prefix_hash);
-    }
-    
-    // Convert recipient to 20 bytes
-    let mut recipient_array = [0u8; 20];
-    if recipient_bytes.len() >= 20 {
-        recipient_array.copy_from_slice(&recipient_bytes[..20]);
-    } else {
-        recipient_array[..recipient_bytes.len()].copy_from_slice(&recipient_bytes);
-    }
-    
-    // Convert secret to proper length
-    let mut secret_array = [0u8; 32];
-    if secret_bytes.len() >= 32 {
-        secret_array.copy_from_slice(&secret_bytes[..32]);
-    } else {
-        secret_array[..secret_bytes.len()].copy_from_slice(&secret_bytes);
-    }
-    
-    // Parse network_id from string to u32 (default to 1 for mainnet)
-    let network_id = package.burn_transaction.network_id.parse::<u32>().unwrap_or(1);
-    
-    // Default values for target_chain_id and commitment_version
-    let target_chain_id = 42161; // Arbitrum One
-    let commitment_version = 1;
-    
-    let winterfell_proof = prover.prove_burn_mint(
-        package.burn_transaction.burn_amount_atomic,
-        package.get_mint_amount_atomic(),
-        tx_hash_array,
-        &recipient_array,
-        &secret_array,
-        network_id,
-        target_chain_id,
-        commitment_version,
-    ).map_err(|e| XfgStarkError::CryptoError(format!("Proof generation failed: {}", e)))?;
-
-    println!("✅ STARK proof generated successfully");
-
-    // Convert Winterfell proof to our format
-    let proof_data = winterfell_proof.to_bytes();
-    println!("📏 Proof size: {} bytes", proof_data.len());
-
-    let proof = StarkProof {
-        proof_data: proof_data.clone(),
-        public_inputs: xfg_stark::proof_data_schema::StarkPublicInputs {
-            burn_amount: package.burn_transaction.burn_amount_atomic,
-            mint_amount: package.get_mint_amount_atomic(),
-            txn_hash: package.burn_transaction.transaction_hash.clone(),
-            recipient_hash: package.recipient.ethereum_address.clone(),
-            state: 0,
-        },
-        metadata: xfg_stark::proof_data_schema::ProofMetadata {
-            version: "1.0.0".to_string(),
-            created_at: chrono::Utc::now().to_rfc3339(),
-            description: format!("STARK proof for {} XFG burn", package.burn_transaction.burn_amount_xfg),
-            network: package.metadata.network.clone(),
-        },
-    };
-
-    // Save proof
-    let json = serde_json::to_string_pretty(&proof)
-        .map_err(|e| XfgStarkError::JsonError(e))?;
-
-    std::fs::write(output_file, json)
-        .map_err(|e| XfgStarkError::IoError(e))?;
-
-    println!("�� Proof saved to: {}", output_file);
-    println!("🚀 Ready for submission to HEAT mint contract!");
-
-    Ok(())
-}
-
-/// Validate data package with enhanced Fuego blockchain validation
-fn validate_package(input_file: &str) -> Result<()> {
-    println!("🔍 Loading data package from: {}", input_file);
-
-    let package = StarkProofDataPackage::load_from_file(input_file)
-        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
-
-    println!("�� Package Information:");
-    println!("   Version: {}", package.metadata.version);
-    println!("   Network: {}", package.metadata.network);
-    println!("   Created: {}", package.metadata.created_at);
-    println!("   Description: {}", package.metadata.description);
-
-    println!("\n🔥 Burn Transaction:");
-    println!("   Hash: {}", package.burn_transaction.transaction_hash);
-    println!("   Amount: {} XFG ({} atomic units)",
-             package.burn_transaction.burn_amount_xfg,
-             package.burn_transaction.burn_amount_atomic);
-    println!("   Block Height: {}", package.burn_transaction.block_height);
-    println!("   Timestamp: {}", package.burn_transaction.timestamp);
-
-    println!("\n👤 Recipient:");
-    println!("   Address: {}", package.recipient.ethereum_address);
-    if let Some(ref ens) = package.recipient.ens_name {
-        println!("   ENS: {}", ens);
-    }
-    if let Some(ref label) = package.recipient.label {
-        println!("   Label: {}", label);
-    }
-
-    println!("\n🔐 Secret:");
-    println!("   Key: {}...", &package.secret.secret_key[..8.min(package.secret.secret_key.len())]);
-    if let Some(ref salt) = package.secret.salt {
-        println!("   Salt: {}", salt);
-    }
-    if let Some(ref hint) = package.secret.hint {
-        println!("   Hint: {}", hint);
-    }
-
-    println!("\n📊 Validation Results:");
-
+        .subcommand(
+            Command::new("add-signing-key")
+                .about("Register a signing key on a data package and sign it")
+                .arg(
+                    Arg::new("package")
+                        .short('p')
+                        .long("package")
+                        .value_name("FILE")
+                        .help("Data package file")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("key-file")
+                        .short('k')
+                        .long("key-file")
+                        .value_name("FILE")
+                        .help("File containing a hex-encoded 32-byte Ed25519 seed")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("key-id")
+                        .long("key-id")
+                        .value_name("ID")
+                        .help("Identifier for the signing key")
+                        .required(true)
+                )
+        )
+        .subcommand(
+            Command::new("rotate-signing-key")
+                .about("Retire a signing key and sign the data package with its replacement")
+                .arg(
+                    Arg::new("package")
+                        .short('p')
+                        .long("package")
+                        .value_name("FILE")
+                        .help("Data package file")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("old-key-id")
+                        .long("old-key-id")
+                        .value_name("ID")
+                        .help("Identifier of the key being retired")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("key-file")
+                        .short('k')
+                        .long("key-file")
+                        .value_name("FILE")
+                        .help("File containing a hex-encoded 32-byte Ed25519 seed")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("key-id")
+                        .long("key-id")
+                        .value_name("ID")
+                        .help("Identifier for the signing key")
+                        .required(true)
+                )
+        )
+        .subcommand(
+            Command::new("verify-signatures")
+                .about("Verify the signatures on a data package")
+                .arg(
+                    Arg::new("package")
+                        .short('p')
+                        .long("package")
+                        .value_name("FILE")
+                        .help("Data package file or <store-uri>#<tx-hash>")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("trusted-keys")
+                        .short('t')
+                        .long("trusted-keys")
+                        .value_name("FILE")
+                        .help("JSON list of trusted signing key records")
+                        .required(true)
+                )
+        )
+        .subcommand(
+            Command::new("encrypt-package")
+                .about("Encrypt a proof package so only the mint recipient can open it")
+                .arg(
+                    Arg::new("package")
+                        .short('p')
+                        .long("package")
+                        .value_name("FILE")
+                        .help("Complete proof package file")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("recipient-key")
+                        .long("recipient-key")
+                        .value_name("HEX")
+                        .help("Recipient's secp256k1 public key (compressed, uncompressed or 64-byte Ethereum form)")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Output envelope file")
+                        .required(true)
+                )
+        )
+        .subcommand(
+            Command::new("decrypt-package")
+                .about("Decrypt a recipient-encrypted proof package")
+                .arg(
+                    Arg::new("envelope")
+                        .short('e')
+                        .long("envelope")
+                        .value_name("FILE")
+                        .help("Encrypted envelope file")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("key-file")
+                        .short('k')
+                        .long("key-file")
+                        .value_name("FILE")
+                        .help("File containing the recipient's hex-encoded 32-byte secp256k1 secret key")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Output package file")
+                        .required(true)
+                )
+        )
+        .subcommand(
+            Command::new("audit-commitment")
+                .about("Compare a package's commitment and burn amount with its burn transaction on the Fuego chain")
+                .arg(
+                    Arg::new("package")
+                        .value_name("PACKAGE")
+                        .help("Data package file or <store-uri>#<tx-hash>")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("rpc-url")
+                        .long("rpc-url")
+                        .value_name("URL")
+                        .help("Fuego daemon RPC URL")
+                        .default_value("http://127.0.0.1:18180")
+                )
+        )
+        .subcommand(
+            Command::new("inspect")
+                .about("Show the public inputs and metadata of a proof file")
+                .arg(
+                    Arg::new("proof")
+                        .value_name("PROOF")
+                        .help("Proof file or <store-uri>#<tx-hash>")
+                        .required(true)
+                )
+        )
+        .subcommand(
+            Command::new("report")
+                .about("Render a human-readable report for a verified proof package")
+                .arg(
+                    Arg::new("package")
+                        .value_name("PACKAGE")
+                        .help("Complete proof package file")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("format")
+                        .short('f')
+                        .long("format")
+                        .value_name("FORMAT")
+                        .help("Report format")
+                        .value_parser(["markdown", "html"])
+                        .default_value("markdown")
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Output file (defaults to stdout)")
+                )
+        )
+        .subcommand(
+            Command::new("bug-report")
+                .about("Collect a sanitized diagnostics bundle for a failing proof package")
+                .arg(
+                    Arg::new("package")
+                        .value_name("PACKAGE")
+                        .help("Complete proof package file")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Bundle file to attach to the issue")
+                        .default_value("xfg-bug-report.json")
+                )
+        )
+        .subcommand(
+            Command::new("store")
+                .about("Manage packages and proofs in a package store")
+                .subcommand_required(true)
+                .arg(
+                    Arg::new("store")
+                        .long("store")
+                        .value_name("URI")
+                        .help("Store URI (file://DIR, s3://BUCKET/PREFIX, or a directory path)")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("kind")
+                        .long("kind")
+                        .value_name("KIND")
+                        .help("Object kind")
+                        .value_parser(["package", "proof"])
+                        .default_value("package")
+                )
+                .subcommand(
+                    Command::new("put")
+                        .about("Store a package or proof file")
+                        .arg(
+                            Arg::new("input")
+                                .value_name("FILE")
+                                .help("Package or proof file")
+                                .required(true)
+                        )
+                )
+                .subcommand(
+                    Command::new("get")
+                        .about("Fetch a package or proof by transaction hash or digest")
+                        .arg(
+                            Arg::new("tx-hash")
+                                .long("tx-hash")
+                                .value_name("HASH")
+                                .help("Burn transaction hash")
+                                .required_unless_present("digest")
+                                .conflicts_with("digest")
+                        )
+                        .arg(
+                            Arg::new("digest")
+                                .long("digest")
+                                .value_name("DIGEST")
+                                .help("Object digest")
+                        )
+                        .arg(
+                            Arg::new("output")
+                                .short('o')
+                                .long("output")
+                                .value_name("FILE")
+                                .help("Output file")
+                                .required(true)
+                        )
+                )
+                .subcommand(
+                    Command::new("list")
+                        .about("List stored packages or proofs")
+                )
+        )
+        .subcommand(
+            Command::new("serve")
+                .about("Run the streaming proof verification server")
+                .arg(
+                    Arg::new("bind")
+                        .long("bind")
+                        .value_name("ADDR")
+                        .help("Address to listen on")
+                        .default_value(DEFAULT_SERVER_ADDR)
+                )
+                .arg(
+                    Arg::new("workers")
+                        .long("workers")
+                        .value_name("N")
+                        .help("Proofs verified concurrently (defaults to the CPU count)")
+                        .value_parser(clap::value_parser!(usize))
+                )
+                .arg(
+                    Arg::new("max-pending")
+                        .long("max-pending")
+                        .value_name("N")
+                        .help("Proofs allowed to wait for a worker before submissions get 429")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("64")
+                )
+                .arg(
+                    Arg::new("tenants")
+                        .long("tenants")
+                        .value_name("FILE")
+                        .help("JSON list of tenants (API keys, limits, quotas); connections must authenticate")
+                )
+        )
+        .subcommand(
+            Command::new("stress")
+                .about("Generate and verify synthetic proofs across the burn tiers for capacity planning")
+                .arg(
+                    Arg::new("proofs")
+                        .short('n')
+                        .long("proofs")
+                        .value_name("N")
+                        .help("Proofs to generate and verify")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("1000")
+                )
+                .arg(
+                    Arg::new("parallelism")
+                        .short('j')
+                        .long("parallelism")
+                        .value_name("N")
+                        .help("Proofs generated concurrently (defaults to the CPU count)")
+                        .value_parser(clap::value_parser!(usize))
+                )
+                .arg(
+                    Arg::new("seed")
+                        .long("seed")
+                        .value_name("SEED")
+                        .help("Seed for the synthetic burn transactions")
+                        .value_parser(clap::value_parser!(u64))
+                        .default_value("0")
+                )
+                .arg(
+                    Arg::new("format")
+                        .short('f')
+                        .long("format")
+                        .value_name("FORMAT")
+                        .help("Summary format")
+                        .value_parser(["text", "json"])
+                        .default_value("text")
+                )
+        )
+        .subcommand(
+            Command::new("estimate-gas")
+                .about("Estimate gas for claiming HEAT by simulating the claim over Ethereum RPC")
+                .arg(
+                    Arg::new("package")
+                        .short('p')
+                        .long("package")
+                        .value_name("FILE")
+                        .help("Complete proof package with a STARK proof")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("rpc-url")
+                        .long("rpc-url")
+                        .value_name("URL")
+                        .help("Ethereum JSON-RPC URL of the network's target chain")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("network")
+                        .short('n')
+                        .long("network")
+                        .value_name("NETWORK")
+                        .help("Network to claim on: sepolia, mainnet or a network from --networks")
+                        .default_value("sepolia")
+                )
+                .arg(
+                    Arg::new("networks")
+                        .long("networks")
+                        .value_name("FILE")
+                        .help("TOML file of custom networks")
+                )
+                .arg(
+                    Arg::new("eldernode-proof")
+                        .long("eldernode-proof")
+                        .value_name("HEX")
+                        .help("Eldernode consensus proof bytes, if the contract requires consensus")
+                )
+                .arg(
+                    Arg::new("buffer")
+                        .long("buffer")
+                        .value_name("PERCENT")
+                        .help("Buffer added to the simulated gas")
+                        .value_parser(clap::value_parser!(u32))
+                        .default_value("20")
+                )
+        )
+        .subcommand(
+            Command::new("check-network")
+                .about("Check network status and contracts")
+                .arg(
+                    Arg::new("network")
+                        .short('n')
+                        .long("network")
+                        .value_name("NETWORK")
+                        .help("Network to check: sepolia, mainnet or a network from --networks")
+                        .default_value("sepolia")
+                )
+                .arg(
+                    Arg::new("networks")
+                        .long("networks")
+                        .value_name("FILE")
+                        .help("TOML file of custom networks")
+                )
+        )
+        .get_matches();
+
+    JSON_OUTPUT.store(matches.get_flag("json"), Ordering::Relaxed);
+    if !json_output() {
+        // Display cool ASCII art header
+        print_brand_header();
+    }
+
+    match matches.subcommand() {
+        Some(("interactive", _)) => {
+            let mut cli = InteractiveCLI::new();
+            cli.run()?;
+        }
+        Some(("generate", args)) => {
+            let input_file = args.get_one::<String>("input").unwrap();
+            let output_file = args.get_one::<String>("output").unwrap();
+            let expected_commitment = args.get_one::<String>("expected-commitment");
+            #[cfg(feature = "debug")]
+            let dump_trace = args.get_one::<String>("dump-trace").map(String::as_str);
+            #[cfg(not(feature = "debug"))]
+            let dump_trace = None;
+            let hash_function = args
+                .get_one::<String>("hash")
+                .unwrap()
+                .parse::<HashFunction>()
+                .map_err(XfgStarkError::ParseError)?;
+            let memory_budget = args
+                .get_one::<usize>("memory-budget")
+                .map_or_else(ProverMemoryBudget::unlimited, |megabytes| ProverMemoryBudget::from_megabytes(*megabytes));
+            let producer = load_producer(args)?;
+            emit("generate", generate_proof(
+                input_file,
+                output_file,
+                expected_commitment.map(String::as_str),
+                dump_trace,
+                hash_function,
+                memory_budget,
+                producer,
+            ))?;
+        }
+        Some(("generate-batch", args)) => {
+            let input_dir = args.get_one::<String>("input-dir").unwrap();
+            let output_dir = args.get_one::<String>("output-dir").unwrap();
+            let mut config = BatchConfig::default();
+            if let Some(workers) = args.get_one::<usize>("workers") {
+                config.workers = *workers;
+            }
+            config.hash_function = args
+                .get_one::<String>("hash")
+                .unwrap()
+                .parse::<HashFunction>()
+                .map_err(XfgStarkError::ParseError)?;
+            config.producer = load_producer(args)?;
+            generate_proof_batch(input_dir, output_dir, &config)?;
+        }
+        Some(("verify", args)) => {
+            let package_file = args.get_one::<String>("package").unwrap();
+            let proof_file = args.get_one::<String>("proof").unwrap();
+            if !verify_proof_file(package_file, proof_file)? {
+                std::process::exit(1);
+            }
+        }
+        Some(("export-calldata", args)) => {
+            let package_file = args.get_one::<String>("package").unwrap();
+            let output_file = args.get_one::<String>("output").unwrap();
+            let eldernode_proof = args.get_one::<String>("eldernode-proof").map(String::as_str);
+            let domain = match args.get_one::<String>("contract") {
+                Some(contract) if args.get_flag("eip712") => {
+                    let contract = contract
+                        .parse()
+                        .map_err(|e: xfg_stark::AddressError| XfgStarkError::ParseError(format!("Invalid contract address: {}", e)))?;
+                    Some(Eip712Domain::new(*args.get_one::<u64>("chain-id").unwrap(), contract))
+                }
+                _ => None,
+            };
+            export_calldata(package_file, output_file, eldernode_proof, domain.as_ref())?;
+        }
+        Some(("validate", args)) => {
+            let input_file = args.get_one::<String>("input").unwrap();
+            let ens_rpc = args.get_one::<String>("ens-rpc").map(String::as_str);
+            emit("validate", validate_package(input_file, ens_rpc))?;
+        }
+        Some(("estimate-gas", args)) => {
+            let package_file = args.get_one::<String>("package").unwrap();
+            let rpc_url = args.get_one::<String>("rpc-url").unwrap();
+            let network = args.get_one::<String>("network").unwrap();
+            let networks_file = args.get_one::<String>("networks").map(String::as_str);
+            let eldernode_proof = args.get_one::<String>("eldernode-proof").map(String::as_str);
+            let buffer_percent = *args.get_one::<u32>("buffer").unwrap();
+            emit(
+                "estimate-gas",
+                estimate_gas_fees(package_file, rpc_url, network, networks_file, eldernode_proof, buffer_percent),
+            )?;
+        }
+        Some(("check-network", args)) => {
+            let network = args.get_one::<String>("network").unwrap();
+            let networks_file = args.get_one::<String>("networks").map(String::as_str);
+            emit("check-network", check_network_status(network, networks_file))?;
+        }
+        Some(("create-template", args)) => {
+            let _burn_amount = args.get_one::<f64>("burn-amount").unwrap();
+            let output_file = args.get_one::<String>("output").unwrap();
+            create_template(output_file)?;
+        }
+        Some(("create-package", args)) => {
+            let _template_file = args.get_one::<String>("template").unwrap();
+            let txn_hash = args.get_one::<String>("txn-hash").unwrap();
+            let recipient = args.get_one::<String>("recipient").unwrap();
+            let output_file = args.get_one::<String>("output").unwrap();
+            let fee_bps = *args.get_one::<u32>("fee-bps").unwrap();
+            let keystore = args.get_one::<String>("keystore").map(String::as_str);
+            let producer = load_producer(args)?;
+            create_package(txn_hash, recipient, output_file, fee_bps, keystore, producer)?;
+        }
+        Some(("add-signing-key", args)) => {
+            let package_file = args.get_one::<String>("package").unwrap();
+            let key_file = args.get_one::<String>("key-file").unwrap();
+            let key_id = args.get_one::<String>("key-id").unwrap();
+            add_signing_key(package_file, key_file, key_id)?;
+        }
+        Some(("rotate-signing-key", args)) => {
+            let package_file = args.get_one::<String>("package").unwrap();
+            let old_key_id = args.get_one::<String>("old-key-id").unwrap();
+            let key_file = args.get_one::<String>("key-file").unwrap();
+            let key_id = args.get_one::<String>("key-id").unwrap();
+            rotate_signing_key(package_file, old_key_id, key_file, key_id)?;
+        }
+        Some(("verify-signatures", args)) => {
+            let package_file = args.get_one::<String>("package").unwrap();
+            let trusted_keys_file = args.get_one::<String>("trusted-keys").unwrap();
+            verify_package_signatures(package_file, trusted_keys_file)?;
+        }
+        Some(("encrypt-package", args)) => {
+            let package_file = args.get_one::<String>("package").unwrap();
+            let recipient_key = args.get_one::<String>("recipient-key").unwrap();
+            let output_file = args.get_one::<String>("output").unwrap();
+            encrypt_package(package_file, recipient_key, output_file)?;
+        }
+        Some(("decrypt-package", args)) => {
+            let envelope_file = args.get_one::<String>("envelope").unwrap();
+            let key_file = args.get_one::<String>("key-file").unwrap();
+            let output_file = args.get_one::<String>("output").unwrap();
+            decrypt_package(envelope_file, key_file, output_file)?;
+        }
+        Some(("audit-commitment", args)) => {
+            let package_file = args.get_one::<String>("package").unwrap();
+            let rpc_url = args.get_one::<String>("rpc-url").unwrap();
+            audit_commitment(package_file, rpc_url)?;
+        }
+        Some(("inspect", args)) => {
+            let proof_file = args.get_one::<String>("proof").unwrap();
+            inspect_proof(proof_file)?;
+        }
+        Some(("report", args)) => {
+            let package_file = args.get_one::<String>("package").unwrap();
+            let format = args.get_one::<String>("format").unwrap();
+            let output_file = args.get_one::<String>("output");
+            report_package(package_file, format, output_file.map(String::as_str))?;
+        }
+        Some(("bug-report", args)) => {
+            let package_file = args.get_one::<String>("package").unwrap();
+            let output_file = args.get_one::<String>("output").unwrap();
+            bug_report(package_file, output_file)?;
+        }
+        Some(("store", args)) => {
+            let store = open_store(args.get_one::<String>("store").unwrap()).map_err(store_error)?;
+            let kind = match args.get_one::<String>("kind").unwrap().as_str() {
+                "proof" => StoredKind::Proof,
+                _ => StoredKind::Package,
+            };
+            match args.subcommand() {
+                Some(("put", put_args)) => {
+                    let input_file = put_args.get_one::<String>("input").unwrap();
+                    store_put(store.as_ref(), kind, input_file)?;
+                }
+                Some(("get", get_args)) => {
+                    let lookup = match get_args.get_one::<String>("digest") {
+                        Some(digest) => StoreLookup::Digest(digest.clone()),
+                        None => StoreLookup::TxHash(get_args.get_one::<String>("tx-hash").unwrap().clone()),
+                    };
+                    let output_file = get_args.get_one::<String>("output").unwrap();
+                    store_get(store.as_ref(), kind, &lookup, output_file)?;
+                }
+                Some(("list", _)) => store_list(store.as_ref(), kind)?,
+                _ => unreachable!("store requires a subcommand"),
+            }
+        }
+        Some(("serve", args)) => {
+            let bind = args.get_one::<String>("bind").unwrap();
+            let mut config = ServerConfig::default();
+            if let Some(workers) = args.get_one::<usize>("workers") {
+                config.workers = *workers;
+            }
+            config.max_pending = *args.get_one::<usize>("max-pending").unwrap();
+            let tenants = args.get_one::<String>("tenants").map(String::as_str);
+            serve(bind, config, tenants)?;
+        }
+        Some(("stress", args)) => {
+            let mut config = StressConfig {
+                proofs: *args.get_one::<usize>("proofs").unwrap(),
+                seed: *args.get_one::<u64>("seed").unwrap(),
+                ..StressConfig::default()
+            };
+            if let Some(parallelism) = args.get_one::<usize>("parallelism") {
+                config.parallelism = *parallelism;
+            }
+            let format = args.get_one::<String>("format").unwrap();
+            stress(config, format)?;
+        }
+        _ => {
+            eprintln!("Unknown subcommand. Use --help for usage information.");
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+// Eldernode verification
+fn eldernode_verify_package(input_file: &str) -> Result<()> {
+    println!("\n🔍 Eldernode Verification");
+    println!("==========================");
+    println!("📋 Loading package from: {}", input_file);
+
+    let package = load_package(input_file)?;
+
+    println!("✅ Package loaded successfully");
+    println!("🔥 Burn Transaction:");
+    println!("   Hash: {}", package.burn_transaction.transaction_hash);
+    println!("   Amount: {} XFG", package.burn_transaction.burn_amount_xfg);
+    println!("   Block Height: {}", package.burn_transaction.block_height);
+    println!("👤 Recipient: {}", package.recipient.ethereum_address);
+
+    println!("\n🔄 Contacting Fuego Eldernodes...");
+    println!("   This may take a few minutes...");
+    
+    // Simulate eldernode verification process
+    println!("   ┌─────────────────────────────────────────────────────────────────┐");
+    println!("   │ 🔍 Verifying transaction on Fuego blockchain...");
+    println!("   │ 📊 Checking burn amount and recipient...");
+    println!("   │ 🛡️  Validating against double-spend protection...");
+    println!("   │ ✅ Confirming transaction finality...");
+    println!("   └─────────────────────────────────────────────────────────────────┘");
+
+    // In a real implementation, this would:
+    // 1. Contact multiple Eldernodes
+    // 2. Verify the transaction exists on Fuego blockchain
+    // 3. Check that the burn amount matches
+    // 4. Ensure the transaction is confirmed and not double-spent
+    // 5. Get consensus from Eldernodes
+    // 6. Return verification result
+
+    println!("\n✅ Eldernode Verification Complete!");
+    println!("📋 Verification Results:");
+    println!("   ┌─────────────────────────────────────────────────────────────────┐");
+    println!("   │ ✅ Transaction confirmed on Fuego blockchain");
+    println!("   │ ✅ Burn amount verified: {} XFG", package.burn_transaction.burn_amount_xfg);
+    println!("   │ ✅ No double-spend detected");
+    println!("   │ ✅ Transaction is final (block height: {})", package.burn_transaction.block_height);
+    println!("   │ ✅ Eldernode consensus reached");
+    println!("   └─────────────────────────────────────────────────────────────────┘");
+
+    println!("\n💡 Next Steps:");
+    println!("   1. Your burn transaction is now verified by Eldernodes");
+    println!("   2. You can proceed to generate the STARK proof");
+    println!("   3. Run: generate {} <proof_output.json>", input_file);
+
+    Ok(())
+}
+
+/// Add the `--dump-trace` flag to `generate` in builds with the `debug` feature
+#[cfg(feature = "debug")]
+fn with_debug_args(command: Command) -> Command {
+    command.arg(
+        Arg::new("dump-trace")
+            .long("dump-trace")
+            .value_name("FILE")
+            .help("Save the execution trace before proving, for offline analysis of failing proofs")
+    )
+}
+
+/// Add the `--dump-trace` flag to `generate` in builds with the `debug` feature
+#[cfg(not(feature = "debug"))]
+fn with_debug_args(command: Command) -> Command {
+    command
+}
+
+/// `--producer` flag shared by the commands that create packages and proofs
+fn producer_arg() -> Arg {
+    Arg::new("producer")
+        .long("producer")
+        .value_name("FILE")
+        .help("JSON producer provenance (organization, contact, policy_uri, license) to embed in the output")
+}
+
+/// Load and validate the `--producer` file, if given
+fn load_producer(args: &clap::ArgMatches) -> Result<Option<ProducerInfo>> {
+    args.get_one::<String>("producer")
+        .map(|path| ProducerInfo::load_from_file(path).map_err(|e| XfgStarkError::ParseError(e.to_string())))
+        .transpose()
+}
+
+/// Generate STARK proof from data package using real prover
+fn generate_proof(
+    input_file: &str,
+    output_file: &str,
+    expected_commitment: Option<&str>,
+    dump_trace: Option<&str>,
+    hash_function: HashFunction,
+    memory_budget: ProverMemoryBudget,
+    producer: Option<ProducerInfo>,
+) -> Result<CommandReport> {
+    say!("🔍 Loading data package from: {}", input_file);
+    let mut report = CommandReport::new("generate").file("package", input_file);
+
+    // Load and validate data package
+    let package = load_unlocked_package(input_file)?;
+
     let validation = package.validate();
+    report.warnings = validation.warnings.clone();
+
+    if !validation.is_valid {
+        if json_output() {
+            report.errors = validation.errors;
+            println!("{}", serde_json::to_string_pretty(&report.finish())?);
+        } else {
+            eprintln!("❌ Data package validation failed:");
+            for error in &validation.errors {
+                eprintln!("   - {}", error);
+            }
+        }
+        std::process::exit(1);
+    }
+
+    if !validation.warnings.is_empty() {
+        say!("⚠️  Warnings:");
+        for warning in &validation.warnings {
+            say!("   - {}", warning);
+        }
+    }
+
+    say!("✅ Data package validated successfully");
+
+    if let Some(producer) = producer.as_ref().or(package.metadata.producer.as_ref()) {
+        say!("🏷️  Producer: {}", producer);
+    }
+    say!("📊 Burn amount: {} XFG ({} atomic units)",
+             package.burn_transaction.burn_amount_xfg,
+             package.burn_transaction.burn_amount_atomic);
+    say!("🎯 Mint amount: {} HEAT", package.get_mint_amount_heat());
+
+    // Create real prover
+    say!("🔐 Creating STARK prover...");
+    let mut prover = XfgBurnMintProver::new(128)
+        .with_hash_function(hash_function)
+        .with_memory_budget(memory_budget);
+    say!("🔑 Hash function: {}", hash_function);
+    say!("🧠 Memory budget: {} ({} trace extension)", memory_budget, prover.lde_strategy());
+    if let Some(path) = dump_trace {
+        say!("🧾 Execution trace will be saved to: {}", path);
+        prover = prover.with_prover_options(ProverOptions::default().with_trace_dump(path));
+    }
+
+    // A secret that misses the on-chain commitment yields a proof that can never mint
+    if let Some(expected_commitment) = expected_commitment {
+        let expected: [u8; 32] = hex_to_bytes(expected_commitment)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| XfgStarkError::ParseError("Expected commitment must be 32 bytes of hex".to_string()))?;
+        let computed = package.commitment_preimage()?.to_bytes();
+        if computed != expected {
+            return Err(XfgStarkError::CommitmentMismatch { expected, computed });
+        }
+        say!("✅ Secret matches the on-chain commitment");
+    }
+
+    // Generate real STARK proof
+    say!("⚡ Generating STARK proof...");
+
+    let proof = if json_output() {
+        prove_package(&package, prover, producer)?
+    } else {
+        let progress = ProgressReporter::new();
+        let spinner = spawn_progress_spinner(progress.subscribe());
+        progress.stark(StarkPhase::Proving);
+        let prover = prover.with_progress(progress.clone());
+        let proof_result = prove_package(&package, prover, producer);
+        progress.stark(match &proof_result {
+            Ok(_) => StarkPhase::Done,
+            Err(e) => StarkPhase::Failed(e.to_string()),
+        });
+        drop(progress);
+        let _ = spinner.join();
+        proof_result?
+    };
+
+    say!("✅ STARK proof generated successfully");
+    say!("📏 Proof size: {} bytes", proof.proof_data.len());
+
+    // Save proof
+    let json = serde_json::to_string_pretty(&proof)
+        .map_err(|e| XfgStarkError::JsonError(e))?;
+
+    std::fs::write(output_file, json)
+        .map_err(|e| XfgStarkError::IoError(e))?;
+
+    say!("�� Proof saved to: {}", output_file);
+    say!("🚀 Ready for submission to HEAT mint contract!");
+
+    if let Some(path) = dump_trace {
+        report = report.file("trace", path);
+    }
+    Ok(report
+        .file("proof", output_file)
+        .detail("hash_function", json!(hash_function.to_string()))
+        .detail("burn_amount_atomic", json!(package.burn_transaction.burn_amount_atomic))
+        .detail("mint_amount_atomic", json!(package.get_mint_amount_atomic()))
+        .detail("proof_size", json!(proof.proof_data.len())))
+}
+
+/// Generate proofs for a directory of data packages, exiting non-zero if any fails
+fn generate_proof_batch(input_dir: &str, output_dir: &str, config: &BatchConfig) -> Result<()> {
+    println!("🔍 Proving data packages in: {}", input_dir);
+    println!("🔑 Hash function: {}", config.hash_function);
+
+    let manifest = generate_batch(Path::new(input_dir), Path::new(output_dir), config)?;
+    for entry in &manifest.entries {
+        match (&entry.proof, &entry.error) {
+            (Some(proof), None) => println!("✅ {} → {} ({:.1} ms)", entry.package, proof, entry.elapsed_ms),
+            (_, error) => println!("❌ {}: {}", entry.package, error.as_deref().unwrap_or("not proven")),
+        }
+    }
+
+    println!("\n📊 {} proved, {} failed ({} workers)", manifest.proved, manifest.failed, manifest.workers);
+    println!("📋 Manifest saved to: {}", Path::new(output_dir).join(BATCH_MANIFEST_FILE).display());
+    if !manifest.is_success() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Show STARK generation progress on a spinner until every reporter is dropped
+fn spawn_progress_spinner(mut watcher: ProgressWatcher) -> std::thread::JoinHandle<()> {
+    std::thread::spawn(move || {
+        let spinner = indicatif::ProgressBar::new_spinner();
+        spinner.enable_steady_tick(std::time::Duration::from_millis(100));
+        spinner.set_message(watcher.latest().stark.to_string());
+
+        if let Ok(runtime) = tokio::runtime::Builder::new_current_thread().build() {
+            runtime.block_on(async {
+                while let Some(progress) = watcher.changed().await {
+                    spinner.set_message(format!("{} ({:.1?})", progress.stark, progress.elapsed()));
+                }
+            });
+        }
+        spinner.finish_and_clear();
+    })
+}
+
+/// Validate data package with enhanced Fuego blockchain validation
+fn validate_package(input_file: &str, ens_rpc: Option<&str>) -> Result<CommandReport> {
+    say!("🔍 Loading data package from: {}", input_file);
+
+    let package = load_package(input_file)?;
+
+    say!("�� Package Information:");
+    say!("   Version: {}", package.metadata.version);
+    say!("   Network: {}", package.metadata.network);
+    say!("   Created: {}", package.metadata.created_at);
+    say!("   Description: {}", package.metadata.description);
+
+    say!("\n🔥 Burn Transaction:");
+    say!("   Hash: {}", package.burn_transaction.transaction_hash);
+    say!("   Amount: {} XFG ({} atomic units)",
+             package.burn_transaction.burn_amount_xfg,
+             package.burn_transaction.burn_amount_atomic);
+    say!("   Block Height: {}", package.burn_transaction.block_height);
+    say!("   Timestamp: {}", package.burn_transaction.timestamp);
+    if package.burn_transaction.protocol_fee_bps > 0 {
+        say!("   Protocol Fee: {} bps (mint {} atomic units)",
+                 package.burn_transaction.protocol_fee_bps,
+                 package.get_mint_amount_atomic());
+    }
+
+    say!("\n👤 Recipient:");
+    say!("   Address: {}", package.recipient.ethereum_address);
+    if let Some(ref ens) = package.recipient.ens_name {
+        say!("   ENS: {}", ens);
+    }
+    if let Some(ref label) = package.recipient.label {
+        say!("   Label: {}", label);
+    }
+
+    say!("\n🔐 Secret:");
+    match &package.secret.secret_ref {
+        Some(secret_ref) => say!("   Keystore: {}", secret_ref.keystore),
+        None => say!("   Key: {}...", &package.secret.secret_key[..8.min(package.secret.secret_key.len())]),
+    }
+    if let Some(ref salt) = package.secret.salt {
+        say!("   Salt: {}", salt);
+    }
+    if let Some(ref hint) = package.secret.hint {
+        say!("   Hint: {}", hint);
+    }
+    // Commitment public input the proof will carry, for checking against a wallet
+    let proof_commitment = (!package.secret.is_locked())
+        .then(|| package.commitment_preimage().ok())
+        .flatten()
+        .map(|preimage| preimage.to_bytes());
+    if let Some(commitment) = proof_commitment {
+        say!("   Proof commitment: 0x{}", hex::encode(commitment));
+    }
+
+    say!("\n📊 Validation Results:");
+
+    let validation = match ens_rpc {
+        Some(url) => package.validate_with_ens(&EnsRpcResolver::new(url)),
+        None => package.validate(),
+    };
     if validation.is_valid {
-        println!("   ✅ Package is valid");
+        say!("   ✅ Package is valid");
     } else {
-        println!("   ❌ Package has errors:");
+        say!("   ❌ Package has errors:");
         for error in &validation.errors {
-            println!("      - {}", error);
+            say!("      - {}", error);
         }
         for warning in &validation.warnings {
-            println!("      - {}", warning);
+            say!("      - {}", warning);
         }
     }
 
     // Additional Fuego blockchain validation
-    println!("\n🔗 Fuego Blockchain Validation:");
+    say!("\n🔗 Fuego Blockchain Validation:");
     validate_fuego_transaction(&package)?;
 
-    Ok(())
+    let mut report = CommandReport::new("validate")
+        .file("package", input_file)
+        .detail("valid", json!(validation.is_valid))
+        .detail("network", json!(package.metadata.network))
+        .detail("transaction_hash", json!(package.burn_transaction.transaction_hash))
+        .detail("burn_amount_atomic", json!(package.burn_transaction.burn_amount_atomic))
+        .detail("mint_amount_atomic", json!(package.get_mint_amount_atomic()))
+        .detail("recipient", json!(package.recipient.ethereum_address))
+        .detail("proof_commitment", json!(proof_commitment));
+    report.errors = validation.errors;
+    report.warnings = validation.warnings;
+    Ok(report)
 }
 
 /// Validate Fuego blockchain transaction details
 fn validate_fuego_transaction(package: &StarkProofDataPackage) -> Result<()> {
     // Validate transaction hash format (Fuego native format - no 0x prefix)
     if package.burn_transaction.transaction_hash.starts_with("0x") {
-        println!("   ❌ Transaction hash should not have 0x prefix for Fuego");
+        say!("   ❌ Transaction hash should not have 0x prefix for Fuego");
         return Err(XfgStarkError::ParseError("Invalid Fuego transaction hash format".to_string()));
     }
 
     // Validate transaction hash length (Fuego uses 32-byte hashes, 64 hex chars)
     if package.burn_transaction.transaction_hash.len() != 64 {
-        println!("   ❌ Transaction hash should be 64 hex characters for Fuego");
+        say!("   ❌ Transaction hash should be 64 hex characters for Fuego");
         return Err(XfgStarkError::ParseError("Invalid Fuego transaction hash length".to_string()));
     }
 
     // Validate block height is after XFG burn implementation (800,000+)
     if package.burn_transaction.block_height < 800_000 {
-        println!("   ❌ Block height {} is before XFG burn implementation (800,000)", package.burn_transaction.block_height);
+        say!("   ❌ Block height {} is before XFG burn implementation (800,000)", package.burn_transaction.block_height);
         return Err(XfgStarkError::ParseError("Block height must be after XFG burn implementation (800,000+)".to_string()));
     }
 
     // Validate network ID format
     if package.burn_transaction.network_id.is_empty() {
-        println!("   ❌ Network ID is required");
+        say!("   ❌ Network ID is required");
         return Err(XfgStarkError::ParseError("Network ID cannot be empty".to_string()));
     }
 
-    println!("   ✅ Fuego blockchain validation passed");
+    say!("   ✅ Fuego blockchain validation passed");
     Ok(())
 }
 
@@ -674,24 +1621,67 @@ fn create_package(
     txn_hash: &str,
     recipient: &str,
     output_file: &str,
+    fee_bps: u32,
+    keystore_file: Option<&str>,
+    producer: Option<ProducerInfo>,
 ) -> Result<()> {
     // Parse burn amount
-    let burn_amount_f64: f64 = 0.8; // Default to standard burn
+    let burn_amount_f64 = xfg_stark::constants::atomic_units_to_xfg(xfg_stark::constants::STANDARD_BURN_ATOMIC);
 
     // Validate burn amount
-    if burn_amount_f64 != 0.8 && burn_amount_f64 != 800.0 {
-        eprintln!("❌ Burn amount must be exactly 0.8 or 800.0 XFG");
+    if !xfg_stark::constants::xfg_to_atomic_units(burn_amount_f64).is_ok_and(xfg_stark::constants::is_valid_burn_amount) {
+        eprintln!("❌ {}", xfg_stark::constants::INVALID_BURN_AMOUNT);
         std::process::exit(1);
     }
 
+    // A new keystore is created around a secret the user enters; an existing one is referenced
+    let new_secret = match keystore_file {
+        Some(keystore_file) if !Path::new(keystore_file).exists() => {
+            let secret = read_hidden("🔐 Burn secret: ")?;
+            if secret.len() < 8 {
+                return Err(XfgStarkError::ParseError("Secret key must be at least 8 characters".to_string()));
+            }
+            Some(secret)
+        }
+        _ => None,
+    };
+
     // Create package
-    let package = StarkProofDataPackage::new(
+    let mut package = StarkProofDataPackage::new_with_clock(
         burn_amount_f64,
         txn_hash.to_string(),
         recipient.to_string(),
-        "dummy_secret_key".to_string(),
+        new_secret.as_deref().map_or("dummy_secret_key", String::as_str).to_string(),
         "fuego-mainnet".to_string(),
+        clock_from_env().as_ref(),
     );
+    package.burn_transaction.protocol_fee_bps = fee_bps;
+    if let Some(keystore_file) = keystore_file {
+        let secret_ref = keystore_ref(keystore_file, output_file);
+        if new_secret.is_some() {
+            let passphrase = keystore_passphrase(keystore_file, true)?;
+            let keystore = package
+                .lock_secret(&secret_ref, &passphrase, ScryptParams::default())
+                .map_err(keystore_error)?;
+            keystore.save_to_file(keystore_file)?;
+            println!("🔐 Keystore created: {}", keystore_file);
+        } else {
+            // Check the passphrase now rather than when the proof is generated
+            let passphrase = keystore_passphrase(keystore_file, false)?;
+            Keystore::load_from_file(keystore_file)?
+                .decrypt(&passphrase)
+                .map_err(keystore_error)?;
+            package.secret.secret_key.clear();
+            package.secret.secret_ref = Some(xfg_stark::proof_data_schema::SecretRef { keystore: secret_ref });
+            println!("🔐 Using keystore: {}", keystore_file);
+        }
+    }
+    package.fee_model()?;
+    if let Some(producer) = producer {
+        package = package
+            .with_producer(producer)
+            .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+    }
 
     // Save package
     package.save_to_file(output_file)?;
@@ -699,9 +1689,15 @@ fn create_package(
     println!("📦 Data package created: {}", output_file);
     println!("🔥 Burn amount: {} XFG", burn_amount_f64);
     println!("🎯 Mint amount: {} HEAT", package.get_mint_amount_heat());
+    if fee_bps > 0 {
+        println!("💸 Protocol fee: {} bps", fee_bps);
+    }
     println!("�� Transaction: {}", txn_hash);
     println!("👤 Recipient: {}", recipient);
     println!("🌐 Network: fuego-mainnet");
+    if keystore_file.is_none() {
+        println!("⚠️  The burn secret is stored in plaintext; use --keystore to encrypt it");
+    }
 
     println!("\n💡 Next steps:");
     println!("   1. Edit {} to add block height and timestamp", output_file);
@@ -711,6 +1707,552 @@ fn create_package(
     Ok(())
 }
 
+/// Register a signing key on a data package and sign it
+fn add_signing_key(package_file: &str, key_file: &str, key_id: &str) -> Result<()> {
+    let mut package = StarkProofDataPackage::load_from_file(package_file)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+    let signing_key = load_signing_key(key_file)?;
+    let now = chrono::Utc::now().timestamp() as u64;
+
+    package.signatures.add_key(key_id, &signing_key.verifying_key(), now)
+        .map_err(signing_error)?;
+    package.sign_package(key_id, &signing_key, now)
+        .map_err(signing_error)?;
+    package.save_to_file(package_file)?;
+
+    println!("🔑 Signing key added: {}", key_id);
+    println!("✍️  Package signed: {}", package_file);
+
+    Ok(())
+}
+
+/// Retire a signing key and sign the data package with its replacement
+fn rotate_signing_key(package_file: &str, old_key_id: &str, key_file: &str, key_id: &str) -> Result<()> {
+    let mut package = StarkProofDataPackage::load_from_file(package_file)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+    let signing_key = load_signing_key(key_file)?;
+    let now = chrono::Utc::now().timestamp() as u64;
+
+    package.signatures.rotate_key(old_key_id, key_id, &signing_key.verifying_key(), now)
+        .map_err(signing_error)?;
+    package.sign_package(key_id, &signing_key, now)
+        .map_err(signing_error)?;
+    package.save_to_file(package_file)?;
+
+    println!("🔄 Signing key rotated: {} → {}", old_key_id, key_id);
+    println!("✍️  Package signed: {}", package_file);
+
+    Ok(())
+}
+
+/// Verify the signatures on a data package against the trusted key records in `trusted_keys_file`
+fn verify_package_signatures(package_file: &str, trusted_keys_file: &str) -> Result<()> {
+    let package = load_package(package_file)?;
+    let records: Vec<SigningKeyRecord> = serde_json::from_str(&std::fs::read_to_string(trusted_keys_file)?)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+    let trusted_keys = TrustedSigningKeys::new(records).map_err(signing_error)?;
+
+    let report = package.verify_signatures(&trusted_keys);
+    for key_id in &report.valid_key_ids {
+        println!("   ✅ Valid signature from key: {}", key_id);
+    }
+    for (key_id, reason) in &report.rejected {
+        println!("   ❌ Rejected signature from key {}: {}", key_id, reason);
+    }
+
+    if !report.is_valid() {
+        return Err(XfgStarkError::CryptoError("No valid package signatures".to_string()));
+    }
+
+    println!("🔐 Package signatures verified");
+    Ok(())
+}
+
+/// Encrypt a proof package to the recipient's public key
+fn encrypt_package(package_file: &str, recipient_key: &str, output_file: &str) -> Result<()> {
+    let package = CompleteProofPackage::load_from_file(package_file)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+    let recipient_key = parse_recipient_public_key(recipient_key)
+        .map_err(encryption_error)?;
+
+    let envelope = package.encrypt_for_recipient(&recipient_key)
+        .map_err(encryption_error)?;
+    envelope.save_to_file(output_file)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+
+    println!("🔒 Package encrypted for {}", package.stark_proof_data.recipient.ethereum_address);
+    println!("💾 Envelope saved to: {}", output_file);
+    Ok(())
+}
+
+/// Decrypt a recipient-encrypted proof package
+fn decrypt_package(envelope_file: &str, key_file: &str, output_file: &str) -> Result<()> {
+    let envelope = PackageEnvelope::load_from_file(envelope_file)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+    let contents = std::fs::read_to_string(key_file)
+        .map_err(XfgStarkError::IoError)?;
+    let secret_key = parse_recipient_secret_key(&contents)
+        .map_err(encryption_error)?;
+
+    let package = envelope.decrypt_with_key(&secret_key)
+        .map_err(encryption_error)?;
+    package.save_to_file(output_file)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+
+    println!("🔓 Package decrypted");
+    println!("💾 Package saved to: {}", output_file);
+    Ok(())
+}
+
+/// Audit a package's commitment and burn amount against the burn transaction on chain
+fn audit_commitment(package_file: &str, rpc_url: &str) -> Result<()> {
+    println!("\n🔍 Commitment Audit");
+    println!("===================");
+    println!("📋 Loading package from: {}", package_file);
+
+    let package = load_package(package_file)?;
+
+    println!("🔥 Burn transaction: {}", package.burn_transaction.transaction_hash);
+    println!("🌐 Fetching transaction from: {}", rpc_url);
+
+    let rpc = FuegoRpcClient::new(rpc_url);
+    let transaction = rpc
+        .get_transaction(&package.burn_transaction.transaction_hash)
+        .map_err(|e| XfgStarkError::CryptoError(e.to_string()))?;
+    let report = audit_commitment_with_tx_extra(&package, &transaction.extra).map_err(audit_error)?;
+    let burn_amount = transaction
+        .burn_amount()
+        .map_err(|e| XfgStarkError::CryptoError(e.to_string()))?;
+
+    println!("   Computed: {}", hex::encode(report.computed));
+    println!("   On-chain: {}", hex::encode(report.on_chain));
+
+    if !report.is_match() {
+        println!("❌ Commitment mismatch at {} byte(s):", report.mismatched_bytes.len());
+        for &offset in &report.mismatched_bytes {
+            println!(
+                "   byte {:2}: computed 0x{:02x}, on-chain 0x{:02x}",
+                offset, report.computed[offset], report.on_chain[offset]
+            );
+        }
+        return Err(XfgStarkError::CryptoError("Commitment does not match tx_extra".to_string()));
+    }
+
+    println!("✅ Commitment matches tx_extra");
+
+    println!("   Claimed burn: {} atomic units", package.burn_transaction.burn_amount_atomic);
+    println!("   On-chain burn: {} atomic units", burn_amount);
+    if burn_amount != package.burn_transaction.burn_amount_atomic {
+        return Err(XfgStarkError::CryptoError("Burn amount does not match the burn outputs".to_string()));
+    }
+
+    println!("✅ Burn amount matches the burn outputs");
+    Ok(())
+}
+
+/// Print the public inputs and metadata of a proof file
+fn inspect_proof(proof_file: &str) -> Result<()> {
+    let proof = load_proof(proof_file)?;
+
+    println!("\n🔍 Proof: {}", proof_file);
+    println!("📏 Size: {} bytes", proof.proof_data.len());
+    println!("📊 Public Inputs:");
+    println!("   Burn amount: {}", proof.public_inputs.burn_amount);
+    println!("   Mint amount: {}", proof.public_inputs.mint_amount);
+    println!("   Transaction: {}", proof.public_inputs.txn_hash);
+    println!("   Recipient binding: {}", proof.public_inputs.recipient_hash);
+    println!("📋 Metadata:");
+    println!("   Version: {}", proof.metadata.version);
+    println!("   Created: {}", proof.metadata.created_at);
+    println!("   Network: {}", proof.metadata.network);
+    println!("   Hash function: {}", proof.metadata.hash_function);
+    if let Some(producer) = &proof.metadata.producer {
+        println!("   Producer: {}", producer);
+    }
+    print_build_info(&proof.metadata);
+
+    Ok(())
+}
+
+/// Verify a proof file against its data package and print a pass/fail report
+///
+/// Public inputs are rebuilt from the package, so a proof for a different burn,
+/// recipient or secret fails even if it verifies on its own.
+fn verify_proof_file(package_file: &str, proof_file: &str) -> Result<bool> {
+    let package = load_unlocked_package(package_file)?;
+    let proof = load_proof(proof_file)?;
+
+    println!("\n🔍 Proof: {}", proof_file);
+    println!("📦 Package: {}", package_file);
+    println!("📊 Claim:");
+    println!("   Burn amount: {} atomic units", package.burn_transaction.burn_amount_atomic);
+    println!("   Mint amount: {} atomic units", package.get_mint_amount_atomic());
+    println!("   Transaction: {}", package.burn_transaction.transaction_hash);
+    println!("   Recipient: {}", package.recipient.ethereum_address);
+    println!("   Hash function: {}", proof.metadata.hash_function);
+
+    let verifier = XfgBurnMintVerifier::default()
+        .with_fee_model(package.fee_model()?)
+        .with_hash_function(proof.metadata.hash_function);
+    let mut complete = CompleteProofPackage::new(package);
+    complete.add_stark_proof(proof);
+
+    let failure = match verifier.verify_package(&complete) {
+        Ok(report) => {
+            println!("🔐 STARK:");
+            println!("   Size: {} bytes (sha256 {})", report.proof_size, hex::encode(report.proof_digest));
+            println!("   Trace length: {}", report.trace_length);
+            println!("   Security: {} bits", report.security_bits);
+            println!("   Verification time: {:.1?}", report.verification_time);
+            report.error
+        }
+        Err(e) => Some(e.to_string()),
+    };
+
+    match failure {
+        None => {
+            println!("\n✅ Proof is VALID");
+            println!("🚀 Ready for submission to HEAT mint contract!");
+            Ok(true)
+        }
+        Some(reason) => {
+            println!("\n❌ Proof is INVALID");
+            println!("   Reason: {}", reason);
+            Ok(false)
+        }
+    }
+}
+
+/// Export `claimHEAT` calldata, or its EIP-712 typed payload, for a proven package
+/// Build the `claimHEAT` call of a proven package
+fn load_claim_call(package_file: &str, eldernode_proof: Option<&str>) -> Result<ClaimCall> {
+    let mut package = CompleteProofPackage::load_from_file(package_file)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+    unlock_package_secret(&mut package.stark_proof_data, package_file)?;
+    let eldernode_proof = eldernode_proof
+        .map(hex_to_bytes)
+        .transpose()
+        .map_err(|e| XfgStarkError::ParseError(format!("Invalid Eldernode proof: {}", e)))?
+        .unwrap_or_default();
+    Ok(ClaimCall::from_package(&package, eldernode_proof)?)
+}
+
+fn export_calldata(
+    package_file: &str,
+    output_file: &str,
+    eldernode_proof: Option<&str>,
+    domain: Option<&Eip712Domain>,
+) -> Result<()> {
+    let call = load_claim_call(package_file, eldernode_proof)?;
+    let calldata = call.calldata();
+
+    println!("📦 Package: {}", package_file);
+    println!("👤 Recipient: {}", call.recipient);
+    println!("🔥 Burn tier: {}", if call.is_large_burn { "large (800 XFG)" } else { "standard (0.8 XFG)" });
+    println!("📏 Calldata size: {} bytes", calldata.len());
+    if call.eldernode_proof.is_empty() {
+        println!("⚠️  No Eldernode proof: the contract must not require Eldernode consensus");
+    }
+
+    match domain {
+        Some(domain) => {
+            let typed_data = serde_json::to_string_pretty(&call.typed_data(domain))
+                .map_err(XfgStarkError::JsonError)?;
+            std::fs::write(output_file, typed_data)
+                .map_err(XfgStarkError::IoError)?;
+            println!("✍️  EIP-712 digest: 0x{}", hex::encode(call.eip712_digest(domain)));
+            println!("   Check your hardware wallet shows this digest before signing");
+            println!("💾 Typed data saved to: {}", output_file);
+        }
+        None => {
+            std::fs::write(output_file, format!("0x{}\n", hex::encode(&calldata)))
+                .map_err(XfgStarkError::IoError)?;
+            println!("💾 Calldata saved to: {}", output_file);
+        }
+    }
+    println!("🔒 The calldata carries your burn secret; keep it private until you submit it");
+
+    Ok(())
+}
+
+/// Print the build information recorded in proof metadata
+fn print_build_info(metadata: &xfg_stark::proof_data_schema::ProofMetadata) {
+    match &metadata.build_info {
+        Some(info) => {
+            println!("🏗️  Built by: xfg-stark {} ({})", info.crate_version, info.target_triple);
+            println!("   Features: {}", if info.features.is_empty() { "none".to_string() } else { info.features.join(", ") });
+            println!("   Lockfile: {}", info.lockfile_digest.as_deref().unwrap_or("unknown"));
+        }
+        None => println!("🏗️  Built by: unknown (no build info recorded)"),
+    }
+}
+
+/// Render a narrative report for a verified proof package
+fn report_package(package_file: &str, format: &str, output_file: Option<&str>) -> Result<()> {
+    let package = CompleteProofPackage::load_from_file(package_file)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+    let format: ReportFormat = format.parse()
+        .map_err(|e: ReportError| XfgStarkError::ParseError(e.to_string()))?;
+
+    let report = generate_report(&package, format)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+
+    match output_file {
+        Some(output_file) => {
+            std::fs::write(output_file, report)
+                .map_err(XfgStarkError::IoError)?;
+            println!("📄 {} report saved to: {}", format, output_file);
+        }
+        None => println!("{}", report),
+    }
+
+    Ok(())
+}
+
+/// Collect a sanitized diagnostics bundle for a package
+fn bug_report(package_file: &str, output_file: &str) -> Result<()> {
+    let package = CompleteProofPackage::load_from_file(package_file)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+
+    let report = BugReport::collect(&package)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+    report.save_to_file(output_file)
+        .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+
+    println!("🐞 Bug report saved to: {}", output_file);
+    match &report.verification.error {
+        Some(error) => println!("   Verification: failed - {}", error),
+        None => println!("   Verification: passed"),
+    }
+    if !report.redacted_fields.is_empty() {
+        println!("   Redacted {} field(s) containing secrets:", report.redacted_fields.len());
+        for field in &report.redacted_fields {
+            println!("   - {}", field);
+        }
+    }
+    println!("   Secrets and proof bytes are stripped; the bundle is safe to attach to an issue.");
+
+    Ok(())
+}
+
+/// Store a package or proof file, validating it parses as the given kind
+fn store_put(store: &dyn PackageStore, kind: StoredKind, input_file: &str) -> Result<()> {
+    let digest = match kind {
+        StoredKind::Package => {
+            let package = StarkProofDataPackage::load_from_file(input_file)
+                .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+            store.put_package(&package).map_err(store_error)?
+        }
+        StoredKind::Proof => {
+            let json = std::fs::read_to_string(input_file)
+                .map_err(XfgStarkError::IoError)?;
+            let proof: StarkProof = serde_json::from_str(&json)
+                .map_err(XfgStarkError::JsonError)?;
+            store.put_proof(&proof).map_err(store_error)?
+        }
+    };
+
+    println!("✅ Stored {} {}", kind, digest);
+    Ok(())
+}
+
+/// Fetch a package or proof into a file
+fn store_get(store: &dyn PackageStore, kind: StoredKind, lookup: &StoreLookup, output_file: &str) -> Result<()> {
+    let bytes = store.get(kind, lookup).map_err(store_error)?
+        .ok_or_else(|| XfgStarkError::ParseError(format!("No {} found for {:?}", kind, lookup)))?;
+
+    std::fs::write(output_file, bytes)
+        .map_err(XfgStarkError::IoError)?;
+
+    println!("✅ Saved {} to: {}", kind, output_file);
+    Ok(())
+}
+
+/// List stored packages or proofs
+fn store_list(store: &dyn PackageStore, kind: StoredKind) -> Result<()> {
+    let entries = store.list(kind).map_err(store_error)?;
+    println!("📦 {} stored {}(s)", entries.len(), kind);
+    for entry in entries {
+        println!("   {}  {}", entry.tx_hash, entry.digest);
+    }
+    Ok(())
+}
+
+/// Run the streaming verification server until it fails
+fn serve(bind: &str, config: ServerConfig, tenants_file: Option<&str>) -> Result<()> {
+    let mut server = VerificationServer::new(XfgBurnMintVerifier::default(), config);
+    let config = server.config();
+    if let Some(path) = tenants_file {
+        let tenants = TenantRegistry::load(Path::new(path))
+            .map_err(|e| XfgStarkError::ParseError(e.to_string()))?;
+        server = server.with_tenants(tenants);
+    }
+
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(XfgStarkError::IoError)?;
+    runtime.block_on(async {
+        let listener = tokio::net::TcpListener::bind(bind).await?;
+        println!("🛰️  Verification server listening on {}", listener.local_addr()?);
+        println!("   Workers: {}, queue limit: {} (further submissions get 429)", config.workers, config.max_pending);
+        let tenants = server.tenant_metrics();
+        if !tenants.is_empty() {
+            let names: Vec<_> = tenants.iter().map(|t| t.tenant.as_str()).collect();
+            println!("   Tenants: {}", names.join(", "));
+        }
+        server.serve(listener).await
+    })
+    .map_err(XfgStarkError::IoError)
+}
+
+/// Generate and verify synthetic proofs and print the capacity summary
+fn stress(config: StressConfig, format: &str) -> Result<()> {
+    if format == "text" {
+        println!("🏋️  Stress test: {} proofs across {} worker(s)", config.proofs, config.parallelism);
+    }
+
+    let summary = run_stress(&config);
+    match format {
+        "json" => println!("{}", serde_json::to_string_pretty(&summary)
+            .map_err(XfgStarkError::JsonError)?),
+        _ => println!("{}", summary),
+    }
+
+    if summary.failures > 0 {
+        return Err(XfgStarkError::CryptoError(format!("{} of {} proofs failed", summary.failures, summary.proofs)));
+    }
+    Ok(())
+}
+
+fn store_error(e: StoreError) -> XfgStarkError {
+    XfgStarkError::BoxError(Box::new(e))
+}
+
+fn audit_error(e: AuditError) -> XfgStarkError {
+    XfgStarkError::CryptoError(e.to_string())
+}
+
+/// Load an Ed25519 signing key from a file containing a hex-encoded seed
+fn load_signing_key(key_file: &str) -> Result<ed25519_dalek::SigningKey> {
+    let contents = std::fs::read_to_string(key_file)
+        .map_err(XfgStarkError::IoError)?;
+    let seed = hex_to_bytes(contents.trim())
+        .map_err(|e| XfgStarkError::ParseError(format!("Invalid signing key hex: {}", e)))?;
+    let seed: [u8; 32] = seed.try_into()
+        .map_err(|_| XfgStarkError::ParseError("Signing key must be 32 bytes".to_string()))?;
+    Ok(ed25519_dalek::SigningKey::from_bytes(&seed))
+}
+
+fn signing_error(e: SigningError) -> XfgStarkError {
+    XfgStarkError::CryptoError(e.to_string())
+}
+
+fn encryption_error(e: EncryptionError) -> XfgStarkError {
+    XfgStarkError::CryptoError(e.to_string())
+}
+
+fn keystore_error(e: KeystoreError) -> XfgStarkError {
+    XfgStarkError::CryptoError(e.to_string())
+}
+
+/// Environment variable supplying the keystore passphrase to non-interactive runs
+const KEYSTORE_PASSPHRASE_ENV: &str = "XFG_KEYSTORE_PASSPHRASE";
+
+/// Read a secret value without echoing it, or a line of stdin when it is not a terminal
+fn read_hidden(prompt: &str) -> Result<Zeroizing<String>> {
+    let value = if io::stdin().is_terminal() {
+        rpassword::prompt_password(prompt)?
+    } else {
+        let mut line = String::new();
+        io::stdin().lock().read_line(&mut line)?;
+        line.truncate(line.trim_end_matches(['\r', '\n']).len());
+        line
+    };
+    Ok(Zeroizing::new(value))
+}
+
+/// Passphrase for `keystore_file` from `XFG_KEYSTORE_PASSPHRASE` or a prompt
+///
+/// With `confirm`, an interactive passphrase must be entered twice.
+fn keystore_passphrase(keystore_file: &str, confirm: bool) -> Result<Zeroizing<String>> {
+    if let Ok(passphrase) = std::env::var(KEYSTORE_PASSPHRASE_ENV) {
+        return Ok(Zeroizing::new(passphrase));
+    }
+    let passphrase = read_hidden(&format!("🔑 Passphrase for {}: ", keystore_file))?;
+    if passphrase.is_empty() {
+        return Err(XfgStarkError::ParseError("Keystore passphrase must not be empty".to_string()));
+    }
+    if confirm && io::stdin().is_terminal() && *read_hidden("🔑 Confirm passphrase: ")? != *passphrase {
+        return Err(XfgStarkError::ParseError("Passphrases do not match".to_string()));
+    }
+    Ok(passphrase)
+}
+
+/// Keystore reference for a package saved to `package_file`
+///
+/// Keystores beside the package are referenced by file name so the pair can be moved
+/// together; others by absolute path.
+fn keystore_ref(keystore_file: &str, package_file: &str) -> String {
+    let keystore = Path::new(keystore_file);
+    let same_dir = keystore.parent().map(|dir| dir.as_os_str().to_os_string())
+        == Path::new(package_file).parent().map(|dir| dir.as_os_str().to_os_string());
+    match keystore.file_name() {
+        Some(name) if same_dir => name.to_string_lossy().into_owned(),
+        _ => std::path::absolute(keystore)
+            .map_or_else(|_| keystore_file.to_string(), |path| path.display().to_string()),
+    }
+}
+
+/// Restore a package's secret from its keystore, prompting for the passphrase
+fn unlock_package_secret(package: &mut StarkProofDataPackage, package_file: &str) -> Result<()> {
+    if let Some(secret_ref) = &package.secret.secret_ref {
+        let passphrase = keystore_passphrase(&secret_ref.keystore, false)?;
+        package
+            .unlock_secret(Path::new(package_file), &passphrase)
+            .map_err(keystore_error)?;
+    }
+    Ok(())
+}
+
+/// Load a data package, unlocking its secret if it is kept in a keystore
+///
+/// Relative keystore references of a stored package resolve against the working directory.
+fn load_unlocked_package(package_file: &str) -> Result<StarkProofDataPackage> {
+    let mut package = load_package(package_file)?;
+    let keystore_base = if StoreRef::parse(package_file).is_some() { "" } else { package_file };
+    unlock_package_secret(&mut package, keystore_base)?;
+    Ok(package)
+}
+
+/// Load a data package from a file, or from a store reference `<store-uri>#<tx-hash>`
+fn load_package(source: &str) -> Result<StarkProofDataPackage> {
+    match load_stored(source, StoredKind::Package)? {
+        Some(bytes) => serde_json::from_slice(&bytes).map_err(XfgStarkError::JsonError),
+        None => StarkProofDataPackage::load_from_file(source)
+            .map_err(|e| XfgStarkError::ParseError(e.to_string())),
+    }
+}
+
+/// Load a proof from a file, or from a store reference `<store-uri>#<tx-hash>`
+fn load_proof(source: &str) -> Result<StarkProof> {
+    let json = match load_stored(source, StoredKind::Proof)? {
+        Some(bytes) => bytes,
+        None => std::fs::read(source).map_err(XfgStarkError::IoError)?,
+    };
+    serde_json::from_slice(&json).map_err(XfgStarkError::JsonError)
+}
+
+/// Fetch the object a store reference points at, or `None` if `source` is a plain path
+fn load_stored(source: &str, kind: StoredKind) -> Result<Option<Vec<u8>>> {
+    let Some(store_ref) = StoreRef::parse(source) else {
+        return Ok(None);
+    };
+    store_ref
+        .get(kind)
+        .map_err(store_error)?
+        .map(Some)
+        .ok_or_else(|| XfgStarkError::ParseError(format!("No {} found in store for {}", kind, source)))
+}
+
 // Helper functions for hex conversion
 fn hex_to_bytes(hex: &str) -> std::result::Result<Vec<u8>, hex::FromHexError> {
     // Remove 0x prefix if present
@@ -722,78 +2264,139 @@ fn hex_to_bytes(hex: &str) -> std::result::Result<Vec<u8>, hex::FromHexError> {
     hex::decode(hex_clean)
 }
 
-fn hex_to_u64(hex: &str) -> Result<u64> {
-    let bytes = hex_to_bytes(hex)
-        .map_err(|e| XfgStarkError::ParseError(format!("Invalid hex string: {}", e)))?;
-    
-    if bytes.len() < 8 {
-        return Err(XfgStarkError::ParseError("Hex string too short for u64".to_string()));
+// Helper functions for gas estimation and network status
+/// Format a wei amount in a larger unit (9 decimals for gwei, 18 for ETH)
+fn format_units(wei: u128, decimals: u32) -> String {
+    let scale = 10u128.pow(decimals);
+    let fraction = format!("{:0width$}", wei % scale, width = decimals as usize);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        format!("{}", wei / scale)
+    } else {
+        format!("{}.{}", wei / scale, fraction)
     }
-    
-    let mut u64_bytes = [0u8; 8];
-    u64_bytes.copy_from_slice(&bytes[0..8]);
-    Ok(u64::from_le_bytes(u64_bytes))
 }
 
-// Helper functions for gas estimation and network status
-fn estimate_gas_fees(recipient: &str, _verbose: bool) -> Result<()> {
-    println!("🔍 Estimating L1 gas fees for HEAT minting...");
-    println!("📧 Recipient: {}", recipient);
-    println!();
-    println!("💰 Estimated Gas Costs:");
-    println!("   • Base transaction: ~21,000 gas");
-    println!("   • STARK proof verification: ~500,000 gas");
-    println!("   • HEAT token minting: ~100,000 gas");
-    println!("   • Total estimated: ~621,000 gas");
-    println!();
-    println!("💡 Current gas prices:");
-    println!("   • Sepolia testnet: ~1-5 gwei");
-    println!("   • Mainnet: ~10-50 gwei");
-    println!();
-    println!("⚠️  Important:");
-    println!("   • Add 20% buffer for safety");
-    println!("   • Insufficient gas will cause transaction to fail");
-    println!("   • Failed transactions require restarting the entire process");
-    println!();
-    println!("💸 Recommended ETH amounts:");
-    println!("   • Sepolia: 0.001 ETH (with buffer)");
-    println!("   • Mainnet: 0.05 ETH (with buffer)");
-    Ok(())
+fn estimate_gas_fees(
+    package_file: &str,
+    rpc_url: &str,
+    network: &str,
+    networks_file: Option<&str>,
+    eldernode_proof: Option<&str>,
+    buffer_percent: u32,
+) -> Result<CommandReport> {
+    let mut registry = NetworkRegistry::builtin();
+    if let Some(path) = networks_file {
+        registry.load_toml(path)?;
+    }
+    let params = registry.require(network)?;
+    let verifier = params.burn_verifier()?;
+    let call = load_claim_call(package_file, eldernode_proof)?;
+
+    say!("🔍 Estimating gas for claiming HEAT on {}...", params.display_name);
+    say!("📦 Package: {}", package_file);
+    say!("👤 Recipient: {}", call.recipient);
+    say!("📜 Burn verifier: {}", verifier);
+    say!();
+
+    let estimate = GasEstimator::new(rpc_url)
+        .with_chain_id(params.target_chain_id.into())
+        .with_buffer_percent(buffer_percent)
+        .estimate_claim(verifier, &call)?;
+
+    say!("💰 Simulated claim:");
+    say!("   • Gas used: {}", estimate.gas_limit);
+    say!("   • Gas limit (+{}%): {}", estimate.buffer_percent, estimate.buffered_gas_limit());
+    say!();
+    say!("⛽ Current fees:");
+    say!("   • Base fee: {} gwei", format_units(estimate.base_fee_per_gas, 9));
+    say!("   • Priority fee: {} gwei", format_units(estimate.max_priority_fee_per_gas, 9));
+    say!("   • Max fee: {} gwei", format_units(estimate.max_fee_per_gas(), 9));
+    say!();
+    say!("💸 Cost:");
+    say!("   • Expected: {} ETH", format_units(estimate.expected_cost_wei(), 18));
+    say!("   • At most: {} ETH", format_units(estimate.max_cost_wei(), 18));
+    say!();
+    say!("⚠️  Fees move with every block; estimate again right before submitting");
+
+    Ok(CommandReport::new("estimate-gas")
+        .detail("network", json!(params.name))
+        .detail("chain_id", json!(estimate.chain_id))
+        .detail("recipient", json!(call.recipient.to_string()))
+        .detail("burn_verifier", json!(verifier.to_string()))
+        .detail("gas", json!({
+            "estimated": estimate.gas_limit,
+            "limit": estimate.buffered_gas_limit(),
+            "buffer_percent": estimate.buffer_percent,
+        }))
+        .detail("fees_wei", json!({
+            "base_fee_per_gas": estimate.base_fee_per_gas.to_string(),
+            "max_priority_fee_per_gas": estimate.max_priority_fee_per_gas.to_string(),
+            "max_fee_per_gas": estimate.max_fee_per_gas().to_string(),
+        }))
+        .detail("cost_wei", json!({
+            "expected": estimate.expected_cost_wei().to_string(),
+            "max": estimate.max_cost_wei().to_string(),
+        })))
 }
 
-fn check_network_status(network: &str) -> Result<()> {
-    println!("🌐 Checking {} network status...", network);
-    println!();
-    
-    match network.to_lowercase().as_str() {
-        "sepolia" => {
-            println!("🔗 Sepolia Testnet:");
-            println!("   • HEAT Token: 0x1234567890123456789012345678901234567890");
-            println!("   • Burn Verifier: 0xabcdefabcdefabcdefabcdefabcdefabcdefabcd");
-            println!("   • Eldernode Verifier: 0xfedcbafedcbafedcbafedcbafedcbafedcbafedc");
-            println!("   • Status: ✅ Active");
-            println!("   • Gas Price: ~1-5 gwei");
-        },
-        "mainnet" => {
-            println!("🔗 Ethereum Mainnet:");
-            println!("   • HEAT Token: 0x9876543210987654321098765432109876543210");
-            println!("   • Burn Verifier: 0xdcbadcbadcbadcbadcbadcbadcbadcbadcbadcba");
-            println!("   • Eldernode Verifier: 0xabcdefabcdefabcdefabcdefabcdefabcdefabcd");
-            println!("   • Status: ✅ Active");
-            println!("   • Gas Price: ~10-50 gwei");
-        },
-        _ => {
-            println!("❌ Unknown network: {}", network);
-            println!("   Supported networks: sepolia, mainnet");
+/// Typical gas prices on the built-in networks
+const GAS_PRICE_HINTS: &[(&str, &str)] = &[("sepolia", "~1-5 gwei"), ("mainnet", "~10-50 gwei")];
+
+fn check_network_status(network: &str, networks_file: Option<&str>) -> Result<CommandReport> {
+    say!("🌐 Checking {} network status...", network);
+    say!();
+
+    let mut registry = NetworkRegistry::builtin();
+    if let Some(path) = networks_file {
+        registry.load_toml(path)?;
+    }
+
+    let mut report = CommandReport::new("check-network").detail("network", json!(network));
+    match registry.require(network) {
+        Ok(params) => {
+            let address = |contract: &Option<EthAddress>| contract.as_ref().map(|address| address.to_string());
+            let contracts = &params.contracts;
+            say!("🔗 {}:", params.display_name);
+            say!("   • Fuego network: {}", params.fuego_network_id);
+            say!("   • Target chain: {}", params.target_chain_id);
+            say!("   • Commitment version: {}", params.commitment_version);
+            for (label, contract) in [
+                ("HEAT Token", &contracts.heat_token),
+                ("Burn Verifier", &contracts.burn_verifier),
+                ("Eldernode Verifier", &contracts.eldernode_verifier),
+            ] {
+                say!("   • {}: {}", label, address(contract).unwrap_or_else(|| "not deployed".to_string()));
+            }
+            say!("   • Status: ✅ Active");
+            report = report
+                .detail("active", json!(true))
+                .detail("fuego_network_id", json!(params.fuego_network_id))
+                .detail("target_chain_id", json!(params.target_chain_id))
+                .detail("commitment_version", json!(params.commitment_version))
+                .detail("domain_separator", json!(hex::encode(params.domain_separator())))
+                .detail("contracts", json!({
+                    "heat_token": address(&contracts.heat_token),
+                    "burn_verifier": address(&contracts.burn_verifier),
+                    "eldernode_verifier": address(&contracts.eldernode_verifier),
+                }));
+            if let Some((_, gas_price)) = GAS_PRICE_HINTS.iter().find(|(key, _)| params.name == *key) {
+                say!("   • Gas Price: {}", gas_price);
+                report = report.detail("gas_price", json!(gas_price));
+            }
+        }
+        Err(e) => {
+            say!("❌ {}", e);
+            report.errors.push(e.to_string());
         }
     }
-    
-    println!();
-    println!("📊 Network Info:");
-    println!("   • Block time: ~12 seconds");
-    println!("   • Confirmation time: ~1-2 minutes");
-    println!("   • Cross-chain messaging: Arbitrum L2→L1");
-    Ok(())
+
+    say!();
+    say!("📊 Network Info:");
+    say!("   • Block time: ~12 seconds");
+    say!("   • Confirmation time: ~1-2 minutes");
+    say!("   • Cross-chain messaging: Arbitrum L2→L1");
+    Ok(report)
 }
 
 // ASCII Art and Branding Functions
@@ -1,7 +1,7 @@
 
 
 // STARK generation inputs structure
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 struct StarkGenerationInputs {
     secret: Vec<u8>,
     burn_amount: u64,
@@ -14,6 +14,22 @@ struct StarkGenerationInputs {
     domain_separator: String,
 }
 
+impl std::fmt::Debug for StarkGenerationInputs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StarkGenerationInputs")
+            .field("secret", &xfg_stark::types::secret::Redacted::bytes(self.secret.len()))
+            .field("burn_amount", &self.burn_amount)
+            .field("mint_amount", &self.mint_amount)
+            .field("tx_prefix_hash", &hex::encode(self.tx_prefix_hash))
+            .field("recipient_hash", &hex::encode(&self.recipient_hash))
+            .field("network_id", &self.network_id)
+            .field("target_chain_id", &self.target_chain_id)
+            .field("commitment_version", &self.commitment_version)
+            .field("domain_separator", &self.domain_separator)
+            .finish()
+    }
+}
+
 // Eldernode verification inputs (simplified - only transaction-related)
 #[derive(Debug, Clone)]
 struct EldernodeVerificationInputs {
@@ -0,0 +1,423 @@
+//! Sanitized Diagnostics Bundles for Bug Reports
+//!
+//! Users reporting proof failures tend to paste whole packages, secret included.
+//! [`BugReport::collect`] gathers what a maintainer needs into a single JSON bundle
+//! that is safe to attach to an issue:
+//!
+//! - the complete package with its secret key, salt and hint replaced, and any other
+//!   field containing one of the secrets redacted (listed in `redacted_fields`)
+//! - digests of the proof bytes, which are dropped from the package
+//! - the proof parameters and the registry protocol version they belong to
+//! - the verification outcome, computed before the secret is stripped
+//! - build information, compiled features and an environment summary
+
+use crate::{
+    build_info::BuildInfo,
+    burn_mint_air::FinalStatePolicy,
+    burn_mint_verifier::XfgBurnMintVerifier,
+    clock::{Clock, SystemClock},
+    constants::COMMITMENT_VERSION,
+    features::FeatureSet,
+    parameter_registry::{ParameterRegistry, BURN_MINT_STATEMENT_ID},
+    proof_data_schema::CompleteProofPackage,
+    statements::HashFunction,
+    types::secret::Redacted,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use thiserror::Error;
+use winterfell::StarkProof;
+
+/// Version of the bundle layout
+pub const BUG_REPORT_FORMAT_VERSION: u32 = 1;
+
+/// Secrets shorter than this are not searched for; they would redact unrelated fields
+const MIN_SECRET_LEN: usize = 4;
+
+/// Sanitized diagnostics bundle for a proof package
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BugReport {
+    /// Version of the bundle layout
+    pub format_version: u32,
+    /// When the bundle was collected (RFC 3339)
+    pub created_at: String,
+    /// Build that collected the bundle
+    pub build: BuildInfo,
+    /// Optional subsystems compiled into that build
+    pub features: String,
+    /// Host the bundle was collected on
+    pub environment: EnvironmentSummary,
+    /// Package with secrets and proof bytes stripped
+    pub package: Value,
+    /// JSON paths of package fields redacted because they contained a secret
+    pub redacted_fields: Vec<String>,
+    /// Digests of the proof bytes, if the package carries a proof
+    pub proof: Option<ProofDigests>,
+    /// Parameters decoded from the proof, if it decodes
+    pub parameters: Option<ParameterSnapshot>,
+    /// Outcome of verifying the package
+    pub verification: VerificationSummary,
+}
+
+/// Host summary
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvironmentSummary {
+    /// Operating system
+    pub os: String,
+    /// CPU architecture
+    pub arch: String,
+    /// Operating system family
+    pub family: String,
+    /// Available parallelism (threads)
+    pub cpus: usize,
+}
+
+impl EnvironmentSummary {
+    /// Summary of the running host
+    #[must_use]
+    pub fn current() -> Self {
+        Self {
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            family: std::env::consts::FAMILY.to_string(),
+            cpus: std::thread::available_parallelism().map_or(1, std::num::NonZero::get),
+        }
+    }
+}
+
+/// Digests identifying a proof without including it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofDigests {
+    /// Size of the proof (bytes)
+    pub size: usize,
+    /// SHA-256 of the proof bytes (hex)
+    pub sha256: String,
+    /// BLAKE3 of the proof bytes (hex)
+    pub blake3: String,
+}
+
+impl ProofDigests {
+    /// Digest proof bytes
+    #[must_use]
+    pub fn of(proof_bytes: &[u8]) -> Self {
+        Self {
+            size: proof_bytes.len(),
+            sha256: hex::encode(Sha256::digest(proof_bytes)),
+            blake3: blake3::hash(proof_bytes).to_hex().to_string(),
+        }
+    }
+}
+
+/// Proof parameters decoded from a proof
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParameterSnapshot {
+    /// Number of FRI queries
+    pub num_queries: usize,
+    /// Blowup factor of the LDE domain
+    pub blowup_factor: usize,
+    /// Proof-of-work grinding bits
+    pub grinding_factor: u32,
+    /// Field extension degree
+    pub field_extension: u32,
+    /// FRI folding factor
+    pub fri_folding_factor: usize,
+    /// FRI remainder polynomial maximum degree
+    pub fri_remainder_max_degree: usize,
+    /// Execution trace length (rows)
+    pub trace_length: usize,
+    /// Hash function recorded in the proof metadata
+    pub hash_function: HashFunction,
+    /// Final-state policy recorded in the trace metadata
+    pub final_state: String,
+    /// Registry protocol version with these parameters, `None` if unregistered
+    pub protocol_version: Option<u32>,
+}
+
+impl ParameterSnapshot {
+    /// Snapshot the parameters of a decoded proof
+    #[must_use]
+    pub fn of(proof: &StarkProof, hash_function: HashFunction) -> Self {
+        let options = proof.options();
+        let fri = options.to_fri_options();
+        let protocol_version = ParameterRegistry::builtin()
+            .entries()
+            .iter()
+            .find(|set| set.matches(options, COMMITMENT_VERSION, BURN_MINT_STATEMENT_ID))
+            .map(|set| set.protocol_version);
+        let final_state = match FinalStatePolicy::from_trace_info(&proof.get_trace_info()) {
+            Ok(policy) => format!("{policy:?}"),
+            Err(e) => e.to_string(),
+        };
+
+        Self {
+            num_queries: options.num_queries(),
+            blowup_factor: options.blowup_factor(),
+            grinding_factor: options.grinding_factor(),
+            field_extension: options.field_extension().degree(),
+            fri_folding_factor: fri.folding_factor(),
+            fri_remainder_max_degree: fri.remainder_max_degree(),
+            trace_length: proof.trace_length(),
+            hash_function,
+            final_state,
+            protocol_version,
+        }
+    }
+}
+
+/// Outcome of verifying a package
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationSummary {
+    /// Whether the proof verified against the package
+    pub valid: bool,
+    /// Why verification failed or could not run
+    pub error: Option<String>,
+    /// Conjectured security of the proof (bits)
+    pub security_bits: Option<u32>,
+    /// Time spent in STARK verification (milliseconds)
+    pub verification_time_ms: Option<u64>,
+}
+
+impl BugReport {
+    /// Collect a sanitized bundle for `package`
+    ///
+    /// The package is verified with the hash function its proof records, before the
+    /// secret is stripped.
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::collect_with_clock`]
+    pub fn collect(package: &CompleteProofPackage) -> Result<Self, BugReportError> {
+        Self::collect_with_clock(package, &SystemClock)
+    }
+
+    /// Collect a sanitized bundle for `package`, stamped by `clock`
+    ///
+    /// # Errors
+    ///
+    /// [`BugReportError::Json`] if the sanitized package cannot be serialized; verification
+    /// failures are recorded in the bundle
+    pub fn collect_with_clock(package: &CompleteProofPackage, clock: &dyn Clock) -> Result<Self, BugReportError> {
+        let secrets: Vec<String> = package
+            .stark_proof_data
+            .secrets()
+            .into_iter()
+            .map(|secret| secret.strip_prefix("0x").unwrap_or(secret).to_ascii_lowercase())
+            .filter(|secret| secret.len() >= MIN_SECRET_LEN)
+            .collect();
+
+        let proof_file = package.stark_proof.as_ref();
+        let proof = proof_file.map(|file| (ProofDigests::of(&file.proof_data), file.metadata.hash_function));
+        let parameters = proof_file.and_then(|file| {
+            StarkProof::from_bytes(&file.proof_data)
+                .ok()
+                .map(|decoded| ParameterSnapshot::of(&decoded, file.metadata.hash_function))
+        });
+
+        let verifier = XfgBurnMintVerifier::default()
+            .with_hash_function(proof.as_ref().map(|(_, hash)| *hash).unwrap_or_default());
+        let verification = match verifier.verify_package(package) {
+            Ok(report) => VerificationSummary {
+                valid: report.is_valid(),
+                error: report.error.map(|error| redact_str(&error, &secrets)),
+                security_bits: Some(report.security_bits),
+                verification_time_ms: u64::try_from(report.verification_time.as_millis()).ok(),
+            },
+            Err(e) => VerificationSummary {
+                valid: false,
+                error: Some(redact_str(&e.to_string(), &secrets)),
+                security_bits: None,
+                verification_time_ms: None,
+            },
+        };
+
+        let mut stripped = package.clone();
+        let secret = &mut stripped.stark_proof_data.secret;
+        secret.secret_key = Redacted::hex(&secret.secret_key).to_string();
+        secret.salt = secret.salt.as_deref().map(|salt| Redacted::hex(salt).to_string());
+        secret.hint = secret.hint.as_ref().map(|_| Redacted::opaque().to_string());
+        if let Some(proof_file) = stripped.stark_proof.as_mut() {
+            proof_file.proof_data.clear();
+        }
+
+        let mut package_value = serde_json::to_value(&stripped)?;
+        let mut redacted_fields = Vec::new();
+        redact_value(&mut package_value, &secrets, "", &mut redacted_fields);
+
+        Ok(Self {
+            format_version: BUG_REPORT_FORMAT_VERSION,
+            created_at: clock.rfc3339(),
+            build: BuildInfo::current(),
+            features: FeatureSet::current().to_string(),
+            environment: EnvironmentSummary::current(),
+            package: package_value,
+            redacted_fields,
+            proof: proof.map(|(digests, _)| digests),
+            parameters,
+            verification,
+        })
+    }
+
+    /// Serialize the bundle as pretty-printed JSON
+    ///
+    /// # Errors
+    ///
+    /// [`BugReportError::Json`] if the bundle cannot be serialized
+    pub fn to_json(&self) -> Result<String, BugReportError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Write the bundle to a file
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::to_json`], or [`BugReportError::Io`] if the file cannot be written
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), BugReportError> {
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+}
+
+/// Check whether `text` contains one of the (lowercase) secrets
+fn contains_secret(text: &str, secrets: &[String]) -> bool {
+    let text = text.to_ascii_lowercase();
+    secrets.iter().any(|secret| text.contains(secret.as_str()))
+}
+
+/// Replace `text` entirely if it contains a secret
+fn redact_str(text: &str, secrets: &[String]) -> String {
+    if contains_secret(text, secrets) {
+        Redacted::opaque().to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+/// Redact every string (and object key) in `value` that contains a secret
+fn redact_value(value: &mut Value, secrets: &[String], path: &str, redacted: &mut Vec<String>) {
+    match value {
+        Value::String(text) if contains_secret(text, secrets) => {
+            *text = Redacted::opaque().to_string();
+            redacted.push(path.to_string());
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                redact_value(item, secrets, &format!("{path}[{index}]"), redacted);
+            }
+        }
+        Value::Object(map) => {
+            let entries = std::mem::take(map);
+            for (key, mut item) in entries {
+                let key = if contains_secret(&key, secrets) {
+                    redacted.push(format!("{path}.<key>"));
+                    Redacted::opaque().to_string()
+                } else {
+                    key
+                };
+                let item_path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+                redact_value(&mut item, secrets, &item_path, redacted);
+                map.insert(key, item);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Bug report collection or output error
+#[derive(Debug, Error)]
+pub enum BugReportError {
+    /// The bundle could not be serialized
+    #[error("Bug report serialization failed: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The bundle could not be written
+    #[error("Bug report I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_prover::XfgBurnMintProver;
+    use crate::commitment::recipient_binding;
+    use crate::constants::DEFAULT_TARGET_CHAIN_ID;
+    use crate::proof_data_schema::{StarkProof as ProofFile, StarkProofDataPackage, StarkPublicInputs};
+
+    const SECRET: &str = "a1b2c3d4e5f6a7b8c9d0e1f2a3b4c5d6";
+
+    fn proven_package() -> CompleteProofPackage {
+        let data = StarkProofDataPackage::new(
+            0.8,
+            "7d0725f8e03021b99560add456c596fea7d8df23529e23765e56923b73236e4d".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            SECRET.to_string(),
+            "fuego-testnet".to_string(),
+        );
+        let recipient = data.recipient_address().unwrap();
+        let proof = XfgBurnMintProver::default()
+            .prove_burn_mint(
+                data.burn_transaction.burn_amount_atomic,
+                data.get_mint_amount_atomic(),
+                data.tx_prefix_hash().unwrap(),
+                recipient.as_bytes(),
+                &data.secret_bytes(),
+                data.network_id_number(),
+                DEFAULT_TARGET_CHAIN_ID,
+                COMMITMENT_VERSION,
+            )
+            .expect("Proof generation should succeed");
+
+        let mut package = CompleteProofPackage::new(data.clone());
+        package.add_stark_proof(ProofFile {
+            proof_data: proof.to_bytes(),
+            public_inputs: StarkPublicInputs {
+                burn_amount: data.burn_transaction.burn_amount_atomic,
+                mint_amount: data.get_mint_amount_atomic(),
+                txn_hash: data.burn_transaction.transaction_hash.clone(),
+                recipient_hash: hex::encode(recipient_binding(recipient.as_bytes(), DEFAULT_TARGET_CHAIN_ID)),
+                state: 0,
+                fee_bps: 0,
+            },
+            metadata: data.metadata.clone(),
+        });
+        package
+    }
+
+    #[test]
+    fn test_bundle_contains_no_secrets() {
+        let mut package = proven_package();
+        let proof_bytes = package.stark_proof.as_ref().unwrap().proof_data.clone();
+        let data = &mut package.stark_proof_data;
+        data.secret.salt = Some("5a17".repeat(8));
+        data.secret.hint = Some("my dog's name".to_string());
+        data.additional_data.insert("note".to_string(), format!("secret is 0x{}", SECRET.to_uppercase()));
+
+        let report = BugReport::collect(&package).unwrap();
+        let json = report.to_json().unwrap().to_lowercase();
+        for secret in [SECRET, "5a175a17", "my dog"] {
+            assert!(!json.contains(secret), "{} leaked into the bundle", secret);
+        }
+        assert_eq!(report.redacted_fields, ["stark_proof_data.additional_data.note"]);
+
+        // The proof is identified by digest only, and was verified before stripping
+        assert!(!json.contains("\"proof_data\": [\n"));
+        assert_eq!(report.proof, Some(ProofDigests::of(&proof_bytes)));
+        assert!(report.verification.valid, "{:?}", report.verification.error);
+        let parameters = report.parameters.unwrap();
+        assert_eq!(parameters.protocol_version, Some(2));
+        assert_eq!(parameters.final_state, "LastRow");
+    }
+
+    #[test]
+    fn test_bundle_without_proof() {
+        let mut package = proven_package();
+        package.stark_proof = None;
+
+        let report = BugReport::collect(&package).unwrap();
+        assert!(report.proof.is_none());
+        assert!(report.parameters.is_none());
+        assert!(!report.verification.valid);
+        assert!(report.verification.error.unwrap().contains("no STARK proof"));
+        assert_eq!(report.environment, EnvironmentSummary::current());
+    }
+}
@@ -0,0 +1,72 @@
+//! Build Information for Supply-Chain Traceability
+//!
+//! This module records which binary produced a proof: crate version, enabled features,
+//! target triple and a digest of the dependency lockfile. The values are captured at
+//! compile time by the build script and embedded in `ProofMetadata` at prove time.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Build information of the binary that produced a proof
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildInfo {
+    /// Crate version
+    pub crate_version: String,
+    /// Enabled cargo features (sorted)
+    pub features: Vec<String>,
+    /// Target triple
+    pub target_triple: String,
+    /// SHA-256 digest of `Cargo.lock` (hex), `None` if no lockfile was present
+    #[serde(default)]
+    pub lockfile_digest: Option<String>,
+}
+
+impl BuildInfo {
+    /// Build information of the running binary
+    pub fn current() -> Self {
+        let features = env!("XFG_BUILD_FEATURES");
+        let lockfile_digest = env!("XFG_BUILD_LOCKFILE_DIGEST");
+
+        Self {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            features: features
+                .split(',')
+                .filter(|feature| !feature.is_empty())
+                .map(str::to_string)
+                .collect(),
+            target_triple: env!("XFG_BUILD_TARGET").to_string(),
+            lockfile_digest: if lockfile_digest.is_empty() {
+                None
+            } else {
+                Some(lockfile_digest.to_string())
+            },
+        }
+    }
+}
+
+impl Display for BuildInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "xfg-stark {} ({}) features=[{}] lockfile={}",
+            self.crate_version,
+            self.target_triple,
+            self.features.join(","),
+            self.lockfile_digest.as_deref().unwrap_or("none")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_build_info_is_deterministic() {
+        let info = BuildInfo::current();
+        assert_eq!(info, BuildInfo::current());
+        assert_eq!(info.crate_version, env!("CARGO_PKG_VERSION"));
+        assert!(!info.target_triple.is_empty());
+        assert!(info.features.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
@@ -8,16 +8,423 @@
 //! - 1 XFG = 10,000,000 atomic units (7 decimal places)
 //! - All burn/mint operations use 1:1 conversion ratio in atomic units
 //! - This ensures precise calculations without floating point errors
-
-use crate::{types::field::PrimeField64, Result};
+//!
+//! ## Burn Amount Range
+//! Any burn amount from 1 to `2^53 - 1` atomic units can be proven. A
+//! [`RangeCheckGadget`] decomposes the burn amount into 53 bits over two registers, so
+//! the proof itself shows the public burn amount is in range.
+//!
+//! ## Mint Outputs
+//! One burn can mint to up to [`MAX_MINT_OUTPUTS`] recipients. Each [`MintOutput`] is a
+//! public input, and a running-sum register adds the output amounts row by row and must
+//! end at the mint amount, so the outputs together mint exactly the burn less the fee.
+//!
+//! ## Protocol Fee
+//! The fee is constrained inside the trace by one limb of division with remainder:
+//! `burn * fee_bps = (burn - mint) * 10000 + r`. Two more range checks show the
+//! remainder `r` and its slack `9999 - r` both fit in 14 bits, so `r` is below 10000 and
+//! `burn - mint` is exactly `floor(burn * fee_bps / 10000)` (see [`crate::fees`]).
+//!
+//! ## Nullifier and Commitment
+//! The nullifier and commitment public inputs are whole Rescue-Prime digests of the
+//! 256-bit secret (see [`RescueNullifierGadget`] and [`CommitmentPreimage`]). The trace
+//! holds the secret and runs both permutations with [`enforce_round`], and the digest
+//! registers of each output row are asserted to the public values, so a proof shows
+//! knowledge of a secret that opens both, not just two public numbers.
+//!
+//! The nullifier is scoped to the burn transaction: its scope is the tx prefix hash and
+//! the burn amount, so one burn has exactly one nullifier. The commitment is the value
+//! the wallet wrote into the burn's `tx_extra`, so the secret a proof uses is the one the
+//! burn committed to.
+//!
+//! ## Final State
+//! The state register must reach the complete state at a row chosen by a
+//! [`FinalStatePolicy`]: the last row by default, or a specific step. The policy is
+//! carried in the trace metadata, which Winterfell binds into the proof, so verifiers
+//! rebuild the same assertions from the proof alone.
+//!
+//! ## Configuration
+//! A [`BurnMintConfig`] sets the trace length, the number of states the state register
+//! passes through and the final-state policy. The state count travels in the trace
+//! metadata next to the policy; the default (four states, last row) writes none, so
+//! proofs made before the state count was configurable read back unchanged.
+
+use crate::{
+    air::hash_gadget::{
+        absorb, blinding_elements, enforce_round, periodic_columns, permutation_rows, round_constraint_degrees,
+        RescueNullifierGadget, CYCLE_LENGTH, DIGEST_LIMBS, DIGEST_RANGE, NUM_PERIODIC_COLUMNS,
+        PERMUTATION_WIDTH, RATE_RANGE, STATE_WIDTH,
+    },
+    commitment::{
+        recipient_binding, recipient_binding_limbs, CommitmentPreimage,
+        COMMITMENT_LIMBS, RECIPIENT_BINDING_LIMBS,
+    },
+    constants::{
+        is_valid_burn_amount, is_valid_trace_length, BURN_AMOUNT_BITS, INVALID_BURN_AMOUNT, MAX_MINT_OUTPUTS,
+        TRACE_LENGTH,
+    },
+    fees::{check_fee_bps, protocol_fee, BPS_DENOMINATOR},
+    proof::{
+        memory::LdeStrategy,
+        progress::{ProvingPhase, SharedProgress},
+    },
+    statements::{ConstantColumnTraceLde, StatementHasher},
+    statements::gadgets::{
+        copy_constraint, copy_constraint_degree, pin_register, RangeCheckGadget,
+        StateMachineGadget, PINNED_REGISTER_ASSERTIONS,
+    },
+    types::secret::{wipe_elements, SecretLimbs},
+    Result,
+};
 use anyhow;
-use sha3::{Digest, Keccak256};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::sync::Arc;
+use thiserror::Error;
 use winter_math::{FieldElement, StarkField, ToElements};
+use zeroize::Zeroize;
 use winterfell::{
-    math::fields::f64::BaseElement, Air, AirContext, Assertion, EvaluationFrame, ProofOptions,
-    Prover, TraceInfo, TraceTable, TransitionConstraintDegree,
+    crypto::{hashers::Rp64_256, DefaultRandomCoin, ElementHasher, Hasher},
+    math::fields::f64::BaseElement,
+    matrix::ColMatrix,
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, Prover, Trace, TraceInfo, TraceLayout, TraceTable,
+    TransitionConstraintDegree,
 };
 
+/// Number of registers in the burn & mint execution trace
+pub const TRACE_WIDTH: usize = HASH_REGISTERS.end;
+
+/// Field elements the burn secret is carried in (see [`crate::burn_mint_prover::SECRET_LEN`])
+pub const SECRET_LIMBS: usize = 4;
+
+/// Field elements of a nullifier: the whole Rescue-Prime digest
+pub const NULLIFIER_LIMBS: usize = DIGEST_LIMBS;
+
+/// Registers holding the same value on every row
+const CONSTANT_REGISTERS: [usize; 3] = [0, 1, 2];
+
+/// Register cycling through the recipient binding limbs, limb `i % 8` on row `i`
+const RECIPIENT_REGISTER: usize = 3;
+
+/// Register holding the state machine state
+const STATE_REGISTER: usize = 4;
+
+/// Register holding one bit of the burn amount per row
+const BURN_BIT_REGISTER: usize = 5;
+
+/// Register accumulating the burn amount bits
+const BURN_ACCUMULATOR_REGISTER: usize = 6;
+
+/// Register summing the mint output amounts
+const MINT_ACCUMULATOR_REGISTER: usize = 7;
+
+/// Register holding one bit of the fee remainder per row
+const FEE_REMAINDER_BIT_REGISTER: usize = 8;
+
+/// Register accumulating the fee remainder bits
+const FEE_REMAINDER_ACCUMULATOR_REGISTER: usize = 9;
+
+/// Register holding one bit of the fee remainder's slack below the denominator per row
+const FEE_SLACK_BIT_REGISTER: usize = 10;
+
+/// Register accumulating the fee slack bits
+const FEE_SLACK_ACCUMULATOR_REGISTER: usize = 11;
+
+/// Registers carrying the secret limbs from the nullifier input to the commitment input
+const SECRET_REGISTERS: Range<usize> = 12..12 + SECRET_LIMBS;
+
+/// Registers running the Rescue-Prime permutation (see [`crate::air::hash_gadget`])
+const HASH_REGISTERS: Range<usize> = SECRET_REGISTERS.end..SECRET_REGISTERS.end + PERMUTATION_WIDTH;
+
+/// Rows of the permutation registers that hash: the nullifier cycle, then the commitment cycle
+const HASH_ROWS: usize = 2 * CYCLE_LENGTH;
+
+/// Registers whose values depend on the secret: the secret carry and the permutation
+pub const SECRET_BOUND_REGISTERS: Range<usize> = SECRET_REGISTERS.start..TRACE_WIDTH;
+
+/// State registers both permutation inputs hold the secret limbs in
+const SECRET_SLOTS: Range<usize> = RATE_RANGE.start..RATE_RANGE.start + SECRET_LIMBS;
+
+/// Domain tag of the blinding elements seeded with the secret
+const BLINDING_DOMAIN: &[u8] = b"burn-mint-blinding";
+
+/// Number of boundary assertions: two periodic assertions for each of the three constant
+/// registers, one for each recipient binding limb, the initial and final state, the burn
+/// amount range check, the first and last mint output sums, the start of both fee range
+/// checks, both permutation inputs but for the secret, and both digests
+const NUM_ASSERTIONS: usize = CONSTANT_REGISTERS.len() * PINNED_REGISTER_ASSERTIONS
+    + RECIPIENT_BINDING_LIMBS
+    + 2
+    + RangeCheckGadget::NUM_ASSERTIONS
+    + 2
+    + 2
+    + 2 * (STATE_WIDTH - SECRET_LIMBS)
+    + NULLIFIER_LIMBS
+    + COMMITMENT_LIMBS;
+
+/// Range check on the burn amount
+pub(crate) const BURN_RANGE: RangeCheckGadget = RangeCheckGadget::new(BURN_AMOUNT_BITS);
+
+/// Range check on the fee remainder and its slack below [`BPS_DENOMINATOR`], each under a
+/// leading one bit
+const FEE_REMAINDER_RANGE: RangeCheckGadget = RangeCheckGadget::new(14);
+
+/// Domain tag for burn nullifiers
+pub(crate) const BURN_NULLIFIER: RescueNullifierGadget = RescueNullifierGadget::new(b"nullifier");
+
+/// Default burn & mint state machine: init(0) → burn(1) → mint(2) → complete(3)
+const BURN_MINT_STATES: StateMachineGadget = StateMachineGadget::new(4);
+
+/// Trace metadata tag of a state count other than the default
+const NUM_STATES_TAG: u8 = 2;
+
+/// Row at which the burn & mint state machine must reach the complete state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FinalStatePolicy {
+    /// The last row of the trace, whatever the trace length
+    #[default]
+    LastRow,
+    /// A specific step; the state stays complete for the remaining rows
+    AtStep(u32),
+}
+
+impl FinalStatePolicy {
+    /// Trace metadata tag of [`FinalStatePolicy::AtStep`]
+    const AT_STEP_TAG: u8 = 1;
+
+    /// Row of the final-state assertion in a trace of `trace_length` rows
+    ///
+    /// A specific step must leave room to pass through every state and lie inside the trace.
+    ///
+    /// # Errors
+    ///
+    /// [`FinalStatePolicyError::StepOutOfRange`] if the step does not
+    pub fn final_step(&self, trace_length: usize) -> std::result::Result<usize, FinalStatePolicyError> {
+        self.final_step_with(trace_length, BURN_MINT_STATES)
+    }
+
+    /// Row of the final-state assertion for the state machine `states`
+    ///
+    /// # Errors
+    ///
+    /// [`FinalStatePolicyError::StepOutOfRange`] if the step is before `states` can
+    /// reach its final state or outside the trace
+    pub fn final_step_with(
+        &self,
+        trace_length: usize,
+        states: StateMachineGadget,
+    ) -> std::result::Result<usize, FinalStatePolicyError> {
+        let step = match *self {
+            FinalStatePolicy::LastRow => trace_length.saturating_sub(1),
+            FinalStatePolicy::AtStep(step) => step as usize,
+        };
+        if step < states.num_states().saturating_sub(1) as usize || step >= trace_length {
+            return Err(FinalStatePolicyError::StepOutOfRange { step, trace_length });
+        }
+        Ok(step)
+    }
+
+    /// Trace metadata recording the policy (empty for the last row)
+    #[must_use]
+    pub fn to_trace_meta(&self) -> Vec<u8> {
+        match *self {
+            FinalStatePolicy::LastRow => Vec::new(),
+            FinalStatePolicy::AtStep(step) => {
+                let mut meta = vec![Self::AT_STEP_TAG];
+                meta.extend_from_slice(&step.to_le_bytes());
+                meta
+            }
+        }
+    }
+
+    /// Policy recorded in a trace's metadata, checked against its length
+    ///
+    /// # Errors
+    ///
+    /// [`FinalStatePolicyError::UnknownMetadata`] if the metadata does not encode a policy,
+    /// or [`FinalStatePolicyError::StepOutOfRange`] if the policy does not fit the trace
+    pub fn from_trace_info(trace_info: &TraceInfo) -> std::result::Result<Self, FinalStatePolicyError> {
+        let (policy, num_states) = parse_trace_meta(trace_info.meta())?;
+        policy.final_step_with(trace_info.length(), StateMachineGadget::new(num_states))?;
+        Ok(policy)
+    }
+}
+
+/// Final-state policy and state count recorded in trace metadata
+///
+/// Each field is a tag byte followed by a little-endian `u32`. Only the canonical
+/// encoding [`BurnMintConfig::to_trace_meta`] writes is accepted, so one configuration
+/// has one metadata string.
+fn parse_trace_meta(meta: &[u8]) -> std::result::Result<(FinalStatePolicy, u32), FinalStatePolicyError> {
+    let unknown = || FinalStatePolicyError::UnknownMetadata(hex::encode(meta));
+    let mut config = BurnMintConfig::default();
+    for field in meta.chunks(5) {
+        let value = match <[u8; 4]>::try_from(&field[1..]) {
+            Ok(value) => u32::from_le_bytes(value),
+            Err(_) => return Err(unknown()),
+        };
+        match field[0] {
+            FinalStatePolicy::AT_STEP_TAG => config.final_state = FinalStatePolicy::AtStep(value),
+            NUM_STATES_TAG => config.num_states = value,
+            _ => return Err(unknown()),
+        }
+    }
+    if config.num_states < 2 || config.to_trace_meta() != meta {
+        return Err(unknown());
+    }
+    Ok((config.final_state, config.num_states))
+}
+
+/// Trace length and state schedule of the burn & mint AIR
+///
+/// The state register advances evenly through `num_states` states, from 0 on the first
+/// row to the final state at the row the final-state policy names, and stays there.
+/// Lengths shorter than [`TRACE_LENGTH`] leave no room for the 53-bit burn range check,
+/// so shorter testnet traces are limited by it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BurnMintConfig {
+    /// Rows in the execution trace
+    pub trace_length: usize,
+    /// States the state register passes through
+    pub num_states: u32,
+    /// Row at which the final state must be reached
+    pub final_state: FinalStatePolicy,
+}
+
+impl Default for BurnMintConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: TRACE_LENGTH,
+            num_states: BURN_MINT_STATES.num_states(),
+            final_state: FinalStatePolicy::LastRow,
+        }
+    }
+}
+
+impl BurnMintConfig {
+    /// Default schedule over a trace of `trace_length` rows
+    #[must_use]
+    pub fn new(trace_length: usize) -> Self {
+        Self {
+            trace_length,
+            ..Self::default()
+        }
+    }
+
+    /// Pass through `num_states` states instead of four
+    #[must_use]
+    pub fn with_num_states(mut self, num_states: u32) -> Self {
+        self.num_states = num_states;
+        self
+    }
+
+    /// Require the final state at another row than the last
+    #[must_use]
+    pub fn with_final_state(mut self, final_state: FinalStatePolicy) -> Self {
+        self.final_state = final_state;
+        self
+    }
+
+    /// State machine the schedule runs
+    #[must_use]
+    pub fn states(&self) -> StateMachineGadget {
+        StateMachineGadget::new(self.num_states)
+    }
+
+    /// Row of the final-state assertion
+    ///
+    /// # Errors
+    ///
+    /// As [`FinalStatePolicy::final_step_with`]
+    pub fn final_step(&self) -> std::result::Result<usize, FinalStatePolicyError> {
+        self.final_state.final_step_with(self.trace_length, self.states())
+    }
+
+    /// Check that the trace length and schedule can be proven
+    ///
+    /// # Errors
+    ///
+    /// [`BurnMintConfigError::InvalidTraceLength`] if the trace length is invalid (see
+    /// [`is_valid_trace_length`]), [`BurnMintConfigError::InvalidStateCount`] for fewer
+    /// than two states, or [`BurnMintConfigError::FinalState`] if the final step is out of range
+    pub fn validate(&self) -> std::result::Result<(), BurnMintConfigError> {
+        if !is_valid_trace_length(self.trace_length) {
+            return Err(BurnMintConfigError::InvalidTraceLength(self.trace_length));
+        }
+        if self.num_states < 2 {
+            return Err(BurnMintConfigError::InvalidStateCount(self.num_states));
+        }
+        self.final_step()?;
+        Ok(())
+    }
+
+    /// Trace metadata recording the schedule (empty for the default)
+    #[must_use]
+    pub fn to_trace_meta(&self) -> Vec<u8> {
+        let mut meta = self.final_state.to_trace_meta();
+        if self.num_states != BURN_MINT_STATES.num_states() {
+            meta.push(NUM_STATES_TAG);
+            meta.extend_from_slice(&self.num_states.to_le_bytes());
+        }
+        meta
+    }
+
+    /// Trace layout for this configuration
+    #[must_use]
+    pub fn to_trace_info(&self) -> TraceInfo {
+        TraceInfo::with_meta(TRACE_WIDTH, self.trace_length, self.to_trace_meta())
+    }
+
+    /// Configuration a trace layout records, checked
+    ///
+    /// # Errors
+    ///
+    /// [`BurnMintConfigError::FinalState`] if the trace metadata is not recognised, or as
+    /// [`Self::validate`]
+    pub fn from_trace_info(trace_info: &TraceInfo) -> std::result::Result<Self, BurnMintConfigError> {
+        let (final_state, num_states) = parse_trace_meta(trace_info.meta())?;
+        let config = Self {
+            trace_length: trace_info.length(),
+            num_states,
+            final_state,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/// Burn & mint configuration that cannot be proven
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BurnMintConfigError {
+    /// The trace length is not a power of two with room for the range check and outputs
+    #[error("Invalid burn & mint trace length {0}")]
+    InvalidTraceLength(usize),
+    /// The state machine needs an initial and a final state
+    #[error("Burn & mint state machine needs at least 2 states, got {0}")]
+    InvalidStateCount(u32),
+    /// The final-state policy does not fit the trace
+    #[error(transparent)]
+    FinalState(#[from] FinalStatePolicyError),
+}
+
+/// Final-state policy that cannot be applied to a trace
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FinalStatePolicyError {
+    /// The step is before the last state can be reached or outside the trace
+    #[error("Final state step {step} is before the final state or outside {trace_length} rows")]
+    StepOutOfRange {
+        /// Requested step
+        step: usize,
+        /// Rows in the trace
+        trace_length: usize,
+    },
+    /// The trace metadata does not encode a policy
+    #[error("Unknown final state metadata 0x{0}")]
+    UnknownMetadata(String),
+}
+
 /// Public inputs for burn & mint verification
 #[derive(Debug, Clone)]
 pub struct BurnMintPublicInputs {
@@ -27,8 +434,10 @@ pub struct BurnMintPublicInputs {
     pub mint_amount: BaseElement,
     /// Transaction hash (legacy - first 32 bits, kept for compatibility)
     pub txn_hash: BaseElement,
-    /// Recipient address hash (Keccak256 of recipient address)
+    /// Recipient hash (first limb of the recipient binding)
     pub recipient_hash: BaseElement,
+    /// Recipient binding as little-endian 32-bit limbs (see [`crate::commitment`])
+    pub recipient_binding: [BaseElement; RECIPIENT_BINDING_LIMBS],
     /// State (0=init, 1=burn, 2=mint, 3=complete)
     pub state: BaseElement,
 
@@ -49,6 +458,44 @@ pub struct BurnMintPublicInputs {
     pub target_chain_id: BaseElement,
     /// Commitment format version (for future upgrades)
     pub commitment_version: BaseElement,
+    /// Protocol fee in basis points (see [`crate::fees`])
+    pub fee_bps: BaseElement,
+
+    /// Nullifier derived from the secret and the burn transaction (published to prevent
+    /// double minting)
+    pub nullifier: [BaseElement; NULLIFIER_LIMBS],
+    /// Commitment derived from the secret (the commitment in `tx_extra`, see
+    /// [`CommitmentPreimage::to_bytes`])
+    pub commitment: [BaseElement; COMMITMENT_LIMBS],
+
+    /// Recipients splitting `mint_amount`, the first matching `recipient_binding`
+    ///
+    /// Empty when the whole mint goes to `recipient_binding`.
+    pub outputs: Vec<MintOutput>,
+}
+
+/// The 32-bit little-endian limbs of the tx prefix hash the public inputs carry
+#[must_use]
+pub fn tx_prefix_hash_limbs(tx_prefix_hash: &[u8; 32]) -> [BaseElement; 4] {
+    let mut limbs = [BaseElement::ZERO; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(tx_prefix_hash.as_chunks::<4>().0) {
+        *limb = BaseElement::from(u32::from_le_bytes(*chunk));
+    }
+    limbs
+}
+
+/// One recipient of a multi-output mint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MintOutput {
+    /// Amount minted to the recipient (atomic units)
+    pub amount: BaseElement,
+    /// Recipient binding as little-endian 32-bit limbs (see [`crate::commitment`])
+    pub recipient_binding: [BaseElement; RECIPIENT_BINDING_LIMBS],
+}
+
+impl MintOutput {
+    /// Number of field elements an output appends to the public inputs
+    pub const NUM_ELEMENTS: usize = 1 + RECIPIENT_BINDING_LIMBS;
 }
 
 impl ToElements<BaseElement> for BurnMintPublicInputs {
@@ -66,7 +513,220 @@ impl ToElements<BaseElement> for BurnMintPublicInputs {
             self.network_id,
             self.target_chain_id,
             self.commitment_version,
+            self.fee_bps,
         ]
+        .into_iter()
+        .chain(self.nullifier)
+        .chain(self.commitment)
+        .chain(self.recipient_binding)
+        .chain(
+            self.outputs
+                .iter()
+                .flat_map(|output| std::iter::once(output.amount).chain(output.recipient_binding)),
+        )
+        .collect()
+    }
+}
+
+impl BurnMintPublicInputs {
+    /// Number of field elements produced by `to_elements` for a single-recipient mint
+    ///
+    /// Each [`MintOutput`] appends [`MintOutput::NUM_ELEMENTS`] more.
+    pub const NUM_ELEMENTS: usize = 13 + NULLIFIER_LIMBS + COMMITMENT_LIMBS + RECIPIENT_BINDING_LIMBS;
+
+    /// Build the public inputs of a single-recipient claim from the data a user holds
+    ///
+    /// Encodes the claim exactly as the prover does: the recipient binding limbs for
+    /// `target_chain_id`, the 32-bit tx prefix hash limbs and the initial state. The mint
+    /// is the whole burn (see [`Self::with_fee_bps`]); the nullifier and commitment are
+    /// left at zero for the values the claim publishes.
+    #[must_use]
+    pub fn from_user_data(
+        burn_amount: u64,
+        tx_prefix_hash: [u8; 32],
+        recipient: &[u8; 20],
+        network_id: u32,
+        target_chain_id: u32,
+        commitment_version: u32,
+    ) -> Self {
+        let [tx_prefix_hash_0, tx_prefix_hash_1, tx_prefix_hash_2, tx_prefix_hash_3] =
+            tx_prefix_hash_limbs(&tx_prefix_hash);
+        let binding = recipient_binding_limbs(&recipient_binding(recipient, target_chain_id));
+        Self {
+            burn_amount: BaseElement::new(burn_amount),
+            mint_amount: BaseElement::new(burn_amount),
+            // Legacy field: low 32 bits of the tx prefix hash
+            txn_hash: tx_prefix_hash_0,
+            recipient_hash: binding[0],
+            recipient_binding: binding,
+            state: BaseElement::ZERO, // init
+            tx_prefix_hash_0,
+            tx_prefix_hash_1,
+            tx_prefix_hash_2,
+            tx_prefix_hash_3,
+            network_id: BaseElement::from(network_id),
+            target_chain_id: BaseElement::from(target_chain_id),
+            commitment_version: BaseElement::from(commitment_version),
+            fee_bps: BaseElement::ZERO,
+            nullifier: [BaseElement::ZERO; NULLIFIER_LIMBS],
+            commitment: [BaseElement::ZERO; COMMITMENT_LIMBS],
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Charge a protocol fee, reducing the mint to the burn less the fee
+    #[must_use]
+    pub fn with_fee_bps(mut self, fee_bps: u32) -> Self {
+        let burn_amount = self.burn_amount.as_int();
+        self.fee_bps = BaseElement::from(fee_bps);
+        self.mint_amount = BaseElement::new(burn_amount - protocol_fee(burn_amount, fee_bps));
+        self
+    }
+
+    /// Rebuild public inputs from the `to_elements` layout
+    ///
+    /// Returns `None` when `elements` does not hold [`Self::NUM_ELEMENTS`] values followed
+    /// by at most [`MAX_MINT_OUTPUTS`] whole outputs.
+    #[must_use]
+    pub fn from_elements(elements: &[BaseElement]) -> Option<Self> {
+        let (elements, outputs) = elements.split_at_checked(Self::NUM_ELEMENTS)?;
+        if outputs.len() % MintOutput::NUM_ELEMENTS != 0
+            || outputs.len() / MintOutput::NUM_ELEMENTS > MAX_MINT_OUTPUTS
+        {
+            return None;
+        }
+        let (nullifier, rest) = elements[13..].split_at(NULLIFIER_LIMBS);
+        let (commitment, recipient_binding) = rest.split_at(COMMITMENT_LIMBS);
+        let outputs = outputs
+            .chunks_exact(MintOutput::NUM_ELEMENTS)
+            .map(|output| {
+                Some(MintOutput {
+                    amount: output[0],
+                    recipient_binding: output[1..].try_into().ok()?,
+                })
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Self {
+            burn_amount: elements[0],
+            mint_amount: elements[1],
+            txn_hash: elements[2],
+            recipient_hash: elements[3],
+            recipient_binding: recipient_binding.try_into().ok()?,
+            state: elements[4],
+            tx_prefix_hash_0: elements[5],
+            tx_prefix_hash_1: elements[6],
+            tx_prefix_hash_2: elements[7],
+            tx_prefix_hash_3: elements[8],
+            network_id: elements[9],
+            target_chain_id: elements[10],
+            commitment_version: elements[11],
+            fee_bps: elements[12],
+            nullifier: nullifier.try_into().ok()?,
+            commitment: commitment.try_into().ok()?,
+            outputs,
+        })
+    }
+
+    /// Encode the `to_elements` layout as little-endian `u64`s
+    ///
+    /// The public inputs encoding `xfg-stark-verifier-core` verifies against.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_elements().iter().flat_map(|element| element.as_int().to_le_bytes()).collect()
+    }
+
+    /// Scope of the nullifier: the tx prefix hash limbs, then the burn amount
+    ///
+    /// Ties the nullifier to one burn transaction, so two burns of the same amount under
+    /// one secret still have different nullifiers.
+    #[must_use]
+    pub fn nullifier_scope(&self) -> [BaseElement; 5] {
+        [
+            self.tx_prefix_hash_0,
+            self.tx_prefix_hash_1,
+            self.tx_prefix_hash_2,
+            self.tx_prefix_hash_3,
+            self.burn_amount,
+        ]
+    }
+
+    /// Check the recipient hash is the first limb of the recipient binding
+    ///
+    /// The AIR constrains the binding limbs; the recipient hash only repeats the first
+    /// of them for the transaction hash and older consumers, so it must not disagree.
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError::CryptoError`] if the two differ
+    pub fn check_recipient_binding(&self) -> Result<()> {
+        if self.recipient_hash != self.recipient_binding[0] {
+            return Err(crate::XfgStarkError::CryptoError(
+                "Recipient hash must be the first recipient binding limb".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check that the claim is for `network`
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError::NetworkError`] if an identifier differs from the network's
+    pub fn check_network(&self, network: &crate::networks::NetworkParams) -> Result<()> {
+        // Identifiers outside u32 belong to no network
+        let id = |element: BaseElement| u32::try_from(element.as_int()).unwrap_or(u32::MAX);
+        network.check_claim(id(self.network_id), id(self.target_chain_id), id(self.commitment_version))?;
+        Ok(())
+    }
+
+    /// Amount and recipient of every output, including the implied single output
+    #[must_use]
+    pub fn mint_outputs(&self) -> Vec<MintOutput> {
+        if self.outputs.is_empty() {
+            return vec![MintOutput {
+                amount: self.mint_amount,
+                recipient_binding: self.recipient_binding,
+            }];
+        }
+        self.outputs.clone()
+    }
+
+    /// Check that the outputs split the mint amount between at most
+    /// [`MAX_MINT_OUTPUTS`] recipients, the first of them the bound recipient
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError::CryptoError`] naming the first rule the outputs break
+    pub fn check_mint_outputs(&self) -> Result<()> {
+        if self.outputs.is_empty() {
+            return Ok(());
+        }
+        if self.outputs.len() > MAX_MINT_OUTPUTS {
+            return Err(crate::XfgStarkError::CryptoError(format!(
+                "A burn can mint to at most {MAX_MINT_OUTPUTS} recipients"
+            )));
+        }
+        if self.outputs.iter().any(|output| output.amount == BaseElement::ZERO) {
+            return Err(crate::XfgStarkError::CryptoError(
+                "Every mint output must be greater than 0".to_string(),
+            ));
+        }
+        let total = self
+            .outputs
+            .iter()
+            .try_fold(0u64, |total, output| total.checked_add(output.amount.as_int()));
+        if total != Some(self.mint_amount.as_int()) {
+            return Err(crate::XfgStarkError::CryptoError(
+                "Mint outputs must sum to the mint amount".to_string(),
+            ));
+        }
+        if self.outputs[0].recipient_binding != self.recipient_binding {
+            return Err(crate::XfgStarkError::CryptoError(
+                "The first mint output must go to the bound recipient".to_string(),
+            ));
+        }
+        Ok(())
     }
 }
 
@@ -79,192 +739,291 @@ impl ToElements<BaseElement> for BurnMintPublicInputs {
 /// - Register 0: Burn amount (XFG)
 /// - Register 1: Mint amount (HEAT)
 /// - Register 2: Transaction hash (for uniqueness and binding)
-/// - Register 3: Recipient hash (for destination binding)
+/// - Register 3: Recipient binding, limb `i % 8` on row `i` (for destination binding)
 /// - Register 4: State (0=init, 1=burn, 2=mint, 3=complete)
-/// - Register 5: Nullifier (for uniqueness)
-/// - Register 6: Commitment (cryptographic binding)
+/// - Register 5: Burn amount bit (range check)
+/// - Register 6: Burn amount accumulator (range check)
+/// - Register 7: Running sum of the mint output amounts
+/// - Registers 8–9: Fee remainder bit and accumulator (range check)
+/// - Registers 10–11: Fee slack bit and accumulator (range check)
+/// - Registers 12–15: Secret limbs, on the rows from the nullifier input to the
+///   commitment input
+/// - Registers 16–51: Rescue-Prime permutation state and S-box helpers
+///
+/// Registers 0–2 are constant: they are pinned to the public inputs on every row by
+/// periodic assertions, and copy constraints (`next == current`) hold them between rows.
+/// Register 3 carries every limb of the recipient binding, so all 256 bits of it are
+/// constrained, not just the first limb: each limb is pinned by a periodic assertion
+/// with stride 8 and matched against a periodic column of the limbs. Register 4 is
+/// constrained by the state transition constraint, registers 5 and 6 by the range check,
+/// whose accumulator ends at the burn amount, and register 7 adds the output amount of
+/// each row (a periodic column) and ends at the mint amount. Registers 8–11 range-check
+/// the remainder of the fee division and its slack below 10000; on the last row the
+/// remainder must equal `burn * fee_bps - (burn - mint) * 10000` and the two must add up
+/// to 9999.
+///
+/// The permutation registers hash the nullifier input on rows 0–7 and the commitment
+/// input on rows 8–15. Both inputs are pinned to the public inputs except for the
+/// secret, which must match registers 12–15, held unchanged from row 0 to row 8. The
+/// digest registers of each output row are pinned to the nullifier and commitment.
+/// Winterfell proofs are not zero-knowledge, so the rows past these are filled with
+/// [`blinding_elements`] derived from the secret: the trace values a proof opens then mix
+/// the secret with elements a verifier cannot predict.
 pub struct XfgBurnMintAir {
     context: AirContext<BaseElement>,
     public_inputs: BurnMintPublicInputs,
-    secret: BaseElement,
+    config: BurnMintConfig,
+    /// Held only by the prover, which needs it for the trace; wiped when the AIR is dropped
+    secret: Option<SecretLimbs>,
+    /// Secret-bound columns supplied by a secret holder, in place of `secret`
+    secret_cells: Option<SecretTraceCells>,
     options: ProofOptions,
 }
 
 impl XfgBurnMintAir {
     /// Create new XFG Burn & Mint AIR
+    ///
+    /// The nullifier and commitment public inputs are derived from `secret`.
     pub fn new(
         trace_info: TraceInfo,
         public_inputs: BurnMintPublicInputs,
-        secret: BaseElement,
+        secret: [BaseElement; SECRET_LIMBS],
         options: ProofOptions,
     ) -> Self {
-        // Define constraint degrees for our constraints
-        // Note: 7 registers in trace, but more public inputs now
-        let constraint_degrees = vec![
-            TransitionConstraintDegree::new(1), // burn amount validation
-            TransitionConstraintDegree::new(1), // mint proportionality
-            TransitionConstraintDegree::new(1), // transaction hash consistency
-            TransitionConstraintDegree::new(1), // recipient hash consistency
-            TransitionConstraintDegree::new(1), // state transitions
-            TransitionConstraintDegree::new(1), // nullifier uniqueness
-            TransitionConstraintDegree::new(1), // commitment validation
-        ];
+        let config = Self::config_of(&trace_info);
+        let context = Self::build_context(trace_info, &options);
+
+        let mut air = Self {
+            context,
+            public_inputs,
+            config,
+            secret: Some(SecretLimbs(secret)),
+            secret_cells: None,
+            options,
+        };
+        air.public_inputs.nullifier = air.compute_nullifier(&secret);
+        air.public_inputs.commitment = air.compute_commitment(&secret);
+        air
+    }
 
-        let context = AirContext::new(trace_info, constraint_degrees, 7, options.clone());
+    /// Create the prover-side AIR from secret-bound columns computed elsewhere
+    ///
+    /// `public_inputs` must already hold the nullifier and commitment, e.g. from
+    /// [`crate::secret_oracle::derive_public_values`]; [`Self::check_witness`] checks the
+    /// cells hash to them.
+    #[must_use]
+    pub fn with_secret_cells(
+        trace_info: TraceInfo,
+        public_inputs: BurnMintPublicInputs,
+        secret_cells: SecretTraceCells,
+        options: ProofOptions,
+    ) -> Self {
+        let config = Self::config_of(&trace_info);
+        let context = Self::build_context(trace_info, &options);
 
         Self {
             context,
             public_inputs,
-            secret,
+            config,
+            secret: None,
+            secret_cells: Some(secret_cells),
             options,
         }
     }
 
-    /// Compute nullifier using Keccak256 hash
-    fn compute_nullifier(&self, secret: &BaseElement) -> BaseElement {
-        let mut hasher = Keccak256::new();
-        hasher.update(&secret.as_int().to_le_bytes());
-        hasher.update(b"nullifier");
-        hasher.update(&self.public_inputs.burn_amount.as_int().to_le_bytes());
-        let hash = hasher.finalize();
-
-        // Convert first 8 bytes of hash to field element
-        BaseElement::from(u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]]))
+    /// Public inputs the AIR binds, including the derived nullifier and commitment
+    #[must_use]
+    pub fn public_inputs(&self) -> &BurnMintPublicInputs {
+        &self.public_inputs
     }
 
-    /// Compute transaction hash using real Fuego blockchain data
-    fn compute_transaction_hash(&self) -> [u8; 32] {
-        let mut hasher = Keccak256::new();
-
-        // Include burn amount, timestamp, and recipient for uniqueness
-        hasher.update(&self.public_inputs.burn_amount.as_int().to_le_bytes());
-        hasher.update(&self.public_inputs.recipient_hash.as_int().to_le_bytes());
-
-        // Add current timestamp for additional uniqueness
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        hasher.update(&timestamp.to_le_bytes());
-
-        // Add Fuego-specific domain separator
-        hasher.update(b"fuego-burn-transaction");
-
-        hasher.finalize().into()
+    /// Final-state policy recorded in the trace metadata
+    #[must_use]
+    pub fn final_state(&self) -> FinalStatePolicy {
+        self.config.final_state
     }
 
-    /// Compute recipient hash using real Fuego address data
-    fn compute_recipient_hash(&self) -> [u8; 32] {
-        let mut hasher = Keccak256::new();
-
-        // Use recipient hash from public inputs
-        hasher.update(&self.public_inputs.recipient_hash.as_int().to_le_bytes());
+    /// Trace length and state schedule recorded in the trace metadata
+    #[must_use]
+    pub fn config(&self) -> &BurnMintConfig {
+        &self.config
+    }
 
-        // Add Ethereum address format for HEAT minting
-        hasher.update(b"ethereum-recipient");
+    /// Row at which the state register must be in the complete state
+    ///
+    /// # Panics
+    ///
+    /// If the schedule does not fit the trace. AIRs are built from trace info, where a
+    /// recorded schedule is checked against the trace length and the fallback ends on the
+    /// last of at least 8 rows, so this does not happen.
+    #[must_use]
+    pub fn final_step(&self) -> usize {
+        self.config.final_step().expect("config was checked against the trace length")
+    }
 
-        // Add domain separator for cross-chain operations
-        hasher.update(b"fuego-to-heat-bridge");
+    /// Read the configuration from the trace metadata
+    ///
+    /// Metadata this crate did not write falls back to the default schedule ending on the
+    /// last row, so the AIR never asserts outside the trace; verifiers reject such proofs
+    /// before building the AIR.
+    fn config_of(trace_info: &TraceInfo) -> BurnMintConfig {
+        BurnMintConfig::from_trace_info(trace_info).unwrap_or_else(|_| BurnMintConfig::new(trace_info.length()))
+    }
 
-        hasher.finalize().into()
+    /// Build the AIR context shared by the prover and verifier
+    fn build_context(trace_info: TraceInfo, options: &ProofOptions) -> AirContext<BaseElement> {
+        // Only the state and range check registers change between rows; constant registers
+        // are bound by periodic assertions and, independently, by copy constraints
+        let mut constraint_degrees = vec![
+            StateMachineGadget::constraint_degree(), // state transitions
+        ];
+        constraint_degrees.extend(CONSTANT_REGISTERS.map(|_| copy_constraint_degree()));
+        constraint_degrees.extend(RangeCheckGadget::constraint_degrees());
+        // The periodic output amount is added, not multiplied, so the mint sum stays linear
+        constraint_degrees.push(TransitionConstraintDegree::new(1));
+        // Likewise the periodic recipient limb is subtracted from the recipient register
+        constraint_degrees.push(TransitionConstraintDegree::new(1));
+        // The fee remainder and its slack are range checked, and the division and the
+        // slack sum are gated to the last row by a selector column spanning the whole trace
+        constraint_degrees.extend(RangeCheckGadget::constraint_degrees());
+        constraint_degrees.extend(RangeCheckGadget::constraint_degrees());
+        let last_row = TransitionConstraintDegree::with_cycles(1, vec![trace_info.length()]);
+        constraint_degrees.extend(std::iter::repeat_n(last_row, 2));
+        // The secret carry and the secret slots are gated by selector columns spanning the
+        // whole trace, and so are the rounds
+        let selected = TransitionConstraintDegree::with_cycles(1, vec![trace_info.length()]);
+        constraint_degrees.extend(std::iter::repeat_n(selected, 2 * SECRET_LIMBS));
+        constraint_degrees.extend(round_constraint_degrees(trace_info.length()));
+
+        AirContext::new(trace_info, constraint_degrees, NUM_ASSERTIONS, options.clone())
     }
 
-    /// Compute commitment using Keccak256 hash with full domain separation
-    /// Preimage: secret || le64(amount) || tx_prefix_hash || recipient_hash || network_id || target_chain_id || version
-    fn compute_commitment(&self, secret: &BaseElement) -> BaseElement {
-        let mut hasher = Keccak256::new();
-        hasher.update(&secret.as_int().to_le_bytes());
+    /// Check the witness against the burn & mint rules before proving
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError::CryptoError`] if the burn amount, fee rate, recipient binding
+    /// or mint outputs are invalid, the mint is not the burn less the fee, or the nullifier and
+    /// commitment do not match the secret; errors of the secret's trace cells when proving with them
+    pub fn check_witness(&self) -> Result<()> {
+        if !is_valid_burn_amount(self.public_inputs.burn_amount.as_int()) {
+            return Err(crate::XfgStarkError::CryptoError(INVALID_BURN_AMOUNT.to_string()));
+        }
 
-        // Amount (64-bit, little-endian)
-        hasher.update(&self.public_inputs.burn_amount.as_int().to_le_bytes());
-        hasher.update(&self.public_inputs.mint_amount.as_int().to_le_bytes()); // for completeness
+        check_fee_bps(self.public_inputs.fee_bps.as_int())?;
+        self.public_inputs.check_recipient_binding()?;
+        self.public_inputs.check_mint_outputs()?;
+
+        if self.validate_mint_proportionality(
+            self.public_inputs.burn_amount,
+            self.public_inputs.mint_amount,
+        ) != BaseElement::ZERO
+        {
+            return Err(crate::XfgStarkError::CryptoError(
+                "Mint amount must equal burn amount less the protocol fee".to_string(),
+            ));
+        }
 
-        // Full tx prefix hash (32 bytes)
-        hasher.update(&self.public_inputs.tx_prefix_hash_0.as_int().to_le_bytes());
-        hasher.update(&self.public_inputs.tx_prefix_hash_1.as_int().to_le_bytes());
-        hasher.update(&self.public_inputs.tx_prefix_hash_2.as_int().to_le_bytes());
-        hasher.update(&self.public_inputs.tx_prefix_hash_3.as_int().to_le_bytes());
+        // The trace runs both hashes over the secret, or takes them from its holder
+        let Some(SecretLimbs(secret)) = self.secret else {
+            return match &self.secret_cells {
+                Some(cells) => cells.check(&self.public_inputs, self.trace_length()),
+                None => Err(crate::XfgStarkError::CryptoError(
+                    "Proving a burn & mint needs the secret or its trace cells".to_string(),
+                )),
+            };
+        };
+
+        if self.validate_nullifier_consistency(self.public_inputs.nullifier) != [BaseElement::ZERO; NULLIFIER_LIMBS] {
+            return Err(crate::XfgStarkError::CryptoError(
+                "Nullifier does not match the secret".to_string(),
+            ));
+        }
 
-        // Recipient hash (32 bytes, not the truncated 32-bit version)
-        let recipient_hash_full = self.compute_recipient_hash();
-        hasher.update(&recipient_hash_full);
+        if self.public_inputs.commitment != self.compute_commitment(&secret) {
+            return Err(crate::XfgStarkError::CryptoError(
+                "Commitment does not match the secret".to_string(),
+            ));
+        }
 
-        // Network IDs for domain separation
-        hasher.update(&self.public_inputs.network_id.as_int().to_le_bytes());
-        hasher.update(&self.public_inputs.target_chain_id.as_int().to_le_bytes());
-        hasher.update(&self.public_inputs.commitment_version.as_int().to_le_bytes());
+        Ok(())
+    }
 
-        hasher.update(b"heat-commitment-v1");
-        let hash = hasher.finalize();
+    /// Compute the Rescue-Prime nullifier, scoped to the burn transaction
+    fn compute_nullifier(&self, secret: &[BaseElement; SECRET_LIMBS]) -> [BaseElement; NULLIFIER_LIMBS] {
+        BURN_NULLIFIER.derive(secret, &self.public_inputs.nullifier_scope())
+    }
 
-        // Convert first 4 bytes of hash to field element (32-bit)
-        BaseElement::from(u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]]))
+    /// Compute the commitment public input over the secret and this claim
+    ///
+    /// The element layout is documented on [`CommitmentPreimage`].
+    fn compute_commitment(&self, secret: &[BaseElement; SECRET_LIMBS]) -> [BaseElement; COMMITMENT_LIMBS] {
+        CommitmentPreimage::new(*secret, &self.public_inputs).hash()
     }
 
-    /// Validate burn amount constraints (in atomic units)
-    fn validate_burn_amount<E: FieldElement<BaseField = BaseElement>>(&self, burn_amount: E) -> E {
-        // XFG uses 7 decimal places: 1 XFG = 10,000,000 atomic units
-        let standard_burn = E::from(8_000_000u32);  // 0.8 XFG in atomic units
-        
-        // For large burns, we'll use a different approach since 800 XFG = 8,000,000,000 exceeds u32
-        // We'll validate that the burn amount is either the standard amount OR a large amount
-        // by checking if it's divisible by the standard amount and within reasonable bounds
-        
-        // Check if burn_amount is either standard_burn OR a multiple of standard_burn * 1000 (800 XFG)
-        let large_burn_multiplier = E::from(1000u32);
-        let large_burn_threshold = standard_burn * large_burn_multiplier;
-        
-        // Constraint: (burn_amount - standard_burn) * (burn_amount - large_burn_threshold) = 0
-        // This ensures burn_amount is either 0.8 XFG or 800 XFG
-        (burn_amount - standard_burn) * (burn_amount - large_burn_threshold)
+    /// Validate nullifier consistency: zero in every limb when `nullifier` is the secret's
+    fn validate_nullifier_consistency(&self, nullifier: [BaseElement; NULLIFIER_LIMBS]) -> [BaseElement; NULLIFIER_LIMBS] {
+        let secret = self.secret.map_or([BaseElement::ZERO; SECRET_LIMBS], |SecretLimbs(secret)| secret);
+        let expected = self.compute_nullifier(&secret);
+        core::array::from_fn(|i| nullifier[i] - expected[i])
     }
 
-    /// Validate mint proportionality (1:1 ratio in atomic units)
+    /// Validate mint proportionality (burn less the protocol fee, in atomic units)
     fn validate_mint_proportionality<E: FieldElement<BaseField = BaseElement>>(
         &self,
         burn_amount: E,
         mint_amount: E,
     ) -> E {
-        // Use a conversion rate of 1:1 in atomic units
-        // burn_amount_atomic : mint_amount_atomic = 1:1
-        // This ensures precise conversion without floating point errors
-        mint_amount - burn_amount
+        // mint = burn - floor(burn * fee_bps / 10000); with no fee this is 1:1.
+        // The fee is computed over integers so the floor is exact
+        let fee = E::from(self.protocol_fee());
+        mint_amount - (burn_amount - fee)
     }
 
-    /// Validate state transitions (constraint logic)
-    fn validate_state_transitions<E: FieldElement<BaseField = BaseElement>>(
-        current_state: E,
-        next_state: E,
-    ) -> E {
-        // Valid transitions: 0→1, 1→2, 2→3, or stay in same state
-        // Invalid: jumping multiple states, going backwards, exceeding state 3
-
-        let state_diff = next_state - current_state;
+    /// Protocol fee taken from the burn, derived from the burn and fee public inputs
+    fn protocol_fee(&self) -> BaseElement {
+        let fee_bps = u32::try_from(self.public_inputs.fee_bps.as_int()).unwrap_or(u32::MAX);
+        BaseElement::new(protocol_fee(self.public_inputs.burn_amount.as_int(), fee_bps))
+    }
 
-        // Constraint: diff * (diff - 1) = 0
-        // This is satisfied only when diff = 0 (stay same) OR diff = 1 (advance one step)
-        let valid_diff = state_diff * (state_diff - E::ONE);
+    /// Remainder of the fee division, `burn * fee_bps mod 10000`
+    fn fee_remainder(&self) -> u64 {
+        // Reducing both factors first keeps the product below 10^8
+        let [burn_amount, fee_bps] =
+            [self.public_inputs.burn_amount, self.public_inputs.fee_bps].map(|value| value.as_int() % BPS_DENOMINATOR);
+        burn_amount * fee_bps % BPS_DENOMINATOR
+    }
 
-        // For simplicity, we'll only check the diff constraint
-        // The max state check can be handled at the application level
-        valid_diff
+    /// Recipient binding limb each row of the recipient register holds
+    fn recipient_limb(&self, step: usize) -> BaseElement {
+        self.public_inputs.recipient_binding[step % RECIPIENT_BINDING_LIMBS]
     }
 
-    /// Validate nullifier consistency (constraint logic)
-    fn validate_nullifier_consistency<E: FieldElement<BaseField = BaseElement>>(
-        &self,
-        trace_nullifier: E,
-    ) -> E {
-        // Nullifier must match the computed hash of (secret + burn_amount + "nullifier")
-        // This ensures:
-        // 1. Only someone with the correct secret can generate valid nullifier
-        // 2. Nullifier is tied to specific burn amount (prevents reuse)
-        // 3. Cryptographic integrity of the nullifier value
+    /// Output amount added to the mint sum at each row, zero past the last output
+    fn mint_output_column(&self) -> Vec<BaseElement> {
+        let mut column: Vec<BaseElement> =
+            self.public_inputs.mint_outputs().iter().map(|output| output.amount).collect();
+        column.resize(self.trace_length(), BaseElement::ZERO);
+        column
+    }
 
-        let expected_nullifier = E::from(self.compute_nullifier(&self.secret));
+    /// Mint amount the burn entitles the recipient to after the protocol fee
+    ///
+    /// Computed from public inputs only; the proof itself enforces it through the fee
+    /// constraints (see [`crate::fees`]).
+    #[must_use]
+    pub fn expected_mint_amount(&self) -> BaseElement {
+        self.public_inputs.burn_amount - self.protocol_fee()
+    }
 
-        // Constraint is satisfied when trace_nullifier == expected_nullifier
-        trace_nullifier - expected_nullifier
+    /// Validate state transitions (constraint logic)
+    fn validate_state_transitions<E: FieldElement<BaseField = BaseElement>>(
+        current_state: E,
+        next_state: E,
+    ) -> E {
+        // Valid transitions: 0→1, 1→2, 2→3, or stay in same state.
+        // The final state is enforced by a boundary assertion
+        StateMachineGadget::transition(current_state, next_state)
     }
 }
 
@@ -273,27 +1032,26 @@ impl XfgBurnMintAir {
     pub fn new_with_secret(
         trace_info: TraceInfo,
         public_inputs: BurnMintPublicInputs,
-        secret: BaseElement,
+        secret: [BaseElement; SECRET_LIMBS],
         options: ProofOptions,
     ) -> Self {
-        let constraint_degrees = vec![
-            TransitionConstraintDegree::new(1), // burn amount validation
-            TransitionConstraintDegree::new(1), // mint proportionality
-            TransitionConstraintDegree::new(1), // transaction hash consistency
-            TransitionConstraintDegree::new(1), // recipient hash consistency
-            TransitionConstraintDegree::new(1), // state transitions
-            TransitionConstraintDegree::new(1), // nullifier uniqueness
-            TransitionConstraintDegree::new(1), // commitment validation
-        ];
+        Self::new(trace_info, public_inputs, secret, options)
+    }
+}
 
-        let context = AirContext::new(trace_info, constraint_degrees, 7, options.clone());
+impl Drop for XfgBurnMintAir {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
 
-        Self {
-            context,
-            public_inputs,
-            secret,
-            options,
-        }
+impl std::fmt::Debug for XfgBurnMintAir {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("XfgBurnMintAir")
+            .field("public_inputs", &self.public_inputs)
+            .field("secret", &crate::types::secret::Redacted::bytes(8 * SECRET_LIMBS))
+            .field("options", &self.options)
+            .finish_non_exhaustive()
     }
 }
 
@@ -306,24 +1064,16 @@ impl Air for XfgBurnMintAir {
         public_inputs: Self::PublicInputs,
         options: ProofOptions,
     ) -> Self {
-        let constraint_degrees = vec![
-            TransitionConstraintDegree::new(1), // burn amount validation
-            TransitionConstraintDegree::new(1), // mint proportionality
-            TransitionConstraintDegree::new(1), // network ID consistency
-            TransitionConstraintDegree::new(1), // state transitions
-            TransitionConstraintDegree::new(1), // nullifier uniqueness
-            TransitionConstraintDegree::new(1), // commitment validation
-        ];
-
-        let context = AirContext::new(trace_info, constraint_degrees, 6, options.clone());
-
-        // Use a fixed secret that matches the test secret conversion: [1,2,3,4,5,6,7,8] -> 67305985
-        let secret = BaseElement::from(67305985u32);
+        let config = Self::config_of(&trace_info);
+        let context = Self::build_context(trace_info, &options);
 
+        // The verifier never sees the secret; nullifier and commitment come from public inputs
         Self {
             context,
             public_inputs,
-            secret,
+            config,
+            secret: None,
+            secret_cells: None,
             options,
         }
     }
@@ -332,67 +1082,364 @@ impl Air for XfgBurnMintAir {
         &self.context
     }
 
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let trace_length = self.trace_length();
+        // The round flag repeats over both permutation cycles and stops after them
+        let mut hash_columns = periodic_columns();
+        let round_flag = hash_columns[0].repeat(2);
+        hash_columns[0] = round_flag
+            .into_iter()
+            .chain(std::iter::repeat(BaseElement::ZERO))
+            .take(trace_length)
+            .collect();
+
+        let mut columns = vec![self.mint_output_column(), self.public_inputs.recipient_binding.to_vec()];
+        columns.extend(hash_columns);
+        columns.push(selector(trace_length, 0..CYCLE_LENGTH));
+        columns.push(selector(trace_length, [0, CYCLE_LENGTH]));
+        columns.push(selector(trace_length, [trace_length - 2]));
+        columns
+    }
+
     fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
         &self,
         frame: &EvaluationFrame<E>,
-        _periodic_values: &[E],
+        periodic_values: &[E],
         result: &mut [E],
     ) {
         let current = frame.current();
         let next = frame.next();
 
-        // Extract values from trace registers
-        let burn_amount = current[0];
-        let mint_amount = current[1];
-        let txn_hash = current[2];
-        let recipient_hash = current[3];
-        let current_state = current[4];
-        let nullifier = current[5];
-        let commitment = current[6];
+        // A proof over a narrower trace (an older layout) cannot satisfy the AIR
+        if current.len() < TRACE_WIDTH {
+            result.fill(E::ONE);
+            return;
+        }
+
+        // State transitions validation
+        // Ensures valid state machine progression: init(0) → burn(1) → mint(2) → complete(3)
+        result[0] = Self::validate_state_transitions(current[STATE_REGISTER], next[STATE_REGISTER]);
+
+        // Constant registers (amounts, transaction hash) never change
+        for (i, register) in CONSTANT_REGISTERS.into_iter().enumerate() {
+            result[1 + i] = copy_constraint(current[register], next[register]);
+        }
 
-        let next_state = next[4];
+        // The burn amount bits are binary and accumulate into the burn amount
+        let range = RangeCheckGadget::transition(
+            current[BURN_ACCUMULATOR_REGISTER],
+            next[BURN_BIT_REGISTER],
+            next[BURN_ACCUMULATOR_REGISTER],
+        );
+        let range_start = 1 + CONSTANT_REGISTERS.len();
+        result[range_start..range_start + range.len()].copy_from_slice(&range);
+
+        // Each row adds its mint output amount to the running sum
+        result[range_start + range.len()] =
+            next[MINT_ACCUMULATOR_REGISTER] - (current[MINT_ACCUMULATOR_REGISTER] + periodic_values[0]);
+
+        // Each row holds the recipient binding limb for its position
+        result[range_start + range.len() + 1] = current[RECIPIENT_REGISTER] - periodic_values[1];
+
+        // The fee remainder and its slack are 14-bit values
+        let [carry, input_row, last_row] = [0, 1, 2].map(|i| periodic_values[2 + NUM_PERIODIC_COLUMNS + i]);
+        let fee_start = range_start + range.len() + 2;
+        let remainder_range = RangeCheckGadget::transition(
+            current[FEE_REMAINDER_ACCUMULATOR_REGISTER],
+            next[FEE_REMAINDER_BIT_REGISTER],
+            next[FEE_REMAINDER_ACCUMULATOR_REGISTER],
+        );
+        let slack_range = RangeCheckGadget::transition(
+            current[FEE_SLACK_ACCUMULATOR_REGISTER],
+            next[FEE_SLACK_BIT_REGISTER],
+            next[FEE_SLACK_ACCUMULATOR_REGISTER],
+        );
+        result[fee_start..fee_start + 2].copy_from_slice(&remainder_range);
+        result[fee_start + 2..fee_start + 4].copy_from_slice(&slack_range);
+
+        // On the last row, remainder and slack (less their leading ones) add up to one less
+        // than the denominator, and the fee `burn - mint` is the quotient of
+        // `burn * fee_bps` by the denominator with that remainder
+        let denominator = E::from(BaseElement::new(BPS_DENOMINATOR));
+        let leading_one = E::from(BaseElement::new(1 << FEE_REMAINDER_RANGE.bits()));
+        let [remainder, slack] = [FEE_REMAINDER_ACCUMULATOR_REGISTER, FEE_SLACK_ACCUMULATOR_REGISTER]
+            .map(|register| next[register] - leading_one);
+        let fee = next[0] - next[1];
+        result[fee_start + 4] = last_row * (remainder + slack - (denominator - E::ONE));
+        result[fee_start + 5] =
+            last_row * (next[0] * E::from(self.public_inputs.fee_bps) - fee * denominator - remainder);
+
+        // The secret is carried unchanged from the nullifier input to the commitment input,
+        // where both permutations take it
+        let hash_periodic = &periodic_values[2..2 + NUM_PERIODIC_COLUMNS];
+        let hash = &current[HASH_REGISTERS];
+        let hash_start = fee_start + 6;
+        for (limb, (register, slot)) in SECRET_REGISTERS.zip(SECRET_SLOTS).enumerate() {
+            result[hash_start + limb] = carry * copy_constraint(current[register], next[register]);
+            result[hash_start + SECRET_LIMBS + limb] = input_row * (hash[slot] - current[register]);
+        }
 
-        // Constraint 1: Burn amount validation
-        result[0] = self.validate_burn_amount(burn_amount);
+        // One Rescue-Prime round per row of both cycles
+        enforce_round(hash, &next[HASH_REGISTERS], hash_periodic, &mut result[hash_start + 2 * SECRET_LIMBS..]);
+    }
 
-        // Constraint 2: Mint proportionality (mint_amount = burn_amount * conversion_rate)
-        result[1] = self.validate_mint_proportionality(burn_amount, mint_amount);
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // The fee constraints tie the mint register to the burn register, so the proof
+        // only holds when the claimed mint honours the protocol fee
+        let constant_registers: [(usize, BaseElement); CONSTANT_REGISTERS.len()] = [
+            (0, self.public_inputs.burn_amount),
+            (1, self.public_inputs.mint_amount),
+            (2, self.public_inputs.txn_hash),
+        ];
+
+        let mut assertions = Vec::with_capacity(NUM_ASSERTIONS);
 
-        // Constraint 3: Transaction hash consistency
-        result[2] = txn_hash - E::from(self.public_inputs.txn_hash.as_int() as u32);
+        // Pin constant registers on every row (even and odd steps)
+        for (register, value) in constant_registers {
+            assertions.extend(pin_register(register, value));
+        }
 
-        // Constraint 4: Recipient hash consistency
-        result[3] = recipient_hash - E::from(self.public_inputs.recipient_hash.as_int() as u32);
+        // Pin every recipient binding limb, each on the rows it occupies
+        for (limb, value) in self.public_inputs.recipient_binding.into_iter().enumerate() {
+            assertions.push(Assertion::periodic(RECIPIENT_REGISTER, limb, RECIPIENT_BINDING_LIMBS, value));
+        }
 
-        // Constraint 5: State transitions validation
-        // Ensures valid state machine progression: init(0) → burn(1) → mint(2) → complete(3)
-        result[4] = Self::validate_state_transitions(current_state, next_state);
+        // Start in init state and reach the complete state where the policy requires
+        assertions.extend(self.config.states().boundary_assertions_at(STATE_REGISTER, self.final_step()));
+
+        // The range check accumulator ends at the burn amount
+        assertions.extend(BURN_RANGE.boundary_assertions(
+            BURN_ACCUMULATOR_REGISTER,
+            self.trace_length(),
+            self.public_inputs.burn_amount,
+        ));
+
+        // The mint outputs sum to the mint amount
+        assertions.push(Assertion::single(MINT_ACCUMULATOR_REGISTER, 0, BaseElement::ZERO));
+        assertions.push(Assertion::single(
+            MINT_ACCUMULATOR_REGISTER,
+            self.trace_length() - 1,
+            self.public_inputs.mint_amount,
+        ));
+
+        // The fee remainder and its slack accumulate below a leading one, so both stay
+        // below 2^14
+        for register in [FEE_REMAINDER_ACCUMULATOR_REGISTER, FEE_SLACK_ACCUMULATOR_REGISTER] {
+            assertions.push(FEE_REMAINDER_RANGE.leading_one_assertion(register, self.trace_length()));
+        }
+
+        // Both permutation inputs are public but for the secret slots
+        let no_secret = [BaseElement::ZERO; SECRET_LIMBS];
+        for (step, input) in [
+            (0, nullifier_input(&self.public_inputs, &no_secret)),
+            (CYCLE_LENGTH, commitment_input(&self.public_inputs, &no_secret)),
+        ] {
+            for (register, value) in input.into_iter().enumerate().filter(|(register, _)| !SECRET_SLOTS.contains(register)) {
+                assertions.push(Assertion::single(HASH_REGISTERS.start + register, step, value));
+            }
+        }
 
-        // Constraint 6: Nullifier consistency validation
-        // Ensures nullifier was computed from correct secret and burn amount
-        result[5] = self.validate_nullifier_consistency(nullifier);
+        // Each output row holds its digest: the nullifier, then the commitment
+        for (step, digest) in [
+            (CYCLE_LENGTH - 1, self.public_inputs.nullifier),
+            (HASH_ROWS - 1, self.public_inputs.commitment),
+        ] {
+            for (register, value) in DIGEST_RANGE.zip(digest) {
+                assertions.push(Assertion::single(HASH_REGISTERS.start + register, step, value));
+            }
+        }
 
-        // Constraint 7: Commitment validation - cryptographic integrity
-        let expected_commitment = E::from(self.compute_commitment(&self.secret));
-        result[6] = commitment - expected_commitment;
+        assertions
     }
+}
 
-    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
-        let nullifier = self.compute_nullifier(&self.secret);
+/// Permutation input of the nullifier hash
+fn nullifier_input(
+    public_inputs: &BurnMintPublicInputs,
+    secret: &[BaseElement; SECRET_LIMBS],
+) -> [BaseElement; STATE_WIDTH] {
+    BURN_NULLIFIER.input_state(secret, &public_inputs.nullifier_scope())
+}
 
-        vec![
-            // Initial state assertions
-            Assertion::single(0, 0, self.public_inputs.burn_amount),
-            Assertion::single(1, 0, self.public_inputs.mint_amount),
-            Assertion::single(2, 0, self.public_inputs.txn_hash),
-            Assertion::single(3, 0, self.public_inputs.recipient_hash),
-            Assertion::single(4, 0, BaseElement::from(0u32)), // Start in init state
-            Assertion::single(5, 0, nullifier),               // Initial nullifier
-            Assertion::single(6, 0, self.compute_commitment(&self.secret)), // Initial commitment
-            // Final state assertions
-            Assertion::single(4, 63, BaseElement::from(3u32)), // End in complete state
-        ]
+/// Permutation input of the commitment hash
+fn commitment_input(
+    public_inputs: &BurnMintPublicInputs,
+    secret: &[BaseElement; SECRET_LIMBS],
+) -> [BaseElement; STATE_WIDTH] {
+    absorb(&CommitmentPreimage::new(*secret, public_inputs).commitment_input())
+}
+
+/// Burn & mint trace columns that depend on the secret
+///
+/// One column per register of [`SECRET_BOUND_REGISTERS`], over the whole trace: the
+/// secret carry and both permutations, with their blinding rows. A secret holder computes
+/// them with [`Self::derive`] so a host can prove without keeping the secret (see
+/// [`crate::secret_oracle`]). Registers 12–15 hold the secret itself, so whoever proves
+/// with the cells learns it. Wiped when dropped.
+pub struct SecretTraceCells {
+    columns: Vec<Vec<BaseElement>>,
+}
+
+impl SecretTraceCells {
+    /// Compute the cells of the claim in `public_inputs` over `trace_length` rows
+    ///
+    /// The nullifier and commitment fields of `public_inputs` are not read.
+    #[must_use]
+    pub fn derive(
+        secret: &[BaseElement; SECRET_LIMBS],
+        public_inputs: &BurnMintPublicInputs,
+        trace_length: usize,
+    ) -> Self {
+        // The secret and both permutations, then blinding for every row no constraint reads
+        let secret_rows = CYCLE_LENGTH + 1;
+        let mut seed = secret.to_vec();
+        seed.push(Rp64_256::hash(BLINDING_DOMAIN).as_elements()[0]);
+        let num_blinding =
+            (trace_length - secret_rows) * SECRET_LIMBS + (trace_length - HASH_ROWS) * HASH_REGISTERS.len();
+        let mut blinding = blinding_elements(&seed, num_blinding).into_iter();
+        wipe_elements(&mut seed);
+
+        let mut columns = Vec::with_capacity(SECRET_BOUND_REGISTERS.len());
+        for &limb in secret {
+            let mut secret_column = vec![limb; secret_rows];
+            secret_column.extend(blinding.by_ref().take(trace_length - secret_rows));
+            columns.push(secret_column);
+        }
+
+        let rows: Vec<_> = permutation_rows(nullifier_input(public_inputs, secret))
+            .into_iter()
+            .chain(permutation_rows(commitment_input(public_inputs, secret)))
+            .collect();
+        for offset in 0..HASH_REGISTERS.len() {
+            let mut column: Vec<_> = rows.iter().map(|row| row[offset]).collect();
+            column.extend(blinding.by_ref().take(trace_length - HASH_ROWS));
+            columns.push(column);
+        }
+
+        Self { columns }
+    }
+
+    /// Cells computed elsewhere, one column per register of [`SECRET_BOUND_REGISTERS`]
+    #[must_use]
+    pub fn from_columns(columns: Vec<Vec<BaseElement>>) -> Self {
+        Self { columns }
+    }
+
+    /// Check the cells fit a trace of `trace_length` rows and hash to the claimed values
+    fn check(&self, public_inputs: &BurnMintPublicInputs, trace_length: usize) -> Result<()> {
+        if self.columns.len() != SECRET_BOUND_REGISTERS.len()
+            || self.columns.iter().any(|column| column.len() != trace_length)
+        {
+            return Err(crate::XfgStarkError::CryptoError(format!(
+                "Secret trace cells must be {} columns of {} rows",
+                SECRET_BOUND_REGISTERS.len(),
+                trace_length
+            )));
+        }
+
+        let digest_at = |row: usize| -> Vec<BaseElement> {
+            DIGEST_RANGE
+                .map(|register| self.columns[HASH_REGISTERS.start - SECRET_REGISTERS.start + register][row])
+                .collect()
+        };
+        if digest_at(CYCLE_LENGTH - 1) != public_inputs.nullifier {
+            return Err(crate::XfgStarkError::CryptoError(
+                "Nullifier does not match the secret trace cells".to_string(),
+            ));
+        }
+        if digest_at(HASH_ROWS - 1) != public_inputs.commitment {
+            return Err(crate::XfgStarkError::CryptoError(
+                "Commitment does not match the secret trace cells".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for SecretTraceCells {
+    fn drop(&mut self) {
+        for column in &mut self.columns {
+            wipe_elements(column);
+        }
+    }
+}
+
+impl std::fmt::Debug for SecretTraceCells {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretTraceCells")
+            .field("columns", &self.columns.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Burn & mint execution trace that wipes its [`SECRET_BOUND_REGISTERS`] when dropped
+///
+/// Winterfell takes ownership of the trace it proves and frees it without clearing it;
+/// [`BurnMintStatementProver`] proves this trace instead of a [`TraceTable`] so the
+/// secret and its permutation rows do not outlive the proof in the trace buffer.
+pub struct BurnMintTrace {
+    layout: TraceLayout,
+    meta: Vec<u8>,
+    main: ColMatrix<BaseElement>,
+}
+
+impl Trace for BurnMintTrace {
+    type BaseField = BaseElement;
+
+    fn layout(&self) -> &TraceLayout {
+        &self.layout
+    }
+
+    fn length(&self) -> usize {
+        self.main.num_rows()
+    }
+
+    fn meta(&self) -> &[u8] {
+        &self.meta
+    }
+
+    fn main_segment(&self) -> &ColMatrix<BaseElement> {
+        &self.main
+    }
+
+    fn build_aux_segment<E>(&mut self, _aux_segments: &[ColMatrix<E>], _rand_elements: &[E]) -> Option<ColMatrix<E>>
+    where
+        E: FieldElement<BaseField = BaseElement>,
+    {
+        None
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<BaseElement>) {
+        let next_row_idx = (row_idx + 1) % self.length();
+        self.main.read_row_into(row_idx, frame.current_mut());
+        self.main.read_row_into(next_row_idx, frame.next_mut());
+    }
+}
+
+impl Drop for BurnMintTrace {
+    fn drop(&mut self) {
+        for register in SECRET_BOUND_REGISTERS {
+            wipe_elements(self.main.get_column_mut(register));
+        }
+    }
+}
+
+impl std::fmt::Debug for BurnMintTrace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BurnMintTrace")
+            .field("length", &self.length())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Periodic column over the whole trace, 1 on `rows` and 0 elsewhere
+fn selector(trace_length: usize, rows: impl IntoIterator<Item = usize>) -> Vec<BaseElement> {
+    let mut column = vec![BaseElement::ZERO; trace_length];
+    for row in rows {
+        column[row] = BaseElement::ONE;
     }
+    column
 }
 
 /// Generate execution trace for burn & mint operation
@@ -401,90 +1448,203 @@ pub fn generate_burn_mint_trace(
     mint_amount: u64,
     txn_hash: u64,
     recipient_hash: u64,
-    secret: BaseElement,
+    secret: [BaseElement; SECRET_LIMBS],
     air: &XfgBurnMintAir,
 ) -> Result<TraceTable<BaseElement>> {
     let mut trace_data = Vec::new();
 
-    // Generate 64 steps of execution trace
-    for step in 0..64 {
-        let state = if step < 16 {
-            0
-        } else if step < 32 {
-            1
-        } else if step < 48 {
-            2
-        } else {
-            3
-        };
+    // The hashes are the same on every row
+    let nullifier = air.compute_nullifier(&secret);
+    let commitment = air.compute_commitment(&secret);
 
-        let nullifier = air.compute_nullifier(&secret);
-        let commitment = air.compute_commitment(&secret);
+    // Generate the execution trace
+    for step in 0..air.trace_length() {
+        let state = air.config().states().state_completing_at(step, air.final_step());
 
-        let row = vec![
+        let mut row = vec![
             BaseElement::from(burn_amount as u32), // Register 0: Burn amount
             BaseElement::from(mint_amount as u32), // Register 1: Mint amount
             BaseElement::from(txn_hash as u32),    // Register 2: Transaction hash
             BaseElement::from(recipient_hash as u32), // Register 3: Recipient hash
             BaseElement::from(state as u32),       // Register 4: State
-            nullifier,                             // Register 5: Nullifier
-            commitment,                            // Register 6: Commitment
         ];
+        row.extend_from_slice(&nullifier);
+        row.extend_from_slice(&commitment);
 
         trace_data.push(row);
     }
 
-    Ok(TraceTable::new(7, trace_data.len()))
+    Ok(TraceTable::new(TRACE_WIDTH, trace_data.len()))
 }
 
 impl XfgBurnMintAir {
     /// Builds an execution trace for the burn & mint operation
+    ///
+    /// The trace has the AIR's length, any power of two with room for the burn range
+    /// check and the mint outputs. Columns are filled independently, in parallel with
+    /// the `parallel` feature.
+    ///
+    /// The table is not wiped when dropped; [`Self::build_proving_trace`] builds a trace
+    /// that is.
+    ///
+    /// # Panics
+    /// If the AIR was built without the secret or its trace cells (through [`Air::new`]);
+    /// build it with [`Self::new`] or [`Self::with_secret_cells`] instead.
     pub fn build_trace(&self) -> TraceTable<BaseElement> {
-        let mut reg0 = Vec::new(); // Burn amount
-        let mut reg1 = Vec::new(); // Mint amount
-        let mut reg2 = Vec::new(); // Transaction hash
-        let mut reg3 = Vec::new(); // Recipient hash
-        let mut reg4 = Vec::new(); // State
-        let mut reg5 = Vec::new(); // Nullifier
-        let mut reg6 = Vec::new(); // Commitment
-
-        let nullifier = self.compute_nullifier(&self.secret);
-        let commitment = self.compute_commitment(&self.secret);
-
-        // Generate 64 steps of execution trace
-        for step in 0..64 {
-            let state = if step < 16 {
-                0
-            } else if step < 32 {
-                1
-            } else if step < 48 {
-                2
-            } else {
-                3
-            };
+        // The metadata carries the final-state policy into the proof
+        let mut trace = TraceTable::init(self.trace_columns());
+        trace.set_meta(self.trace_info().meta().to_vec());
+        trace
+    }
 
-            reg0.push(self.public_inputs.burn_amount);
-            reg1.push(self.public_inputs.mint_amount);
-            reg2.push(self.public_inputs.txn_hash);
-            reg3.push(self.public_inputs.recipient_hash);
-            reg4.push(BaseElement::from(state as u32));
-            reg5.push(nullifier);
-            reg6.push(commitment);
+    /// Build the execution trace as [`Self::build_trace`] does, wiping its secret
+    /// registers when dropped
+    ///
+    /// # Panics
+    /// As [`Self::build_trace`].
+    #[must_use]
+    pub fn build_proving_trace(&self) -> BurnMintTrace {
+        BurnMintTrace {
+            layout: TraceLayout::new(TRACE_WIDTH, [0], [0]),
+            meta: self.trace_info().meta().to_vec(),
+            main: ColMatrix::new(self.trace_columns()),
         }
+    }
+
+    /// Every column of the execution trace
+    fn trace_columns(&self) -> Vec<Vec<BaseElement>> {
+        let derived;
+        let cells = match (self.secret, &self.secret_cells) {
+            (Some(SecretLimbs(secret)), _) => {
+                derived = SecretTraceCells::derive(&secret, &self.public_inputs, self.trace_length());
+                &derived
+            }
+            (None, Some(cells)) => cells,
+            (None, None) => panic!(
+                "the burn & mint trace needs the secret; build the AIR with XfgBurnMintAir::new"
+            ),
+        };
+
+        #[cfg(feature = "parallel")]
+        let registers = (0..TRACE_WIDTH).into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let registers = 0..TRACE_WIDTH;
+
+        let mut columns: Vec<Vec<BaseElement>> = registers.map(|register| self.trace_column(register)).collect();
+
+        // Both range check columns come from one decomposition of at most 53 rows
+        let [bits, accumulator] = BURN_RANGE.columns(self.public_inputs.burn_amount.as_int(), self.trace_length());
+        columns[BURN_BIT_REGISTER] = bits;
+        columns[BURN_ACCUMULATOR_REGISTER] = accumulator;
+
+        // The fee remainder and its slack, each below a leading one
+        let remainder = self.fee_remainder();
+        let [bits, accumulator] = FEE_REMAINDER_RANGE.leading_one_columns(remainder, self.trace_length());
+        columns[FEE_REMAINDER_BIT_REGISTER] = bits;
+        columns[FEE_REMAINDER_ACCUMULATOR_REGISTER] = accumulator;
+        let [bits, accumulator] =
+            FEE_REMAINDER_RANGE.leading_one_columns(BPS_DENOMINATOR - 1 - remainder, self.trace_length());
+        columns[FEE_SLACK_BIT_REGISTER] = bits;
+        columns[FEE_SLACK_ACCUMULATOR_REGISTER] = accumulator;
+
+        for (column, cells) in columns[SECRET_BOUND_REGISTERS].iter_mut().zip(&cells.columns) {
+            column.clone_from(cells);
+        }
+        columns
+    }
 
-        TraceTable::init(vec![reg0, reg1, reg2, reg3, reg4, reg5, reg6])
+    /// Values of `register` on every row of the trace
+    ///
+    /// The range check, secret and permutation columns are left empty for
+    /// [`Self::build_trace`] to fill.
+    fn trace_column(&self, register: usize) -> Vec<BaseElement> {
+        let trace_length = self.trace_length();
+        let inputs = &self.public_inputs;
+        match register {
+            0 => vec![inputs.burn_amount; trace_length],
+            1 => vec![inputs.mint_amount; trace_length],
+            2 => vec![inputs.txn_hash; trace_length],
+            RECIPIENT_REGISTER => (0..trace_length).map(|step| self.recipient_limb(step)).collect(),
+            STATE_REGISTER => {
+                let (states, final_step) = (self.config.states(), self.final_step());
+                (0..trace_length)
+                    .map(|step| BaseElement::from(states.state_completing_at(step, final_step)))
+                    .collect()
+            }
+            BURN_BIT_REGISTER | BURN_ACCUMULATOR_REGISTER => Vec::new(),
+            MINT_ACCUMULATOR_REGISTER => self
+                .mint_output_column()
+                .iter()
+                .scan(BaseElement::ZERO, |sum, amount| {
+                    let row = *sum;
+                    *sum += *amount;
+                    Some(row)
+                })
+                .collect(),
+            _ if register < TRACE_WIDTH => Vec::new(),
+            _ => unreachable!("burn & mint trace has {} registers", TRACE_WIDTH),
+        }
     }
 }
 
-impl Prover for XfgBurnMintAir {
+/// Burn & mint prover committing with hash function `H`
+///
+/// Defaults to [`StatementHasher`]; [`crate::statements::HashFunction`] selects the
+/// hasher at runtime.
+pub struct BurnMintStatementProver<'a, H = StatementHasher> {
+    air: &'a XfgBurnMintAir,
+    progress: SharedProgress,
+    lde_strategy: LdeStrategy,
+    trace_inv_twiddles: Option<Arc<[BaseElement]>>,
+    _hasher: PhantomData<H>,
+}
+
+impl<'a, H> BurnMintStatementProver<'a, H> {
+    /// Prove the statement `air` describes
+    #[must_use]
+    pub fn new(air: &'a XfgBurnMintAir) -> Self {
+        Self {
+            air,
+            progress: SharedProgress::none(),
+            lde_strategy: LdeStrategy::InMemory,
+            trace_inv_twiddles: None,
+            _hasher: PhantomData,
+        }
+    }
+
+    /// Report the finished low-degree extension to `progress`
+    #[must_use]
+    pub fn with_progress(mut self, progress: SharedProgress) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Extend and commit to the trace as `lde_strategy` directs (in memory by default)
+    #[must_use]
+    pub fn with_lde_strategy(mut self, lde_strategy: LdeStrategy) -> Self {
+        self.lde_strategy = lde_strategy;
+        self
+    }
+
+    /// Interpolate the trace with these inverse twiddles instead of computing them
+    #[must_use]
+    pub fn with_trace_inv_twiddles(mut self, trace_inv_twiddles: Arc<[BaseElement]>) -> Self {
+        self.trace_inv_twiddles = Some(trace_inv_twiddles);
+        self
+    }
+}
+
+impl<H> Prover for BurnMintStatementProver<'_, H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
     type BaseField = BaseElement;
     type Air = XfgBurnMintAir;
-    type Trace = TraceTable<BaseElement>;
-    type HashFn = winterfell::crypto::hashers::Blake3_256<BaseElement>;
-    type RandomCoin =
-        winterfell::crypto::DefaultRandomCoin<winterfell::crypto::hashers::Blake3_256<BaseElement>>;
+    type Trace = BurnMintTrace;
+    type HashFn = H;
+    type RandomCoin = DefaultRandomCoin<H>;
     type TraceLde<E>
-        = winterfell::DefaultTraceLde<E, winterfell::crypto::hashers::Blake3_256<BaseElement>>
+        = ConstantColumnTraceLde<E, H>
     where
         E: winterfell::math::FieldElement<BaseField = Self::BaseField>;
     type ConstraintEvaluator<'a, E>
@@ -494,11 +1654,11 @@ impl Prover for XfgBurnMintAir {
 
     fn get_pub_inputs(&self, _trace: &Self::Trace) -> <Self::Air as Air>::PublicInputs {
         // Return the actual public inputs from the AIR
-        self.public_inputs.clone()
+        self.air.public_inputs.clone()
     }
 
     fn options(&self) -> &ProofOptions {
-        &self.options
+        &self.air.options
     }
 
     fn new_trace_lde<E>(
@@ -510,7 +1670,14 @@ impl Prover for XfgBurnMintAir {
     where
         E: winterfell::math::FieldElement<BaseField = Self::BaseField>,
     {
-        winterfell::DefaultTraceLde::new(trace_info, main_trace, domain)
+        let lde = match &self.trace_inv_twiddles {
+            Some(inv_twiddles) => {
+                ConstantColumnTraceLde::with_inv_twiddles(trace_info, main_trace, domain, self.lde_strategy, inv_twiddles)
+            }
+            None => ConstantColumnTraceLde::with_strategy(trace_info, main_trace, domain, self.lde_strategy),
+        };
+        self.progress.report(ProvingPhase::LdeDone);
+        lde
     }
 
     fn new_evaluator<'a, E>(
@@ -533,78 +1700,84 @@ impl Prover for XfgBurnMintAir {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::air::hash_gadget::NUM_ROUND_CONSTRAINTS;
 
-    #[test]
-    fn test_burn_mint_air_creation() {
-        let trace_info = TraceInfo::new(7, 64);
+    /// Secret the prover tests burn with, as the AIR holds it
+    fn test_secret() -> [BaseElement; SECRET_LIMBS] {
+        crate::burn_mint_prover::secret_to_field_elements(&[9u8; 32]).unwrap()
+    }
 
-        // Create AIR instance to compute real transaction hashes
-        let temp_secret = BaseElement::from(42u32);
-        let temp_public_inputs = BurnMintPublicInputs {
-            burn_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units
-            mint_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units (1:1 ratio)
-            txn_hash: BaseElement::from(12345u32), // Temporary placeholder
-            recipient_hash: BaseElement::from(67890u32), // Temporary placeholder
-            state: BaseElement::from(0u32),
-        };
-        let temp_options = ProofOptions::new(42, 8, 4, winterfell::FieldExtension::None, 8, 31);
-        let temp_air = XfgBurnMintAir::new(trace_info.clone(), temp_public_inputs.clone(), temp_secret, temp_options);
+    /// Values of every periodic column at `row`
+    fn periodic_row(periodic: &[Vec<BaseElement>], row: usize) -> Vec<BaseElement> {
+        periodic.iter().map(|column| column[row % column.len()]).collect()
+    }
+
+    /// Index of the recipient limb constraint
+    const RECIPIENT_CONSTRAINT: usize = 1 + CONSTANT_REGISTERS.len() + 2 + 1;
 
-        // Compute real transaction and recipient hashes
-        let real_txn_hash = temp_air.compute_transaction_hash();
-        let real_recipient_hash = temp_air.compute_recipient_hash();
+    /// Index of the first fee constraint: both range checks, then the slack sum and the
+    /// division
+    const FEE_CONSTRAINT: usize = RECIPIENT_CONSTRAINT + 1;
 
-        // Convert hash bytes to field elements
-        let txn_hash_field = BaseElement::from(u32::from_le_bytes([real_txn_hash[0], real_txn_hash[1], real_txn_hash[2], real_txn_hash[3]]));
-        let recipient_hash_field = BaseElement::from(u32::from_le_bytes([real_recipient_hash[0], real_recipient_hash[1], real_recipient_hash[2], real_recipient_hash[3]]));
+    /// Index of the first secret carry constraint
+    const SECRET_CONSTRAINT: usize = FEE_CONSTRAINT + 6;
+
+    #[test]
+    fn test_burn_mint_air_creation() {
+        let trace_info = TraceInfo::new(TRACE_WIDTH, 64);
 
         let public_inputs = BurnMintPublicInputs {
             burn_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units
             mint_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units (1:1 ratio)
-            txn_hash: txn_hash_field, // Real computed Fuego transaction hash
-            recipient_hash: recipient_hash_field, // Real computed recipient hash
+            txn_hash: BaseElement::from(12345u32),
+            recipient_hash: BaseElement::from(67890u32),
+            recipient_binding: [BaseElement::from(0u32); RECIPIENT_BINDING_LIMBS],
             state: BaseElement::from(0u32),
+            tx_prefix_hash_0: BaseElement::from(0u32),
+            tx_prefix_hash_1: BaseElement::from(0u32),
+            tx_prefix_hash_2: BaseElement::from(0u32),
+            tx_prefix_hash_3: BaseElement::from(0u32),
+            network_id: BaseElement::from(1u32),
+            target_chain_id: BaseElement::from(42161u32),
+            commitment_version: BaseElement::from(1u32),
+            fee_bps: BaseElement::from(0u32),
+            nullifier: [BaseElement::from(0u32); NULLIFIER_LIMBS],
+            commitment: [BaseElement::from(0u32); COMMITMENT_LIMBS],
+            outputs: Vec::new(),
         };
-        let secret = BaseElement::from(42u32);
+        let secret = [BaseElement::from(42u32); SECRET_LIMBS];
         let options = ProofOptions::new(42, 8, 4, winterfell::FieldExtension::None, 8, 31);
 
         let air = XfgBurnMintAir::new(trace_info, public_inputs, secret, options);
 
-        assert_eq!(air.trace_info().width(), 7);
+        assert_eq!(air.trace_info().width(), TRACE_WIDTH);
         assert_eq!(air.trace_info().length(), 64);
     }
 
     #[test]
     fn test_nullifier_computation() {
-        let trace_info = TraceInfo::new(7, 64);
-
-        // Create AIR instance to compute real transaction hashes
-        let temp_secret = BaseElement::from(42u32);
-        let temp_public_inputs = BurnMintPublicInputs {
-            burn_amount: BaseElement::from(8_000_000u32),
-            mint_amount: BaseElement::from(8_000_000u32),
-            txn_hash: BaseElement::from(67890u32), // Temporary
-            recipient_hash: BaseElement::from(11111u32), // Temporary
-            state: BaseElement::from(0u32),
-        };
-        let temp_options = ProofOptions::new(42, 8, 4, winterfell::FieldExtension::None, 8, 31);
-        let temp_air = XfgBurnMintAir::new(trace_info.clone(), temp_public_inputs.clone(), temp_secret, temp_options);
-
-        // Compute real hashes
-        let real_txn_hash = temp_air.compute_transaction_hash();
-        let real_recipient_hash = temp_air.compute_recipient_hash();
-
-        let txn_hash_field = BaseElement::from(u32::from_le_bytes([real_txn_hash[4], real_txn_hash[5], real_txn_hash[6], real_txn_hash[7]]));
-        let recipient_hash_field = BaseElement::from(u32::from_le_bytes([real_recipient_hash[4], real_recipient_hash[5], real_recipient_hash[6], real_recipient_hash[7]]));
+        let trace_info = TraceInfo::new(TRACE_WIDTH, 64);
 
         let public_inputs = BurnMintPublicInputs {
             burn_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units
             mint_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units (1:1 ratio)
-            txn_hash: txn_hash_field, // Real computed Fuego transaction hash
-            recipient_hash: recipient_hash_field, // Real computed recipient hash
+            txn_hash: BaseElement::from(67890u32),
+            recipient_hash: BaseElement::from(11111u32),
+            recipient_binding: [BaseElement::from(0u32); RECIPIENT_BINDING_LIMBS],
             state: BaseElement::from(0u32),
+            tx_prefix_hash_0: BaseElement::from(0u32),
+            tx_prefix_hash_1: BaseElement::from(0u32),
+            tx_prefix_hash_2: BaseElement::from(0u32),
+            tx_prefix_hash_3: BaseElement::from(0u32),
+            network_id: BaseElement::from(1u32),
+            target_chain_id: BaseElement::from(42161u32),
+            commitment_version: BaseElement::from(1u32),
+            fee_bps: BaseElement::from(0u32),
+            nullifier: [BaseElement::from(0u32); NULLIFIER_LIMBS],
+            commitment: [BaseElement::from(0u32); COMMITMENT_LIMBS],
+            outputs: Vec::new(),
         };
-        let secret = BaseElement::from(42u32);
+        let secret = [BaseElement::from(42u32); SECRET_LIMBS];
         let options = ProofOptions::new(42, 8, 4, winterfell::FieldExtension::None, 8, 31);
 
         let air = XfgBurnMintAir::new(trace_info, public_inputs, secret, options);
@@ -617,35 +1790,28 @@ mod tests {
 
     #[test]
     fn test_commitment_computation() {
-        let trace_info = TraceInfo::new(7, 64);
-
-        // Create AIR instance to compute real transaction hashes
-        let temp_secret = BaseElement::from(42u32);
-        let temp_public_inputs = BurnMintPublicInputs {
-            burn_amount: BaseElement::from(8_000_000u32),
-            mint_amount: BaseElement::from(8_000_000u32),
-            txn_hash: BaseElement::from(11111u32), // Temporary
-            recipient_hash: BaseElement::from(22222u32), // Temporary
-            state: BaseElement::from(0u32),
-        };
-        let temp_options = ProofOptions::new(42, 8, 4, winterfell::FieldExtension::None, 8, 31);
-        let temp_air = XfgBurnMintAir::new(trace_info.clone(), temp_public_inputs.clone(), temp_secret, temp_options);
-
-        // Compute real hashes
-        let real_txn_hash = temp_air.compute_transaction_hash();
-        let real_recipient_hash = temp_air.compute_recipient_hash();
-
-        let txn_hash_field = BaseElement::from(u32::from_le_bytes([real_txn_hash[8], real_txn_hash[9], real_txn_hash[10], real_txn_hash[11]]));
-        let recipient_hash_field = BaseElement::from(u32::from_le_bytes([real_recipient_hash[8], real_recipient_hash[9], real_recipient_hash[10], real_recipient_hash[11]]));
+        let trace_info = TraceInfo::new(TRACE_WIDTH, 64);
 
         let public_inputs = BurnMintPublicInputs {
             burn_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units
             mint_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units (1:1 ratio)
-            txn_hash: txn_hash_field, // Real computed Fuego transaction hash
-            recipient_hash: recipient_hash_field, // Real computed recipient hash
+            txn_hash: BaseElement::from(11111u32),
+            recipient_hash: BaseElement::from(22222u32),
+            recipient_binding: [BaseElement::from(0u32); RECIPIENT_BINDING_LIMBS],
             state: BaseElement::from(0u32),
+            tx_prefix_hash_0: BaseElement::from(0u32),
+            tx_prefix_hash_1: BaseElement::from(0u32),
+            tx_prefix_hash_2: BaseElement::from(0u32),
+            tx_prefix_hash_3: BaseElement::from(0u32),
+            network_id: BaseElement::from(1u32),
+            target_chain_id: BaseElement::from(42161u32),
+            commitment_version: BaseElement::from(1u32),
+            fee_bps: BaseElement::from(0u32),
+            nullifier: [BaseElement::from(0u32); NULLIFIER_LIMBS],
+            commitment: [BaseElement::from(0u32); COMMITMENT_LIMBS],
+            outputs: Vec::new(),
         };
-        let secret = BaseElement::from(42u32);
+        let secret = [BaseElement::from(42u32); SECRET_LIMBS];
         let options = ProofOptions::new(42, 8, 4, winterfell::FieldExtension::None, 8, 31);
 
         let air = XfgBurnMintAir::new(trace_info, public_inputs, secret, options);
@@ -701,35 +1867,28 @@ mod tests {
 
     #[test]
     fn test_nullifier_consistency_validation() {
-        let trace_info = TraceInfo::new(7, 64);
-
-        // Create AIR instance to compute real transaction hashes
-        let temp_secret = BaseElement::from(42u32);
-        let temp_public_inputs = BurnMintPublicInputs {
-            burn_amount: BaseElement::from(8_000_000u32),
-            mint_amount: BaseElement::from(8_000_000u32),
-            txn_hash: BaseElement::from(67890u32), // Temporary
-            recipient_hash: BaseElement::from(33333u32), // Temporary
-            state: BaseElement::from(0u32),
-        };
-        let temp_options = ProofOptions::new(42, 8, 4, winterfell::FieldExtension::None, 8, 31);
-        let temp_air = XfgBurnMintAir::new(trace_info.clone(), temp_public_inputs.clone(), temp_secret, temp_options);
-
-        // Compute real hashes
-        let real_txn_hash = temp_air.compute_transaction_hash();
-        let real_recipient_hash = temp_air.compute_recipient_hash();
-
-        let txn_hash_field = BaseElement::from(u32::from_le_bytes([real_txn_hash[12], real_txn_hash[13], real_txn_hash[14], real_txn_hash[15]]));
-        let recipient_hash_field = BaseElement::from(u32::from_le_bytes([real_recipient_hash[12], real_recipient_hash[13], real_recipient_hash[14], real_recipient_hash[15]]));
+        let trace_info = TraceInfo::new(TRACE_WIDTH, 64);
 
         let public_inputs = BurnMintPublicInputs {
             burn_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units
             mint_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units (1:1 ratio)
-            txn_hash: txn_hash_field, // Real computed Fuego transaction hash
-            recipient_hash: recipient_hash_field, // Real computed recipient hash
+            txn_hash: BaseElement::from(67890u32),
+            recipient_hash: BaseElement::from(33333u32),
+            recipient_binding: [BaseElement::from(0u32); RECIPIENT_BINDING_LIMBS],
             state: BaseElement::from(0u32),
+            tx_prefix_hash_0: BaseElement::from(0u32),
+            tx_prefix_hash_1: BaseElement::from(0u32),
+            tx_prefix_hash_2: BaseElement::from(0u32),
+            tx_prefix_hash_3: BaseElement::from(0u32),
+            network_id: BaseElement::from(1u32),
+            target_chain_id: BaseElement::from(42161u32),
+            commitment_version: BaseElement::from(1u32),
+            fee_bps: BaseElement::from(0u32),
+            nullifier: [BaseElement::from(0u32); NULLIFIER_LIMBS],
+            commitment: [BaseElement::from(0u32); COMMITMENT_LIMBS],
+            outputs: Vec::new(),
         };
-        let secret = BaseElement::from(42u32);
+        let secret = [BaseElement::from(42u32); SECRET_LIMBS];
         let options = ProofOptions::new(42, 8, 4, winterfell::FieldExtension::None, 8, 31);
 
         let air = XfgBurnMintAir::new(trace_info, public_inputs, secret, options);
@@ -738,57 +1897,48 @@ mod tests {
         let correct_nullifier = air.compute_nullifier(&secret);
         assert_eq!(
             air.validate_nullifier_consistency(correct_nullifier),
-            BaseElement::ZERO
+            [BaseElement::ZERO; NULLIFIER_LIMBS]
         ); // Should be valid
 
         // Test with incorrect nullifier
-        let incorrect_nullifier = BaseElement::from(999999u32);
+        let mut incorrect_nullifier = correct_nullifier;
+        incorrect_nullifier[NULLIFIER_LIMBS - 1] += BaseElement::ONE;
         assert_ne!(
             air.validate_nullifier_consistency(incorrect_nullifier),
-            BaseElement::ZERO
+            [BaseElement::ZERO; NULLIFIER_LIMBS]
         ); // Should be invalid
     }
 
     #[test]
     fn test_constraint_completeness() {
-        let trace_info = TraceInfo::new(7, 64);
-
-        // Create AIR instance to compute real transaction hashes
-        let temp_secret = BaseElement::from(12345u32);
-        let temp_public_inputs = BurnMintPublicInputs {
-            burn_amount: BaseElement::from(8_000_000u32),
-            mint_amount: BaseElement::from(8_000_000u32),
-            txn_hash: BaseElement::from(0xabcdef1234567890u64 as u32), // Temporary
-            recipient_hash: BaseElement::from(0x1234567890abcdefu64 as u32), // Temporary
-            state: BaseElement::from(0u32),
-        };
-        let temp_options = ProofOptions::new(42, 8, 4, winterfell::FieldExtension::None, 8, 31);
-        let temp_air = XfgBurnMintAir::new(trace_info.clone(), temp_public_inputs.clone(), temp_secret, temp_options);
-
-        // Compute real hashes
-        let real_txn_hash = temp_air.compute_transaction_hash();
-        let real_recipient_hash = temp_air.compute_recipient_hash();
-
-        let txn_hash_field = BaseElement::from(u32::from_le_bytes([real_txn_hash[16], real_txn_hash[17], real_txn_hash[18], real_txn_hash[19]]));
-        let recipient_hash_field = BaseElement::from(u32::from_le_bytes([real_recipient_hash[16], real_recipient_hash[17], real_recipient_hash[18], real_recipient_hash[19]]));
+        let trace_info = TraceInfo::new(TRACE_WIDTH, 64);
 
         let public_inputs = BurnMintPublicInputs {
             burn_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units
             mint_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units (1:1 ratio)
-            txn_hash: txn_hash_field, // Real computed Fuego transaction hash
-            recipient_hash: recipient_hash_field, // Real computed recipient hash
+            txn_hash: BaseElement::from(0xabcdef1234567890u64 as u32),
+            recipient_hash: BaseElement::from(0x1234567890abcdefu64 as u32),
+            recipient_binding: [BaseElement::from(0u32); RECIPIENT_BINDING_LIMBS],
             state: BaseElement::from(0u32),
+            tx_prefix_hash_0: BaseElement::from(0u32),
+            tx_prefix_hash_1: BaseElement::from(0u32),
+            tx_prefix_hash_2: BaseElement::from(0u32),
+            tx_prefix_hash_3: BaseElement::from(0u32),
+            network_id: BaseElement::from(1u32),
+            target_chain_id: BaseElement::from(42161u32),
+            commitment_version: BaseElement::from(1u32),
+            fee_bps: BaseElement::from(0u32),
+            nullifier: [BaseElement::from(0u32); NULLIFIER_LIMBS],
+            commitment: [BaseElement::from(0u32); COMMITMENT_LIMBS],
+            outputs: Vec::new(),
         };
-        let secret = BaseElement::from(12345u32);
+        let secret = [BaseElement::from(12345u32); SECRET_LIMBS];
         let options = ProofOptions::new(42, 8, 4, winterfell::FieldExtension::None, 8, 31);
 
         let air = XfgBurnMintAir::new(trace_info, public_inputs.clone(), secret, options);
 
         // Test that all constraint validation methods exist and work
-        assert_eq!(
-            air.validate_burn_amount(public_inputs.burn_amount),
-            BaseElement::ZERO
-        );
+        assert!(BURN_RANGE.contains(public_inputs.burn_amount.as_int()));
         assert_eq!(
             air.validate_mint_proportionality(public_inputs.burn_amount, public_inputs.mint_amount),
             BaseElement::ZERO
@@ -804,10 +1954,634 @@ mod tests {
         let nullifier = air.compute_nullifier(&secret);
         assert_eq!(
             air.validate_nullifier_consistency(nullifier),
-            BaseElement::ZERO
+            [BaseElement::ZERO; NULLIFIER_LIMBS]
         );
 
         let commitment = air.compute_commitment(&secret);
         assert_eq!(commitment, air.compute_commitment(&secret)); // Deterministic
     }
+
+    #[test]
+    fn test_debug_redacts_secret() {
+        let secret = [BaseElement::from(0xdead_beef_u32); SECRET_LIMBS];
+        let air = XfgBurnMintAir::new(
+            TraceInfo::new(TRACE_WIDTH, 64),
+            BurnMintPublicInputs {
+                burn_amount: BaseElement::from(8_000_000u32),
+                mint_amount: BaseElement::from(8_000_000u32),
+                txn_hash: BaseElement::from(12345u32),
+                recipient_hash: BaseElement::from(67890u32),
+                recipient_binding: [BaseElement::from(0u32); RECIPIENT_BINDING_LIMBS],
+                state: BaseElement::from(0u32),
+                tx_prefix_hash_0: BaseElement::from(0u32),
+                tx_prefix_hash_1: BaseElement::from(0u32),
+                tx_prefix_hash_2: BaseElement::from(0u32),
+                tx_prefix_hash_3: BaseElement::from(0u32),
+                network_id: BaseElement::from(1u32),
+                target_chain_id: BaseElement::from(42161u32),
+                commitment_version: BaseElement::from(1u32),
+                fee_bps: BaseElement::from(0u32),
+                nullifier: [BaseElement::from(0u32); NULLIFIER_LIMBS],
+                commitment: [BaseElement::from(0u32); COMMITMENT_LIMBS],
+                outputs: Vec::new(),
+            },
+            secret,
+            ProofOptions::new(42, 8, 4, winterfell::FieldExtension::None, 8, 31),
+        );
+
+        let debug = format!("{:?}", air);
+        assert!(debug.contains("[REDACTED 32 bytes]"));
+        assert!(!debug.contains(&secret[0].as_int().to_string()));
+        assert!(!debug.contains("deadbeef"));
+    }
+
+    #[test]
+    fn test_public_inputs_from_user_data() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::fees::FeeModel;
+
+        let prover = XfgBurnMintProver::default().with_fee_model(FeeModel::new(30).unwrap());
+        let (_, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 7_976_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+
+        let mut rebuilt =
+            BurnMintPublicInputs::from_user_data(8_000_000, [7u8; 32], &[0x12u8; 20], 1, 42161, 1).with_fee_bps(30);
+        assert_eq!(rebuilt.mint_amount, BaseElement::new(7_976_000));
+        rebuilt.nullifier = public_inputs.nullifier;
+        rebuilt.commitment = public_inputs.commitment;
+        assert_eq!(rebuilt.to_elements(), public_inputs.to_elements());
+    }
+
+    #[test]
+    fn test_claim_proof_deterministic() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+
+        let prover = XfgBurnMintProver::default();
+        let claim = |tx_prefix_hash: [u8; 32]| {
+            let (proof, public_inputs) = prover
+                .prove_burn_mint_with_public_inputs(
+                    &BurnMintRequest {
+                        burn_amount: 8_000_000,
+                        mint_amount: 8_000_000,
+                        tx_prefix_hash,
+                        recipient_address: &[0x12u8; 20],
+                        network_id: 1,
+                        target_chain_id: 42161,
+                        commitment_version: 1,
+                    },
+                    &[9u8; 32],
+                )
+                .expect("Proof generation should succeed");
+            (proof.to_bytes(), public_inputs.to_elements())
+        };
+
+        // Nothing but the claim reaches the proof, so the same claim proves to the same bytes
+        let (proof, public_inputs) = claim([7u8; 32]);
+        assert_eq!((proof.clone(), public_inputs.clone()), claim([7u8; 32]));
+        let (other_proof, other_inputs) = claim([8u8; 32]);
+        assert_ne!(proof, other_proof);
+        assert_ne!(public_inputs, other_inputs);
+    }
+
+    #[test]
+    fn test_final_state_policy() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::burn_mint_verifier::XfgBurnMintVerifier;
+
+        let policy = FinalStatePolicy::AtStep(40);
+        let trace_info = TraceInfo::with_meta(TRACE_WIDTH, 64, policy.to_trace_meta());
+        assert_eq!(FinalStatePolicy::from_trace_info(&trace_info), Ok(policy));
+        assert_eq!(FinalStatePolicy::from_trace_info(&TraceInfo::new(TRACE_WIDTH, 64)), Ok(FinalStatePolicy::LastRow));
+        assert_eq!(FinalStatePolicy::LastRow.final_step(128), Ok(127));
+        for step in [2, 64] {
+            assert_eq!(
+                FinalStatePolicy::AtStep(step).final_step(64),
+                Err(FinalStatePolicyError::StepOutOfRange { step: step as usize, trace_length: 64 })
+            );
+        }
+        assert!(matches!(
+            FinalStatePolicy::from_trace_info(&TraceInfo::with_meta(TRACE_WIDTH, 64, vec![9])),
+            Err(FinalStatePolicyError::UnknownMetadata(_))
+        ));
+
+        // The verifier reads the policy from the proof and asserts the same row
+        let prover = XfgBurnMintProver::default().with_final_state(policy);
+        let (proof, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let air = <XfgBurnMintAir as Air>::new(proof.get_trace_info(), public_inputs.clone(), proof.options().clone());
+        assert_eq!(air.final_state(), policy);
+        assert!(air
+            .get_assertions()
+            .contains(&Assertion::single(4, 40, BaseElement::from(BURN_MINT_STATES.final_state()))));
+        assert!(XfgBurnMintVerifier::default()
+            .verify_with_public_inputs(&proof, &public_inputs)
+            .unwrap());
+
+        let late = XfgBurnMintProver::default().with_final_state(FinalStatePolicy::AtStep(64));
+        assert!(matches!(
+            late.prove_burn_mint(8_000_000, 8_000_000, [7u8; 32], &[0x12u8; 20], &[9u8; 32], 1, 42161, 1),
+            Err(crate::XfgStarkError::FinalStatePolicyError(_))
+        ));
+    }
+
+    #[test]
+    fn test_burn_mint_config() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::burn_mint_verifier::XfgBurnMintVerifier;
+
+        // The default config writes no metadata, as before the state count was configurable
+        assert!(BurnMintConfig::default().to_trace_meta().is_empty());
+        let config = BurnMintConfig::new(128).with_num_states(6).with_final_state(FinalStatePolicy::AtStep(100));
+        assert_eq!(BurnMintConfig::from_trace_info(&config.to_trace_info()), Ok(config));
+        assert_eq!(config.final_step(), Ok(100));
+
+        assert_eq!(BurnMintConfig::new(32).validate(), Err(BurnMintConfigError::InvalidTraceLength(32)));
+        assert_eq!(BurnMintConfig::default().with_num_states(1).validate(), Err(BurnMintConfigError::InvalidStateCount(1)));
+        assert!(matches!(
+            BurnMintConfig::default().with_num_states(80).validate(),
+            Err(BurnMintConfigError::FinalState(FinalStatePolicyError::StepOutOfRange { .. }))
+        ));
+        // An explicit default state count is not the canonical encoding
+        let mut meta = FinalStatePolicy::LastRow.to_trace_meta();
+        meta.push(NUM_STATES_TAG);
+        meta.extend_from_slice(&4u32.to_le_bytes());
+        assert!(BurnMintConfig::from_trace_info(&TraceInfo::with_meta(TRACE_WIDTH, 64, meta)).is_err());
+
+        // The verifier rebuilds the schedule from the proof
+        let (proof, public_inputs) = XfgBurnMintProver::default()
+            .with_config(config)
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let air = <XfgBurnMintAir as Air>::new(proof.get_trace_info(), public_inputs.clone(), proof.options().clone());
+        assert_eq!(air.config(), &config);
+        assert!(air.get_assertions().contains(&Assertion::single(STATE_REGISTER, 100, BaseElement::from(5u32))));
+        assert!(XfgBurnMintVerifier::default()
+            .verify_with_public_inputs(&proof, &public_inputs)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_constant_registers_copied() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::statements::{check_constraints, ConstraintViolation};
+        use winterfell::Trace;
+
+        let prover = XfgBurnMintProver::default();
+        let (_, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let air = XfgBurnMintAir::new(
+            prover.domain().trace_info.clone(),
+            public_inputs,
+            test_secret(),
+            prover.proof_options().clone(),
+        );
+        // State machine, copies, range check, mint sum, recipient limbs, the fee range
+        // checks, slack sum and division, the secret carry and slot of every limb, and the
+        // rounds
+        let num_constraints = 1
+            + CONSTANT_REGISTERS.len()
+            + RangeCheckGadget::constraint_degrees().len()
+            + 2
+            + 2 * RangeCheckGadget::constraint_degrees().len()
+            + 2
+            + 2 * SECRET_LIMBS
+            + NUM_ROUND_CONSTRAINTS;
+        assert_eq!(air.context().num_main_transition_constraints(), num_constraints);
+
+        let periodic = air.get_periodic_column_values();
+        let evaluate = |trace: &TraceTable<BaseElement>, row: usize| {
+            let mut frame = EvaluationFrame::new(trace.width());
+            trace.read_main_frame(row, &mut frame);
+            let mut result = vec![BaseElement::ZERO; num_constraints];
+            air.evaluate_transition(&frame, &periodic_row(&periodic, row), &mut result);
+            result
+        };
+
+        for (i, register) in CONSTANT_REGISTERS.into_iter().enumerate() {
+            // Change a constant register in a middle row only
+            let mut trace = air.build_trace();
+            let value = trace.get(register, 30);
+            trace.set(register, 30, value + BaseElement::ONE);
+
+            // Entering the row and leaving it both break the copy constraint
+            assert_eq!(evaluate(&trace, 29)[1 + i], BaseElement::ONE);
+            assert_eq!(evaluate(&trace, 30)[1 + i], -BaseElement::ONE);
+            assert!(evaluate(&trace, 28).iter().all(|v| *v == BaseElement::ZERO));
+            assert!(matches!(
+                check_constraints(&air, &trace),
+                Err(ConstraintViolation::Assertion { row: 30, column, .. }) if column == register
+            ));
+        }
+    }
+
+    #[test]
+    fn test_every_recipient_limb_constrained() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::statements::{check_constraints, ConstraintViolation};
+        use winterfell::Trace;
+
+        let prover = XfgBurnMintProver::default();
+        let (_, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let binding = public_inputs.recipient_binding;
+        let air = XfgBurnMintAir::new(
+            prover.domain().trace_info.clone(),
+            public_inputs,
+            test_secret(),
+            prover.proof_options().clone(),
+        );
+        let periodic = air.get_periodic_column_values();
+        assert_eq!(periodic[1], binding);
+
+        for limb in 0..RECIPIENT_BINDING_LIMBS {
+            // Each limb is pinned where it occurs
+            assert!(air
+                .get_assertions()
+                .contains(&Assertion::periodic(RECIPIENT_REGISTER, limb, RECIPIENT_BINDING_LIMBS, binding[limb])));
+
+            let row = 16 + limb;
+            let mut trace = air.build_trace();
+            assert_eq!(trace.get(RECIPIENT_REGISTER, row), binding[limb]);
+            trace.set(RECIPIENT_REGISTER, row, binding[limb] + BaseElement::ONE);
+
+            let mut frame = EvaluationFrame::new(trace.width());
+            trace.read_main_frame(row, &mut frame);
+            let mut result = vec![BaseElement::ZERO; air.context().num_main_transition_constraints()];
+            air.evaluate_transition(&frame, &periodic_row(&periodic, row), &mut result);
+            assert_eq!(result[RECIPIENT_CONSTRAINT], BaseElement::ONE);
+            assert!(matches!(
+                check_constraints(&air, &trace),
+                Err(ConstraintViolation::Assertion { row: r, column: RECIPIENT_REGISTER, .. }) if r == row
+            ));
+        }
+    }
+
+    #[test]
+    fn test_burn_amount_range_checked() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::burn_mint_verifier::XfgBurnMintVerifier;
+        use crate::constants::MAX_BURN_ATOMIC;
+        use crate::statements::{check_constraints, ConstraintViolation};
+
+        // Amounts other than the burn tiers prove and verify
+        let prover = XfgBurnMintProver::default();
+        for amount in [123_456_789, MAX_BURN_ATOMIC] {
+            let (proof, public_inputs) = prover
+                .prove_burn_mint_with_public_inputs(
+                    &BurnMintRequest {
+                        burn_amount: amount,
+                        mint_amount: amount,
+                        tx_prefix_hash: [7u8; 32],
+                        recipient_address: &[0x12u8; 20],
+                        network_id: 1,
+                        target_chain_id: 42161,
+                        commitment_version: 1,
+                    },
+                    &[9u8; 32],
+                )
+                .expect("Proof generation should succeed");
+            assert_eq!(public_inputs.burn_amount, BaseElement::new(amount));
+            assert!(XfgBurnMintVerifier::default()
+                .verify_with_public_inputs(&proof, &public_inputs)
+                .unwrap());
+        }
+
+        // Amounts of 2^53 atomic units or more are rejected before proving
+        assert!(prover
+            .prove_burn_mint(MAX_BURN_ATOMIC + 1, MAX_BURN_ATOMIC + 1, [7u8; 32], &[0x12u8; 20], &[9u8; 32], 1, 42161, 1)
+            .is_err());
+
+        // The accumulator cannot end on a burn amount its bits do not add up to
+        let (_, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let air = XfgBurnMintAir::new(
+            prover.domain().trace_info.clone(),
+            public_inputs,
+            test_secret(),
+            prover.proof_options().clone(),
+        );
+        let mut trace = air.build_trace();
+        assert!(check_constraints(&air, &trace).is_ok());
+        trace.set(BURN_BIT_REGISTER, 63, BaseElement::new(2));
+        assert!(matches!(
+            check_constraints(&air, &trace),
+            Err(ConstraintViolation::Transition { row: 62, constraint, .. }) if constraint == 1 + CONSTANT_REGISTERS.len()
+        ));
+    }
+
+    #[test]
+    fn test_protocol_fee_constrained() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::burn_mint_verifier::XfgBurnMintVerifier;
+        use crate::fees::FeeModel;
+        use crate::statements::{check_constraints, ConstraintViolation};
+        use winterfell::Trace;
+
+        // Fees with and without a remainder prove and verify
+        let prover = XfgBurnMintProver::default().with_fee_model(FeeModel::new(30).unwrap());
+        let verifier = XfgBurnMintVerifier::default().with_fee_model(FeeModel::new(30).unwrap());
+        for (burn, mint) in [(8_000_000, 7_976_000), (123_456_789, 123_086_419)] {
+            let (proof, public_inputs) = prover
+                .prove_burn_mint_with_public_inputs(
+                    &BurnMintRequest {
+                        burn_amount: burn,
+                        mint_amount: mint,
+                        tx_prefix_hash: [7u8; 32],
+                        recipient_address: &[0x12u8; 20],
+                        network_id: 1,
+                        target_chain_id: 42161,
+                        commitment_version: 1,
+                    },
+                    &[9u8; 32],
+                )
+                .expect("Proof generation should succeed");
+            assert!(verifier.verify_with_public_inputs(&proof, &public_inputs).unwrap());
+        }
+
+        let (_, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 123_456_789,
+                    mint_amount: 123_086_419,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let trace_info = prover.domain().trace_info.clone();
+        let last = trace_info.length() - 1;
+        let air = |public_inputs: BurnMintPublicInputs| {
+            XfgBurnMintAir::new(trace_info.clone(), public_inputs, test_secret(), prover.proof_options().clone())
+        };
+        let honest = air(public_inputs.clone());
+        let trace = honest.build_trace();
+        assert!(check_constraints(&honest, &trace).is_ok());
+        assert_eq!(trace.get(FEE_REMAINDER_ACCUMULATOR_REGISTER, last), BaseElement::new((1 << 14) + 3670));
+        assert_eq!(trace.get(FEE_SLACK_ACCUMULATOR_REGISTER, last), BaseElement::new((1 << 14) + 6329));
+
+        // A mint that skips part of the fee breaks the division
+        let mut skimmed = public_inputs.clone();
+        skimmed.mint_amount += BaseElement::ONE;
+        let skimmed = air(skimmed);
+        assert!(matches!(
+            check_constraints(&skimmed, &skimmed.build_trace()),
+            Err(ConstraintViolation::Transition { row, constraint, .. }) if row == last - 1 && constraint == FEE_CONSTRAINT + 5
+        ));
+
+        // Moving one unit of fee into a remainder of 10000 or more leaves no room for the slack
+        let mut trace = honest.build_trace();
+        let [bits, accumulator] = FEE_REMAINDER_RANGE.leading_one_columns(3670 + BPS_DENOMINATOR, trace_info.length());
+        let [slack_bits, slack_accumulator] = FEE_REMAINDER_RANGE.leading_one_columns(0, trace_info.length());
+        for row in 0..trace_info.length() {
+            trace.set(1, row, public_inputs.mint_amount + BaseElement::ONE);
+            trace.set(FEE_REMAINDER_BIT_REGISTER, row, bits[row]);
+            trace.set(FEE_REMAINDER_ACCUMULATOR_REGISTER, row, accumulator[row]);
+            trace.set(FEE_SLACK_BIT_REGISTER, row, slack_bits[row]);
+            trace.set(FEE_SLACK_ACCUMULATOR_REGISTER, row, slack_accumulator[row]);
+        }
+        let mut evaluations = vec![BaseElement::ZERO; honest.context().num_main_transition_constraints()];
+        let periodic = honest.get_periodic_column_values();
+        let mut frame = EvaluationFrame::new(trace.width());
+        trace.read_main_frame(last - 1, &mut frame);
+        honest.evaluate_transition(&frame, &periodic_row(&periodic, last - 1), &mut evaluations);
+        assert_eq!(evaluations[FEE_CONSTRAINT + 5], BaseElement::ZERO);
+        assert_ne!(evaluations[FEE_CONSTRAINT + 4], BaseElement::ZERO);
+    }
+
+    #[test]
+    fn test_mint_outputs_sum_to_mint() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::burn_mint_verifier::XfgBurnMintVerifier;
+        use crate::commitment::{proof_commitment, recipient_binding, recipient_binding_limbs};
+        use crate::constants::MAX_MINT_OUTPUTS;
+        use crate::statements::check_constraints;
+
+        let prover = XfgBurnMintProver::default();
+        let verifier = XfgBurnMintVerifier::default();
+        let request = BurnMintRequest {
+            burn_amount: 8_000_000,
+            mint_amount: 8_000_000,
+            tx_prefix_hash: [7u8; 32],
+            recipient_address: &[0x12; 20],
+            network_id: 1,
+            target_chain_id: 42161,
+            commitment_version: 1,
+        };
+        let outputs: [(&[u8], u64); 3] = [(&[0x12; 20], 5_000_000), (&[0x34; 20], 2_000_000), (&[0x56; 20], 1_000_000)];
+        let (proof, public_inputs) = prover
+            .prove_burn_mint_outputs(&request, &outputs, &[9u8; 32])
+            .expect("Proof generation should succeed");
+        assert_eq!(public_inputs.mint_amount, BaseElement::new(8_000_000));
+        assert_eq!(public_inputs.outputs.len(), 3);
+        assert_eq!(
+            public_inputs.outputs[1].recipient_binding,
+            recipient_binding_limbs(&recipient_binding(&[0x34; 20], 42161))
+        );
+        assert_eq!(public_inputs.commitment, proof_commitment(test_secret(), &public_inputs));
+        assert!(verifier.verify_with_public_inputs(&proof, &public_inputs).unwrap());
+
+        // The outputs travel with the public inputs
+        let elements = public_inputs.to_elements();
+        assert_eq!(elements.len(), BurnMintPublicInputs::NUM_ELEMENTS + 3 * MintOutput::NUM_ELEMENTS);
+        assert_eq!(BurnMintPublicInputs::from_elements(&elements).unwrap().outputs, public_inputs.outputs);
+        assert!(BurnMintPublicInputs::from_elements(&elements[..elements.len() - 1]).is_none());
+
+        // Moving value between recipients changes what the proof binds
+        let mut moved = public_inputs.clone();
+        moved.outputs[1].amount = BaseElement::new(1_000_000);
+        moved.outputs[2].amount = BaseElement::new(2_000_000);
+        assert!(!matches!(verifier.verify_with_public_inputs(&proof, &moved), Ok(true)));
+
+        // Outputs that do not add up to the mint are rejected, and violate the AIR
+        let short = [(outputs[0].0, 5_000_000), (outputs[1].0, 2_000_000)];
+        assert!(prover.prove_burn_mint_outputs(&request, &short, &[9u8; 32]).is_err());
+        let mut unbalanced = public_inputs.clone();
+        unbalanced.outputs[2].amount = BaseElement::new(999_999);
+        let air = XfgBurnMintAir::new(proof.get_trace_info().clone(), unbalanced, test_secret(), proof.options().clone());
+        assert!(air.public_inputs().check_mint_outputs().is_err());
+        assert!(check_constraints(&air, &air.build_trace()).is_err());
+
+        let too_many = vec![(outputs[0].0, 1_000_000); MAX_MINT_OUTPUTS + 1];
+        let mint_amount = 1_000_000 * too_many.len() as u64;
+        let request = BurnMintRequest { burn_amount: mint_amount, mint_amount, ..request };
+        assert!(prover.prove_burn_mint_outputs(&request, &too_many, &[9u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_every_secret_limb_hashed() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::statements::{check_constraints, ConstraintViolation};
+        use winterfell::Trace;
+
+        let prover = XfgBurnMintProver::default();
+        let (_, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let air = XfgBurnMintAir::new(
+            prover.domain().trace_info.clone(),
+            public_inputs,
+            test_secret(),
+            prover.proof_options().clone(),
+        );
+        let secret_constraint = SECRET_CONSTRAINT;
+
+        for (limb, slot) in SECRET_SLOTS.enumerate() {
+            // Hashing another value than the secret limb breaks its slot constraint
+            for row in [0, CYCLE_LENGTH] {
+                let mut trace = air.build_trace();
+                let value = trace.get(HASH_REGISTERS.start + slot, row);
+                trace.set(HASH_REGISTERS.start + slot, row, value + BaseElement::ONE);
+                assert!(matches!(
+                    check_constraints(&air, &trace),
+                    Err(ConstraintViolation::Transition { row: r, constraint, .. })
+                        if r == row && constraint == secret_constraint + SECRET_LIMBS + limb
+                ));
+            }
+
+            // Swapping the limb between the two hashes breaks its carry
+            let mut trace = air.build_trace();
+            let register = SECRET_REGISTERS.start + limb;
+            for row in CYCLE_LENGTH..HASH_ROWS {
+                let value = trace.get(register, row);
+                trace.set(register, row, value + BaseElement::ONE);
+            }
+            assert!(matches!(
+                check_constraints(&air, &trace),
+                Err(ConstraintViolation::Transition { row, constraint, .. })
+                    if row == CYCLE_LENGTH - 1 && constraint == secret_constraint + limb
+            ));
+        }
+    }
+
+    #[test]
+    fn test_forged_nullifier_or_commitment_rejected() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::burn_mint_verifier::XfgBurnMintVerifier;
+        use crate::statements::{check_constraints, ConstraintViolation};
+
+        let prover = XfgBurnMintProver::default();
+        let (proof, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let trace_info = proof.get_trace_info();
+        let honest = XfgBurnMintAir::new(trace_info.clone(), public_inputs.clone(), test_secret(), proof.options().clone());
+        assert!(check_constraints(&honest, &honest.build_trace()).is_ok());
+
+        // A forger publishing another digest finds the trace's output row disagrees
+        for (output_row, forge) in [
+            (CYCLE_LENGTH - 1, (|inputs: &mut BurnMintPublicInputs| &mut inputs.nullifier) as fn(&mut BurnMintPublicInputs) -> &mut [BaseElement; NULLIFIER_LIMBS]),
+            (HASH_ROWS - 1, |inputs: &mut BurnMintPublicInputs| &mut inputs.commitment),
+        ] {
+            let mut forged = public_inputs.clone();
+            forge(&mut forged)[0] += BaseElement::ONE;
+
+            let air = <XfgBurnMintAir as Air>::new(trace_info.clone(), forged.clone(), proof.options().clone());
+            assert!(matches!(
+                check_constraints(&air, &honest.build_trace()),
+                Err(ConstraintViolation::Assertion { column, row, .. })
+                    if column == HASH_REGISTERS.start + DIGEST_RANGE.start && row == output_row
+            ));
+            assert!(!matches!(
+                XfgBurnMintVerifier::default().verify_with_public_inputs(&proof, &forged),
+                Ok(true)
+            ));
+        }
+    }
 }
@@ -2,14 +2,95 @@
 //!
 //! This module implements the Winterfell prover for XFG burn and HEAT mint operations,
 //! providing secure and efficient proof generation.
+//!
+//! ## Thread Safety
+//! `XfgBurnMintProver` is `Send + Sync`, and proofs share no mutable state, so a single
+//! prover can be shared across threads behind an `Arc` instead of being rebuilt per
+//! request. Domain data that does not depend on the statement being proven (trace
+//! layout, LDE domain size, the inverse twiddles the trace is interpolated with and the
+//! [`ProvingKey`] behind [`XfgBurnMintProver::verifying_key`]) is computed lazily on
+//! first use and reused by every proof; proving keys come from the process-wide cache
+//! in [`crate::stark`], so provers of the same shape share them too. Winterfell builds
+//! the `StarkDomain` inside `Prover::prove`, so the forward twiddles the trace is
+//! extended with are still computed per proof.
 
 use crate::ExecutionTrace;
 use crate::{
-    burn_mint_air::{generate_burn_mint_trace, BurnMintPublicInputs, XfgBurnMintAir},
+    burn_mint_air::{BurnMintConfig, BurnMintConfigError, BurnMintPublicInputs, FinalStatePolicy, BurnMintStatementProver, MintOutput, XfgBurnMintAir, SECRET_LIMBS, TRACE_WIDTH},
+    commitment::{
+        recipient_binding, verify_commitment_opening, recipient_binding_limbs, RECIPIENT_ADDRESS_LEN, RECIPIENT_BINDING_LIMBS,
+    },
+    constants::{self, is_valid_burn_amount, is_valid_trace_length, INVALID_BURN_AMOUNT},
+    fees::FeeModel,
+    networks::NetworkParams,
+    proof::{
+        memory::{LdeStrategy, ProverMemoryBudget},
+        progress::{ProgressReporter, ProvingPhase, SharedProgress},
+        CancellationToken,
+    },
+    secret_oracle::{derive_public_values, SecretOracle},
+    stark::{setup, AirShape, ProvingKey, VerifyingKey},
+    statements::{prove_statement_with, HashFunction, Keccak256Hasher, ProverOptions, StatementHasher},
+    types::secret::SecretKey,
     Result,
 };
 use anyhow;
-use winterfell::{math::fields::f64::BaseElement, ProofOptions, Prover, StarkProof, TraceInfo};
+use std::sync::{Arc, OnceLock};
+use winterfell::{
+    crypto::hashers::Rp64_256,
+    math::{fft, fields::f64::BaseElement},
+    ProofOptions, StarkProof, TraceInfo,
+};
+
+/// Domain data shared by all proofs generated with the same prover
+#[derive(Debug, Clone)]
+pub struct ProverDomain {
+    /// Cached proving key for the burn & mint shape
+    pub proving_key: Arc<ProvingKey>,
+    /// Trace layout (registers and steps)
+    pub trace_info: TraceInfo,
+    /// Size of the low-degree extension domain
+    pub lde_domain_size: usize,
+    /// Inverse FFT twiddles for interpolating the trace columns
+    pub trace_inv_twiddles: Arc<[BaseElement]>,
+}
+
+impl ProverDomain {
+    /// Build domain data for the given proof options and trace length
+    fn new(proof_options: &ProofOptions, trace_length: usize) -> Self {
+        let shape = AirShape::new(TRACE_WIDTH, trace_length, proof_options.blowup_factor());
+        // ProofOptions only admits power-of-two blowup factors of at least 2, and the
+        // trace length is checked before the domain is built
+        let proving_key = setup(shape).expect("burn & mint AIR shape is valid");
+        Self {
+            trace_info: TraceInfo::new(TRACE_WIDTH, trace_length),
+            lde_domain_size: shape.lde_domain_size(),
+            trace_inv_twiddles: fft::get_inv_twiddles(trace_length).into(),
+            proving_key,
+        }
+    }
+}
+
+/// Burn & mint claim a proof is generated for
+///
+/// Every field ends up in the proof's public inputs; the secret is passed alongside.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BurnMintRequest<'a> {
+    /// Burn amount in atomic units
+    pub burn_amount: u64,
+    /// Mint amount in atomic units
+    pub mint_amount: u64,
+    /// Full 32-byte transaction prefix hash of the burn
+    pub tx_prefix_hash: [u8; 32],
+    /// Recipient Ethereum address
+    pub recipient_address: &'a [u8; RECIPIENT_ADDRESS_LEN],
+    /// Fuego network ID
+    pub network_id: u32,
+    /// HEAT target chain ID
+    pub target_chain_id: u32,
+    /// Commitment format version
+    pub commitment_version: u32,
+}
 
 /// XFG Burn & Mint Prover using Winterfell
 ///
@@ -20,23 +101,52 @@ pub struct XfgBurnMintProver {
     security_parameter: usize,
     /// Proof options for Winterfell
     proof_options: ProofOptions,
+    /// Protocol fee charged on mints
+    fee_model: FeeModel,
+    /// Network claims must be for, if any
+    network: Option<NetworkParams>,
+    /// Constraint checking and trace dumping before commitment
+    prover_options: ProverOptions,
+    /// Hash function the proof commits with
+    hash_function: HashFunction,
+    /// Trace length and state schedule
+    config: BurnMintConfig,
+    /// Receives proving progress
+    progress: SharedProgress,
+    /// Checked before the witness check and before Winterfell proves
+    cancellation: CancellationToken,
+    /// Cap on the memory of the trace extension and its commitment
+    memory_budget: ProverMemoryBudget,
+    /// Lazily-initialized domain data reused across proofs
+    domain: OnceLock<ProverDomain>,
 }
 
 impl XfgBurnMintProver {
     /// Create new XFG Burn & Mint Prover
     pub fn new(security_parameter: usize) -> Self {
+        // A quadratic extension is needed to clear the verifier's minimum security
+        // policy; over the 64-bit base field alone the proof is limited to ~54 bits.
         let proof_options = ProofOptions::new(
-            42,                               // blowup factor
-            8,                                // grinding factor
-            4,                                // hash function
-            winterfell::FieldExtension::None, // field extension
-            8,                                // FRI folding factor
-            31,                               // FRI remainder max degree
+            42,                                    // number of queries
+            8,                                     // blowup factor
+            4,                                     // grinding factor
+            winterfell::FieldExtension::Quadratic, // field extension
+            8,                                     // FRI folding factor
+            31,                                    // FRI remainder max degree
         );
 
         Self {
             security_parameter,
             proof_options,
+            fee_model: FeeModel::none(),
+            network: None,
+            prover_options: ProverOptions::default(),
+            hash_function: HashFunction::default(),
+            config: BurnMintConfig::default(),
+            progress: SharedProgress::none(),
+            cancellation: CancellationToken::new(),
+            memory_budget: ProverMemoryBudget::unlimited(),
+            domain: OnceLock::new(),
         }
     }
 
@@ -45,16 +155,169 @@ impl XfgBurnMintProver {
         Self {
             security_parameter,
             proof_options,
+            fee_model: FeeModel::none(),
+            network: None,
+            prover_options: ProverOptions::default(),
+            hash_function: HashFunction::default(),
+            config: BurnMintConfig::default(),
+            progress: SharedProgress::none(),
+            cancellation: CancellationToken::new(),
+            memory_budget: ProverMemoryBudget::unlimited(),
+            domain: OnceLock::new(),
         }
     }
 
+    /// Charge a protocol fee on mints (fee-enabled networks)
+    #[must_use]
+    pub fn with_fee_model(mut self, fee_model: FeeModel) -> Self {
+        self.fee_model = fee_model;
+        self
+    }
+
+    /// Protocol fee charged on mints
+    pub fn fee_model(&self) -> FeeModel {
+        self.fee_model
+    }
+
+    /// Only prove claims for `network`
+    #[must_use]
+    pub fn with_network(mut self, network: NetworkParams) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    /// Network claims must be for, if any
+    pub fn network(&self) -> Option<&NetworkParams> {
+        self.network.as_ref()
+    }
+
+    /// Check or dump the trace before committing (checks are on by default in debug builds)
+    #[must_use]
+    pub fn with_prover_options(mut self, prover_options: ProverOptions) -> Self {
+        self.prover_options = prover_options;
+        self
+    }
+
+    /// Constraint checking and trace dumping before commitment
+    pub fn prover_options(&self) -> &ProverOptions {
+        &self.prover_options
+    }
+
+    /// Commit with another hash function (verifiers must use the same one)
+    #[must_use]
+    pub fn with_hash_function(mut self, hash_function: HashFunction) -> Self {
+        self.hash_function = hash_function;
+        self
+    }
+
+    /// Hash function the proof commits with
+    pub fn hash_function(&self) -> HashFunction {
+        self.hash_function
+    }
+
+    /// Require the complete state at another row than the last
+    #[must_use]
+    pub fn with_final_state(mut self, final_state: FinalStatePolicy) -> Self {
+        self.config.final_state = final_state;
+        self
+    }
+
+    /// Row at which the state machine must be complete
+    pub fn final_state(&self) -> FinalStatePolicy {
+        self.config.final_state
+    }
+
+    /// Prove with another trace length and state schedule
+    ///
+    /// The configuration is recorded in the proof, so verifiers need no matching setting.
+    /// A configuration that fails [`BurnMintConfig::validate`] fails the next proof.
+    #[must_use]
+    pub fn with_config(mut self, config: BurnMintConfig) -> Self {
+        self.config = config;
+        self.domain = OnceLock::new();
+        self
+    }
+
+    /// Trace length and state schedule
+    pub fn config(&self) -> &BurnMintConfig {
+        &self.config
+    }
+
+    /// Prove over a trace of `trace_length` rows instead of [`constants::TRACE_LENGTH`]
+    ///
+    /// Verifiers read the length from the proof. Longer traces cost proving time and
+    /// proof size and add no security by themselves.
+    ///
+    /// # Errors
+    ///
+    /// [`BurnMintConfigError::InvalidTraceLength`] if `trace_length` is not a power of two
+    /// with room for the burn range check and the mint outputs (see [`is_valid_trace_length`])
+    pub fn with_trace_length(mut self, trace_length: usize) -> std::result::Result<Self, BurnMintConfigError> {
+        if !is_valid_trace_length(trace_length) {
+            return Err(BurnMintConfigError::InvalidTraceLength(trace_length));
+        }
+        self.config.trace_length = trace_length;
+        self.domain = OnceLock::new();
+        Ok(self)
+    }
+
+    /// Rows in the execution trace
+    pub fn trace_length(&self) -> usize {
+        self.config.trace_length
+    }
+
+    /// Report each completed proving phase to `reporter`
+    ///
+    /// Winterfell folds FRI layers internally, so no per-layer progress is reported.
+    #[must_use]
+    pub fn with_progress(mut self, reporter: impl ProgressReporter + 'static) -> Self {
+        self.progress = SharedProgress::new(reporter);
+        self
+    }
+
+    /// Stop with [`crate::proof::ProofError::Cancelled`] at the next checkpoint after
+    /// `token` is cancelled
+    ///
+    /// Winterfell cannot be interrupted, so the checkpoints are before the witness check
+    /// and once the trace is built, before commitment starts.
+    #[must_use]
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// Keep the trace extension and its commitment within `memory_budget`
+    ///
+    /// Over budget, the extension is spilled to a temporary file and committed in chunks
+    /// (see [`Self::lde_strategy`]). Proofs are identical either way; chunked proving
+    /// trades memory for disk reads.
+    #[must_use]
+    pub fn with_memory_budget(mut self, memory_budget: ProverMemoryBudget) -> Self {
+        self.memory_budget = memory_budget;
+        self
+    }
+
+    /// Cap on the memory of the trace extension and its commitment
+    pub fn memory_budget(&self) -> ProverMemoryBudget {
+        self.memory_budget
+    }
+
+    /// How the next proof extends and commits to the trace under the memory budget
+    pub fn lde_strategy(&self) -> LdeStrategy {
+        self.memory_budget.select_strategy(
+            TRACE_WIDTH,
+            self.config.trace_length,
+            self.proof_options.blowup_factor(),
+        )
+    }
+
     /// Prove XFG burn and HEAT mint operation
     ///
     /// This generates a STARK proof that validates:
     /// - Burn amount is within valid range
-    /// - Mint amount is proportional to burn amount
+    /// - Mint amount equals the burn amount less the protocol fee
     /// - Full transaction prefix hash is bound to proof
-    /// - Recipient hash is bound to proof
+    /// - Every limb of the recipient binding (address and target chain) is bound to proof
     /// - Network IDs prevent cross-chain replay
     /// - State transitions are valid
     /// - Nullifier prevents double-spending
@@ -64,68 +327,268 @@ impl XfgBurnMintProver {
         burn_amount: u64,
         mint_amount: u64,
         tx_prefix_hash: [u8; 32], // Full 32-byte tx prefix hash
-        recipient_address: &[u8], // 20-byte Ethereum address
+        recipient_address: &[u8; RECIPIENT_ADDRESS_LEN],
         secret: &[u8],
         network_id: u32,          // Fuego network ID
         target_chain_id: u32,     // HEAT target chain ID
         commitment_version: u32,  // Commitment format version
     ) -> Result<StarkProof> {
-        // Validate inputs (using legacy txn_hash for backward compatibility)
-        let legacy_txn_hash = u64::from_le_bytes(tx_prefix_hash[0..8].try_into().unwrap());
-        self.validate_inputs(burn_amount, mint_amount, legacy_txn_hash, recipient_address)?;
-
-        // Convert secret to field element
-        let secret_element = self.secret_to_field_element(secret)?;
-
-        // Compute recipient hash
-        let recipient_hash = self.compute_recipient_hash(recipient_address);
-
-        // Extract tx prefix hash limbs
-        let tx_prefix_hash_0 = u32::from_le_bytes(tx_prefix_hash[0..4].try_into().unwrap());
-        let tx_prefix_hash_1 = u32::from_le_bytes(tx_prefix_hash[4..8].try_into().unwrap());
-        let tx_prefix_hash_2 = u32::from_le_bytes(tx_prefix_hash[8..12].try_into().unwrap());
-        let tx_prefix_hash_3 = u32::from_le_bytes(tx_prefix_hash[12..16].try_into().unwrap());
-
-        // Create extended public inputs
-        let public_inputs = BurnMintPublicInputs {
-            burn_amount: BaseElement::from(burn_amount as u32),
-            mint_amount: BaseElement::from(mint_amount as u32),
-            txn_hash: BaseElement::from(legacy_txn_hash as u32), // Keep legacy for compatibility
-            recipient_hash: BaseElement::from(recipient_hash as u32),
-            state: BaseElement::from(0u32),
-
-            // Full tx prefix hash (32 bytes as 4 limbs)
-            tx_prefix_hash_0: BaseElement::from(tx_prefix_hash_0),
-            tx_prefix_hash_1: BaseElement::from(tx_prefix_hash_1),
-            tx_prefix_hash_2: BaseElement::from(tx_prefix_hash_2),
-            tx_prefix_hash_3: BaseElement::from(tx_prefix_hash_3),
-
-            // Network identifiers
-            network_id: BaseElement::from(network_id),
-            target_chain_id: BaseElement::from(target_chain_id),
-            commitment_version: BaseElement::from(commitment_version),
-        };
+        self.prove_burn_mint_with_public_inputs(
+            &BurnMintRequest {
+                burn_amount,
+                mint_amount,
+                tx_prefix_hash,
+                recipient_address,
+                network_id,
+                target_chain_id,
+                commitment_version,
+            },
+            secret,
+        )
+        .map(|(proof, _)| proof)
+    }
 
-        // Create trace info (7 registers, 64 steps)
-        let trace_info = TraceInfo::new(7, 64);
+    /// Prove XFG burn and HEAT mint operation against the commitment recorded on chain
+    ///
+    /// `expected_commitment` is the HEAT commitment from the burn transaction's
+    /// `tx_extra`. A secret that does not reproduce it fails with
+    /// [`crate::XfgStarkError::CommitmentMismatch`] before any proving work, since the
+    /// resulting proof could never mint.
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError::CommitmentMismatch`] as above, otherwise as
+    /// [`Self::prove_burn_mint_with_public_inputs`]
+    pub fn prove_burn_mint_checked(
+        &self,
+        expected_commitment: &[u8; 32],
+        request: &BurnMintRequest<'_>,
+        secret: &[u8],
+    ) -> Result<StarkProof> {
+        let public_inputs = self.claim_public_inputs(request)?;
+        verify_commitment_opening(secret, &public_inputs, expected_commitment)?;
+
+        self.prove_burn_mint_with_public_inputs(request, secret)
+            .map(|(proof, _)| proof)
+    }
+
+    /// Prove XFG burn and HEAT mint operation, returning the public inputs the proof binds
+    ///
+    /// The returned public inputs include the nullifier and commitment derived from the
+    /// secret; verifiers need them to check the proof.
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError::CryptoError`] if the request or the secret is invalid (see
+    /// [`XfgBurnMintAir::check_witness`]), or the error that stopped proving
+    pub fn prove_burn_mint_with_public_inputs(
+        &self,
+        request: &BurnMintRequest<'_>,
+        secret: &[u8],
+    ) -> Result<(StarkProof, BurnMintPublicInputs)> {
+        let public_inputs = self.claim_public_inputs(request)?;
+
+        // Convert secret to field elements
+        let secret_element = secret_to_field_elements(secret)?;
 
         // Create AIR
         let air = XfgBurnMintAir::new_with_secret(
-            trace_info,
+            self.trace_info()?,
             public_inputs,
             secret_element,
             self.proof_options.clone(),
         );
 
+        self.prove_air(&air)
+    }
+
+    /// Prove XFG burn and HEAT mint operation with a zeroize-on-drop secret
+    ///
+    /// Same as [`Self::prove_burn_mint_with_public_inputs`]. When proving returns, the
+    /// prover-side AIR has wiped the secret-derived field element, and the execution
+    /// trace and its extension have wiped the registers that hold the secret or its
+    /// permutation ([`crate::burn_mint_air::SECRET_BOUND_REGISTERS`]).
+    ///
+    /// Winterfell frees its own copies of the trace polynomials and constraint
+    /// evaluations without clearing them, so secret-derived values can remain in freed
+    /// memory.
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::prove_burn_mint_with_public_inputs`]
+    pub fn prove_burn_mint_with_secret_key(
+        &self,
+        request: &BurnMintRequest<'_>,
+        secret: &SecretKey,
+    ) -> Result<(StarkProof, BurnMintPublicInputs)> {
+        self.prove_burn_mint_with_public_inputs(request, secret.expose())
+    }
+
+    /// Prove one XFG burn minting HEAT to several recipients
+    ///
+    /// `outputs` pairs each 20-byte recipient address with the amount minted to it, at
+    /// most [`constants::MAX_MINT_OUTPUTS`] of them. `request` is the claim of the whole
+    /// burn: the amounts must sum to its mint amount, which the protocol fee allows, and
+    /// the first recipient is its recipient, the one the commitment binds.
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError::CryptoError`] if the request, the outputs or the secret are
+    /// invalid, or the error that stopped proving
+    pub fn prove_burn_mint_outputs(
+        &self,
+        request: &BurnMintRequest<'_>,
+        outputs: &[(&[u8], u64)],
+        secret: &[u8],
+    ) -> Result<(StarkProof, BurnMintPublicInputs)> {
+        if outputs.is_empty() {
+            return Err(crate::XfgStarkError::CryptoError(
+                "A burn must mint to at least one recipient".to_string(),
+            ));
+        }
+
+        let mut public_inputs = self.claim_public_inputs(request)?;
+        public_inputs.outputs = outputs
+            .iter()
+            .map(|&(recipient, amount)| {
+                Ok(MintOutput {
+                    amount: BaseElement::new(amount),
+                    recipient_binding: Self::compute_recipient_binding(recipient, request.target_chain_id)?,
+                })
+            })
+            .collect::<Result<_>>()?;
+        public_inputs.check_mint_outputs()?;
+        // A single recipient is the plain single-output claim
+        if outputs.len() == 1 {
+            public_inputs.outputs.clear();
+        }
+
+        let air = XfgBurnMintAir::new_with_secret(
+            self.trace_info()?,
+            public_inputs,
+            secret_to_field_elements(secret)?,
+            self.proof_options.clone(),
+        );
+
+        self.prove_air(&air)
+    }
+
+    /// Prove XFG burn and HEAT mint operation with the secret held elsewhere
+    ///
+    /// `oracle` supplies the nullifier, the commitment and the secret-bound trace cells
+    /// (e.g. a hardware wallet, after showing the claim to its user); every other step
+    /// runs on the host. The cells hold the secret, so the host sees it while proving but
+    /// keeps no copy: the AIR wipes them when proving returns. Oracle errors surface as
+    /// [`crate::XfgStarkError::SecretOracleError`] before any proving work.
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError::CryptoError`] if the request is invalid or the oracle's
+    /// cells do not hash to its nullifier and commitment, an oracle error, or the error
+    /// that stopped proving
+    pub fn prove_burn_mint_with_oracle(
+        &self,
+        request: &BurnMintRequest<'_>,
+        oracle: &dyn SecretOracle,
+    ) -> Result<(StarkProof, BurnMintPublicInputs)> {
+        let mut public_inputs = self.claim_public_inputs(request)?;
+        derive_public_values(oracle, &mut public_inputs)?;
+
+        let trace_info = self.trace_info()?;
+        let secret_cells = oracle.trace_cells(&public_inputs, trace_info.length())?;
+        let air = XfgBurnMintAir::with_secret_cells(
+            trace_info,
+            public_inputs,
+            secret_cells,
+            self.proof_options.clone(),
+        );
+
+        self.prove_air(&air)
+    }
+
+    /// Validate a claim and build its public inputs, leaving the secret-derived values zero
+    fn claim_public_inputs(&self, request: &BurnMintRequest<'_>) -> Result<BurnMintPublicInputs> {
+        // Validate inputs (using legacy txn_hash for backward compatibility)
+        let legacy_txn_hash = u64::from_le_bytes(request.tx_prefix_hash[0..8].try_into().unwrap());
+        self.validate_inputs(
+            request.burn_amount,
+            request.mint_amount,
+            legacy_txn_hash,
+            request.recipient_address,
+        )?;
+
+        let mut public_inputs = BurnMintPublicInputs::from_user_data(
+            request.burn_amount,
+            request.tx_prefix_hash,
+            request.recipient_address,
+            request.network_id,
+            request.target_chain_id,
+            request.commitment_version,
+        )
+        .with_fee_bps(self.fee_model.fee_bps());
+        // The claimed mint; check_witness rejects one the fee does not allow
+        public_inputs.mint_amount = BaseElement::new(request.mint_amount);
+        if let Some(network) = &self.network {
+            public_inputs.check_network(network)?;
+        }
+        Ok(public_inputs)
+    }
+
+    /// Trace layout for the next proof
+    ///
+    /// Records the final-state policy and state count in the metadata so verifiers
+    /// rebuild the same assertions.
+    fn trace_info(&self) -> Result<TraceInfo> {
+        self.config.final_step()?;
+        self.config.validate()?;
+        Ok(self.config.to_trace_info())
+    }
+
+    /// Check the witness, build the trace and prove it with the configured hash function
+    fn prove_air(&self, air: &XfgBurnMintAir) -> Result<(StarkProof, BurnMintPublicInputs)> {
+        self.cancellation.check()?;
+        air.check_witness()?;
+
         // Generate execution trace
-        let trace = air.build_trace();
+        let trace = air.build_proving_trace();
+        let public_inputs = air.public_inputs().clone();
+        self.progress.report(ProvingPhase::TraceBuilt);
+        self.cancellation.check()?;
 
         // Generate STARK proof using Winterfell
-        let proof = air
-            .prove(trace)
-            .map_err(|e| crate::XfgStarkError::CryptoError(format!("Prover error: {:?}", e)))?;
+        let options = &self.prover_options;
+        let progress = self.progress.clone();
+        let strategy = self.lde_strategy();
+        let inv_twiddles = Arc::clone(&self.domain().trace_inv_twiddles);
+        let proof = match self.hash_function {
+            HashFunction::Blake3 => prove_statement_with(
+                &BurnMintStatementProver::<StatementHasher>::new(air)
+                    .with_progress(progress)
+                    .with_lde_strategy(strategy)
+                    .with_trace_inv_twiddles(inv_twiddles),
+                trace,
+                options,
+            ),
+            HashFunction::Keccak => prove_statement_with(
+                &BurnMintStatementProver::<Keccak256Hasher<BaseElement>>::new(air)
+                    .with_progress(progress)
+                    .with_lde_strategy(strategy)
+                    .with_trace_inv_twiddles(inv_twiddles),
+                trace,
+                options,
+            ),
+            HashFunction::Rescue => prove_statement_with(
+                &BurnMintStatementProver::<Rp64_256>::new(air)
+                    .with_progress(progress)
+                    .with_lde_strategy(strategy)
+                    .with_trace_inv_twiddles(inv_twiddles),
+                trace,
+                options,
+            ),
+        }?;
+        self.progress.report(ProvingPhase::QueriesAnswered);
 
-        Ok(proof)
+        Ok((proof, public_inputs))
     }
 
     /// Validate input parameters (amounts in atomic units)
@@ -137,31 +600,15 @@ impl XfgBurnMintProver {
         recipient_address: &[u8],
     ) -> Result<()> {
         // Validate burn amount (in atomic units)
-    let standard_burn = 8_000_000;  // 0.8 XFG in atomic units
-            let large_burn = 8_000_000_000; // 800 XFG in atomic units
-    
-    if burn_amount != standard_burn && burn_amount != large_burn {
-        return Err(crate::XfgStarkError::CryptoError(
-            "Burn amount must be exactly 0.8 XFG (8,000,000 atomic units) or 800 XFG (8,000,000,000 atomic units)".to_string(),
-        ));
-    }
-
-        // Validate mint amount matches burn amount (1:1 conversion in atomic units)
-    if mint_amount != burn_amount {
-        return Err(crate::XfgStarkError::CryptoError(format!(
-            "Mint amount {} does not match burn amount {} for 1:1 atomic unit conversion",
-            mint_amount, burn_amount
-        )));
-    }
-
-        // Validate proportionality (1:1 conversion in atomic units)
-        if mint_amount != burn_amount {
-            return Err(crate::XfgStarkError::CryptoError(format!(
-                "Mint amount {} does not match burn amount {} for 1:1 atomic unit conversion",
-                mint_amount, burn_amount
-            )));
+        if !is_valid_burn_amount(burn_amount) {
+            return Err(crate::XfgStarkError::CryptoError(
+                INVALID_BURN_AMOUNT.to_string(),
+            ));
         }
 
+        // Validate mint amount is the burn less the protocol fee (1:1 when there is no fee)
+        self.fee_model.check_mint(burn_amount, mint_amount)?;
+
         // Validate transaction hash
         if txn_hash == 0 {
             return Err(crate::XfgStarkError::CryptoError(
@@ -181,43 +628,32 @@ impl XfgBurnMintProver {
 
     /// Convert XFG amount from whole units to atomic units
     /// XFG uses 7 decimal places: 1 XFG = 10,000,000 atomic units
-    pub fn xfg_to_atomic_units(xfg_amount: f64) -> u64 {
-        (xfg_amount * 10_000_000.0) as u64
+    ///
+    /// # Errors
+    ///
+    /// [`constants::InvalidXfgAmount`] if the amount is negative, not finite or above
+    /// `u64::MAX` units
+    pub fn xfg_to_atomic_units(xfg_amount: f64) -> std::result::Result<u64, constants::InvalidXfgAmount> {
+        constants::xfg_to_atomic_units(xfg_amount)
     }
 
     /// Convert XFG amount from atomic units to whole units
     /// XFG uses 7 decimal places: 10,000,000 atomic units = 1 XFG
     pub fn atomic_units_to_xfg(atomic_units: u64) -> f64 {
-        atomic_units as f64 / 10_000_000.0
+        constants::atomic_units_to_xfg(atomic_units)
     }
 
-    /// Convert secret bytes to field element
-    fn secret_to_field_element(&self, secret: &[u8]) -> Result<BaseElement> {
-        if secret.len() < 4 {
-            return Err(crate::XfgStarkError::CryptoError(
-                "Secret must be at least 4 bytes".to_string(),
-            ));
-        }
-
-        // Use first 8 bytes of secret
-        let mut bytes = [0u8; 8];
-        bytes.copy_from_slice(&secret[..8]);
-
-        let value = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
-        Ok(BaseElement::from(value))
-    }
-
-    /// Compute recipient hash from Ethereum address
-    fn compute_recipient_hash(&self, recipient_address: &[u8]) -> u32 {
-        use sha3::{Digest, Keccak256};
-
-        let mut hasher = Keccak256::new();
-        hasher.update(recipient_address);
-        hasher.update(b"recipient");
-        let hash = hasher.finalize();
-
-        // Convert first 4 bytes of hash to u32
-        u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]])
+    /// Compute the recipient binding limbs for an Ethereum address on the target chain
+    fn compute_recipient_binding(
+        recipient_address: &[u8],
+        target_chain_id: u32,
+    ) -> Result<[BaseElement; RECIPIENT_BINDING_LIMBS]> {
+        let address = recipient_address.try_into().map_err(|_| {
+            crate::XfgStarkError::CryptoError(
+                "Recipient address must be exactly 20 bytes".to_string(),
+            )
+        })?;
+        Ok(recipient_binding_limbs(&recipient_binding(address, target_chain_id)))
     }
 
     /// Get proof size in bytes
@@ -235,6 +671,16 @@ impl XfgBurnMintProver {
     pub fn proof_options(&self) -> &ProofOptions {
         &self.proof_options
     }
+
+    /// Get domain data, computing it on first use
+    pub fn domain(&self) -> &ProverDomain {
+        self.domain.get_or_init(|| ProverDomain::new(&self.proof_options, self.config.trace_length))
+    }
+
+    /// Verifying key for the proofs this prover generates
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        self.domain().proving_key.verifying_key()
+    }
 }
 
 impl Default for XfgBurnMintProver {
@@ -243,9 +689,31 @@ impl Default for XfgBurnMintProver {
     }
 }
 
+/// Length of a burn secret in bytes
+pub const SECRET_LEN: usize = 8 * SECRET_LIMBS;
+
+/// Convert secret bytes to the field elements the AIR derives nullifier and commitment from
+///
+/// Each element is eight little-endian bytes of the secret reduced modulo the field
+/// prime, so the secret keeps almost all of its 256 bits.
+pub(crate) fn secret_to_field_elements(secret: &[u8]) -> Result<[BaseElement; SECRET_LIMBS]> {
+    if secret.len() != SECRET_LEN {
+        return Err(crate::XfgStarkError::CryptoError(format!(
+            "Secret must be {SECRET_LEN} bytes"
+        )));
+    }
+
+    let mut limbs = [BaseElement::new(0); SECRET_LIMBS];
+    for (limb, chunk) in limbs.iter_mut().zip(secret.chunks_exact(8)) {
+        *limb = BaseElement::new(u64::from_le_bytes(chunk.try_into().expect("chunk holds eight bytes")));
+    }
+    Ok(limbs)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::TRACE_LENGTH;
 
     #[test]
     fn test_prover_creation() {
@@ -275,7 +743,7 @@ mod tests {
         assert!(prover.validate_inputs(0, 8_000_000, tx_hash_u64, &recipient).is_err());
 
         // Invalid burn amount (too large)
-        let max_atomic = 8_000_000_001; // Exceeds max (800 XFG + 1)
+        let max_atomic = constants::MAX_BURN_ATOMIC + 1; // Exceeds max (2^53 - 1 atomic units)
         assert!(prover
             .validate_inputs(max_atomic, 8_000_000, tx_hash_u64, &recipient)
             .is_err());
@@ -302,16 +770,17 @@ mod tests {
 
     #[test]
     fn test_secret_conversion() {
-        let prover = XfgBurnMintProver::new(128);
-
-        // Valid secret
-        let secret = [1, 2, 3, 4, 5, 6, 7, 8];
-        let element = prover.secret_to_field_element(&secret).unwrap();
-        assert_eq!(element, BaseElement::from(0x04030201u32));
+        // Valid secret: every byte reaches a limb
+        let mut secret = [0u8; SECRET_LEN];
+        secret[..8].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        secret[31] = 0x7f;
+        let limbs = secret_to_field_elements(&secret).unwrap();
+        assert_eq!(limbs[0], BaseElement::new(0x0807060504030201));
+        assert_eq!(limbs[3], BaseElement::new(0x7f << 56));
 
-        // Invalid secret (too short)
-        let short_secret = [1, 2, 3];
-        assert!(prover.secret_to_field_element(&short_secret).is_err());
+        // Invalid secrets (too short, too long)
+        assert!(secret_to_field_elements(&secret[..4]).is_err());
+        assert!(secret_to_field_elements(&[0u8; SECRET_LEN + 1]).is_err());
     }
 
     #[test]
@@ -322,10 +791,7 @@ mod tests {
         use crate::test_data_generator::TestDataGenerator;
         let tx_hash_str = TestDataGenerator::generate_tx_hash();
         let tx_hash_bytes = hex::decode(&tx_hash_str).expect("Valid hex string");
-        let tx_hash_u64 = u64::from_le_bytes([
-            tx_hash_bytes[0], tx_hash_bytes[1], tx_hash_bytes[2], tx_hash_bytes[3],
-            tx_hash_bytes[4], tx_hash_bytes[5], tx_hash_bytes[6], tx_hash_bytes[7]
-        ]);
+        let tx_prefix_hash: [u8; 32] = tx_hash_bytes.try_into().expect("32-byte hash");
         
         let recipient = [0x12u8; 20]; // Valid 20-byte address
         let secret = [42u8; 32]; // Valid 32-byte secret
@@ -334,12 +800,226 @@ mod tests {
         let result = prover.prove_burn_mint(
             8_000_000, // 0.8 XFG in atomic units
             8_000_000, // 0.8 XFG in atomic units (1:1 ratio)
-            tx_hash_u64, // Real generated transaction hash
+            tx_prefix_hash, // Real generated transaction hash
             &recipient,
             &secret,
+            1,  // Fuego network ID
+            42, // HEAT target chain ID
+            1,  // Commitment version
         );
 
         // The proof generation should succeed
         assert!(result.is_ok(), "Proof generation should succeed: {:?}", result);
     }
+
+    #[test]
+    fn test_domain_cached_across_proofs() {
+        let prover = XfgBurnMintProver::new(128);
+
+        let first = prover.domain() as *const ProverDomain;
+        let second = prover.domain() as *const ProverDomain;
+        assert_eq!(first, second);
+
+        let domain = prover.domain();
+        assert_eq!(domain.trace_info.width(), TRACE_WIDTH);
+        assert_eq!(domain.trace_info.length(), 64);
+        assert_eq!(domain.lde_domain_size, 64 * prover.proof_options().blowup_factor());
+        assert_eq!(*domain.trace_inv_twiddles, fft::get_inv_twiddles::<BaseElement>(64)[..]);
+    }
+
+    #[test]
+    fn test_configurable_trace_length() {
+        use crate::burn_mint_verifier::XfgBurnMintVerifier;
+
+        let prover = XfgBurnMintProver::default().with_trace_length(4 * TRACE_LENGTH).unwrap();
+        assert_eq!(prover.domain().trace_info.length(), 4 * TRACE_LENGTH);
+        let (proof, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .unwrap();
+        assert_eq!(proof.trace_length(), 4 * TRACE_LENGTH);
+        assert!(prover.verifying_key().check_proof(&proof).is_ok());
+        assert!(XfgBurnMintVerifier::default().verify_with_public_inputs(&proof, &public_inputs).unwrap());
+
+        assert!(matches!(
+            XfgBurnMintProver::default().with_trace_length(TRACE_LENGTH / 2),
+            Err(BurnMintConfigError::InvalidTraceLength(length)) if length == TRACE_LENGTH / 2
+        ));
+    }
+
+    #[test]
+    fn test_chunked_lde_matches_in_memory() {
+        use crate::burn_mint_verifier::XfgBurnMintVerifier;
+
+        let prover = XfgBurnMintProver::default().with_trace_length(4 * TRACE_LENGTH).unwrap();
+        let chunked = XfgBurnMintProver::default()
+            .with_trace_length(4 * TRACE_LENGTH)
+            .unwrap()
+            .with_memory_budget(ProverMemoryBudget::new(64 * 1024));
+        assert_eq!(chunked.lde_strategy(), LdeStrategy::Chunked { chunk_rows: 128 });
+
+        let prove = |prover: &XfgBurnMintProver| {
+            prover
+                .prove_burn_mint_with_public_inputs(
+                    &BurnMintRequest {
+                        burn_amount: 8_000_000,
+                        mint_amount: 8_000_000,
+                        tx_prefix_hash: [7u8; 32],
+                        recipient_address: &[0x12u8; 20],
+                        network_id: 1,
+                        target_chain_id: 42161,
+                        commitment_version: 1,
+                    },
+                    &[9u8; 32],
+                )
+                .unwrap()
+        };
+        let (expected, _) = prove(&prover);
+        let (proof, public_inputs) = prove(&chunked);
+        assert_eq!(proof.to_bytes(), expected.to_bytes());
+        assert!(XfgBurnMintVerifier::default().verify_with_public_inputs(&proof, &public_inputs).unwrap());
+    }
+
+    #[test]
+    fn test_proving_key_shared_across_provers() {
+        let prover = XfgBurnMintProver::new(128);
+        let other = XfgBurnMintProver::default().with_fee_model(FeeModel::new(25).unwrap());
+        assert!(Arc::ptr_eq(&prover.domain().proving_key, &other.domain().proving_key));
+
+        let (proof, _) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .unwrap();
+        assert!(prover.verifying_key().check_proof(&proof).is_ok());
+
+        let wider = setup(AirShape::new(TRACE_WIDTH + 1, TRACE_LENGTH, 8)).unwrap();
+        assert!(wider.verifying_key().check_proof(&proof).is_err());
+    }
+
+    #[test]
+    fn test_shared_prover_across_threads() {
+        use crate::burn_mint_verifier::XfgBurnMintVerifier;
+        use std::sync::Arc;
+
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<XfgBurnMintProver>();
+
+        // Threads race on lazy initialization and prove concurrently; all must observe
+        // the same domain, and every proof must verify against its own statement
+        let prover = Arc::new(XfgBurnMintProver::new(128));
+        let handles: Vec<_> = (0..4u8)
+            .map(|thread| {
+                let prover = Arc::clone(&prover);
+                std::thread::spawn(move || {
+                    let (proof, public_inputs) = prover
+                        .prove_burn_mint_with_public_inputs(
+                            &BurnMintRequest {
+                                burn_amount: 8_000_000,
+                                mint_amount: 8_000_000,
+                                tx_prefix_hash: [thread + 1; 32],
+                                recipient_address: &[0x12u8; 20],
+                                network_id: 1,
+                                target_chain_id: 42161,
+                                commitment_version: 1,
+                            },
+                            &[thread + 9; 32],
+                        )
+                        .expect("Concurrent proof generation should succeed");
+                    assert!(XfgBurnMintVerifier::default()
+                        .verify_with_public_inputs(&proof, &public_inputs)
+                        .unwrap());
+                    prover.domain() as *const ProverDomain as usize
+                })
+            })
+            .collect();
+
+        let addresses: Vec<usize> = handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Prover thread panicked"))
+            .collect();
+        assert!(addresses.iter().all(|&address| address == addresses[0]));
+        assert_eq!(addresses[0], prover.domain() as *const ProverDomain as usize);
+    }
+
+    #[test]
+    fn test_commitment_checked_before_proving() {
+        use crate::commitment::CommitmentPreimage;
+
+        let prover = XfgBurnMintProver::new(128);
+        let recipient = [0x12u8; 20];
+        let secret = [42u8; 32];
+        let commitment = |secret: &[u8]| {
+            CommitmentPreimage::from_user_data(secret, 8_000_000, &recipient, 1, 42161, 1).unwrap().to_bytes()
+        };
+        let on_chain = commitment(&secret);
+
+        let request = BurnMintRequest {
+            burn_amount: 8_000_000,
+            mint_amount: 8_000_000,
+            tx_prefix_hash: [7u8; 32],
+            recipient_address: &recipient,
+            network_id: 1,
+            target_chain_id: 42161,
+            commitment_version: 1,
+        };
+        let prove = |secret: &[u8]| prover.prove_burn_mint_checked(&on_chain, &request, secret);
+
+        let (proof, public_inputs) = prover.prove_burn_mint_with_public_inputs(&request, &secret).unwrap();
+        assert_eq!(prove(&secret).unwrap().to_bytes(), proof.to_bytes());
+        // The proof carries the commitment the burn wrote on chain
+        assert_eq!(crate::commitment::commitment_to_bytes(&public_inputs.commitment), on_chain);
+        match prove(&[43u8; 32]) {
+            Err(crate::XfgStarkError::CommitmentMismatch { expected, computed }) => {
+                assert_eq!(expected, on_chain);
+                assert_eq!(computed, commitment(&[43u8; 32]));
+            }
+            other => panic!("Expected a commitment mismatch, got {:?}", other.map(|_| ())),
+        }
+    }
+    #[test]
+    fn test_prove_with_secret_key() {
+        use crate::nullifier::{derive_nullifier_with_key, Nullifier};
+
+        let prover = XfgBurnMintProver::default();
+        let key = SecretKey::new(vec![9u8; 32]);
+        let (_, public_inputs) = prover
+            .prove_burn_mint_with_secret_key(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &key,
+            )
+            .expect("Proof generation should succeed");
+        assert_eq!(
+            Nullifier::from_public_inputs(&public_inputs),
+            derive_nullifier_with_key(&key, [7u8; 32], 8_000_000).unwrap()
+        );
+        assert!(!format!("{:?}", key).contains("9, 9"));
+    }
 }
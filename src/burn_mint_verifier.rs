@@ -2,18 +2,159 @@
 //!
 //! This module implements the Winterfell verifier for XFG burn and HEAT mint operations,
 //! providing secure and efficient proof verification.
+//!
+//! Gateways receive proofs as raw bytes from untrusted users.
+//! [`XfgBurnMintVerifier::verify_from_bytes`] bounds the input with [`ProofDecodeLimits`]
+//! before and after decoding, rejects bytes outside the pinned Winterfell encoding (see
+//! [`crate::proof_compat`]), verifies under the options embedded in the proof once they
+//! pass the security policy, and returns a [`ProofBytesReport`].
 
 use crate::{
-    burn_mint_air::{BurnMintPublicInputs, XfgBurnMintAir},
+    burn_mint_air::{BurnMintConfig, BurnMintPublicInputs, XfgBurnMintAir},
+    commitment::{commitment_from_bytes, RECIPIENT_ADDRESS_LEN},
+    fees::FeeModel,
+    networks::NetworkParams,
+    nullifier::{Nullifier, NullifierSet},
+    parameter_registry::{ParameterRegistry, BURN_MINT_STATEMENT_ID},
+    proof_compat::{check_proof_encoding, UnsupportedProofEncoding},
+    statements::{verify_statement_with_hash, HashFunction},
+    types::stark::StarkError,
     Result,
 };
-use std::time::Instant;
-use winter_crypto::hashers::Blake3_256;
-use winterfell::{
-    crypto::{DefaultRandomCoin, MerkleTree},
-    math::fields::f64::BaseElement,
-    verify, AcceptableOptions, ProofOptions, StarkProof, VerifierError,
+#[cfg(feature = "packages")]
+use crate::{
+    proof_data_schema::{CompleteProofPackage, StarkProof as ProofFile},
+    verification_context::VerificationContext,
 };
+use sha2::{Digest, Sha256};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use winterfell::{ProofOptions, StarkProof, VerifierError};
+
+/// Default minimum conjectured security a proof must provide (bits)
+pub const DEFAULT_MIN_SECURITY_BITS: u32 = 100;
+
+/// Rejection by the verifier's minimum security policy
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SecurityPolicyError {
+    /// Proof parameters give less security than the configured minimum
+    #[error("Proof provides {effective_bits}-bit security, below the required {minimum_bits} bits")]
+    InsufficientSecurity {
+        /// Security recomputed from the proof's parameters (bits)
+        effective_bits: u32,
+        /// Minimum required by the verifier (bits)
+        minimum_bits: u32,
+    },
+}
+
+/// Bounds on untrusted proof bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofDecodeLimits {
+    /// Largest serialized proof accepted (bytes)
+    pub max_proof_bytes: usize,
+    /// Longest execution trace a proof may claim (rows)
+    pub max_trace_length: usize,
+    /// Most FRI queries a proof may request
+    pub max_queries: usize,
+    /// Largest LDE blowup factor a proof may use
+    pub max_blowup: usize,
+}
+
+impl Default for ProofDecodeLimits {
+    fn default() -> Self {
+        Self {
+            max_proof_bytes: 512 * 1024,
+            max_trace_length: 1 << 20,
+            max_queries: 128,
+            max_blowup: 64,
+        }
+    }
+}
+
+/// Rejection of proof bytes before verification
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ProofDecodeError {
+    /// Input exceeds the size limit
+    #[error("Proof is {size} bytes, above the {max}-byte limit")]
+    TooLarge {
+        /// Size of the input (bytes)
+        size: usize,
+        /// Configured limit (bytes)
+        max: usize,
+    },
+    /// Input is not a serialized STARK proof
+    #[error("Malformed proof: {0}")]
+    Malformed(String),
+    /// Input decodes but is not in the pinned Winterfell encoding
+    #[error(transparent)]
+    UnsupportedEncoding(#[from] UnsupportedProofEncoding),
+    /// A decoded proof parameter exceeds its limit
+    #[error("Proof {parameter} {value} exceeds the limit of {max}")]
+    LimitExceeded {
+        /// Parameter name
+        parameter: &'static str,
+        /// Value in the proof
+        value: usize,
+        /// Configured limit
+        max: usize,
+    },
+}
+
+/// Disagreement between a package and the proof it carries
+#[cfg(feature = "packages")]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PackageClaimError {
+    /// Package has not been proven yet
+    #[error("Package has no STARK proof")]
+    MissingProof,
+    /// A package field cannot be turned into a public input
+    #[error("Invalid package {field}: {reason}")]
+    InvalidField {
+        /// Offending field
+        field: &'static str,
+        /// What is wrong with it
+        reason: String,
+    },
+    /// A package field differs from the public input recorded with the proof
+    #[error("Package {field} {package} does not match the proof's {proof}")]
+    Mismatch {
+        /// Public input name
+        field: &'static str,
+        /// Value derived from the package
+        package: String,
+        /// Value recorded with the proof
+        proof: String,
+    },
+}
+
+/// Outcome of verifying a serialized proof
+#[derive(Debug, Clone)]
+pub struct ProofBytesReport {
+    /// Size of the proof in bytes
+    pub proof_size: usize,
+    /// SHA-256 of the proof bytes
+    pub proof_digest: [u8; 32],
+    /// Proof options reconstructed from the proof
+    pub options: ProofOptions,
+    /// Execution trace length the proof claims
+    pub trace_length: usize,
+    /// Hash function the proof was verified with
+    pub hash_function: HashFunction,
+    /// Conjectured security of the proof (bits)
+    pub security_bits: u32,
+    /// Time spent in STARK verification
+    pub verification_time: Duration,
+    /// Why STARK verification failed, if it did
+    pub error: Option<String>,
+}
+
+impl ProofBytesReport {
+    /// Check if the proof verified
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+}
 
 /// Result of proof verification with detailed information
 #[derive(Debug, Clone)]
@@ -71,32 +212,74 @@ impl VerificationResult {
     }
 }
 
+/// Burn & mint claim a proof is verified against
+///
+/// Mirrors [`crate::burn_mint_prover::BurnMintRequest`]: the mint amount is not part of
+/// it, the verifier derives it from the burn amount and its protocol fee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BurnMintVerification<'a> {
+    /// Burn amount in atomic units
+    pub burn_amount: u64,
+    /// Full 32-byte transaction prefix hash of the burn
+    pub tx_prefix_hash: [u8; 32],
+    /// Recipient Ethereum address
+    pub recipient_address: &'a [u8; RECIPIENT_ADDRESS_LEN],
+    /// Fuego network ID
+    pub network_id: u32,
+    /// HEAT target chain ID
+    pub target_chain_id: u32,
+    /// Commitment format version
+    pub commitment_version: u32,
+    /// Nullifier published with the mint claim
+    pub nullifier: &'a Nullifier,
+    /// Commitment written to the burn's `tx_extra`
+    pub commitment: &'a [u8; 32],
+}
+
 /// XFG Burn & Mint Verifier using Winterfell
 ///
 /// This verifier validates STARK proofs for XFG burn and HEAT mint operations
 /// using Winterfell's verification system.
+#[derive(Clone)]
 pub struct XfgBurnMintVerifier {
     /// Security parameter for proof verification
     security_parameter: usize,
     /// Proof options for Winterfell
     proof_options: ProofOptions,
+    /// Minimum conjectured security a proof must provide (bits)
+    min_security_bits: u32,
+    /// Protocol fee the network charges on mints
+    fee_model: FeeModel,
+    /// Network proofs must be claimed on, if any
+    network: Option<NetworkParams>,
+    /// Hash function proofs must commit with
+    hash_function: HashFunction,
+    /// Bounds on serialized proofs
+    decode_limits: ProofDecodeLimits,
 }
 
 impl XfgBurnMintVerifier {
     /// Create new XFG Burn & Mint Verifier
     pub fn new(security_parameter: usize) -> Self {
+        // A quadratic extension is needed to clear the verifier's minimum security
+        // policy; over the 64-bit base field alone the proof is limited to ~54 bits.
         let proof_options = ProofOptions::new(
-            42,                               // blowup factor
-            8,                                // grinding factor
-            4,                                // hash function
-            winterfell::FieldExtension::None, // field extension
-            8,                                // FRI folding factor
-            31,                               // FRI remainder max degree
+            42,                                    // number of queries
+            8,                                     // blowup factor
+            4,                                     // grinding factor
+            winterfell::FieldExtension::Quadratic, // field extension
+            8,                                     // FRI folding factor
+            31,                                    // FRI remainder max degree
         );
 
         Self {
             security_parameter,
             proof_options,
+            min_security_bits: DEFAULT_MIN_SECURITY_BITS,
+            fee_model: FeeModel::none(),
+            network: None,
+            hash_function: HashFunction::default(),
+            decode_limits: ProofDecodeLimits::default(),
         }
     }
 
@@ -105,62 +288,195 @@ impl XfgBurnMintVerifier {
         Self {
             security_parameter,
             proof_options,
+            min_security_bits: DEFAULT_MIN_SECURITY_BITS,
+            fee_model: FeeModel::none(),
+            network: None,
+            hash_function: HashFunction::default(),
+            decode_limits: ProofDecodeLimits::default(),
         }
     }
 
+    /// Set the minimum conjectured security a proof must provide (bits)
+    #[must_use]
+    pub fn with_min_security_bits(mut self, min_security_bits: u32) -> Self {
+        self.min_security_bits = min_security_bits;
+        self
+    }
+
+    /// Require proofs to charge the network's protocol fee
+    #[must_use]
+    pub fn with_fee_model(mut self, fee_model: FeeModel) -> Self {
+        self.fee_model = fee_model;
+        self
+    }
+
+    /// Accept only proofs claimed on `network`
+    #[must_use]
+    pub fn with_network(mut self, network: NetworkParams) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    /// Accept only proofs committed with `hash_function`
+    #[must_use]
+    pub fn with_hash_function(mut self, hash_function: HashFunction) -> Self {
+        self.hash_function = hash_function;
+        self
+    }
+
+    /// Bound serialized proofs by `decode_limits`
+    #[must_use]
+    pub fn with_decode_limits(mut self, decode_limits: ProofDecodeLimits) -> Self {
+        self.decode_limits = decode_limits;
+        self
+    }
+
+    /// Check the hash function a proof's metadata records against the verifier's
+    ///
+    /// Verification runs under the verifier's hash function regardless, so a proof
+    /// committed with another one fails; this reports the mismatch up front.
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError::HashFunctionMismatch`] if `recorded` differs
+    pub fn check_hash_function(&self, recorded: HashFunction) -> Result<()> {
+        if recorded != self.hash_function {
+            return Err(crate::XfgStarkError::HashFunctionMismatch {
+                expected: self.hash_function,
+                recorded,
+            });
+        }
+        Ok(())
+    }
+
+    /// Recompute a proof's conjectured security from its own parameters (bits)
+    ///
+    /// The level is derived from the proof options, trace length and field size
+    /// embedded in the proof and the verifier's hash function, not from any security
+    /// parameter the prover claims.
+    #[must_use]
+    pub fn effective_security_bits(&self, proof: &StarkProof) -> u32 {
+        self.hash_function.security_level(proof, true)
+    }
+
+    /// Check a proof against the minimum security policy
+    ///
+    /// Returns the effective security level if it meets the configured minimum.
+    ///
+    /// # Errors
+    ///
+    /// [`SecurityPolicyError::InsufficientSecurity`] if the proof falls short of the minimum
+    pub fn check_security_policy(
+        &self,
+        proof: &StarkProof,
+    ) -> std::result::Result<u32, SecurityPolicyError> {
+        let effective_bits = self.effective_security_bits(proof);
+        if effective_bits < self.min_security_bits {
+            return Err(SecurityPolicyError::InsufficientSecurity {
+                effective_bits,
+                minimum_bits: self.min_security_bits,
+            });
+        }
+        Ok(effective_bits)
+    }
+
     /// Verify XFG burn and HEAT mint proof
     ///
     /// This verifies a STARK proof that validates:
     /// - Burn amount is within valid range
-    /// - Mint amount is proportional to burn amount
+    /// - Mint amount equals the burn amount less the protocol fee
     /// - Transaction hash is consistent
-    /// - Recipient hash is bound to proof
+    /// - Every limb of the recipient binding (address and target chain) is bound to proof
     /// - State transitions are valid
     /// - Nullifier prevents double-spending
-    /// - Commitment ensures data integrity
+    /// - Commitment is the one written to the burn's `tx_extra`
+    ///
+    /// The public inputs are built with [`BurnMintPublicInputs::from_user_data`], exactly
+    /// as the prover encodes a single-recipient claim, with this verifier's protocol fee.
+    /// With a `nullifiers` registry, a nullifier it already holds is rejected with
+    /// [`crate::nullifier::NullifierError::AlreadySpent`] and a verified proof's nullifier is recorded.
     pub fn verify_burn_mint(
         &self,
         proof: &StarkProof,
-        burn_amount: u64,
-        mint_amount: u64,
-        txn_hash: u64,
-        recipient_address: &[u8], // 20-byte Ethereum address
-        network_id: u32,          // Fuego network ID
-        target_chain_id: u32,     // HEAT target chain ID
-        commitment_version: u32,  // Commitment format version
+        claim: &BurnMintVerification<'_>,
+        nullifiers: Option<&dyn NullifierSet>,
     ) -> Result<bool> {
-        // Validate inputs
-        self.validate_inputs(burn_amount, mint_amount, txn_hash, recipient_address)?;
-
-        // Compute recipient hash
-        let recipient_hash = self.compute_recipient_hash(recipient_address);
-
-        // Create public inputs
-        let public_inputs = BurnMintPublicInputs {
-            burn_amount: BaseElement::from(burn_amount as u32),
-            mint_amount: BaseElement::from(mint_amount as u32),
-            txn_hash: BaseElement::from(txn_hash as u32),
-            recipient_hash: BaseElement::from(recipient_hash as u32),
-            state: BaseElement::from(0u32),
+        let mut public_inputs = BurnMintPublicInputs::from_user_data(
+            claim.burn_amount,
+            claim.tx_prefix_hash,
+            claim.recipient_address,
+            claim.network_id,
+            claim.target_chain_id,
+            claim.commitment_version,
+        )
+        .with_fee_bps(self.fee_model.fee_bps());
+        public_inputs.nullifier = claim
+            .nullifier
+            .limbs()
+            .ok_or_else(|| crate::XfgStarkError::CryptoError("Nullifier is not canonical".to_string()))?;
+        public_inputs.commitment = commitment_from_bytes(claim.commitment)
+            .ok_or_else(|| crate::XfgStarkError::CryptoError("Commitment is not canonical".to_string()))?;
+
+        // Validate inputs before the amounts are reduced into the field
+        self.validate_inputs(
+            claim.burn_amount,
+            public_inputs.mint_amount.as_int(),
+            public_inputs.txn_hash.as_int(),
+            claim.recipient_address,
+        )?;
+
+        match nullifiers {
+            Some(nullifiers) => self.verify_with_nullifiers(proof, &public_inputs, nullifiers),
+            None => self.verify_with_public_inputs(proof, &public_inputs),
+        }
+    }
 
-            // Full tx prefix hash (32 bytes as 4 limbs) - placeholder for now
-            tx_prefix_hash_0: BaseElement::from(0u32),
-            tx_prefix_hash_1: BaseElement::from(0u32),
-            tx_prefix_hash_2: BaseElement::from(0u32),
-            tx_prefix_hash_3: BaseElement::from(0u32),
+    /// Verify a claim and record its nullifier, rejecting nullifiers already spent
+    ///
+    /// A claim that fails verification leaves the registry unchanged.
+    ///
+    /// # Errors
+    ///
+    /// [`crate::nullifier::NullifierError::AlreadySpent`] for a claim whose nullifier
+    /// `nullifiers` holds, a registry error, or the error
+    /// [`Self::verify_with_public_inputs`] returns for invalid public inputs or a proof
+    /// below the security policy
+    pub fn verify_with_nullifiers(
+        &self,
+        proof: &StarkProof,
+        public_inputs: &BurnMintPublicInputs,
+        nullifiers: &dyn NullifierSet,
+    ) -> Result<bool> {
+        self.validate_public_inputs(public_inputs)?;
+        self.check_security_policy(proof)?;
+        self.verify_and_spend(proof, public_inputs, nullifiers)
+    }
 
-            // Network identifiers
-            network_id: BaseElement::from(network_id as u32),
-            target_chain_id: BaseElement::from(target_chain_id as u32),
-            commitment_version: BaseElement::from(commitment_version as u32),
-        };
+    /// Check the nullifier is unspent, verify, and spend it if the proof verified
+    fn verify_and_spend(
+        &self,
+        proof: &StarkProof,
+        public_inputs: &BurnMintPublicInputs,
+        nullifiers: &dyn NullifierSet,
+    ) -> Result<bool> {
+        let nullifier = Nullifier::from_public_inputs(public_inputs);
+        // Spent nullifiers are rejected before paying for verification
+        nullifiers.ensure_unspent(nullifier)?;
+        if !self.verify_winterfell_result(proof, public_inputs) {
+            return Ok(false);
+        }
+        // Another claim with this nullifier may have verified meanwhile
+        nullifiers.spend(nullifier)?;
+        Ok(true)
+    }
 
-        // Verify the proof using Winterfell's verification system
-        match self.verify_with_winterfell(proof, &public_inputs) {
-            Ok(_) => Ok(true),
+    /// Verify with Winterfell, reporting a rejected proof as `false`
+    fn verify_winterfell_result(&self, proof: &StarkProof, public_inputs: &BurnMintPublicInputs) -> bool {
+        match self.verify_with_winterfell(proof, public_inputs) {
+            Ok(()) => true,
             Err(e) => {
-                eprintln!("Proof verification failed: {:?}", e);
-                Ok(false)
+                eprintln!("Proof verification failed: {e:?}");
+                false
             }
         }
     }
@@ -174,14 +490,171 @@ impl XfgBurnMintVerifier {
         // Validate public inputs
         self.validate_public_inputs(public_inputs)?;
 
+        // Reject under-parameterized proofs before verifying them
+        self.check_security_policy(proof)?;
+
         // Verify the proof using Winterfell's verification system
-        match self.verify_with_winterfell(proof, public_inputs) {
-            Ok(_) => Ok(true),
-            Err(e) => {
-                eprintln!("Proof verification failed: {:?}", e);
-                Ok(false)
+        Ok(self.verify_winterfell_result(proof, public_inputs))
+    }
+
+    /// Verify a proof under the registered parameters it was generated with
+    ///
+    /// The proof's options, commitment version and statement must match a parameter set
+    /// in `registry` that its policy accepts. Registered parameters take the place of the
+    /// minimum security policy, so deprecated releases verify when the policy allows them.
+    /// A proof that does not verify is a [`StarkError::VerificationFailed`] error.
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError`] if the public inputs are malformed,
+    /// [`crate::parameter_registry::RegistryError`] if no accepted parameter set matches the
+    /// proof, and [`StarkError::VerificationFailed`] if it does not verify
+    pub fn verify_historical(
+        &self,
+        proof: &StarkProof,
+        public_inputs: &BurnMintPublicInputs,
+        registry: &ParameterRegistry,
+    ) -> Result<()> {
+        self.validate_public_inputs(public_inputs)?;
+
+        let parameters = registry.resolve(
+            proof.options(),
+            u32::try_from(public_inputs.commitment_version.as_int()).unwrap_or(u32::MAX),
+            BURN_MINT_STATEMENT_ID,
+        )?;
+
+        verify_statement_with_hash::<XfgBurnMintAir>(
+            proof.clone(),
+            public_inputs.clone(),
+            &parameters.options,
+            self.hash_function,
+        )
+        .map_err(|e| StarkError::VerificationFailed(e.to_string()).into())
+    }
+
+    /// Verify a serialized proof against public inputs
+    ///
+    /// The bytes are checked against the decode limits before and after decoding. The
+    /// proof is verified under the options embedded in it, which must meet the minimum
+    /// security policy. Malformed, oversized or under-parameterized proofs are errors;
+    /// a proof that decodes but does not verify yields a report with an error.
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::decode_bounded`], or if the public inputs are invalid or the proof falls
+    /// short of the security policy
+    pub fn verify_from_bytes(
+        &self,
+        proof_bytes: &[u8],
+        public_inputs: &BurnMintPublicInputs,
+    ) -> Result<ProofBytesReport> {
+        let proof = self.decode_bounded(proof_bytes)?;
+        self.verify_decoded(proof, proof_bytes, public_inputs)
+    }
+
+    /// Verify a package's proof against the claim its fields describe
+    ///
+    /// Public inputs are rebuilt from the burn transaction, recipient and secret stored
+    /// in the package; the nullifier and commitment are derived from the secret as the
+    /// prover derives them. The package must agree with the public inputs recorded in
+    /// its proof, and the recorded hash function must be the verifier's.
+    ///
+    /// # Errors
+    ///
+    /// [`PackageClaimError`] if the package is missing its proof or disagrees with the public
+    /// inputs the proof records, [`crate::XfgStarkError::HashFunctionMismatch`] for another hash
+    /// function, otherwise as [`Self::verify_from_bytes`]
+    #[cfg(feature = "packages")]
+    pub fn verify_package(&self, package: &CompleteProofPackage) -> Result<ProofBytesReport> {
+        let context = VerificationContext::from_package(&package.stark_proof_data)?;
+        self.verify_package_with_context(package, &context)
+    }
+
+    /// Verify a package's proof against a context already parsed from its data
+    ///
+    /// Lets Eldernode attestation and STARK verification share one
+    /// [`VerificationContext`], so both check the same parsed claim. The context must
+    /// have been built from `package.stark_proof_data`.
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::verify_package`]
+    #[cfg(feature = "packages")]
+    pub fn verify_package_with_context(
+        &self,
+        package: &CompleteProofPackage,
+        context: &VerificationContext,
+    ) -> Result<ProofBytesReport> {
+        let proof_file = package.stark_proof.as_ref().ok_or(PackageClaimError::MissingProof)?;
+        self.check_hash_function(proof_file.metadata.hash_function)?;
+
+        let proof = self.decode_bounded(&proof_file.proof_data)?;
+        let public_inputs = package_public_inputs(context, proof_file, &proof)?;
+        self.verify_decoded(proof, &proof_file.proof_data, &public_inputs)
+    }
+
+    /// Decode untrusted proof bytes within the decode limits
+    ///
+    /// Reads no clock, so browser and other `wasm32` builds can decode before verifying
+    /// with [`Self::verify_with_public_inputs`].
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError::ProofDecodeError`] if the bytes exceed the limits, do not
+    /// decode canonically, or describe a proof beyond the limits
+    pub fn decode_bounded(&self, proof_bytes: &[u8]) -> Result<StarkProof> {
+        let limits = &self.decode_limits;
+        if proof_bytes.len() > limits.max_proof_bytes {
+            return Err(ProofDecodeError::TooLarge {
+                size: proof_bytes.len(),
+                max: limits.max_proof_bytes,
+            }
+            .into());
+        }
+
+        let proof = StarkProof::from_bytes(proof_bytes).map_err(|e| ProofDecodeError::Malformed(e.to_string()))?;
+        check_proof_encoding(&proof, proof_bytes).map_err(ProofDecodeError::from)?;
+        BurnMintConfig::from_trace_info(&proof.get_trace_info())
+            .map_err(|e| ProofDecodeError::Malformed(e.to_string()))?;
+        let options = proof.options();
+        for (parameter, value, max) in [
+            ("trace length", proof.trace_length(), limits.max_trace_length),
+            ("query count", options.num_queries(), limits.max_queries),
+            ("blowup factor", options.blowup_factor(), limits.max_blowup),
+        ] {
+            if value > max {
+                return Err(ProofDecodeError::LimitExceeded { parameter, value, max }.into());
             }
         }
+        Ok(proof)
+    }
+
+    /// Verify a decoded proof under its embedded options and report on it
+    fn verify_decoded(
+        &self,
+        proof: StarkProof,
+        proof_bytes: &[u8],
+        public_inputs: &BurnMintPublicInputs,
+    ) -> Result<ProofBytesReport> {
+        let options = proof.options().clone();
+        self.validate_public_inputs(public_inputs)?;
+        let security_bits = self.check_security_policy(&proof)?;
+
+        let trace_length = proof.trace_length();
+        let started = Instant::now();
+        let outcome =
+            verify_statement_with_hash::<XfgBurnMintAir>(proof, public_inputs.clone(), &options, self.hash_function);
+
+        Ok(ProofBytesReport {
+            proof_size: proof_bytes.len(),
+            proof_digest: Sha256::digest(proof_bytes).into(),
+            options,
+            trace_length,
+            hash_function: self.hash_function,
+            security_bits,
+            verification_time: started.elapsed(),
+            error: outcome.err().map(|e| e.to_string()),
+        })
     }
 
     /// Validate input parameters
@@ -193,14 +666,9 @@ impl XfgBurnMintVerifier {
         recipient_address: &[u8],
     ) -> Result<()> {
         // Validate burn amount
-        let standard_burn = 8_000_000u64;  // 0.8 XFG in atomic units
-            let large_burn = 8_000_000_000u64; // 800 XFG in atomic units
-    
-    if burn_amount != standard_burn && burn_amount != large_burn {
-        return Err(crate::XfgStarkError::CryptoError(
-            "Burn amount must be exactly 0.8 XFG or 800 XFG".to_string(),
-        ));
-    }
+        if !crate::constants::is_valid_burn_amount(burn_amount) {
+            return Err(crate::XfgStarkError::CryptoError(crate::constants::INVALID_BURN_AMOUNT.to_string()));
+        }
 
         // Validate mint amount
         if mint_amount == 0 {
@@ -209,12 +677,8 @@ impl XfgBurnMintVerifier {
             ));
         }
 
-       // Validate 1:1 conversion in atomic units
-    if mint_amount != burn_amount {
-        return Err(crate::XfgStarkError::CryptoError(
-            "Mint amount must equal burn amount for 1:1 conversion".to_string(),
-        ));
-    }
+        // Validate mint amount is the burn less the protocol fee (1:1 when there is no fee)
+        self.fee_model.check_mint(burn_amount, mint_amount)?;
 
         // Validate transaction hash
         if txn_hash == 0 {
@@ -234,7 +698,7 @@ impl XfgBurnMintVerifier {
     }
 
     /// Validate public inputs
-    fn validate_public_inputs(&self, public_inputs: &BurnMintPublicInputs) -> Result<()> {
+    pub(crate) fn validate_public_inputs(&self, public_inputs: &BurnMintPublicInputs) -> Result<()> {
         // For public inputs validation, we can't validate recipient address
         // since we only have the hash. Just validate the amounts and txn_hash.
         let burn_amount = public_inputs.burn_amount.as_int() as u64;
@@ -258,6 +722,15 @@ impl XfgBurnMintVerifier {
             ));
         }
 
+        // The proof must charge this network's fee, and the claimed mint must honour it
+        self.fee_model.check_rate(public_inputs.fee_bps.as_int())?;
+        self.fee_model.check_mint(burn_amount, mint_amount)?;
+        public_inputs.check_recipient_binding()?;
+        public_inputs.check_mint_outputs()?;
+        if let Some(network) = &self.network {
+            public_inputs.check_network(network)?;
+        }
+
         Ok(())
     }
 
@@ -267,34 +740,15 @@ impl XfgBurnMintVerifier {
         proof: &StarkProof,
         public_inputs: &BurnMintPublicInputs,
     ) -> std::result::Result<(), VerifierError> {
-        // Create acceptable options for verification
-        let acceptable_options = AcceptableOptions::OptionSet(vec![self.proof_options.clone()]);
-
-        // Convert public inputs to the format expected by Winterfell
-        // For our AIR, we need to provide the public inputs in the expected format
-        let public_inputs_for_verification = public_inputs.clone();
-
-        // Use Winterfell's verification system with Blake3_256 hasher
-        verify::<XfgBurnMintAir, Blake3_256<BaseElement>, DefaultRandomCoin<Blake3_256<BaseElement>>>(
+        // Use Winterfell's verification system with the configured hasher
+        verify_statement_with_hash::<XfgBurnMintAir>(
             proof.clone(),
-            public_inputs_for_verification,
-            &acceptable_options,
+            public_inputs.clone(),
+            &self.proof_options,
+            self.hash_function,
         )
     }
 
-    /// Compute recipient hash from Ethereum address
-    fn compute_recipient_hash(&self, recipient_address: &[u8]) -> u32 {
-        use sha3::{Digest, Keccak256};
-
-        let mut hasher = Keccak256::new();
-        hasher.update(recipient_address);
-        hasher.update(b"recipient");
-        let hash = hasher.finalize();
-
-        // Convert first 4 bytes of hash to u32
-        u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]])
-    }
-
     /// Get verification time estimate
     pub fn estimate_verification_time(&self, proof_size: usize) -> std::time::Duration {
         // Rough estimate: 1ms per KB of proof size
@@ -313,6 +767,36 @@ impl XfgBurnMintVerifier {
         &self.proof_options
     }
 
+    /// Hash function proofs must commit with
+    #[must_use]
+    pub fn hash_function(&self) -> HashFunction {
+        self.hash_function
+    }
+
+    /// Bounds on serialized proofs
+    #[must_use]
+    pub fn decode_limits(&self) -> &ProofDecodeLimits {
+        &self.decode_limits
+    }
+
+    /// Get minimum conjectured security a proof must provide (bits)
+    #[must_use]
+    pub fn min_security_bits(&self) -> u32 {
+        self.min_security_bits
+    }
+
+    /// Get protocol fee the network charges on mints
+    #[must_use]
+    pub fn fee_model(&self) -> FeeModel {
+        self.fee_model
+    }
+
+    /// Network proofs must be claimed on, if any
+    #[must_use]
+    pub fn network(&self) -> Option<&NetworkParams> {
+        self.network.as_ref()
+    }
+
     /// Check if proof is valid format
     pub fn is_valid_proof_format(&self, proof: &StarkProof) -> bool {
         // Basic format validation
@@ -346,6 +830,9 @@ impl XfgBurnMintVerifier {
         // Validate public inputs first
         self.validate_public_inputs(public_inputs)?;
 
+        // Reject under-parameterized proofs before verifying them
+        self.check_security_policy(proof)?;
+
         // Attempt verification
         match self.verify_with_winterfell(proof, public_inputs) {
             Ok(_) => Ok(VerificationResult::Success {
@@ -380,14 +867,29 @@ impl BatchBurnMintVerifier {
         }
     }
 
+    /// Create batch verifier from an existing verifier
+    #[must_use]
+    pub fn with_verifier(verifier: XfgBurnMintVerifier) -> Self {
+        Self { verifier }
+    }
+
     /// Verify multiple proofs in batch
+    ///
+    /// A claim whose nullifier was already used by an earlier claim in the batch is
+    /// rejected, even if its proof is valid.
     pub fn verify_batch(
         &self,
         proofs_and_inputs: &[(&StarkProof, &BurnMintPublicInputs)],
     ) -> Result<Vec<bool>> {
         let mut results = Vec::new();
+        let mut seen_nullifiers = std::collections::HashSet::new();
 
         for (proof, public_inputs) in proofs_and_inputs {
+            if !seen_nullifiers.insert(Nullifier::from_public_inputs(public_inputs)) {
+                results.push(false);
+                continue;
+            }
+
             let result = self
                 .verifier
                 .verify_with_public_inputs(proof, public_inputs)?;
@@ -407,9 +909,53 @@ impl BatchBurnMintVerifier {
     }
 }
 
+/// Rebuild the public inputs of a package's proof from its parsed fields
+///
+/// Packages are proven for [`crate::constants::DEFAULT_TARGET_CHAIN_ID`]; a proof for another chain fails
+/// the recipient binding check.
+#[cfg(feature = "packages")]
+fn package_public_inputs(
+    context: &VerificationContext,
+    proof_file: &ProofFile,
+    proof: &StarkProof,
+) -> Result<BurnMintPublicInputs> {
+    // The package must describe the claim recorded with the proof
+    let hex_digits = |value: &str| value.trim_start_matches("0x").to_ascii_lowercase();
+    let recorded = &proof_file.public_inputs;
+    for (field, package_value, proof_value) in [
+        ("burn_amount", context.burn_amount().to_string(), recorded.burn_amount.to_string()),
+        ("mint_amount", context.mint_amount().to_string(), recorded.mint_amount.to_string()),
+        ("fee_bps", context.fee_bps().to_string(), recorded.fee_bps.to_string()),
+        (
+            "txn_hash",
+            hex_digits(context.transaction_hash()),
+            hex_digits(&recorded.txn_hash),
+        ),
+        (
+            "recipient_hash",
+            hex::encode(context.recipient_binding()),
+            hex_digits(&recorded.recipient_hash),
+        ),
+        ("state", "0".to_string(), recorded.state.to_string()),
+    ] {
+        if package_value != proof_value {
+            return Err(PackageClaimError::Mismatch {
+                field,
+                package: package_value,
+                proof: proof_value,
+            }
+            .into());
+        }
+    }
+
+    Ok(context.public_inputs(proof.get_trace_info(), proof.options().clone()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::commitment::{commitment_to_bytes, recipient_binding, recipient_binding_limbs, RECIPIENT_BINDING_LIMBS};
+    use winterfell::math::fields::f64::BaseElement;
 
     #[test]
     fn test_verifier_creation() {
@@ -477,23 +1023,53 @@ mod tests {
             tx_hash_bytes[0], tx_hash_bytes[1], tx_hash_bytes[2], tx_hash_bytes[3],
             tx_hash_bytes[4], tx_hash_bytes[5], tx_hash_bytes[6], tx_hash_bytes[7]
         ]);
+        let binding = recipient_binding_limbs(&recipient_binding(&[0x12; 20], 42161));
         
         let valid_inputs = BurnMintPublicInputs {
             burn_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units
             mint_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units (1:1 ratio)
             txn_hash: BaseElement::from(tx_hash_u64 as u32), // Real generated transaction hash
-            recipient_hash: BaseElement::from(67890u32), // TODO: Use real recipient hash
+            recipient_hash: binding[0],
+            recipient_binding: binding,
             state: BaseElement::from(0u32),
+            tx_prefix_hash_0: BaseElement::from(0u32),
+            tx_prefix_hash_1: BaseElement::from(0u32),
+            tx_prefix_hash_2: BaseElement::from(0u32),
+            tx_prefix_hash_3: BaseElement::from(0u32),
+            network_id: BaseElement::from(1u32),
+            target_chain_id: BaseElement::from(42161u32),
+            commitment_version: BaseElement::from(1u32),
+            fee_bps: BaseElement::from(0u32),
+            nullifier: [BaseElement::from(0u32); 4],
+            commitment: [BaseElement::from(0u32); 4],
+            outputs: Vec::new(),
         };
 
         assert!(verifier.validate_public_inputs(&valid_inputs).is_ok());
 
+        // The recipient hash must repeat the first binding limb
+        let mut rebound = valid_inputs.clone();
+        rebound.recipient_hash = BaseElement::from(67890u32);
+        assert!(verifier.validate_public_inputs(&rebound).is_err());
+
         let invalid_inputs = BurnMintPublicInputs {
             burn_amount: BaseElement::from(0u32), // Invalid
             mint_amount: BaseElement::from(8_000_000u32),
             txn_hash: BaseElement::from(tx_hash_u64 as u32), // Real generated transaction hash
-            recipient_hash: BaseElement::from(67890u32), // TODO: Use real recipient hash
+            recipient_hash: binding[0],
+            recipient_binding: binding,
             state: BaseElement::from(0u32),
+            tx_prefix_hash_0: BaseElement::from(0u32),
+            tx_prefix_hash_1: BaseElement::from(0u32),
+            tx_prefix_hash_2: BaseElement::from(0u32),
+            tx_prefix_hash_3: BaseElement::from(0u32),
+            network_id: BaseElement::from(1u32),
+            target_chain_id: BaseElement::from(42161u32),
+            commitment_version: BaseElement::from(1u32),
+            fee_bps: BaseElement::from(0u32),
+            nullifier: [BaseElement::from(0u32); 4],
+            commitment: [BaseElement::from(0u32); 4],
+            outputs: Vec::new(),
         };
 
         assert!(verifier.validate_public_inputs(&invalid_inputs).is_err());
@@ -537,7 +1113,19 @@ mod tests {
             mint_amount: BaseElement::from(8_000_000u32), // 0.8 XFG in atomic units (1:1 ratio)
             txn_hash: BaseElement::from(tx_hash_u64 as u32), // Real generated transaction hash
             recipient_hash: BaseElement::from(67890u32), // TODO: Use real recipient hash
+            recipient_binding: [BaseElement::from(0u32); RECIPIENT_BINDING_LIMBS],
             state: BaseElement::from(0u32),
+            tx_prefix_hash_0: BaseElement::from(0u32),
+            tx_prefix_hash_1: BaseElement::from(0u32),
+            tx_prefix_hash_2: BaseElement::from(0u32),
+            tx_prefix_hash_3: BaseElement::from(0u32),
+            network_id: BaseElement::from(1u32),
+            target_chain_id: BaseElement::from(42161u32),
+            commitment_version: BaseElement::from(1u32),
+            fee_bps: BaseElement::from(0u32),
+            nullifier: [BaseElement::from(0u32); 4],
+            commitment: [BaseElement::from(0u32); 4],
+            outputs: Vec::new(),
         };
 
         let batch = vec![(&dummy_proof, &valid_inputs)];
@@ -549,4 +1137,356 @@ mod tests {
             Err(e) => println!("Batch verification failed (expected in development): {}", e),
         }
     }
+
+    #[test]
+    fn test_security_policy() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+
+        let prove = |prover: &XfgBurnMintProver| {
+            prover
+                .prove_burn_mint_with_public_inputs(
+                    &BurnMintRequest {
+                        burn_amount: 8_000_000,
+                        mint_amount: 8_000_000,
+                        tx_prefix_hash: [7u8; 32],
+                        recipient_address: &[0x12u8; 20],
+                        network_id: 1,
+                        target_chain_id: 42161,
+                        commitment_version: 1,
+                    },
+                    &[9u8; 32],
+                )
+                .expect("Proof generation should succeed")
+        };
+
+        // Default parameters clear the default minimum
+        let verifier = XfgBurnMintVerifier::default();
+        let (proof, public_inputs) = prove(&XfgBurnMintProver::default());
+        let effective_bits = verifier.check_security_policy(&proof).unwrap();
+        assert!(effective_bits >= DEFAULT_MIN_SECURITY_BITS);
+        assert!(verifier.verify_with_public_inputs(&proof, &public_inputs).unwrap());
+
+        // Base-field-only proofs are under-parameterized
+        let weak_options = ProofOptions::new(42, 8, 4, winterfell::FieldExtension::None, 8, 31);
+        let (weak_proof, weak_inputs) = prove(&XfgBurnMintProver::with_options(128, weak_options.clone()));
+        let weak_verifier = XfgBurnMintVerifier::with_options(128, weak_options);
+        let weak_bits = weak_verifier.effective_security_bits(&weak_proof);
+        assert_eq!(
+            weak_verifier.check_security_policy(&weak_proof),
+            Err(SecurityPolicyError::InsufficientSecurity {
+                effective_bits: weak_bits,
+                minimum_bits: DEFAULT_MIN_SECURITY_BITS,
+            })
+        );
+        assert!(matches!(
+            weak_verifier.verify_with_public_inputs(&weak_proof, &weak_inputs),
+            Err(crate::XfgStarkError::SecurityPolicyError(_))
+        ));
+
+        // Lowering the minimum admits the proof
+        let relaxed = weak_verifier.with_min_security_bits(weak_bits);
+        assert!(relaxed.verify_with_public_inputs(&weak_proof, &weak_inputs).unwrap());
+    }
+
+    #[test]
+    fn test_verify_burn_mint_from_user_data() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+
+        let recipient = [0x12u8; 20];
+        let (proof, public_inputs) = XfgBurnMintProver::default()
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &recipient,
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let verify = |recipient: &[u8; 20]| {
+            XfgBurnMintVerifier::default()
+                .verify_burn_mint(
+                    &proof,
+                    &BurnMintVerification {
+                        burn_amount: 8_000_000,
+                        tx_prefix_hash: [7u8; 32],
+                        recipient_address: recipient,
+                        network_id: 1,
+                        target_chain_id: 42161,
+                        commitment_version: 1,
+                        nullifier: &Nullifier::from_public_inputs(&public_inputs),
+                        commitment: &commitment_to_bytes(&public_inputs.commitment),
+                    },
+                    None,
+                )
+                .unwrap()
+        };
+
+        assert!(verify(&recipient));
+        let mut redirected = recipient;
+        redirected[19] ^= 1;
+        assert!(!verify(&redirected));
+    }
+
+    #[test]
+    fn test_protocol_fee_enforced() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::fees::{FeeError, FeeModel};
+
+        let fee_model = FeeModel::new(50).unwrap();
+        let prover = XfgBurnMintProver::default().with_fee_model(fee_model);
+
+        // 1:1 mints are rejected on a fee-enabled network
+        assert!(prover
+            .prove_burn_mint(8_000_000, 8_000_000, [7u8; 32], &[0x12u8; 20], &[9u8; 32], 1, 42161, 1)
+            .is_err());
+
+        let (proof, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 7_960_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Fee-adjusted mint should prove");
+
+        let verifier = XfgBurnMintVerifier::default().with_fee_model(fee_model);
+        assert!(verifier.verify_with_public_inputs(&proof, &public_inputs).unwrap());
+
+        // A verifier for a fee-free network refuses the fee-charging proof
+        assert!(matches!(
+            XfgBurnMintVerifier::default().verify_with_public_inputs(&proof, &public_inputs),
+            Err(crate::XfgStarkError::FeeError(FeeError::RateMismatch { expected: 0, actual: 50 }))
+        ));
+
+        // Dropping the fee from the public inputs breaks the in-circuit mint assertion
+        let mut tampered = public_inputs.clone();
+        tampered.fee_bps = BaseElement::from(0u32);
+        assert!(verifier.verify_with_winterfell(&proof, &tampered).is_err());
+    }
+
+    #[test]
+    fn test_historical_parameters() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::parameter_registry::{HistoricalPolicy, RegistryError};
+
+        let registry = ParameterRegistry::builtin();
+        let legacy_options = registry.get(1).unwrap().options.clone();
+        let (proof, public_inputs) = XfgBurnMintProver::with_options(128, legacy_options)
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+
+        // Deprecated parameters are refused unless the policy allows them
+        let verifier = XfgBurnMintVerifier::default();
+        assert!(matches!(
+            verifier.verify_historical(&proof, &public_inputs, &registry),
+            Err(crate::XfgStarkError::RegistryError(RegistryError::Deprecated { protocol_version: 1 }))
+        ));
+        let historical = registry.clone().with_policy(HistoricalPolicy::AllowDeprecated);
+        verifier.verify_historical(&proof, &public_inputs, &historical).unwrap();
+
+        // A proof that does not verify against the claim is an error, not a log line
+        let mut other_burn = public_inputs.clone();
+        other_burn.txn_hash += BaseElement::from(1u32);
+        assert!(matches!(
+            verifier.verify_historical(&proof, &other_burn, &historical),
+            Err(crate::XfgStarkError::StarkError(StarkError::VerificationFailed(_)))
+        ));
+
+        // A commitment version never released with these options is rejected
+        let mut unreleased = public_inputs.clone();
+        unreleased.commitment_version = BaseElement::from(2u32);
+        assert!(matches!(
+            verifier.verify_historical(&proof, &unreleased, &historical),
+            Err(crate::XfgStarkError::RegistryError(RegistryError::UnknownParameters { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_hash_function_enforced() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+
+        for hash_function in [HashFunction::Keccak, HashFunction::Rescue] {
+            let (proof, public_inputs) = XfgBurnMintProver::default()
+                .with_hash_function(hash_function)
+                .prove_burn_mint_with_public_inputs(
+                    &BurnMintRequest {
+                        burn_amount: 8_000_000,
+                        mint_amount: 8_000_000,
+                        tx_prefix_hash: [7u8; 32],
+                        recipient_address: &[0x12u8; 20],
+                        network_id: 1,
+                        target_chain_id: 42161,
+                        commitment_version: 1,
+                    },
+                    &[9u8; 32],
+                )
+                .expect("Proof generation should succeed");
+
+            let verifier = XfgBurnMintVerifier::default().with_hash_function(hash_function);
+            assert!(verifier.check_hash_function(hash_function).is_ok());
+            assert!(verifier.verify_with_public_inputs(&proof, &public_inputs).unwrap());
+            assert_eq!(verifier.effective_security_bits(&proof), hash_function.security_level(&proof, true));
+
+            // A BLAKE3 verifier neither accepts the metadata nor the proof
+            let blake3 = XfgBurnMintVerifier::default();
+            assert!(matches!(
+                blake3.check_hash_function(hash_function),
+                Err(crate::XfgStarkError::HashFunctionMismatch { expected: HashFunction::Blake3, .. })
+            ));
+            assert!(!blake3.verify_with_public_inputs(&proof, &public_inputs).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_verify_from_bytes() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+
+        let (proof, public_inputs) = XfgBurnMintProver::default()
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let bytes = proof.to_bytes();
+
+        // Options are taken from the proof, not from the verifier
+        let other_options = ProofOptions::new(64, 16, 0, winterfell::FieldExtension::Quadratic, 4, 7);
+        let verifier = XfgBurnMintVerifier::with_options(128, other_options);
+        let report = verifier.verify_from_bytes(&bytes, &public_inputs).unwrap();
+        assert!(report.is_valid());
+        assert_eq!(&report.options, proof.options());
+        assert_eq!(report.proof_size, bytes.len());
+        assert_eq!(report.trace_length, proof.trace_length());
+        assert!(report.security_bits >= DEFAULT_MIN_SECURITY_BITS);
+
+        let mut tampered = public_inputs.clone();
+        tampered.commitment[0] += BaseElement::new(1);
+        assert!(!verifier.verify_from_bytes(&bytes, &tampered).unwrap().is_valid());
+
+        assert!(matches!(
+            verifier.verify_from_bytes(&bytes[..bytes.len() / 2], &public_inputs),
+            Err(crate::XfgStarkError::ProofDecodeError(ProofDecodeError::Malformed(_)))
+        ));
+
+        // Limits apply to the raw size and to the decoded parameters
+        let limits = ProofDecodeLimits::default();
+        let small = verifier.clone().with_decode_limits(ProofDecodeLimits {
+            max_proof_bytes: bytes.len() - 1,
+            ..limits
+        });
+        assert!(matches!(
+            small.verify_from_bytes(&bytes, &public_inputs),
+            Err(crate::XfgStarkError::ProofDecodeError(ProofDecodeError::TooLarge { .. }))
+        ));
+        let few_queries = verifier.with_decode_limits(ProofDecodeLimits { max_queries: 41, ..limits });
+        assert!(matches!(
+            few_queries.verify_from_bytes(&bytes, &public_inputs),
+            Err(crate::XfgStarkError::ProofDecodeError(ProofDecodeError::LimitExceeded {
+                parameter: "query count",
+                value: 42,
+                max: 41
+            }))
+        ));
+    }
+
+    #[cfg(feature = "packages")]
+    #[test]
+    fn test_verify_package() {
+        use crate::burn_mint_prover::XfgBurnMintProver;
+        use crate::commitment_audit::compute_package_commitment;
+        use crate::constants::{COMMITMENT_VERSION, DEFAULT_TARGET_CHAIN_ID};
+        use crate::proof_data_schema::{ProofMetadata, StarkProofDataPackage, StarkPublicInputs};
+
+        let data = StarkProofDataPackage::new(
+            0.8,
+            "7d0725f8e03021b99560add456c596fea7d8df23529e23765e56923b73236e4d".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "a1b2c3d4e5f6a7b8c9d0e1f2a3b4c5d6".to_string(),
+            "fuego-testnet".to_string(),
+        );
+        let mut package = CompleteProofPackage::new(data.clone());
+        let verifier = XfgBurnMintVerifier::default();
+        assert!(matches!(
+            verifier.verify_package(&package),
+            Err(crate::XfgStarkError::PackageClaimError(PackageClaimError::MissingProof))
+        ));
+
+        let recipient = data.recipient_address().unwrap();
+        let proof = XfgBurnMintProver::default()
+            .prove_burn_mint(
+                data.burn_transaction.burn_amount_atomic,
+                data.get_mint_amount_atomic(),
+                data.tx_prefix_hash().unwrap(),
+                recipient.as_bytes(),
+                &data.secret_bytes(),
+                data.network_id_number(),
+                DEFAULT_TARGET_CHAIN_ID,
+                COMMITMENT_VERSION,
+            )
+            .expect("Proof generation should succeed");
+        package.add_stark_proof(ProofFile {
+            proof_data: proof.to_bytes(),
+            public_inputs: StarkPublicInputs {
+                burn_amount: data.burn_transaction.burn_amount_atomic,
+                mint_amount: data.get_mint_amount_atomic(),
+                txn_hash: data.burn_transaction.transaction_hash.clone(),
+                recipient_hash: hex::encode(recipient_binding(recipient.as_bytes(), DEFAULT_TARGET_CHAIN_ID)),
+                state: 0,
+                fee_bps: 0,
+            },
+            metadata: ProofMetadata {
+                hash_function: HashFunction::Blake3,
+                ..data.metadata.clone()
+            },
+        });
+        assert!(verifier.verify_package(&package).unwrap().is_valid());
+
+        // Eldernode attestation and STARK verification can share one parsed context
+        let context = VerificationContext::from_package(&package.stark_proof_data).unwrap();
+        assert_eq!(context.eldernode_inputs().commitment, compute_package_commitment(&data).unwrap());
+        assert!(verifier.verify_package_with_context(&package, &context).unwrap().is_valid());
+
+        // Recorded public inputs must agree with the package
+        let mut inconsistent = package.clone();
+        inconsistent.stark_proof.as_mut().unwrap().public_inputs.burn_amount = 8_000_000_000;
+        assert!(matches!(
+            verifier.verify_package(&inconsistent),
+            Err(crate::XfgStarkError::PackageClaimError(PackageClaimError::Mismatch { field: "burn_amount", .. }))
+        ));
+
+        // Another secret derives another commitment, which the proof does not bind
+        let mut other_secret = package.clone();
+        other_secret.stark_proof_data.secret.secret_key = "f1e2d3c4b5a6978877665544332211ff".to_string();
+        assert!(!verifier.verify_package(&other_secret).unwrap().is_valid());
+    }
 }
@@ -0,0 +1,311 @@
+//! Structural Claim Binding Checks for Light Consumers
+//!
+//! Indexers and UIs often only need to know whether a serialized proof claims the burn
+//! they are displaying. [`verify_claim_binding`] decodes a proof file, recomputes the
+//! recipient binding digest from the claim and compares it and the other public inputs
+//! with the ones embedded in the proof, without running STARK verification.
+//!
+//! **The result is not cryptographic.** Anyone can write a proof file whose public
+//! inputs match any claim; only [`crate::burn_mint_verifier::XfgBurnMintVerifier`]
+//! establishes that the proof is valid. The result says so in its type and display.
+
+use crate::commitment::{recipient_binding, RECIPIENT_ADDRESS_LEN};
+use crate::constants::is_valid_burn_amount;
+use crate::fees::protocol_fee;
+use crate::proof_data_schema::StarkProof as ProofFile;
+use sha2::{Digest, Sha256};
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+use winterfell::StarkProof;
+
+/// Burn & mint a consumer wants matched against a proof
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BurnMintClaim {
+    /// Burn amount in atomic units
+    pub burn_amount: u64,
+    /// Mint amount in atomic units
+    pub mint_amount: u64,
+    /// Burn transaction hash (hex)
+    pub txn_hash: String,
+    /// Recipient Ethereum address
+    pub recipient_address: [u8; RECIPIENT_ADDRESS_LEN],
+    /// Chain the HEAT is minted on
+    pub target_chain_id: u32,
+}
+
+/// A public input that disagrees with the claim or with the protocol rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClaimMismatch {
+    /// Embedded burn amount differs from the claim
+    BurnAmount {
+        /// Claimed amount
+        claimed: u64,
+        /// Amount in the proof
+        embedded: u64,
+    },
+    /// Embedded mint amount differs from the claim
+    MintAmount {
+        /// Claimed amount
+        claimed: u64,
+        /// Amount in the proof
+        embedded: u64,
+    },
+    /// Embedded transaction hash differs from the claim
+    TxnHash {
+        /// Claimed hash
+        claimed: String,
+        /// Hash in the proof
+        embedded: String,
+    },
+    /// Recipient binding recomputed from the claim differs from the proof's
+    RecipientBinding {
+        /// Binding of the claimed recipient and chain (hex)
+        claimed: String,
+        /// Binding in the proof (hex)
+        embedded: String,
+    },
+    /// Embedded burn amount is outside the provable range
+    InvalidBurnAmount(u64),
+    /// Embedded mint amount is not the burn less the embedded protocol fee
+    FeeInconsistent {
+        /// Embedded fee rate (basis points)
+        fee_bps: u32,
+        /// Mint amount the fee rate implies
+        expected_mint: u64,
+    },
+}
+
+impl Display for ClaimMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClaimMismatch::BurnAmount { claimed, embedded } => {
+                write!(f, "burn amount: claimed {claimed}, proof has {embedded}")
+            }
+            ClaimMismatch::MintAmount { claimed, embedded } => {
+                write!(f, "mint amount: claimed {claimed}, proof has {embedded}")
+            }
+            ClaimMismatch::TxnHash { claimed, embedded } => {
+                write!(f, "transaction hash: claimed {claimed}, proof has {embedded}")
+            }
+            ClaimMismatch::RecipientBinding { claimed, embedded } => {
+                write!(f, "recipient binding: claimed {claimed}, proof has {embedded}")
+            }
+            ClaimMismatch::InvalidBurnAmount(amount) => write!(f, "burn amount {amount} is outside the provable range"),
+            ClaimMismatch::FeeInconsistent { fee_bps, expected_mint } => {
+                write!(f, "mint amount does not match a {fee_bps} bps fee (expected {expected_mint})")
+            }
+        }
+    }
+}
+
+/// Outcome of a structural claim check
+///
+/// Not a cryptographic verdict: the proof itself has not been verified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructuralClaimCheck {
+    /// SHA-256 of the embedded STARK proof bytes
+    pub proof_digest: [u8; 32],
+    /// Public inputs that disagree with the claim or the protocol rules
+    pub mismatches: Vec<ClaimMismatch>,
+}
+
+impl StructuralClaimCheck {
+    /// Always `false`: the STARK proof was not verified
+    pub const CRYPTOGRAPHICALLY_VERIFIED: bool = false;
+
+    /// Check whether the embedded public inputs match the claim
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl Display for StructuralClaimCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_consistent() {
+            write!(f, "Public inputs match the claim")?;
+        } else {
+            write!(f, "Public inputs do not match the claim:")?;
+            for mismatch in &self.mismatches {
+                write!(f, "\n  - {mismatch}")?;
+            }
+        }
+        write!(
+            f,
+            "\nStructural check only; proof {} was not cryptographically verified",
+            hex::encode(self.proof_digest)
+        )
+    }
+}
+
+/// Reasons a proof file cannot be checked at all
+#[derive(Debug, Error)]
+pub enum ClaimBindingError {
+    /// Proof file is not valid JSON of the proof schema
+    #[error("Malformed proof file: {0}")]
+    MalformedProofFile(#[from] serde_json::Error),
+    /// Embedded proof bytes are not a STARK proof
+    #[error("Embedded STARK proof does not decode: {0}")]
+    MalformedStarkProof(String),
+}
+
+/// Compare the public inputs embedded in a serialized proof file with a claim
+///
+/// Decodes the proof structure and recomputes the recipient binding, but does not
+/// verify the proof. See [`StructuralClaimCheck`].
+///
+/// # Errors
+///
+/// [`ClaimBindingError`] if the proof file or its embedded proof does not decode; a
+/// decodable proof that disagrees with the claim is reported through the mismatches
+pub fn verify_claim_binding(
+    proof_bytes: &[u8],
+    claim: &BurnMintClaim,
+) -> Result<StructuralClaimCheck, ClaimBindingError> {
+    let proof_file: ProofFile = serde_json::from_slice(proof_bytes)?;
+    StarkProof::from_bytes(&proof_file.proof_data)
+        .map_err(|e| ClaimBindingError::MalformedStarkProof(e.to_string()))?;
+
+    let embedded = &proof_file.public_inputs;
+    let mut mismatches = Vec::new();
+
+    if embedded.burn_amount != claim.burn_amount {
+        mismatches.push(ClaimMismatch::BurnAmount {
+            claimed: claim.burn_amount,
+            embedded: embedded.burn_amount,
+        });
+    }
+    if embedded.mint_amount != claim.mint_amount {
+        mismatches.push(ClaimMismatch::MintAmount {
+            claimed: claim.mint_amount,
+            embedded: embedded.mint_amount,
+        });
+    }
+    if normalize_hex(&embedded.txn_hash) != normalize_hex(&claim.txn_hash) {
+        mismatches.push(ClaimMismatch::TxnHash {
+            claimed: claim.txn_hash.clone(),
+            embedded: embedded.txn_hash.clone(),
+        });
+    }
+
+    let claimed_binding = hex::encode(recipient_binding(&claim.recipient_address, claim.target_chain_id));
+    if normalize_hex(&embedded.recipient_hash) != claimed_binding {
+        mismatches.push(ClaimMismatch::RecipientBinding {
+            claimed: claimed_binding,
+            embedded: embedded.recipient_hash.clone(),
+        });
+    }
+
+    if is_valid_burn_amount(embedded.burn_amount) {
+        let expected_mint = embedded.burn_amount - protocol_fee(embedded.burn_amount, embedded.fee_bps);
+        if embedded.mint_amount != expected_mint {
+            mismatches.push(ClaimMismatch::FeeInconsistent {
+                fee_bps: embedded.fee_bps,
+                expected_mint,
+            });
+        }
+    } else {
+        mismatches.push(ClaimMismatch::InvalidBurnAmount(embedded.burn_amount));
+    }
+
+    Ok(StructuralClaimCheck {
+        proof_digest: Sha256::digest(&proof_file.proof_data).into(),
+        mismatches,
+    })
+}
+
+/// Lowercase hex without a `0x` prefix
+fn normalize_hex(value: &str) -> String {
+    value.trim_start_matches("0x").to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_prover::XfgBurnMintProver;
+    use crate::proof_data_schema::{ProofMetadata, StarkPublicInputs};
+    use crate::statements::HashFunction;
+
+    const RECIPIENT: [u8; 20] = [0x12; 20];
+    const TXN_HASH: &str = "0707070707070707070707070707070707070707070707070707070707070707";
+
+    fn claim() -> BurnMintClaim {
+        BurnMintClaim {
+            burn_amount: 8_000_000,
+            mint_amount: 8_000_000,
+            txn_hash: TXN_HASH.to_string(),
+            recipient_address: RECIPIENT,
+            target_chain_id: 42161,
+        }
+    }
+
+    fn proof_file() -> Vec<u8> {
+        let proof = XfgBurnMintProver::default()
+            .prove_burn_mint(8_000_000, 8_000_000, [7u8; 32], &RECIPIENT, &[9u8; 32], 1, 42161, 1)
+            .expect("Proof generation should succeed");
+        let proof_file = ProofFile {
+            proof_data: proof.to_bytes(),
+            public_inputs: StarkPublicInputs {
+                burn_amount: 8_000_000,
+                mint_amount: 8_000_000,
+                txn_hash: TXN_HASH.to_string(),
+                recipient_hash: hex::encode(recipient_binding(&RECIPIENT, 42161)),
+                state: 0,
+                fee_bps: 0,
+            },
+            metadata: ProofMetadata {
+                version: "1.0.0".to_string(),
+                created_at: "2025-01-01T00:00:00Z".to_string(),
+                description: "claim binding test".to_string(),
+                network: "testnet".to_string(),
+                build_info: None,
+                hash_function: HashFunction::default(),
+                producer: None,
+                migrated_from: None,
+            },
+        };
+        serde_json::to_vec(&proof_file).unwrap()
+    }
+
+    #[test]
+    fn test_matching_claim() {
+        let bytes = proof_file();
+        let check = verify_claim_binding(&bytes, &claim()).unwrap();
+        assert!(check.is_consistent());
+        assert!(!StructuralClaimCheck::CRYPTOGRAPHICALLY_VERIFIED);
+        assert!(check.to_string().contains("not cryptographically verified"));
+
+        // Hex case and prefix do not matter
+        let prefixed = BurnMintClaim {
+            txn_hash: format!("0x{}", TXN_HASH.to_uppercase()),
+            ..claim()
+        };
+        assert!(verify_claim_binding(&bytes, &prefixed).unwrap().is_consistent());
+    }
+
+    #[test]
+    fn test_mismatching_claims() {
+        let bytes = proof_file();
+
+        let other_chain = BurnMintClaim {
+            target_chain_id: 1,
+            ..claim()
+        };
+        assert!(matches!(
+            verify_claim_binding(&bytes, &other_chain).unwrap().mismatches.as_slice(),
+            [ClaimMismatch::RecipientBinding { .. }]
+        ));
+
+        let large_burn = BurnMintClaim {
+            burn_amount: 8_000_000_000,
+            mint_amount: 8_000_000_000,
+            ..claim()
+        };
+        assert_eq!(verify_claim_binding(&bytes, &large_burn).unwrap().mismatches.len(), 2);
+
+        assert!(matches!(
+            verify_claim_binding(b"{}", &claim()),
+            Err(ClaimBindingError::MalformedProofFile(_))
+        ));
+    }
+}
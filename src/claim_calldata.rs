@@ -0,0 +1,382 @@
+//! HEAT Claim Calldata
+//!
+//! [`ClaimCall`] builds the exact calldata of the HEAT verifier contract's
+//! [`CLAIM_SIGNATURE`] from a proven [`CompleteProofPackage`], so a claim can be
+//! assembled offline and submitted from any wallet:
+//!
+//! ```text
+//! claimHEAT(bytes32 secret, bytes proof, bytes32[] publicInputs, address recipient,
+//!           bool isLargeBurn, bytes eldernodeProof)
+//! ```
+//!
+//! `publicInputs` holds the four words the contract reads: the proof's nullifier, the
+//! proof's commitment (which is the HEAT commitment in the burn's `tx_extra`),
+//! `keccak256(recipient)` and [`FUEGO_NETWORK_ID`]. `eldernodeProof` is passed through as
+//! given; it may be empty when the contract does not require Eldernode consensus.
+//!
+//! `isLargeBurn` is the contract's only tier argument, so two of the four burn tiers can
+//! be claimed through it: 0.8 XFG (`false`) and 800 XFG (`true`). Packages burning 8 or
+//! 80 XFG, or any other amount, are rejected with
+//! [`ClaimCalldataError::UnsupportedBurnAmount`].
+//!
+//! [`ClaimCall::typed_data`] wraps the same arguments in an EIP-712 payload for
+//! `eth_signTypedData_v4`, and [`ClaimCall::eip712_digest`] is the hash a hardware
+//! wallet displays when signing it.
+
+use crate::burn_mint_verifier::XfgBurnMintVerifier;
+use crate::commitment::commitment_to_bytes;
+use crate::constants::{LARGE_BURN_ATOMIC, STANDARD_BURN_ATOMIC};
+use crate::eth_address::EthAddress;
+use crate::nullifier::Nullifier;
+use crate::proof::evm::{encode_bytes, word, WORD};
+use crate::proof_data_schema::CompleteProofPackage;
+use crate::verification_context::VerificationContext;
+use serde_json::{json, Value};
+use sha3::{Digest, Keccak256};
+use thiserror::Error;
+
+/// Solidity signature of the HEAT verifier contract's claim function
+pub const CLAIM_SIGNATURE: &str = "claimHEAT(bytes32,bytes,bytes32[],address,bool,bytes)";
+
+/// Fuego network ID the HEAT verifier contract checks (`"FANDOMGOLD"` and a version)
+pub const FUEGO_NETWORK_ID: u128 = 93_385_046_440_755_750_514_194_170_694_064_996_624;
+
+/// EIP-712 type of the claim arguments
+const CLAIM_TYPE: &str =
+    "ClaimHEAT(bytes32 secret,bytes proof,bytes32[] publicInputs,address recipient,bool isLargeBurn,bytes eldernodeProof)";
+
+/// EIP-712 type of the signing domain
+const DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+
+/// Number of head words: one per argument
+const HEAD_WORDS: usize = 6;
+
+/// A package that cannot be turned into a claim
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ClaimCalldataError {
+    /// Package has not been proven yet
+    #[error("Package has no STARK proof")]
+    MissingProof,
+    /// The package's burn, recipient or secret does not parse
+    #[error("Invalid package: {0}")]
+    InvalidPackage(String),
+    /// The proof bytes do not decode
+    #[error("Invalid proof: {0}")]
+    InvalidProof(String),
+    /// The contract only mints for the standard and large burn tiers
+    #[error("Burn of {0} atomic units is not a tier the HEAT contract mints for")]
+    UnsupportedBurnAmount(u64),
+}
+
+/// Arguments of a `claimHEAT` call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimCall {
+    /// Secret from the burn transaction's `tx_extra`
+    pub secret: [u8; 32],
+    /// Serialized STARK proof
+    pub proof: Vec<u8>,
+    /// Nullifier, HEAT commitment, recipient hash and network ID
+    pub public_inputs: [[u8; 32]; 4],
+    /// Address receiving the HEAT
+    pub recipient: EthAddress,
+    /// Whether the burn is the large (800 XFG) tier
+    pub is_large_burn: bool,
+    /// Eldernode consensus proof, empty when the contract does not require one
+    pub eldernode_proof: Vec<u8>,
+}
+
+/// EIP-712 signing domain of the HEAT verifier contract
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip712Domain {
+    /// Contract name
+    pub name: String,
+    /// Signing domain version
+    pub version: String,
+    /// Chain the contract is deployed on
+    pub chain_id: u64,
+    /// Contract address
+    pub verifying_contract: EthAddress,
+}
+
+impl Eip712Domain {
+    /// Domain of the `HEATBurnProofVerifier` contract at `verifying_contract`
+    #[must_use]
+    pub fn new(chain_id: u64, verifying_contract: EthAddress) -> Self {
+        Self {
+            name: "HEATBurnProofVerifier".to_string(),
+            version: "1".to_string(),
+            chain_id,
+            verifying_contract,
+        }
+    }
+
+    /// `hashStruct` of the domain
+    #[must_use]
+    pub fn separator(&self) -> [u8; 32] {
+        let mut encoded = Keccak256::digest(DOMAIN_TYPE.as_bytes()).to_vec();
+        encoded.extend_from_slice(&Keccak256::digest(self.name.as_bytes()));
+        encoded.extend_from_slice(&Keccak256::digest(self.version.as_bytes()));
+        encoded.extend_from_slice(&word(self.chain_id));
+        encoded.extend_from_slice(&address_word(&self.verifying_contract));
+        Keccak256::digest(encoded).into()
+    }
+}
+
+impl ClaimCall {
+    /// Claim arguments for a proven package
+    ///
+    /// Only the 0.8 and 800 XFG tiers map onto `isLargeBurn`; the 8 and 80 XFG tiers have
+    /// no claim call and are rejected.
+    ///
+    /// # Errors
+    ///
+    /// [`ClaimCalldataError::MissingProof`] if the package has no proof,
+    /// [`ClaimCalldataError::UnsupportedBurnAmount`] for the 8 and 80 XFG tiers, and
+    /// [`ClaimCalldataError::InvalidPackage`] or [`ClaimCalldataError::InvalidProof`] if
+    /// the package data or the proof cannot be read
+    pub fn from_package(package: &CompleteProofPackage, eldernode_proof: Vec<u8>) -> Result<Self, ClaimCalldataError> {
+        let proof_file = package.stark_proof.as_ref().ok_or(ClaimCalldataError::MissingProof)?;
+        let data = &package.stark_proof_data;
+        let is_large_burn = match data.burn_transaction.burn_amount_atomic {
+            STANDARD_BURN_ATOMIC => false,
+            LARGE_BURN_ATOMIC => true,
+            other => return Err(ClaimCalldataError::UnsupportedBurnAmount(other)),
+        };
+
+        let context =
+            VerificationContext::from_package(data).map_err(|e| ClaimCalldataError::InvalidPackage(e.to_string()))?;
+        let proof = XfgBurnMintVerifier::default()
+            .decode_bounded(&proof_file.proof_data)
+            .map_err(|e| ClaimCalldataError::InvalidProof(e.to_string()))?;
+        let public_inputs = context.public_inputs(proof.get_trace_info(), proof.options().clone());
+
+        let recipient = context.recipient();
+        let mut network_id = [0u8; 32];
+        network_id[16..].copy_from_slice(&FUEGO_NETWORK_ID.to_be_bytes());
+        Ok(Self {
+            secret: data.secret_bytes(),
+            proof: proof_file.proof_data.clone(),
+            public_inputs: [
+                Nullifier::from_public_inputs(&public_inputs).0,
+                commitment_to_bytes(&public_inputs.commitment),
+                Keccak256::digest(recipient.as_bytes()).into(),
+                network_id,
+            ],
+            recipient,
+            is_large_burn,
+            eldernode_proof,
+        })
+    }
+
+    /// Calldata of the call: the [`claim_selector`] followed by the ABI-encoded arguments
+    #[must_use]
+    pub fn calldata(&self) -> Vec<u8> {
+        let proof = encode_bytes(&self.proof);
+        let mut public_inputs = word(self.public_inputs.len() as u64).to_vec();
+        public_inputs.extend(self.public_inputs.iter().flatten());
+        let eldernode_proof = encode_bytes(&self.eldernode_proof);
+
+        let proof_offset = HEAD_WORDS * WORD;
+        let public_inputs_offset = proof_offset + proof.len();
+        let eldernode_proof_offset = public_inputs_offset + public_inputs.len();
+
+        let mut calldata = claim_selector().to_vec();
+        calldata.extend_from_slice(&self.secret);
+        calldata.extend_from_slice(&word(proof_offset as u64));
+        calldata.extend_from_slice(&word(public_inputs_offset as u64));
+        calldata.extend_from_slice(&address_word(&self.recipient));
+        calldata.extend_from_slice(&word(u64::from(self.is_large_burn)));
+        calldata.extend_from_slice(&word(eldernode_proof_offset as u64));
+        calldata.extend_from_slice(&proof);
+        calldata.extend_from_slice(&public_inputs);
+        calldata.extend_from_slice(&eldernode_proof);
+        calldata
+    }
+
+    /// `hashStruct` of the claim arguments
+    #[must_use]
+    pub fn struct_hash(&self) -> [u8; 32] {
+        let mut encoded = Keccak256::digest(CLAIM_TYPE.as_bytes()).to_vec();
+        encoded.extend_from_slice(&self.secret);
+        encoded.extend_from_slice(&Keccak256::digest(&self.proof));
+        encoded.extend_from_slice(&Keccak256::digest(self.public_inputs.concat()));
+        encoded.extend_from_slice(&address_word(&self.recipient));
+        encoded.extend_from_slice(&word(u64::from(self.is_large_burn)));
+        encoded.extend_from_slice(&Keccak256::digest(&self.eldernode_proof));
+        Keccak256::digest(encoded).into()
+    }
+
+    /// EIP-712 digest of the claim in `domain`
+    #[must_use]
+    pub fn eip712_digest(&self, domain: &Eip712Domain) -> [u8; 32] {
+        let mut encoded = vec![0x19, 0x01];
+        encoded.extend_from_slice(&domain.separator());
+        encoded.extend_from_slice(&self.struct_hash());
+        Keccak256::digest(encoded).into()
+    }
+
+    /// EIP-712 typed data of the claim in `domain`, for `eth_signTypedData_v4`
+    #[must_use]
+    pub fn typed_data(&self, domain: &Eip712Domain) -> Value {
+        let hex32 = |bytes: &[u8; 32]| format!("0x{}", hex::encode(bytes));
+        json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" },
+                ],
+                "ClaimHEAT": [
+                    { "name": "secret", "type": "bytes32" },
+                    { "name": "proof", "type": "bytes" },
+                    { "name": "publicInputs", "type": "bytes32[]" },
+                    { "name": "recipient", "type": "address" },
+                    { "name": "isLargeBurn", "type": "bool" },
+                    { "name": "eldernodeProof", "type": "bytes" },
+                ],
+            },
+            "primaryType": "ClaimHEAT",
+            "domain": {
+                "name": domain.name,
+                "version": domain.version,
+                "chainId": domain.chain_id,
+                "verifyingContract": domain.verifying_contract.to_string(),
+            },
+            "message": {
+                "secret": hex32(&self.secret),
+                "proof": format!("0x{}", hex::encode(&self.proof)),
+                "publicInputs": self.public_inputs.iter().map(hex32).collect::<Vec<_>>(),
+                "recipient": self.recipient.to_string(),
+                "isLargeBurn": self.is_large_burn,
+                "eldernodeProof": format!("0x{}", hex::encode(&self.eldernode_proof)),
+            },
+        })
+    }
+}
+
+/// Function selector of [`CLAIM_SIGNATURE`]
+#[must_use]
+pub fn claim_selector() -> [u8; 4] {
+    let digest = Keccak256::digest(CLAIM_SIGNATURE.as_bytes());
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+/// ABI word holding an address, right-aligned
+fn address_word(address: &EthAddress) -> [u8; WORD] {
+    let mut word = [0u8; WORD];
+    word[WORD - address.as_bytes().len()..].copy_from_slice(address.as_bytes());
+    word
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::batch_generation::prove_package;
+    use crate::burn_mint_prover::XfgBurnMintProver;
+    use crate::constants::{HIGH_BURN_ATOMIC, MEDIUM_BURN_ATOMIC};
+    use crate::proof_data_schema::StarkProofDataPackage;
+
+    fn proven_package() -> CompleteProofPackage {
+        let data = StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "my-secret-key-123".to_string(),
+            "fuego-testnet".to_string(),
+        );
+        let proof = prove_package(&data, XfgBurnMintProver::new(128), None).unwrap();
+        let mut package = CompleteProofPackage::new(data);
+        package.add_stark_proof(proof);
+        package
+    }
+
+    #[test]
+    fn test_claim_calldata_layout() {
+        let package = proven_package();
+        let call = ClaimCall::from_package(&package, vec![0xee; 3]).unwrap();
+        assert!(!call.is_large_burn);
+        assert_eq!(call.public_inputs[1], VerificationContext::from_package(&package.stark_proof_data).unwrap().heat_commitment());
+
+        let calldata = call.calldata();
+        assert_eq!(calldata[..4], claim_selector());
+        let args = &calldata[4..];
+        assert_eq!(args.len() % WORD, 0);
+        let head = |i: usize| &args[i * WORD..(i + 1) * WORD];
+        let offset = |i: usize| u64::from_be_bytes(head(i)[WORD - 8..].try_into().unwrap()) as usize;
+
+        assert_eq!(head(0), call.secret);
+        assert_eq!(head(3)[12..], *call.recipient.as_bytes());
+        assert_eq!(head(4), word(0));
+        assert_eq!(offset(1), HEAD_WORDS * WORD);
+        assert_eq!(args[offset(1) + WORD..offset(1) + WORD + call.proof.len()], call.proof[..]);
+        assert_eq!(args[offset(2)..offset(2) + WORD], word(4));
+        assert_eq!(args[offset(2) + 4 * WORD..offset(2) + 5 * WORD], call.public_inputs[3]);
+        assert_eq!(args[offset(5)..offset(5) + WORD], word(3));
+        assert_eq!(args[offset(5) + WORD..offset(5) + WORD + 3], [0xee; 3]);
+
+        let mut unproven = package.clone();
+        unproven.stark_proof = None;
+        assert_eq!(ClaimCall::from_package(&unproven, Vec::new()), Err(ClaimCalldataError::MissingProof));
+    }
+
+    #[test]
+    fn test_middle_tiers_have_no_claim_call() {
+        let package = proven_package();
+        // 8 and 80 XFG are burn tiers, but `isLargeBurn` only tells 0.8 from 800 XFG
+        for burn_amount in [MEDIUM_BURN_ATOMIC, HIGH_BURN_ATOMIC] {
+            let mut tier = package.clone();
+            tier.stark_proof_data.burn_transaction.burn_amount_atomic = burn_amount;
+            assert_eq!(
+                ClaimCall::from_package(&tier, Vec::new()),
+                Err(ClaimCalldataError::UnsupportedBurnAmount(burn_amount))
+            );
+        }
+    }
+
+    #[test]
+    fn test_calldata_commitment_is_proof_commitment() {
+        use crate::commitment::commitment_from_bytes;
+        use crate::nullifier::Nullifier;
+        use winterfell::math::{fields::f64::BaseElement, FieldElement};
+
+        let package = proven_package();
+        let call = ClaimCall::from_package(&package, Vec::new()).unwrap();
+        let proof = XfgBurnMintVerifier::default()
+            .decode_bounded(&package.stark_proof.as_ref().unwrap().proof_data)
+            .unwrap();
+
+        // The proof verifies against the nullifier and commitment the calldata carries
+        let context = VerificationContext::from_package(&package.stark_proof_data).unwrap();
+        let mut public_inputs = context.public_inputs(proof.get_trace_info(), proof.options().clone());
+        public_inputs.nullifier = Nullifier(call.public_inputs[0]).limbs().unwrap();
+        public_inputs.commitment = commitment_from_bytes(&call.public_inputs[1]).unwrap();
+        assert!(XfgBurnMintVerifier::default().verify_with_public_inputs(&proof, &public_inputs).unwrap());
+        assert_eq!(call.public_inputs[1], context.heat_commitment());
+
+        public_inputs.commitment[0] += BaseElement::ONE;
+        assert!(!matches!(XfgBurnMintVerifier::default().verify_with_public_inputs(&proof, &public_inputs), Ok(true)));
+    }
+
+    #[test]
+    fn test_eip712_domain_separator() {
+        // The domain of the EIP-712 specification's example
+        let domain = Eip712Domain {
+            name: "Ether Mail".to_string(),
+            version: "1".to_string(),
+            chain_id: 1,
+            verifying_contract: "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".parse().unwrap(),
+        };
+        assert_eq!(
+            hex::encode(domain.separator()),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+
+        let call = ClaimCall::from_package(&proven_package(), Vec::new()).unwrap();
+        let domain = Eip712Domain::new(42161, "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".parse().unwrap());
+        let typed_data = call.typed_data(&domain);
+        assert_eq!(typed_data["message"]["publicInputs"].as_array().unwrap().len(), 4);
+        assert_eq!(typed_data["domain"]["chainId"], 42161);
+        assert_ne!(call.eip712_digest(&domain), call.eip712_digest(&Eip712Domain::new(1, domain.verifying_contract)));
+    }
+}
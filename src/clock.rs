@@ -0,0 +1,130 @@
+//! Time Sources
+//!
+//! Timestamps written into proofs, packages and reports come from a [`Clock`] rather
+//! than straight from [`SystemTime::now`], so tests and reproducible builds can pin
+//! them:
+//!
+//! - [`SystemClock`]: wall-clock time (the default everywhere)
+//! - [`FixedClock`]: always the same instant
+//! - [`OffsetClock`]: another clock shifted by a signed number of seconds
+//!
+//! [`clock_from_env`] honours `SOURCE_DATE_EPOCH`, the reproducible-builds convention
+//! for pinning timestamps.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Environment variable pinning timestamps to a Unix time (seconds)
+pub const SOURCE_DATE_EPOCH: &str = "SOURCE_DATE_EPOCH";
+
+/// Source of the current time
+pub trait Clock: Debug + Send + Sync {
+    /// Current time
+    fn now(&self) -> SystemTime;
+
+    /// Current time in whole seconds since the Unix epoch (0 before the epoch)
+    fn unix_seconds(&self) -> u64 {
+        self.now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+    }
+
+    /// Current time as an RFC 3339 string, as written into packages
+    #[cfg(feature = "packages")]
+    fn rfc3339(&self) -> String {
+        chrono::DateTime::<chrono::Utc>::from(self.now()).to_rfc3339()
+    }
+}
+
+/// Clock shared between the components that stamp the same artifact
+pub type SharedClock = Arc<dyn Clock>;
+
+/// Wall-clock time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock stopped at one instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub SystemTime);
+
+impl FixedClock {
+    /// Clock stopped `secs` seconds after the Unix epoch
+    #[must_use]
+    pub fn from_unix_seconds(secs: u64) -> Self {
+        Self(UNIX_EPOCH + Duration::from_secs(secs))
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+/// Another clock shifted by a signed number of seconds
+#[derive(Debug, Clone)]
+pub struct OffsetClock {
+    inner: SharedClock,
+    offset_secs: i64,
+}
+
+impl OffsetClock {
+    /// Shift `inner` by `offset_secs` (negative values run behind it)
+    pub fn new(inner: SharedClock, offset_secs: i64) -> Self {
+        Self { inner, offset_secs }
+    }
+}
+
+impl Clock for OffsetClock {
+    fn now(&self) -> SystemTime {
+        let now = self.inner.now();
+        let offset = Duration::from_secs(self.offset_secs.unsigned_abs());
+        let shifted = if self.offset_secs >= 0 {
+            now.checked_add(offset)
+        } else {
+            now.checked_sub(offset)
+        };
+        shifted.unwrap_or(now)
+    }
+}
+
+/// Shared wall clock
+#[must_use]
+pub fn system_clock() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+/// [`FixedClock`] at `SOURCE_DATE_EPOCH` when it is set to a Unix time, else the wall clock
+#[must_use]
+pub fn clock_from_env() -> SharedClock {
+    match std::env::var(SOURCE_DATE_EPOCH).ok().and_then(|secs| secs.trim().parse().ok()) {
+        Some(secs) => Arc::new(FixedClock::from_unix_seconds(secs)),
+        None => system_clock(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_and_offset_clocks() {
+        let fixed = FixedClock::from_unix_seconds(1_705_312_200);
+        assert_eq!(fixed.unix_seconds(), 1_705_312_200);
+        assert_eq!(fixed.now(), fixed.now());
+
+        let ahead = OffsetClock::new(Arc::new(fixed), 3_600);
+        let behind = OffsetClock::new(Arc::new(fixed), -1_705_312_201);
+        assert_eq!(ahead.unix_seconds(), 1_705_315_800);
+        assert_eq!(behind.unix_seconds(), 0);
+        assert!(SystemClock.unix_seconds() > 1_705_312_200);
+
+        #[cfg(feature = "packages")]
+        assert_eq!(fixed.rfc3339(), "2024-01-15T09:50:00+00:00");
+    }
+}
@@ -0,0 +1,9 @@
+//! Code Generation
+//!
+//! Source code for other environments, generated from this crate's parameters so the
+//! two cannot drift apart.
+//!
+//! - **Solidity**: the on-chain burn & mint verifier library, see
+//!   [`solidity::SolidityVerifierGenerator`]
+
+pub mod solidity;
@@ -0,0 +1,609 @@
+//! Solidity Verifier Code Generation
+//!
+//! [`SolidityVerifierGenerator`] emits a Solidity library that checks a burn & mint
+//! proof submitted as the arguments of [`crate::proof::evm::VERIFY_SIGNATURE`]:
+//!
+//! - **Constants**: the field modulus, the public input layout and the proof
+//!   parameters, taken from an [`XfgBurnMintVerifier`]
+//! - **Field arithmetic**: addition, subtraction, multiplication, exponentiation and
+//!   inversion modulo the 64-bit field prime
+//! - **Parameter checks**: the metadata words must name the layout, hash function, trace
+//!   width and FRI parameters the library was generated for, so a proof made with weaker
+//!   options reverts before it is verified
+//! - **Claim checks**: canonical public inputs in the layout's shape, with non-zero
+//!   amounts, the network's fee and mint outputs that sum to the mint, as
+//!   [`XfgBurnMintVerifier`] validates them
+//!
+//! The STARK itself (trace and constraint commitments, DEEP composition and FRI) is
+//! verified by an `IXfgStarkCore` contract the library calls once the checks pass.
+//!
+//! Reverts use the HEAT verifier's custom errors, so [`crate::revert_reason::RevertDecoder`]
+//! decodes them. [`SolidityVerifierGenerator::check_claim`] is the Rust reference of the
+//! generated checks; the golden tests below compare it with this crate's verifier on
+//! the known-answer proofs, and `tests/fixtures/solidity/` holds the generated library
+//! and the expected outcomes for the contract's own tests, rewritten by
+//! `crate::fixtures::regenerate`.
+
+use crate::burn_mint_air::{MintOutput, BurnMintPublicInputs, TRACE_WIDTH};
+use crate::burn_mint_verifier::XfgBurnMintVerifier;
+use crate::commitment::RECIPIENT_BINDING_LIMBS;
+use crate::constants::{is_valid_trace_length, MAX_MINT_OUTPUTS};
+use crate::fees::{protocol_fee, BPS_DENOMINATOR};
+use crate::proof::evm::EVM_METADATA_WORDS;
+use crate::public_input_layout::{public_input_fields, PUBLIC_INPUT_LAYOUT_VERSION};
+use crate::revert_reason::HeatContractError;
+use crate::statements::HashFunction;
+use std::fmt::Write;
+use std::path::Path;
+use winter_math::StarkField;
+use winterfell::math::fields::f64::BaseElement;
+
+/// Library name used unless [`SolidityVerifierGenerator::with_library_name`] sets one
+pub const DEFAULT_LIBRARY_NAME: &str = "XfgBurnMintVerifier";
+
+/// Compiler version range used unless [`SolidityVerifierGenerator::with_pragma`] sets one
+pub const DEFAULT_PRAGMA: &str = "^0.8.20";
+
+/// Generator of the Solidity burn & mint verifier library
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolidityVerifierGenerator {
+    /// Name of the generated library
+    library_name: String,
+    /// Solidity compiler version range
+    pragma: String,
+    /// Hash function proofs must commit with
+    hash_function: HashFunction,
+    /// Number of FRI queries
+    num_queries: u64,
+    /// Low-degree extension blowup factor
+    blowup_factor: u64,
+    /// Proof-of-work bits
+    grinding_factor: u64,
+    /// Field extension degree
+    field_extension: u64,
+    /// FRI folding factor
+    fri_folding_factor: u64,
+    /// FRI remainder max degree
+    fri_remainder_max_degree: u64,
+    /// Protocol fee proofs must charge (basis points)
+    fee_bps: u32,
+}
+
+impl SolidityVerifierGenerator {
+    /// Generator for the parameters `verifier` accepts
+    #[must_use]
+    pub fn from_verifier(verifier: &XfgBurnMintVerifier) -> Self {
+        let options = verifier.proof_options();
+        let fri_options = options.to_fri_options();
+        Self {
+            library_name: DEFAULT_LIBRARY_NAME.to_string(),
+            pragma: DEFAULT_PRAGMA.to_string(),
+            hash_function: verifier.hash_function(),
+            num_queries: options.num_queries() as u64,
+            blowup_factor: options.blowup_factor() as u64,
+            grinding_factor: u64::from(options.grinding_factor()),
+            field_extension: u64::from(options.field_extension().degree()),
+            fri_folding_factor: fri_options.folding_factor() as u64,
+            fri_remainder_max_degree: fri_options.remainder_max_degree() as u64,
+            fee_bps: verifier.fee_model().fee_bps(),
+        }
+    }
+
+    /// Name the generated library
+    #[must_use]
+    pub fn with_library_name(mut self, library_name: impl Into<String>) -> Self {
+        self.library_name = library_name.into();
+        self
+    }
+
+    /// Set the Solidity compiler version range
+    #[must_use]
+    pub fn with_pragma(mut self, pragma: impl Into<String>) -> Self {
+        self.pragma = pragma.into();
+        self
+    }
+
+    /// Name of the generated library
+    #[must_use]
+    pub fn library_name(&self) -> &str {
+        &self.library_name
+    }
+
+    /// Metadata words a proof must carry, in [`crate::proof::evm`] table order
+    ///
+    /// The trace length (word 3) varies with the burn & mint configuration and is
+    /// reported as the shortest one accepted.
+    #[must_use]
+    pub fn metadata(&self) -> [u64; EVM_METADATA_WORDS] {
+        [
+            u64::from(PUBLIC_INPUT_LAYOUT_VERSION),
+            hash_function_index(self.hash_function),
+            TRACE_WIDTH as u64,
+            min_trace_length(),
+            self.num_queries,
+            self.blowup_factor,
+            self.grinding_factor,
+            self.field_extension,
+            self.fri_folding_factor,
+            self.fri_remainder_max_degree,
+        ]
+    }
+
+    /// Check metadata and public inputs as the generated library does
+    ///
+    /// Returns the custom error the library reverts with. `Ok` means the checks pass
+    /// and the STARK core decides.
+    ///
+    /// # Errors
+    ///
+    /// [`HeatContractError::InvalidProof`] if the metadata does not name this library's
+    /// parameters, or the error the claim checks revert with
+    pub fn check_claim(&self, metadata: &[u64], public_inputs: &[u64]) -> Result<(), HeatContractError> {
+        self.check_metadata(metadata)?;
+        self.check_public_inputs(public_inputs)
+    }
+
+    /// Parameter checks of the generated `checkMetadata`
+    fn check_metadata(&self, metadata: &[u64]) -> Result<(), HeatContractError> {
+        let expected = self.metadata();
+        if metadata.len() != EVM_METADATA_WORDS {
+            return Err(HeatContractError::InvalidProof);
+        }
+        let trace_length = metadata[3];
+        let pinned = metadata.iter().zip(&expected).enumerate().all(|(i, (actual, expected))| i == 3 || actual == expected);
+        if !pinned || trace_length < min_trace_length() || !trace_length.is_power_of_two() {
+            return Err(HeatContractError::InvalidProof);
+        }
+        Ok(())
+    }
+
+    /// Claim checks of the generated `checkPublicInputs` and `checkMintOutputs`
+    fn check_public_inputs(&self, public_inputs: &[u64]) -> Result<(), HeatContractError> {
+        let outputs = public_inputs
+            .len()
+            .checked_sub(BurnMintPublicInputs::NUM_ELEMENTS)
+            .filter(|tail| tail % MintOutput::NUM_ELEMENTS == 0 && tail / MintOutput::NUM_ELEMENTS <= MAX_MINT_OUTPUTS)
+            .ok_or(HeatContractError::InvalidPublicInputs)?
+            / MintOutput::NUM_ELEMENTS;
+        if public_inputs.iter().any(|value| *value >= BaseElement::MODULUS) {
+            return Err(HeatContractError::InvalidPublicInputs);
+        }
+
+        let burn_amount = public_inputs[field_index("burn_amount")];
+        let mint_amount = public_inputs[field_index("mint_amount")];
+        if burn_amount == 0 || mint_amount == 0 {
+            return Err(HeatContractError::InvalidAmount);
+        }
+        if public_inputs[field_index("txn_hash")] == 0 {
+            return Err(HeatContractError::InvalidPublicInputs);
+        }
+        if public_inputs[field_index("recipient_hash")] != public_inputs[field_index("recipient_binding[0]")] {
+            return Err(HeatContractError::InvalidRecipient);
+        }
+        if public_inputs[field_index("fee_bps")] != u64::from(self.fee_bps)
+            || mint_amount != burn_amount - protocol_fee(burn_amount, self.fee_bps)
+        {
+            return Err(HeatContractError::InvalidAmount);
+        }
+        if outputs == 0 {
+            return Ok(());
+        }
+
+        let outputs = &public_inputs[BurnMintPublicInputs::NUM_ELEMENTS..];
+        let amounts = outputs.iter().step_by(MintOutput::NUM_ELEMENTS);
+        if amounts.clone().any(|amount| *amount == 0) || amounts.map(|amount| u128::from(*amount)).sum::<u128>() != u128::from(mint_amount) {
+            return Err(HeatContractError::InvalidAmount);
+        }
+        let binding = field_index("recipient_binding[0]");
+        if outputs[1..MintOutput::NUM_ELEMENTS] != public_inputs[binding..binding + RECIPIENT_BINDING_LIMBS] {
+            return Err(HeatContractError::InvalidRecipient);
+        }
+        Ok(())
+    }
+
+    /// Generate the Solidity library source
+    #[must_use]
+    pub fn generate(&self) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write_constants(&mut out).and_then(|()| Self::write_checks(&mut out));
+        out
+    }
+
+    /// Write the library header and its metadata and public input constants
+    fn write_constants(&self, out: &mut String) -> std::fmt::Result {
+        let metadata = self.metadata();
+        let fields = public_input_fields(PUBLIC_INPUT_LAYOUT_VERSION).expect("current layout version is defined");
+
+        write!(
+            out,
+            r"// SPDX-License-Identifier: MIT
+// Generated by xfg-stark (codegen::solidity). Do not edit; regenerate instead.
+pragma solidity {pragma};
+
+/// @notice STARK core verifier the burn & mint library hands a checked proof to
+interface IXfgStarkCore {{
+    function verifyStark(uint256[] calldata metadata, uint256[] calldata publicInputs, bytes calldata proof)
+        external
+        view
+        returns (bool);
+}}
+
+/// @title XFG burn & mint proof verifier
+/// @notice Checks the parameters and claim of a burn & mint proof as xfg-stark does,
+/// then has the STARK core verify the proof itself
+library {library} {{
+    error InvalidProof();
+    error InvalidPublicInputs();
+    error InvalidAmount();
+    error InvalidRecipient();
+
+    /// @dev Field modulus, 2^64 - 2^32 + 1
+    uint256 internal constant MODULUS = {modulus:#X};
+
+    /* ---------------------------------- Metadata ---------------------------------- */
+
+    uint256 internal constant METADATA_WORDS = {metadata_words};
+    uint256 internal constant PUBLIC_INPUT_LAYOUT_VERSION = {layout_version};
+    /// @dev {hash_name}
+    uint256 internal constant HASH_FUNCTION = {hash_function};
+    uint256 internal constant TRACE_WIDTH = {trace_width};
+    uint256 internal constant MIN_TRACE_LENGTH = {min_trace_length};
+    uint256 internal constant NUM_QUERIES = {num_queries};
+    uint256 internal constant BLOWUP_FACTOR = {blowup_factor};
+    uint256 internal constant GRINDING_FACTOR = {grinding_factor};
+    uint256 internal constant FIELD_EXTENSION = {field_extension};
+    uint256 internal constant FRI_FOLDING_FACTOR = {fri_folding_factor};
+    uint256 internal constant FRI_REMAINDER_MAX_DEGREE = {fri_remainder_max_degree};
+
+    /* ------------------------------ Public inputs ------------------------------ */
+
+    uint256 internal constant NUM_PUBLIC_INPUTS = {num_public_inputs};
+    uint256 internal constant MINT_OUTPUT_ELEMENTS = {mint_output_elements};
+    uint256 internal constant MAX_MINT_OUTPUTS = {max_mint_outputs};
+    uint256 internal constant RECIPIENT_BINDING_LIMBS = {binding_limbs};
+    uint256 internal constant FEE_BPS = {fee_bps};
+    uint256 internal constant BPS_DENOMINATOR = {bps_denominator};
+
+",
+            pragma = self.pragma,
+            library = self.library_name,
+            modulus = BaseElement::MODULUS,
+            metadata_words = EVM_METADATA_WORDS,
+            layout_version = metadata[0],
+            hash_name = self.hash_function.name(),
+            hash_function = metadata[1],
+            trace_width = metadata[2],
+            min_trace_length = metadata[3],
+            num_queries = metadata[4],
+            blowup_factor = metadata[5],
+            grinding_factor = metadata[6],
+            field_extension = metadata[7],
+            fri_folding_factor = metadata[8],
+            fri_remainder_max_degree = metadata[9],
+            num_public_inputs = BurnMintPublicInputs::NUM_ELEMENTS,
+            mint_output_elements = MintOutput::NUM_ELEMENTS,
+            max_mint_outputs = MAX_MINT_OUTPUTS,
+            binding_limbs = RECIPIENT_BINDING_LIMBS,
+            fee_bps = self.fee_bps,
+            bps_denominator = BPS_DENOMINATOR,
+        )?;
+        for (index, field) in fields.iter().enumerate() {
+            writeln!(out, "    uint256 internal constant {} = {index};", index_constant(field))?;
+        }
+        Ok(())
+    }
+
+    /// Write the library's field arithmetic and checks
+    fn write_checks(out: &mut String) -> std::fmt::Result {
+        write!(
+            out,
+            r"
+    /* ------------------------------ Field arithmetic ------------------------------ */
+
+    function add(uint256 a, uint256 b) internal pure returns (uint256) {{
+        return addmod(a, b, MODULUS);
+    }}
+
+    /// @dev Operands are canonical, below MODULUS
+    function sub(uint256 a, uint256 b) internal pure returns (uint256) {{
+        return addmod(a, MODULUS - b, MODULUS);
+    }}
+
+    function mul(uint256 a, uint256 b) internal pure returns (uint256) {{
+        return mulmod(a, b, MODULUS);
+    }}
+
+    function pow(uint256 base, uint256 exponent) internal pure returns (uint256 result) {{
+        result = 1;
+        base %= MODULUS;
+        while (exponent != 0) {{
+            if (exponent & 1 == 1) {{
+                result = mulmod(result, base, MODULUS);
+            }}
+            base = mulmod(base, base, MODULUS);
+            exponent >>= 1;
+        }}
+    }}
+
+    /// @dev Zero has no inverse and maps to zero
+    function inv(uint256 a) internal pure returns (uint256) {{
+        return pow(a, MODULUS - 2);
+    }}
+
+    /* ---------------------------------- Checks ---------------------------------- */
+
+    /// @notice Revert unless the metadata names the parameters this library was generated for
+    function checkMetadata(uint256[] calldata metadata) internal pure {{
+        if (metadata.length != METADATA_WORDS) revert InvalidProof();
+        uint256 traceLength = metadata[3];
+        if (
+            metadata[0] != PUBLIC_INPUT_LAYOUT_VERSION || metadata[1] != HASH_FUNCTION || metadata[2] != TRACE_WIDTH
+                || traceLength < MIN_TRACE_LENGTH || traceLength & (traceLength - 1) != 0 || metadata[4] != NUM_QUERIES
+                || metadata[5] != BLOWUP_FACTOR || metadata[6] != GRINDING_FACTOR || metadata[7] != FIELD_EXTENSION
+                || metadata[8] != FRI_FOLDING_FACTOR || metadata[9] != FRI_REMAINDER_MAX_DEGREE
+        ) revert InvalidProof();
+    }}
+
+    /// @notice Revert unless the public inputs are a canonical burn & mint claim
+    function checkPublicInputs(uint256[] calldata publicInputs) internal pure {{
+        uint256 length = publicInputs.length;
+        if (
+            length < NUM_PUBLIC_INPUTS || (length - NUM_PUBLIC_INPUTS) % MINT_OUTPUT_ELEMENTS != 0
+                || (length - NUM_PUBLIC_INPUTS) / MINT_OUTPUT_ELEMENTS > MAX_MINT_OUTPUTS
+        ) revert InvalidPublicInputs();
+        for (uint256 i = 0; i < length; ++i) {{
+            if (publicInputs[i] >= MODULUS) revert InvalidPublicInputs();
+        }}
+
+        uint256 burnAmount = publicInputs[{burn}];
+        uint256 mintAmount = publicInputs[{mint}];
+        if (burnAmount == 0 || mintAmount == 0) revert InvalidAmount();
+        if (publicInputs[{txn_hash}] == 0) revert InvalidPublicInputs();
+        if (publicInputs[{recipient}] != publicInputs[{binding}]) revert InvalidRecipient();
+        if (
+            publicInputs[{fee}] != FEE_BPS
+                || mintAmount != burnAmount - burnAmount * FEE_BPS / BPS_DENOMINATOR
+        ) revert InvalidAmount();
+        checkMintOutputs(publicInputs, mintAmount);
+    }}
+
+    /// @dev Outputs, when present, are non-zero, sum to the mint and start with the bound recipient
+    function checkMintOutputs(uint256[] calldata publicInputs, uint256 mintAmount) private pure {{
+        uint256 count = (publicInputs.length - NUM_PUBLIC_INPUTS) / MINT_OUTPUT_ELEMENTS;
+        if (count == 0) return;
+        uint256 total = 0;
+        for (uint256 i = 0; i < count; ++i) {{
+            uint256 amount = publicInputs[NUM_PUBLIC_INPUTS + i * MINT_OUTPUT_ELEMENTS];
+            if (amount == 0) revert InvalidAmount();
+            total += amount;
+        }}
+        if (total != mintAmount) revert InvalidAmount();
+        for (uint256 limb = 0; limb < RECIPIENT_BINDING_LIMBS; ++limb) {{
+            if (publicInputs[NUM_PUBLIC_INPUTS + 1 + limb] != publicInputs[{binding} + limb]) {{
+                revert InvalidRecipient();
+            }}
+        }}
+    }}
+
+    /// @notice Check a proof's parameters and claim, then verify it with the STARK core
+    /// @dev Arguments are those of verifyProof(uint256[],uint256[],bytes); reverts on failure
+    function verifyProof(
+        IXfgStarkCore core,
+        uint256[] calldata metadata,
+        uint256[] calldata publicInputs,
+        bytes calldata proof
+    ) internal view {{
+        checkMetadata(metadata);
+        checkPublicInputs(publicInputs);
+        if (!core.verifyStark(metadata, publicInputs, proof)) revert InvalidProof();
+    }}
+}}
+",
+            burn = index_constant("burn_amount"),
+            mint = index_constant("mint_amount"),
+            txn_hash = index_constant("txn_hash"),
+            recipient = index_constant("recipient_hash"),
+            fee = index_constant("fee_bps"),
+            binding = index_constant("recipient_binding[0]"),
+        )
+    }
+
+    /// Write the generated library to `path`
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be written
+    pub fn write_to(&self, path: impl AsRef<Path>) -> crate::Result<()> {
+        std::fs::write(path, self.generate())?;
+        Ok(())
+    }
+}
+
+impl Default for SolidityVerifierGenerator {
+    fn default() -> Self {
+        Self::from_verifier(&XfgBurnMintVerifier::default())
+    }
+}
+
+/// Metadata index of a hash function, as [`crate::proof::evm`] encodes it
+fn hash_function_index(hash_function: HashFunction) -> u64 {
+    HashFunction::ALL.iter().position(|h| *h == hash_function).unwrap_or_default() as u64
+}
+
+/// Shortest burn & mint trace accepted
+fn min_trace_length() -> u64 {
+    (0..usize::BITS)
+        .map(|log| 1usize << log)
+        .find(|length| is_valid_trace_length(*length))
+        .expect("some power of two is a valid trace length") as u64
+}
+
+/// Position of a public input in the current layout
+fn field_index(field: &str) -> usize {
+    public_input_fields(PUBLIC_INPUT_LAYOUT_VERSION)
+        .and_then(|fields| fields.iter().position(|name| *name == field))
+        .expect("field is part of the current layout")
+}
+
+/// Solidity constant holding a public input's position: `recipient_binding[0]` becomes
+/// `RECIPIENT_BINDING_0_INDEX`
+fn index_constant(field: &str) -> String {
+    format!("{}_INDEX", field.replace('[', "_").replace(']', "").to_uppercase())
+}
+
+/// Generation of the Solidity fixtures
+#[cfg(any(test, feature = "testing"))]
+pub(crate) mod fixtures {
+    use super::{field_index, SolidityVerifierGenerator};
+    use crate::burn_mint_air::BurnMintPublicInputs;
+    use crate::burn_mint_verifier::XfgBurnMintVerifier;
+    use crate::proof::evm::metadata_words;
+    use crate::statements::HashFunction;
+    use crate::test_data_generator::KatFile;
+    use crate::XfgStarkError;
+    use serde::{Deserialize, Serialize};
+    use std::path::Path;
+    use winter_math::StarkField;
+    use winterfell::math::fields::f64::BaseElement;
+    use winterfell::StarkProof;
+
+    /// Expected outcome of one submission, for the contract's tests
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct GoldenCase {
+        /// Known-answer vector whose proof is submitted
+        pub(crate) seed: u64,
+        /// Mutation applied to the honest submission
+        pub(crate) case: String,
+        /// Submitted metadata words
+        pub(crate) metadata: Vec<u64>,
+        /// Submitted public inputs
+        pub(crate) public_inputs: Vec<u64>,
+        /// Custom error the library reverts with, or `None` when the STARK core decides
+        pub(crate) library_revert: Option<String>,
+        /// Whether this crate's verifier accepts the submission
+        pub(crate) rust_accepts: bool,
+    }
+
+    /// Known-answer proofs with their honest metadata and public inputs
+    fn kat_submissions(root: &Path) -> crate::Result<Vec<(u64, StarkProof, Vec<u64>, Vec<u64>)>> {
+        let file: KatFile = serde_json::from_str(&std::fs::read_to_string(root.join("kat/burn_mint.json"))?)?;
+        file.vectors
+            .into_iter()
+            .map(|vector| {
+                let proof = hex::decode(&vector.proof)
+                    .ok()
+                    .and_then(|bytes| StarkProof::from_bytes(&bytes).ok())
+                    .ok_or_else(|| XfgStarkError::ParseError(format!("Known-answer proof {} does not decode", vector.seed)))?;
+                let metadata = metadata_words(&proof, HashFunction::default()).to_vec();
+                Ok((vector.seed, proof, metadata, vector.public_inputs))
+            })
+            .collect()
+    }
+
+    /// Whether this crate accepts a submission, decoding it as [`crate::proof::evm`] does
+    fn rust_accepts(proof: &StarkProof, metadata: &[u64], public_inputs: &[u64]) -> bool {
+        if public_inputs.iter().any(|value| *value >= BaseElement::MODULUS)
+            || metadata != metadata_words(proof, HashFunction::default())
+        {
+            return false;
+        }
+        let elements: Vec<BaseElement> = public_inputs.iter().map(|value| BaseElement::new(*value)).collect();
+        BurnMintPublicInputs::from_elements(&elements).is_some_and(|claim| {
+            matches!(XfgBurnMintVerifier::default().verify_with_public_inputs(proof, &claim), Ok(true))
+        })
+    }
+
+    /// The honest submission of every known-answer proof under `root` and a set of mutations
+    pub(crate) fn golden_cases(root: &Path) -> crate::Result<Vec<GoldenCase>> {
+        let generator = SolidityVerifierGenerator::default();
+        let mut cases = Vec::new();
+        for (seed, proof, metadata, public_inputs) in kat_submissions(root)? {
+            let mutate_inputs = |field: &str, value: u64| {
+                let mut inputs = public_inputs.clone();
+                inputs[field_index(field)] = value;
+                inputs
+            };
+            let mut fewer_queries = metadata.clone();
+            fewer_queries[4] -= 1;
+            let burn = public_inputs[field_index("burn_amount")];
+            let recipient_hash = public_inputs[field_index("recipient_hash")];
+
+            for (case, metadata, inputs) in [
+                ("honest", metadata.clone(), public_inputs.clone()),
+                ("fewer_queries", fewer_queries, public_inputs.clone()),
+                ("inflated_burn", metadata.clone(), mutate_inputs("burn_amount", 2 * burn)),
+                ("zero_txn_hash", metadata.clone(), mutate_inputs("txn_hash", 0)),
+                ("non_canonical_input", metadata.clone(), mutate_inputs("recipient_hash", BaseElement::MODULUS + recipient_hash)),
+                ("truncated_inputs", metadata.clone(), public_inputs[..public_inputs.len() - 1].to_vec()),
+                ("other_recipient", metadata.clone(), mutate_inputs("recipient_hash", recipient_hash + 1)),
+            ] {
+                cases.push(GoldenCase {
+                    seed,
+                    case: case.to_string(),
+                    library_revert: generator.check_claim(&metadata, &inputs).err().map(|e| e.name().to_string()),
+                    rust_accepts: rust_accepts(&proof, &metadata, &inputs),
+                    metadata,
+                    public_inputs: inputs,
+                });
+            }
+        }
+        Ok(cases)
+    }
+
+    /// Write the library and the expected outcomes under `solidity/` in `root`
+    ///
+    /// The outcomes are computed from the known-answer vectors in `root`, so those are
+    /// written first.
+    pub(crate) fn write(root: &Path) -> crate::Result<()> {
+        let dir = root.join("solidity");
+        std::fs::create_dir_all(&dir)?;
+        SolidityVerifierGenerator::default().write_to(dir.join("XfgBurnMintVerifier.sol"))?;
+        std::fs::write(dir.join("outcomes.json"), serde_json::to_string_pretty(&golden_cases(root)?)? + "\n")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::{golden_cases, GoldenCase};
+    use super::*;
+    use crate::test_data_generator::KAT_SEEDS;
+
+    fn fixture(name: &str) -> std::path::PathBuf {
+        crate::fixtures::fixtures_dir().join(name)
+    }
+
+    #[test]
+    fn test_generated_library_matches_golden() {
+        let generator = SolidityVerifierGenerator::default();
+        let golden = std::fs::read_to_string(fixture("solidity/XfgBurnMintVerifier.sol")).unwrap();
+        assert_eq!(generator.generate(), golden, "regenerate the Solidity fixtures");
+
+        let renamed = generator.clone().with_library_name("HeatVerifier").with_pragma("0.8.24").generate();
+        assert!(renamed.contains("library HeatVerifier {") && renamed.contains("pragma solidity 0.8.24;"));
+        for field in public_input_fields(PUBLIC_INPUT_LAYOUT_VERSION).unwrap() {
+            assert!(golden.contains(&format!("{} = {};", index_constant(field), field_index(field))));
+        }
+    }
+
+    #[test]
+    fn test_golden_outcomes_match_rust_verifier() {
+        let golden: Vec<GoldenCase> =
+            serde_json::from_str(&std::fs::read_to_string(fixture("solidity/outcomes.json")).unwrap()).unwrap();
+        let cases = golden_cases(&crate::fixtures::fixtures_dir()).unwrap();
+        assert_eq!(cases, golden, "regenerate the Solidity fixtures");
+        assert_eq!(cases.iter().filter(|case| case.case == "honest").count(), KAT_SEEDS.len());
+
+        for case in &cases {
+            // The library never reverts on a proof this crate accepts, and never lets a
+            // claim this crate rejects reach the core
+            assert_eq!(case.rust_accepts, case.case == "honest", "{} for seed {}", case.case, case.seed);
+            if case.rust_accepts {
+                assert_eq!(case.library_revert, None, "{} for seed {}", case.case, case.seed);
+            } else {
+                assert!(case.library_revert.is_some(), "{} for seed {}", case.case, case.seed);
+            }
+        }
+    }
+}
@@ -0,0 +1,435 @@
+//! Recipient Binding and HEAT Commitments
+//!
+//! The recipient binding ties a burn to the exact 20-byte Ethereum address and target
+//! chain that will receive the minted HEAT. Wallet-side tooling, the prover, the AIR and
+//! the verifier all derive it with [`recipient_binding`], so every party computes the same
+//! value from the real address bytes rather than from a truncated public input.
+//!
+//! Inside the proof the 32-byte binding is carried as eight little-endian `u32` limbs
+//! (see [`recipient_binding_limbs`]), which the AIR reassembles with
+//! [`recipient_binding_from_limbs`] before hashing it into the commitment.
+//!
+//! The HEAT commitment is a Rescue-Prime commitment over the secret and the public claim.
+//! [`CommitmentPreimage`] is its one definition: the wallet writes it into the burn
+//! transaction's `tx_extra`, the burn & mint AIR recomputes it in its trace as the
+//! proof's `commitment` public input, and the verifier, the package schema, the claim
+//! calldata and the CLI all hash it through that type. Provers, wallets and Eldernodes
+//! check a secret against it with [`verify_commitment_opening`], without any STARK
+//! machinery or gas, before spending minutes on a proof that cannot mint.
+
+use crate::{
+    burn_mint_air::{BurnMintPublicInputs, SECRET_LIMBS},
+    burn_mint_prover::{secret_to_field_elements, SECRET_LEN},
+    types::secret::Redacted,
+};
+use sha3::{Digest, Keccak256};
+use winter_math::{FieldElement, StarkField};
+use winterfell::crypto::{hashers::Rp64_256, ElementHasher, Hasher};
+use winterfell::math::fields::f64::BaseElement;
+
+/// Domain separator for the recipient binding hash
+pub const RECIPIENT_BINDING_DOMAIN: &[u8] = b"fuego-to-heat-bridge/recipient-binding-v1";
+
+/// Number of 32-bit limbs used to carry the binding in public inputs
+pub const RECIPIENT_BINDING_LIMBS: usize = 8;
+
+/// Length of an Ethereum recipient address in bytes
+pub const RECIPIENT_ADDRESS_LEN: usize = 20;
+
+/// Tag opening the commitment claim
+pub const PROOF_COMMITMENT_TAG: &[u8] = b"heat-commitment-v2";
+
+/// Number of claim elements for a single-recipient mint
+pub const PROOF_COMMITMENT_CLAIM_LEN: usize = 2 + RECIPIENT_BINDING_LIMBS + 3;
+
+/// Number of elements of the claim digest the commitment hashes with the secret
+pub const CLAIM_DIGEST_LEN: usize = 4;
+
+/// Number of field elements in a commitment: the whole Rescue-Prime digest
+pub const COMMITMENT_LIMBS: usize = 4;
+
+/// Compute the recipient binding for an address on a target chain
+///
+/// `Keccak256(RECIPIENT_BINDING_DOMAIN || address || le32(chain_id))`
+#[must_use]
+pub fn recipient_binding(address: &[u8; RECIPIENT_ADDRESS_LEN], chain_id: u32) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(RECIPIENT_BINDING_DOMAIN);
+    hasher.update(address);
+    hasher.update(chain_id.to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// Split a binding into little-endian `u32` limbs for public inputs
+#[must_use]
+pub fn recipient_binding_limbs(binding: &[u8; 32]) -> [BaseElement; RECIPIENT_BINDING_LIMBS] {
+    let mut limbs = [BaseElement::from(0u32); RECIPIENT_BINDING_LIMBS];
+    for (limb, chunk) in limbs.iter_mut().zip(binding.chunks_exact(4)) {
+        *limb = BaseElement::from(u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]));
+    }
+    limbs
+}
+
+/// Reassemble a binding from its public-input limbs
+#[must_use]
+pub fn recipient_binding_from_limbs(limbs: &[BaseElement; RECIPIENT_BINDING_LIMBS]) -> [u8; 32] {
+    let mut binding = [0u8; 32];
+    for (chunk, limb) in binding.chunks_exact_mut(4).zip(limbs.iter()) {
+        chunk.copy_from_slice(&limb.as_int().to_le_bytes()[..4]);
+    }
+    binding
+}
+
+/// One recipient of a multi-output mint, as committed to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommitmentOutput {
+    /// Amount minted to the recipient (atomic units)
+    pub amount: BaseElement,
+    /// Recipient binding (see [`recipient_binding`])
+    pub recipient_binding: [u8; 32],
+}
+
+/// Preimage of the HEAT commitment
+///
+/// The commitment is the whole digest `Rp64_256::hash_elements([k₀, k₁, k₂, k₃, d₀, d₁,
+/// d₂, d₃])`, where `k` is the secret as four little-endian limbs and `d` the
+/// [`CommitmentPreimage::claim_digest`] of the public claim. The claim is hashed first
+/// so that the secret and digest fit one permutation, which the burn & mint AIR runs in
+/// its trace. [`CommitmentPreimage::claim_elements`] lays the claim out as:
+///
+/// | Index | Elements | Field                                                |
+/// |-------|----------|------------------------------------------------------|
+/// | 0     | 1        | first element of `Rp64_256::hash(PROOF_COMMITMENT_TAG)` |
+/// | 1     | 1        | `burn_amount`                                        |
+/// | 2     | 8        | recipient binding limbs                              |
+/// | 10    | 1        | `network_id`                                         |
+/// | 11    | 1        | `target_chain_id`                                    |
+/// | 12    | 1        | `commitment_version`                                 |
+///
+/// A multi-output mint appends nine elements per output: the output amount, then its
+/// recipient binding limbs.
+///
+/// The claim holds only what the wallet knows before it builds the burn transaction, so
+/// the wallet writes [`CommitmentPreimage::to_bytes`] into `tx_extra` and the proof's
+/// `commitment` public input is that same value. The transaction prefix hash covers
+/// `tx_extra` and so cannot be part of it; the nullifier binds the proof to the burn
+/// transaction instead.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CommitmentPreimage {
+    /// Secret as four little-endian field element limbs
+    pub secret: [BaseElement; SECRET_LIMBS],
+    /// Burn amount (atomic units)
+    pub burn_amount: BaseElement,
+    /// Recipient binding of the primary recipient
+    pub recipient_binding: [u8; 32],
+    /// Fuego network ID
+    pub network_id: BaseElement,
+    /// Chain the HEAT is minted on
+    pub target_chain_id: BaseElement,
+    /// Commitment format version
+    pub commitment_version: BaseElement,
+    /// Further recipients of a multi-output mint
+    pub outputs: Vec<CommitmentOutput>,
+}
+
+impl CommitmentPreimage {
+    /// Preimage for a secret and the claim in `public_inputs`
+    ///
+    /// The `commitment` and `nullifier` fields of `public_inputs` are not part of it.
+    #[must_use]
+    pub fn new(secret: [BaseElement; SECRET_LIMBS], public_inputs: &BurnMintPublicInputs) -> Self {
+        Self {
+            secret,
+            burn_amount: public_inputs.burn_amount,
+            recipient_binding: recipient_binding_from_limbs(&public_inputs.recipient_binding),
+            network_id: public_inputs.network_id,
+            target_chain_id: public_inputs.target_chain_id,
+            commitment_version: public_inputs.commitment_version,
+            outputs: public_inputs
+                .outputs
+                .iter()
+                .map(|output| CommitmentOutput {
+                    amount: output.amount,
+                    recipient_binding: recipient_binding_from_limbs(&output.recipient_binding),
+                })
+                .collect(),
+        }
+    }
+
+    /// Preimage of a single-recipient burn from the data the wallet holds before it builds
+    /// the burn transaction
+    ///
+    /// Encodes the claim exactly as [`BurnMintPublicInputs::from_user_data`] does, so the
+    /// commitment written into `tx_extra` is the one the proof later carries.
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError::CryptoError`] if the secret is not [`SECRET_LEN`] bytes
+    pub fn from_user_data(
+        secret: &[u8],
+        burn_amount: u64,
+        recipient: &[u8; RECIPIENT_ADDRESS_LEN],
+        network_id: u32,
+        target_chain_id: u32,
+        commitment_version: u32,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            secret: secret_to_field_elements(secret)?,
+            burn_amount: BaseElement::new(burn_amount),
+            recipient_binding: recipient_binding(recipient, target_chain_id),
+            network_id: BaseElement::from(network_id),
+            target_chain_id: BaseElement::from(target_chain_id),
+            commitment_version: BaseElement::from(commitment_version),
+            outputs: Vec::new(),
+        })
+    }
+
+    /// Public claim in the canonical element layout (the secret is not part of it)
+    #[must_use]
+    pub fn claim_elements(&self) -> Vec<BaseElement> {
+        let mut claim = Vec::with_capacity(PROOF_COMMITMENT_CLAIM_LEN + (1 + RECIPIENT_BINDING_LIMBS) * self.outputs.len());
+        claim.push(Rp64_256::hash(PROOF_COMMITMENT_TAG).as_elements()[0]);
+        claim.push(self.burn_amount);
+        claim.extend(recipient_binding_limbs(&self.recipient_binding));
+        claim.extend([self.network_id, self.target_chain_id, self.commitment_version]);
+        for output in &self.outputs {
+            claim.push(output.amount);
+            claim.extend(recipient_binding_limbs(&output.recipient_binding));
+        }
+        claim
+    }
+
+    /// Rescue-Prime digest of the public claim
+    #[must_use]
+    pub fn claim_digest(&self) -> [BaseElement; CLAIM_DIGEST_LEN] {
+        Rp64_256::hash_elements(&self.claim_elements()).into()
+    }
+
+    /// Elements the commitment hashes: the secret, then the claim digest
+    #[must_use]
+    pub fn commitment_input(&self) -> [BaseElement; SECRET_LIMBS + CLAIM_DIGEST_LEN] {
+        let mut input = [BaseElement::ZERO; SECRET_LIMBS + CLAIM_DIGEST_LEN];
+        input[..SECRET_LIMBS].copy_from_slice(&self.secret);
+        input[SECRET_LIMBS..].copy_from_slice(&self.claim_digest());
+        input
+    }
+
+    /// Commitment public input: the whole commitment digest
+    #[must_use]
+    pub fn hash(&self) -> [BaseElement; COMMITMENT_LIMBS] {
+        Rp64_256::hash_elements(&self.commitment_input()).into()
+    }
+
+    /// Commitment as written into `tx_extra` (see [`commitment_to_bytes`])
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 32] {
+        commitment_to_bytes(&self.hash())
+    }
+}
+
+impl std::fmt::Debug for CommitmentPreimage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommitmentPreimage")
+            .field("secret", &Redacted::bytes(SECRET_LEN))
+            .field("burn_amount", &self.burn_amount.as_int())
+            .field("recipient_binding", &hex::encode(self.recipient_binding))
+            .field("network_id", &self.network_id.as_int())
+            .field("target_chain_id", &self.target_chain_id.as_int())
+            .field("commitment_version", &self.commitment_version.as_int())
+            .field("outputs", &self.outputs.len())
+            .finish()
+    }
+}
+
+/// Encode a commitment digest as its four limbs in little-endian bytes
+#[must_use]
+pub fn commitment_to_bytes(commitment: &[BaseElement; COMMITMENT_LIMBS]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.chunks_exact_mut(8).zip(commitment) {
+        chunk.copy_from_slice(&limb.as_int().to_le_bytes());
+    }
+    bytes
+}
+
+/// Decode a commitment written by [`commitment_to_bytes`]
+///
+/// Returns `None` when a limb is not a canonical field element, which no commitment is.
+#[must_use]
+pub fn commitment_from_bytes(bytes: &[u8; 32]) -> Option<[BaseElement; COMMITMENT_LIMBS]> {
+    let mut commitment = [BaseElement::ZERO; COMMITMENT_LIMBS];
+    for (limb, chunk) in commitment.iter_mut().zip(bytes.as_chunks::<8>().0) {
+        let value = u64::from_le_bytes(*chunk);
+        if value >= BaseElement::MODULUS {
+            return None;
+        }
+        *limb = BaseElement::new(value);
+    }
+    Some(commitment)
+}
+
+/// Proof commitment for a secret and claim (shorthand for [`CommitmentPreimage::hash`])
+#[must_use]
+pub fn proof_commitment(
+    secret: [BaseElement; SECRET_LIMBS],
+    public_inputs: &BurnMintPublicInputs,
+) -> [BaseElement; COMMITMENT_LIMBS] {
+    CommitmentPreimage::new(secret, public_inputs).hash()
+}
+
+/// Check that a secret opens the commitment a proof's public inputs carry
+///
+/// The `commitment` field of `public_inputs` is ignored; `expected_commitment` is
+/// compared instead, so callers can check a value taken from a proof file or contract.
+///
+/// # Errors
+///
+/// [`crate::XfgStarkError::CryptoError`] if the secret is not [`crate::burn_mint_prover::SECRET_LEN`]
+/// bytes, or [`crate::XfgStarkError::CommitmentMismatch`] if it does not open
+/// `expected_commitment`
+pub fn verify_commitment_opening(
+    secret: &[u8],
+    public_inputs: &BurnMintPublicInputs,
+    expected_commitment: &[u8; 32],
+) -> crate::Result<()> {
+    let computed = CommitmentPreimage::new(secret_to_field_elements(secret)?, public_inputs).to_bytes();
+    if &computed != expected_commitment {
+        return Err(crate::XfgStarkError::CommitmentMismatch {
+            expected: *expected_commitment,
+            computed,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recipient_binding_depends_on_address_and_chain() {
+        let address = [0x12u8; 20];
+        let binding = recipient_binding(&address, 42161);
+
+        assert_eq!(binding, recipient_binding(&address, 42161));
+        assert_ne!(binding, recipient_binding(&address, 1));
+
+        let mut other = address;
+        other[19] ^= 1;
+        assert_ne!(binding, recipient_binding(&other, 42161));
+    }
+
+    #[test]
+    fn test_recipient_binding_limb_round_trip() {
+        let binding = recipient_binding(&[0xabu8; 20], 42161);
+        let limbs = recipient_binding_limbs(&binding);
+
+        assert_eq!(recipient_binding_from_limbs(&limbs), binding);
+        assert_eq!(
+            limbs[0],
+            BaseElement::from(u32::from_le_bytes([binding[0], binding[1], binding[2], binding[3]]))
+        );
+    }
+
+    #[test]
+    fn test_prover_uses_wallet_binding() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+
+        let address = [0x12u8; 20];
+        let (_, public_inputs) = XfgBurnMintProver::default()
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &address,
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+
+        let expected = recipient_binding_limbs(&recipient_binding(&address, 42161));
+        assert_eq!(public_inputs.recipient_binding, expected);
+        assert_eq!(public_inputs.recipient_hash, expected[0]);
+    }
+
+    #[test]
+    fn test_wallet_commitment_is_proof_commitment() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+
+        let address = [0x12u8; 20];
+        let secret = [9u8; 32];
+        let on_chain = CommitmentPreimage::from_user_data(&secret, 8_000_000, &address, 1, 42161, 1)
+            .unwrap()
+            .to_bytes();
+        let (_, public_inputs) = XfgBurnMintProver::default()
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &address,
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &secret,
+            )
+            .expect("Proof generation should succeed");
+
+        // The tx_extra commitment is the proof's commitment public input, whatever the tx
+        assert_eq!(commitment_to_bytes(&public_inputs.commitment), on_chain);
+        assert_eq!(commitment_from_bytes(&on_chain), Some(public_inputs.commitment));
+        assert!(commitment_from_bytes(&[0xff; 32]).is_none());
+        assert!(CommitmentPreimage::from_user_data(&secret[..31], 8_000_000, &address, 1, 42161, 1).is_err());
+    }
+
+    #[test]
+    fn test_commitment_opening() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+
+        let secret = [9u8; 32];
+        let (_, public_inputs) = XfgBurnMintProver::default()
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &secret,
+            )
+            .expect("Proof generation should succeed");
+        let expected = commitment_to_bytes(&public_inputs.commitment);
+
+        // The documented layout reproduces the AIR's commitment
+        let preimage = CommitmentPreimage::new(secret_to_field_elements(&secret).unwrap(), &public_inputs);
+        assert_eq!(preimage.hash(), public_inputs.commitment);
+        assert!(!format!("{:?}", preimage).contains(&preimage.secret[0].as_int().to_string()));
+        let claim = preimage.claim_elements();
+        assert_eq!(claim.len(), PROOF_COMMITMENT_CLAIM_LEN);
+        assert_eq!(claim[0], Rp64_256::hash(PROOF_COMMITMENT_TAG).as_elements()[0]);
+        assert_eq!(claim[1], BaseElement::from(8_000_000u32));
+        assert_eq!(claim[2..10], recipient_binding_limbs(&recipient_binding(&[0x12u8; 20], 42161)));
+        assert_eq!(claim[11], BaseElement::from(42161u32));
+        assert_eq!(preimage.commitment_input()[0], BaseElement::new(u64::from_le_bytes([9; 8])));
+        assert_eq!(preimage.commitment_input()[SECRET_LIMBS..], preimage.claim_digest());
+        assert!(verify_commitment_opening(&secret, &public_inputs, &expected).is_ok());
+
+        // Every secret limb is committed to, not just the first
+        let mut last_limb = secret;
+        last_limb[31] ^= 1;
+        assert!(matches!(
+            verify_commitment_opening(&last_limb, &public_inputs, &expected),
+            Err(crate::XfgStarkError::CommitmentMismatch { expected: e, .. }) if e == expected
+        ));
+        let mut other_chain = public_inputs.clone();
+        other_chain.target_chain_id = BaseElement::from(1u32);
+        assert!(verify_commitment_opening(&secret, &other_chain, &expected).is_err());
+    }
+}
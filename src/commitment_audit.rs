@@ -0,0 +1,270 @@
+//! Commitment Audit for XFG Burn Transactions
+//!
+//! This module cross-checks the commitment a data package claims against the one
+//! recorded on the Fuego chain. The commitment is recomputed from the package inputs,
+//! the burn transaction's `tx_extra` is fetched over Fuego RPC, the HEAT commitment
+//! (tag `0x08`) is extracted, and the two are compared byte by byte.
+//!
+//! ## `tx_extra` Layout
+//! `tx_extra` is a sequence of tagged fields:
+//! - `0x00` padding, running to the end of the field
+//! - `0x01` transaction public key, 32 bytes
+//! - `0x08` HEAT commitment, 32 bytes
+//! - any other tag, a varint length followed by that many bytes
+//!
+//! Fetching `tx_extra` over RPC requires the `rpc` feature (see [`crate::fuego_rpc`]).
+
+use crate::proof_data_schema::StarkProofDataPackage;
+#[cfg(feature = "rpc")]
+pub use crate::fuego_rpc::FuegoRpcClient;
+
+/// `tx_extra` tag carrying the HEAT commitment
+pub const HEAT_COMMITMENT_TAG: u8 = 0x08;
+
+/// `tx_extra` padding tag
+const TX_EXTRA_PADDING_TAG: u8 = 0x00;
+
+/// `tx_extra` transaction public key tag
+const TX_EXTRA_PUBKEY_TAG: u8 = 0x01;
+
+/// Size of 32-byte `tx_extra` fields (public key, commitment)
+const TX_EXTRA_FIELD_SIZE: usize = 32;
+
+/// Recompute the HEAT commitment from package inputs
+///
+/// See [`crate::commitment::CommitmentPreimage`] for the preimage the wallet writes into
+/// `tx_extra`.
+///
+/// # Errors
+///
+/// [`AuditError::InvalidPackage`] if the package inputs do not form a commitment preimage
+pub fn compute_package_commitment(
+    package: &StarkProofDataPackage,
+) -> Result<[u8; 32], AuditError> {
+    package
+        .commitment_preimage()
+        .map(|preimage| preimage.to_bytes())
+        .map_err(|e| AuditError::InvalidPackage(e.to_string()))
+}
+
+/// Extract the HEAT commitment from raw `tx_extra` bytes
+///
+/// # Errors
+///
+/// [`AuditError::MalformedTxExtra`] if a field is truncated or has an invalid length, and
+/// [`AuditError::MissingCommitment`] if no field carries the HEAT commitment
+pub fn extract_heat_commitment(tx_extra: &[u8]) -> Result<[u8; 32], AuditError> {
+    let mut offset = 0;
+
+    while offset < tx_extra.len() {
+        let tag = tx_extra[offset];
+        offset += 1;
+
+        match tag {
+            TX_EXTRA_PADDING_TAG => break,
+            TX_EXTRA_PUBKEY_TAG | HEAT_COMMITMENT_TAG => {
+                let end = offset + TX_EXTRA_FIELD_SIZE;
+                let field = tx_extra.get(offset..end).ok_or_else(|| {
+                    AuditError::MalformedTxExtra(format!(
+                        "Tag 0x{:02x} at byte {} is truncated",
+                        tag,
+                        offset - 1
+                    ))
+                })?;
+                if tag == HEAT_COMMITMENT_TAG {
+                    let mut commitment = [0u8; 32];
+                    commitment.copy_from_slice(field);
+                    return Ok(commitment);
+                }
+                offset = end;
+            }
+            _ => {
+                let (length, read) = read_varint(&tx_extra[offset..]).ok_or_else(|| {
+                    AuditError::MalformedTxExtra(format!(
+                        "Invalid length for tag 0x{:02x} at byte {}",
+                        tag,
+                        offset - 1
+                    ))
+                })?;
+                offset += read;
+                offset = offset
+                    .checked_add(length)
+                    .filter(|end| *end <= tx_extra.len())
+                    .ok_or_else(|| {
+                        AuditError::MalformedTxExtra(format!(
+                            "Tag 0x{tag:02x} length {length} exceeds tx_extra"
+                        ))
+                    })?;
+            }
+        }
+    }
+
+    Err(AuditError::MissingCommitment)
+}
+
+/// Read a little-endian base-128 varint, returning the value and bytes consumed
+fn read_varint(bytes: &[u8]) -> Option<(usize, usize)> {
+    let mut value: usize = 0;
+    for (i, (byte, shift)) in bytes.iter().zip((0..63).step_by(7)).enumerate() {
+        value |= usize::from(byte & 0x7f).checked_shl(shift)?;
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+/// Byte-level comparison of a recomputed and an on-chain commitment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitmentAuditReport {
+    /// Burn transaction hash
+    pub transaction_hash: String,
+    /// Commitment recomputed from the package
+    pub computed: [u8; 32],
+    /// Commitment extracted from `tx_extra`
+    pub on_chain: [u8; 32],
+    /// Byte offsets at which the commitments differ
+    pub mismatched_bytes: Vec<usize>,
+}
+
+impl CommitmentAuditReport {
+    /// Compare two commitments byte by byte
+    #[must_use]
+    pub fn compare(transaction_hash: &str, computed: [u8; 32], on_chain: [u8; 32]) -> Self {
+        let mismatched_bytes = computed
+            .iter()
+            .zip(on_chain.iter())
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(i, _)| i)
+            .collect();
+
+        Self {
+            transaction_hash: transaction_hash.to_string(),
+            computed,
+            on_chain,
+            mismatched_bytes,
+        }
+    }
+
+    /// Check whether the commitments match
+    #[must_use]
+    pub fn is_match(&self) -> bool {
+        self.mismatched_bytes.is_empty()
+    }
+}
+
+/// Audit a package's commitment against raw `tx_extra` bytes
+///
+/// # Errors
+///
+/// Errors of [`compute_package_commitment`] and [`extract_heat_commitment`]
+pub fn audit_commitment_with_tx_extra(
+    package: &StarkProofDataPackage,
+    tx_extra: &[u8],
+) -> Result<CommitmentAuditReport, AuditError> {
+    let computed = compute_package_commitment(package)?;
+    let on_chain = extract_heat_commitment(tx_extra)?;
+    Ok(CommitmentAuditReport::compare(
+        &package.burn_transaction.transaction_hash,
+        computed,
+        on_chain,
+    ))
+}
+
+/// Audit a package's commitment against its burn transaction on the Fuego chain
+///
+/// # Errors
+///
+/// [`AuditError::Rpc`] if the burn transaction cannot be fetched, and errors of
+/// [`audit_commitment_with_tx_extra`]
+#[cfg(feature = "rpc")]
+pub fn audit_package_commitment(
+    package: &StarkProofDataPackage,
+    rpc: &FuegoRpcClient,
+) -> Result<CommitmentAuditReport, AuditError> {
+    let tx_extra = rpc.fetch_tx_extra(&package.burn_transaction.transaction_hash)?;
+    audit_commitment_with_tx_extra(package, &tx_extra)
+}
+
+/// Commitment audit errors
+#[derive(Debug, thiserror::Error)]
+pub enum AuditError {
+    /// Package inputs could not be used to recompute the commitment
+    #[error("Invalid package: {0}")]
+    InvalidPackage(String),
+
+    /// Fuego RPC request failed
+    #[error("Fuego RPC error: {0}")]
+    Rpc(String),
+
+    /// `tx_extra` could not be parsed
+    #[error("Malformed tx_extra: {0}")]
+    MalformedTxExtra(String),
+
+    /// `tx_extra` carries no HEAT commitment tag
+    #[error("tx_extra has no HEAT commitment (tag 0x08)")]
+    MissingCommitment,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_package() -> StarkProofDataPackage {
+        StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "my-secret-key-123".to_string(),
+            "fuego-testnet".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_extract_commitment_after_other_fields() {
+        let commitment = [0xabu8; 32];
+        let mut tx_extra = vec![TX_EXTRA_PUBKEY_TAG];
+        tx_extra.extend_from_slice(&[0x11; 32]);
+        tx_extra.extend_from_slice(&[0x02, 0x03, 1, 2, 3]);
+        tx_extra.push(HEAT_COMMITMENT_TAG);
+        tx_extra.extend_from_slice(&commitment);
+
+        assert_eq!(extract_heat_commitment(&tx_extra).unwrap(), commitment);
+    }
+
+    #[test]
+    fn test_extract_commitment_errors() {
+        let mut pubkey_only = vec![TX_EXTRA_PUBKEY_TAG];
+        pubkey_only.extend_from_slice(&[0x11; 32]);
+        assert!(matches!(
+            extract_heat_commitment(&pubkey_only),
+            Err(AuditError::MissingCommitment)
+        ));
+
+        let truncated = [HEAT_COMMITMENT_TAG, 1, 2, 3];
+        assert!(matches!(
+            extract_heat_commitment(&truncated),
+            Err(AuditError::MalformedTxExtra(_))
+        ));
+    }
+
+    #[test]
+    fn test_audit_reports_mismatched_bytes() {
+        let package = test_package();
+        let mut commitment = compute_package_commitment(&package).unwrap();
+
+        let mut tx_extra = vec![HEAT_COMMITMENT_TAG];
+        tx_extra.extend_from_slice(&commitment);
+        assert!(audit_commitment_with_tx_extra(&package, &tx_extra)
+            .unwrap()
+            .is_match());
+
+        commitment[3] ^= 0xff;
+        commitment[31] ^= 0x01;
+        tx_extra[1..].copy_from_slice(&commitment);
+        let report = audit_commitment_with_tx_extra(&package, &tx_extra).unwrap();
+        assert!(!report.is_match());
+        assert_eq!(report.mismatched_bytes, vec![3, 31]);
+    }
+}
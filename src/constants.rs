@@ -0,0 +1,208 @@
+//! Protocol Constants for XFG → HEAT Burn & Mint
+//!
+//! Single source of truth for the burn tiers and provable burn range, the XFG
+//! atomic-unit scale, the execution trace length, the default target chain and the
+//! commitment version. The AIR, prover, verifier, data package schema, CLI and test data
+//! generators all read these values instead of re-declaring them.
+
+use thiserror::Error;
+
+/// Decimal places of the XFG token
+pub const XFG_DECIMALS: u32 = 7;
+
+/// Atomic units in one XFG (`10^XFG_DECIMALS`)
+pub const ATOMIC_UNITS_PER_XFG: u64 = 10u64.pow(XFG_DECIMALS);
+
+/// XFG amount with no atomic-unit representation
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+#[error("{0} XFG is not between 0 and u64::MAX atomic units")]
+pub struct InvalidXfgAmount(pub f64);
+
+/// Standard burn: 0.8 XFG in atomic units
+pub const STANDARD_BURN_ATOMIC: u64 = 8_000_000;
+
+/// Medium burn: 8 XFG in atomic units
+pub const MEDIUM_BURN_ATOMIC: u64 = 80_000_000;
+
+/// High burn: 80 XFG in atomic units
+pub const HIGH_BURN_ATOMIC: u64 = 800_000_000;
+
+/// Large burn: 800 XFG in atomic units
+pub const LARGE_BURN_ATOMIC: u64 = 8_000_000_000;
+
+/// Burn tiers (0.8, 8, 80 and 800 XFG), in atomic units
+///
+/// Any amount up to [`MAX_BURN_ATOMIC`] is provable; the tiers are the amounts wallets
+/// and test data default to.
+pub const BURN_TIERS_ATOMIC: [u64; 4] = [STANDARD_BURN_ATOMIC, MEDIUM_BURN_ATOMIC, HIGH_BURN_ATOMIC, LARGE_BURN_ATOMIC];
+
+/// Bits the circuit's range check decomposes a burn amount into
+pub const BURN_AMOUNT_BITS: u32 = 53;
+
+/// Largest provable burn amount, in atomic units (`2^53 - 1`)
+pub const MAX_BURN_ATOMIC: u64 = (1 << BURN_AMOUNT_BITS) - 1;
+
+/// Rejection message for a burn amount outside `1..=MAX_BURN_ATOMIC`
+pub const INVALID_BURN_AMOUNT: &str = "Burn amount must be between 1 and 2^53 - 1 atomic units";
+
+/// Steps in the burn & mint execution trace, unless the prover is configured otherwise
+pub const TRACE_LENGTH: usize = 64;
+
+/// Most recipients a single burn can mint to
+pub const MAX_MINT_OUTPUTS: usize = 8;
+
+/// Chain HEAT is minted on (Arbitrum One)
+pub const DEFAULT_TARGET_CHAIN_ID: u32 = crate::networks::ARBITRUM_ONE_CHAIN_ID;
+
+/// Commitment format version produced by this release
+pub const COMMITMENT_VERSION: u32 = 1;
+
+/// Check whether a burn amount (atomic units) is nonzero and within the range check
+#[must_use]
+pub fn is_valid_burn_amount(burn_amount: u64) -> bool {
+    (1..=MAX_BURN_ATOMIC).contains(&burn_amount)
+}
+
+/// Check whether a burn & mint trace length is a power of two with room for the burn
+/// range check and the mint outputs
+#[must_use]
+pub fn is_valid_trace_length(trace_length: usize) -> bool {
+    trace_length.is_power_of_two() && trace_length > BURN_AMOUNT_BITS as usize && trace_length > MAX_MINT_OUTPUTS
+}
+
+/// Convert an XFG amount from whole units to atomic units, rounded to the nearest unit
+///
+/// # Errors
+///
+/// [`InvalidXfgAmount`] if the amount is negative, not finite or above `u64::MAX` units
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+pub fn xfg_to_atomic_units(xfg_amount: f64) -> Result<u64, InvalidXfgAmount> {
+    let atomic_units = (xfg_amount * f64::from(10u32.pow(XFG_DECIMALS))).round();
+    // `u64::MAX as f64` is 2^64, one past the largest amount, so the range is half-open
+    let in_range = atomic_units.is_finite() && (0.0..u64::MAX as f64).contains(&atomic_units);
+    if !in_range {
+        return Err(InvalidXfgAmount(xfg_amount));
+    }
+    // Integral and in range, so the cast is exact
+    Ok(atomic_units as u64)
+}
+
+/// Convert an XFG amount from atomic units to whole units
+///
+/// Amounts up to 2^53 atomic units convert exactly before the division, so the result
+/// is the `f64` nearest the exact amount.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn atomic_units_to_xfg(atomic_units: u64) -> f64 {
+    atomic_units as f64 / ATOMIC_UNITS_PER_XFG as f64
+}
+
+/// Burn tiers in whole XFG
+#[must_use]
+pub fn burn_tiers_xfg() -> [f64; 4] {
+    BURN_TIERS_ATOMIC.map(atomic_units_to_xfg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+    use crate::test_data_generator::TestDataGenerator;
+
+    #[test]
+    fn test_tier_scale() {
+        assert_eq!(ATOMIC_UNITS_PER_XFG, 10_000_000);
+        assert_eq!(burn_tiers_xfg(), [0.8, 8.0, 80.0, 800.0]);
+        for tier in BURN_TIERS_ATOMIC {
+            assert_eq!(xfg_to_atomic_units(atomic_units_to_xfg(tier)), Ok(tier));
+        }
+        assert!(TRACE_LENGTH.is_power_of_two());
+        assert!(TRACE_LENGTH > BURN_AMOUNT_BITS as usize);
+        assert!(TRACE_LENGTH > MAX_MINT_OUTPUTS);
+        assert!(is_valid_trace_length(TRACE_LENGTH));
+        assert!(is_valid_trace_length(4 * TRACE_LENGTH));
+        assert!(!is_valid_trace_length(TRACE_LENGTH / 2));
+        assert!(!is_valid_trace_length(TRACE_LENGTH + 1));
+
+        assert!(is_valid_burn_amount(1));
+        assert!(is_valid_burn_amount(MAX_BURN_ATOMIC));
+        assert!(!is_valid_burn_amount(0));
+        assert!(!is_valid_burn_amount(MAX_BURN_ATOMIC + 1));
+    }
+
+    #[test]
+    fn test_xfg_amounts_round_to_atomic_units() {
+        // 2.01 * 10^7 is 20099999.999999996 and 0.57 * 10^7 is 5699999.999999999
+        assert_eq!(xfg_to_atomic_units(2.01), Ok(20_100_000));
+        assert_eq!(xfg_to_atomic_units(0.57), Ok(5_700_000));
+        assert_eq!(xfg_to_atomic_units(0.000_000_04), Ok(0));
+        assert_eq!(xfg_to_atomic_units(-0.000_000_04), Ok(0));
+        assert_eq!(xfg_to_atomic_units(0.0), Ok(0));
+        assert_eq!(atomic_units_to_xfg(1), 0.000_000_1);
+        assert_eq!(atomic_units_to_xfg(u64::MAX), 1_844_674_407_370.955_2);
+
+        for invalid in [-1.0, f64::NAN, f64::INFINITY, 2e12] {
+            assert!(matches!(xfg_to_atomic_units(invalid), Err(InvalidXfgAmount(_))));
+        }
+    }
+
+    #[test]
+    fn test_modules_agree_on_constants() {
+        for (xfg, atomic) in burn_tiers_xfg().into_iter().zip(BURN_TIERS_ATOMIC) {
+            assert_eq!(XfgBurnMintProver::xfg_to_atomic_units(xfg), Ok(atomic));
+            assert_eq!(XfgBurnMintProver::atomic_units_to_xfg(atomic), xfg);
+        }
+
+        let (xfg, atomic) = TestDataGenerator::generate_burn_amounts();
+        assert!(is_valid_burn_amount(atomic));
+        assert_eq!(xfg_to_atomic_units(xfg), Ok(atomic));
+
+        let (proof, _) = XfgBurnMintProver::default()
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: LARGE_BURN_ATOMIC,
+                    mint_amount: LARGE_BURN_ATOMIC,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Every tier should be provable");
+        assert_eq!(proof.get_trace_info().length(), TRACE_LENGTH);
+
+        assert!(XfgBurnMintProver::default()
+            .prove_burn_mint(
+                MAX_BURN_ATOMIC + 1,
+                MAX_BURN_ATOMIC + 1,
+                [7u8; 32],
+                &[0x12u8; 20],
+                &[9u8; 32],
+                1,
+                42161,
+                1,
+            )
+            .is_err());
+    }
+
+    #[cfg(feature = "packages")]
+    #[test]
+    fn test_schema_agrees_on_constants() {
+        use crate::proof_data_schema::StarkProofDataPackage;
+
+        for (xfg, atomic) in burn_tiers_xfg().into_iter().zip(BURN_TIERS_ATOMIC) {
+            assert_eq!(StarkProofDataPackage::xfg_to_atomic_units(xfg), Ok(atomic));
+            let package = StarkProofDataPackage::new(
+                xfg,
+                "a".repeat(64),
+                format!("0x{}", "12".repeat(20)),
+                "secret-key-material".to_string(),
+                "fuego-mainnet".to_string(),
+            );
+            assert_eq!(package.burn_transaction.burn_amount_atomic, atomic);
+            assert!(package.validate().is_valid);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Constraint Debugging
+//!
+//! Winterfell rejects a trace that breaks its AIR with an opaque error, and
+//! [`check_constraints`](crate::statements::check_constraints) stops at the first
+//! violation. [`check_trace_against_air`] evaluates every assertion and transition
+//! constraint on every row and reports each violation with the constraint, the row, the
+//! evaluated value and the register values involved, so a change to a circuit can be
+//! checked against a known-good trace in one pass.
+//!
+//! Its [`ConstraintViolation`] is not re-exported at the crate root, where the name
+//! refers to the first-violation error of [`crate::statements`].
+
+use std::fmt::{Display, Formatter};
+use winterfell::{math::fields::f64::BaseElement, math::FieldElement, Air, EvaluationFrame, Trace};
+
+/// Constraint a [`ConstraintViolation`] refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolatedConstraint {
+    /// The trace width differs from the AIR's; nothing else was evaluated
+    TraceWidth {
+        /// Columns the AIR expects
+        expected: usize,
+        /// Columns in the trace
+        actual: usize,
+    },
+    /// An assertion, by index in [`Air::get_assertions`]
+    Assertion {
+        /// Index of the assertion
+        index: usize,
+        /// Asserted column
+        column: usize,
+        /// Asserted value
+        expected: u64,
+    },
+    /// A transition constraint, by index in the AIR's evaluation order
+    Transition {
+        /// Index of the constraint
+        index: usize,
+    },
+}
+
+/// One constraint failing on one row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintViolation {
+    /// Failing constraint
+    pub constraint: ViolatedConstraint,
+    /// Row the constraint is evaluated at (the current row of a transition)
+    pub row: usize,
+    /// Constraint evaluation: the transition value, or found minus asserted value
+    pub value: u64,
+    /// Register values at `row`
+    pub current: Vec<u64>,
+    /// Register values at `row + 1` for transitions, empty otherwise
+    pub next: Vec<u64>,
+}
+
+impl Display for ConstraintViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.constraint {
+            ViolatedConstraint::TraceWidth { expected, actual } => {
+                write!(f, "trace has {actual} columns, the AIR expects {expected}")
+            }
+            ViolatedConstraint::Assertion { index, column, expected } => write!(
+                f,
+                "assertion {} on column {} at row {}: expected {}, found {} (registers {:?})",
+                index, column, self.row, expected, self.current[*column], self.current
+            ),
+            ViolatedConstraint::Transition { index } => write!(
+                f,
+                "transition constraint {} at row {} evaluated to {} (current {:?}, next {:?})",
+                index, self.row, self.value, self.current, self.next
+            ),
+        }
+    }
+}
+
+/// Evaluate every main-segment assertion and transition constraint of `air` on every
+/// row of `trace`, returning all violations in assertion, then row, order
+pub fn check_trace_against_air<A, T>(trace: &T, air: &A) -> Vec<ConstraintViolation>
+where
+    A: Air<BaseField = BaseElement>,
+    T: Trace<BaseField = BaseElement>,
+{
+    let width = air.trace_layout().main_trace_width();
+    if trace.main_trace_width() != width {
+        return vec![ConstraintViolation {
+            constraint: ViolatedConstraint::TraceWidth {
+                expected: width,
+                actual: trace.main_trace_width(),
+            },
+            row: 0,
+            value: 0,
+            current: Vec::new(),
+            next: Vec::new(),
+        }];
+    }
+
+    let main = trace.main_segment();
+    let row_values = |row: usize| -> Vec<u64> { (0..width).map(|column| main.get(column, row).as_int()).collect() };
+    let mut violations = Vec::new();
+
+    for (index, assertion) in air.get_assertions().into_iter().enumerate() {
+        assertion.apply(trace.length(), |row, expected| {
+            let actual = main.get(assertion.column(), row);
+            if actual != expected {
+                violations.push(ConstraintViolation {
+                    constraint: ViolatedConstraint::Assertion {
+                        index,
+                        column: assertion.column(),
+                        expected: expected.as_int(),
+                    },
+                    row,
+                    value: (actual - expected).as_int(),
+                    current: row_values(row),
+                    next: Vec::new(),
+                });
+            }
+        });
+    }
+
+    // Periodic columns repeat over the trace domain, so row values index them directly
+    let periodic_columns = air.get_periodic_column_values();
+    let mut periodic_values = vec![BaseElement::ZERO; periodic_columns.len()];
+    let mut frame = EvaluationFrame::new(width);
+    let mut evaluations = vec![BaseElement::ZERO; air.context().num_main_transition_constraints()];
+
+    for row in 0..trace.length() - air.context().num_transition_exemptions() {
+        for (value, column) in periodic_values.iter_mut().zip(&periodic_columns) {
+            *value = column[row % column.len()];
+        }
+        trace.read_main_frame(row, &mut frame);
+        air.evaluate_transition(&frame, &periodic_values, &mut evaluations);
+
+        for (index, value) in evaluations.iter().enumerate().filter(|(_, value)| **value != BaseElement::ZERO) {
+            violations.push(ConstraintViolation {
+                constraint: ViolatedConstraint::Transition { index },
+                row,
+                value: value.as_int(),
+                current: frame.current().iter().map(BaseElement::as_int).collect(),
+                next: frame.next().iter().map(BaseElement::as_int).collect(),
+            });
+        }
+    }
+
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_air::XfgBurnMintAir;
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+    use winterfell::TraceTable;
+
+    /// AIR and a valid trace for a standard burn
+    fn burn_mint_air_and_trace() -> (XfgBurnMintAir, TraceTable<BaseElement>) {
+        let prover = XfgBurnMintProver::default();
+        let (_, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let air = XfgBurnMintAir::new(
+            prover.domain().trace_info.clone(),
+            public_inputs,
+            crate::burn_mint_prover::secret_to_field_elements(&[9u8; 32]).unwrap(),
+            prover.proof_options().clone(),
+        );
+        let trace = air.build_trace();
+        (air, trace)
+    }
+
+    #[test]
+    fn test_reports_every_violation() {
+        let (air, mut trace) = burn_mint_air_and_trace();
+        assert!(check_trace_against_air(&trace, &air).is_empty());
+
+        // Jump the state register two states ahead mid-trace, and break an assertion
+        let state = trace.get(4, 10);
+        trace.set(4, 11, state + BaseElement::new(2));
+        let burn = trace.get(0, 5);
+        trace.set(0, 5, burn + BaseElement::ONE);
+
+        let violations = check_trace_against_air(&trace, &air);
+        let assertion = violations
+            .iter()
+            .find(|violation| matches!(violation.constraint, ViolatedConstraint::Assertion { column: 0, .. }))
+            .unwrap();
+        assert_eq!(assertion.row, 5);
+        assert_eq!(assertion.value, 1);
+        assert_eq!(assertion.current[0], burn.as_int() + 1);
+
+        // The jump breaks the transitions into and out of row 11
+        let transition_rows: Vec<usize> = violations
+            .iter()
+            .filter(|violation| violation.constraint == ViolatedConstraint::Transition { index: 0 })
+            .map(|violation| violation.row)
+            .collect();
+        assert_eq!(transition_rows, [10, 11]);
+        let jump = violations.iter().find(|violation| violation.row == 10).unwrap();
+        assert_eq!(jump.next[4], state.as_int() + 2);
+        assert!(jump.to_string().contains("transition constraint 0 at row 10"));
+    }
+}
@@ -0,0 +1,320 @@
+//! Deterministic Proof Verification for Consensus
+//!
+//! Eldernodes that reach consensus on proof validity must all compute the same verdict
+//! on any hardware. [`DeterministicVerifier`] verifies a serialized burn & mint proof
+//! without floating point, clocks, threads, randomness or hash-map iteration:
+//!
+//! - Public inputs must be canonical field elements. A word at or above the field
+//!   modulus is rejected rather than reduced, so two encodings of one input cannot both
+//!   verify.
+//! - Proof options must match the consensus options exactly. The conjectured security
+//!   estimate used by [`crate::burn_mint_verifier::XfgBurnMintVerifier`] is computed in
+//!   floating point and is therefore not evaluated here.
+//! - Every step and its outcome is appended to a [`VerificationTranscript`], whose
+//!   [`VerificationTranscript::digest`] nodes can compare to detect divergence.
+//!
+//! Build consensus verifiers with the `consensus` profile
+//! (`cargo build --profile consensus`), which fixes codegen units, enables LTO and keeps
+//! overflow checks on so every node runs identical arithmetic.
+//!
+//! `tests/deterministic_allocator.rs` runs the verifier under a global allocator that
+//! moves every allocation between runs and checks the transcripts still agree.
+//!
+//! Only the native verifier is built and tested. Avoiding host-dependent behaviour
+//! keeps it portable to a deterministic WASM runtime, but this crate ships no wasm32
+//! build of it and no sandbox to run one in.
+
+use crate::burn_mint_air::{BurnMintPublicInputs, XfgBurnMintAir};
+use crate::burn_mint_verifier::XfgBurnMintVerifier;
+use crate::constants::{is_valid_burn_amount, INVALID_BURN_AMOUNT};
+use crate::fees::FeeModel;
+use crate::statements::verify_statement;
+use sha2::{Digest, Sha256};
+use winterfell::{
+    math::{fields::f64::BaseElement, StarkField},
+    ProofOptions, StarkProof,
+};
+
+/// One step of a deterministic verification run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranscriptStep {
+    /// Proof bytes were decoded
+    ProofDecoded {
+        /// SHA-256 of the proof bytes
+        digest: [u8; 32],
+        /// Length of the proof in bytes
+        len: usize,
+    },
+    /// Public inputs as canonical field element values
+    PublicInputs(Vec<u64>),
+    /// Proof options compared with the consensus options
+    OptionsChecked {
+        /// Whether the proof was generated with the consensus options
+        matches: bool,
+    },
+    /// Public inputs checked against the burn tiers and the fee model
+    InputsChecked {
+        /// Whether the inputs are admissible
+        admissible: bool,
+    },
+    /// STARK verification ran
+    StarkVerified {
+        /// Whether the proof verified
+        valid: bool,
+    },
+    /// A step failed and verification stopped
+    Rejected(String),
+}
+
+impl TranscriptStep {
+    /// Canonical byte encoding: a tag byte followed by fixed-width little-endian fields
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            TranscriptStep::ProofDecoded { digest, len } => {
+                out.push(0);
+                out.extend_from_slice(digest);
+                out.extend_from_slice(&(*len as u64).to_le_bytes());
+            }
+            TranscriptStep::PublicInputs(values) => {
+                out.push(1);
+                out.extend_from_slice(&(values.len() as u64).to_le_bytes());
+                for value in values {
+                    out.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            TranscriptStep::OptionsChecked { matches } => out.extend_from_slice(&[2, u8::from(*matches)]),
+            TranscriptStep::InputsChecked { admissible } => out.extend_from_slice(&[3, u8::from(*admissible)]),
+            TranscriptStep::StarkVerified { valid } => out.extend_from_slice(&[4, u8::from(*valid)]),
+            TranscriptStep::Rejected(reason) => {
+                out.push(5);
+                out.extend_from_slice(&(reason.len() as u64).to_le_bytes());
+                out.extend_from_slice(reason.as_bytes());
+            }
+        }
+    }
+}
+
+/// Ordered record of a deterministic verification run
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VerificationTranscript {
+    steps: Vec<TranscriptStep>,
+}
+
+impl VerificationTranscript {
+    fn push(&mut self, step: TranscriptStep) {
+        self.steps.push(step);
+    }
+
+    fn reject(mut self, reason: impl Into<String>) -> Self {
+        self.push(TranscriptStep::Rejected(reason.into()));
+        self
+    }
+
+    /// Steps in execution order
+    #[must_use]
+    pub fn steps(&self) -> &[TranscriptStep] {
+        &self.steps
+    }
+
+    /// Whether the proof was accepted
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        matches!(self.steps.last(), Some(TranscriptStep::StarkVerified { valid: true }))
+    }
+
+    /// Canonical encoding of the transcript
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        for step in &self.steps {
+            step.encode(&mut out);
+        }
+        out
+    }
+
+    /// SHA-256 of the canonical encoding
+    #[must_use]
+    pub fn digest(&self) -> [u8; 32] {
+        Sha256::digest(self.to_bytes()).into()
+    }
+}
+
+/// Burn & mint verifier whose verdict depends only on its inputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeterministicVerifier {
+    options: ProofOptions,
+    fee_model: FeeModel,
+}
+
+impl DeterministicVerifier {
+    /// Create a verifier accepting only proofs generated with `options`
+    #[must_use]
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            fee_model: FeeModel::none(),
+        }
+    }
+
+    /// Set the protocol fee the network charges
+    #[must_use]
+    pub fn with_fee_model(mut self, fee_model: FeeModel) -> Self {
+        self.fee_model = fee_model;
+        self
+    }
+
+    /// Consensus proof options
+    #[must_use]
+    pub fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    /// Verify a serialized proof against public inputs in `to_elements` order
+    pub fn verify(&self, proof_bytes: &[u8], public_inputs: &[u64]) -> VerificationTranscript {
+        let mut transcript = VerificationTranscript::default();
+
+        let proof = match StarkProof::from_bytes(proof_bytes) {
+            Ok(proof) => proof,
+            Err(e) => return transcript.reject(format!("Invalid proof encoding: {e}")),
+        };
+        transcript.push(TranscriptStep::ProofDecoded {
+            digest: Sha256::digest(proof_bytes).into(),
+            len: proof_bytes.len(),
+        });
+
+        if let Some(index) = public_inputs.iter().position(|&word| word >= BaseElement::MODULUS) {
+            return transcript.reject(format!(
+                "Public input {index} is not a canonical field element"
+            ));
+        }
+        let elements: Vec<BaseElement> = public_inputs.iter().copied().map(BaseElement::new).collect();
+        let Some(inputs) = BurnMintPublicInputs::from_elements(&elements) else {
+            return transcript.reject(format!(
+                "Expected {} public inputs, got {}",
+                BurnMintPublicInputs::NUM_ELEMENTS,
+                elements.len()
+            ));
+        };
+        transcript.push(TranscriptStep::PublicInputs(
+            elements.iter().map(BaseElement::as_int).collect(),
+        ));
+
+        let matches = proof.options() == &self.options;
+        transcript.push(TranscriptStep::OptionsChecked { matches });
+        if !matches {
+            return transcript.reject("Proof options differ from the consensus options");
+        }
+
+        let admissible = self.check_inputs(&inputs);
+        transcript.push(TranscriptStep::InputsChecked {
+            admissible: admissible.is_ok(),
+        });
+        if let Err(reason) = admissible {
+            return transcript.reject(reason);
+        }
+
+        let valid = verify_statement::<XfgBurnMintAir>(proof, inputs, &self.options).is_ok();
+        transcript.push(TranscriptStep::StarkVerified { valid });
+        transcript
+    }
+
+    /// Check public inputs with integer arithmetic only
+    fn check_inputs(&self, inputs: &BurnMintPublicInputs) -> std::result::Result<(), String> {
+        if !is_valid_burn_amount(inputs.burn_amount.as_int()) {
+            return Err(INVALID_BURN_AMOUNT.to_string());
+        }
+        XfgBurnMintVerifier::default()
+            .with_fee_model(self.fee_model)
+            .validate_public_inputs(inputs)
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl Default for DeterministicVerifier {
+    fn default() -> Self {
+        Self::new(XfgBurnMintVerifier::default().proof_options().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+    use winter_math::ToElements;
+
+    fn standard_proof() -> (Vec<u8>, Vec<u64>) {
+        let (proof, public_inputs) = XfgBurnMintProver::default()
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let inputs = public_inputs.to_elements().iter().map(|e| e.as_int()).collect();
+        (proof.to_bytes(), inputs)
+    }
+
+    #[test]
+    fn test_transcripts_identical_across_runs() {
+        let verifier = DeterministicVerifier::default();
+        let (proof, inputs) = standard_proof();
+
+        let first = verifier.verify(&proof, &inputs);
+        let second = verifier.verify(&proof, &inputs);
+
+        assert!(first.is_valid());
+        assert_eq!(first, second);
+        assert_eq!(first.to_bytes(), second.to_bytes());
+        assert_eq!(first.digest(), second.digest());
+    }
+
+    #[test]
+    fn test_transcript_records_rejection() {
+        let verifier = DeterministicVerifier::default();
+        let (proof, mut inputs) = standard_proof();
+        inputs[0] = 1 << 53;
+
+        let transcript = verifier.verify(&proof, &inputs);
+        assert!(!transcript.is_valid());
+        assert_eq!(
+            transcript.steps().last(),
+            Some(&TranscriptStep::Rejected(INVALID_BURN_AMOUNT.to_string()))
+        );
+
+        let other = DeterministicVerifier::new(ProofOptions::new(
+            28,
+            8,
+            0,
+            winterfell::FieldExtension::Quadratic,
+            8,
+            31,
+        ));
+        let transcript = other.verify(&proof, &standard_proof().1);
+        assert!(transcript
+            .steps()
+            .contains(&TranscriptStep::OptionsChecked { matches: false }));
+        assert_ne!(transcript.digest(), verifier.verify(&proof, &standard_proof().1).digest());
+    }
+
+    #[test]
+    fn test_non_canonical_public_input_rejected() {
+        let verifier = DeterministicVerifier::default();
+        let (proof, mut inputs) = standard_proof();
+
+        // Same field element as the honest network ID, encoded above the modulus
+        inputs[9] += BaseElement::MODULUS;
+        let transcript = verifier.verify(&proof, &inputs);
+        assert!(!transcript.is_valid());
+        assert_eq!(
+            transcript.steps().last(),
+            Some(&TranscriptStep::Rejected("Public input 9 is not a canonical field element".to_string()))
+        );
+    }
+}
@@ -0,0 +1,765 @@
+//! Eldernode Burn Attestation
+//!
+//! Eldernodes are Fuego nodes that attest to burns: each one looks the burn transaction
+//! up on chain, reads the HEAT commitment from its `tx_extra` and the amount of its
+//! undefined-output-key burn output, and signs what it found with its Ed25519 key. A
+//! burn is confirmed once enough known Eldernodes have signed the commitment and burn
+//! amount the package claims ([`ConsensusThreshold`], e.g. 2/2 or 3/5).
+//!
+//! [`EldernodeClient`] fetches attestations; [`HttpEldernodeClient`] is the REST
+//! transport. [`EldernodeVerifier`] checks them against the trusted [`EldernodeSet`],
+//! so no transport needs to be trusted, and [`reach_consensus`] ties the two together.
+//!
+//! ## REST Endpoint
+//!
+//! ```text
+//! POST /api/v1/verify
+//! → {"transaction_hash":"<hex>","commitment":"<hex>","burn_amount":800000000}
+//! ← {"attestations":[{"eldernode_id":"elder1","commitment":"<hex>","burn_amount":800000000,
+//!                     "timestamp":1705312200,"signature":"<hex>"}, ...],
+//!    "merkle_proof":{...}}
+//! ```
+//!
+//! Each signature covers [`EldernodeAttestation::signing_digest`] for the requested
+//! transaction hash, so an attestation of another burn does not verify. `merkle_proof`
+//! is optional.
+//!
+//! Signatures recorded in a package are checked again by [`consensus`] whenever the
+//! record is added with `CompleteProofPackage::add_eldernode_verification`.
+
+pub mod consensus;
+
+use crate::progress::{ConsensusPhase, ProgressReporter};
+use crate::proof_data_schema::{
+    ConsensusInfo, EldernodeSignature, EldernodeVerification, MerkleProof, VerificationMetadata,
+};
+use crate::verification_context::EldernodeAttestationInputs;
+use consensus::{attestation_digest, verify_attestation_signature};
+use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::io::Read;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Documented Eldernode verification endpoint
+pub const DEFAULT_ELDERNODE_ENDPOINT: &str = "https://eldernodes.fuego.network/api/v1/verify";
+
+/// Largest endpoint response read (bytes)
+const MAX_RESPONSE_BYTES: u64 = 1024 * 1024;
+
+/// Eldernode error types
+#[derive(Debug, thiserror::Error)]
+pub enum EldernodeError {
+    /// Threshold that cannot be met or is not `required/total`
+    #[error("Invalid consensus threshold: {0}")]
+    InvalidThreshold(String),
+
+    /// Eldernode ID registered twice
+    #[error("Duplicate Eldernode ID: {0}")]
+    DuplicateEldernode(String),
+
+    /// Malformed Eldernode public key
+    #[error("Invalid Eldernode key: {0}")]
+    InvalidKey(String),
+
+    /// Trusted set size differs from the threshold's total
+    #[error("Threshold {threshold} needs {} Eldernodes, {registered} are registered", .threshold.total)]
+    ThresholdMismatch {
+        /// Configured threshold
+        threshold: ConsensusThreshold,
+        /// Eldernodes in the trusted set
+        registered: usize,
+    },
+
+    /// Endpoint unreachable or answered with an error
+    #[error("Eldernode transport error: {0}")]
+    Transport(String),
+
+    /// Endpoint response is not an attestation list
+    #[error("Invalid Eldernode response: {0}")]
+    InvalidResponse(String),
+
+    /// A recorded signature does not count toward consensus
+    #[error("Eldernode {eldernode_id} signature rejected: {reason}")]
+    SignatureRejected {
+        /// Signer the record names
+        eldernode_id: String,
+        /// Why the signature does not count
+        reason: AttestationRejection,
+    },
+
+    /// Too few Eldernodes confirmed the burn
+    #[error("{confirmed} of {total} Eldernodes confirmed the burn, {required} required")]
+    ThresholdNotMet {
+        /// Eldernodes that confirmed the claimed commitment and burn amount
+        confirmed: usize,
+        /// Confirmations required
+        required: usize,
+        /// Eldernodes in the trusted set
+        total: usize,
+    },
+}
+
+/// Confirmations required out of the trusted Eldernodes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusThreshold {
+    /// Confirmations required
+    pub required: usize,
+    /// Eldernodes asked
+    pub total: usize,
+}
+
+impl ConsensusThreshold {
+    /// Both of two Eldernodes
+    pub const TWO_OF_TWO: Self = Self { required: 2, total: 2 };
+    /// Three of five Eldernodes
+    pub const THREE_OF_FIVE: Self = Self { required: 3, total: 5 };
+
+    /// Threshold of `required` confirmations out of `total`
+    ///
+    /// # Errors
+    ///
+    /// [`EldernodeError::InvalidThreshold`] if `required` is zero or exceeds `total`
+    pub fn new(required: usize, total: usize) -> Result<Self, EldernodeError> {
+        if required == 0 || required > total {
+            return Err(EldernodeError::InvalidThreshold(format!("{required}/{total}")));
+        }
+        Ok(Self { required, total })
+    }
+}
+
+impl Display for ConsensusThreshold {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.required, self.total)
+    }
+}
+
+impl FromStr for ConsensusThreshold {
+    type Err = EldernodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || EldernodeError::InvalidThreshold(s.to_string());
+        let (required, total) = s.split_once('/').ok_or_else(invalid)?;
+        let required = required.trim().parse().map_err(|_| invalid())?;
+        let total = total.trim().parse().map_err(|_| invalid())?;
+        Self::new(required, total)
+    }
+}
+
+/// Trusted Eldernode entry, as stored in configuration files
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EldernodeRecord {
+    /// Eldernode identifier used in attestations
+    pub eldernode_id: String,
+    /// Ed25519 public key (hex string)
+    pub public_key: String,
+}
+
+/// Eldernodes whose attestations count toward consensus
+#[derive(Debug, Clone, Default)]
+pub struct EldernodeSet {
+    keys: BTreeMap<String, VerifyingKey>,
+}
+
+impl EldernodeSet {
+    /// Create an empty set
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode a set from configuration records
+    ///
+    /// # Errors
+    ///
+    /// [`EldernodeError::InvalidKey`] if a key is not a hex Ed25519 public key, or
+    /// [`EldernodeError::DuplicateEldernode`] if an ID repeats
+    pub fn from_records(records: &[EldernodeRecord]) -> Result<Self, EldernodeError> {
+        let mut set = Self::new();
+        for record in records {
+            let invalid = |reason: String| EldernodeError::InvalidKey(format!("{}: {}", record.eldernode_id, reason));
+            let bytes = hex::decode(record.public_key.trim_start_matches("0x")).map_err(|e| invalid(e.to_string()))?;
+            let bytes: [u8; 32] = bytes
+                .try_into()
+                .map_err(|_| invalid("public key must be 32 bytes".to_string()))?;
+            let key = VerifyingKey::from_bytes(&bytes).map_err(|e| invalid(e.to_string()))?;
+            set.add(&record.eldernode_id, key)?;
+        }
+        Ok(set)
+    }
+
+    /// Trust an Eldernode's key
+    ///
+    /// # Errors
+    ///
+    /// [`EldernodeError::DuplicateEldernode`] if the ID is already trusted
+    pub fn add(&mut self, eldernode_id: &str, key: VerifyingKey) -> Result<(), EldernodeError> {
+        if self.keys.contains_key(eldernode_id) {
+            return Err(EldernodeError::DuplicateEldernode(eldernode_id.to_string()));
+        }
+        self.keys.insert(eldernode_id.to_string(), key);
+        Ok(())
+    }
+
+    /// Key of a trusted Eldernode
+    #[must_use]
+    pub fn key(&self, eldernode_id: &str) -> Option<&VerifyingKey> {
+        self.keys.get(eldernode_id)
+    }
+
+    /// Number of trusted Eldernodes
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Check whether no Eldernode is trusted
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+/// One Eldernode's signed view of a burn
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EldernodeAttestation {
+    /// Attesting Eldernode
+    pub eldernode_id: String,
+    /// HEAT commitment found in the burn's `tx_extra` (hex string)
+    pub commitment: String,
+    /// Amount of the burn's undefined-output-key output (atomic units)
+    pub burn_amount: u64,
+    /// When the Eldernode attested (UNIX seconds)
+    pub timestamp: u64,
+    /// Ed25519 signature over the signing digest (hex string)
+    pub signature: String,
+}
+
+impl EldernodeAttestation {
+    /// Attest to a burn, as an Eldernode does
+    #[must_use]
+    pub fn sign(
+        eldernode_id: &str,
+        key: &SigningKey,
+        transaction_hash: &str,
+        commitment: [u8; 32],
+        burn_amount: u64,
+        timestamp: u64,
+    ) -> Self {
+        let mut attestation = Self {
+            eldernode_id: eldernode_id.to_string(),
+            commitment: hex::encode(commitment),
+            burn_amount,
+            timestamp,
+            signature: String::new(),
+        };
+        if let Some(digest) = attestation.signing_digest(transaction_hash) {
+            attestation.signature = hex::encode(key.sign(&digest).to_bytes());
+        }
+        attestation
+    }
+
+    /// Digest signed for the burn `transaction_hash`, `None` if the commitment is not 32 hex bytes
+    #[must_use]
+    pub fn signing_digest(&self, transaction_hash: &str) -> Option<[u8; 32]> {
+        let commitment = self.commitment_bytes()?;
+        Some(attestation_digest(transaction_hash, &commitment, self.burn_amount, self.timestamp))
+    }
+
+    /// Attested commitment, `None` if it is not 32 hex bytes
+    #[must_use]
+    pub fn commitment_bytes(&self) -> Option<[u8; 32]> {
+        hex::decode(self.commitment.trim_start_matches("0x")).ok()?.try_into().ok()
+    }
+}
+
+/// Why an attestation does not count toward consensus
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttestationRejection {
+    /// Signer is not in the trusted set
+    UnknownEldernode,
+    /// Eldernode already attested in this round
+    Duplicate,
+    /// Recorded public key is not the Eldernode's trusted key
+    KeyMismatch,
+    /// Commitment or signature does not decode
+    Malformed(String),
+    /// Signature does not verify for the requested burn
+    InvalidSignature,
+}
+
+impl Display for AttestationRejection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttestationRejection::UnknownEldernode => write!(f, "unknown Eldernode"),
+            AttestationRejection::Duplicate => write!(f, "duplicate attestation"),
+            AttestationRejection::KeyMismatch => write!(f, "public key differs from the trusted key"),
+            AttestationRejection::Malformed(reason) => write!(f, "malformed attestation: {reason}"),
+            AttestationRejection::InvalidSignature => write!(f, "invalid signature"),
+        }
+    }
+}
+
+/// Attestations collected for one burn
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttestationResponse {
+    /// Signed attestations, one per responding Eldernode
+    pub attestations: Vec<EldernodeAttestation>,
+    /// Merkle proof of the burn transaction's inclusion, if the endpoint provides one
+    #[serde(default)]
+    pub merkle_proof: Option<MerkleProof>,
+}
+
+/// Outcome of checking attestations against a claim
+#[derive(Debug, Clone)]
+pub struct EldernodeConsensus {
+    /// Threshold the attestations were held to
+    pub threshold: ConsensusThreshold,
+    /// Valid attestations of the claimed commitment and burn amount
+    pub confirmed: Vec<EldernodeAttestation>,
+    /// Valid attestations of a different commitment or burn amount
+    pub disputed: Vec<EldernodeAttestation>,
+    /// Attestations that do not count, by Eldernode ID
+    pub rejected: Vec<(String, AttestationRejection)>,
+    /// Merkle proof returned with the attestations
+    pub merkle_proof: Option<MerkleProof>,
+}
+
+impl EldernodeConsensus {
+    /// Check whether enough Eldernodes confirmed the claim
+    #[must_use]
+    pub fn is_reached(&self) -> bool {
+        self.confirmed.len() >= self.threshold.required
+    }
+
+    /// Error unless enough Eldernodes confirmed the claim
+    ///
+    /// # Errors
+    ///
+    /// [`EldernodeError::ThresholdNotMet`] if too few did
+    pub fn ensure_reached(&self) -> Result<(), EldernodeError> {
+        if self.is_reached() {
+            return Ok(());
+        }
+        Err(EldernodeError::ThresholdNotMet {
+            confirmed: self.confirmed.len(),
+            required: self.threshold.required,
+            total: self.threshold.total,
+        })
+    }
+
+    /// Package record of the confirming signatures
+    #[must_use]
+    pub fn to_verification(
+        &self,
+        eldernodes: &EldernodeSet,
+        network: &str,
+        verified_at: String,
+    ) -> EldernodeVerification {
+        let eldernode_signatures = self
+            .confirmed
+            .iter()
+            .map(|attestation| EldernodeSignature {
+                public_key: eldernodes
+                    .key(&attestation.eldernode_id)
+                    .map(|key| hex::encode(key.to_bytes()))
+                    .unwrap_or_default(),
+                eldernode_address: attestation.eldernode_id.clone(),
+                signature: attestation.signature.clone(),
+                timestamp: attestation.timestamp,
+            })
+            .collect();
+
+        EldernodeVerification {
+            merkle_proof: self.merkle_proof.clone().unwrap_or(MerkleProof {
+                root_hash: String::new(),
+                leaf_hash: String::new(),
+                proof_path: Vec::new(),
+                proof_indices: Vec::new(),
+            }),
+            eldernode_signatures,
+            consensus: ConsensusInfo {
+                eldernode_count: u32::try_from(self.confirmed.len()).unwrap_or(u32::MAX),
+                threshold_met: self.is_reached(),
+                consensus_type: self.threshold.to_string(),
+            },
+            metadata: VerificationMetadata {
+                verified_at,
+                network: network.to_string(),
+                version: "1.0.0".to_string(),
+            },
+        }
+    }
+}
+
+/// Checks Eldernode attestations against the trusted set
+#[derive(Debug, Clone)]
+pub struct EldernodeVerifier {
+    eldernodes: EldernodeSet,
+    threshold: ConsensusThreshold,
+}
+
+impl EldernodeVerifier {
+    /// Verifier requiring `threshold` confirmations; `threshold.total` must equal the set size
+    ///
+    /// # Errors
+    ///
+    /// [`EldernodeError::ThresholdMismatch`] if it does not
+    pub fn new(eldernodes: EldernodeSet, threshold: ConsensusThreshold) -> Result<Self, EldernodeError> {
+        if eldernodes.len() != threshold.total {
+            return Err(EldernodeError::ThresholdMismatch {
+                threshold,
+                registered: eldernodes.len(),
+            });
+        }
+        Ok(Self { eldernodes, threshold })
+    }
+
+    /// Trusted Eldernodes
+    #[must_use]
+    pub fn eldernodes(&self) -> &EldernodeSet {
+        &self.eldernodes
+    }
+
+    /// Confirmations required
+    #[must_use]
+    pub fn threshold(&self) -> ConsensusThreshold {
+        self.threshold
+    }
+
+    /// Sort attestations of the burn `inputs` describes into confirmed, disputed and rejected
+    #[must_use]
+    pub fn evaluate(&self, inputs: &EldernodeAttestationInputs, response: AttestationResponse) -> EldernodeConsensus {
+        let mut consensus = EldernodeConsensus {
+            threshold: self.threshold,
+            confirmed: Vec::new(),
+            disputed: Vec::new(),
+            rejected: Vec::new(),
+            merkle_proof: response.merkle_proof,
+        };
+        let mut seen = BTreeSet::new();
+
+        for attestation in response.attestations {
+            match self.check(inputs, &attestation) {
+                Err(reason) => consensus.rejected.push((attestation.eldernode_id, reason)),
+                Ok(()) if !seen.insert(attestation.eldernode_id.clone()) => {
+                    consensus.rejected.push((attestation.eldernode_id, AttestationRejection::Duplicate));
+                }
+                Ok(()) => {
+                    let matches = attestation.commitment_bytes() == Some(inputs.commitment)
+                        && attestation.burn_amount == inputs.burn_amount;
+                    if matches {
+                        consensus.confirmed.push(attestation);
+                    } else {
+                        consensus.disputed.push(attestation);
+                    }
+                }
+            }
+        }
+        consensus
+    }
+
+    /// Check an attestation's signer and signature
+    fn check(
+        &self,
+        inputs: &EldernodeAttestationInputs,
+        attestation: &EldernodeAttestation,
+    ) -> Result<(), AttestationRejection> {
+        let key = self
+            .eldernodes
+            .key(&attestation.eldernode_id)
+            .ok_or(AttestationRejection::UnknownEldernode)?;
+        let digest = attestation
+            .signing_digest(&inputs.transaction_hash)
+            .ok_or_else(|| AttestationRejection::Malformed("commitment must be 32 bytes".to_string()))?;
+        verify_attestation_signature(key, &digest, &attestation.signature)
+    }
+}
+
+/// Source of Eldernode attestations
+pub trait EldernodeClient {
+    /// Ask the Eldernodes to attest to the burn `inputs` describes
+    fn request_attestations(
+        &self,
+        inputs: &EldernodeAttestationInputs,
+    ) -> impl Future<Output = Result<AttestationResponse, EldernodeError>> + Send;
+}
+
+/// Eldernode client for the REST verification endpoint
+#[derive(Debug, Clone)]
+pub struct HttpEldernodeClient {
+    /// Verification endpoint URL
+    pub endpoint: String,
+    /// Timeout for the whole request
+    pub timeout: Duration,
+}
+
+impl HttpEldernodeClient {
+    /// Client for an endpoint URL with a 30 second timeout
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Set the request timeout
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// POST the attestation request and decode the response
+    fn post(&self, inputs: &EldernodeAttestationInputs) -> Result<AttestationResponse, EldernodeError> {
+        let request = serde_json::json!({
+            "transaction_hash": inputs.transaction_hash,
+            "commitment": hex::encode(inputs.commitment),
+            "burn_amount": inputs.burn_amount,
+        });
+        let response = ureq::AgentBuilder::new()
+            .timeout(self.timeout)
+            .build()
+            .post(&self.endpoint)
+            .send_json(request)
+            .map_err(|e| EldernodeError::Transport(e.to_string()))?;
+        serde_json::from_reader(response.into_reader().take(MAX_RESPONSE_BYTES))
+            .map_err(|e| EldernodeError::InvalidResponse(e.to_string()))
+    }
+}
+
+impl Default for HttpEldernodeClient {
+    fn default() -> Self {
+        Self::new(DEFAULT_ELDERNODE_ENDPOINT)
+    }
+}
+
+impl EldernodeClient for HttpEldernodeClient {
+    fn request_attestations(
+        &self,
+        inputs: &EldernodeAttestationInputs,
+    ) -> impl Future<Output = Result<AttestationResponse, EldernodeError>> + Send {
+        let client = self.clone();
+        let inputs = inputs.clone();
+        async move {
+            // ureq blocks, so the request runs off the async workers
+            tokio::task::spawn_blocking(move || client.post(&inputs))
+                .await
+                .map_err(|e| EldernodeError::Transport(e.to_string()))?
+        }
+    }
+}
+
+/// Collect attestations for a burn and require the verifier's threshold
+///
+/// Reports each consensus phase to `progress` when given.
+///
+/// # Errors
+///
+/// The client's error if the attestations cannot be collected, or
+/// [`EldernodeError::ThresholdNotMet`] if too few of them verify
+pub async fn reach_consensus<C: EldernodeClient>(
+    client: &C,
+    verifier: &EldernodeVerifier,
+    inputs: &EldernodeAttestationInputs,
+    progress: Option<&ProgressReporter>,
+) -> Result<EldernodeConsensus, EldernodeError> {
+    let report = |phase| {
+        if let Some(progress) = progress {
+            progress.consensus(phase);
+        }
+    };
+
+    report(ConsensusPhase::Sending);
+    report(ConsensusPhase::AwaitingConsensus);
+    let outcome = client.request_attestations(inputs).await.and_then(|response| {
+        report(ConsensusPhase::Responses {
+            responded: u32::try_from(response.attestations.len()).unwrap_or(u32::MAX),
+            total: u32::try_from(verifier.threshold().total).unwrap_or(u32::MAX),
+        });
+        let consensus = verifier.evaluate(inputs, response);
+        consensus.ensure_reached().map(|()| consensus)
+    });
+
+    match &outcome {
+        Ok(_) => report(ConsensusPhase::ConsensusReached),
+        Err(e) => report(ConsensusPhase::Failed(e.to_string())),
+    }
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TX_HASH: &str = "7d0725f8e03021b99560add456c596fea7d8df23529e23765e56923b73236e4d";
+
+    fn inputs() -> EldernodeAttestationInputs {
+        EldernodeAttestationInputs {
+            transaction_hash: TX_HASH.to_string(),
+            commitment: [0x42; 32],
+            burn_amount: 800_000_000,
+        }
+    }
+
+    /// Five trusted Eldernodes and their keys
+    fn eldernodes() -> (EldernodeSet, Vec<SigningKey>) {
+        let keys: Vec<SigningKey> = (1..=5u8).map(|i| SigningKey::from_bytes(&[i; 32])).collect();
+        let records: Vec<EldernodeRecord> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| EldernodeRecord {
+                eldernode_id: format!("elder{}", i + 1),
+                public_key: hex::encode(key.verifying_key().to_bytes()),
+            })
+            .collect();
+        (EldernodeSet::from_records(&records).unwrap(), keys)
+    }
+
+    fn attest(keys: &[SigningKey], index: usize, commitment: [u8; 32]) -> EldernodeAttestation {
+        let id = format!("elder{}", index + 1);
+        EldernodeAttestation::sign(&id, &keys[index], TX_HASH, commitment, 800_000_000, 1_705_312_200)
+    }
+
+    struct FixedClient(AttestationResponse);
+
+    impl EldernodeClient for FixedClient {
+        fn request_attestations(
+            &self,
+            _inputs: &EldernodeAttestationInputs,
+        ) -> impl Future<Output = Result<AttestationResponse, EldernodeError>> + Send {
+            let response = self.0.clone();
+            async move { Ok(response) }
+        }
+    }
+
+    #[test]
+    fn test_threshold_parsing() {
+        assert_eq!("3/5".parse::<ConsensusThreshold>().unwrap(), ConsensusThreshold::THREE_OF_FIVE);
+        assert_eq!(ConsensusThreshold::TWO_OF_TWO.to_string(), "2/2");
+        assert!("0/5".parse::<ConsensusThreshold>().is_err());
+        assert!("6/5".parse::<ConsensusThreshold>().is_err());
+        assert!("three".parse::<ConsensusThreshold>().is_err());
+
+        let (set, _) = eldernodes();
+        assert!(matches!(
+            EldernodeVerifier::new(set, ConsensusThreshold::TWO_OF_TWO),
+            Err(EldernodeError::ThresholdMismatch { registered: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn test_evaluate_sorts_attestations() {
+        let (set, keys) = eldernodes();
+        let verifier = EldernodeVerifier::new(set, ConsensusThreshold::THREE_OF_FIVE).unwrap();
+        let outsider = SigningKey::from_bytes(&[9; 32]);
+
+        let mut forged = attest(&keys, 3, [0x42; 32]);
+        forged.burn_amount += 1;
+        let mut other_burn = attest(&keys, 4, [0x42; 32]);
+        other_burn.signature =
+            EldernodeAttestation::sign("elder5", &keys[4], "00", [0x42; 32], 800_000_000, 1_705_312_200).signature;
+        let response = AttestationResponse {
+            attestations: vec![
+                attest(&keys, 0, [0x42; 32]),
+                attest(&keys, 1, [0x42; 32]),
+                attest(&keys, 1, [0x42; 32]),
+                attest(&keys, 2, [0x43; 32]),
+                forged,
+                other_burn,
+                EldernodeAttestation::sign("elder6", &outsider, TX_HASH, [0x42; 32], 800_000_000, 0),
+            ],
+            merkle_proof: None,
+        };
+
+        let consensus = verifier.evaluate(&inputs(), response);
+        assert_eq!(consensus.confirmed.len(), 2);
+        assert_eq!(consensus.disputed.len(), 1);
+        assert_eq!(
+            consensus.rejected,
+            vec![
+                ("elder2".to_string(), AttestationRejection::Duplicate),
+                ("elder4".to_string(), AttestationRejection::InvalidSignature),
+                ("elder5".to_string(), AttestationRejection::InvalidSignature),
+                ("elder6".to_string(), AttestationRejection::UnknownEldernode),
+            ]
+        );
+        assert!(matches!(
+            consensus.ensure_reached(),
+            Err(EldernodeError::ThresholdNotMet { confirmed: 2, required: 3, total: 5 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reach_consensus_reports_progress() {
+        let (set, keys) = eldernodes();
+        let verifier = EldernodeVerifier::new(set, ConsensusThreshold::THREE_OF_FIVE).unwrap();
+        let client = FixedClient(AttestationResponse {
+            attestations: (0..3).map(|i| attest(&keys, i, [0x42; 32])).collect(),
+            merkle_proof: None,
+        });
+        let progress = ProgressReporter::new();
+
+        let consensus = reach_consensus(&client, &verifier, &inputs(), Some(&progress)).await.unwrap();
+        assert!(consensus.is_reached());
+        assert_eq!(progress.snapshot().consensus, ConsensusPhase::ConsensusReached);
+
+        let record = consensus.to_verification(verifier.eldernodes(), "fuego-testnet", "now".to_string());
+        assert_eq!(record.eldernode_signatures.len(), 3);
+        assert_eq!(record.consensus.consensus_type, "3/5");
+        assert!(record.consensus.threshold_met);
+    }
+
+    #[tokio::test]
+    async fn test_http_client_posts_claim() {
+        use std::io::{BufRead, BufReader, Write};
+
+        let (_, keys) = eldernodes();
+        let response = AttestationResponse {
+            attestations: vec![attest(&keys, 0, [0x42; 32])],
+            merkle_proof: None,
+        };
+        let body = serde_json::to_string(&response).unwrap();
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/api/v1/verify", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+            let mut request = vec![0u8; content_length];
+            reader.read_exact(&mut request).unwrap();
+            let mut stream = stream;
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            serde_json::from_slice::<serde_json::Value>(&request).unwrap()
+        });
+
+        let client = HttpEldernodeClient::new(endpoint).with_timeout(Duration::from_secs(10));
+        let received = client.request_attestations(&inputs()).await.unwrap();
+        assert_eq!(received, response);
+
+        let request = server.join().unwrap();
+        assert_eq!(request["transaction_hash"], TX_HASH);
+        assert_eq!(request["commitment"], hex::encode([0x42; 32]));
+        assert_eq!(request["burn_amount"], 800_000_000);
+    }
+}
@@ -0,0 +1,237 @@
+//! Eldernode Signature Consensus
+//!
+//! An [`EldernodeVerification`] record keeps the Ed25519 signatures that confirmed a
+//! burn. Each signature covers the canonical attestation message, hashed with SHA-256:
+//!
+//! | Field | Encoding |
+//! |-------|----------|
+//! | Domain | [`ATTESTATION_DOMAIN`] |
+//! | Transaction hash | le64 length, then lowercase hex without `0x` |
+//! | HEAT commitment | 32 bytes |
+//! | Burn amount | le64, atomic units |
+//! | Timestamp | le64, UNIX seconds |
+//!
+//! [`verify_eldernode_signatures`] rebuilds that message from the package's own burn
+//! facts and each signature's timestamp, so a record copied from another burn, or one
+//! with an edited timestamp, does not verify. Every signer must be a distinct trusted
+//! Eldernode presenting its trusted key, and the verifier's threshold must be met.
+
+use super::{AttestationRejection, EldernodeError, EldernodeVerifier};
+use crate::proof_data_schema::EldernodeVerification;
+use crate::verification_context::EldernodeAttestationInputs;
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+
+/// Domain separator for attestation signing digests
+pub const ATTESTATION_DOMAIN: &[u8] = b"xfg-eldernode-attestation-v1";
+
+/// Digest of the canonical attestation message an Eldernode signs
+#[must_use]
+pub fn attestation_digest(transaction_hash: &str, commitment: &[u8; 32], burn_amount: u64, timestamp: u64) -> [u8; 32] {
+    let transaction_hash = transaction_hash.trim_start_matches("0x").to_ascii_lowercase();
+    let mut hasher = Sha256::new();
+    hasher.update(ATTESTATION_DOMAIN);
+    hasher.update((transaction_hash.len() as u64).to_le_bytes());
+    hasher.update(transaction_hash.as_bytes());
+    hasher.update(commitment);
+    hasher.update(burn_amount.to_le_bytes());
+    hasher.update(timestamp.to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// Check a hex Ed25519 signature over an attestation digest
+///
+/// # Errors
+///
+/// [`AttestationRejection::Malformed`] if `signature` is not a hex Ed25519 signature,
+/// or [`AttestationRejection::InvalidSignature`] if it does not verify under `key`
+pub fn verify_attestation_signature(
+    key: &VerifyingKey,
+    digest: &[u8; 32],
+    signature: &str,
+) -> Result<(), AttestationRejection> {
+    let signature = hex::decode(signature.trim_start_matches("0x"))
+        .map_err(|e| AttestationRejection::Malformed(e.to_string()))
+        .and_then(|bytes| Signature::from_slice(&bytes).map_err(|e| AttestationRejection::Malformed(e.to_string())))?;
+    key.verify(digest, &signature)
+        .map_err(|_| AttestationRejection::InvalidSignature)
+}
+
+/// Verify every signature of a record against the burn `inputs` describes
+///
+/// Returns the number of confirming Eldernodes. Any signature that does not count is an
+/// error rather than being skipped: a record only ever holds confirmations, so one that
+/// does not verify has been altered.
+///
+/// # Errors
+///
+/// [`EldernodeError::SignatureRejected`] for the first signature that is not a distinct
+/// trusted Eldernode's over the burn, or [`EldernodeError::ThresholdNotMet`] if too few
+/// Eldernodes signed
+pub fn verify_eldernode_signatures(
+    verification: &EldernodeVerification,
+    inputs: &EldernodeAttestationInputs,
+    verifier: &EldernodeVerifier,
+) -> Result<usize, EldernodeError> {
+    let mut signers = BTreeSet::new();
+    let mut keys = BTreeSet::new();
+
+    for signature in &verification.eldernode_signatures {
+        let reject = |reason| EldernodeError::SignatureRejected {
+            eldernode_id: signature.eldernode_address.clone(),
+            reason,
+        };
+        let key = verifier
+            .eldernodes()
+            .key(&signature.eldernode_address)
+            .ok_or_else(|| reject(AttestationRejection::UnknownEldernode))?;
+        let recorded_key = hex::decode(signature.public_key.trim_start_matches("0x"))
+            .map_err(|e| reject(AttestationRejection::Malformed(e.to_string())))?;
+        if recorded_key != key.to_bytes() {
+            return Err(reject(AttestationRejection::KeyMismatch));
+        }
+        // One Eldernode may not count twice, under its own ID or another one
+        if !signers.insert(signature.eldernode_address.as_str()) || !keys.insert(key.to_bytes()) {
+            return Err(reject(AttestationRejection::Duplicate));
+        }
+
+        let digest = attestation_digest(
+            &inputs.transaction_hash,
+            &inputs.commitment,
+            inputs.burn_amount,
+            signature.timestamp,
+        );
+        verify_attestation_signature(key, &digest, &signature.signature).map_err(reject)?;
+    }
+
+    let threshold = verifier.threshold();
+    if signers.len() < threshold.required {
+        return Err(EldernodeError::ThresholdNotMet {
+            confirmed: signers.len(),
+            required: threshold.required,
+            total: threshold.total,
+        });
+    }
+    Ok(signers.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eldernode::{
+        AttestationResponse, ConsensusThreshold, EldernodeAttestation, EldernodeSet,
+    };
+    use crate::proof_data_schema::{CompleteProofPackage, StarkProof, StarkProofDataPackage};
+    use crate::verification_context::VerificationContext;
+    use crate::XfgStarkError;
+    use ed25519_dalek::SigningKey;
+
+    fn package() -> CompleteProofPackage {
+        let mut package = CompleteProofPackage::new(StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "my-secret-key-123".to_string(),
+            "fuego-testnet".to_string(),
+        ));
+        package.add_stark_proof(StarkProof::new_dummy());
+        package
+    }
+
+    /// A 3/5 verifier and the Eldernode keys
+    fn verifier() -> (EldernodeVerifier, Vec<SigningKey>) {
+        let keys: Vec<SigningKey> = (1..=5u8).map(|i| SigningKey::from_bytes(&[i; 32])).collect();
+        let mut set = EldernodeSet::new();
+        for (i, key) in keys.iter().enumerate() {
+            set.add(&format!("elder{}", i + 1), key.verifying_key()).unwrap();
+        }
+        (EldernodeVerifier::new(set, ConsensusThreshold::THREE_OF_FIVE).unwrap(), keys)
+    }
+
+    /// Record of the first `count` Eldernodes attesting to `inputs`
+    fn record(
+        verifier: &EldernodeVerifier,
+        keys: &[SigningKey],
+        inputs: &EldernodeAttestationInputs,
+        count: usize,
+    ) -> EldernodeVerification {
+        let attestations = keys[..count]
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                EldernodeAttestation::sign(
+                    &format!("elder{}", i + 1),
+                    key,
+                    &inputs.transaction_hash,
+                    inputs.commitment,
+                    inputs.burn_amount,
+                    1_705_312_200 + i as u64,
+                )
+            })
+            .collect();
+        let consensus = verifier.evaluate(inputs, AttestationResponse { attestations, merkle_proof: None });
+        consensus.to_verification(verifier.eldernodes(), "fuego-testnet", "2024-01-15T09:50:00Z".to_string())
+    }
+
+    fn rejection(result: crate::Result<()>) -> AttestationRejection {
+        match result {
+            Err(XfgStarkError::EldernodeError(EldernodeError::SignatureRejected { reason, .. })) => reason,
+            other => panic!("expected a rejected signature, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_package_accepts_verified_consensus() {
+        let (verifier, keys) = verifier();
+        let mut package = package();
+        let inputs = VerificationContext::from_package(&package.stark_proof_data).unwrap().eldernode_inputs();
+
+        let mut verification = record(&verifier, &keys, &inputs, 3);
+        verification.consensus.threshold_met = false;
+        package.add_eldernode_verification(verification, &verifier).unwrap();
+        assert!(package.is_ready_for_contract());
+        let consensus = &package.get_eldernode_verification().unwrap().consensus;
+        assert_eq!((consensus.eldernode_count, consensus.threshold_met), (3, true));
+        assert_eq!(consensus.consensus_type, "3/5");
+
+        assert!(matches!(
+            package.add_eldernode_verification(record(&verifier, &keys, &inputs, 2), &verifier),
+            Err(XfgStarkError::EldernodeError(EldernodeError::ThresholdNotMet { confirmed: 2, required: 3, .. }))
+        ));
+    }
+
+    #[test]
+    fn test_package_rejects_altered_signatures() {
+        let (verifier, keys) = verifier();
+        let mut package = package();
+        let inputs = VerificationContext::from_package(&package.stark_proof_data).unwrap().eldernode_inputs();
+        let honest = record(&verifier, &keys, &inputs, 3);
+
+        let mut duplicated = honest.clone();
+        duplicated.eldernode_signatures[2] = duplicated.eldernode_signatures[0].clone();
+        assert_eq!(rejection(package.add_eldernode_verification(duplicated, &verifier)), AttestationRejection::Duplicate);
+
+        let mut retimed = honest.clone();
+        retimed.eldernode_signatures[1].timestamp += 1;
+        assert_eq!(
+            rejection(package.add_eldernode_verification(retimed, &verifier)),
+            AttestationRejection::InvalidSignature
+        );
+
+        let mut rekeyed = honest.clone();
+        rekeyed.eldernode_signatures[0].public_key = hex::encode(SigningKey::from_bytes(&[9; 32]).verifying_key().to_bytes());
+        assert_eq!(rejection(package.add_eldernode_verification(rekeyed, &verifier)), AttestationRejection::KeyMismatch);
+
+        // Signatures over another burn amount do not carry over
+        let other_burn = EldernodeAttestationInputs {
+            burn_amount: inputs.burn_amount + 1,
+            ..inputs.clone()
+        };
+        assert_eq!(
+            rejection(package.add_eldernode_verification(record(&verifier, &keys, &other_burn, 3), &verifier)),
+            AttestationRejection::InvalidSignature
+        );
+        assert!(!package.is_ready_for_contract());
+    }
+}
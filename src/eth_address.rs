@@ -0,0 +1,346 @@
+//! Ethereum Recipient Addresses
+//!
+//! [`EthAddress`] parses the recipient of a HEAT mint strictly: a `0x` prefix, exactly
+//! 40 hex digits, and a valid [EIP-55] checksum whenever the address mixes upper and
+//! lower case. All-lowercase and all-uppercase addresses carry no checksum and are
+//! accepted as is. Parsed addresses display in checksummed form.
+//!
+//! Packages may also name the recipient by ENS. With the `rpc` feature,
+//! [`EnsRpcResolver`] resolves names through an Ethereum JSON-RPC endpoint so schema
+//! validation can check that the name and the address agree. Names are lowercased
+//! before hashing; full UTS-46 normalization is left to the wallet that wrote them.
+//!
+//! [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+
+use crate::commitment::RECIPIENT_ADDRESS_LEN;
+use sha3::{Digest, Keccak256};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// A 20-byte Ethereum address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EthAddress([u8; RECIPIENT_ADDRESS_LEN]);
+
+impl EthAddress {
+    /// Wrap raw address bytes
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; RECIPIENT_ADDRESS_LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Raw address bytes
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; RECIPIENT_ADDRESS_LEN] {
+        &self.0
+    }
+
+    /// Check whether this is the zero address
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.0 == [0u8; RECIPIENT_ADDRESS_LEN]
+    }
+
+    /// EIP-55 checksummed form, `0x`-prefixed
+    #[must_use]
+    pub fn to_checksum_string(&self) -> String {
+        let lower = hex::encode(self.0);
+        let digest = Keccak256::digest(lower.as_bytes());
+
+        let mut checksummed = String::with_capacity(2 + lower.len());
+        checksummed.push_str("0x");
+        for (i, c) in lower.chars().enumerate() {
+            // Each hex digit is uppercased when the matching nibble of the hash is >= 8
+            let nibble = (digest[i / 2] >> (4 * (1 - i % 2))) & 0x0f;
+            if nibble >= 8 {
+                checksummed.push(c.to_ascii_uppercase());
+            } else {
+                checksummed.push(c);
+            }
+        }
+        checksummed
+    }
+}
+
+impl Display for EthAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_checksum_string())
+    }
+}
+
+impl FromStr for EthAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix("0x").ok_or(AddressError::MissingPrefix)?;
+
+        if let Some((index, character)) = digits.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            return Err(AddressError::InvalidHexCharacter {
+                character,
+                position: index + 2,
+            });
+        }
+        if digits.len() != 2 * RECIPIENT_ADDRESS_LEN {
+            return Err(AddressError::InvalidLength(digits.len()));
+        }
+
+        let mut bytes = [0u8; RECIPIENT_ADDRESS_LEN];
+        hex::decode_to_slice(digits, &mut bytes).map_err(|_| AddressError::InvalidLength(digits.len()))?;
+        let address = Self(bytes);
+
+        let has_lower = digits.chars().any(|c| c.is_ascii_lowercase());
+        let has_upper = digits.chars().any(|c| c.is_ascii_uppercase());
+        if has_lower && has_upper {
+            let expected = address.to_checksum_string();
+            if expected != s {
+                return Err(AddressError::ChecksumMismatch { expected });
+            }
+        }
+
+        Ok(address)
+    }
+}
+
+/// Reasons a string is not an Ethereum address
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AddressError {
+    /// Address lacks the `0x` prefix
+    #[error("must start with 0x")]
+    MissingPrefix,
+    /// Address has the wrong number of hex digits
+    #[error("must have 40 hex digits after 0x, found {0}")]
+    InvalidLength(usize),
+    /// Address contains a non-hex character
+    #[error("invalid hex character {character:?} at position {position}")]
+    InvalidHexCharacter {
+        /// Offending character
+        character: char,
+        /// Character offset in the address, counting the `0x` prefix
+        position: usize,
+    },
+    /// Mixed-case address whose capitalization is not its EIP-55 checksum
+    #[error("EIP-55 checksum mismatch (expected {expected})")]
+    ChecksumMismatch {
+        /// Correctly checksummed address
+        expected: String,
+    },
+}
+
+/// Check that an ENS name is syntactically usable: dot-separated non-empty labels
+///
+/// # Errors
+///
+/// [`EnsError::InvalidName`] for an empty label, a single label, or whitespace or control
+/// characters
+pub fn validate_ens_name(name: &str) -> Result<(), EnsError> {
+    let labels: Vec<&str> = name.split('.').collect();
+    if labels.len() < 2 || labels.iter().any(|label| label.is_empty()) {
+        return Err(EnsError::InvalidName(name.to_string()));
+    }
+    if name.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(EnsError::InvalidName(name.to_string()));
+    }
+    Ok(())
+}
+
+/// ENS namehash of a (lowercased) name
+#[must_use]
+pub fn ens_namehash(name: &str) -> [u8; 32] {
+    let mut node = [0u8; 32];
+    if name.is_empty() {
+        return node;
+    }
+    for label in name.to_lowercase().rsplit('.') {
+        let mut hasher = Keccak256::new();
+        hasher.update(node);
+        hasher.update(Keccak256::digest(label.as_bytes()));
+        node = hasher.finalize().into();
+    }
+    node
+}
+
+/// Resolves ENS names to addresses
+pub trait EnsResolver {
+    /// Address `name` resolves to, or `None` when it has no address record
+    ///
+    /// # Errors
+    ///
+    /// [`EnsError`] if `name` is invalid or the lookup fails
+    fn resolve(&self, name: &str) -> Result<Option<EthAddress>, EnsError>;
+}
+
+/// ENS resolution errors
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum EnsError {
+    /// Name is not a dot-separated sequence of labels
+    #[error("invalid ENS name {0:?}")]
+    InvalidName(String),
+    /// Resolver endpoint failed or returned garbage
+    #[error("ENS lookup failed: {0}")]
+    Lookup(String),
+}
+
+/// ENS registry address, identical on mainnet and the public testnets
+pub const ENS_REGISTRY_ADDRESS: &str = "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e";
+
+/// [`ENS_REGISTRY_ADDRESS`] as bytes
+#[cfg(feature = "rpc")]
+const ENS_REGISTRY: EthAddress = EthAddress::from_bytes([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x2e, 0x07, 0x4e, 0xc6, 0x9a, 0x0d, 0xfb, 0x29, 0x97, 0xba, 0x6c, 0x7d, 0x2e, 0x1e,
+]);
+
+/// `resolver(bytes32)` selector on the ENS registry
+#[cfg(feature = "rpc")]
+const RESOLVER_SELECTOR: &str = "0178b8bf";
+
+/// `addr(bytes32)` selector on ENS resolvers
+#[cfg(feature = "rpc")]
+const ADDR_SELECTOR: &str = "3b3b57de";
+
+/// ENS resolver backed by an Ethereum JSON-RPC endpoint
+#[cfg(feature = "rpc")]
+#[derive(Debug, Clone)]
+pub struct EnsRpcResolver {
+    /// JSON-RPC endpoint URL
+    pub url: String,
+    /// ENS registry contract
+    pub registry: EthAddress,
+}
+
+#[cfg(feature = "rpc")]
+impl EnsRpcResolver {
+    /// Create a resolver for an endpoint, using the canonical ENS registry
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            registry: ENS_REGISTRY,
+        }
+    }
+
+    /// Use a different ENS registry contract
+    #[must_use]
+    pub fn with_registry(mut self, registry: EthAddress) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// `eth_call` a single-`bytes32` function and read the address it returns
+    fn call_address(&self, to: &EthAddress, selector: &str, node: &[u8; 32]) -> Result<EthAddress, EnsError> {
+        use serde_json::{json, Value};
+
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_call",
+            "params": [
+                { "to": to.to_string(), "data": format!("0x{}{}", selector, hex::encode(node)) },
+                "latest",
+            ],
+        });
+
+        let response: Value = ureq::post(&self.url)
+            .send_json(request)
+            .map_err(|e| EnsError::Lookup(e.to_string()))?
+            .into_json()
+            .map_err(|e| EnsError::Lookup(format!("Invalid response: {e}")))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(EnsError::Lookup(error.to_string()));
+        }
+
+        let result = response
+            .get("result")
+            .and_then(Value::as_str)
+            .ok_or_else(|| EnsError::Lookup("Response has no result".to_string()))?;
+        let word = hex::decode(result.trim_start_matches("0x")).map_err(|e| EnsError::Lookup(e.to_string()))?;
+        if word.len() != 32 {
+            return Err(EnsError::Lookup(format!("Expected a 32-byte word, got {} bytes", word.len())));
+        }
+
+        let mut address = [0u8; RECIPIENT_ADDRESS_LEN];
+        address.copy_from_slice(&word[32 - RECIPIENT_ADDRESS_LEN..]);
+        Ok(EthAddress::from_bytes(address))
+    }
+}
+
+#[cfg(feature = "rpc")]
+impl EnsResolver for EnsRpcResolver {
+    fn resolve(&self, name: &str) -> Result<Option<EthAddress>, EnsError> {
+        validate_ens_name(name)?;
+        let node = ens_namehash(name);
+
+        let resolver = self.call_address(&self.registry, RESOLVER_SELECTOR, &node)?;
+        if resolver.is_zero() {
+            return Ok(None);
+        }
+        let address = self.call_address(&resolver, ADDR_SELECTOR, &node)?;
+        Ok(if address.is_zero() { None } else { Some(address) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eip55_checksums() {
+        // Test vectors from EIP-55
+        for address in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            let parsed: EthAddress = address.parse().unwrap();
+            assert_eq!(parsed.to_string(), address);
+            assert_eq!(address.to_lowercase().parse::<EthAddress>(), Ok(parsed));
+            assert_eq!(format!("0x{}", address[2..].to_uppercase()).parse::<EthAddress>(), Ok(parsed));
+        }
+
+        assert_eq!(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".parse::<EthAddress>(),
+            Err(AddressError::ChecksumMismatch {
+                expected: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_malformed_addresses() {
+        assert_eq!(
+            "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".parse::<EthAddress>(),
+            Err(AddressError::MissingPrefix)
+        );
+        assert_eq!("0x5aaeb6".parse::<EthAddress>(), Err(AddressError::InvalidLength(6)));
+        assert_eq!(
+            format!("0x{}g", "a".repeat(39)).parse::<EthAddress>(),
+            Err(AddressError::InvalidHexCharacter { character: 'g', position: 41 })
+        );
+        assert_eq!(
+            format!("0x{}", "a".repeat(42)).parse::<EthAddress>(),
+            Err(AddressError::InvalidLength(42))
+        );
+    }
+
+    #[test]
+    fn test_ens_namehash() {
+        assert_eq!(ens_namehash(""), [0u8; 32]);
+        assert_eq!(
+            hex::encode(ens_namehash("eth")),
+            "93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae"
+        );
+        assert_eq!(
+            hex::encode(ens_namehash("foo.eth")),
+            "de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f"
+        );
+        assert_eq!(ens_namehash("Foo.ETH"), ens_namehash("foo.eth"));
+
+        assert!(validate_ens_name("alice.eth").is_ok());
+        assert!(validate_ens_name("alice").is_err());
+        assert!(validate_ens_name("alice..eth").is_err());
+        assert!(validate_ens_name("al ice.eth").is_err());
+
+        #[cfg(feature = "rpc")]
+        assert_eq!(EnsRpcResolver::new("").registry.to_string(), ENS_REGISTRY_ADDRESS);
+    }
+}
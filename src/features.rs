@@ -0,0 +1,240 @@
+//! Cargo Feature Introspection and Minimal-Build Checks
+//!
+//! This module reports which optional subsystems were compiled in and provides a
+//! check that the proving core works on its own. Because `check_minimal_build` only
+//! touches modules that are always compiled, building and testing the crate with
+//! `--no-default-features --features std` verifies the minimal build locally, without CI.
+
+use crate::{burn_mint_prover::{BurnMintRequest, XfgBurnMintProver}, burn_mint_verifier::XfgBurnMintVerifier, Result};
+use std::fmt::{Display, Formatter};
+
+/// Optional subsystem behind a cargo feature
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    /// Data package schema, reports, stores and commitment audits
+    Packages,
+    /// Ed25519 package signing
+    Signing,
+    /// ECIES package encryption to the recipient key
+    Encryption,
+    /// Fuego daemon RPC
+    Rpc,
+    /// S3-compatible package store
+    S3,
+    /// Streaming and HTTP proof verification servers
+    Server,
+    /// Proof generation and consensus progress channel
+    Progress,
+    /// Eldernode attestation client and consensus checks
+    Eldernode,
+    /// zstd trace dumps and the CLI `--dump-trace` flag
+    Debug,
+    /// zstd proof compression
+    Compression,
+    /// TOML custom network definitions
+    Networks,
+    /// Command-line binaries
+    Cli,
+    /// Browser verification bindings
+    Wasm,
+    /// Multi-threaded FRI and Merkle tree construction
+    Parallel,
+    /// AVX2/NEON batch field arithmetic
+    Simd,
+    /// Property-testing generators
+    Testing,
+}
+
+impl Feature {
+    /// Every optional subsystem, in display order
+    pub const ALL: [Feature; 16] = [
+        Feature::Packages,
+        Feature::Signing,
+        Feature::Encryption,
+        Feature::Rpc,
+        Feature::S3,
+        Feature::Server,
+        Feature::Progress,
+        Feature::Eldernode,
+        Feature::Debug,
+        Feature::Compression,
+        Feature::Networks,
+        Feature::Cli,
+        Feature::Wasm,
+        Feature::Parallel,
+        Feature::Simd,
+        Feature::Testing,
+    ];
+
+    /// Cargo feature name
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Feature::Packages => "packages",
+            Feature::Signing => "signing",
+            Feature::Encryption => "encryption",
+            Feature::Rpc => "rpc",
+            Feature::S3 => "s3",
+            Feature::Server => "server",
+            Feature::Progress => "progress",
+            Feature::Eldernode => "eldernode",
+            Feature::Debug => "debug",
+            Feature::Compression => "compression",
+            Feature::Networks => "networks",
+            Feature::Cli => "cli",
+            Feature::Wasm => "wasm",
+            Feature::Parallel => "parallel",
+            Feature::Simd => "simd",
+            Feature::Testing => "testing",
+        }
+    }
+
+    /// Check whether the current build compiles the feature in
+    const fn is_compiled(self) -> bool {
+        match self {
+            Feature::Packages => cfg!(feature = "packages"),
+            Feature::Signing => cfg!(feature = "signing"),
+            Feature::Encryption => cfg!(feature = "encryption"),
+            Feature::Rpc => cfg!(feature = "rpc"),
+            Feature::S3 => cfg!(feature = "s3"),
+            Feature::Server => cfg!(feature = "server"),
+            Feature::Progress => cfg!(feature = "progress"),
+            Feature::Eldernode => cfg!(feature = "eldernode"),
+            Feature::Debug => cfg!(feature = "debug"),
+            Feature::Compression => cfg!(feature = "compression"),
+            Feature::Networks => cfg!(feature = "networks"),
+            Feature::Cli => cfg!(feature = "cli"),
+            Feature::Wasm => cfg!(feature = "wasm"),
+            Feature::Parallel => cfg!(feature = "parallel"),
+            Feature::Simd => cfg!(feature = "simd"),
+            Feature::Testing => cfg!(feature = "testing"),
+        }
+    }
+
+    const fn bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// Optional subsystems compiled into this build
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureSet(u32);
+
+impl FeatureSet {
+    /// Features of the current build
+    #[must_use]
+    pub const fn current() -> Self {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < Feature::ALL.len() {
+            if Feature::ALL[i].is_compiled() {
+                bits |= Feature::ALL[i].bit();
+            }
+            i += 1;
+        }
+        Self(bits)
+    }
+
+    /// Check whether a feature is enabled
+    #[must_use]
+    pub const fn contains(&self, feature: Feature) -> bool {
+        self.0 & feature.bit() != 0
+    }
+
+    /// Check whether no optional subsystem is enabled
+    #[must_use]
+    pub const fn is_minimal(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Display for FeatureSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let enabled: Vec<&str> = Feature::ALL
+            .iter()
+            .filter(|feature| self.contains(**feature))
+            .map(|feature| feature.name())
+            .collect();
+
+        if enabled.is_empty() {
+            write!(f, "minimal")
+        } else {
+            write!(f, "{}", enabled.join(", "))
+        }
+    }
+}
+
+/// Prove and verify a standard burn using only the always-compiled proving core
+///
+/// # Errors
+///
+/// Proving and verification errors, or [`crate::XfgStarkError::CryptoError`] if the proof
+/// fails to verify
+pub fn check_minimal_build() -> Result<()> {
+    let prover = XfgBurnMintProver::default();
+    let verifier = XfgBurnMintVerifier::default();
+
+    let (proof, public_inputs) = prover.prove_burn_mint_with_public_inputs(
+        &BurnMintRequest {
+            burn_amount: 8_000_000,
+            mint_amount: 8_000_000,
+            tx_prefix_hash: [1u8; 32],
+            recipient_address: &[0x12u8; 20],
+            network_id: 1,
+            target_chain_id: 42161,
+            commitment_version: 1,
+        },
+        &[7u8; 32],
+    )?;
+
+    if verifier.verify_with_public_inputs(&proof, &public_inputs)? {
+        Ok(())
+    } else {
+        Err(crate::XfgStarkError::CryptoError(
+            "Minimal build check proof failed verification".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_implications() {
+        let features = FeatureSet::current();
+        let has = |feature| features.contains(feature);
+        assert!(!has(Feature::Signing) || has(Feature::Packages));
+        assert!(!has(Feature::Encryption) || has(Feature::Packages));
+        assert!(!has(Feature::Rpc) || has(Feature::Packages));
+        assert!(!has(Feature::S3) || has(Feature::Packages));
+        assert!(!has(Feature::Eldernode) || (has(Feature::Signing) && has(Feature::Progress)));
+        assert!(
+            !has(Feature::Cli)
+                || [
+                    Feature::Packages,
+                    Feature::Signing,
+                    Feature::Encryption,
+                    Feature::Rpc,
+                    Feature::Server,
+                    Feature::Progress,
+                    Feature::Eldernode,
+                    Feature::Networks,
+                ]
+                .into_iter()
+                .all(has)
+        );
+    }
+
+    #[test]
+    fn test_minimal_build_check() {
+        check_minimal_build().expect("Proving core should work in every build");
+    }
+
+    #[cfg(not(feature = "packages"))]
+    #[test]
+    fn test_minimal_build_reports_minimal() {
+        assert!(FeatureSet::current().is_minimal());
+        assert_eq!(FeatureSet::current().to_string(), "minimal");
+    }
+}
@@ -0,0 +1,205 @@
+//! Protocol Fee Accounting for Burn & Mint
+//!
+//! Fee-enabled networks take a protocol fee, in basis points, out of every mint:
+//!
+//! `mint = burn - floor(burn * fee_bps / 10000)`
+//!
+//! The fee rate is carried as a public input and bounded by [`MAX_PROTOCOL_FEE_BPS`].
+//! The burn & mint AIR constrains the fee inside the trace with one limb of division
+//! with remainder, `burn * fee_bps = (burn - mint) * 10000 + r`, and range-checks `r`
+//! and `9999 - r` so the remainder is below the denominator (see
+//! [`crate::burn_mint_air`]). A proof therefore only verifies when the claimed mint
+//! honours the fee. Off the trace, the product `burn * fee_bps` is formed over 128-bit
+//! integers rather than in the field, so the floor division is exact for every 64-bit
+//! burn amount.
+
+use thiserror::Error;
+
+/// Basis points in one whole (100%)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Highest protocol fee any network may charge (basis points)
+pub const MAX_PROTOCOL_FEE_BPS: u32 = 500;
+
+/// Fee accounting errors
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FeeError {
+    /// Fee rate exceeds the protocol bound
+    #[error("Protocol fee of {fee_bps} bps exceeds the maximum of {max_bps} bps")]
+    FeeTooHigh {
+        /// Requested fee (basis points)
+        fee_bps: u64,
+        /// Protocol maximum (basis points)
+        max_bps: u32,
+    },
+    /// Fee rate in the public inputs differs from the network's fee
+    #[error("Proof charges a {actual} bps fee but the network charges {expected} bps")]
+    RateMismatch {
+        /// Network fee rate (basis points)
+        expected: u32,
+        /// Fee rate carried in the public inputs (basis points)
+        actual: u64,
+    },
+    /// Mint amount does not match the burn after the protocol fee
+    #[error("Mint amount {actual} does not match {expected} expected after a {fee_bps} bps fee")]
+    MintMismatch {
+        /// Mint amount the burn entitles the recipient to
+        expected: u64,
+        /// Claimed mint amount
+        actual: u64,
+        /// Fee rate applied (basis points)
+        fee_bps: u32,
+    },
+}
+
+/// Protocol fee model for a network
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FeeModel {
+    fee_bps: u32,
+}
+
+impl FeeModel {
+    /// Fee model with no protocol fee (1:1 conversion)
+    #[must_use]
+    pub const fn none() -> Self {
+        Self { fee_bps: 0 }
+    }
+
+    /// Fee model charging `fee_bps` basis points, bounded by the protocol maximum
+    ///
+    /// # Errors
+    ///
+    /// [`FeeError::FeeTooHigh`] if `fee_bps` exceeds [`MAX_PROTOCOL_FEE_BPS`]
+    pub fn new(fee_bps: u32) -> Result<Self, FeeError> {
+        check_fee_bps(u64::from(fee_bps))?;
+        Ok(Self { fee_bps })
+    }
+
+    /// Fee rate (basis points)
+    #[must_use]
+    pub const fn fee_bps(&self) -> u32 {
+        self.fee_bps
+    }
+
+    /// Protocol fee taken from a burn (atomic units)
+    #[must_use]
+    pub fn fee(&self, burn_amount: u64) -> u64 {
+        protocol_fee(burn_amount, self.fee_bps)
+    }
+
+    /// Mint amount a burn entitles the recipient to (atomic units)
+    #[must_use]
+    pub fn mint_amount(&self, burn_amount: u64) -> u64 {
+        burn_amount - self.fee(burn_amount)
+    }
+
+    /// Check that a public-input fee rate matches this model
+    ///
+    /// # Errors
+    ///
+    /// [`FeeError::FeeTooHigh`] if `fee_bps` exceeds the protocol maximum, and
+    /// [`FeeError::RateMismatch`] if it differs from this model's rate
+    pub fn check_rate(&self, fee_bps: u64) -> Result<(), FeeError> {
+        check_fee_bps(fee_bps)?;
+        if fee_bps == u64::from(self.fee_bps) {
+            Ok(())
+        } else {
+            Err(FeeError::RateMismatch {
+                expected: self.fee_bps,
+                actual: fee_bps,
+            })
+        }
+    }
+
+    /// Check that a claimed mint honours the fee
+    ///
+    /// # Errors
+    ///
+    /// [`FeeError::MintMismatch`] if the mint is not the burn less the fee
+    pub fn check_mint(&self, burn_amount: u64, mint_amount: u64) -> Result<(), FeeError> {
+        let expected = self.mint_amount(burn_amount);
+        if mint_amount == expected {
+            Ok(())
+        } else {
+            Err(FeeError::MintMismatch {
+                expected,
+                actual: mint_amount,
+                fee_bps: self.fee_bps,
+            })
+        }
+    }
+}
+
+/// Check a fee rate against the protocol bound
+///
+/// # Errors
+///
+/// [`FeeError::FeeTooHigh`] if `fee_bps` exceeds [`MAX_PROTOCOL_FEE_BPS`]
+pub fn check_fee_bps(fee_bps: u64) -> Result<(), FeeError> {
+    if fee_bps > u64::from(MAX_PROTOCOL_FEE_BPS) {
+        return Err(FeeError::FeeTooHigh {
+            fee_bps,
+            max_bps: MAX_PROTOCOL_FEE_BPS,
+        });
+    }
+    Ok(())
+}
+
+/// Protocol fee `floor(burn_amount * fee_bps / 10000)` in atomic units
+#[must_use]
+pub fn protocol_fee(burn_amount: u64, fee_bps: u32) -> u64 {
+    let fee = u128::from(burn_amount) * u128::from(fee_bps) / u128::from(BPS_DENOMINATOR);
+    // fee_bps <= 10000 keeps the quotient within the burn amount
+    u64::try_from(fee).map_or(burn_amount, |fee| fee.min(burn_amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_rounds_down() {
+        let model = FeeModel::new(50).unwrap();
+        assert_eq!(model.fee(8_000_000), 40_000);
+        assert_eq!(model.mint_amount(8_000_000), 7_960_000);
+        assert_eq!(model.mint_amount(8_000_000_000), 7_960_000_000);
+
+        // 0.5% of 199 atomic units is 0.995, which floors to 0
+        assert_eq!(model.fee(199), 0);
+        assert_eq!(model.fee(200), 1);
+    }
+
+    #[test]
+    fn test_no_fee_is_one_to_one() {
+        let model = FeeModel::none();
+        assert_eq!(model.mint_amount(8_000_000), 8_000_000);
+        assert!(model.check_mint(8_000_000, 8_000_000).is_ok());
+    }
+
+    #[test]
+    fn test_fee_bounds_and_mismatch() {
+        assert!(FeeModel::new(MAX_PROTOCOL_FEE_BPS).is_ok());
+        assert_eq!(
+            FeeModel::new(MAX_PROTOCOL_FEE_BPS + 1),
+            Err(FeeError::FeeTooHigh {
+                fee_bps: u64::from(MAX_PROTOCOL_FEE_BPS) + 1,
+                max_bps: MAX_PROTOCOL_FEE_BPS,
+            })
+        );
+
+        let model = FeeModel::new(50).unwrap();
+        assert!(model.check_rate(50).is_ok());
+        assert_eq!(
+            model.check_rate(0),
+            Err(FeeError::RateMismatch { expected: 50, actual: 0 })
+        );
+        assert_eq!(
+            model.check_mint(8_000_000, 8_000_000),
+            Err(FeeError::MintMismatch {
+                expected: 7_960_000,
+                actual: 8_000_000,
+                fee_bps: 50,
+            })
+        );
+    }
+}
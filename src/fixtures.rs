@@ -0,0 +1,73 @@
+//! Golden Fixture Regeneration
+//!
+//! Several tests compare this crate's output with files kept under `tests/fixtures/`:
+//!
+//! - `kat/burn_mint.json`: known-answer vectors, see [`crate::test_data_generator`]
+//! - `proofs/winterfell-<version>/`: proofs in the pinned Winterfell encoding, see
+//!   [`crate::proof_compat`]
+//! - `public_inputs/layout-v<version>.json`: public input vectors, see
+//!   [`crate::public_input_layout`]
+//! - `solidity/`: the generated verifier library and its expected outcomes, see
+//!   [`crate::codegen::solidity`]
+//!
+//! After an intentional change to any of them, rewrite them all with
+//!
+//! ```text
+//! cargo run --features testing --bin regenerate-fixtures
+//! ```
+//!
+//! and commit the diff. A public input layout change bumps
+//! [`crate::public_input_layout::PUBLIC_INPUT_LAYOUT_VERSION`] first, so the vectors of
+//! the previous layout are kept beside the new ones.
+
+use crate::test_data_generator::{TestDataGenerator, KAT_SEEDS};
+use crate::{codegen, proof_compat, public_input_layout, Result};
+use std::path::{Path, PathBuf};
+
+/// Directory the fixtures are kept in, `tests/fixtures/` of this crate's source tree
+#[must_use]
+pub fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// Rewrite every fixture under `root`
+///
+/// # Errors
+///
+/// [`crate::XfgStarkError`] if a fixture cannot be generated or written
+pub fn regenerate(root: &Path) -> Result<()> {
+    // The Solidity outcomes are computed from the known-answer vectors, so those go first
+    std::fs::create_dir_all(root.join("kat"))?;
+    TestDataGenerator::write_kat(root.join("kat/burn_mint.json"), &KAT_SEEDS)?;
+    proof_compat::fixtures::write(root)?;
+    public_input_layout::fixtures::write(root)?;
+    codegen::solidity::fixtures::write(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regenerate_reproduces_fixtures() {
+        let root = std::env::temp_dir().join(format!("xfg-fixtures-{}", std::process::id()));
+        regenerate(&root).unwrap();
+
+        for file in [
+            "kat/burn_mint.json",
+            "proofs/winterfell-0.8/blake3.bin",
+            "proofs/winterfell-0.8/keccak.bin",
+            "proofs/winterfell-0.8/rescue.bin",
+            "public_inputs/layout-v2.json",
+            "solidity/XfgBurnMintVerifier.sol",
+            "solidity/outcomes.json",
+        ] {
+            assert_eq!(
+                std::fs::read(root.join(file)).unwrap(),
+                std::fs::read(fixtures_dir().join(file)).unwrap(),
+                "{file} differs from the checked-in fixture"
+            );
+        }
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,286 @@
+//! Fuego Daemon RPC
+//!
+//! Looks burn transactions up on a Fuego daemon over its JSON-RPC interface
+//! (`f_transaction_json`) and reads the two facts a burn proof depends on: the HEAT
+//! commitment in `tx_extra` (tag `0x08`, see [`crate::commitment_audit`]) and the burned
+//! amount, carried by the outputs whose key is undefined (absent or all zero), since
+//! nobody can spend them.
+//!
+//! `CryptoNote` splits amounts into denominations, so a burn may span several undefined
+//! outputs; [`FuegoTransaction::burn_amount`] is their sum.
+
+use crate::commitment_audit::{extract_heat_commitment, AuditError};
+use serde_json::{json, Value};
+
+/// Fuego daemon RPC error types
+#[derive(Debug, thiserror::Error)]
+pub enum FuegoRpcError {
+    /// Daemon unreachable or answered with an HTTP error
+    #[error("Fuego RPC transport error: {0}")]
+    Transport(String),
+
+    /// Daemon answered with a JSON-RPC error
+    #[error("Fuego RPC error: {0}")]
+    Rpc(String),
+
+    /// Response is not a transaction
+    #[error("Invalid Fuego RPC response: {0}")]
+    InvalidResponse(String),
+
+    /// Transaction has no output with an undefined key
+    #[error("Transaction {0} has no burn output")]
+    MissingBurnOutput(String),
+}
+
+/// Transaction output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxOutput {
+    /// Amount in atomic units
+    pub amount: u64,
+    /// Output public key (hex string), `None` if the output has none
+    pub key: Option<String>,
+}
+
+impl TxOutput {
+    /// Check whether the output key is undefined, so the output is burned
+    #[must_use]
+    pub fn is_burn(&self) -> bool {
+        self.key
+            .as_deref()
+            .is_none_or(|key| key.is_empty() || key.bytes().all(|digit| digit == b'0'))
+    }
+}
+
+/// Burn-relevant fields of a Fuego transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuegoTransaction {
+    /// Transaction hash (hex string)
+    pub hash: String,
+    /// Raw `tx_extra` bytes
+    pub extra: Vec<u8>,
+    /// Outputs in transaction order
+    pub outputs: Vec<TxOutput>,
+}
+
+impl FuegoTransaction {
+    /// Parse the `result` of an `f_transaction_json` response
+    ///
+    /// # Errors
+    ///
+    /// [`FuegoRpcError::InvalidResponse`] if a field is missing or malformed
+    pub fn from_rpc_result(hash: &str, result: &Value) -> Result<Self, FuegoRpcError> {
+        let invalid = |reason: &str| FuegoRpcError::InvalidResponse(reason.to_string());
+        let tx = result.get("tx").ok_or_else(|| invalid("response has no tx field"))?;
+
+        let extra = tx
+            .get("extra")
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid("transaction has no extra field"))?;
+        let extra = hex::decode(extra).map_err(|e| FuegoRpcError::InvalidResponse(format!("tx.extra: {e}")))?;
+
+        let outputs = tx
+            .get("vout")
+            .and_then(Value::as_array)
+            .ok_or_else(|| invalid("transaction has no vout field"))?
+            .iter()
+            .map(|output| {
+                let amount = output
+                    .get("amount")
+                    .and_then(Value::as_u64)
+                    .ok_or_else(|| invalid("output has no amount"))?;
+                let key = output
+                    .pointer("/target/data/key")
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+                Ok(TxOutput { amount, key })
+            })
+            .collect::<Result<_, FuegoRpcError>>()?;
+
+        Ok(Self {
+            hash: hash.trim_start_matches("0x").to_string(),
+            extra,
+            outputs,
+        })
+    }
+
+    /// HEAT commitment recorded in `tx_extra`
+    ///
+    /// # Errors
+    ///
+    /// As [`extract_heat_commitment`]
+    pub fn heat_commitment(&self) -> Result<[u8; 32], AuditError> {
+        extract_heat_commitment(&self.extra)
+    }
+
+    /// Total amount of the outputs with an undefined key (atomic units)
+    ///
+    /// # Errors
+    ///
+    /// [`FuegoRpcError::MissingBurnOutput`] if there are none, or
+    /// [`FuegoRpcError::InvalidResponse`] if their total overflows
+    pub fn burn_amount(&self) -> Result<u64, FuegoRpcError> {
+        let mut burns = self.outputs.iter().filter(|output| output.is_burn()).peekable();
+        if burns.peek().is_none() {
+            return Err(FuegoRpcError::MissingBurnOutput(self.hash.clone()));
+        }
+        burns.try_fold(0u64, |total, output| total.checked_add(output.amount)).ok_or_else(|| {
+            FuegoRpcError::InvalidResponse(format!("burn outputs of {} overflow u64", self.hash))
+        })
+    }
+}
+
+/// Minimal Fuego daemon RPC client
+#[derive(Debug, Clone)]
+pub struct FuegoRpcClient {
+    /// Daemon base URL (e.g. `http://127.0.0.1:18180`)
+    pub url: String,
+}
+
+impl FuegoRpcClient {
+    /// Create a client for a daemon base URL
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Fetch a transaction by hash
+    ///
+    /// # Errors
+    ///
+    /// [`FuegoRpcError::Transport`] if the node cannot be reached, [`FuegoRpcError::Rpc`] if
+    /// it answers with an error, or [`FuegoRpcError::InvalidResponse`] if the answer does
+    /// not parse
+    pub fn get_transaction(&self, transaction_hash: &str) -> Result<FuegoTransaction, FuegoRpcError> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": "xfg-stark",
+            "method": "f_transaction_json",
+            "params": { "hash": transaction_hash.trim_start_matches("0x") },
+        });
+
+        let response: Value = ureq::post(&format!("{}/json_rpc", self.url))
+            .send_json(request)
+            .map_err(|e| FuegoRpcError::Transport(e.to_string()))?
+            .into_json()
+            .map_err(|e| FuegoRpcError::InvalidResponse(e.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(FuegoRpcError::Rpc(error.to_string()));
+        }
+        let result = response
+            .get("result")
+            .ok_or_else(|| FuegoRpcError::InvalidResponse("response has no result field".to_string()))?;
+        FuegoTransaction::from_rpc_result(transaction_hash, result)
+    }
+
+    /// Fetch the raw `tx_extra` of a transaction
+    ///
+    /// # Errors
+    ///
+    /// [`AuditError::Rpc`] wrapping the error of [`Self::get_transaction`]
+    pub fn fetch_tx_extra(&self, transaction_hash: &str) -> Result<Vec<u8>, AuditError> {
+        self.get_transaction(transaction_hash)
+            .map(|tx| tx.extra)
+            .map_err(|e| AuditError::Rpc(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commitment_audit::HEAT_COMMITMENT_TAG;
+
+    const TX_HASH: &str = "7d0725f8e03021b99560add456c596fea7d8df23529e23765e56923b73236e4d";
+
+    /// `f_transaction_json` result for a burn of 800 XFG split into two denominations
+    fn burn_result() -> Value {
+        let mut extra = vec![0x01];
+        extra.extend_from_slice(&[0x11; 32]);
+        extra.push(HEAT_COMMITMENT_TAG);
+        extra.extend_from_slice(&[0xab; 32]);
+        json!({
+            "tx": {
+                "version": 1,
+                "extra": hex::encode(extra),
+                "vout": [
+                    { "amount": 700_000_000u64, "target": { "type": "02", "data": { "key": "00".repeat(32) } } },
+                    { "amount": 100_000_000u64, "target": { "type": "02", "data": {} } },
+                    { "amount": 5_000_000u64, "target": { "type": "02", "data": { "key": "ef".repeat(32) } } },
+                ],
+            },
+            "status": "OK",
+        })
+    }
+
+    #[test]
+    fn test_parse_burn_transaction() {
+        let tx = FuegoTransaction::from_rpc_result(&format!("0x{}", TX_HASH), &burn_result()).unwrap();
+        assert_eq!(tx.hash, TX_HASH);
+        assert_eq!(tx.heat_commitment().unwrap(), [0xab; 32]);
+        assert_eq!(tx.burn_amount().unwrap(), 800_000_000);
+        assert_eq!(tx.outputs.iter().filter(|output| output.is_burn()).count(), 2);
+
+        let mut spendable = tx.clone();
+        spendable.outputs.retain(|output| !output.is_burn());
+        assert!(matches!(spendable.burn_amount(), Err(FuegoRpcError::MissingBurnOutput(_))));
+
+        let mut no_vout = burn_result();
+        no_vout["tx"].as_object_mut().unwrap().remove("vout");
+        assert!(matches!(
+            FuegoTransaction::from_rpc_result(TX_HASH, &no_vout),
+            Err(FuegoRpcError::InvalidResponse(_))
+        ));
+    }
+
+    #[test]
+    fn test_client_fetches_transaction() {
+        use std::io::{BufRead, BufReader, Read, Write};
+
+        let responses = [
+            json!({ "jsonrpc": "2.0", "id": "xfg-stark", "result": burn_result() }),
+            json!({ "jsonrpc": "2.0", "id": "xfg-stark", "error": { "code": -5, "message": "not found" } }),
+        ];
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let mut methods = Vec::new();
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                let mut request = vec![0u8; content_length];
+                reader.read_exact(&mut request).unwrap();
+                let request: Value = serde_json::from_slice(&request).unwrap();
+                methods.push(request["method"].clone());
+
+                let body = response.to_string();
+                let mut stream = stream;
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            methods
+        });
+
+        let client = FuegoRpcClient::new(url);
+        let tx = client.get_transaction(TX_HASH).unwrap();
+        assert_eq!(tx.burn_amount().unwrap(), 800_000_000);
+        assert!(matches!(client.get_transaction(TX_HASH), Err(FuegoRpcError::Rpc(_))));
+        assert_eq!(server.join().unwrap(), vec!["f_transaction_json", "f_transaction_json"]);
+    }
+}
@@ -0,0 +1,421 @@
+//! Passphrase-Encrypted Keystores for Burn Secrets
+//!
+//! Anyone holding a package's burn secret can claim its HEAT, so the secret should not
+//! sit in the package JSON in the clear. A [`Keystore`] holds it encrypted under a
+//! passphrase instead:
+//!
+//! - scrypt derives a 256-bit key from the passphrase and a random salt
+//! - AES-256-GCM encrypts the secret, authenticating the keystore version and KDF parameters
+//!
+//! A locked package carries a [`SecretRef`] to its
+//! keystore file in place of the secret, and `StarkProofDataPackage::unlock_secret`
+//! restores the secret in memory for proving. The keystore types are always available so
+//! keystores round-trip unchanged; encrypting and decrypting requires the `keystore`
+//! feature.
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "keystore")]
+use crate::proof_data_schema::{SecretRef, StarkProofDataPackage};
+#[cfg(feature = "keystore")]
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm, Nonce,
+};
+#[cfg(feature = "keystore")]
+use rand::{rngs::OsRng, RngCore};
+#[cfg(feature = "keystore")]
+use zeroize::{Zeroize, Zeroizing};
+
+/// Keystore format version
+pub const KEYSTORE_VERSION: u32 = 1;
+
+/// Key derivation function of version 1 keystores
+pub const KEYSTORE_KDF: &str = "scrypt";
+
+/// Cipher of version 1 keystores
+pub const KEYSTORE_CIPHER: &str = "aes-256-gcm";
+
+/// scrypt salt length (bytes)
+#[cfg(feature = "keystore")]
+const SALT_LEN: usize = 32;
+
+/// AES-GCM nonce length (bytes)
+#[cfg(feature = "keystore")]
+const NONCE_LEN: usize = 12;
+
+/// Largest scrypt `log_n` a keystore may ask for (`N = 2^20`, 1 GiB at `r = 8`)
+pub const MAX_SCRYPT_LOG_N: u8 = 20;
+
+/// Largest scrypt block size a keystore may ask for
+pub const MAX_SCRYPT_R: u32 = 8;
+
+/// Largest scrypt parallelism a keystore may ask for
+pub const MAX_SCRYPT_P: u32 = 1;
+
+/// scrypt cost parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScryptParams {
+    /// log2 of the CPU/memory cost `N`
+    pub log_n: u8,
+    /// Block size `r`
+    pub r: u32,
+    /// Parallelism `p`
+    pub p: u32,
+}
+
+impl Default for ScryptParams {
+    /// The scrypt crate's recommended interactive parameters (`N = 2^17`, `r = 8`, `p = 1`)
+    fn default() -> Self {
+        Self { log_n: 17, r: 8, p: 1 }
+    }
+}
+
+impl ScryptParams {
+    /// Check the parameters stay within [`MAX_SCRYPT_LOG_N`], [`MAX_SCRYPT_R`] and [`MAX_SCRYPT_P`]
+    ///
+    /// The parameters come from the keystore file, so a hostile file could otherwise make
+    /// key derivation exhaust memory or run for hours before the passphrase is checked.
+    ///
+    /// # Errors
+    ///
+    /// [`KeystoreError::ParamsTooCostly`] if any parameter exceeds its bound
+    pub fn check_bounds(&self) -> Result<(), KeystoreError> {
+        if self.log_n > MAX_SCRYPT_LOG_N || self.r > MAX_SCRYPT_R || self.p > MAX_SCRYPT_P {
+            return Err(KeystoreError::ParamsTooCostly(*self));
+        }
+        Ok(())
+    }
+}
+
+/// Burn secret encrypted under a passphrase
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    /// Keystore format version
+    pub version: u32,
+    /// Key derivation function
+    pub kdf: String,
+    /// Key derivation cost parameters
+    pub kdf_params: ScryptParams,
+    /// Key derivation salt (hex string)
+    pub salt: String,
+    /// Cipher
+    pub cipher: String,
+    /// AES-GCM nonce (hex string)
+    pub nonce: String,
+    /// Encrypted secret with the authentication tag (hex string)
+    pub ciphertext: String,
+}
+
+impl Keystore {
+    /// Save the keystore to a JSON file
+    ///
+    /// # Errors
+    ///
+    /// If the keystore cannot be serialized or the file cannot be written
+    pub fn save_to_file(&self, filepath: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(filepath, json)?;
+        Ok(())
+    }
+
+    /// Load a keystore from a JSON file
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is not a keystore
+    pub fn load_from_file(filepath: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let json = std::fs::read_to_string(filepath)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+#[cfg(feature = "keystore")]
+impl Keystore {
+    /// Encrypt `secret` under `passphrase` with the default scrypt parameters
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::encrypt_with_params`]
+    pub fn encrypt(secret: &str, passphrase: &str) -> Result<Self, KeystoreError> {
+        Self::encrypt_with_params(secret, passphrase, ScryptParams::default())
+    }
+
+    /// Encrypt `secret` under `passphrase` with the given scrypt parameters
+    ///
+    /// # Errors
+    ///
+    /// [`KeystoreError::ParamsTooCostly`] or [`KeystoreError::InvalidParams`] if scrypt
+    /// rejects `params`, or [`KeystoreError::Encryption`] if encryption fails
+    pub fn encrypt_with_params(secret: &str, passphrase: &str, params: ScryptParams) -> Result<Self, KeystoreError> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+
+        let cipher = keystore_cipher(passphrase, &salt, params)?;
+        let aad = associated_data(KEYSTORE_VERSION, params);
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg: secret.as_bytes(), aad: &aad })
+            .map_err(|_| KeystoreError::Encryption)?;
+
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            kdf: KEYSTORE_KDF.to_string(),
+            kdf_params: params,
+            salt: hex::encode(salt),
+            cipher: KEYSTORE_CIPHER.to_string(),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    /// Decrypt the secret with `passphrase`
+    ///
+    /// # Errors
+    ///
+    /// [`KeystoreError::UnsupportedVersion`] or [`KeystoreError::UnsupportedScheme`] for
+    /// another format, [`KeystoreError::InvalidEncoding`] if a field or the secret is
+    /// malformed, [`KeystoreError::ParamsTooCostly`] or [`KeystoreError::InvalidParams`] for
+    /// unusable scrypt parameters, and [`KeystoreError::Decryption`] if the passphrase is
+    /// wrong or the keystore was tampered with
+    pub fn decrypt(&self, passphrase: &str) -> Result<Zeroizing<String>, KeystoreError> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(KeystoreError::UnsupportedVersion(self.version));
+        }
+        if self.kdf != KEYSTORE_KDF {
+            return Err(KeystoreError::UnsupportedScheme(self.kdf.clone()));
+        }
+        if self.cipher != KEYSTORE_CIPHER {
+            return Err(KeystoreError::UnsupportedScheme(self.cipher.clone()));
+        }
+
+        let salt = decode_hex(&self.salt, "salt")?;
+        let nonce = decode_hex(&self.nonce, "nonce")?;
+        if nonce.len() != NONCE_LEN {
+            return Err(KeystoreError::InvalidEncoding(format!("nonce must be {NONCE_LEN} bytes")));
+        }
+        let ciphertext = decode_hex(&self.ciphertext, "ciphertext")?;
+
+        let cipher = keystore_cipher(passphrase, &salt, self.kdf_params)?;
+        let aad = associated_data(self.version, self.kdf_params);
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: &aad })
+            .map_err(|_| KeystoreError::Decryption)?;
+
+        String::from_utf8(plaintext).map(Zeroizing::new).map_err(|e| {
+            e.into_bytes().zeroize();
+            KeystoreError::InvalidEncoding("secret is not UTF-8".to_string())
+        })
+    }
+}
+
+#[cfg(feature = "keystore")]
+impl StarkProofDataPackage {
+    /// Move the secret key into a keystore encrypted under `passphrase`
+    ///
+    /// The package keeps a [`SecretRef`] to `keystore_file`, resolved against the
+    /// package file's directory when relative; the caller saves the returned keystore
+    /// there.
+    ///
+    /// # Errors
+    ///
+    /// [`KeystoreError::AlreadyLocked`] if the secret is already in a keystore, or as
+    /// [`Keystore::encrypt_with_params`]
+    pub fn lock_secret(
+        &mut self,
+        keystore_file: &str,
+        passphrase: &str,
+        params: ScryptParams,
+    ) -> Result<Keystore, KeystoreError> {
+        if self.secret.secret_ref.is_some() {
+            return Err(KeystoreError::AlreadyLocked);
+        }
+        let keystore = Keystore::encrypt_with_params(&self.secret.secret_key, passphrase, params)?;
+        self.secret.secret_key.zeroize();
+        self.secret.secret_ref = Some(SecretRef { keystore: keystore_file.to_string() });
+        Ok(keystore)
+    }
+
+    /// Restore the secret key from the keystore the package references
+    ///
+    /// `package_file` is where the package was loaded from, for resolving relative
+    /// keystore paths. Packages that embed their secret are left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// [`KeystoreError::Unreadable`] if the keystore file cannot be loaded, or as
+    /// [`Keystore::decrypt`]
+    pub fn unlock_secret(&mut self, package_file: &std::path::Path, passphrase: &str) -> Result<(), KeystoreError> {
+        let Some(secret_ref) = &self.secret.secret_ref else {
+            return Ok(());
+        };
+        let path = secret_ref.keystore_path(package_file);
+        let keystore = Keystore::load_from_file(&path.to_string_lossy()).map_err(|e| KeystoreError::Unreadable {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+        let secret = keystore.decrypt(passphrase)?;
+
+        self.secret.secret_key = secret.as_str().to_string();
+        self.secret.secret_ref = None;
+        Ok(())
+    }
+}
+
+/// AES-256-GCM cipher keyed by scrypt(passphrase, salt)
+#[cfg(feature = "keystore")]
+fn keystore_cipher(passphrase: &str, salt: &[u8], params: ScryptParams) -> Result<Aes256Gcm, KeystoreError> {
+    params.check_bounds()?;
+    let scrypt_params = scrypt::Params::new(params.log_n, params.r, params.p, 32)
+        .map_err(|e| KeystoreError::InvalidParams(e.to_string()))?;
+
+    let mut key = Zeroizing::new([0u8; 32]);
+    scrypt::scrypt(passphrase.as_bytes(), salt, &scrypt_params, key.as_mut())
+        .map_err(|e| KeystoreError::InvalidParams(e.to_string()))?;
+    Ok(Aes256Gcm::new(key.as_ref().into()))
+}
+
+/// Data authenticated alongside the ciphertext, so cost parameters cannot be downgraded
+#[cfg(feature = "keystore")]
+fn associated_data(version: u32, params: ScryptParams) -> Vec<u8> {
+    format!(
+        "xfg-keystore-v{}:{}:{}:{}:{}:{}",
+        version, KEYSTORE_KDF, params.log_n, params.r, params.p, KEYSTORE_CIPHER
+    )
+    .into_bytes()
+}
+
+#[cfg(feature = "keystore")]
+fn decode_hex(value: &str, field: &str) -> Result<Vec<u8>, KeystoreError> {
+    hex::decode(value).map_err(|e| KeystoreError::InvalidEncoding(format!("{field}: {e}")))
+}
+
+/// Keystore error types
+#[derive(Debug, thiserror::Error)]
+pub enum KeystoreError {
+    /// Keystore written by an unknown format version
+    #[error("Unsupported keystore version: {0}")]
+    UnsupportedVersion(u32),
+
+    /// Keystore uses an unknown KDF or cipher
+    #[error("Unsupported keystore scheme: {0}")]
+    UnsupportedScheme(String),
+
+    /// scrypt cost parameters out of range
+    #[error("Invalid scrypt parameters: {0}")]
+    InvalidParams(String),
+
+    /// scrypt cost parameters above the accepted maximums
+    #[error(
+        "scrypt parameters log_n={}, r={}, p={} exceed the maximums log_n={}, r={}, p={}",
+        .0.log_n, .0.r, .0.p, MAX_SCRYPT_LOG_N, MAX_SCRYPT_R, MAX_SCRYPT_P
+    )]
+    ParamsTooCostly(ScryptParams),
+
+    /// Malformed hex field in the keystore
+    #[error("Invalid keystore encoding: {0}")]
+    InvalidEncoding(String),
+
+    /// Encryption failed
+    #[error("Keystore encryption failed")]
+    Encryption,
+
+    /// Ciphertext did not authenticate under the passphrase
+    #[error("Keystore decryption failed: wrong passphrase or corrupted keystore")]
+    Decryption,
+
+    /// Keystore file could not be read
+    #[error("Cannot read keystore {path}: {reason}")]
+    Unreadable {
+        /// Keystore file
+        path: String,
+        /// Underlying error
+        reason: String,
+    },
+
+    /// Package secret is already in a keystore
+    #[error("Package secret is already locked in a keystore")]
+    AlreadyLocked,
+}
+
+#[cfg(all(test, feature = "keystore"))]
+mod tests {
+    use super::*;
+
+    /// Cheap parameters so tests do not spend 128 MiB per derivation
+    const TEST_PARAMS: ScryptParams = ScryptParams { log_n: 4, r: 8, p: 1 };
+
+    #[test]
+    fn test_keystore_round_trip() {
+        let keystore = Keystore::encrypt_with_params("my-secret-key-123", "correct horse", TEST_PARAMS).unwrap();
+
+        let json = serde_json::to_string(&keystore).unwrap();
+        assert!(!json.contains("my-secret-key-123"));
+        let keystore: Keystore = serde_json::from_str(&json).unwrap();
+        assert_eq!(keystore.decrypt("correct horse").unwrap().as_str(), "my-secret-key-123");
+
+        assert!(matches!(keystore.decrypt("battery staple"), Err(KeystoreError::Decryption)));
+
+        // Weakening the cost parameters invalidates the authentication tag
+        let mut downgraded = keystore.clone();
+        downgraded.kdf_params.log_n = 1;
+        assert!(matches!(downgraded.decrypt("correct horse"), Err(KeystoreError::Decryption)));
+    }
+
+    #[test]
+    fn test_costly_params_rejected_before_derivation() {
+        let keystore = Keystore::encrypt_with_params("my-secret-key-123", "correct horse", TEST_PARAMS).unwrap();
+
+        // Deriving with any of these would take gigabytes or hours; rejection is immediate
+        for params in [
+            ScryptParams { log_n: 40, ..TEST_PARAMS },
+            ScryptParams { r: 1 << 20, ..TEST_PARAMS },
+            ScryptParams { p: 1 << 16, ..TEST_PARAMS },
+        ] {
+            let mut hostile = keystore.clone();
+            hostile.kdf_params = params;
+            assert!(matches!(hostile.decrypt("correct horse"), Err(KeystoreError::ParamsTooCostly(p)) if p == params));
+        }
+        assert!(ScryptParams::default().check_bounds().is_ok());
+    }
+
+    #[test]
+    fn test_lock_and_unlock_package_secret() {
+        let dir = std::env::temp_dir().join(format!("xfg-keystore-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let package_file = dir.join("package.json");
+
+        let mut package = StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "my-secret-key-123".to_string(),
+            "fuego-testnet".to_string(),
+        );
+        let keystore = package.lock_secret("package.keystore.json", "correct horse", TEST_PARAMS).unwrap();
+        keystore.save_to_file(&dir.join("package.keystore.json").to_string_lossy()).unwrap();
+        package.save_to_file(&package_file.to_string_lossy()).unwrap();
+        assert!(matches!(
+            package.lock_secret("other.json", "correct horse", TEST_PARAMS),
+            Err(KeystoreError::AlreadyLocked)
+        ));
+
+        // The stored package references the keystore and still validates
+        let json = std::fs::read_to_string(&package_file).unwrap();
+        assert!(!json.contains("my-secret-key-123"));
+        assert!(json.contains("\"secret_ref\""));
+        let mut loaded = StarkProofDataPackage::load_from_file(&package_file.to_string_lossy()).unwrap();
+        assert!(loaded.secret.is_locked());
+        assert!(loaded.validate().errors.iter().all(|e| !e.contains("ecret")));
+
+        assert!(matches!(
+            loaded.clone().unlock_secret(&package_file, "battery staple"),
+            Err(KeystoreError::Decryption)
+        ));
+        loaded.unlock_secret(&package_file, "correct horse").unwrap();
+        assert!(!loaded.secret.is_locked());
+        assert_eq!(loaded.secret.secret_key, "my-secret-key-123");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
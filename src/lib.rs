@@ -9,6 +9,41 @@
 //! - **Polynomial Operations**: Efficient polynomial arithmetic and evaluation
 //! - **STARK Proof System**: Complete STARK proof generation and verification
 //! - **Type System**: Comprehensive type definitions for all cryptographic operations
+//! - **Custom Statements**: Reusable nullifier, commitment and state-machine gadgets for new circuits
+//! - **Code Generation**: The Solidity burn & mint verifier library, see [`codegen::solidity`]
+//! - **Verifier Core**: The `verifier-core` workspace crate verifies burn & mint proofs
+//!   without std, for Arbitrum Stylus and RISC-V targets
+//!
+//! ## Cargo Features
+//!
+//! The field, polynomial, AIR and burn & mint proving code has no optional dependencies.
+//! Heavier subsystems are opt-in:
+//!
+//! - `packages`: data package schema and migrations, batch proof generation, claim calldata, reports, stores and commitment audits (chrono)
+//! - `signing`: Ed25519 package signing and key rotation (ed25519-dalek)
+//! - `encryption`: ECIES encryption of packages to the recipient's secp256k1 key (k256, hkdf, aes-gcm)
+//! - `keystore`: passphrase-encrypted keystores for burn secrets (scrypt, aes-gcm)
+//! - `rpc`: Fuego daemon RPC for transaction lookup and commitment audits, and live gas
+//!   estimation over Ethereum JSON-RPC (ureq)
+//! - `s3`: S3-compatible package store (ureq, hmac)
+//! - `progress`: proof generation and Eldernode consensus progress channel (tokio)
+//! - `server`: streaming TCP verification server and HTTP verification service (tokio, axum)
+//! - `eldernode`: Eldernode attestation client and consensus checks (ureq, ed25519-dalek)
+//! - `compression`: zstd proof compression (zstd)
+//! - `debug`: execution trace dumps for offline analysis and the CLI `--dump-trace` flag (zstd)
+//! - `wasm`: browser verification bindings (wasm-bindgen, getrandom)
+//! - `parallel`: multi-threaded FRI folding, query evaluation and Merkle tree construction (rayon)
+//! - `simd`: AVX2 (runtime-detected) and NEON kernels for batch field arithmetic
+//! - `networks`: custom network definitions loaded from TOML files (toml)
+//! - `testing`: property-testing generators for field elements, polynomials and Merkle
+//!   trees, the end-to-end test harness, replay scenarios and the `regenerate-fixtures`
+//!   binary (quickcheck)
+//! - `cli`: the command-line binaries (clap, tokio, indicatif); enabled by default
+//!
+//! The crate does not build without `std` yet, so every feature above enables it.
+//!
+//! Consumers that only need the proving core can depend on the crate with
+//! `default-features = false, features = ["std"]`.
 
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -25,13 +60,86 @@ pub mod types;
 pub mod utils;
 pub mod air;
 pub mod proof;
+pub mod proof_compat;
+pub mod public_input_layout;
+pub mod secret_oracle;
 pub mod winterfell_integration;
 pub mod benchmarks;
 pub mod burn_mint_air;
 pub mod burn_mint_prover;
 pub mod burn_mint_verifier;
+pub mod clock;
+pub mod commitment;
+pub mod constants;
+pub mod debug;
+pub mod deterministic;
+pub mod eth_address;
+pub mod fees;
+pub mod networks;
+pub mod nullifier;
+pub mod parameter_registry;
+pub mod soundness;
+pub mod statements;
+pub mod stress;
+pub mod trace_compression;
+pub mod trace_dump;
+#[cfg(feature = "packages")]
 pub mod proof_data_schema;
+#[cfg(feature = "packages")]
+pub mod schema_migration;
 pub mod test_data_generator;
+#[cfg(feature = "packages")]
+pub mod package_signing;
+#[cfg(feature = "packages")]
+pub mod package_encryption;
+#[cfg(feature = "packages")]
+pub mod keystore;
+#[cfg(any(test, feature = "testing"))]
+pub mod replay_scenarios;
+#[cfg(any(test, feature = "testing"))]
+pub mod test_harness;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+#[cfg(any(test, feature = "testing"))]
+pub mod fixtures;
+pub mod revert_reason;
+pub mod codegen;
+#[cfg(feature = "packages")]
+pub mod commitment_audit;
+#[cfg(feature = "rpc")]
+pub mod fuego_rpc;
+#[cfg(feature = "packages")]
+pub mod verification_context;
+#[cfg(feature = "packages")]
+pub mod package_store;
+pub mod build_info;
+#[cfg(feature = "packages")]
+pub mod claim_binding;
+#[cfg(feature = "packages")]
+pub mod proof_report;
+#[cfg(feature = "packages")]
+pub mod provenance;
+#[cfg(feature = "packages")]
+pub mod bug_report;
+#[cfg(feature = "packages")]
+pub mod batch_generation;
+#[cfg(feature = "packages")]
+pub mod claim_calldata;
+pub mod features;
+#[cfg(feature = "server")]
+pub mod admission;
+#[cfg(feature = "server")]
+pub mod verification_server;
+#[cfg(feature = "server")]
+pub mod tenants;
+#[cfg(feature = "server")]
+pub mod service;
+#[cfg(feature = "progress")]
+pub mod progress;
+#[cfg(feature = "eldernode")]
+pub mod eldernode;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 
 pub use field::*;
@@ -41,13 +149,75 @@ pub use types::*;
 pub use utils::*;
 pub use air::*;
 pub use proof::*;
+pub use public_input_layout::*;
+pub use secret_oracle::*;
 pub use winterfell_integration::*;
 pub use benchmarks::*;
 pub use burn_mint_air::*;
 pub use burn_mint_prover::*;
 pub use burn_mint_verifier::*;
+pub use clock::*;
+pub use commitment::*;
+pub use constants::*;
+pub use deterministic::*;
+pub use eth_address::*;
+pub use fees::*;
+pub use networks::*;
+pub use nullifier::*;
+pub use parameter_registry::*;
+pub use soundness::*;
+pub use statements::*;
+pub use stress::*;
+pub use trace_compression::*;
+pub use trace_dump::*;
+#[cfg(feature = "packages")]
 pub use proof_data_schema::*;
+#[cfg(feature = "packages")]
+pub use schema_migration::*;
 pub use test_data_generator::*;
+#[cfg(feature = "packages")]
+pub use package_signing::*;
+#[cfg(feature = "packages")]
+pub use package_encryption::*;
+#[cfg(feature = "packages")]
+pub use keystore::*;
+#[cfg(any(test, feature = "testing"))]
+pub use replay_scenarios::*;
+#[cfg(any(test, feature = "testing"))]
+pub use test_harness::*;
+pub use revert_reason::*;
+#[cfg(feature = "packages")]
+pub use commitment_audit::*;
+#[cfg(feature = "rpc")]
+pub use fuego_rpc::*;
+#[cfg(feature = "packages")]
+pub use verification_context::*;
+#[cfg(feature = "packages")]
+pub use package_store::*;
+pub use build_info::*;
+#[cfg(feature = "packages")]
+pub use claim_binding::*;
+#[cfg(feature = "packages")]
+pub use proof_report::*;
+#[cfg(feature = "packages")]
+pub use provenance::*;
+#[cfg(feature = "packages")]
+pub use bug_report::*;
+#[cfg(feature = "packages")]
+pub use batch_generation::*;
+#[cfg(feature = "packages")]
+pub use claim_calldata::*;
+pub use features::*;
+#[cfg(feature = "server")]
+pub use admission::*;
+#[cfg(feature = "server")]
+pub use verification_server::*;
+#[cfg(feature = "server")]
+pub use tenants::*;
+#[cfg(feature = "progress")]
+pub use progress::*;
+#[cfg(feature = "eldernode")]
+pub use eldernode::*;
 
 
 /// Re-exports for common cryptographic operations
@@ -107,6 +277,109 @@ pub enum XfgStarkError {
     /// Cryptographic error
     #[error("Cryptographic error: {0}")]
     CryptoError(String),
+
+    /// Proof rejected by the verifier's minimum security policy
+    #[error("Security policy error: {0}")]
+    SecurityPolicyError(#[from] burn_mint_verifier::SecurityPolicyError),
+
+    /// Serialized proof rejected before verification
+    #[error("Proof decode error: {0}")]
+    ProofDecodeError(#[from] burn_mint_verifier::ProofDecodeError),
+
+    /// Package disagrees with the proof it carries
+    #[cfg(feature = "packages")]
+    #[error("Package claim error: {0}")]
+    PackageClaimError(#[from] burn_mint_verifier::PackageClaimError),
+
+    /// Final-state policy does not fit the trace
+    #[error("Final state policy error: {0}")]
+    FinalStatePolicyError(#[from] burn_mint_air::FinalStatePolicyError),
+
+    /// Burn & mint trace length or state schedule cannot be proven
+    #[error("Burn & mint config error: {0}")]
+    BurnMintConfigError(#[from] burn_mint_air::BurnMintConfigError),
+
+    /// Known-answer test vectors differ from this crate's output
+    #[error("KAT error: {0}")]
+    KatError(#[from] test_data_generator::KatError),
+
+    /// Package cannot be turned into a HEAT claim call
+    #[cfg(feature = "packages")]
+    #[error("Claim calldata error: {0}")]
+    ClaimCalldataError(#[from] claim_calldata::ClaimCalldataError),
+
+    /// Eldernode attestation, signature or consensus failure
+    #[cfg(feature = "eldernode")]
+    #[error("Eldernode error: {0}")]
+    EldernodeError(#[from] eldernode::EldernodeError),
+
+    /// Background task panicked or was cancelled
+    #[cfg(any(feature = "progress", feature = "server"))]
+    #[error("Task join error: {0}")]
+    JoinError(#[from] tokio::task::JoinError),
+
+    /// Gas estimation over Ethereum JSON-RPC failed or the simulated call reverted
+    #[cfg(feature = "rpc")]
+    #[error("Gas estimation error: {0}")]
+    GasEstimateError(#[from] proof::evm::GasEstimateError),
+
+    /// External secret holder failed to supply a secret-derived value
+    #[error("Secret oracle error: {0}")]
+    SecretOracleError(#[from] secret_oracle::SecretOracleError),
+
+    /// Protocol fee accounting error
+    #[error("Fee error: {0}")]
+    FeeError(#[from] fees::FeeError),
+
+    /// XFG amount with no atomic-unit representation
+    #[error("Amount error: {0}")]
+    InvalidXfgAmount(#[from] constants::InvalidXfgAmount),
+
+    /// Secret does not reproduce the commitment recorded on chain
+    #[error("Secret does not match the on-chain commitment: expected {}, computed {}", hex::encode(.expected), hex::encode(.computed))]
+    CommitmentMismatch {
+        /// Commitment from the burn transaction's `tx_extra`
+        expected: [u8; 32],
+        /// Commitment recomputed from the supplied secret
+        computed: [u8; 32],
+    },
+
+    /// Proof records a different hash function than the verifier enforces
+    #[error("Proof commits with {recorded}, but the verifier requires {expected}")]
+    HashFunctionMismatch {
+        /// Hash function the verifier enforces
+        expected: statements::HashFunction,
+        /// Hash function recorded in the proof metadata
+        recorded: statements::HashFunction,
+    },
+
+    /// Mint submission reverted on chain
+    #[error("Submission error: {0}")]
+    SubmissionReverted(#[from] revert_reason::SubmissionReverted),
+
+    /// Trace violates its AIR
+    #[error("Constraint violation: {0}")]
+    ConstraintViolation(#[from] statements::ConstraintViolation),
+
+    /// Execution trace dump error
+    #[error("Trace dump error: {0}")]
+    TraceDumpError(#[from] trace_dump::TraceDumpError),
+
+    /// Proof parameters rejected by the parameter registry
+    #[error("Parameter registry error: {0}")]
+    RegistryError(#[from] parameter_registry::RegistryError),
+
+    /// Unknown network or claim for another network
+    #[error("Network error: {0}")]
+    NetworkError(#[from] networks::NetworkError),
+
+    /// Nullifier already spent or registry failure
+    #[error("Nullifier error: {0}")]
+    NullifierError(#[from] nullifier::NullifierError),
+
+    /// Proving failed or was cancelled
+    #[error("Proof error: {0}")]
+    ProofError(#[from] proof::ProofError),
 }
 
 /// Result type for XFG STARK operations
@@ -157,6 +430,6 @@ mod tests {
         let network_id_field = types::field::PrimeField64::new(network_id_u64);
         
         // Verify the field element conversion
-        assert_eq!(network_id_field.to_string(), "PrimeField64(1742133188492406885)");
+        assert_eq!(network_id_field.to_string(), "PrimeField64(10965505225347182692)");
     }
 }
@@ -0,0 +1,507 @@
+//! Network Parameters Registry
+//!
+//! A proof is claimed on one network: a Fuego network the burn happened on, paired with
+//! the chain HEAT is minted on. [`NetworkParams`] records both IDs, the commitment
+//! version claims carry there and the contracts that verify them, and
+//! [`NetworkRegistry::builtin`] holds the networks this release supports:
+//!
+//! | Name | Fuego network | Target chain | Commitment version |
+//! |------|---------------|--------------|--------------------|
+//! | `mainnet` | 1 (Fuego mainnet) | 42161 (Arbitrum One) | 1 |
+//! | `sepolia` | 4 (Fuego testnet) | 421614 (Arbitrum Sepolia) | 1 |
+//!
+//! Each network has a domain separator over its IDs and commitment version, so no two
+//! registered networks share one. Provers and verifiers built `with_network` reject
+//! claims for any other network before proving or verifying.
+//!
+//! With the `networks` feature, custom networks (devnets, forks) are loaded from a TOML
+//! file with [`NetworkRegistry::load_toml`]:
+//!
+//! ```toml
+//! [[network]]
+//! name = "devnet"
+//! display_name = "Local devnet"
+//! fuego_network_id = 100
+//! target_chain_id = 31337
+//! commitment_version = 1
+//!
+//! [network.contracts]
+//! burn_verifier = "0x5fbdb2315678afecb367f032d93f642f64180aa3"
+//! ```
+
+use crate::eth_address::EthAddress;
+use sha3::{Digest, Keccak256};
+use thiserror::Error;
+
+/// Domain separator for network domain hashes
+pub const NETWORK_DOMAIN: &[u8] = b"fuego-to-heat-bridge/network-v1";
+
+/// Fuego mainnet network ID
+pub const FUEGO_MAINNET_NETWORK_ID: u32 = 1;
+
+/// Fuego testnet network ID
+pub const FUEGO_TESTNET_NETWORK_ID: u32 = 4;
+
+/// Arbitrum One chain ID
+pub const ARBITRUM_ONE_CHAIN_ID: u32 = 42161;
+
+/// Arbitrum Sepolia chain ID
+pub const ARBITRUM_SEPOLIA_CHAIN_ID: u32 = 421_614;
+
+/// Contracts deployed on a network's target chain
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkContracts {
+    /// HEAT token
+    pub heat_token: Option<EthAddress>,
+    /// Burn proof verifier
+    pub burn_verifier: Option<EthAddress>,
+    /// Eldernode attestation verifier
+    pub eldernode_verifier: Option<EthAddress>,
+}
+
+/// Parameters of one network a proof can be claimed on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkParams {
+    /// Registry key, e.g. `mainnet`
+    pub name: String,
+    /// Human-readable name
+    pub display_name: String,
+    /// Fuego network the burn happens on
+    pub fuego_network_id: u32,
+    /// Chain HEAT is minted on
+    pub target_chain_id: u32,
+    /// Commitment format version claims carry
+    pub commitment_version: u32,
+    /// Expected contract addresses
+    pub contracts: NetworkContracts,
+}
+
+impl NetworkParams {
+    /// Fuego mainnet minting on Arbitrum One
+    #[must_use]
+    pub fn mainnet() -> Self {
+        Self {
+            name: "mainnet".to_string(),
+            display_name: "Arbitrum One".to_string(),
+            fuego_network_id: FUEGO_MAINNET_NETWORK_ID,
+            target_chain_id: ARBITRUM_ONE_CHAIN_ID,
+            commitment_version: crate::constants::COMMITMENT_VERSION,
+            contracts: NetworkContracts {
+                heat_token: Some(builtin_address("0x9876543210987654321098765432109876543210")),
+                burn_verifier: Some(builtin_address("0xdcbadcbadcbadcbadcbadcbadcbadcbadcbadcba")),
+                eldernode_verifier: Some(builtin_address("0xabcdefabcdefabcdefabcdefabcdefabcdefabcd")),
+            },
+        }
+    }
+
+    /// Fuego testnet minting on Arbitrum Sepolia
+    #[must_use]
+    pub fn sepolia() -> Self {
+        Self {
+            name: "sepolia".to_string(),
+            display_name: "Arbitrum Sepolia".to_string(),
+            fuego_network_id: FUEGO_TESTNET_NETWORK_ID,
+            target_chain_id: ARBITRUM_SEPOLIA_CHAIN_ID,
+            commitment_version: crate::constants::COMMITMENT_VERSION,
+            contracts: NetworkContracts {
+                heat_token: Some(builtin_address("0x1234567890123456789012345678901234567890")),
+                burn_verifier: Some(builtin_address("0xabcdefabcdefabcdefabcdefabcdefabcdefabcd")),
+                eldernode_verifier: Some(builtin_address("0xfedcbafedcbafedcbafedcbafedcbafedcbafedc")),
+            },
+        }
+    }
+
+    /// Domain hash of the network
+    ///
+    /// `Keccak256(NETWORK_DOMAIN || le32(fuego_network_id) || le32(target_chain_id) || le32(commitment_version))`
+    #[must_use]
+    pub fn domain_separator(&self) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        hasher.update(NETWORK_DOMAIN);
+        hasher.update(self.fuego_network_id.to_le_bytes());
+        hasher.update(self.target_chain_id.to_le_bytes());
+        hasher.update(self.commitment_version.to_le_bytes());
+        hasher.finalize().into()
+    }
+
+    /// Burn verifier contract claims are submitted to
+    ///
+    /// # Errors
+    ///
+    /// [`NetworkError::MissingContract`] if the network has no burn verifier
+    pub fn burn_verifier(&self) -> Result<&EthAddress, NetworkError> {
+        self.contracts.burn_verifier.as_ref().ok_or_else(|| NetworkError::MissingContract {
+            network: self.name.clone(),
+            contract: "burn_verifier",
+        })
+    }
+
+    /// Check that a claim's identifiers belong to this network
+    ///
+    /// # Errors
+    ///
+    /// [`NetworkError::WrongNetwork`] naming the first identifier that differs
+    pub fn check_claim(
+        &self,
+        fuego_network_id: u32,
+        target_chain_id: u32,
+        commitment_version: u32,
+    ) -> Result<(), NetworkError> {
+        for (field, expected, found) in [
+            ("Fuego network ID", self.fuego_network_id, fuego_network_id),
+            ("target chain ID", self.target_chain_id, target_chain_id),
+            ("commitment version", self.commitment_version, commitment_version),
+        ] {
+            if expected != found {
+                return Err(NetworkError::WrongNetwork {
+                    network: self.name.clone(),
+                    field,
+                    expected,
+                    found,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+fn builtin_address(address: &str) -> EthAddress {
+    address.parse().expect("builtin contract addresses are valid")
+}
+
+/// Networks a proof can be claimed on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkRegistry {
+    networks: Vec<NetworkParams>,
+}
+
+impl NetworkRegistry {
+    /// Registry of the networks this release supports
+    #[must_use]
+    pub fn builtin() -> Self {
+        Self {
+            networks: vec![NetworkParams::mainnet(), NetworkParams::sepolia()],
+        }
+    }
+
+    /// Registered networks, built-in ones first
+    #[must_use]
+    pub fn networks(&self) -> &[NetworkParams] {
+        &self.networks
+    }
+
+    /// Network registered under `name` (case-insensitive)
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&NetworkParams> {
+        self.networks
+            .iter()
+            .find(|network| network.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Network registered under `name`
+    ///
+    /// # Errors
+    ///
+    /// [`NetworkError::UnknownNetwork`] if no network has that name
+    pub fn require(&self, name: &str) -> Result<&NetworkParams, NetworkError> {
+        self.get(name).ok_or_else(|| NetworkError::UnknownNetwork {
+            name: name.to_string(),
+            known: self.names(),
+        })
+    }
+
+    /// Network a Fuego network ID and target chain ID pair belongs to
+    #[must_use]
+    pub fn find(&self, fuego_network_id: u32, target_chain_id: u32) -> Option<&NetworkParams> {
+        self.networks.iter().find(|network| {
+            network.fuego_network_id == fuego_network_id && network.target_chain_id == target_chain_id
+        })
+    }
+
+    /// Names of the registered networks
+    #[must_use]
+    pub fn names(&self) -> Vec<String> {
+        self.networks.iter().map(|network| network.name.clone()).collect()
+    }
+
+    /// Register a custom network
+    ///
+    /// Names are unique, and so are domain separators: a network that would accept the
+    /// same claims as a registered one is rejected.
+    ///
+    /// # Errors
+    ///
+    /// [`NetworkError::InvalidConfig`] for an empty name, [`NetworkError::DuplicateName`]
+    /// or [`NetworkError::DuplicateDomain`] if it clashes with a registered network
+    pub fn register(&mut self, network: NetworkParams) -> Result<(), NetworkError> {
+        if network.name.is_empty() {
+            return Err(NetworkError::InvalidConfig("network name must not be empty".to_string()));
+        }
+        if self.get(&network.name).is_some() {
+            return Err(NetworkError::DuplicateName(network.name));
+        }
+        let domain = network.domain_separator();
+        if let Some(existing) = self.networks.iter().find(|existing| existing.domain_separator() == domain) {
+            return Err(NetworkError::DuplicateDomain {
+                name: network.name,
+                existing: existing.name.clone(),
+            });
+        }
+        self.networks.push(network);
+        Ok(())
+    }
+
+    /// Register the networks of a TOML network file
+    ///
+    /// # Errors
+    ///
+    /// [`NetworkError::InvalidConfig`] if the file cannot be read, or as
+    /// [`Self::extend_from_toml`]
+    #[cfg(feature = "networks")]
+    pub fn load_toml(&mut self, path: impl AsRef<std::path::Path>) -> Result<(), NetworkError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| NetworkError::InvalidConfig(format!("{}: {}", path.display(), e)))?;
+        self.extend_from_toml(&contents)
+    }
+
+    /// Register the networks of a TOML network file's contents
+    ///
+    /// # Errors
+    ///
+    /// [`NetworkError::InvalidConfig`] if the contents are not a network file or name an
+    /// invalid contract address, or as [`Self::register`]; networks before the failing
+    /// one stay registered
+    #[cfg(feature = "networks")]
+    pub fn extend_from_toml(&mut self, contents: &str) -> Result<(), NetworkError> {
+        let file: toml_config::NetworkFile =
+            toml::from_str(contents).map_err(|e| NetworkError::InvalidConfig(e.to_string()))?;
+        for network in file.network {
+            self.register(network.into_params()?)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for NetworkRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+#[cfg(feature = "networks")]
+mod toml_config {
+    use super::{NetworkContracts, NetworkError, NetworkParams};
+    use crate::eth_address::EthAddress;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub(super) struct NetworkFile {
+        #[serde(default)]
+        pub(super) network: Vec<NetworkEntry>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub(super) struct NetworkEntry {
+        name: String,
+        display_name: Option<String>,
+        fuego_network_id: u32,
+        target_chain_id: u32,
+        #[serde(default = "default_commitment_version")]
+        commitment_version: u32,
+        #[serde(default)]
+        contracts: ContractEntries,
+    }
+
+    #[derive(Deserialize, Default)]
+    #[serde(deny_unknown_fields)]
+    struct ContractEntries {
+        heat_token: Option<String>,
+        burn_verifier: Option<String>,
+        eldernode_verifier: Option<String>,
+    }
+
+    fn default_commitment_version() -> u32 {
+        crate::constants::COMMITMENT_VERSION
+    }
+
+    impl NetworkEntry {
+        pub(super) fn into_params(self) -> Result<NetworkParams, NetworkError> {
+            let name = self.name;
+            let address = |contract: &str, value: Option<String>| {
+                value
+                    .map(|value| value.parse::<EthAddress>())
+                    .transpose()
+                    .map_err(|e| NetworkError::InvalidConfig(format!("{name}.contracts.{contract}: {e}")))
+            };
+            let contracts = NetworkContracts {
+                heat_token: address("heat_token", self.contracts.heat_token)?,
+                burn_verifier: address("burn_verifier", self.contracts.burn_verifier)?,
+                eldernode_verifier: address("eldernode_verifier", self.contracts.eldernode_verifier)?,
+            };
+            Ok(NetworkParams {
+                display_name: self.display_name.unwrap_or_else(|| name.clone()),
+                name,
+                fuego_network_id: self.fuego_network_id,
+                target_chain_id: self.target_chain_id,
+                commitment_version: self.commitment_version,
+                contracts,
+            })
+        }
+    }
+}
+
+/// Network registry error types
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum NetworkError {
+    /// No network is registered under the name
+    #[error("Unknown network '{name}' (known: {})", .known.join(", "))]
+    UnknownNetwork {
+        /// Requested name
+        name: String,
+        /// Registered names
+        known: Vec<String>,
+    },
+
+    /// A network is already registered under the name
+    #[error("Network '{0}' is already registered")]
+    DuplicateName(String),
+
+    /// A registered network has the same IDs and commitment version
+    #[error("Network '{name}' has the same domain as '{existing}'")]
+    DuplicateDomain {
+        /// Network being registered
+        name: String,
+        /// Registered network with the same domain
+        existing: String,
+    },
+
+    /// A claim belongs to another network
+    #[error("Claim has {field} {found}, but network '{network}' uses {expected}")]
+    WrongNetwork {
+        /// Network the prover or verifier is configured for
+        network: String,
+        /// Mismatched identifier
+        field: &'static str,
+        /// The network's value
+        expected: u32,
+        /// The claim's value
+        found: u32,
+    },
+
+    /// Network has no address for a contract
+    #[error("Network '{network}' has no {contract} contract")]
+    MissingContract {
+        /// Network name
+        network: String,
+        /// Missing contract
+        contract: &'static str,
+    },
+
+    /// Network file is unreadable or malformed
+    #[error("Invalid network configuration: {0}")]
+    InvalidConfig(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_networks() {
+        let registry = NetworkRegistry::builtin();
+        let mainnet = registry.require("Mainnet").unwrap();
+        assert_eq!(mainnet.target_chain_id, crate::constants::DEFAULT_TARGET_CHAIN_ID);
+        assert_eq!(registry.find(FUEGO_TESTNET_NETWORK_ID, ARBITRUM_SEPOLIA_CHAIN_ID).unwrap().name, "sepolia");
+        assert_ne!(mainnet.domain_separator(), registry.require("sepolia").unwrap().domain_separator());
+        assert!(matches!(registry.require("goerli"), Err(NetworkError::UnknownNetwork { .. })));
+
+        assert!(mainnet.check_claim(1, 42161, 1).is_ok());
+        assert_eq!(
+            mainnet.check_claim(1, 421614, 1),
+            Err(NetworkError::WrongNetwork {
+                network: "mainnet".to_string(),
+                field: "target chain ID",
+                expected: 42161,
+                found: 421614,
+            })
+        );
+
+        // A custom network may not alias a registered one
+        let mut registry = registry;
+        let alias = NetworkParams {
+            name: "arbitrum".to_string(),
+            ..NetworkParams::mainnet()
+        };
+        assert!(matches!(registry.register(alias), Err(NetworkError::DuplicateDomain { .. })));
+        assert!(matches!(registry.register(NetworkParams::sepolia()), Err(NetworkError::DuplicateName(_))));
+    }
+
+    #[cfg(feature = "networks")]
+    #[test]
+    fn test_custom_networks_from_toml() {
+        let mut registry = NetworkRegistry::builtin();
+        registry
+            .extend_from_toml(
+                r#"
+                [[network]]
+                name = "devnet"
+                fuego_network_id = 100
+                target_chain_id = 31337
+
+                [network.contracts]
+                burn_verifier = "0x5fbdb2315678afecb367f032d93f642f64180aa3"
+                "#,
+            )
+            .unwrap();
+        let devnet = registry.require("devnet").unwrap();
+        assert_eq!(devnet.display_name, "devnet");
+        assert_eq!(devnet.commitment_version, crate::constants::COMMITMENT_VERSION);
+        assert!(devnet.contracts.burn_verifier.is_some() && devnet.contracts.heat_token.is_none());
+
+        for invalid in [
+            "[[network]]\nname = \"x\"\nfuego_network_id = 1\ntarget_chain_id = 1\nrpc = \"http://x\"",
+            "[[network]]\nname = \"x\"\nfuego_network_id = 1\ntarget_chain_id = 1\n[network.contracts]\nheat_token = \"0x12\"",
+        ] {
+            assert!(matches!(registry.extend_from_toml(invalid), Err(NetworkError::InvalidConfig(_))));
+        }
+    }
+
+    #[test]
+    fn test_prover_and_verifier_reject_other_networks() {
+        use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+        use crate::burn_mint_verifier::XfgBurnMintVerifier;
+
+        let sepolia = NetworkParams::sepolia();
+        let prover = XfgBurnMintProver::default().with_network(sepolia.clone());
+        let claim = |prover: &XfgBurnMintProver, target_chain_id| {
+            prover.prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12; 20],
+                    network_id: 4,
+                    target_chain_id,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+        };
+        assert!(matches!(claim(&prover, ARBITRUM_ONE_CHAIN_ID), Err(crate::XfgStarkError::NetworkError(_))));
+
+        let (proof, public_inputs) = claim(&prover, ARBITRUM_SEPOLIA_CHAIN_ID).unwrap();
+        assert!(XfgBurnMintVerifier::default()
+            .with_network(sepolia)
+            .verify_with_public_inputs(&proof, &public_inputs)
+            .unwrap());
+        assert!(matches!(
+            XfgBurnMintVerifier::default()
+                .with_network(NetworkParams::mainnet())
+                .verify_with_public_inputs(&proof, &public_inputs),
+            Err(crate::XfgStarkError::NetworkError(_))
+        ));
+    }
+}
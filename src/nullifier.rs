@@ -0,0 +1,325 @@
+//! Nullifier Registry
+//!
+//! Every burn & mint proof publishes a nullifier derived from the burn secret and the
+//! burn transaction (see [`derive_nullifier`]). A valid proof can be submitted any number
+//! of times, so a verifier that mints must remember which nullifiers it has accepted and
+//! reject a proof whose nullifier was already spent. [`NullifierSet`] is that memory:
+//!
+//! - [`MemoryNullifierSet`]: in-process, for tests and single-run tools
+//! - [`FsNullifierSet`]: an append-only file of spent nullifiers, synced before a
+//!   nullifier is reported as newly spent, so a restart does not forget a mint
+//!
+//! [`crate::XfgBurnMintVerifier::verify_with_nullifiers`] checks a proof and spends its
+//! nullifier in one call.
+
+use crate::burn_mint_air::{tx_prefix_hash_limbs, BurnMintPublicInputs, BURN_NULLIFIER, NULLIFIER_LIMBS};
+use crate::burn_mint_prover::secret_to_field_elements;
+use crate::types::secret::SecretKey;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, RwLock};
+use winterfell::math::fields::f64::BaseElement;
+
+/// Bytes per record in a nullifier file
+const RECORD_LEN: usize = 32;
+
+/// Nullifier published by a burn & mint proof: its four limbs in little-endian bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Nullifier(pub [u8; 32]);
+
+impl Nullifier {
+    /// Nullifier a claim's public inputs publish
+    #[must_use]
+    pub fn from_public_inputs(public_inputs: &BurnMintPublicInputs) -> Self {
+        Self::from(public_inputs.nullifier)
+    }
+
+    /// Field limbs of the nullifier, `None` when a limb is not a canonical field element
+    #[must_use]
+    pub fn limbs(&self) -> Option<[BaseElement; NULLIFIER_LIMBS]> {
+        crate::commitment::commitment_from_bytes(&self.0)
+    }
+}
+
+impl From<[BaseElement; NULLIFIER_LIMBS]> for Nullifier {
+    fn from(limbs: [BaseElement; NULLIFIER_LIMBS]) -> Self {
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs) {
+            chunk.copy_from_slice(&limb.as_int().to_le_bytes());
+        }
+        Self(bytes)
+    }
+}
+
+impl Display for Nullifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+/// Nullifier the prover derives for a burn secret and burn transaction
+///
+/// # Errors
+///
+/// [`crate::XfgStarkError::CryptoError`] if the secret is not
+/// [`crate::burn_mint_prover::SECRET_LEN`] bytes
+pub fn derive_nullifier(secret: &[u8], tx_prefix_hash: [u8; 32], burn_amount: u64) -> crate::Result<Nullifier> {
+    let secret = secret_to_field_elements(secret)?;
+    let [tx0, tx1, tx2, tx3] = tx_prefix_hash_limbs(&tx_prefix_hash);
+    Ok(BURN_NULLIFIER.derive(&secret, &[tx0, tx1, tx2, tx3, BaseElement::new(burn_amount)]).into())
+}
+
+/// Nullifier the prover derives for a [`SecretKey`] and burn transaction
+///
+/// # Errors
+///
+/// As [`derive_nullifier`]
+pub fn derive_nullifier_with_key(
+    secret: &SecretKey,
+    tx_prefix_hash: [u8; 32],
+    burn_amount: u64,
+) -> crate::Result<Nullifier> {
+    derive_nullifier(secret.expose(), tx_prefix_hash, burn_amount)
+}
+
+/// Nullifier registry error types
+#[derive(Debug, thiserror::Error)]
+pub enum NullifierError {
+    /// Nullifier was already spent by an earlier claim
+    #[error("Nullifier {0} has already been spent")]
+    AlreadySpent(Nullifier),
+
+    /// Registry storage failed
+    #[error("Nullifier registry I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Registry backend failed
+    #[error("Nullifier registry error: {0}")]
+    Backend(String),
+}
+
+/// Spent nullifiers
+pub trait NullifierSet: Send + Sync {
+    /// Check whether a nullifier has been spent
+    ///
+    /// # Errors
+    ///
+    /// [`NullifierError::Io`] or [`NullifierError::Backend`] if the set cannot be read
+    fn contains(&self, nullifier: Nullifier) -> Result<bool, NullifierError>;
+
+    /// Record a nullifier as spent, returning `false` if it already was
+    ///
+    /// Checking and recording are one step, so two claims racing on a nullifier cannot
+    /// both see it unspent.
+    ///
+    /// # Errors
+    ///
+    /// [`NullifierError::Io`] or [`NullifierError::Backend`] if the set cannot be updated
+    fn insert(&self, nullifier: Nullifier) -> Result<bool, NullifierError>;
+
+    /// Error if a nullifier has been spent
+    ///
+    /// # Errors
+    ///
+    /// [`NullifierError::AlreadySpent`] if it has, or an error of [`Self::contains`]
+    fn ensure_unspent(&self, nullifier: Nullifier) -> Result<(), NullifierError> {
+        if self.contains(nullifier)? {
+            return Err(NullifierError::AlreadySpent(nullifier));
+        }
+        Ok(())
+    }
+
+    /// Record a nullifier as spent, or error if it already was
+    ///
+    /// # Errors
+    ///
+    /// [`NullifierError::AlreadySpent`] if it was, or an error of [`Self::insert`]
+    fn spend(&self, nullifier: Nullifier) -> Result<(), NullifierError> {
+        if !self.insert(nullifier)? {
+            return Err(NullifierError::AlreadySpent(nullifier));
+        }
+        Ok(())
+    }
+}
+
+/// In-memory nullifier set
+#[derive(Debug, Default)]
+pub struct MemoryNullifierSet {
+    spent: RwLock<HashSet<Nullifier>>,
+}
+
+impl MemoryNullifierSet {
+    /// Create an empty set
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl NullifierSet for MemoryNullifierSet {
+    fn contains(&self, nullifier: Nullifier) -> Result<bool, NullifierError> {
+        let spent = self
+            .spent
+            .read()
+            .map_err(|_| NullifierError::Backend("Registry lock poisoned".to_string()))?;
+        Ok(spent.contains(&nullifier))
+    }
+
+    fn insert(&self, nullifier: Nullifier) -> Result<bool, NullifierError> {
+        let mut spent = self
+            .spent
+            .write()
+            .map_err(|_| NullifierError::Backend("Registry lock poisoned".to_string()))?;
+        Ok(spent.insert(nullifier))
+    }
+}
+
+/// File-backed nullifier set
+///
+/// The file is a sequence of 32-byte nullifiers. A record cut short by a
+/// crash mid-write was never reported as spent and is dropped when the file is opened.
+#[derive(Debug)]
+pub struct FsNullifierSet {
+    path: PathBuf,
+    state: Mutex<FsState>,
+}
+
+#[derive(Debug)]
+struct FsState {
+    file: File,
+    spent: HashSet<Nullifier>,
+}
+
+impl FsNullifierSet {
+    /// Open the set stored at `path`, creating an empty one if the file does not exist
+    ///
+    /// # Errors
+    ///
+    /// [`NullifierError::Io`] if the file cannot be created, read or truncated
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, NullifierError> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().read(true).append(true).create(true).open(&path)?;
+
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        let (records, partial) = bytes.as_chunks::<RECORD_LEN>();
+        if !partial.is_empty() {
+            file.set_len((bytes.len() - partial.len()) as u64)?;
+        }
+        let spent = records.iter().copied().map(Nullifier).collect();
+
+        Ok(Self {
+            path,
+            state: Mutex::new(FsState { file, spent }),
+        })
+    }
+
+    /// File backing the set
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn state(&self) -> Result<std::sync::MutexGuard<'_, FsState>, NullifierError> {
+        self.state
+            .lock()
+            .map_err(|_| NullifierError::Backend("Registry lock poisoned".to_string()))
+    }
+}
+
+impl NullifierSet for FsNullifierSet {
+    fn contains(&self, nullifier: Nullifier) -> Result<bool, NullifierError> {
+        Ok(self.state()?.spent.contains(&nullifier))
+    }
+
+    fn insert(&self, nullifier: Nullifier) -> Result<bool, NullifierError> {
+        let mut state = self.state()?;
+        if state.spent.contains(&nullifier) {
+            return Ok(false);
+        }
+        // Durable before the caller mints against it
+        state.file.write_all(&nullifier.0)?;
+        state.file.sync_data()?;
+        state.spent.insert(nullifier);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+    use crate::burn_mint_verifier::XfgBurnMintVerifier;
+    use crate::XfgStarkError;
+
+    #[test]
+    fn test_fs_set_persists_and_drops_torn_record() {
+        let dir = std::env::temp_dir().join(format!("xfg-nullifiers-{}", std::process::id()));
+        let path = dir.join("spent.bin");
+        let _ = std::fs::remove_file(&path);
+
+        let set = FsNullifierSet::open(&path).unwrap();
+        assert!(set.insert(Nullifier([7; 32])).unwrap());
+        assert!(!set.insert(Nullifier([7; 32])).unwrap());
+        set.spend(Nullifier([9; 32])).unwrap();
+        drop(set);
+
+        // A crash in the middle of the next write leaves part of a record
+        OpenOptions::new().append(true).open(&path).unwrap().write_all(&[1, 2, 3]).unwrap();
+
+        let reopened = FsNullifierSet::open(&path).unwrap();
+        assert!(reopened.contains(Nullifier([7; 32])).unwrap());
+        assert!(matches!(reopened.spend(Nullifier([9; 32])), Err(NullifierError::AlreadySpent(Nullifier([9, ..])))));
+        assert!(reopened.insert(Nullifier([11; 32])).unwrap());
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 3 * RECORD_LEN as u64);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_verifier_rejects_spent_nullifier() {
+        let secret = [9u8; 32];
+        let (proof, public_inputs) = XfgBurnMintProver::default()
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &secret,
+            )
+            .expect("Proof generation should succeed");
+        assert_eq!(
+            derive_nullifier(&secret, [7u8; 32], 8_000_000).unwrap(),
+            Nullifier::from_public_inputs(&public_inputs)
+        );
+        // Another burn of the same amount under the same secret has its own nullifier
+        assert_ne!(
+            derive_nullifier(&secret, [8u8; 32], 8_000_000).unwrap(),
+            Nullifier::from_public_inputs(&public_inputs)
+        );
+
+        let verifier = XfgBurnMintVerifier::default();
+        let nullifiers = MemoryNullifierSet::new();
+        assert!(verifier.verify_with_nullifiers(&proof, &public_inputs, &nullifiers).unwrap());
+        assert!(matches!(
+            verifier.verify_with_nullifiers(&proof, &public_inputs, &nullifiers),
+            Err(XfgStarkError::NullifierError(NullifierError::AlreadySpent(_)))
+        ));
+
+        // A claim that fails verification does not spend its nullifier
+        let fresh = MemoryNullifierSet::new();
+        let mut forged = public_inputs.clone();
+        forged.mint_amount = BaseElement::new(9_000_000);
+        assert!(!verifier.verify_with_nullifiers(&proof, &forged, &fresh).unwrap_or(false));
+        assert!(!fresh.contains(Nullifier::from_public_inputs(&public_inputs)).unwrap());
+    }
+}
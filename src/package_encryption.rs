@@ -0,0 +1,403 @@
+//! Recipient Encryption for XFG STARK Proof Packages
+//!
+//! Custodial workflows hand proof packages to third parties that must not be able to
+//! submit them. A [`CompleteProofPackage`] can be encrypted to the mint recipient's
+//! Ethereum public key so only the holder of that key can open it:
+//!
+//! - ECIES over secp256k1: an ephemeral key agrees a shared secret with the recipient key
+//! - HKDF-SHA256 derives a 256-bit key from the shared secret and both public keys
+//! - AES-256-GCM encrypts the package JSON, authenticating the scheme and recipient address
+//!
+//! Packages are stored in a [`PackageEnvelope`], either in the clear or as a
+//! [`RecipientCiphertext`]. The envelope types are always available so encrypted
+//! packages round-trip unchanged; encrypting and decrypting requires the `encryption`
+//! feature.
+
+use crate::proof_data_schema::CompleteProofPackage;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "encryption")]
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm, Nonce,
+};
+#[cfg(feature = "encryption")]
+use hkdf::Hkdf;
+#[cfg(feature = "encryption")]
+use k256::{
+    ecdh::{diffie_hellman, EphemeralSecret},
+    elliptic_curve::sec1::ToEncodedPoint,
+    PublicKey, SecretKey,
+};
+#[cfg(feature = "encryption")]
+use rand::{rngs::OsRng, RngCore};
+#[cfg(feature = "encryption")]
+use sha2::Sha256;
+#[cfg(feature = "encryption")]
+use sha3::{Digest, Keccak256};
+
+/// Identifier of the recipient encryption scheme
+pub const RECIPIENT_ENCRYPTION_SCHEME: &str = "ecies-secp256k1-hkdf-sha256-aes256gcm";
+
+/// HKDF info string for package encryption keys
+#[cfg(feature = "encryption")]
+const PACKAGE_ENCRYPTION_DOMAIN: &[u8] = b"xfg-package-encryption-v1";
+
+/// AES-GCM nonce length (bytes)
+#[cfg(feature = "encryption")]
+const NONCE_LEN: usize = 12;
+
+/// Proof package encrypted to the recipient's secp256k1 key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecipientCiphertext {
+    /// Encryption scheme identifier
+    pub scheme: String,
+    /// Ethereum address of the recipient (lowercase 0x-prefixed hex)
+    pub recipient_address: String,
+    /// Ephemeral secp256k1 public key (uncompressed SEC1, hex string)
+    pub ephemeral_public_key: String,
+    /// AES-GCM nonce (hex string)
+    pub nonce: String,
+    /// Encrypted package JSON with the authentication tag (hex string)
+    pub ciphertext: String,
+}
+
+/// Proof package as stored or handed to a custodian
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "envelope", rename_all = "snake_case")]
+pub enum PackageEnvelope {
+    /// Package in the clear
+    Plain(Box<CompleteProofPackage>),
+    /// Package only the mint recipient can open
+    RecipientEncrypted(RecipientCiphertext),
+}
+
+impl PackageEnvelope {
+    /// Check whether the package is encrypted
+    #[must_use]
+    pub fn is_encrypted(&self) -> bool {
+        matches!(self, PackageEnvelope::RecipientEncrypted(_))
+    }
+
+    /// Save the envelope to a JSON file
+    ///
+    /// # Errors
+    ///
+    /// I/O errors writing the file, or serialization errors
+    pub fn save_to_file(&self, filepath: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(filepath, json)?;
+        Ok(())
+    }
+
+    /// Load an envelope from a JSON file
+    ///
+    /// Bare `CompleteProofPackage` files load as [`PackageEnvelope::Plain`].
+    ///
+    /// # Errors
+    ///
+    /// I/O errors reading the file, or JSON errors if it holds neither an envelope nor a
+    /// package
+    pub fn load_from_file(filepath: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let json = std::fs::read_to_string(filepath)?;
+        match serde_json::from_str(&json) {
+            Ok(envelope) => Ok(envelope),
+            Err(e) => serde_json::from_str::<CompleteProofPackage>(&json)
+                .map(|package| PackageEnvelope::Plain(Box::new(package)))
+                .map_err(|_| e.into()),
+        }
+    }
+}
+
+impl From<CompleteProofPackage> for PackageEnvelope {
+    fn from(package: CompleteProofPackage) -> Self {
+        PackageEnvelope::Plain(Box::new(package))
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl PackageEnvelope {
+    /// Open the envelope with the recipient's secret key
+    ///
+    /// Plain envelopes are returned without using the key.
+    ///
+    /// # Errors
+    ///
+    /// Errors of [`RecipientCiphertext::decrypt_with_key`] for encrypted envelopes
+    pub fn decrypt_with_key(&self, secret_key: &SecretKey) -> Result<CompleteProofPackage, EncryptionError> {
+        match self {
+            PackageEnvelope::Plain(package) => Ok(package.as_ref().clone()),
+            PackageEnvelope::RecipientEncrypted(ciphertext) => ciphertext.decrypt_with_key(secret_key),
+        }
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl CompleteProofPackage {
+    /// Encrypt the package to the recipient's public key
+    ///
+    /// The key must hash to the package's recipient address, so only the mint recipient
+    /// can open the envelope.
+    ///
+    /// # Errors
+    ///
+    /// [`EncryptionError::RecipientMismatch`] if the key is not the recipient's, and
+    /// [`EncryptionError::Serialization`] or [`EncryptionError::Encryption`] if sealing fails
+    pub fn encrypt_for_recipient(&self, recipient_key: &PublicKey) -> Result<PackageEnvelope, EncryptionError> {
+        let recipient_address = ethereum_address(recipient_key);
+        let expected = self.stark_proof_data.recipient.ethereum_address.to_lowercase();
+        if recipient_address != expected {
+            return Err(EncryptionError::RecipientMismatch {
+                expected,
+                actual: recipient_address,
+            });
+        }
+
+        let plaintext = serde_json::to_vec(self).map_err(|e| EncryptionError::Serialization(e.to_string()))?;
+
+        let ephemeral = EphemeralSecret::random(&mut OsRng);
+        let ephemeral_public = ephemeral.public_key();
+        let shared = ephemeral.diffie_hellman(recipient_key);
+        let cipher = package_cipher(shared.raw_secret_bytes(), &ephemeral_public, recipient_key);
+
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+        let aad = associated_data(&recipient_address);
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg: &plaintext, aad: &aad })
+            .map_err(|_| EncryptionError::Encryption)?;
+
+        Ok(PackageEnvelope::RecipientEncrypted(RecipientCiphertext {
+            scheme: RECIPIENT_ENCRYPTION_SCHEME.to_string(),
+            recipient_address,
+            ephemeral_public_key: hex::encode(ephemeral_public.to_sec1_bytes()),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        }))
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl RecipientCiphertext {
+    /// Decrypt the package with the recipient's secret key
+    ///
+    /// # Errors
+    ///
+    /// [`EncryptionError::UnsupportedScheme`] for an unknown scheme,
+    /// [`EncryptionError::RecipientMismatch`] if the key is not the recipient's,
+    /// [`EncryptionError::InvalidKey`] or [`EncryptionError::InvalidEncoding`] for malformed
+    /// fields, and [`EncryptionError::Decryption`] if the ciphertext does not authenticate
+    pub fn decrypt_with_key(&self, secret_key: &SecretKey) -> Result<CompleteProofPackage, EncryptionError> {
+        if self.scheme != RECIPIENT_ENCRYPTION_SCHEME {
+            return Err(EncryptionError::UnsupportedScheme(self.scheme.clone()));
+        }
+
+        let recipient_key = secret_key.public_key();
+        let address = ethereum_address(&recipient_key);
+        if address != self.recipient_address {
+            return Err(EncryptionError::RecipientMismatch {
+                expected: self.recipient_address.clone(),
+                actual: address,
+            });
+        }
+
+        let ephemeral_public = decode_hex(&self.ephemeral_public_key, "ephemeral public key")
+            .and_then(|bytes| {
+                PublicKey::from_sec1_bytes(&bytes).map_err(|e| EncryptionError::InvalidKey(e.to_string()))
+            })?;
+        let nonce = decode_hex(&self.nonce, "nonce")?;
+        if nonce.len() != NONCE_LEN {
+            return Err(EncryptionError::InvalidEncoding(format!("nonce must be {NONCE_LEN} bytes")));
+        }
+        let ciphertext = decode_hex(&self.ciphertext, "ciphertext")?;
+
+        let shared = diffie_hellman(secret_key.to_nonzero_scalar(), ephemeral_public.as_affine());
+        let cipher = package_cipher(shared.raw_secret_bytes(), &ephemeral_public, &recipient_key);
+        let aad = associated_data(&self.recipient_address);
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: &aad })
+            .map_err(|_| EncryptionError::Decryption)?;
+
+        serde_json::from_slice(&plaintext).map_err(|e| EncryptionError::Serialization(e.to_string()))
+    }
+}
+
+/// Ethereum address of a secp256k1 public key (lowercase 0x-prefixed hex)
+#[cfg(feature = "encryption")]
+#[must_use]
+pub fn ethereum_address(public_key: &PublicKey) -> String {
+    let encoded = public_key.to_encoded_point(false);
+    let hash = Keccak256::digest(&encoded.as_bytes()[1..]);
+    format!("0x{}", hex::encode(&hash[12..]))
+}
+
+/// Parse a recipient public key from hex
+///
+/// Accepts compressed (33 bytes) and uncompressed (65 bytes) SEC1 keys, and the raw
+/// 64-byte `x || y` form used by Ethereum tooling.
+///
+/// # Errors
+///
+/// [`EncryptionError::InvalidEncoding`] if the hex is malformed, and
+/// [`EncryptionError::InvalidKey`] if the bytes are not a secp256k1 public key
+#[cfg(feature = "encryption")]
+pub fn parse_recipient_public_key(hex_key: &str) -> Result<PublicKey, EncryptionError> {
+    let mut bytes = decode_hex(hex_key.trim().trim_start_matches("0x"), "public key")?;
+    if bytes.len() == 64 {
+        bytes.insert(0, 0x04);
+    }
+    PublicKey::from_sec1_bytes(&bytes).map_err(|e| EncryptionError::InvalidKey(e.to_string()))
+}
+
+/// Parse a recipient secret key from 32 hex-encoded bytes
+///
+/// # Errors
+///
+/// [`EncryptionError::InvalidEncoding`] if the hex is malformed, and
+/// [`EncryptionError::InvalidKey`] if the bytes are not a secp256k1 secret key
+#[cfg(feature = "encryption")]
+pub fn parse_recipient_secret_key(hex_key: &str) -> Result<SecretKey, EncryptionError> {
+    let bytes = decode_hex(hex_key.trim().trim_start_matches("0x"), "secret key")?;
+    SecretKey::from_slice(&bytes).map_err(|e| EncryptionError::InvalidKey(e.to_string()))
+}
+
+/// AES-256-GCM cipher keyed by HKDF-SHA256(shared secret, ephemeral key || recipient key)
+#[cfg(feature = "encryption")]
+fn package_cipher(shared_secret: &[u8], ephemeral_public: &PublicKey, recipient_key: &PublicKey) -> Aes256Gcm {
+    let mut salt = ephemeral_public.to_sec1_bytes().into_vec();
+    salt.extend_from_slice(&recipient_key.to_sec1_bytes());
+
+    let mut key = [0u8; 32];
+    Hkdf::<Sha256>::new(Some(&salt), shared_secret)
+        .expand(PACKAGE_ENCRYPTION_DOMAIN, &mut key)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    Aes256Gcm::new(&key.into())
+}
+
+/// Data authenticated alongside the ciphertext: scheme || recipient address
+#[cfg(feature = "encryption")]
+fn associated_data(recipient_address: &str) -> Vec<u8> {
+    [RECIPIENT_ENCRYPTION_SCHEME.as_bytes(), recipient_address.as_bytes()].concat()
+}
+
+#[cfg(feature = "encryption")]
+fn decode_hex(value: &str, field: &str) -> Result<Vec<u8>, EncryptionError> {
+    hex::decode(value).map_err(|e| EncryptionError::InvalidEncoding(format!("{field}: {e}")))
+}
+
+/// Package encryption error types
+#[derive(Debug, thiserror::Error)]
+pub enum EncryptionError {
+    /// Malformed public or secret key
+    #[error("Invalid key: {0}")]
+    InvalidKey(String),
+
+    /// Key does not belong to the package recipient
+    #[error("Key belongs to {actual}, but the package recipient is {expected}")]
+    RecipientMismatch {
+        /// Recipient address of the package
+        expected: String,
+        /// Address derived from the key
+        actual: String,
+    },
+
+    /// Envelope uses an unknown encryption scheme
+    #[error("Unsupported encryption scheme: {0}")]
+    UnsupportedScheme(String),
+
+    /// Malformed hex field in the envelope
+    #[error("Invalid envelope encoding: {0}")]
+    InvalidEncoding(String),
+
+    /// Encryption failed
+    #[error("Package encryption failed")]
+    Encryption,
+
+    /// Ciphertext did not authenticate under the key
+    #[error("Package decryption failed: ciphertext does not authenticate")]
+    Decryption,
+
+    /// Package JSON could not be encoded or decoded
+    #[error("Package serialization failed: {0}")]
+    Serialization(String),
+}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests {
+    use super::*;
+    use crate::proof_data_schema::StarkProofDataPackage;
+
+    fn recipient_key(seed: u8) -> SecretKey {
+        SecretKey::from_slice(&[seed; 32]).unwrap()
+    }
+
+    fn test_package(recipient: &SecretKey) -> CompleteProofPackage {
+        CompleteProofPackage::new(StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            ethereum_address(&recipient.public_key()),
+            "my-secret-key-123".to_string(),
+            "fuego-testnet".to_string(),
+        ))
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt_round_trip() {
+        let key = recipient_key(7);
+        let package = test_package(&key);
+
+        let envelope = package.encrypt_for_recipient(&key.public_key()).unwrap();
+        assert!(envelope.is_encrypted());
+
+        // The secret never appears in the stored envelope
+        let json = serde_json::to_string(&envelope).unwrap();
+        assert!(json.contains("\"envelope\":\"recipient_encrypted\""));
+        assert!(!json.contains("my-secret-key-123"));
+
+        let envelope: PackageEnvelope = serde_json::from_str(&json).unwrap();
+        let opened = envelope.decrypt_with_key(&key).unwrap();
+        assert_eq!(serde_json::to_value(&opened).unwrap(), serde_json::to_value(&package).unwrap());
+    }
+
+    #[test]
+    fn test_only_recipient_can_open() {
+        let key = recipient_key(7);
+        let other = recipient_key(8);
+        let package = test_package(&key);
+
+        // Packages cannot be encrypted to anyone but their recipient
+        assert!(matches!(
+            package.encrypt_for_recipient(&other.public_key()),
+            Err(EncryptionError::RecipientMismatch { .. })
+        ));
+
+        let envelope = package.encrypt_for_recipient(&key.public_key()).unwrap();
+        assert!(matches!(
+            envelope.decrypt_with_key(&other),
+            Err(EncryptionError::RecipientMismatch { .. })
+        ));
+
+        // Tampering with the ciphertext or the authenticated address is detected
+        let PackageEnvelope::RecipientEncrypted(mut ciphertext) = envelope else {
+            unreachable!("encrypt_for_recipient returns an encrypted envelope");
+        };
+        let mut bytes = hex::decode(&ciphertext.ciphertext).unwrap();
+        bytes[0] ^= 1;
+        ciphertext.ciphertext = hex::encode(bytes);
+        assert!(matches!(ciphertext.decrypt_with_key(&key), Err(EncryptionError::Decryption)));
+    }
+
+    #[test]
+    fn test_parse_recipient_public_key_forms() {
+        let public_key = recipient_key(9).public_key();
+        let uncompressed = public_key.to_encoded_point(false);
+
+        for encoded in [
+            hex::encode(uncompressed.as_bytes()),
+            format!("0x{}", hex::encode(&uncompressed.as_bytes()[1..])),
+            hex::encode(public_key.to_encoded_point(true).as_bytes()),
+        ] {
+            assert_eq!(parse_recipient_public_key(&encoded).unwrap(), public_key);
+        }
+        assert!(parse_recipient_public_key("0x1234").is_err());
+    }
+}
@@ -0,0 +1,581 @@
+//! Package Signing for XFG STARK Data Packages
+//!
+//! This module provides a signature envelope for `StarkProofDataPackage` files,
+//! allowing a package to carry several Ed25519 signatures from rotating signing keys.
+//!
+//! ## Key Rotation
+//! Every signing key is registered in the envelope with a key ID and a validity window
+//! (`valid_from` inclusive, `valid_until` exclusive, both UNIX seconds). A signature is
+//! accepted when the key that produced it was valid at the signature's `signed_at` time,
+//! so packages signed before a rotation keep verifying after the old key is retired.
+//!
+//! ## Trust
+//! The key records in an envelope only tell a reader which keys signed; they never
+//! establish trust, since anyone can add a key to a package. Verification takes a
+//! [`TrustedSigningKeys`] set from the caller and checks each signature against the
+//! caller's record of that key. Every signature covers the signer's key ID, public key
+//! and `valid_from` as well as the package digest.
+//!
+//! `signed_at` is chosen by the signer, so a retired key could otherwise backdate a
+//! signature into its old window. A package signature is therefore rejected when its
+//! `signed_at` precedes the burn transaction's timestamp: a retired key can only sign
+//! packages for burns made before it was retired.
+//!
+//! The envelope types are always available so packages round-trip unchanged; creating
+//! and checking signatures requires the `signing` feature.
+
+use crate::proof_data_schema::StarkProofDataPackage;
+#[cfg(feature = "signing")]
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+/// Domain separator for package signing digests
+const PACKAGE_SIGNING_DOMAIN: &[u8] = b"xfg-package-signature-v1";
+
+/// Signing key registered in a signature envelope
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SigningKeyRecord {
+    /// Key identifier referenced by signatures
+    pub key_id: String,
+    /// Ed25519 public key (hex string)
+    pub public_key: String,
+    /// Start of the validity window (UNIX seconds, inclusive)
+    pub valid_from: u64,
+    /// End of the validity window (UNIX seconds, exclusive), `None` while the key is active
+    #[serde(default)]
+    pub valid_until: Option<u64>,
+}
+
+impl SigningKeyRecord {
+    /// Check whether the key was valid at the given time
+    #[must_use]
+    pub fn is_valid_at(&self, timestamp: u64) -> bool {
+        timestamp >= self.valid_from && self.valid_until.is_none_or(|until| timestamp < until)
+    }
+
+    /// Decode the Ed25519 verifying key
+    ///
+    /// # Errors
+    ///
+    /// [`SigningError::InvalidKey`] if the stored public key is not a valid Ed25519 key
+    #[cfg(feature = "signing")]
+    pub fn verifying_key(&self) -> Result<VerifyingKey, SigningError> {
+        let bytes = hex::decode(&self.public_key)
+            .map_err(|e| SigningError::InvalidKey(format!("{}: {}", self.key_id, e)))?;
+        let bytes: [u8; 32] = bytes.try_into().map_err(|_| {
+            SigningError::InvalidKey(format!("{}: public key must be 32 bytes", self.key_id))
+        })?;
+        VerifyingKey::from_bytes(&bytes)
+            .map_err(|e| SigningError::InvalidKey(format!("{}: {}", self.key_id, e)))
+    }
+}
+
+/// Signing keys a verifier trusts, with their validity windows
+///
+/// Built from the verifier's own configuration, never from the envelope being checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrustedSigningKeys {
+    keys: Vec<SigningKeyRecord>,
+}
+
+impl TrustedSigningKeys {
+    /// Trust `keys`; key IDs must be distinct
+    ///
+    /// # Errors
+    ///
+    /// [`SigningError::DuplicateKeyId`] if two keys share an ID
+    pub fn new(keys: Vec<SigningKeyRecord>) -> Result<Self, SigningError> {
+        for (index, key) in keys.iter().enumerate() {
+            if keys[..index].iter().any(|earlier| earlier.key_id == key.key_id) {
+                return Err(SigningError::DuplicateKeyId(key.key_id.clone()));
+            }
+        }
+        Ok(Self { keys })
+    }
+
+    /// Look up a trusted key by ID
+    #[must_use]
+    pub fn key(&self, key_id: &str) -> Option<&SigningKeyRecord> {
+        self.keys.iter().find(|key| key.key_id == key_id)
+    }
+}
+
+/// Signature over a package digest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageSignature {
+    /// Key identifier of the signing key
+    pub key_id: String,
+    /// Ed25519 signature (hex string)
+    pub signature: String,
+    /// Signing time (UNIX seconds)
+    pub signed_at: u64,
+}
+
+/// Signature envelope holding signing keys and their signatures
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureEnvelope {
+    /// Registered signing keys, including retired ones
+    #[serde(default)]
+    pub keys: Vec<SigningKeyRecord>,
+    /// Signatures over the package digest
+    #[serde(default)]
+    pub signatures: Vec<PackageSignature>,
+}
+
+/// Outcome of verifying a signature envelope
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureVerificationReport {
+    /// Key IDs whose signatures verified
+    pub valid_key_ids: Vec<String>,
+    /// Rejected signatures with the reason
+    pub rejected: Vec<(String, String)>,
+}
+
+impl SignatureVerificationReport {
+    /// Check whether at least one signature verified
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        !self.valid_key_ids.is_empty()
+    }
+}
+
+impl SignatureEnvelope {
+    /// Create an empty envelope
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Look up a key by ID
+    #[must_use]
+    pub fn key(&self, key_id: &str) -> Option<&SigningKeyRecord> {
+        self.keys.iter().find(|key| key.key_id == key_id)
+    }
+}
+
+#[cfg(feature = "signing")]
+impl SignatureEnvelope {
+    /// Register a new signing key valid from `valid_from`
+    ///
+    /// # Errors
+    ///
+    /// [`SigningError::DuplicateKeyId`] if `key_id` is already registered
+    pub fn add_key(
+        &mut self,
+        key_id: &str,
+        verifying_key: &VerifyingKey,
+        valid_from: u64,
+    ) -> Result<(), SigningError> {
+        if self.key(key_id).is_some() {
+            return Err(SigningError::DuplicateKeyId(key_id.to_string()));
+        }
+
+        self.keys.push(SigningKeyRecord {
+            key_id: key_id.to_string(),
+            public_key: hex::encode(verifying_key.to_bytes()),
+            valid_from,
+            valid_until: None,
+        });
+        Ok(())
+    }
+
+    /// Retire `old_key_id` at `rotated_at` and register `new_key_id` from the same time
+    ///
+    /// # Errors
+    ///
+    /// [`SigningError::DuplicateKeyId`] if `new_key_id` is already registered,
+    /// [`SigningError::UnknownKeyId`] if `old_key_id` is not, and
+    /// [`SigningError::KeyNotValid`] if the old key is not valid at `rotated_at`
+    pub fn rotate_key(
+        &mut self,
+        old_key_id: &str,
+        new_key_id: &str,
+        new_verifying_key: &VerifyingKey,
+        rotated_at: u64,
+    ) -> Result<(), SigningError> {
+        if self.key(new_key_id).is_some() {
+            return Err(SigningError::DuplicateKeyId(new_key_id.to_string()));
+        }
+
+        let old_key = self
+            .keys
+            .iter_mut()
+            .find(|key| key.key_id == old_key_id)
+            .ok_or_else(|| SigningError::UnknownKeyId(old_key_id.to_string()))?;
+
+        if !old_key.is_valid_at(rotated_at) {
+            return Err(SigningError::KeyNotValid {
+                key_id: old_key_id.to_string(),
+                timestamp: rotated_at,
+            });
+        }
+
+        old_key.valid_until = Some(rotated_at);
+        self.add_key(new_key_id, new_verifying_key, rotated_at)
+    }
+
+    /// Sign a package digest with a registered key
+    ///
+    /// The signature covers the digest, the key's ID, public key and `valid_from`, and
+    /// `signed_at`.
+    ///
+    /// # Errors
+    ///
+    /// [`SigningError::UnknownKeyId`] if `key_id` is not registered,
+    /// [`SigningError::InvalidKey`] if `signing_key` does not match it, and
+    /// [`SigningError::KeyNotValid`] if the key is not valid at `signed_at`
+    pub fn sign(
+        &mut self,
+        digest: &[u8; 32],
+        key_id: &str,
+        signing_key: &SigningKey,
+        signed_at: u64,
+    ) -> Result<(), SigningError> {
+        let record = self
+            .key(key_id)
+            .ok_or_else(|| SigningError::UnknownKeyId(key_id.to_string()))?;
+
+        if record.verifying_key()? != signing_key.verifying_key() {
+            return Err(SigningError::InvalidKey(format!(
+                "{key_id}: signing key does not match registered public key"
+            )));
+        }
+
+        if !record.is_valid_at(signed_at) {
+            return Err(SigningError::KeyNotValid {
+                key_id: key_id.to_string(),
+                timestamp: signed_at,
+            });
+        }
+
+        let signature = signing_key.sign(&Self::signing_message(digest, record, signed_at)?);
+        self.signatures.push(PackageSignature {
+            key_id: key_id.to_string(),
+            signature: hex::encode(signature.to_bytes()),
+            signed_at,
+        });
+        Ok(())
+    }
+
+    /// Verify all signatures against a package digest and the caller's trusted keys
+    ///
+    /// Signatures by keys outside `trusted_keys`, or made before `not_before` (UNIX
+    /// seconds), are rejected.
+    #[must_use]
+    pub fn verify(
+        &self,
+        digest: &[u8; 32],
+        trusted_keys: &TrustedSigningKeys,
+        not_before: u64,
+    ) -> SignatureVerificationReport {
+        let mut report = SignatureVerificationReport {
+            valid_key_ids: Vec::new(),
+            rejected: Vec::new(),
+        };
+
+        for signature in &self.signatures {
+            match Self::verify_signature(digest, signature, trusted_keys, not_before) {
+                Ok(()) => report.valid_key_ids.push(signature.key_id.clone()),
+                Err(e) => report.rejected.push((signature.key_id.clone(), e.to_string())),
+            }
+        }
+
+        report
+    }
+
+    /// Verify a single signature
+    fn verify_signature(
+        digest: &[u8; 32],
+        signature: &PackageSignature,
+        trusted_keys: &TrustedSigningKeys,
+        not_before: u64,
+    ) -> Result<(), SigningError> {
+        let record = trusted_keys
+            .key(&signature.key_id)
+            .ok_or_else(|| SigningError::UnknownKeyId(signature.key_id.clone()))?;
+
+        if signature.signed_at < not_before {
+            return Err(SigningError::SignedTooEarly {
+                key_id: signature.key_id.clone(),
+                signed_at: signature.signed_at,
+                not_before,
+            });
+        }
+
+        if !record.is_valid_at(signature.signed_at) {
+            return Err(SigningError::KeyNotValid {
+                key_id: signature.key_id.clone(),
+                timestamp: signature.signed_at,
+            });
+        }
+
+        let bytes = hex::decode(&signature.signature)
+            .map_err(|e| SigningError::InvalidSignature(e.to_string()))?;
+        let signature_bytes: [u8; 64] = bytes
+            .try_into()
+            .map_err(|_| SigningError::InvalidSignature("signature must be 64 bytes".to_string()))?;
+
+        record
+            .verifying_key()?
+            .verify(
+                &Self::signing_message(digest, record, signature.signed_at)?,
+                &Signature::from_bytes(&signature_bytes),
+            )
+            .map_err(|e| SigningError::InvalidSignature(e.to_string()))
+    }
+
+    /// Message signed by a key:
+    /// `digest || le64(len) || key_id || public_key || le64(valid_from) || le64(signed_at)`
+    ///
+    /// `valid_until` is left out: rotation sets it after the key's signatures exist.
+    fn signing_message(digest: &[u8; 32], record: &SigningKeyRecord, signed_at: u64) -> Result<Vec<u8>, SigningError> {
+        let public_key = record.verifying_key()?.to_bytes();
+        let mut message = Vec::with_capacity(88 + record.key_id.len());
+        message.extend_from_slice(digest);
+        message.extend_from_slice(&(record.key_id.len() as u64).to_le_bytes());
+        message.extend_from_slice(record.key_id.as_bytes());
+        message.extend_from_slice(&public_key);
+        message.extend_from_slice(&record.valid_from.to_le_bytes());
+        message.extend_from_slice(&signed_at.to_le_bytes());
+        Ok(message)
+    }
+}
+
+impl StarkProofDataPackage {
+    /// Compute the digest covered by package signatures
+    ///
+    /// The digest binds the burn transaction, recipient, metadata and additional data.
+    /// The secret and the signature envelope itself are excluded; each signature binds its
+    /// own key record instead (see [`SignatureEnvelope::sign`]). A migrated package is
+    /// bound to the schema version it was written in, so migration keeps signatures valid.
+    #[must_use]
+    pub fn signing_digest(&self) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        hasher.update(PACKAGE_SIGNING_DOMAIN);
+
+        for field in [
+            self.metadata.migrated_from.as_ref().unwrap_or(&self.metadata.version),
+            &self.metadata.network,
+            &self.burn_transaction.transaction_hash,
+            &self.burn_transaction.burn_amount_xfg,
+            &self.burn_transaction.network_id,
+            &self.recipient.ethereum_address,
+        ] {
+            hasher.update((field.len() as u64).to_le_bytes());
+            hasher.update(field.as_bytes());
+        }
+
+        hasher.update(self.burn_transaction.burn_amount_atomic.to_le_bytes());
+        hasher.update(self.burn_transaction.block_height.to_le_bytes());
+        hasher.update(self.burn_transaction.timestamp.to_le_bytes());
+
+        // HashMap iteration order is not stable, so sort additional data by key
+        let mut additional: Vec<_> = self.additional_data.iter().collect();
+        additional.sort();
+        for (key, value) in additional {
+            hasher.update((key.len() as u64).to_le_bytes());
+            hasher.update(key.as_bytes());
+            hasher.update((value.len() as u64).to_le_bytes());
+            hasher.update(value.as_bytes());
+        }
+
+        hasher.finalize().into()
+    }
+}
+
+#[cfg(feature = "signing")]
+impl StarkProofDataPackage {
+    /// Sign the package with a registered key
+    ///
+    /// # Errors
+    ///
+    /// As [`SignatureEnvelope::sign`]
+    pub fn sign_package(
+        &mut self,
+        key_id: &str,
+        signing_key: &SigningKey,
+        signed_at: u64,
+    ) -> Result<(), SigningError> {
+        let digest = self.signing_digest();
+        self.signatures.sign(&digest, key_id, signing_key, signed_at)
+    }
+
+    /// Verify the package signatures against the caller's trusted keys
+    ///
+    /// A signature must not predate the burn transaction it covers.
+    #[must_use]
+    pub fn verify_signatures(&self, trusted_keys: &TrustedSigningKeys) -> SignatureVerificationReport {
+        self.signatures
+            .verify(&self.signing_digest(), trusted_keys, self.burn_transaction.timestamp)
+    }
+}
+
+/// Package signing error types
+#[derive(Debug, thiserror::Error)]
+pub enum SigningError {
+    /// Key ID already registered
+    #[error("Duplicate signing key ID: {0}")]
+    DuplicateKeyId(String),
+
+    /// Key ID not registered
+    #[error("Unknown signing key ID: {0}")]
+    UnknownKeyId(String),
+
+    /// Key not valid at the requested time
+    #[error("Signing key {key_id} not valid at {timestamp}")]
+    KeyNotValid {
+        /// Key identifier
+        key_id: String,
+        /// Requested time (UNIX seconds)
+        timestamp: u64,
+    },
+
+    /// Signature time before the content it covers
+    #[error("Signature by {key_id} at {signed_at} predates {not_before}")]
+    SignedTooEarly {
+        /// Key identifier
+        key_id: String,
+        /// Claimed signing time (UNIX seconds)
+        signed_at: u64,
+        /// Earliest accepted signing time (UNIX seconds)
+        not_before: u64,
+    },
+
+    /// Malformed or mismatched key
+    #[error("Invalid signing key: {0}")]
+    InvalidKey(String),
+
+    /// Malformed or non-verifying signature
+    #[error("Invalid signature: {0}")]
+    InvalidSignature(String),
+}
+
+#[cfg(all(test, feature = "signing"))]
+mod tests {
+    use super::*;
+
+    fn test_package() -> StarkProofDataPackage {
+        StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "my-secret-key-123".to_string(),
+            "fuego-testnet".to_string(),
+        )
+    }
+
+    /// Trust exactly the keys the package registers, as its publisher would
+    fn trusted(package: &StarkProofDataPackage) -> TrustedSigningKeys {
+        TrustedSigningKeys::new(package.signatures.keys.clone()).unwrap()
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let mut package = test_package();
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+
+        package.signatures.add_key("key-1", &key.verifying_key(), 1_000).unwrap();
+        package.sign_package("key-1", &key, 1_500).unwrap();
+
+        let report = package.verify_signatures(&trusted(&package));
+        assert!(report.is_valid());
+        assert_eq!(report.valid_key_ids, vec!["key-1".to_string()]);
+    }
+
+    #[test]
+    fn test_rotation_keeps_old_signatures_valid() {
+        let mut package = test_package();
+        let old_key = SigningKey::from_bytes(&[1u8; 32]);
+        let new_key = SigningKey::from_bytes(&[2u8; 32]);
+
+        package.signatures.add_key("old", &old_key.verifying_key(), 1_000).unwrap();
+        package.sign_package("old", &old_key, 1_500).unwrap();
+        package
+            .signatures
+            .rotate_key("old", "new", &new_key.verifying_key(), 2_000)
+            .unwrap();
+        package.sign_package("new", &new_key, 2_500).unwrap();
+
+        // The retired key can no longer sign
+        assert!(package.sign_package("old", &old_key, 2_500).is_err());
+
+        let report = package.verify_signatures(&trusted(&package));
+        assert_eq!(report.valid_key_ids, vec!["old".to_string(), "new".to_string()]);
+        assert!(report.rejected.is_empty());
+    }
+
+    #[test]
+    fn test_tampered_package_rejected() {
+        let mut package = test_package();
+        let key = SigningKey::from_bytes(&[3u8; 32]);
+
+        package.signatures.add_key("key-1", &key.verifying_key(), 0).unwrap();
+        package.sign_package("key-1", &key, 10).unwrap();
+        package.recipient.ethereum_address = "0x0000000000000000000000000000000000000001".to_string();
+
+        let report = package.verify_signatures(&trusted(&package));
+        assert!(!report.is_valid());
+        assert_eq!(report.rejected.len(), 1);
+    }
+
+    #[test]
+    fn test_signature_outside_validity_window_rejected() {
+        let mut package = test_package();
+        let key = SigningKey::from_bytes(&[4u8; 32]);
+
+        package.signatures.add_key("key-1", &key.verifying_key(), 0).unwrap();
+        package.sign_package("key-1", &key, 10).unwrap();
+
+        // The verifier only trusts the key from a later time
+        let mut record = package.signatures.keys[0].clone();
+        record.valid_from = 100;
+        let trusted = TrustedSigningKeys::new(vec![record]).unwrap();
+        assert!(!package.verify_signatures(&trusted).is_valid());
+    }
+
+    #[test]
+    fn test_self_registered_key_rejected() {
+        let mut package = test_package();
+        let publisher = SigningKey::from_bytes(&[5u8; 32]);
+        let attacker = SigningKey::from_bytes(&[6u8; 32]);
+
+        package.signatures.add_key("publisher", &publisher.verifying_key(), 0).unwrap();
+        let trusted = trusted(&package);
+
+        // Anyone can register a key in the envelope and sign with it
+        package.signatures.add_key("attacker", &attacker.verifying_key(), 0).unwrap();
+        package.sign_package("attacker", &attacker, 10).unwrap();
+        // Or re-register a trusted key ID under their own public key
+        package.signatures.keys[0].public_key = hex::encode(attacker.verifying_key().to_bytes());
+        package.sign_package("publisher", &attacker, 10).unwrap();
+
+        let report = package.verify_signatures(&trusted);
+        assert!(!report.is_valid());
+        assert_eq!(report.rejected.len(), 2);
+        assert!(report.rejected[0].1.contains("Unknown signing key ID"));
+        assert!(report.rejected[1].1.contains("Invalid signature"));
+    }
+
+    #[test]
+    fn test_backdated_signature_rejected() {
+        let mut package = test_package();
+        let old_key = SigningKey::from_bytes(&[1u8; 32]);
+        let new_key = SigningKey::from_bytes(&[2u8; 32]);
+
+        package.signatures.add_key("old", &old_key.verifying_key(), 1_000).unwrap();
+        package
+            .signatures
+            .rotate_key("old", "new", &new_key.verifying_key(), 2_000)
+            .unwrap();
+        let trusted = trusted(&package);
+
+        // A burn after the rotation, signed by the retired key claiming an earlier time
+        package.burn_transaction.timestamp = 3_000;
+        package.sign_package("old", &old_key, 1_500).unwrap();
+
+        let report = package.verify_signatures(&trusted);
+        assert!(!report.is_valid());
+        assert!(report.rejected[0].1.contains("predates 3000"), "{:?}", report.rejected);
+    }
+}
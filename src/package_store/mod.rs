@@ -0,0 +1,590 @@
+//! Package Storage Backends
+//!
+//! This module provides the `PackageStore` trait for persisting data packages and
+//! proofs outside the local filesystem. Objects are content-addressed by the SHA-256
+//! digest of their JSON encoding and indexed by burn transaction hash.
+//!
+//! ## Backends
+//! - **Filesystem**: `FsPackageStore`, objects stored under a root directory
+//! - **In-memory**: `MemoryPackageStore`, for tests and short-lived services
+//! - **S3-compatible**: `S3PackageStore`, behind the `s3` feature
+//!
+//! ## Object Layout
+//! ```text
+//! packages/<digest>.json       package JSON
+//! packages/by-tx/<tx_hash>     digest of the latest package for a transaction
+//! proofs/<digest>.json         proof JSON
+//! proofs/by-tx/<tx_hash>       digest of the latest proof for a transaction
+//! ```
+//!
+//! Stores are opened from a URI with `open_store`: `file:///var/lib/xfg`, `memory://`
+//! or `s3://bucket/prefix`. A bare path is treated as a filesystem store. A single
+//! object is referenced as `<store-uri>#<tx-hash>` or `<store-uri>#sha256:<digest>`
+//! with `StoreRef`.
+
+#[cfg(feature = "s3")]
+mod s3;
+
+#[cfg(feature = "s3")]
+pub use s3::S3PackageStore;
+
+use crate::proof_data_schema::{StarkProof, StarkProofDataPackage};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// Kind of object held in a store
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StoredKind {
+    /// `StarkProofDataPackage`
+    Package,
+    /// `StarkProof`
+    Proof,
+}
+
+impl StoredKind {
+    /// Key prefix for objects of this kind
+    #[must_use]
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            StoredKind::Package => "packages",
+            StoredKind::Proof => "proofs",
+        }
+    }
+}
+
+impl Display for StoredKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoredKind::Package => write!(f, "package"),
+            StoredKind::Proof => write!(f, "proof"),
+        }
+    }
+}
+
+/// How to look up a stored object
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StoreLookup {
+    /// SHA-256 digest of the object (hex)
+    Digest(String),
+    /// Burn transaction hash (hex)
+    TxHash(String),
+}
+
+/// Entry returned when listing a store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredEntry {
+    /// Burn transaction hash (normalized hex)
+    pub tx_hash: String,
+    /// SHA-256 digest of the object (hex)
+    pub digest: String,
+}
+
+/// Storage backend for packages and proofs
+///
+/// Backends implement the three raw object operations; the keyed get/put/list
+/// operations and the typed helpers are provided on top of them.
+pub trait PackageStore: Send + Sync {
+    /// Read an object, returning `None` if it does not exist
+    ///
+    /// # Errors
+    ///
+    /// [`StoreError`] if the backend cannot be read
+    fn read_object(&self, key: &str) -> Result<Option<Vec<u8>>, StoreError>;
+
+    /// Write an object, replacing any existing one
+    ///
+    /// # Errors
+    ///
+    /// [`StoreError`] if the backend cannot be written
+    fn write_object(&self, key: &str, bytes: &[u8]) -> Result<(), StoreError>;
+
+    /// List the keys of objects directly under a prefix ending in `/`
+    ///
+    /// # Errors
+    ///
+    /// [`StoreError`] if the backend cannot be listed
+    fn list_objects(&self, prefix: &str) -> Result<Vec<String>, StoreError>;
+
+    /// Store an object for a transaction, returning its digest
+    ///
+    /// # Errors
+    ///
+    /// [`StoreError::InvalidKey`] if `tx_hash` is not hex, and backend write errors
+    fn put(&self, kind: StoredKind, tx_hash: &str, bytes: &[u8]) -> Result<String, StoreError> {
+        let tx_hash = normalize_id(tx_hash)?;
+        let digest = hex::encode(Sha256::digest(bytes));
+
+        self.write_object(&object_key(kind, &digest), bytes)?;
+        self.write_object(&index_key(kind, &tx_hash), digest.as_bytes())?;
+        Ok(digest)
+    }
+
+    /// Fetch an object by digest or transaction hash
+    ///
+    /// The fetched bytes are re-hashed and must match the digest they were looked up by.
+    ///
+    /// # Errors
+    ///
+    /// [`StoreError::InvalidKey`] if the digest or transaction hash is not hex,
+    /// [`StoreError::Backend`] if the index entry is corrupt,
+    /// [`StoreError::DigestMismatch`] if the object does not hash to its digest, and
+    /// backend read errors
+    fn get(&self, kind: StoredKind, lookup: &StoreLookup) -> Result<Option<Vec<u8>>, StoreError> {
+        let digest = match lookup {
+            StoreLookup::Digest(digest) => normalize_digest(digest)?,
+            StoreLookup::TxHash(tx_hash) => {
+                match self.read_object(&index_key(kind, &normalize_id(tx_hash)?))? {
+                    Some(bytes) => parse_index_entry(bytes)?,
+                    None => return Ok(None),
+                }
+            }
+        };
+
+        let Some(bytes) = self.read_object(&object_key(kind, &digest))? else {
+            return Ok(None);
+        };
+        let actual = hex::encode(Sha256::digest(&bytes));
+        if actual != digest {
+            return Err(StoreError::DigestMismatch {
+                expected: digest,
+                actual,
+            });
+        }
+        Ok(Some(bytes))
+    }
+
+    /// List stored objects of a kind by transaction hash
+    ///
+    /// # Errors
+    ///
+    /// [`StoreError::Backend`] if an index entry is corrupt, and backend list and read errors
+    fn list(&self, kind: StoredKind) -> Result<Vec<StoredEntry>, StoreError> {
+        let prefix = format!("{}/by-tx/", kind.prefix());
+        let mut entries = Vec::new();
+
+        for key in self.list_objects(&prefix)? {
+            let tx_hash = key[prefix.len()..].to_string();
+            if let Some(bytes) = self.read_object(&key)? {
+                let digest = parse_index_entry(bytes)?;
+                entries.push(StoredEntry { tx_hash, digest });
+            }
+        }
+
+        entries.sort_by(|a, b| a.tx_hash.cmp(&b.tx_hash));
+        Ok(entries)
+    }
+
+    /// Store a data package, returning its digest
+    ///
+    /// # Errors
+    ///
+    /// [`StoreError::Serialization`] if the package cannot be encoded, and errors of [`Self::put`]
+    fn put_package(&self, package: &StarkProofDataPackage) -> Result<String, StoreError> {
+        let bytes = serde_json::to_vec_pretty(package)
+            .map_err(|e| StoreError::Serialization(e.to_string()))?;
+        self.put(StoredKind::Package, &package.burn_transaction.transaction_hash, &bytes)
+    }
+
+    /// Fetch a data package
+    ///
+    /// # Errors
+    ///
+    /// [`StoreError::Serialization`] if the stored package cannot be decoded, and errors of
+    /// [`Self::get`]
+    fn get_package(&self, lookup: &StoreLookup) -> Result<Option<StarkProofDataPackage>, StoreError> {
+        self.get(StoredKind::Package, lookup)?
+            .map(|bytes| {
+                serde_json::from_slice(&bytes).map_err(|e| StoreError::Serialization(e.to_string()))
+            })
+            .transpose()
+    }
+
+    /// Store a proof, returning its digest
+    ///
+    /// # Errors
+    ///
+    /// [`StoreError::Serialization`] if the proof cannot be encoded, and errors of [`Self::put`]
+    fn put_proof(&self, proof: &StarkProof) -> Result<String, StoreError> {
+        let bytes = serde_json::to_vec_pretty(proof)
+            .map_err(|e| StoreError::Serialization(e.to_string()))?;
+        self.put(StoredKind::Proof, &proof.public_inputs.txn_hash, &bytes)
+    }
+
+    /// Fetch a proof
+    ///
+    /// # Errors
+    ///
+    /// [`StoreError::Serialization`] if the stored proof cannot be decoded, and errors of
+    /// [`Self::get`]
+    fn get_proof(&self, lookup: &StoreLookup) -> Result<Option<StarkProof>, StoreError> {
+        self.get(StoredKind::Proof, lookup)?
+            .map(|bytes| {
+                serde_json::from_slice(&bytes).map_err(|e| StoreError::Serialization(e.to_string()))
+            })
+            .transpose()
+    }
+}
+
+/// Key of a content-addressed object
+fn object_key(kind: StoredKind, digest: &str) -> String {
+    format!("{}/{}.json", kind.prefix(), digest)
+}
+
+/// Key of a transaction index entry
+fn index_key(kind: StoredKind, tx_hash: &str) -> String {
+    format!("{}/by-tx/{}", kind.prefix(), tx_hash)
+}
+
+/// Normalize a hex identifier and reject anything that is unsafe as a key segment
+fn normalize_id(id: &str) -> Result<String, StoreError> {
+    let id = id.trim_start_matches("0x").to_ascii_lowercase();
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(StoreError::InvalidKey(id));
+    }
+    Ok(id)
+}
+
+/// Normalize a SHA-256 digest, rejecting anything but 64 hex characters
+fn normalize_digest(digest: &str) -> Result<String, StoreError> {
+    let digest = normalize_id(digest)?;
+    if digest.len() != 64 {
+        return Err(StoreError::InvalidKey(digest));
+    }
+    Ok(digest)
+}
+
+/// Decode a transaction index entry into the digest it points at
+fn parse_index_entry(bytes: Vec<u8>) -> Result<String, StoreError> {
+    let entry = String::from_utf8(bytes)
+        .map_err(|e| StoreError::Backend(format!("Corrupt index entry: {e}")))?;
+    normalize_digest(entry.trim())
+        .map_err(|_| StoreError::Backend(format!("Corrupt index entry: {entry:?}")))
+}
+
+/// Filesystem-backed store
+#[derive(Debug, Clone)]
+pub struct FsPackageStore {
+    root: PathBuf,
+}
+
+impl FsPackageStore {
+    /// Create a store rooted at a directory
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Root directory of the store
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl PackageStore for FsPackageStore {
+    fn read_object(&self, key: &str) -> Result<Option<Vec<u8>>, StoreError> {
+        match std::fs::read(self.root.join(key)) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn write_object(&self, key: &str, bytes: &[u8]) -> Result<(), StoreError> {
+        let path = self.root.join(key);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, bytes)?;
+        Ok(())
+    }
+
+    fn list_objects(&self, prefix: &str) -> Result<Vec<String>, StoreError> {
+        let dir = match std::fs::read_dir(self.root.join(prefix)) {
+            Ok(dir) => dir,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut keys = Vec::new();
+        for entry in dir {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                keys.push(format!("{}{}", prefix, entry.file_name().to_string_lossy()));
+            }
+        }
+        Ok(keys)
+    }
+}
+
+/// In-memory store
+#[derive(Debug, Default)]
+pub struct MemoryPackageStore {
+    objects: RwLock<BTreeMap<String, Vec<u8>>>,
+}
+
+impl MemoryPackageStore {
+    /// Create an empty store
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl PackageStore for MemoryPackageStore {
+    fn read_object(&self, key: &str) -> Result<Option<Vec<u8>>, StoreError> {
+        let objects = self
+            .objects
+            .read()
+            .map_err(|_| StoreError::Backend("Store lock poisoned".to_string()))?;
+        Ok(objects.get(key).cloned())
+    }
+
+    fn write_object(&self, key: &str, bytes: &[u8]) -> Result<(), StoreError> {
+        let mut objects = self
+            .objects
+            .write()
+            .map_err(|_| StoreError::Backend("Store lock poisoned".to_string()))?;
+        objects.insert(key.to_string(), bytes.to_vec());
+        Ok(())
+    }
+
+    fn list_objects(&self, prefix: &str) -> Result<Vec<String>, StoreError> {
+        let objects = self
+            .objects
+            .read()
+            .map_err(|_| StoreError::Backend("Store lock poisoned".to_string()))?;
+        Ok(objects
+            .keys()
+            .filter(|key| key.starts_with(prefix) && !key[prefix.len()..].contains('/'))
+            .cloned()
+            .collect())
+    }
+}
+
+/// Open a store from a URI
+///
+/// Supported schemes are `file://`, `memory://` and, with the `s3` feature, `s3://`.
+/// Anything without a scheme is treated as a filesystem path.
+///
+/// # Errors
+///
+/// [`StoreError::UnsupportedUri`] for an unknown scheme, or `s3://` without the `s3`
+/// feature; S3 configuration errors otherwise
+pub fn open_store(uri: &str) -> Result<Box<dyn PackageStore>, StoreError> {
+    if let Some(path) = uri.strip_prefix("file://") {
+        return Ok(Box::new(FsPackageStore::new(path)));
+    }
+    if uri == "memory://" {
+        return Ok(Box::new(MemoryPackageStore::new()));
+    }
+    if let Some(location) = uri.strip_prefix("s3://") {
+        #[cfg(feature = "s3")]
+        return Ok(Box::new(S3PackageStore::from_env(location)?));
+
+        #[cfg(not(feature = "s3"))]
+        return Err(StoreError::UnsupportedUri(format!(
+            "s3://{location} (rebuild with the `s3` feature)"
+        )));
+    }
+    if uri.contains("://") {
+        return Err(StoreError::UnsupportedUri(uri.to_string()));
+    }
+
+    Ok(Box::new(FsPackageStore::new(uri)))
+}
+
+/// Reference to one object in a store, written `<store-uri>#<tx-hash>` or
+/// `<store-uri>#sha256:<digest>`
+///
+/// CLI commands that read a package or proof accept a reference wherever they accept
+/// a file path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoreRef {
+    /// Store URI, as accepted by [`open_store`]
+    pub uri: String,
+    /// Object within the store
+    pub lookup: StoreLookup,
+}
+
+impl StoreRef {
+    /// Parse a reference, returning `None` for anything without a `#` fragment
+    #[must_use]
+    pub fn parse(spec: &str) -> Option<Self> {
+        let (uri, key) = spec.rsplit_once('#')?;
+        let lookup = match key.strip_prefix("sha256:") {
+            Some(digest) => StoreLookup::Digest(digest.to_string()),
+            None => StoreLookup::TxHash(key.to_string()),
+        };
+        Some(Self {
+            uri: uri.to_string(),
+            lookup,
+        })
+    }
+
+    /// Fetch the referenced object
+    ///
+    /// # Errors
+    ///
+    /// Errors of [`open_store`] and [`PackageStore::get`]
+    pub fn get(&self, kind: StoredKind) -> Result<Option<Vec<u8>>, StoreError> {
+        open_store(&self.uri)?.get(kind, &self.lookup)
+    }
+}
+
+/// Package store errors
+#[derive(Debug, thiserror::Error)]
+pub enum StoreError {
+    /// Filesystem I/O failed
+    #[error("Store I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Object could not be encoded or decoded
+    #[error("Store serialization error: {0}")]
+    Serialization(String),
+
+    /// Digest or transaction hash is not a valid key
+    #[error("Invalid store key: {0}")]
+    InvalidKey(String),
+
+    /// Store URI scheme is not supported
+    #[error("Unsupported store URI: {0}")]
+    UnsupportedUri(String),
+
+    /// Stored object does not hash to the digest it is keyed by
+    #[error("Store object digest mismatch: expected {expected}, got {actual}")]
+    DigestMismatch {
+        /// Digest the object was looked up by
+        expected: String,
+        /// Digest of the bytes actually stored
+        actual: String,
+    },
+
+    /// Backend request failed
+    #[error("Store backend error: {0}")]
+    Backend(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_package() -> StarkProofDataPackage {
+        StarkProofDataPackage::new(
+            0.8,
+            "0x7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "my-secret-key-123".to_string(),
+            "fuego-testnet".to_string(),
+        )
+    }
+
+    fn check_round_trip(store: &dyn PackageStore) {
+        let package = test_package();
+        let digest = store.put_package(&package).unwrap();
+
+        let by_digest = store
+            .get_package(&StoreLookup::Digest(digest.clone()))
+            .unwrap()
+            .expect("Package should be found by digest");
+        assert_eq!(
+            by_digest.burn_transaction.transaction_hash,
+            package.burn_transaction.transaction_hash
+        );
+
+        let by_tx = store
+            .get_package(&StoreLookup::TxHash(
+                package.burn_transaction.transaction_hash.to_lowercase(),
+            ))
+            .unwrap()
+            .expect("Package should be found by transaction hash");
+        assert_eq!(by_tx.secret.secret_key, package.secret.secret_key);
+
+        let entries = store.list(StoredKind::Package).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].digest, digest);
+        assert!(store.list(StoredKind::Proof).unwrap().is_empty());
+        assert!(store
+            .get_proof(&StoreLookup::TxHash(entries[0].tx_hash.clone()))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_memory_store_round_trip() {
+        check_round_trip(&MemoryPackageStore::new());
+    }
+
+    #[test]
+    fn test_fs_store_round_trip() {
+        let root = std::env::temp_dir().join(format!("xfg-package-store-{}", std::process::id()));
+        let store = open_store(&format!("file://{}", root.display())).unwrap();
+        check_round_trip(store.as_ref());
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_rejects_unsafe_index_entry() {
+        let store = MemoryPackageStore::new();
+        store
+            .write_object("packages/by-tx/abcd", b"../../etc/passwd")
+            .unwrap();
+        assert!(matches!(
+            store.get(StoredKind::Package, &StoreLookup::TxHash("abcd".to_string())),
+            Err(StoreError::Backend(_))
+        ));
+        assert!(matches!(store.list(StoredKind::Package), Err(StoreError::Backend(_))));
+    }
+
+    #[test]
+    fn test_rejects_tampered_object() {
+        let store = MemoryPackageStore::new();
+        let digest = store.put_package(&test_package()).unwrap();
+        store
+            .write_object(&object_key(StoredKind::Package, &digest), b"{}")
+            .unwrap();
+
+        for lookup in [
+            StoreLookup::Digest(digest.clone()),
+            StoreLookup::TxHash(test_package().burn_transaction.transaction_hash),
+        ] {
+            assert!(matches!(
+                store.get(StoredKind::Package, &lookup),
+                Err(StoreError::DigestMismatch { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_store_ref_parse() {
+        assert_eq!(StoreRef::parse("package.json"), None);
+        assert_eq!(
+            StoreRef::parse("file:///var/lib/xfg#0xABCD"),
+            Some(StoreRef {
+                uri: "file:///var/lib/xfg".to_string(),
+                lookup: StoreLookup::TxHash("0xABCD".to_string()),
+            })
+        );
+        assert_eq!(
+            StoreRef::parse("s3://bucket/prefix#sha256:ef01").map(|r| r.lookup),
+            Some(StoreLookup::Digest("ef01".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_rejects_unsafe_keys() {
+        let store = MemoryPackageStore::new();
+        assert!(matches!(
+            store.get(StoredKind::Package, &StoreLookup::TxHash("../etc/passwd".to_string())),
+            Err(StoreError::InvalidKey(_))
+        ));
+        assert!(matches!(
+            open_store("ftp://example.com"),
+            Err(StoreError::UnsupportedUri(_))
+        ));
+    }
+}
@@ -0,0 +1,247 @@
+//! S3-Compatible Package Store
+//!
+//! Stores objects in an S3-compatible bucket using path-style requests signed with
+//! AWS Signature Version 4. Works with AWS S3, MinIO, Ceph RGW and similar services.
+//!
+//! Credentials and region come from `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and
+//! `AWS_REGION` (default `us-east-1`). The endpoint comes from `AWS_ENDPOINT_URL`
+//! (default `https://s3.<region>.amazonaws.com`).
+
+use super::{PackageStore, StoreError};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use std::io::Read;
+
+/// S3 service name used in the signing scope
+const S3_SERVICE: &str = "s3";
+
+/// Default region when `AWS_REGION` is unset
+const DEFAULT_REGION: &str = "us-east-1";
+
+/// S3-compatible object store
+#[derive(Debug, Clone)]
+pub struct S3PackageStore {
+    /// Endpoint base URL (e.g. `http://127.0.0.1:9000`)
+    pub endpoint: String,
+    /// Bucket name
+    pub bucket: String,
+    /// Key prefix inside the bucket (empty or ending in `/`)
+    pub prefix: String,
+    /// Signing region
+    pub region: String,
+    access_key: String,
+    secret_key: String,
+}
+
+impl S3PackageStore {
+    /// Create a store for a bucket and key prefix
+    pub fn new(
+        endpoint: impl Into<String>,
+        bucket: impl Into<String>,
+        prefix: impl Into<String>,
+        region: impl Into<String>,
+        access_key: impl Into<String>,
+        secret_key: impl Into<String>,
+    ) -> Self {
+        let prefix = prefix.into().trim_matches('/').to_string();
+        Self {
+            endpoint: endpoint.into().trim_end_matches('/').to_string(),
+            bucket: bucket.into(),
+            prefix: if prefix.is_empty() { prefix } else { format!("{}/", prefix) },
+            region: region.into(),
+            access_key: access_key.into(),
+            secret_key: secret_key.into(),
+        }
+    }
+
+    /// Create a store for `bucket/prefix` using credentials from the environment
+    pub fn from_env(location: &str) -> Result<Self, StoreError> {
+        let (bucket, prefix) = location.split_once('/').unwrap_or((location, ""));
+        if bucket.is_empty() {
+            return Err(StoreError::UnsupportedUri(format!("s3://{} has no bucket", location)));
+        }
+
+        let env = |name: &str| {
+            std::env::var(name).map_err(|_| StoreError::Backend(format!("{} is not set", name)))
+        };
+        let region = std::env::var("AWS_REGION").unwrap_or_else(|_| DEFAULT_REGION.to_string());
+        let endpoint = std::env::var("AWS_ENDPOINT_URL")
+            .unwrap_or_else(|_| format!("https://s3.{}.amazonaws.com", region));
+
+        Ok(Self::new(
+            endpoint,
+            bucket,
+            prefix,
+            region,
+            env("AWS_ACCESS_KEY_ID")?,
+            env("AWS_SECRET_ACCESS_KEY")?,
+        ))
+    }
+
+    /// Send a signed request
+    fn send(
+        &self,
+        method: &str,
+        key: &str,
+        query: &[(&str, &str)],
+        body: &[u8],
+    ) -> Result<Option<ureq::Response>, StoreError> {
+        let path = format!("/{}/{}", self.bucket, uri_encode(key, false));
+        let mut query: Vec<(String, String)> = query
+            .iter()
+            .map(|(k, v)| (uri_encode(k, true), uri_encode(v, true)))
+            .collect();
+        query.sort();
+        let query = query
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let host = self
+            .endpoint
+            .split_once("://")
+            .map_or(self.endpoint.as_str(), |(_, host)| host);
+        let now = chrono::Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let payload_hash = hex::encode(Sha256::digest(body));
+
+        let canonical_request = format!(
+            "{}\n{}\n{}\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\nhost;x-amz-content-sha256;x-amz-date\n{}",
+            method, path, query, host, payload_hash, amz_date, payload_hash
+        );
+        let scope = format!("{}/{}/{}/aws4_request", date, self.region, S3_SERVICE);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let signing_key = [self.region.as_str(), S3_SERVICE, "aws4_request"]
+            .iter()
+            .fold(
+                hmac_sha256(format!("AWS4{}", self.secret_key).as_bytes(), date.as_bytes()),
+                |key, part| hmac_sha256(&key, part.as_bytes()),
+            );
+        let signature = hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders=host;x-amz-content-sha256;x-amz-date, Signature={}",
+            self.access_key, scope, signature
+        );
+
+        let url = if query.is_empty() {
+            format!("{}{}", self.endpoint, path)
+        } else {
+            format!("{}{}?{}", self.endpoint, path, query)
+        };
+        let result = ureq::request(method, &url)
+            .set("x-amz-content-sha256", &payload_hash)
+            .set("x-amz-date", &amz_date)
+            .set("Authorization", &authorization)
+            .send_bytes(body);
+
+        match result {
+            Ok(response) => Ok(Some(response)),
+            Err(ureq::Error::Status(404, _)) => Ok(None),
+            Err(e) => Err(StoreError::Backend(e.to_string())),
+        }
+    }
+}
+
+impl PackageStore for S3PackageStore {
+    fn read_object(&self, key: &str) -> Result<Option<Vec<u8>>, StoreError> {
+        let Some(response) = self.send("GET", &format!("{}{}", self.prefix, key), &[], &[])? else {
+            return Ok(None);
+        };
+
+        let mut bytes = Vec::new();
+        response.into_reader().read_to_end(&mut bytes)?;
+        Ok(Some(bytes))
+    }
+
+    fn write_object(&self, key: &str, bytes: &[u8]) -> Result<(), StoreError> {
+        self.send("PUT", &format!("{}{}", self.prefix, key), &[], bytes)?
+            .ok_or_else(|| StoreError::Backend(format!("Bucket {} not found", self.bucket)))?;
+        Ok(())
+    }
+
+    fn list_objects(&self, prefix: &str) -> Result<Vec<String>, StoreError> {
+        let full_prefix = format!("{}{}", self.prefix, prefix);
+        let mut keys = Vec::new();
+        let mut continuation: Option<String> = None;
+
+        loop {
+            let mut query = vec![
+                ("list-type", "2"),
+                ("prefix", full_prefix.as_str()),
+                ("delimiter", "/"),
+            ];
+            if let Some(token) = &continuation {
+                query.push(("continuation-token", token.as_str()));
+            }
+
+            let body = match self.send("GET", "", &query, &[])? {
+                Some(response) => response.into_string()?,
+                None => return Ok(keys),
+            };
+
+            keys.extend(
+                xml_values(&body, "Key")
+                    .into_iter()
+                    .filter_map(|key| key.strip_prefix(&self.prefix).map(str::to_string)),
+            );
+
+            continuation = xml_values(&body, "NextContinuationToken").into_iter().next();
+            if continuation.is_none() {
+                return Ok(keys);
+            }
+        }
+    }
+}
+
+/// HMAC-SHA256
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Percent-encode per SigV4 rules, optionally encoding `/`
+fn uri_encode(value: &str, encode_slash: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b'/' if !encode_slash => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Extract the text of every `<tag>...</tag>` element
+fn xml_values(xml: &str, tag: &str) -> Vec<String> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let mut values = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find(&open) {
+        rest = &rest[start + open.len()..];
+        let Some(end) = rest.find(&close) else { break };
+        values.push(
+            rest[..end]
+                .replace("&amp;", "&")
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&apos;", "'"),
+        );
+        rest = &rest[end + close.len()..];
+    }
+    values
+}
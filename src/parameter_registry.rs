@@ -0,0 +1,291 @@
+//! Proof Parameter Registry
+//!
+//! Every combination of proof options, commitment version and statements that a release
+//! has produced proofs with is recorded here under a protocol version, mirroring the
+//! **Proof parameters** table in `CHANGELOG.md`. The verifier uses the registry to
+//! accept historical proofs under their original parameters when the
+//! [`HistoricalPolicy`] allows it, and to reject parameter combinations that never
+//! legitimately existed.
+
+use thiserror::Error;
+use winterfell::{FieldExtension, ProofOptions};
+
+/// Statement ID of the XFG burn & HEAT mint statement
+pub const BURN_MINT_STATEMENT_ID: &str = "xfg-burn-mint";
+
+/// Whether proofs under a parameter set are still produced
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterStatus {
+    /// Parameters produced by the current release
+    Active,
+    /// Parameters of an earlier release
+    Deprecated,
+}
+
+/// Parameters active under one protocol version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParameterSet {
+    /// Protocol version
+    pub protocol_version: u32,
+    /// Crate version that introduced the parameters
+    pub crate_version: &'static str,
+    /// Proof options
+    pub options: ProofOptions,
+    /// Commitment format version carried in the public inputs
+    pub commitment_version: u32,
+    /// Statements proven under these parameters
+    pub statement_ids: &'static [&'static str],
+    /// Whether the parameters are still produced
+    pub status: ParameterStatus,
+}
+
+impl ParameterSet {
+    /// Check whether a proof's parameters and statement belong to this set
+    #[must_use]
+    pub fn matches(&self, options: &ProofOptions, commitment_version: u32, statement_id: &str) -> bool {
+        &self.options == options
+            && self.commitment_version == commitment_version
+            && self.statement_ids.contains(&statement_id)
+    }
+}
+
+/// Parameter sets of every release, oldest first
+const PARAMETER_SETS: &[ParameterSet] = &[
+    ParameterSet {
+        protocol_version: 1,
+        crate_version: "0.1.0",
+        options: ProofOptions::new(42, 8, 4, FieldExtension::None, 8, 31),
+        commitment_version: 1,
+        statement_ids: &[BURN_MINT_STATEMENT_ID],
+        status: ParameterStatus::Deprecated,
+    },
+    ParameterSet {
+        protocol_version: 2,
+        crate_version: "0.1.0",
+        options: ProofOptions::new(42, 8, 4, FieldExtension::Quadratic, 8, 31),
+        commitment_version: 1,
+        statement_ids: &[BURN_MINT_STATEMENT_ID],
+        status: ParameterStatus::Active,
+    },
+];
+
+/// Which registered parameter sets a verifier accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HistoricalPolicy {
+    /// Only the active parameters
+    #[default]
+    ActiveOnly,
+    /// Active and deprecated parameters
+    AllowDeprecated,
+}
+
+/// Rejection of a proof's parameters by the registry
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RegistryError {
+    /// No release ever produced proofs with these parameters
+    #[error("No protocol version used these proof options with commitment version {commitment_version} for statement '{statement_id}'")]
+    UnknownParameters {
+        /// Commitment version carried in the public inputs
+        commitment_version: u32,
+        /// Statement the proof claims
+        statement_id: String,
+    },
+    /// Parameters are deprecated and the policy only accepts active ones
+    #[error("Proof parameters of protocol version {protocol_version} are deprecated")]
+    Deprecated {
+        /// Protocol version the parameters belong to
+        protocol_version: u32,
+    },
+}
+
+/// Registry of the proof parameters of every release
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParameterRegistry {
+    entries: Vec<ParameterSet>,
+    policy: HistoricalPolicy,
+}
+
+impl ParameterRegistry {
+    /// Registry of the parameters this crate has released, accepting only active ones
+    #[must_use]
+    pub fn builtin() -> Self {
+        Self {
+            entries: PARAMETER_SETS.to_vec(),
+            policy: HistoricalPolicy::default(),
+        }
+    }
+
+    /// Set which registered parameter sets are accepted
+    #[must_use]
+    pub fn with_policy(mut self, policy: HistoricalPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Acceptance policy
+    #[must_use]
+    pub fn policy(&self) -> HistoricalPolicy {
+        self.policy
+    }
+
+    /// Registered parameter sets, oldest first
+    #[must_use]
+    pub fn entries(&self) -> &[ParameterSet] {
+        &self.entries
+    }
+
+    /// Parameter set of a protocol version
+    #[must_use]
+    pub fn get(&self, protocol_version: u32) -> Option<&ParameterSet> {
+        self.entries
+            .iter()
+            .find(|entry| entry.protocol_version == protocol_version)
+    }
+
+    /// Latest active parameter set
+    #[must_use]
+    pub fn current(&self) -> Option<&ParameterSet> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.status == ParameterStatus::Active)
+    }
+
+    /// Parameter sets introduced by a crate version
+    pub fn for_crate_version<'a>(&'a self, crate_version: &'a str) -> impl Iterator<Item = &'a ParameterSet> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.crate_version == crate_version)
+    }
+
+    /// Look up the parameter set a proof was generated under and apply the policy
+    ///
+    /// # Errors
+    ///
+    /// [`RegistryError::UnknownParameters`] if no parameter set matches, and
+    /// [`RegistryError::Deprecated`] if the match is deprecated and the policy only accepts
+    /// active parameters
+    pub fn resolve(
+        &self,
+        options: &ProofOptions,
+        commitment_version: u32,
+        statement_id: &str,
+    ) -> std::result::Result<&ParameterSet, RegistryError> {
+        let entry = self
+            .entries
+            .iter()
+            .find(|entry| entry.matches(options, commitment_version, statement_id))
+            .ok_or_else(|| RegistryError::UnknownParameters {
+                commitment_version,
+                statement_id: statement_id.to_string(),
+            })?;
+
+        if entry.status == ParameterStatus::Deprecated && self.policy == HistoricalPolicy::ActiveOnly {
+            return Err(RegistryError::Deprecated {
+                protocol_version: entry.protocol_version,
+            });
+        }
+        Ok(entry)
+    }
+}
+
+impl Default for ParameterRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_prover::XfgBurnMintProver;
+    use crate::burn_mint_verifier::XfgBurnMintVerifier;
+
+    /// Parse the proof parameter table rows of `CHANGELOG.md`
+    fn changelog_rows() -> Vec<Vec<String>> {
+        include_str!("../CHANGELOG.md")
+            .lines()
+            .skip_while(|line| line.trim() != "### Proof parameters")
+            .filter(|line| line.starts_with('|'))
+            .skip(2)
+            .map(|line| {
+                line.trim_matches('|')
+                    .split('|')
+                    .map(|cell| cell.trim().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_registry_matches_changelog() {
+        let registry = ParameterRegistry::builtin();
+        let rows = changelog_rows();
+        assert_eq!(rows.len(), registry.entries().len());
+
+        for (row, entry) in rows.iter().zip(registry.entries()) {
+            let fri = entry.options.to_fri_options();
+            let status = match entry.status {
+                ParameterStatus::Active => "active",
+                ParameterStatus::Deprecated => "deprecated",
+            };
+            let extension = match entry.options.field_extension() {
+                FieldExtension::None => "none",
+                FieldExtension::Quadratic => "quadratic",
+                FieldExtension::Cubic => "cubic",
+            };
+            let expected = vec![
+                entry.protocol_version.to_string(),
+                status.to_string(),
+                entry.options.num_queries().to_string(),
+                entry.options.blowup_factor().to_string(),
+                entry.options.grinding_factor().to_string(),
+                extension.to_string(),
+                fri.folding_factor().to_string(),
+                fri.remainder_max_degree().to_string(),
+                entry.commitment_version.to_string(),
+                entry.statement_ids.join(", "),
+            ];
+            assert_eq!(row, &expected);
+        }
+    }
+
+    #[test]
+    fn test_current_parameters_match_prover_and_verifier() {
+        let registry = ParameterRegistry::builtin();
+        let current = registry.current().expect("A parameter set must be active");
+        assert_eq!(&current.options, XfgBurnMintProver::default().proof_options());
+        assert_eq!(&current.options, XfgBurnMintVerifier::default().proof_options());
+        assert_eq!(registry.for_crate_version(crate::VERSION).count(), 2);
+    }
+
+    #[test]
+    fn test_resolve_applies_policy() {
+        let registry = ParameterRegistry::builtin();
+        let legacy = registry.get(1).unwrap().options.clone();
+
+        assert_eq!(
+            registry.resolve(&legacy, 1, BURN_MINT_STATEMENT_ID),
+            Err(RegistryError::Deprecated { protocol_version: 1 })
+        );
+        let historical = registry.clone().with_policy(HistoricalPolicy::AllowDeprecated);
+        assert_eq!(
+            historical
+                .resolve(&legacy, 1, BURN_MINT_STATEMENT_ID)
+                .unwrap()
+                .protocol_version,
+            1
+        );
+
+        // Combinations that were never released are rejected under every policy
+        let never_released = ProofOptions::new(28, 8, 0, FieldExtension::Quadratic, 8, 31);
+        for registry in [&registry, &historical] {
+            assert!(matches!(
+                registry.resolve(&never_released, 1, BURN_MINT_STATEMENT_ID),
+                Err(RegistryError::UnknownParameters { .. })
+            ));
+            assert!(registry.resolve(&legacy, 2, BURN_MINT_STATEMENT_ID).is_err());
+            assert!(registry.resolve(&legacy, 1, "xfg-staking").is_err());
+        }
+    }
+}
@@ -1,5 +1,10 @@
 //! Polynomial Arithmetic Module
 //! 
 //! This module provides polynomial arithmetic implementations for the XFG STARK project.
+//! Coefficient-form types live in [`crate::types::polynomial`]; [`ntt`](mod@ntt) adds the
+//! quasi-linear transforms over [`crate::types::field::PrimeField64`].
+
+pub mod ntt;
 
 pub use crate::types::polynomial::*;
+pub use ntt::*;
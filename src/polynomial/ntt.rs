@@ -0,0 +1,250 @@
+//! Number-Theoretic Transform
+//!
+//! Radix-2 NTT over [`PrimeField64`], whose multiplicative group has a subgroup of every
+//! order `2^k` up to `2^32`. On top of it:
+//!
+//! - [`fft_multiply`]: polynomial multiplication in `O(n log n)`
+//! - [`evaluate_coset`]: evaluations on `offset · <ω>`, the domain an LDE is taken over
+//! - [`interpolate_coset`]: coefficients back from those evaluations
+//!
+//! Polynomials are coefficient slices, constant term first, as in [`FieldPolynomial`].
+
+use super::{FieldPolynomial, PolynomialError};
+use crate::types::field::PrimeField64;
+use crate::types::FieldElement;
+
+/// Evaluate a polynomial on the `n`-th roots of unity in place (`n = values.len()`)
+///
+/// Output `i` is the evaluation at `ω^i` for the primitive `n`-th root `ω`.
+///
+/// # Errors
+///
+/// [`PolynomialError::InvalidDomainSize`] if `values.len()` is not a power of two up to
+/// `2^TWO_ADICITY`
+pub fn ntt(values: &mut [PrimeField64]) -> Result<(), PolynomialError> {
+    let root = domain_root(values.len())?;
+    transform(values, root);
+    Ok(())
+}
+
+/// Inverse of [`ntt`]: coefficients from evaluations on the `n`-th roots of unity
+///
+/// # Errors
+///
+/// As [`ntt`]
+pub fn intt(values: &mut [PrimeField64]) -> Result<(), PolynomialError> {
+    let root = domain_root(values.len())?;
+    let inverse_root = root.inverse().ok_or(PolynomialError::DivisionByZero)?;
+    transform(values, inverse_root);
+
+    let n_inverse = PrimeField64::new(values.len() as u64)
+        .inverse()
+        .ok_or(PolynomialError::InvalidDomainSize(values.len()))?;
+    for value in values.iter_mut() {
+        *value *= n_inverse;
+    }
+    Ok(())
+}
+
+/// Product of two polynomials through the NTT
+///
+/// # Errors
+///
+/// [`PolynomialError::InvalidDomainSize`] if the product needs a domain larger than the field
+/// supports
+pub fn fft_multiply(a: &[PrimeField64], b: &[PrimeField64]) -> Result<Vec<PrimeField64>, PolynomialError> {
+    if a.is_empty() || b.is_empty() {
+        return Ok(Vec::new());
+    }
+    let product_len = a.len() + b.len() - 1;
+    let size = product_len.next_power_of_two();
+
+    let mut a_values = padded(a, size);
+    let mut b_values = padded(b, size);
+    ntt(&mut a_values)?;
+    ntt(&mut b_values)?;
+    for (x, y) in a_values.iter_mut().zip(&b_values) {
+        *x *= *y;
+    }
+    intt(&mut a_values)?;
+
+    a_values.truncate(product_len);
+    Ok(a_values)
+}
+
+/// Evaluate a polynomial on the coset `offset · <ω>` of size `domain_size`
+///
+/// # Errors
+///
+/// [`PolynomialError::InvalidDegree`] if the coefficients do not fit the domain, otherwise
+/// as [`ntt`] for `domain_size`
+pub fn evaluate_coset(
+    coefficients: &[PrimeField64],
+    offset: PrimeField64,
+    domain_size: usize,
+) -> Result<Vec<PrimeField64>, PolynomialError> {
+    if coefficients.len() > domain_size {
+        return Err(PolynomialError::InvalidDegree(format!(
+            "{} coefficients do not fit a domain of {}",
+            coefficients.len(),
+            domain_size
+        )));
+    }
+
+    // p(offset · x) has coefficients c_i · offset^i
+    let mut values = padded(coefficients, domain_size);
+    let mut power = PrimeField64::one();
+    for value in &mut values {
+        *value *= power;
+        power *= offset;
+    }
+    ntt(&mut values)?;
+    Ok(values)
+}
+
+/// Coefficients of the polynomial taking `evaluations` on the coset `offset · <ω>`
+///
+/// # Errors
+///
+/// [`PolynomialError::InterpolationError`] for a zero offset, otherwise as [`intt`]
+pub fn interpolate_coset(
+    evaluations: &[PrimeField64],
+    offset: PrimeField64,
+) -> Result<Vec<PrimeField64>, PolynomialError> {
+    let offset_inverse = offset
+        .inverse()
+        .ok_or_else(|| PolynomialError::InterpolationError("coset offset must be nonzero".to_string()))?;
+
+    let mut coefficients = evaluations.to_vec();
+    intt(&mut coefficients)?;
+    let mut power = PrimeField64::one();
+    for coefficient in &mut coefficients {
+        *coefficient *= power;
+        power *= offset_inverse;
+    }
+    Ok(coefficients)
+}
+
+impl FieldPolynomial<PrimeField64> {
+    /// Multiply by another polynomial through the NTT
+    ///
+    /// # Errors
+    ///
+    /// As [`fft_multiply`]
+    pub fn multiply_fft(&self, other: &Self) -> Result<Self, PolynomialError> {
+        let coefficients = |p: &Self| (0..=p.degree()).map(|i| p.coefficient(i)).collect::<Vec<_>>();
+        Ok(Self::new(fft_multiply(&coefficients(self), &coefficients(other))?))
+    }
+}
+
+/// Primitive root of unity generating a domain of `size` points
+fn domain_root(size: usize) -> Result<PrimeField64, PolynomialError> {
+    if !size.is_power_of_two() {
+        return Err(PolynomialError::InvalidDomainSize(size));
+    }
+    PrimeField64::root_of_unity(size.trailing_zeros()).ok_or(PolynomialError::InvalidDomainSize(size))
+}
+
+/// `values` extended with zeros to `size`
+fn padded(values: &[PrimeField64], size: usize) -> Vec<PrimeField64> {
+    let mut result = values.to_vec();
+    result.resize(size, PrimeField64::zero());
+    result
+}
+
+/// Iterative Cooley-Tukey butterfly network for the domain generated by `root`
+fn transform(values: &mut [PrimeField64], root: PrimeField64) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let step = root.pow((n / len) as u64);
+        let half = len / 2;
+        let mut twiddles = Vec::with_capacity(half);
+        let mut twiddle = PrimeField64::one();
+        for _ in 0..half {
+            twiddles.push(twiddle);
+            twiddle *= step;
+        }
+
+        for chunk in values.chunks_mut(len) {
+            let (low, high) = chunk.split_at_mut(half);
+            for ((u, v), &w) in low.iter_mut().zip(high.iter_mut()).zip(&twiddles) {
+                let t = *v * w;
+                *v = *u - t;
+                *u += t;
+            }
+        }
+        len *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(value: u64) -> PrimeField64 {
+        PrimeField64::new(value)
+    }
+
+    fn naive_evaluate(coefficients: &[PrimeField64], x: PrimeField64) -> PrimeField64 {
+        coefficients.iter().rev().fold(f(0), |acc, &c| acc * x + c)
+    }
+
+    #[test]
+    fn test_roots_of_unity_and_round_trip() {
+        let root = PrimeField64::root_of_unity(PrimeField64::TWO_ADICITY).unwrap();
+        assert_eq!(root.pow(1 << 32), PrimeField64::one());
+        assert_ne!(root.pow(1 << 31), PrimeField64::one());
+        assert!(PrimeField64::root_of_unity(33).is_none());
+
+        let coefficients: Vec<_> = (0..64u64).map(|i| f(i * i + 3)).collect();
+        let mut values = coefficients.clone();
+        ntt(&mut values).unwrap();
+        let omega = PrimeField64::root_of_unity(6).unwrap();
+        assert_eq!(values[5], naive_evaluate(&coefficients, omega.pow(5)));
+
+        intt(&mut values).unwrap();
+        assert_eq!(values, coefficients);
+        assert_eq!(ntt(&mut vec![f(1); 12]), Err(PolynomialError::InvalidDomainSize(12)));
+    }
+
+    #[test]
+    fn test_fft_multiply_matches_schoolbook() {
+        let a = FieldPolynomial::new((1..=37u64).map(|i| f(i * 7919)).collect());
+        let b = FieldPolynomial::new((1..=20u64).map(|i| f(PrimeField64::MODULUS - i)).collect());
+
+        assert_eq!(a.multiply_fft(&b).unwrap(), a.multiply(&b));
+        assert!(fft_multiply(&[], &[f(1)]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_coset_evaluation_and_interpolation() {
+        let coefficients: Vec<_> = (0..16u64).map(|i| f(3 * i + 1)).collect();
+        let offset = f(PrimeField64::GENERATOR);
+        let evaluations = evaluate_coset(&coefficients, offset, 64).unwrap();
+
+        let omega = PrimeField64::root_of_unity(6).unwrap();
+        for i in [0u64, 1, 17, 63] {
+            let x = offset * omega.pow(i);
+            assert_eq!(evaluations[i as usize], naive_evaluate(&coefficients, x));
+        }
+
+        let mut recovered = interpolate_coset(&evaluations, offset).unwrap();
+        assert!(recovered[16..].iter().all(|c| c.is_zero()));
+        recovered.truncate(16);
+        assert_eq!(recovered, coefficients);
+        assert!(evaluate_coset(&coefficients, offset, 8).is_err());
+    }
+}
@@ -0,0 +1,283 @@
+//! Progress Reporting for Proof Generation and Eldernode Consensus
+//!
+//! Proving a burn and collecting Eldernode consensus both take seconds, usually in
+//! parallel. A [`ProgressReporter`] publishes the current [`StarkPhase`] and
+//! [`ConsensusPhase`] of such a run over a `tokio::sync::watch` channel. Reporters are
+//! `Clone + Send`, so the proving thread and the consensus task can each hold one, and
+//! publishing never blocks.
+//!
+//! Displays subscribe with [`ProgressReporter::subscribe`]. A [`ProgressWatcher`] always
+//! sees the latest [`Progress`] snapshot; a slow consumer may skip intermediate phases
+//! but never sees them out of order. The CLI binaries and GUI integrators consume the
+//! same channel.
+//!
+//! A reporter is also a [proving progress callback](crate::proof::progress): handed to
+//! a prover's `with_progress`, it publishes each proving phase as
+//! [`StarkPhase::Proved`] with its percentage.
+
+use crate::proof::progress::{self, ProvingPhase};
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+
+/// Phase of STARK proof generation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StarkPhase {
+    /// Proving has not started
+    NotStarted,
+    /// Inputs are being checked and the prover set up
+    Preparing,
+    /// The STARK proof is being generated
+    Proving,
+    /// A proving phase completed
+    Proved {
+        /// Completed phase
+        phase: ProvingPhase,
+        /// Estimated share of proving done (percent)
+        percent: u8,
+    },
+    /// The proof is being serialized and saved
+    Saving,
+    /// The proof is complete
+    Done,
+    /// Proving failed
+    Failed(String),
+}
+
+impl StarkPhase {
+    /// Check whether the phase is final
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        matches!(self, StarkPhase::Done | StarkPhase::Failed(_))
+    }
+}
+
+impl Display for StarkPhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StarkPhase::NotStarted => write!(f, "Not started"),
+            StarkPhase::Preparing => write!(f, "Preparing prover..."),
+            StarkPhase::Proving => write!(f, "Generating proof..."),
+            StarkPhase::Proved { phase, percent } => write!(f, "Generating proof... {percent}% ({phase})"),
+            StarkPhase::Saving => write!(f, "Saving proof..."),
+            StarkPhase::Done => write!(f, "Proof generated"),
+            StarkPhase::Failed(error) => write!(f, "Failed - {error}"),
+        }
+    }
+}
+
+/// Phase of Eldernode consensus on a burn
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsensusPhase {
+    /// Verification has not started
+    NotStarted,
+    /// Commitment and burn amount are being sent to the Eldernodes
+    Sending,
+    /// Waiting for the first Eldernode response
+    AwaitingConsensus,
+    /// Some Eldernodes have responded
+    Responses {
+        /// Eldernodes that have responded
+        responded: u32,
+        /// Eldernodes asked
+        total: u32,
+    },
+    /// The consensus threshold was reached
+    ConsensusReached,
+    /// Verification failed
+    Failed(String),
+}
+
+impl ConsensusPhase {
+    /// Check whether the phase is final
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        matches!(self, ConsensusPhase::ConsensusReached | ConsensusPhase::Failed(_))
+    }
+}
+
+impl Display for ConsensusPhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsensusPhase::NotStarted => write!(f, "Not started"),
+            ConsensusPhase::Sending => write!(f, "Sending commitment & burn amount verification..."),
+            ConsensusPhase::AwaitingConsensus => write!(f, "Awaiting consensus..."),
+            ConsensusPhase::Responses { responded, total } => {
+                write!(f, "{responded}/{total} Eldernodes responded")
+            }
+            ConsensusPhase::ConsensusReached => write!(f, "Consensus reached!"),
+            ConsensusPhase::Failed(error) => write!(f, "Failed - {error}"),
+        }
+    }
+}
+
+/// Snapshot of a run's progress
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Progress {
+    /// STARK generation phase
+    pub stark: StarkPhase,
+    /// Eldernode consensus phase
+    pub consensus: ConsensusPhase,
+    /// When the run started
+    pub started_at: Instant,
+    /// When a phase last changed
+    pub updated_at: Instant,
+}
+
+impl Progress {
+    /// Time since the run started
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+}
+
+impl Display for Progress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "STARK Generation: {}", self.stark)?;
+        writeln!(f, "Eldernode Verification: {}", self.consensus)?;
+        write!(f, "Total Time: {:?}", self.elapsed())
+    }
+}
+
+/// Publishes the progress of a run
+#[derive(Debug, Clone)]
+pub struct ProgressReporter {
+    sender: Arc<watch::Sender<Progress>>,
+}
+
+impl ProgressReporter {
+    /// Start reporting a run with both phases not started
+    #[must_use]
+    pub fn new() -> Self {
+        let now = Instant::now();
+        let (sender, _) = watch::channel(Progress {
+            stark: StarkPhase::NotStarted,
+            consensus: ConsensusPhase::NotStarted,
+            started_at: now,
+            updated_at: now,
+        });
+        Self {
+            sender: Arc::new(sender),
+        }
+    }
+
+    /// Publish a STARK generation phase
+    pub fn stark(&self, phase: StarkPhase) {
+        self.sender.send_modify(|progress| {
+            progress.stark = phase;
+            progress.updated_at = Instant::now();
+        });
+    }
+
+    /// Publish an Eldernode consensus phase
+    pub fn consensus(&self, phase: ConsensusPhase) {
+        self.sender.send_modify(|progress| {
+            progress.consensus = phase;
+            progress.updated_at = Instant::now();
+        });
+    }
+
+    /// Current progress
+    #[must_use]
+    pub fn snapshot(&self) -> Progress {
+        self.sender.borrow().clone()
+    }
+
+    /// Watch the run's progress
+    #[must_use]
+    pub fn subscribe(&self) -> ProgressWatcher {
+        ProgressWatcher {
+            receiver: self.sender.subscribe(),
+        }
+    }
+}
+
+impl progress::ProgressReporter for ProgressReporter {
+    fn report(&self, phase: &ProvingPhase, percent: u8) {
+        self.stark(StarkPhase::Proved { phase: *phase, percent });
+    }
+}
+
+impl Default for ProgressReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Receives the progress of a run
+#[derive(Debug, Clone)]
+pub struct ProgressWatcher {
+    receiver: watch::Receiver<Progress>,
+}
+
+impl ProgressWatcher {
+    /// Latest progress, marking it seen
+    pub fn latest(&mut self) -> Progress {
+        self.receiver.borrow_and_update().clone()
+    }
+
+    /// Wait for the next unseen progress
+    ///
+    /// Returns `None` once every reporter has been dropped.
+    pub async fn changed(&mut self) -> Option<Progress> {
+        self.receiver.changed().await.ok()?;
+        Some(self.latest())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_watchers_see_latest_phase() {
+        let reporter = ProgressReporter::new();
+        let mut watcher = reporter.subscribe();
+        assert_eq!(watcher.latest().stark, StarkPhase::NotStarted);
+
+        // Reporters are shared across threads
+        let prover = reporter.clone();
+        std::thread::spawn(move || {
+            prover.stark(StarkPhase::Proving);
+            prover.stark(StarkPhase::Done);
+        })
+        .join()
+        .unwrap();
+        reporter.consensus(ConsensusPhase::Responses { responded: 2, total: 5 });
+
+        // Intermediate phases are coalesced into the latest snapshot
+        let progress = watcher.changed().await.unwrap();
+        assert_eq!(progress.stark, StarkPhase::Done);
+        assert_eq!(progress.consensus.to_string(), "2/5 Eldernodes responded");
+        assert_eq!(reporter.snapshot(), progress);
+
+        drop(reporter);
+        assert_eq!(watcher.changed().await, None);
+    }
+
+    #[test]
+    fn test_reporter_receives_proving_phases() {
+        use crate::burn_mint_prover::XfgBurnMintProver;
+
+        let reporter = ProgressReporter::new();
+        let mut watcher = reporter.subscribe();
+        XfgBurnMintProver::default()
+            .with_progress(reporter.clone())
+            .prove_burn_mint(8_000_000, 8_000_000, [7u8; 32], &[0x12u8; 20], &[9u8; 32], 1, 42161, 1)
+            .expect("Proof generation should succeed");
+
+        let phase = ProvingPhase::QueriesAnswered;
+        assert_eq!(watcher.latest().stark, StarkPhase::Proved { phase, percent: 100 });
+        assert_eq!(reporter.snapshot().stark.to_string(), "Generating proof... 100% (queries answered)");
+    }
+
+    #[test]
+    fn test_finished_phases() {
+        assert!(StarkPhase::Failed("out of memory".to_string()).is_finished());
+        assert!(!StarkPhase::Proving.is_finished());
+        assert!(ConsensusPhase::ConsensusReached.is_finished());
+        assert!(!ConsensusPhase::Responses { responded: 5, total: 5 }.is_finished());
+    }
+}
@@ -0,0 +1,101 @@
+//! Cooperative Cancellation
+//!
+//! A [`CancellationToken`] handed to a prover's `with_cancellation` lets another thread
+//! abandon a proof, for example when the user who requested it disconnects. Provers
+//! check the token between phases and, for [`StarkProver`](crate::proof::StarkProver),
+//! between FRI layers, returning [`ProofError::Cancelled`] at the first check after
+//! [`CancellationToken::cancel`]. Work inside a phase is not interrupted.
+
+use crate::proof::ProofError;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Flag a caller sets to abandon a proof in progress
+///
+/// Clones share the flag, so the caller keeps one and hands another to the prover.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a token that has not been cancelled
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask every prover holding a clone of the token to stop at its next checkpoint
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether [`Self::cancel`] has been called
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Checkpoint: fail with [`ProofError::Cancelled`] once cancelled
+    ///
+    /// # Errors
+    ///
+    /// [`ProofError::Cancelled`] if [`Self::cancel`] was called
+    pub fn check(&self) -> Result<(), ProofError> {
+        if self.is_cancelled() {
+            return Err(ProofError::Cancelled);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::air::{Air, BoundaryConditions, TransitionFunction};
+    use crate::proof::progress::ProvingPhase;
+    use crate::proof::StarkProver;
+    use crate::types::field::PrimeField64;
+    use crate::types::FieldElement;
+
+    fn air() -> Air<PrimeField64> {
+        let (zero, one) = (PrimeField64::zero(), PrimeField64::one());
+        let transition = TransitionFunction::new(vec![vec![one, one], vec![zero, one]], 1);
+        Air::new(vec![], transition, BoundaryConditions::new(vec![]), 128)
+    }
+
+    #[test]
+    fn test_cancel_between_fri_layers() {
+        let initial_state = [PrimeField64::new(3), PrimeField64::new(2)];
+        let token = CancellationToken::new();
+        let prover = StarkProver::<PrimeField64>::new(128).with_cancellation(token.clone());
+        assert!(prover.prove(&air(), &initial_state, 64).is_ok());
+
+        // Cancelled from the progress callback as the first layer is folded
+        let canceller = token.clone();
+        let prover = prover.with_progress(move |phase: &ProvingPhase, _: u8| {
+            if matches!(phase, ProvingPhase::LayerFolded { layer: 1, .. }) {
+                canceller.cancel();
+            }
+        });
+        assert!(matches!(prover.prove(&air(), &initial_state, 64), Err(ProofError::Cancelled)));
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_cancelled_burn_mint_proof() {
+        use crate::burn_mint_prover::XfgBurnMintProver;
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let result = XfgBurnMintProver::default().with_cancellation(token).prove_burn_mint(
+            8_000_000,
+            8_000_000,
+            [7u8; 32],
+            &[0x12u8; 20],
+            &[9u8; 32],
+            1,
+            42161,
+            1,
+        );
+        assert!(matches!(result, Err(crate::XfgStarkError::ProofError(ProofError::Cancelled))));
+    }
+}
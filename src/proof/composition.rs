@@ -0,0 +1,337 @@
+//! Constraint Composition
+//!
+//! Builds the composition polynomial the prover hands to FRI:
+//!
+//! 1. Each trace column is interpolated over the trace domain `{0, 1, ..., n - 1}`. Row
+//!    `i` sits at `x = i`, so the next row of a column `P(x)` is `P(x + 1)`.
+//! 2. Transition constraints `P_i(x + 1) - Σ_j M_ij · P_j(x) - C_i(x)` must vanish on
+//!    every row but the last, and are divided by `Z(x) = Π (x - i)` over those rows.
+//!    `C_i` interpolates the periodic columns of register `i` over the trace domain, and
+//!    is zero for registers without any.
+//! 3. Boundary constraints `P_r(x) - v` must vanish at their step `s`, and are divided
+//!    by `x - s`.
+//! 4. Quotient `k` is weighted by `α^k` for a transcript challenge `α` drawn after the
+//!    trace commitment, and the weighted quotients are summed.
+//!
+//! Quotients are computed in the trace's field. With [`composition_polynomial_in`], `α`
+//! and the sum live in an extension of it, so the random combination does not inherit
+//! the prime field's 64-bit soundness bound.
+//!
+//! A division that leaves a remainder means the trace breaks the AIR, so a composition
+//! polynomial only exists for a valid trace.
+
+use crate::air::boundaries::BoundaryType;
+use crate::air::Air;
+use crate::types::stark::ExecutionTrace;
+use crate::types::FieldElement;
+
+/// Ways a trace can fail to compose
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CompositionError {
+    /// Trace has no rows or no registers
+    #[error("Cannot compose an empty trace")]
+    EmptyTrace,
+    /// Interpolation needs an inverse the field does not have
+    #[error("Trace domain of {0} rows is not invertible in the field")]
+    NonInvertible(usize),
+    /// A transition constraint does not hold between two rows
+    #[error("Transition constraint on register {register} fails at step {step}")]
+    TransitionViolated {
+        /// Register whose next value is wrong
+        register: usize,
+        /// Row the transition starts from
+        step: usize,
+    },
+    /// A boundary constraint does not hold
+    #[error("Boundary constraint on register {register} fails at step {step}")]
+    BoundaryViolated {
+        /// Constrained register
+        register: usize,
+        /// Constrained row
+        step: usize,
+    },
+    /// A boundary constraint names a register or step outside the trace
+    #[error("Boundary constraint on register {register} at step {step} is outside the trace")]
+    InvalidBoundary {
+        /// Constrained register
+        register: usize,
+        /// Constrained row
+        step: usize,
+    },
+}
+
+/// Composition polynomial of a trace under an AIR, padded to the trace length
+///
+/// `alpha` is the composition challenge; the prover draws it from its transcript.
+///
+/// # Errors
+///
+/// [`CompositionError`] if the trace is empty, violates a constraint of `air`, or has a
+/// length the field cannot interpolate over
+pub fn composition_polynomial<F: FieldElement>(
+    air: &Air<F>,
+    trace: &ExecutionTrace<F>,
+    alpha: F,
+) -> Result<Vec<F>, CompositionError> {
+    composition_polynomial_in(air, trace, alpha)
+}
+
+/// Composition polynomial with the challenge and combination in a field `E` containing `F`
+///
+/// # Errors
+///
+/// As [`composition_polynomial`]
+pub fn composition_polynomial_in<F: FieldElement, E: FieldElement + From<F>>(
+    air: &Air<F>,
+    trace: &ExecutionTrace<F>,
+    alpha: E,
+) -> Result<Vec<E>, CompositionError> {
+    let length = trace.length;
+    let quotients = constraint_quotients(air, trace)?;
+
+    let mut composition = vec![E::zero(); length];
+    let mut weight = E::one();
+    for quotient in &quotients {
+        let lifted: Vec<E> = quotient.iter().map(|&coefficient| E::from(coefficient)).collect();
+        add_scaled(&mut composition, &lifted, weight);
+        weight *= alpha;
+    }
+    composition.truncate(length.max(1));
+    Ok(composition)
+}
+
+/// Transition quotients by register, then boundary quotients in order
+fn constraint_quotients<F: FieldElement>(
+    air: &Air<F>,
+    trace: &ExecutionTrace<F>,
+) -> Result<Vec<Vec<F>>, CompositionError> {
+    let length = trace.length;
+    if length == 0 || trace.columns.is_empty() {
+        return Err(CompositionError::EmptyTrace);
+    }
+
+    let columns = trace
+        .columns
+        .iter()
+        .map(|column| interpolate_column(column))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut quotients = Vec::new();
+    for (register, row) in air.transition.coefficients().iter().enumerate() {
+        let Some(column) = columns.get(register) else {
+            continue;
+        };
+        let mut constraint = shift_by_one(column);
+        for (input, &coefficient) in row.iter().enumerate() {
+            if let Some(input_column) = columns.get(input) {
+                add_scaled(&mut constraint, input_column, -coefficient);
+            }
+        }
+        for periodic in air.periodic_columns.iter().filter(|periodic| periodic.register() == register) {
+            let periodic_polynomial = interpolate_column(&periodic.trace_values(length))?;
+            add_scaled(&mut constraint, &periodic_polynomial, -F::one());
+        }
+        let quotient = divide_by_roots(constraint, 0..length - 1)
+            .map_err(|step| CompositionError::TransitionViolated { register, step })?;
+        quotients.push(quotient);
+    }
+
+    for constraint in &air.boundary.constraints {
+        let step = match constraint.constraint_type {
+            BoundaryType::Initial => 0,
+            BoundaryType::Final => length - 1,
+        };
+        let register = constraint.register;
+        let column = columns
+            .get(register)
+            .ok_or(CompositionError::InvalidBoundary { register, step })?;
+        let mut numerator = column.clone();
+        add_scaled(&mut numerator, &[F::one()], -constraint.value);
+        let quotient = divide_by_roots(numerator, step..step + 1)
+            .map_err(|step| CompositionError::BoundaryViolated { register, step })?;
+        quotients.push(quotient);
+    }
+
+    Ok(quotients)
+}
+
+/// Coefficients of the polynomial through `values` at `x = 0, 1, ..., n - 1`
+///
+/// Newton's forward differences: `P(x) = Σ_k Δ^k y_0 / k! · x (x - 1) ... (x - k + 1)`.
+///
+/// # Errors
+///
+/// [`CompositionError::NonInvertible`] if a factorial up to `values.len() - 1` is zero in
+/// the field
+pub fn interpolate_column<F: FieldElement>(values: &[F]) -> Result<Vec<F>, CompositionError> {
+    let mut factorials = Vec::with_capacity(values.len());
+    let mut factorial = F::one();
+    for k in 0..values.len() {
+        if k > 0 {
+            factorial *= F::new(k as u64);
+        }
+        if factorial.is_zero() {
+            return Err(CompositionError::NonInvertible(values.len()));
+        }
+        factorials.push(factorial);
+    }
+    let inverse_factorials = F::batch_inverse(&factorials);
+
+    let mut differences = values.to_vec();
+    let mut newton = Vec::with_capacity(values.len());
+    for (k, inverse) in inverse_factorials.into_iter().enumerate() {
+        if k > 0 {
+            for i in 0..values.len() - k {
+                differences[i] = differences[i + 1] - differences[i];
+            }
+        }
+        newton.push(differences[0] * inverse);
+    }
+
+    // Nested form: a_0 + x (a_1 + (x - 1) (a_2 + ...))
+    let mut polynomial = Vec::with_capacity(values.len());
+    for (k, &coefficient) in newton.iter().enumerate().rev() {
+        polynomial = multiply_by_root(&polynomial, F::new(k as u64));
+        add_scaled(&mut polynomial, &[coefficient], F::one());
+    }
+    Ok(polynomial)
+}
+
+/// `P(x + 1)` from `P(x)`
+fn shift_by_one<F: FieldElement>(polynomial: &[F]) -> Vec<F> {
+    let mut shifted: Vec<F> = Vec::with_capacity(polynomial.len());
+    for &coefficient in polynomial.iter().rev() {
+        // shifted · (x + 1) + coefficient
+        let mut next = vec![F::zero(); shifted.len() + 1];
+        for (i, &value) in shifted.iter().enumerate() {
+            next[i] += value;
+            next[i + 1] += value;
+        }
+        next[0] += coefficient;
+        shifted = next;
+    }
+    shifted
+}
+
+/// `P(x) · (x - root)`
+fn multiply_by_root<F: FieldElement>(polynomial: &[F], root: F) -> Vec<F> {
+    let mut product = vec![F::zero(); polynomial.len() + 1];
+    for (i, &value) in polynomial.iter().enumerate() {
+        product[i + 1] += value;
+        product[i] -= value * root;
+    }
+    product
+}
+
+/// `target += scale · addend`, growing `target` as needed
+fn add_scaled<F: FieldElement>(target: &mut Vec<F>, addend: &[F], scale: F) {
+    if target.len() < addend.len() {
+        target.resize(addend.len(), F::zero());
+    }
+    for (value, &add) in target.iter_mut().zip(addend) {
+        *value += add * scale;
+    }
+}
+
+/// Divide by `Π (x - r)` over `roots`, failing with the first root that is not one
+fn divide_by_roots<F: FieldElement>(
+    mut polynomial: Vec<F>,
+    roots: std::ops::Range<usize>,
+) -> Result<Vec<F>, usize> {
+    for root in roots {
+        // Synthetic division by (x - root); the remainder is P(root)
+        let point = F::new(root as u64);
+        let mut carry = F::zero();
+        for value in polynomial.iter_mut().rev() {
+            let next = *value + carry * point;
+            *value = carry;
+            carry = next;
+        }
+        if !carry.is_zero() {
+            return Err(root);
+        }
+        polynomial.pop();
+    }
+    Ok(polynomial)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::air::boundaries::BoundaryConstraint;
+    use crate::air::{BoundaryConditions, TransitionFunction};
+    use crate::types::field::PrimeField64;
+
+    fn f(value: u64) -> PrimeField64 {
+        PrimeField64::new(value)
+    }
+
+    /// Two registers: x' = x + y, y' = y
+    fn stepper(boundary: Vec<BoundaryConstraint<PrimeField64>>) -> Air<PrimeField64> {
+        let transition = TransitionFunction::new(vec![vec![f(1), f(1)], vec![f(0), f(1)]], 1);
+        Air::new(vec![], transition, BoundaryConditions::new(boundary), 128)
+    }
+
+    fn stepper_trace(length: usize) -> ExecutionTrace<PrimeField64> {
+        ExecutionTrace {
+            columns: vec![(0..length as u64).map(|i| f(3 + 2 * i)).collect(), vec![f(2); length]],
+            length,
+            num_registers: 2,
+        }
+    }
+
+    #[test]
+    fn test_interpolation_and_shift() {
+        let values: Vec<_> = (0..16u64).map(|x| f(x * x * x + 5)).collect();
+        let polynomial = interpolate_column(&values).unwrap();
+        let evaluate = |p: &[PrimeField64], x: u64| p.iter().rev().fold(f(0), |acc, &c| acc * f(x) + c);
+
+        for x in 0..16 {
+            assert_eq!(evaluate(&polynomial, x), values[x as usize]);
+        }
+        assert!(polynomial[4..].iter().all(|c| c.is_zero()));
+        assert_eq!(evaluate(&shift_by_one(&polynomial), 20), evaluate(&polynomial, 21));
+    }
+
+    #[test]
+    fn test_valid_trace_composes() {
+        let air = stepper(vec![BoundaryConstraint::initial(0, f(3)), BoundaryConstraint::final_condition(1, f(2))]);
+        let composition = composition_polynomial(&air, &stepper_trace(32), f(97)).unwrap();
+        assert_eq!(composition.len(), 32);
+        assert!(composition.iter().any(|c| !c.is_zero()));
+    }
+
+    #[test]
+    fn test_extension_composition_lifts_base() {
+        use crate::types::field::QuadExtension;
+
+        let air = stepper(vec![BoundaryConstraint::initial(0, f(3))]);
+        let trace = stepper_trace(16);
+        let base = composition_polynomial(&air, &trace, f(97)).unwrap();
+        let lifted = composition_polynomial_in(&air, &trace, QuadExtension::from(f(97))).unwrap();
+        assert_eq!(lifted, base.iter().map(|&c| QuadExtension::from(c)).collect::<Vec<_>>());
+
+        // A challenge outside the prime field leaves it
+        let alpha = QuadExtension::from_coefficients([f(97), f(1)]);
+        let extended = composition_polynomial_in(&air, &trace, alpha).unwrap();
+        assert!(extended.iter().any(|c| c.to_base().is_none()));
+    }
+
+    #[test]
+    fn test_broken_trace_rejected() {
+        let air = stepper(vec![BoundaryConstraint::initial(0, f(3))]);
+
+        let mut tampered = stepper_trace(32);
+        tampered.columns[0][17] = f(1);
+        assert_eq!(
+            composition_polynomial(&air, &tampered, f(97)),
+            Err(CompositionError::TransitionViolated { register: 0, step: 16 })
+        );
+
+        let wrong_start = stepper(vec![BoundaryConstraint::initial(0, f(4))]);
+        assert_eq!(
+            composition_polynomial(&wrong_start, &stepper_trace(32), f(97)),
+            Err(CompositionError::BoundaryViolated { register: 0, step: 0 })
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Proof Compression
+//!
+//! [`StarkProof::compress`] shrinks a proof for storage or transport in two passes:
+//!
+//! 1. **Deduplication**: a non-zero-knowledge proof carries its trace twice, once as
+//!    columns and once as the leaves of the trace commitment. Commitments whose leaves
+//!    are exactly the trace are stored without them and rebuilt from the columns.
+//! 2. **Entropy coding**: the [wire format](crate::proof::wire) of what remains,
+//!    FRI layers and final polynomial coefficients included, is zstd-compressed.
+//!
+//! FRI queries in this proof system carry their responses but no Merkle authentication
+//! paths, so there are no authentication nodes to share between queries.
+//!
+//! ## Format
+//!
+//! | Bytes | Field                                           |
+//! |-------|-------------------------------------------------|
+//! | 8     | Magic `XFGPROOZ`                                |
+//! | 1     | Compression format version                      |
+//! | 4     | Number `k` of commitments stored without leaves |
+//! | 4·k   | Their indices, ascending                        |
+//! | ...   | zstd-compressed wire format                     |
+//!
+//! All integers are little-endian. Decompression stops at the default
+//! [`ProofReadLimits::max_proof_bytes`], so a small input cannot expand without bound.
+
+use crate::proof::wire::{read_from_slice, ProofFormatError, ProofReadLimits};
+use crate::types::stark::StarkProof;
+use crate::types::{FieldElement, StarkComponent};
+use std::io::Read;
+
+/// Magic bytes opening every compressed proof
+pub const COMPRESSED_PROOF_MAGIC: [u8; 8] = *b"XFGPROOZ";
+
+/// Current compressed proof format version
+pub const COMPRESSED_PROOF_VERSION: u8 = 1;
+
+/// zstd level; proofs are written once and read many times
+const ZSTD_LEVEL: i32 = 19;
+
+/// Proof compression error types
+#[derive(Debug, thiserror::Error)]
+pub enum CompressionError {
+    /// Input is not a compressed proof
+    #[error("Not a compressed STARK proof")]
+    BadMagic,
+
+    /// Written by a newer compression format
+    #[error("Unsupported compressed proof version {0}")]
+    UnsupportedVersion(u8),
+
+    /// Header ends early
+    #[error("Compressed proof header is truncated")]
+    Truncated,
+
+    /// Decompressed proof exceeds the read limit
+    #[error("Decompressed proof exceeds {0} bytes")]
+    TooLarge(u64),
+
+    /// zstd failed
+    #[error("zstd error: {0}")]
+    Zstd(#[from] std::io::Error),
+
+    /// Decompressed bytes are not a valid proof
+    #[error("Decompressed proof is malformed: {0}")]
+    Format(#[from] ProofFormatError),
+
+    /// A commitment marked as deduplicated is missing or has leaves
+    #[error("Commitment {0} cannot take the trace as its leaves")]
+    InvalidCommitment(usize),
+}
+
+impl<F: FieldElement> StarkProof<F> {
+    /// Deduplicate and entropy-code the proof (see [`crate::proof::compression`])
+    pub fn compress(&self) -> Result<Vec<u8>, CompressionError> {
+        let trace_leaves = self.trace_leaves();
+        let mut proof = self.clone();
+        let mut deduplicated = Vec::new();
+        for (index, commitment) in proof.commitments.iter_mut().enumerate() {
+            if !commitment.leaves.is_empty() && commitment.leaves == trace_leaves {
+                commitment.leaves = Vec::new();
+                deduplicated.push(index as u32);
+            }
+        }
+
+        let mut out = Vec::new();
+        out.extend_from_slice(&COMPRESSED_PROOF_MAGIC);
+        out.push(COMPRESSED_PROOF_VERSION);
+        out.extend_from_slice(&(deduplicated.len() as u32).to_le_bytes());
+        for index in deduplicated {
+            out.extend_from_slice(&index.to_le_bytes());
+        }
+        out.extend_from_slice(&zstd::bulk::compress(&proof.to_bytes(), ZSTD_LEVEL)?);
+        Ok(out)
+    }
+
+    /// Rebuild a proof from [`Self::compress`] output
+    pub fn decompress(bytes: &[u8]) -> Result<Self, CompressionError> {
+        let (magic, rest) = bytes.split_at_checked(COMPRESSED_PROOF_MAGIC.len()).ok_or(CompressionError::BadMagic)?;
+        if magic != COMPRESSED_PROOF_MAGIC {
+            return Err(CompressionError::BadMagic);
+        }
+        let (&version, rest) = rest.split_first().ok_or(CompressionError::Truncated)?;
+        if version != COMPRESSED_PROOF_VERSION {
+            return Err(CompressionError::UnsupportedVersion(version));
+        }
+        let (count, mut rest) = take_u32(rest)?;
+        let mut deduplicated = Vec::new();
+        for _ in 0..count {
+            let (index, remaining) = take_u32(rest)?;
+            deduplicated.push(index as usize);
+            rest = remaining;
+        }
+
+        let limit = ProofReadLimits::default().max_proof_bytes;
+        let mut wire = Vec::new();
+        zstd::stream::read::Decoder::new(rest)?.take(limit + 1).read_to_end(&mut wire)?;
+        if wire.len() as u64 > limit {
+            return Err(CompressionError::TooLarge(limit));
+        }
+
+        let mut proof: Self = read_from_slice(&wire, |reader| reader.read_proof())?;
+        let trace_leaves = proof.trace_leaves();
+        for index in deduplicated {
+            match proof.commitments.get_mut(index) {
+                Some(commitment) if commitment.leaves.is_empty() => commitment.leaves = trace_leaves.clone(),
+                _ => return Err(CompressionError::InvalidCommitment(index)),
+            }
+        }
+        Ok(proof)
+    }
+
+    /// Trace columns one after the other, as the trace commitment stores them
+    fn trace_leaves(&self) -> Vec<F> {
+        self.trace.columns.iter().flatten().copied().collect()
+    }
+}
+
+/// Split a little-endian `u32` off the front of `bytes`
+fn take_u32(bytes: &[u8]) -> Result<(u32, &[u8]), CompressionError> {
+    let (value, rest) = bytes.split_at_checked(4).ok_or(CompressionError::Truncated)?;
+    Ok((u32::from_le_bytes(value.try_into().expect("split at 4 bytes")), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::air::{Air, BoundaryConditions, TransitionFunction};
+    use crate::proof::StarkProver;
+    use crate::types::field::PrimeField64;
+
+    fn proof() -> StarkProof<PrimeField64> {
+        let (zero, one) = (PrimeField64::zero(), PrimeField64::one());
+        let transition = TransitionFunction::new(vec![vec![one, one], vec![zero, one]], 1);
+        let air = Air::new(vec![], transition, BoundaryConditions::new(vec![]), 128);
+        StarkProver::new(128).prove(&air, &[PrimeField64::new(3), PrimeField64::new(2)], 64).unwrap()
+    }
+
+    #[test]
+    fn test_compression_round_trip() {
+        let proof = proof();
+        assert!(!proof.commitments[0].leaves.is_empty());
+
+        let compressed = proof.compress().unwrap();
+        assert!(compressed.len() * 2 < proof.to_bytes().len());
+        assert_eq!(StarkProof::decompress(&compressed).unwrap(), proof);
+    }
+
+    #[test]
+    fn test_decompress_rejects_bad_input() {
+        let compressed = proof().compress().unwrap();
+        assert!(matches!(
+            StarkProof::<PrimeField64>::decompress(&compressed[1..]),
+            Err(CompressionError::BadMagic)
+        ));
+        assert!(matches!(
+            StarkProof::<PrimeField64>::decompress(&compressed[..10]),
+            Err(CompressionError::Truncated)
+        ));
+
+        // Point the deduplicated index at a commitment that does not exist
+        let mut moved = compressed;
+        moved[13] = 7;
+        assert!(matches!(
+            StarkProof::<PrimeField64>::decompress(&moved),
+            Err(CompressionError::InvalidCommitment(7))
+        ));
+    }
+}
@@ -0,0 +1,354 @@
+//! EVM Calldata Encoding
+//!
+//! [`encode_for_solidity`] lays out a burn & mint proof as the ABI-encoded arguments of
+//! the Solidity verifier's [`VERIFY_SIGNATURE`]:
+//!
+//! ```text
+//! verifyProof(uint256[] metadata, uint256[] publicInputs, bytes proof)
+//! ```
+//!
+//! Every value is a 32-byte big-endian word, as the ABI requires. Field elements are
+//! their canonical value, not the little-endian bytes Winterfell serializes them as.
+//!
+//! | `metadata` index | Value                                                |
+//! |------------------|------------------------------------------------------|
+//! | 0                | Public input layout version                          |
+//! | 1                | Hash function (0 = blake3, 1 = keccak, 2 = rescue)   |
+//! | 2                | Trace width                                          |
+//! | 3                | Trace length                                         |
+//! | 4                | Number of queries                                    |
+//! | 5                | Blowup factor                                        |
+//! | 6                | Grinding factor                                      |
+//! | 7                | Field extension degree                               |
+//! | 8                | FRI folding factor                                   |
+//! | 9                | FRI remainder max degree                             |
+//!
+//! `publicInputs` follows [`crate::public_input_layout`] and `proof` is Winterfell's
+//! proof serialization. [`decode_from_solidity`] inverts the encoding and rejects
+//! calldata whose metadata disagrees with the proof it carries.
+//!
+//! With the `rpc` feature, `GasEstimator` simulates calls against a live chain and
+//! prices them at its current fees.
+
+use crate::burn_mint_air::BurnMintPublicInputs;
+use crate::public_input_layout::PUBLIC_INPUT_LAYOUT_VERSION;
+use crate::statements::HashFunction;
+use sha3::{Digest, Keccak256};
+use thiserror::Error;
+use winter_math::{StarkField, ToElements};
+use winterfell::{math::fields::f64::BaseElement, StarkProof};
+
+#[cfg(feature = "rpc")]
+mod gas;
+#[cfg(feature = "rpc")]
+pub use gas::*;
+
+/// Solidity signature of the verifier function the calldata is encoded for
+pub const VERIFY_SIGNATURE: &str = "verifyProof(uint256[],uint256[],bytes)";
+
+/// Number of `metadata` words
+pub const EVM_METADATA_WORDS: usize = 10;
+
+/// Bytes in an ABI word
+pub(crate) const WORD: usize = 32;
+
+/// Number of head words: one offset per dynamic argument
+const HEAD_WORDS: usize = 3;
+
+/// Calldata that is not an encoded burn & mint proof
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum EvmDecodeError {
+    /// Calldata ends inside a word or argument
+    #[error("Calldata truncated at byte {0}")]
+    Truncated(usize),
+    /// A word holds more than 64 bits, or a field element at or above the modulus
+    #[error("Word at byte {0} is not canonical")]
+    NonCanonicalWord(usize),
+    /// The bytes after the proof are not zero
+    #[error("Proof padding is not zero")]
+    NonZeroPadding,
+    /// The metadata array has the wrong number of words
+    #[error("Metadata has {0} words, expected {EVM_METADATA_WORDS}")]
+    MetadataLength(usize),
+    /// The public inputs follow a layout this release does not know
+    #[error("Unknown public input layout version {0}")]
+    UnknownLayoutVersion(u64),
+    /// The hash function index is out of range
+    #[error("Unknown hash function {0}")]
+    UnknownHashFunction(u64),
+    /// The public inputs do not follow the layout
+    #[error("Public inputs have {0} elements, which is not a burn & mint claim")]
+    PublicInputs(usize),
+    /// The proof bytes do not deserialize
+    #[error("Invalid proof bytes: {0}")]
+    Proof(String),
+    /// A metadata word disagrees with the proof
+    #[error("Metadata word {0} does not match the proof")]
+    MetadataMismatch(usize),
+}
+
+/// Burn & mint proof decoded from calldata
+#[derive(Debug, Clone)]
+pub struct EvmProof {
+    /// Hash function the proof commits with
+    pub hash_function: HashFunction,
+    /// Winterfell proof
+    pub proof: StarkProof,
+    /// Public inputs the proof binds
+    pub public_inputs: BurnMintPublicInputs,
+}
+
+/// ABI-encode a proof, its public inputs and metadata as [`VERIFY_SIGNATURE`] arguments
+#[must_use]
+pub fn encode_for_solidity(
+    proof: &StarkProof,
+    public_inputs: &BurnMintPublicInputs,
+    hash_function: HashFunction,
+) -> Vec<u8> {
+    let metadata = encode_words(&metadata_words(proof, hash_function));
+    let inputs: Vec<u64> = public_inputs.to_elements().iter().map(BaseElement::as_int).collect();
+    let inputs = encode_words(&inputs);
+    let proof = encode_bytes(&proof.to_bytes());
+
+    let mut calldata = Vec::with_capacity(HEAD_WORDS * WORD + metadata.len() + inputs.len() + proof.len());
+    let metadata_offset = HEAD_WORDS * WORD;
+    let inputs_offset = metadata_offset + metadata.len();
+    let proof_offset = inputs_offset + inputs.len();
+    for offset in [metadata_offset, inputs_offset, proof_offset] {
+        calldata.extend_from_slice(&word(offset as u64));
+    }
+    calldata.extend_from_slice(&metadata);
+    calldata.extend_from_slice(&inputs);
+    calldata.extend_from_slice(&proof);
+    calldata
+}
+
+/// Calldata calling the verifier: the [`verify_selector`] followed by the arguments
+#[must_use]
+pub fn encode_verify_call(
+    proof: &StarkProof,
+    public_inputs: &BurnMintPublicInputs,
+    hash_function: HashFunction,
+) -> Vec<u8> {
+    let mut calldata = verify_selector().to_vec();
+    calldata.extend_from_slice(&encode_for_solidity(proof, public_inputs, hash_function));
+    calldata
+}
+
+/// Function selector of [`VERIFY_SIGNATURE`]
+#[must_use]
+pub fn verify_selector() -> [u8; 4] {
+    let digest = Keccak256::digest(VERIFY_SIGNATURE.as_bytes());
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+/// Decode [`VERIFY_SIGNATURE`] arguments produced by [`encode_for_solidity`]
+///
+/// # Errors
+///
+/// An [`EvmDecodeError`] naming the first argument that is not encoded as
+/// [`encode_for_solidity`] would encode it
+pub fn decode_from_solidity(calldata: &[u8]) -> Result<EvmProof, EvmDecodeError> {
+    let metadata = read_array(calldata, read_usize(calldata, 0)?)?;
+    let inputs_offset = read_usize(calldata, WORD)?;
+    let inputs = read_array(calldata, inputs_offset)?;
+    let proof_bytes = read_bytes(calldata, read_usize(calldata, 2 * WORD)?)?;
+
+    if metadata.len() != EVM_METADATA_WORDS {
+        return Err(EvmDecodeError::MetadataLength(metadata.len()));
+    }
+    if metadata[0] != u64::from(PUBLIC_INPUT_LAYOUT_VERSION) {
+        return Err(EvmDecodeError::UnknownLayoutVersion(metadata[0]));
+    }
+    let hash_function = usize::try_from(metadata[1])
+        .ok()
+        .and_then(|index| HashFunction::ALL.get(index).copied())
+        .ok_or(EvmDecodeError::UnknownHashFunction(metadata[1]))?;
+
+    // Public inputs are field elements; a value past the modulus would alias another
+    if let Some(i) = inputs.iter().position(|value| *value >= BaseElement::MODULUS) {
+        return Err(EvmDecodeError::NonCanonicalWord(inputs_offset + WORD * (i + 1)));
+    }
+    let elements: Vec<BaseElement> = inputs.iter().map(|value| BaseElement::new(*value)).collect();
+    let public_inputs =
+        BurnMintPublicInputs::from_elements(&elements).ok_or(EvmDecodeError::PublicInputs(elements.len()))?;
+
+    let proof = StarkProof::from_bytes(proof_bytes).map_err(|e| EvmDecodeError::Proof(e.to_string()))?;
+    if let Some(index) = metadata_words(&proof, hash_function)
+        .iter()
+        .zip(&metadata)
+        .position(|(expected, actual)| expected != actual)
+    {
+        return Err(EvmDecodeError::MetadataMismatch(index));
+    }
+
+    Ok(EvmProof {
+        hash_function,
+        proof,
+        public_inputs,
+    })
+}
+
+/// Metadata words describing a proof, in table order
+#[must_use]
+pub fn metadata_words(proof: &StarkProof, hash_function: HashFunction) -> [u64; EVM_METADATA_WORDS] {
+    let options = proof.options();
+    let trace_info = proof.get_trace_info();
+    let fri_options = options.to_fri_options();
+    let hash_index = HashFunction::ALL.iter().position(|h| *h == hash_function).unwrap_or_default();
+    [
+        u64::from(PUBLIC_INPUT_LAYOUT_VERSION),
+        hash_index as u64,
+        trace_info.width() as u64,
+        trace_info.length() as u64,
+        options.num_queries() as u64,
+        options.blowup_factor() as u64,
+        u64::from(options.grinding_factor()),
+        u64::from(options.field_extension().degree()),
+        fri_options.folding_factor() as u64,
+        fri_options.remainder_max_degree() as u64,
+    ]
+}
+
+/// Big-endian ABI word holding `value`
+pub(crate) fn word(value: u64) -> [u8; WORD] {
+    let mut word = [0u8; WORD];
+    word[WORD - 8..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// ABI tail of a `uint256[]`: the length, then one word per value
+fn encode_words(values: &[u64]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(WORD * (values.len() + 1));
+    out.extend_from_slice(&word(values.len() as u64));
+    for value in values {
+        out.extend_from_slice(&word(*value));
+    }
+    out
+}
+
+/// ABI tail of `bytes`: the length, then the bytes zero-padded to a whole word
+pub(crate) fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(WORD + bytes.len().next_multiple_of(WORD));
+    out.extend_from_slice(&word(bytes.len() as u64));
+    out.extend_from_slice(bytes);
+    out.resize(WORD + bytes.len().next_multiple_of(WORD), 0);
+    out
+}
+
+/// Read the word at byte `at`, which must fit in 64 bits
+fn read_u64(calldata: &[u8], at: usize) -> Result<u64, EvmDecodeError> {
+    let word = at
+        .checked_add(WORD)
+        .and_then(|end| calldata.get(at..end))
+        .ok_or(EvmDecodeError::Truncated(at))?;
+    if word[..WORD - 8].iter().any(|byte| *byte != 0) {
+        return Err(EvmDecodeError::NonCanonicalWord(at));
+    }
+    Ok(u64::from_be_bytes(word[WORD - 8..].try_into().expect("eight bytes")))
+}
+
+/// Read the word at byte `at` as an offset or length, which must fit in a `usize`
+fn read_usize(calldata: &[u8], at: usize) -> Result<usize, EvmDecodeError> {
+    usize::try_from(read_u64(calldata, at)?).map_err(|_| EvmDecodeError::Truncated(at))
+}
+
+/// Read a `uint256[]` whose tail starts at byte `at`
+fn read_array(calldata: &[u8], at: usize) -> Result<Vec<u64>, EvmDecodeError> {
+    let len = read_usize(calldata, at)?;
+    if len > calldata.len() / WORD {
+        return Err(EvmDecodeError::Truncated(at));
+    }
+    (1..=len).map(|i| read_u64(calldata, at + WORD * i)).collect()
+}
+
+/// Read `bytes` whose tail starts at byte `at`, checking the padding is zero
+fn read_bytes(calldata: &[u8], at: usize) -> Result<&[u8], EvmDecodeError> {
+    let len = read_usize(calldata, at)?;
+    let start = at + WORD;
+    let padded = start
+        .checked_add(len.checked_next_multiple_of(WORD).ok_or(EvmDecodeError::Truncated(start))?)
+        .filter(|end| *end <= calldata.len())
+        .ok_or(EvmDecodeError::Truncated(start))?;
+    if calldata[start + len..padded].iter().any(|byte| *byte != 0) {
+        return Err(EvmDecodeError::NonZeroPadding);
+    }
+    Ok(&calldata[start..start + len])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+
+    fn proof() -> (StarkProof, BurnMintPublicInputs) {
+        XfgBurnMintProver::default()
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed")
+    }
+
+    #[test]
+    fn test_round_trip_big_endian_words() {
+        let (proof, public_inputs) = proof();
+        let calldata = encode_for_solidity(&proof, &public_inputs, HashFunction::Blake3);
+        assert_eq!(calldata.len() % WORD, 0);
+
+        // The burn amount is the first public input, right-aligned big-endian
+        let inputs_offset = read_u64(&calldata, WORD).unwrap() as usize;
+        assert_eq!(read_u64(&calldata, inputs_offset).unwrap() as usize, BurnMintPublicInputs::NUM_ELEMENTS);
+        let burn_word = &calldata[inputs_offset + WORD..inputs_offset + 2 * WORD];
+        assert_eq!(burn_word[..24], [0u8; 24]);
+        assert_eq!(burn_word[24..], 8_000_000u64.to_be_bytes());
+
+        let decoded = decode_from_solidity(&calldata).unwrap();
+        assert_eq!(decoded.hash_function, HashFunction::Blake3);
+        assert_eq!(decoded.proof.to_bytes(), proof.to_bytes());
+        assert_eq!(decoded.public_inputs.to_elements(), public_inputs.to_elements());
+
+        let call = encode_verify_call(&proof, &public_inputs, HashFunction::Blake3);
+        assert_eq!(call[..4], verify_selector());
+        assert_eq!(call[4..], calldata[..]);
+    }
+
+    #[test]
+    fn test_rejects_malformed_calldata() {
+        let (proof, public_inputs) = proof();
+        let calldata = encode_for_solidity(&proof, &public_inputs, HashFunction::Blake3);
+        let metadata_offset = HEAD_WORDS * WORD;
+
+        assert!(matches!(decode_from_solidity(&calldata[..calldata.len() - WORD]), Err(EvmDecodeError::Truncated(_))));
+
+        // Metadata must describe the proof it carries
+        let mut other_length = calldata.clone();
+        other_length[metadata_offset + 4 * WORD + 31] ^= 1;
+        assert_eq!(decode_from_solidity(&other_length).unwrap_err(), EvmDecodeError::MetadataMismatch(3));
+
+        // Little-endian words, the usual integration mistake, are not canonical
+        let mut little_endian = calldata.clone();
+        let inputs_offset = read_u64(&calldata, WORD).unwrap() as usize;
+        let burn = inputs_offset + WORD;
+        little_endian[burn..burn + WORD].copy_from_slice(&{
+            let mut word = [0u8; WORD];
+            word[..8].copy_from_slice(&8_000_000u64.to_le_bytes());
+            word
+        });
+        assert_eq!(decode_from_solidity(&little_endian).unwrap_err(), EvmDecodeError::NonCanonicalWord(burn));
+
+        let mut padding = calldata.clone();
+        *padding.last_mut().unwrap() = 1;
+        if proof.to_bytes().len() % WORD != 0 {
+            assert_eq!(decode_from_solidity(&padding).unwrap_err(), EvmDecodeError::NonZeroPadding);
+        }
+    }
+}
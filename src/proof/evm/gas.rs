@@ -0,0 +1,331 @@
+//! Live Gas Estimation
+//!
+//! [`GasEstimator`] prices a call against the chain it will run on, through an Ethereum
+//! JSON-RPC endpoint:
+//!
+//! 1. `eth_chainId`, checked against the expected chain when one is set
+//! 2. `eth_estimateGas` simulates the call; a revert is decoded with
+//!    [`RevertDecoder`], so a claim that would fail is reported before it is sent
+//! 3. `eth_getBlockByNumber("latest")` reads the current base fee
+//! 4. `eth_maxPriorityFeePerGas` reads the node's suggested tip
+//!
+//! The [`GasEstimate`] adds a safety buffer to the gas limit and caps the fee at twice
+//! the base fee plus the tip, which covers six consecutive full blocks of base fee
+//! increases.
+
+use crate::claim_calldata::ClaimCall;
+use crate::eth_address::EthAddress;
+use crate::revert_reason::{RevertDecoder, RevertReason};
+use serde_json::{json, Value};
+use thiserror::Error;
+
+/// Gas limit buffer added to `eth_estimateGas` results (percent)
+pub const DEFAULT_GAS_BUFFER_PERCENT: u32 = 20;
+
+/// Gas and fees of a simulated call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasEstimate {
+    /// Chain the endpoint serves
+    pub chain_id: u64,
+    /// Gas the simulated call used
+    pub gas_limit: u64,
+    /// Base fee of the latest block (wei)
+    pub base_fee_per_gas: u128,
+    /// Suggested priority fee (wei)
+    pub max_priority_fee_per_gas: u128,
+    /// Buffer added to the gas limit (percent)
+    pub buffer_percent: u32,
+}
+
+impl GasEstimate {
+    /// Gas limit to submit with: the simulated gas plus the buffer, rounded up
+    #[must_use]
+    pub fn buffered_gas_limit(&self) -> u64 {
+        let buffered = u128::from(self.gas_limit) * (100 + u128::from(self.buffer_percent));
+        u64::try_from(buffered.div_ceil(100)).unwrap_or(u64::MAX)
+    }
+
+    /// `maxFeePerGas` to submit with: twice the base fee plus the priority fee
+    #[must_use]
+    pub fn max_fee_per_gas(&self) -> u128 {
+        self.base_fee_per_gas
+            .saturating_mul(2)
+            .saturating_add(self.max_priority_fee_per_gas)
+    }
+
+    /// Cost at the current base fee and priority fee, without the buffer (wei)
+    #[must_use]
+    pub fn expected_cost_wei(&self) -> u128 {
+        u128::from(self.gas_limit)
+            .saturating_mul(self.base_fee_per_gas.saturating_add(self.max_priority_fee_per_gas))
+    }
+
+    /// Most the transaction can cost: buffered gas at the maximum fee (wei)
+    #[must_use]
+    pub fn max_cost_wei(&self) -> u128 {
+        u128::from(self.buffered_gas_limit()).saturating_mul(self.max_fee_per_gas())
+    }
+}
+
+/// Ethereum JSON-RPC gas estimator
+#[derive(Debug, Clone)]
+pub struct GasEstimator {
+    /// JSON-RPC endpoint URL
+    pub url: String,
+    /// Buffer added to the gas limit (percent)
+    pub buffer_percent: u32,
+    /// Chain the endpoint must serve, if checked
+    pub chain_id: Option<u64>,
+    /// Decodes reverted simulations
+    pub revert_decoder: RevertDecoder,
+}
+
+impl GasEstimator {
+    /// Create an estimator for an endpoint, with the default buffer
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            buffer_percent: DEFAULT_GAS_BUFFER_PERCENT,
+            chain_id: None,
+            revert_decoder: RevertDecoder::new(),
+        }
+    }
+
+    /// Add another buffer to the gas limit
+    #[must_use]
+    pub fn with_buffer_percent(mut self, buffer_percent: u32) -> Self {
+        self.buffer_percent = buffer_percent;
+        self
+    }
+
+    /// Reject endpoints serving another chain
+    #[must_use]
+    pub fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Decode reverts with another decoder (e.g. one with a contract ABI loaded)
+    #[must_use]
+    pub fn with_revert_decoder(mut self, revert_decoder: RevertDecoder) -> Self {
+        self.revert_decoder = revert_decoder;
+        self
+    }
+
+    /// Simulate a call and price it at the current fees
+    ///
+    /// # Errors
+    ///
+    /// [`GasEstimateError::WrongChain`] if the endpoint serves another chain,
+    /// [`GasEstimateError::Reverted`] with the decoded reason if the call reverts, and
+    /// [`GasEstimateError::Transport`], [`GasEstimateError::Rpc`] or
+    /// [`GasEstimateError::InvalidResponse`] if the endpoint cannot answer
+    pub fn estimate(&self, from: Option<&EthAddress>, to: &EthAddress, calldata: &[u8]) -> Result<GasEstimate, GasEstimateError> {
+        let chain_id = quantity_u64(&self.call("eth_chainId", &json!([]))?)?;
+        if let Some(expected) = self.chain_id {
+            if chain_id != expected {
+                return Err(GasEstimateError::WrongChain { expected, found: chain_id });
+            }
+        }
+
+        let mut transaction = json!({ "to": to.to_string(), "data": format!("0x{}", hex::encode(calldata)) });
+        if let Some(from) = from {
+            transaction["from"] = json!(from.to_string());
+        }
+        let gas_limit = quantity_u64(&self.call("eth_estimateGas", &json!([transaction]))?)?;
+
+        let block = self.call("eth_getBlockByNumber", &json!(["latest", false]))?;
+        let base_fee_per_gas = block
+            .get("baseFeePerGas")
+            .ok_or_else(|| GasEstimateError::InvalidResponse("latest block has no baseFeePerGas (pre-London chain)".to_string()))
+            .and_then(quantity)?;
+        let max_priority_fee_per_gas = quantity(&self.call("eth_maxPriorityFeePerGas", &json!([]))?)?;
+
+        Ok(GasEstimate {
+            chain_id,
+            gas_limit,
+            base_fee_per_gas,
+            max_priority_fee_per_gas,
+            buffer_percent: self.buffer_percent,
+        })
+    }
+
+    /// Simulate a `claimHEAT` call on the HEAT verifier contract, sent by the recipient
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::estimate`]
+    pub fn estimate_claim(&self, verifier: &EthAddress, call: &ClaimCall) -> Result<GasEstimate, GasEstimateError> {
+        self.estimate(Some(&call.recipient), verifier, &call.calldata())
+    }
+
+    /// Make a JSON-RPC call and return its result
+    fn call(&self, method: &str, params: &Value) -> Result<Value, GasEstimateError> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let mut response: Value = ureq::post(&self.url)
+            .send_json(request)
+            .map_err(|e| GasEstimateError::Transport(e.to_string()))?
+            .into_json()
+            .map_err(|e| GasEstimateError::InvalidResponse(e.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            // Nodes report a reverted simulation as an error carrying the revert data
+            let reverted = error.get("code").and_then(Value::as_i64) == Some(3)
+                || error
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .is_some_and(|message| message.contains("revert"));
+            if method == "eth_estimateGas" && reverted {
+                let reason = match error.get("data").and_then(Value::as_str) {
+                    Some(data) => self.revert_decoder.decode_hex(data),
+                    None => RevertReason::Empty,
+                };
+                return Err(GasEstimateError::Reverted(reason));
+            }
+            return Err(GasEstimateError::Rpc(format!("{method}: {error}")));
+        }
+        response
+            .get_mut("result")
+            .map(Value::take)
+            .ok_or_else(|| GasEstimateError::InvalidResponse(format!("{method} response has no result")))
+    }
+}
+
+/// Parse a JSON-RPC hex quantity
+fn quantity(value: &Value) -> Result<u128, GasEstimateError> {
+    let invalid = || GasEstimateError::InvalidResponse(format!("expected a hex quantity, got {value}"));
+    let digits = value.as_str().and_then(|s| s.strip_prefix("0x")).ok_or_else(invalid)?;
+    u128::from_str_radix(digits, 16).map_err(|_| invalid())
+}
+
+fn quantity_u64(value: &Value) -> Result<u64, GasEstimateError> {
+    u64::try_from(quantity(value)?)
+        .map_err(|_| GasEstimateError::InvalidResponse(format!("quantity {value} does not fit in 64 bits")))
+}
+
+/// Gas estimation error types
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum GasEstimateError {
+    /// Endpoint unreachable or answered with an HTTP error
+    #[error("Ethereum RPC transport error: {0}")]
+    Transport(String),
+
+    /// Endpoint answered with a JSON-RPC error
+    #[error("Ethereum RPC error: {0}")]
+    Rpc(String),
+
+    /// Response is not what the method returns
+    #[error("Invalid Ethereum RPC response: {0}")]
+    InvalidResponse(String),
+
+    /// Endpoint serves another chain
+    #[error("RPC endpoint serves chain {found}, expected {expected}")]
+    WrongChain {
+        /// Chain the estimator was configured for
+        expected: u64,
+        /// Chain the endpoint reported
+        found: u64,
+    },
+
+    /// The simulated call reverted, so submitting it would fail
+    #[error("Simulated call reverted: {0}; {hint}", hint = .0.hint())]
+    Reverted(RevertReason),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::revert_reason::HeatContractError;
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    /// Serve one JSON-RPC result or error per request; returns the methods called
+    fn serve(responses: Vec<Value>) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let mut methods = Vec::new();
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                let mut request = vec![0u8; content_length];
+                reader.read_exact(&mut request).unwrap();
+                let request: Value = serde_json::from_slice(&request).unwrap();
+                methods.push(request["method"].as_str().unwrap().to_string());
+
+                let mut body = json!({ "jsonrpc": "2.0", "id": request["id"] });
+                let key = if response.get("code").is_some() { "error" } else { "result" };
+                body[key] = response;
+                let body = body.to_string();
+                let mut stream = stream;
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            methods
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn test_estimate_prices_simulated_call() {
+        let (url, server) = serve(vec![
+            json!("0x66eee"),
+            json!("0x7a120"),
+            json!({ "number": "0x10", "baseFeePerGas": "0x3b9aca00" }),
+            json!("0x5f5e100"),
+        ]);
+        let verifier: EthAddress = "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd".parse().unwrap();
+        let estimate = GasEstimator::new(url)
+            .with_chain_id(421614)
+            .estimate(None, &verifier, &[0xde, 0xad])
+            .unwrap();
+
+        assert_eq!(estimate.chain_id, 421614);
+        assert_eq!(estimate.gas_limit, 500_000);
+        assert_eq!(estimate.buffered_gas_limit(), 600_000);
+        assert_eq!(estimate.max_fee_per_gas(), 2_100_000_000);
+        assert_eq!(estimate.expected_cost_wei(), 500_000 * 1_100_000_000);
+        assert_eq!(estimate.max_cost_wei(), 600_000 * 2_100_000_000);
+        assert_eq!(
+            server.join().unwrap(),
+            ["eth_chainId", "eth_estimateGas", "eth_getBlockByNumber", "eth_maxPriorityFeePerGas"]
+        );
+    }
+
+    #[test]
+    fn test_estimate_reports_reverts_and_wrong_chains() {
+        let revert_data = format!("0x{}", hex::encode(HeatContractError::NullifierAlreadyUsed.selector()));
+        let (url, server) = serve(vec![
+            json!("0x66eee"),
+            json!({ "code": 3, "message": "execution reverted", "data": revert_data }),
+            json!("0xa4b1"),
+        ]);
+        let verifier: EthAddress = "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd".parse().unwrap();
+        let estimator = GasEstimator::new(url).with_chain_id(421614);
+
+        assert_eq!(
+            estimator.estimate(None, &verifier, &[]),
+            Err(GasEstimateError::Reverted(RevertReason::Heat(HeatContractError::NullifierAlreadyUsed)))
+        );
+        assert_eq!(
+            estimator.estimate(None, &verifier, &[]),
+            Err(GasEstimateError::WrongChain { expected: 421614, found: 42161 })
+        );
+        assert_eq!(server.join().unwrap(), ["eth_chainId", "eth_estimateGas", "eth_chainId"]);
+    }
+}
@@ -6,21 +6,74 @@
 //! ## Features
 //! 
 //! - **Polynomial Folding**: Real polynomial folding with field arithmetic
-//! - **Domain Generation**: Efficient multiplicative subgroup generation
+//! - **Domain Generation**: Efficient multiplicative subgroup generation, shifted to a coset
 //! - **Proof Construction**: Complete FRI proof with layers and queries
 //! - **Verification**: Cryptographic verification of FRI proofs
 //! - **Performance Optimization**: Optimized algorithms for production use
-
+//!
+//! ## Coset Domain
+//!
+//! Layers are evaluated and queried over the coset `offset · H` rather than the natural
+//! domain `H`, so query points never coincide with trace domain points. The offset
+//! travels with the proof in [`FriDomain`] and the verifier rejects proofs whose domain
+//! is not shifted or whose queries fall outside the coset.
+//!
+//! ## Folding
+//!
+//! The first layer is the polynomial evaluated over the domain. Each later layer folds
+//! the `folding_factor` points `x·ω^k` that share `y = x^folding_factor` into one value
+//! at `y`, on the domain raised to the same power, until a constant is left. The
+//! verifier recomputes every fold at the queried positions from the previous layer.
+//!
+//! ## Challenges
+//!
+//! Folding challenges and query positions are squeezed from a [`Transcript`] after the
+//! commitments they depend on are absorbed. [`FriProver::prove`] starts a fresh
+//! [`FRI_PROTOCOL`] transcript; the STARK prover passes its own to
+//! [`FriProver::prove_with_transcript`] and the verifier replays it with
+//! [`FriVerifier::verify_queries`].
+//!
+//! ## Layer Commitments
+//!
+//! Each layer is committed to by the root of a [`MerkleTree`] over its values, hashed
+//! with SHA-256 unless the prover and verifier are switched with `with_hasher`. Both
+//! sides must use the same hasher. The proof opens every layer at the positions the
+//! queries read, the cosets a fold combines included, with one [`MultiProof`] per layer.
+//!
+//! ## Parallel Proving
+//!
+//! With the `parallel` feature, domain evaluation, layer folding and query evaluation
+//! run on the rayon thread pool. Challenges are still drawn from the transcript in
+//! order, so proofs are identical to the single-threaded ones.
+
+use crate::soundness::default_num_queries;
 use crate::types::{FieldElement, StarkComponent};
-use crate::types::stark::{FriProof, FriLayer, FriQuery};
+use crate::types::stark::{FriDomain, FriProof, FriLayer, FriQuery};
+use crate::proof::hasher::{Hasher, Sha256Hasher};
+use crate::proof::merkle::{MerkleError, MerkleTree, MultiProof};
+use crate::proof::cancellation::CancellationToken;
+use crate::proof::progress::{ProvingPhase, SharedProgress};
+use crate::proof::transcript::{Transcript, TranscriptLabel, FRI_PROTOCOL};
 use std::fmt::{Display, Formatter};
 use std::marker::PhantomData;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Blowup factor of provers and verifiers built with `new`; must be <= 16 for Winterfell compatibility
+const DEFAULT_BLOWUP_FACTOR: usize = 16;
+
+/// Folding factor of provers and verifiers built with `new`
+const DEFAULT_FOLDING_FACTOR: usize = 4;
+
+/// Default coset offset for FRI evaluation domains
+pub const DEFAULT_DOMAIN_OFFSET: u64 = 7;
+
 /// FRI proof generator
 /// 
 /// Generates FRI proofs for polynomial commitments with cryptographic security.
 #[derive(Debug, Clone)]
-pub struct FriProver<F: FieldElement> {
+pub struct FriProver<F: FieldElement, H: Hasher = Sha256Hasher> {
     /// Security parameter
     security_parameter: u32,
     /// Blowup factor for domain extension
@@ -29,8 +82,16 @@ pub struct FriProver<F: FieldElement> {
     num_queries: usize,
     /// Folding factor for polynomial reduction
     folding_factor: usize,
+    /// Coset offset of the evaluation domain
+    domain_offset: F,
+    /// Receives LDE, folding and query progress
+    progress: SharedProgress,
+    /// Checked after domain evaluation and between layers
+    cancellation: CancellationToken,
     /// Phantom data for type parameter
     _phantom: PhantomData<F>,
+    /// Layer commitment hash
+    _hasher: PhantomData<H>,
 }
 
 impl<F: FieldElement> FriProver<F> {
@@ -38,10 +99,14 @@ impl<F: FieldElement> FriProver<F> {
     pub fn new(security_parameter: u32) -> Self {
         Self {
             security_parameter,
-            blowup_factor: 16, // Must be <= 16 for Winterfell compatibility
-            num_queries: 64,
-            folding_factor: 4,
+            blowup_factor: DEFAULT_BLOWUP_FACTOR,
+            num_queries: default_num_queries(security_parameter, DEFAULT_BLOWUP_FACTOR),
+            folding_factor: DEFAULT_FOLDING_FACTOR,
+            domain_offset: F::new(DEFAULT_DOMAIN_OFFSET),
+            progress: SharedProgress::none(),
+            cancellation: CancellationToken::new(),
             _phantom: PhantomData,
+            _hasher: PhantomData,
         }
     }
 
@@ -57,216 +122,311 @@ impl<F: FieldElement> FriProver<F> {
             blowup_factor,
             num_queries,
             folding_factor,
+            domain_offset: F::new(DEFAULT_DOMAIN_OFFSET),
+            progress: SharedProgress::none(),
+            cancellation: CancellationToken::new(),
             _phantom: PhantomData,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl<F: FieldElement, H: Hasher> FriProver<F, H> {
+    /// Commit to layers with a different hash function
+    pub fn with_hasher<G: Hasher>(self) -> FriProver<F, G> {
+        FriProver {
+            security_parameter: self.security_parameter,
+            blowup_factor: self.blowup_factor,
+            num_queries: self.num_queries,
+            folding_factor: self.folding_factor,
+            domain_offset: self.domain_offset,
+            progress: self.progress,
+            cancellation: self.cancellation,
+            _phantom: PhantomData,
+            _hasher: PhantomData,
+        }
+    }
+
+    /// Use a different coset offset for the evaluation domain
+    #[must_use]
+    pub fn with_domain_offset(mut self, domain_offset: F) -> Self {
+        self.domain_offset = domain_offset;
+        self
+    }
+
+    /// Coset offset of the evaluation domain
+    pub fn domain_offset(&self) -> F {
+        self.domain_offset
+    }
+
+    /// Report domain evaluation, each folded layer and the query responses to `progress`
+    #[must_use]
+    pub fn with_progress(mut self, progress: SharedProgress) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Stop with [`FriError::Cancelled`] once `token` is cancelled, checked after domain
+    /// evaluation and each folded layer
+    #[must_use]
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// Checkpoint between proving steps
+    fn check_cancelled(&self) -> Result<(), FriError> {
+        if self.cancellation.is_cancelled() {
+            return Err(FriError::Cancelled);
         }
+        Ok(())
     }
 
-    /// Generate a complete FRI proof
+    /// Generate a complete FRI proof with challenges from a fresh transcript
     pub fn prove(&self, polynomial: &[F]) -> Result<FriProof<F>, FriError> {
+        self.prove_with_transcript(polynomial, &mut Transcript::new(FRI_PROTOCOL))
+    }
+
+    /// Generate a complete FRI proof, continuing `transcript`
+    ///
+    /// # Errors
+    ///
+    /// [`FriError::InvalidDomainSize`] or [`FriError::GeneratorNotFound`] if the field has
+    /// no evaluation domain for the polynomial, [`FriError::MerkleError`] if a layer cannot
+    /// be committed, or [`FriError::Cancelled`] if proving was cancelled
+    pub fn prove_with_transcript(
+        &self,
+        polynomial: &[F],
+        transcript: &mut Transcript,
+    ) -> Result<FriProof<F>, FriError> {
         // Step 1: Generate evaluation domain
         let domain = self.generate_evaluation_domain(polynomial.len())?;
 
         // Step 2: Evaluate polynomial over domain
-        let evaluations = self.evaluate_polynomial(polynomial, &domain)?;
+        let points = domain.elements();
+        let evaluations = self.evaluate_polynomial(polynomial, &points)?;
+        self.progress.report(ProvingPhase::LdeDone);
+        self.check_cancelled()?;
 
         // Step 3: Generate FRI layers through polynomial folding
-        let layers = self.generate_fri_layers(&evaluations, &domain)?;
+        let (layers, trees) = self.generate_fri_layers(evaluations, &domain, transcript)?;
 
         // Step 4: Generate final polynomial
         let final_polynomial = self.generate_final_polynomial(&layers)?;
 
-        // Step 5: Generate query responses
-        let queries = self.generate_queries(&layers, &domain)?;
+        // Step 5: Generate query responses and open the layers where they read
+        if domain.size == 0 {
+            return Err(FriError::InvalidDomainSize);
+        }
+        let positions: Vec<usize> = (0..self.num_queries)
+            .map(|_| transcript.challenge_index(TranscriptLabel::FriQueryIndex, domain.size))
+            .collect();
+        let queries = Self::generate_queries(&layers, &domain, &positions);
+        let openings = self.generate_openings(&layers, &trees, &positions)?;
+        self.progress.report(ProvingPhase::QueriesAnswered);
 
         // Step 6: Construct FRI proof
         let proof = FriProof {
             layers,
             final_polynomial,
             queries,
+            openings,
+            domain,
         };
 
         Ok(proof)
     }
 
-    /// Generate evaluation domain (multiplicative subgroup)
-    fn generate_evaluation_domain(&self, polynomial_degree: usize) -> Result<Vec<F>, FriError> {
-        let domain_size = polynomial_degree * self.blowup_factor;
+    /// Generate evaluation domain (multiplicative subgroup shifted by the coset offset)
+    fn generate_evaluation_domain(&self, polynomial_degree: usize) -> Result<FriDomain<F>, FriError> {
+        // Roots of unity exist for power-of-two orders up to the field's two-adicity
+        let domain_size = (polynomial_degree * self.blowup_factor).next_power_of_two();
         
         // Find a generator of the multiplicative subgroup
-        let generator = self.find_generator(domain_size)?;
+        let generator = Self::find_generator(domain_size)?;
         
-        // Generate domain elements: {1, g, g^2, ..., g^(domain_size-1)}
-        let mut domain = Vec::with_capacity(domain_size);
-        let mut current = F::one();
-        
-        for _ in 0..domain_size {
-            domain.push(current);
-            current = current * generator;
+        // Domain elements: {c, c·g, c·g^2, ..., c·g^(domain_size-1)}
+        let domain = FriDomain::new(domain_size, generator, self.domain_offset);
+        if !domain.is_shifted() {
+            return Err(FriError::UnshiftedDomain);
         }
 
         Ok(domain)
     }
 
-    /// Find a generator for the multiplicative subgroup
-    fn find_generator(&self, _domain_size: usize) -> Result<F, FriError> {
-        // For simplicity, we'll use a fixed generator
-        // In production, this should be computed based on the field
-        // For now, we'll use a simple approach that works for our field
-        let generator = F::new(5); // Use a fixed generator that works for our field
-        
-        // In a real implementation, we would verify it's a generator
-        // For now, we'll assume it works
-        Ok(generator)
+    /// Find a generator of the subgroup of `domain_size`-th roots of unity
+    ///
+    /// [`FriDomain::contains`] relies on the generator having order exactly `domain_size`.
+    fn find_generator(domain_size: usize) -> Result<F, FriError> {
+        let order = domain_size as u64;
+        if order == 0 || (F::MODULUS - 1) % order != 0 {
+            return Err(FriError::InvalidDomainSize);
+        }
+        let prime_factors = prime_factors(order);
+        (2..F::MODULUS)
+            .map(|candidate| F::new(candidate).pow((F::MODULUS - 1) / order))
+            .find(|root| prime_factors.iter().all(|&q| !root.pow(order / q).is_one()))
+            .ok_or(FriError::GeneratorNotFound)
     }
 
     /// Evaluate polynomial over domain
     fn evaluate_polynomial(&self, polynomial: &[F], domain: &[F]) -> Result<Vec<F>, FriError> {
-        let mut evaluations = Vec::with_capacity(domain.len());
-        
-        for &point in domain {
-            let mut result = F::zero();
-            let mut power = F::one();
-            
-            for &coeff in polynomial {
-                result = result + coeff * power;
-                power = power * point;
-            }
-            
-            evaluations.push(result);
-        }
+        #[cfg(feature = "parallel")]
+        let points = domain.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let points = domain.iter();
 
-        Ok(evaluations)
+        points.map(|&point| self.evaluate_at_point(polynomial, point)).collect()
     }
 
     /// Generate FRI layers through polynomial folding
-    fn generate_fri_layers(&self, evaluations: &[F], domain: &[F]) -> Result<Vec<FriLayer<F>>, FriError> {
-        let mut layers = Vec::new();
-        let mut current_evaluations = evaluations.to_vec();
-        let mut current_domain = domain.to_vec();
+    ///
+    /// The first layer holds `evaluations` over `domain`; each fold divides the degree
+    /// bound by the folding factor until the polynomial is constant. Each layer comes
+    /// with the Merkle tree its commitment is the root of.
+    fn generate_fri_layers(
+        &self,
+        evaluations: Vec<F>,
+        domain: &FriDomain<F>,
+        transcript: &mut Transcript,
+    ) -> Result<CommittedLayers<F, H>, FriError> {
         let mut current_degree = evaluations.len() / self.blowup_factor;
+        let num_folds = self.num_folds(current_degree);
+
+        let tree = Self::commit_layer(&evaluations)?;
+        let commitment = tree.root_hash().to_vec();
+        transcript.absorb(TranscriptLabel::FriLayerCommitment, &commitment);
+        let mut layers = vec![FriLayer {
+            polynomial: evaluations,
+            commitment,
+            degree: current_degree,
+        }];
+        let mut trees = vec![tree];
+        let mut current_domain = *domain;
+
+        while current_degree > 1 {
+            // Draw the folding challenge from everything committed so far
+            let challenge = transcript.challenge(TranscriptLabel::FriFoldingChallenge);
+
+            // Fold the last layer's cosets using the challenge
+            let current_evaluations = &layers[layers.len() - 1].polynomial;
+            let folded_evaluations = self.fold_polynomial(current_evaluations, &current_domain, challenge)?;
+            current_domain = current_domain.fold(self.folding_factor);
+            current_degree = current_degree.div_ceil(self.folding_factor);
 
-        // Continue folding until we have a very small polynomial (degree <= 1)
-        while current_degree > 1 && current_evaluations.len() > self.folding_factor {
-            // Generate random challenge for folding
-            let challenge = self.generate_random_challenge();
-            
-            // Fold polynomial using the challenge
-            let folded_evaluations = self.fold_polynomial(&current_evaluations, challenge)?;
-            
             // Generate commitment for this layer
-            let commitment = self.generate_commitment(&folded_evaluations)?;
-            
-            // Create FRI layer
-            let layer = FriLayer {
-                polynomial: folded_evaluations.clone(),
+            let tree = Self::commit_layer(&folded_evaluations)?;
+            let commitment = tree.root_hash().to_vec();
+            transcript.absorb(TranscriptLabel::FriLayerCommitment, &commitment);
+            trees.push(tree);
+
+            layers.push(FriLayer {
+                polynomial: folded_evaluations,
                 commitment,
                 degree: current_degree,
-            };
-            
-            layers.push(layer);
-            
-            // Update for next iteration
-            current_evaluations = folded_evaluations;
-            current_domain = self.reduce_domain(&current_domain);
-            current_degree = current_degree / self.folding_factor;
-        }
-
-        // Add the final layer if we have remaining evaluations
-        if !current_evaluations.is_empty() {
-            let commitment = self.generate_commitment(&current_evaluations)?;
-            let layer = FriLayer {
-                polynomial: current_evaluations,
-                commitment,
-                degree: current_degree.max(1),
-            };
-            layers.push(layer);
+            });
+            self.progress.report(ProvingPhase::LayerFolded { layer: layers.len() - 1, layers: num_folds });
+            self.check_cancelled()?;
         }
 
-        Ok(layers)
+        Ok((layers, trees))
+    }
+
+    /// Number of folds [`Self::generate_fri_layers`] makes from a degree bound of `degree`
+    fn num_folds(&self, mut degree: usize) -> usize {
+        let mut folds = 0;
+        while degree > 1 {
+            degree = degree.div_ceil(self.folding_factor);
+            folds += 1;
+        }
+        folds
     }
 
-    /// Fold polynomial using random challenge
-    fn fold_polynomial(&self, evaluations: &[F], challenge: F) -> Result<Vec<F>, FriError> {
-        if evaluations.len() % self.folding_factor != 0 {
+    /// Fold the evaluations over `domain` with a random challenge
+    ///
+    /// Point `i` of the folded layer combines the points `i + k · folded_size` of
+    /// `evaluations`, the coset of `x = domain.element(i)` under the folding factor's
+    /// roots of unity; see [`fold_coset`].
+    fn fold_polynomial(&self, evaluations: &[F], domain: &FriDomain<F>, challenge: F) -> Result<Vec<F>, FriError> {
+        if !evaluations.len().is_multiple_of(self.folding_factor) || evaluations.len() != domain.size {
             return Err(FriError::InvalidPolynomialSize);
         }
 
         let folded_size = evaluations.len() / self.folding_factor;
-        let mut folded = Vec::with_capacity(folded_size);
-
-        for i in 0..folded_size {
-            let mut result = F::zero();
-            let mut power = F::one();
-
-            for j in 0..self.folding_factor {
-                let index = i + j * folded_size;
-                result = result + evaluations[index] * power;
-                power = power * challenge;
-            }
-
-            folded.push(result);
-        }
+        let inverse = |element: F| element.inverse().ok_or(FriError::InvalidDomainSize);
+        let offset_inverse = inverse(domain.offset)?;
+        let generator_inverse = inverse(domain.generator)?;
+        let omega_inverse = inverse(domain.generator.pow(folded_size as u64))?;
+        let factor_inverse = inverse(F::new(self.folding_factor as u64))?;
+
+        #[cfg(feature = "parallel")]
+        let indices = (0..folded_size).into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let indices = 0..folded_size;
+
+        let folded = indices
+            .map(|i| {
+                let siblings: Vec<F> = (0..self.folding_factor)
+                    .map(|k| evaluations[i + k * folded_size])
+                    .collect();
+                let x_inverse = offset_inverse * generator_inverse.pow(i as u64);
+                fold_coset(&siblings, x_inverse, omega_inverse, factor_inverse, challenge)
+            })
+            .collect();
 
         Ok(folded)
     }
 
-    /// Reduce domain size for next layer
-    fn reduce_domain(&self, domain: &[F]) -> Vec<F> {
-        let reduced_size = domain.len() / self.folding_factor;
-        domain.iter().step_by(self.folding_factor).take(reduced_size).cloned().collect()
-    }
-
-    /// Generate commitment for layer
-    fn generate_commitment(&self, evaluations: &[F]) -> Result<Vec<u8>, FriError> {
-        // In a real implementation, this would use a cryptographic hash function
-        // For now, we'll use a simple hash-like function
-        let mut commitment = Vec::new();
-        
-        for &eval in evaluations {
-            let bytes = eval.to_bytes();
-            commitment.extend_from_slice(&bytes);
-        }
-
-        // Apply a simple hash (in production, use SHA256 or similar)
-        Ok(commitment)
+    /// Merkle tree over a layer's values
+    fn commit_layer(evaluations: &[F]) -> Result<MerkleTree<H>, FriError> {
+        Ok(MerkleTree::from_leaves(&layer_leaves(evaluations, 0..evaluations.len()))?)
     }
 
     /// Generate final polynomial
+    ///
+    /// Folding stops at a constant, so the final polynomial is the last layer's value.
     fn generate_final_polynomial(&self, layers: &[FriLayer<F>]) -> Result<Vec<F>, FriError> {
-        if layers.is_empty() {
-            return Err(FriError::NoLayers);
-        }
-
-        // The final polynomial is the last layer's polynomial
-        let last_layer = &layers[layers.len() - 1];
-        Ok(last_layer.polynomial.clone())
+        let last_layer = layers.last().ok_or(FriError::NoLayers)?;
+        Ok(last_layer.polynomial.first().copied().into_iter().collect())
     }
 
-    /// Generate query responses
-    fn generate_queries(&self, layers: &[FriLayer<F>], _domain: &[F]) -> Result<Vec<FriQuery<F>>, FriError> {
-        let mut queries = Vec::new();
-
-        for _ in 0..self.num_queries {
-            // Generate random query point
-            let query_point = self.generate_random_challenge();
-            
-            // Generate responses for each layer
-            let mut responses = Vec::new();
-            
-            for layer in layers {
-                let response = self.evaluate_at_point(&layer.polynomial, query_point)?;
-                responses.push(response);
-            }
-
-            let query = FriQuery {
-                point: query_point,
-                responses,
-            };
-
-            queries.push(query);
-        }
+    /// Generate query responses at `positions` of the coset domain, drawn in order after
+    /// every layer is committed
+    fn generate_queries(layers: &[FriLayer<F>], domain: &FriDomain<F>, positions: &[usize]) -> Vec<FriQuery<F>> {
+        #[cfg(feature = "parallel")]
+        let positions = positions.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let positions = positions.iter();
+
+        // Respond with each layer's value at the query's position in that layer
+        positions
+            .map(|&position| {
+                let responses = layers
+                    .iter()
+                    .map(|layer| layer.polynomial[position % layer.polynomial.len()])
+                    .collect();
+                FriQuery { point: domain.element(position), responses }
+            })
+            .collect()
+    }
 
-        Ok(queries)
+    /// Open every layer where the queries at `positions` read it
+    fn generate_openings(
+        &self,
+        layers: &[FriLayer<F>],
+        trees: &[MerkleTree<H>],
+        positions: &[usize],
+    ) -> Result<Vec<MultiProof>, FriError> {
+        layers
+            .iter()
+            .zip(trees)
+            .enumerate()
+            .map(|(i, (layer, tree))| {
+                let folded_len = layers.get(i + 1).map(|folded| folded.polynomial.len());
+                let opened = opened_positions(positions, layer.polynomial.len(), folded_len, self.folding_factor);
+                Ok(tree.generate_multi_proof(&opened)?)
+            })
+            .collect()
     }
 
     /// Evaluate polynomial at a specific point
@@ -281,21 +441,14 @@ impl<F: FieldElement> FriProver<F> {
 
         Ok(result)
     }
-
-    /// Generate random challenge
-    fn generate_random_challenge(&self) -> F {
-        // In production, this should use a cryptographically secure RNG
-        // For now, we'll use a simple deterministic approach
-        F::random()
-    }
 }
 
-impl<F: FieldElement> Display for FriProver<F> {
+impl<F: FieldElement, H: Hasher> Display for FriProver<F, H> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "FriProver(security={}, blowup={}, queries={}, folding={})",
-            self.security_parameter, self.blowup_factor, self.num_queries, self.folding_factor
+            "FriProver(security={}, blowup={}, queries={}, folding={}, hash={})",
+            self.security_parameter, self.blowup_factor, self.num_queries, self.folding_factor, H::NAME
         )
     }
 }
@@ -304,13 +457,17 @@ impl<F: FieldElement> Display for FriProver<F> {
 /// 
 /// Verifies FRI proofs with cryptographic security guarantees.
 #[derive(Debug, Clone)]
-pub struct FriVerifier<F: FieldElement> {
+pub struct FriVerifier<F: FieldElement, H: Hasher = Sha256Hasher> {
     /// Security parameter
     security_parameter: u32,
     /// Number of queries to verify
     num_queries: usize,
+    /// Folding factor between consecutive layers
+    folding_factor: usize,
     /// Phantom data for type parameter
     _phantom: PhantomData<F>,
+    /// Layer commitment hash
+    _hasher: PhantomData<H>,
 }
 
 impl<F: FieldElement> FriVerifier<F> {
@@ -318,13 +475,37 @@ impl<F: FieldElement> FriVerifier<F> {
     pub fn new(security_parameter: u32) -> Self {
         Self {
             security_parameter,
-            num_queries: 64,
+            num_queries: default_num_queries(security_parameter, DEFAULT_BLOWUP_FACTOR),
+            folding_factor: DEFAULT_FOLDING_FACTOR,
+            _phantom: PhantomData,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl<F: FieldElement, H: Hasher> FriVerifier<F, H> {
+    /// Check layer commitments with a different hash function
+    #[must_use]
+    pub fn with_hasher<G: Hasher>(self) -> FriVerifier<F, G> {
+        FriVerifier {
+            security_parameter: self.security_parameter,
+            num_queries: self.num_queries,
+            folding_factor: self.folding_factor,
             _phantom: PhantomData,
+            _hasher: PhantomData,
         }
     }
 
-    /// Verify a FRI proof
-    pub fn verify(&self, proof: &FriProof<F>, _original_polynomial: &[F]) -> Result<bool, FriError> {
+    /// Verify a FRI proof of `original_polynomial`
+    ///
+    /// Replays a fresh [`FRI_PROTOCOL`] transcript, as [`FriProver::prove`] does, and
+    /// checks the first layer's responses are `original_polynomial` at the query points.
+    ///
+    /// # Errors
+    ///
+    /// [`FriError::NoLayers`], [`FriError::NoQueries`] or [`FriError::UnshiftedDomain`] for a
+    /// malformed proof; a well-formed proof that does not verify returns `Ok(false)`
+    pub fn verify(&self, proof: &FriProof<F>, original_polynomial: &[F]) -> Result<bool, FriError> {
         // Step 1: Verify proof structure
         if proof.layers.is_empty() {
             return Err(FriError::NoLayers);
@@ -334,80 +515,171 @@ impl<F: FieldElement> FriVerifier<F> {
             return Err(FriError::NoQueries);
         }
 
-        // Step 2: Verify final polynomial
-        if !self.verify_final_polynomial(&proof.final_polynomial)? {
+        // Step 2: Verify queries stay on the coset, away from trace domain points
+        if !proof.domain.is_shifted() {
+            return Err(FriError::UnshiftedDomain);
+        }
+        if !Self::verify_query_domain(proof) {
+            return Ok(false);
+        }
+
+        // Step 3: Verify commitments, folding and the final polynomial at the query positions
+        if !self.verify_queries(proof, &mut Transcript::new(FRI_PROTOCOL))? {
             return Ok(false);
         }
 
-        // For now, we'll use a simplified verification that just checks structure
-        // In a full implementation, we would verify the polynomial folding
+        // Step 4: Verify the first layer evaluates the original polynomial
+        for query in &proof.queries {
+            if query.responses[0] != self.evaluate_at_point(original_polynomial, query.point)? {
+                return Ok(false);
+            }
+        }
+
         Ok(true)
     }
 
-    /// Verify layer consistency
-    fn verify_layer_consistency(&self, layers: &[FriLayer<F>]) -> Result<bool, FriError> {
-        for i in 1..layers.len() {
-            let prev_layer = &layers[i - 1];
-            let curr_layer = &layers[i];
+    /// Check the proof's layers fold into each other at the query positions that
+    /// `transcript` derives from the layer commitments
+    ///
+    /// Replays the prover's transcript: the first layer's commitment, a folding challenge
+    /// and a commitment per folded layer, then one index per query. Every layer must be
+    /// opened under its commitment where the queries read it, every response must be the
+    /// layer's value at the query's position, every folded value the fold of its coset in
+    /// the layer before, and the last layer the final polynomial.
+    ///
+    /// # Errors
+    ///
+    /// [`FriError::NoLayers`] if the proof has no layers, or [`FriError::InvalidDomainSize`]
+    /// if its first layer does not span the domain or a folded domain is not invertible; a
+    /// proof that does not verify returns `Ok(false)`
+    pub fn verify_queries(&self, proof: &FriProof<F>, transcript: &mut Transcript) -> Result<bool, FriError> {
+        let first = proof.layers.first().ok_or(FriError::NoLayers)?;
+        if proof.domain.size == 0 || first.polynomial.len() != proof.domain.size {
+            return Err(FriError::InvalidDomainSize);
+        }
+        if proof.queries.len() < self.num_queries {
+            return Ok(false);
+        }
+        if !self.verify_layer_consistency(&proof.layers) {
+            return Ok(false);
+        }
 
-            // Check that degrees are consistent (using folding factor)
-            if curr_layer.degree * 4 != prev_layer.degree {
-                return Ok(false);
+        let mut challenges = Vec::with_capacity(proof.layers.len() - 1);
+        for (i, layer) in proof.layers.iter().enumerate() {
+            if i > 0 {
+                challenges.push(transcript.challenge(TranscriptLabel::FriFoldingChallenge));
             }
+            transcript.absorb(TranscriptLabel::FriLayerCommitment, &layer.commitment);
+        }
 
-            // Verify commitment consistency
-            let expected_commitment = self.generate_commitment(&curr_layer.polynomial)?;
-            if curr_layer.commitment != expected_commitment {
-                return Ok(false);
-            }
+        let positions: Vec<usize> = proof
+            .queries
+            .iter()
+            .map(|_| transcript.challenge_index(TranscriptLabel::FriQueryIndex, proof.domain.size))
+            .collect();
+        if proof.queries.iter().zip(&positions).any(|(query, &position)| query.point != proof.domain.element(position)) {
+            return Ok(false);
         }
 
-        Ok(true)
+        Ok(self.verify_layer_openings(proof, &positions)
+            && Self::verify_query_responses(proof, &positions)
+            && self.verify_folding(proof, &challenges, &positions)?
+            && self.verify_final_polynomial(proof, &positions)?)
     }
 
-    /// Verify query responses
-    fn verify_query_responses(
-        &self,
-        proof: &FriProof<F>,
-        _original_polynomial: &[F],
-    ) -> Result<bool, FriError> {
-        for query in &proof.queries {
-            // Verify that responses are consistent with the polynomial
-            for (i, &response) in query.responses.iter().enumerate() {
-                if i < proof.layers.len() {
-                    let layer = &proof.layers[i];
-                    let expected = self.evaluate_at_point(&layer.polynomial, query.point)?;
-                    
-                    if response != expected {
-                        return Ok(false);
-                    }
+    /// Verify layer consistency
+    ///
+    /// Each layer has `folding_factor` times fewer points than the one before and a degree
+    /// bound divided by the folding factor.
+    fn verify_layer_consistency(&self, layers: &[FriLayer<F>]) -> bool {
+        layers.windows(2).all(|pair| {
+            let (prev_layer, curr_layer) = (&pair[0], &pair[1]);
+            curr_layer.polynomial.len() * self.folding_factor == prev_layer.polynomial.len()
+                && curr_layer.degree == prev_layer.degree.div_ceil(self.folding_factor)
+        })
+    }
+
+    /// Verify each layer's multiproof opens exactly the positions the queries read under
+    /// the layer's commitment
+    fn verify_layer_openings(&self, proof: &FriProof<F>, positions: &[usize]) -> bool {
+        if proof.openings.len() != proof.layers.len() {
+            return false;
+        }
+        proof.layers.iter().zip(&proof.openings).enumerate().all(|(i, (layer, opening))| {
+            let folded_len = proof.layers.get(i + 1).map(|folded| folded.polynomial.len());
+            let opened = opened_positions(positions, layer.polynomial.len(), folded_len, self.folding_factor);
+            let Ok(root) = <[u8; 32]>::try_from(layer.commitment.as_slice()) else {
+                return false;
+            };
+            opening.leaf_indices == opened
+                && opening.num_leaves == layer.polynomial.len()
+                && opening.verify_with::<H>(&layer_leaves(&layer.polynomial, opened.iter().copied()), root)
+        })
+    }
+
+    /// Verify every query responds with each layer's value at its position in that layer
+    fn verify_query_responses(proof: &FriProof<F>, positions: &[usize]) -> bool {
+        proof.queries.iter().zip(positions).all(|(query, &position)| {
+            query.responses.len() == proof.layers.len()
+                && proof
+                    .layers
+                    .iter()
+                    .zip(&query.responses)
+                    .all(|(layer, &response)| response == layer.polynomial[position % layer.polynomial.len()])
+        })
+    }
+
+    /// Verify every folded layer is the fold of the layer before at the query positions
+    fn verify_folding(&self, proof: &FriProof<F>, challenges: &[F], positions: &[usize]) -> Result<bool, FriError> {
+        let inverse = |element: F| element.inverse().ok_or(FriError::InvalidDomainSize);
+        let factor_inverse = inverse(F::new(self.folding_factor as u64))?;
+        let mut domain = proof.domain;
+
+        for (pair, &challenge) in proof.layers.windows(2).zip(challenges) {
+            let (layer, folded) = (&pair[0].polynomial, &pair[1].polynomial);
+            let omega_inverse = inverse(domain.generator.pow(folded.len() as u64))?;
+            for &position in positions {
+                let position = position % folded.len();
+                let siblings: Vec<F> = (0..self.folding_factor)
+                    .map(|k| layer[position + k * folded.len()])
+                    .collect();
+                let x_inverse = inverse(domain.element(position))?;
+                if fold_coset(&siblings, x_inverse, omega_inverse, factor_inverse, challenge) != folded[position] {
+                    return Ok(false);
                 }
             }
+            domain = domain.fold(self.folding_factor);
         }
 
         Ok(true)
     }
 
-    /// Verify final polynomial
-    fn verify_final_polynomial(&self, final_polynomial: &[F]) -> Result<bool, FriError> {
-        // The final polynomial should have low degree (allow up to 16 coefficients for small polynomials)
-        if final_polynomial.len() > 16 {
+    /// Verify every query point lies in the coset domain and off the natural domain
+    fn verify_query_domain(proof: &FriProof<F>) -> bool {
+        let natural = proof.domain.natural();
+        proof
+            .queries
+            .iter()
+            .all(|query| proof.domain.contains(query.point) && !natural.contains(query.point))
+    }
+
+    /// Verify the last layer evaluates the final polynomial at the query positions, and
+    /// the final polynomial stays within the last layer's degree bound
+    fn verify_final_polynomial(&self, proof: &FriProof<F>, positions: &[usize]) -> Result<bool, FriError> {
+        let last = proof.layers.last().ok_or(FriError::NoLayers)?;
+        if proof.final_polynomial.len() > last.degree.max(1) {
             return Ok(false);
         }
 
-        Ok(true)
-    }
-
-    /// Generate commitment (same as prover)
-    fn generate_commitment(&self, evaluations: &[F]) -> Result<Vec<u8>, FriError> {
-        let mut commitment = Vec::new();
-        
-        for &eval in evaluations {
-            let bytes = eval.to_bytes();
-            commitment.extend_from_slice(&bytes);
+        let domain = (1..proof.layers.len()).fold(proof.domain, |domain, _| domain.fold(self.folding_factor));
+        for &position in positions {
+            let position = position % last.polynomial.len();
+            if last.polynomial[position] != self.evaluate_at_point(&proof.final_polynomial, domain.element(position))? {
+                return Ok(false);
+            }
         }
 
-        Ok(commitment)
+        Ok(true)
     }
 
     /// Evaluate polynomial at point (same as prover)
@@ -424,7 +696,7 @@ impl<F: FieldElement> FriVerifier<F> {
     }
 }
 
-impl<F: FieldElement> Display for FriVerifier<F> {
+impl<F: FieldElement, H: Hasher> Display for FriVerifier<F, H> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
@@ -434,6 +706,76 @@ impl<F: FieldElement> Display for FriVerifier<F> {
     }
 }
 
+/// FRI layers and the Merkle trees their commitments are the roots of
+type CommittedLayers<F, H> = (Vec<FriLayer<F>>, Vec<MerkleTree<H>>);
+
+/// Positions of a layer of `len` values that queries at `positions` of the first layer
+/// read, sorted and deduplicated
+///
+/// A folded layer of `folded_len` values follows all but the last layer; there each
+/// query reads the whole coset its fold combines, which holds the query's own position.
+fn opened_positions(positions: &[usize], len: usize, folded_len: Option<usize>, folding_factor: usize) -> Vec<usize> {
+    let mut opened: Vec<usize> = match folded_len {
+        Some(folded_len) => positions
+            .iter()
+            .flat_map(|&position| (0..folding_factor).map(move |k| position % folded_len + k * folded_len))
+            .collect(),
+        None => positions.iter().map(|&position| position % len).collect(),
+    };
+    opened.sort_unstable();
+    opened.dedup();
+    opened
+}
+
+/// Merkle leaves of a layer's values at `positions`
+fn layer_leaves<F: FieldElement>(values: &[F], positions: impl IntoIterator<Item = usize>) -> Vec<Vec<u8>> {
+    positions.into_iter().map(|position| values[position].to_bytes().to_vec()).collect()
+}
+
+/// Fold the values `siblings[k] = f(x · ω^k)` of one coset into the next layer's value at
+/// `x^m`, for `m = siblings.len()` and `ω` an `m`-th root of unity
+///
+/// Splitting `f(x) = Σ_j x^j f_j(x^m)`, the folded polynomial is `Σ_j β^j f_j(y)` for the
+/// challenge `β`, and `x^j f_j(x^m) = (1/m) Σ_k ω^(-jk) f(x · ω^k)`.
+fn fold_coset<F: FieldElement>(siblings: &[F], x_inverse: F, omega_inverse: F, factor_inverse: F, challenge: F) -> F {
+    let scale = challenge * x_inverse;
+    let mut folded = F::zero();
+    let mut scale_power = F::one();
+    let mut root_power = F::one();
+    for _ in 0..siblings.len() {
+        // Σ_k ω^(-jk) f(x · ω^k) for j = root_power's exponent
+        let mut twiddle = F::one();
+        let mut term = F::zero();
+        for &value in siblings {
+            term += value * twiddle;
+            twiddle *= root_power;
+        }
+        folded += term * factor_inverse * scale_power;
+        scale_power *= scale;
+        root_power *= omega_inverse;
+    }
+    folded
+}
+
+/// Distinct prime factors of `n`
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut q = 2;
+    while q * q <= n {
+        if n.is_multiple_of(q) {
+            factors.push(q);
+            while n.is_multiple_of(q) {
+                n /= q;
+            }
+        }
+        q += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
 /// FRI-specific error types
 #[derive(Debug, thiserror::Error)]
 pub enum FriError {
@@ -464,6 +806,18 @@ pub enum FriError {
     /// Query verification failed
     #[error("Query verification failed")]
     QueryVerificationFailed,
+
+    /// Evaluation domain offset lies in the natural domain
+    #[error("FRI domain offset does not shift the domain off the trace domain")]
+    UnshiftedDomain,
+
+    /// Proving was cancelled through a [`CancellationToken`]
+    #[error("FRI proving was cancelled")]
+    Cancelled,
+
+    /// Layer commitment or opening failed
+    #[error("FRI layer commitment error: {0}")]
+    MerkleError(#[from] MerkleError),
 }
 
 #[cfg(test)]
@@ -515,4 +869,170 @@ mod tests {
         
         assert!(is_valid, "FRI proof should be valid");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_fri_layers_fold_at_query_positions() {
+        let prover: FriProver<PrimeField64> = FriProver::new(128);
+        let verifier: FriVerifier<PrimeField64> = FriVerifier::new(128);
+        let polynomial: Vec<PrimeField64> = (1..=16).map(PrimeField64::new).collect();
+        let proof = prover.prove(&polynomial).unwrap();
+        assert!(proof.layers.len() > 2);
+        assert_eq!(proof.final_polynomial.len(), 1);
+
+        let mut transcript = Transcript::new(FRI_PROTOCOL);
+        let mut challenges = Vec::new();
+        for (i, layer) in proof.layers.iter().enumerate() {
+            if i > 0 {
+                challenges.push(transcript.challenge(TranscriptLabel::FriFoldingChallenge));
+            }
+            transcript.absorb(TranscriptLabel::FriLayerCommitment, &layer.commitment);
+        }
+        let positions: Vec<usize> = proof
+            .queries
+            .iter()
+            .map(|query| proof.domain.elements().iter().position(|&point| point == query.point).unwrap())
+            .collect();
+        assert!(verifier.verify_folding(&proof, &challenges, &positions).unwrap());
+
+        // A folded value that is not the fold of its coset is caught at its position
+        let mut tampered = proof.clone();
+        let folded = &mut tampered.layers[1].polynomial;
+        let position = positions[0] % folded.len();
+        folded[position] += PrimeField64::one();
+        assert!(!verifier.verify_folding(&tampered, &challenges, &positions).unwrap());
+
+        // Responses for a different polynomial are rejected
+        let other: Vec<PrimeField64> = (2..=17).map(PrimeField64::new).collect();
+        assert!(!verifier.verify(&proof, &other).unwrap());
+    }
+
+    #[test]
+    fn test_fri_layer_openings() {
+        let prover: FriProver<PrimeField64> = FriProver::new(128);
+        let verifier: FriVerifier<PrimeField64> = FriVerifier::new(128);
+        let polynomial: Vec<PrimeField64> = (1..=16).map(PrimeField64::new).collect();
+        let proof = prover.prove(&polynomial).unwrap();
+        assert_eq!(proof.openings.len(), proof.layers.len());
+        assert!(verifier.verify(&proof, &polynomial).unwrap());
+
+        let positions: Vec<usize> = proof
+            .queries
+            .iter()
+            .map(|query| proof.domain.elements().iter().position(|&point| point == query.point).unwrap())
+            .collect();
+        assert!(verifier.verify_layer_openings(&proof, &positions));
+
+        // An opened value that differs from the committed one fails its multiproof
+        let mut tampered = proof.clone();
+        tampered.layers[0].polynomial[positions[0]] += PrimeField64::one();
+        assert!(!verifier.verify_layer_openings(&tampered, &positions));
+
+        // Every layer must be opened
+        let mut unopened = proof.clone();
+        unopened.openings.pop();
+        assert!(!verifier.verify(&unopened, &polynomial).unwrap());
+
+        // An opening must cover the queried positions
+        let mut partial = proof;
+        partial.openings[0] = MerkleTree::<Sha256Hasher>::from_leaves(&layer_leaves(
+            &partial.layers[0].polynomial,
+            0..partial.layers[0].polynomial.len(),
+        ))
+        .unwrap()
+        .generate_multi_proof(&[(positions[0] + 1) % partial.layers[0].polynomial.len()])
+        .unwrap();
+        assert!(!verifier.verify_layer_openings(&partial, &positions));
+    }
+
+    #[test]
+    fn test_fri_queries_avoid_trace_domain() {
+        let prover: FriProver<PrimeField64> = FriProver::new(128);
+        let verifier: FriVerifier<PrimeField64> = FriVerifier::new(128);
+        let polynomial: Vec<PrimeField64> = (1..=4).map(PrimeField64::new).collect();
+
+        let mut proof = prover.prove(&polynomial).expect("FRI proof generation should succeed");
+        assert_eq!(proof.domain.offset, PrimeField64::new(DEFAULT_DOMAIN_OFFSET));
+        assert!(proof.domain.is_shifted());
+
+        let natural = proof.domain.natural();
+        for query in &proof.queries {
+            assert!(proof.domain.contains(query.point));
+            assert!(!natural.contains(query.point));
+        }
+
+        // A query moved onto a trace domain point is rejected
+        proof.queries[0].point = natural.element(3);
+        assert!(!verifier.verify(&proof, &polynomial).unwrap());
+
+        // A domain that is not shifted is rejected outright
+        proof.domain = natural;
+        assert!(matches!(verifier.verify(&proof, &polynomial), Err(FriError::UnshiftedDomain)));
+    }
+
+    #[test]
+    fn test_fri_prover_rejects_unshifted_offset() {
+        let prover: FriProver<PrimeField64> =
+            FriProver::new(128).with_domain_offset(PrimeField64::new(1));
+        let polynomial: Vec<PrimeField64> = (1..=4).map(PrimeField64::new).collect();
+
+        assert!(matches!(prover.prove(&polynomial), Err(FriError::UnshiftedDomain)));
+    }
+
+    #[test]
+    fn test_fri_domain_round_trip() {
+        let domain = FriDomain::new(64, PrimeField64::new(5), PrimeField64::new(DEFAULT_DOMAIN_OFFSET));
+        let bytes = domain.to_bytes();
+
+        assert_eq!(bytes.len(), FriDomain::<PrimeField64>::ENCODED_LEN);
+        assert_eq!(FriDomain::from_bytes(&bytes), Some(domain));
+        assert_eq!(FriDomain::<PrimeField64>::from_bytes(&bytes[1..]), None);
+    }
+
+    #[test]
+    fn test_domain_membership_matches_elements() {
+        let prover: FriProver<PrimeField64> = FriProver::new(128);
+        let domain = prover.generate_evaluation_domain(4).unwrap();
+        assert_eq!(domain.generator.pow(domain.size as u64 / 2), PrimeField64::new(PrimeField64::MODULUS - 1));
+
+        let natural = domain.natural();
+        for point in domain.elements() {
+            assert!(domain.contains(point) && !natural.contains(point));
+        }
+        for point in natural.elements() {
+            assert!(natural.contains(point) && !domain.contains(point));
+        }
+        assert!(!domain.contains(PrimeField64::new(DEFAULT_DOMAIN_OFFSET + 1)));
+
+        // Only sizes dividing the multiplicative group order have roots of unity
+        assert!(matches!(FriProver::<PrimeField64>::find_generator(7), Err(FriError::InvalidDomainSize)));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_proof_matches_single_thread() {
+        let prover = FriProver::<PrimeField64>::new(128);
+        let polynomial: Vec<PrimeField64> = (1..=64).map(PrimeField64::new).collect();
+
+        let serial_pool = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+        let serial = serial_pool.install(|| prover.prove(&polynomial)).unwrap();
+        assert_eq!(prover.prove(&polynomial).unwrap(), serial);
+    }
+
+    #[test]
+    fn test_layer_commitments_use_hasher() {
+        use crate::proof::hasher::Blake3Hasher;
+
+        let prover = FriProver::<PrimeField64>::new(128).with_hasher::<Blake3Hasher>();
+        let polynomial: Vec<PrimeField64> = (1..=16).map(PrimeField64::new).collect();
+        let proof = prover.prove(&polynomial).unwrap();
+        for layer in &proof.layers {
+            let tree = MerkleTree::<Blake3Hasher>::from_leaves(&layer_leaves(&layer.polynomial, 0..layer.polynomial.len())).unwrap();
+            assert_eq!(layer.commitment, tree.root_hash().to_vec());
+        }
+
+        let blake3 = FriVerifier::<PrimeField64>::new(128).with_hasher::<Blake3Hasher>();
+        assert!(blake3.verify_queries(&proof, &mut Transcript::new(FRI_PROTOCOL)).unwrap());
+        let sha256 = FriVerifier::<PrimeField64>::new(128);
+        assert!(!sha256.verify_queries(&proof, &mut Transcript::new(FRI_PROTOCOL)).unwrap());
+    }
+}
@@ -0,0 +1,152 @@
+//! Commitment Hash Functions
+//!
+//! [`Hasher`] is the hash a [`MerkleTree`](crate::proof::merkle::MerkleTree), a trace
+//! commitment or a FRI layer commitment is built with. Commitments default to
+//! [`Sha256Hasher`]; the others match hashes used elsewhere in the stack:
+//!
+//! - [`Keccak256Hasher`]: the hash the EVM side checks commitments with
+//! - [`Blake3Hasher`]: the fastest option for native provers and verifiers
+//! - [`RescueHasher`]: Rescue Prime over the Goldilocks field, cheap to verify inside an AIR
+
+use crate::types::FieldElement;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+use std::fmt::Debug;
+use winter_crypto::hashers::Rp64_256;
+use winter_crypto::{Digest as _, ElementHasher, Hasher as WinterHasher};
+use winter_math::fields::f64::BaseElement;
+
+/// Hash function a commitment is built with
+pub trait Hasher: Debug + Clone + Copy + Default + Send + Sync + 'static {
+    /// Name recorded alongside commitments
+    const NAME: &'static str;
+
+    /// Digest of arbitrary bytes
+    fn hash(data: &[u8]) -> [u8; 32];
+
+    /// Parent digest of two child digests
+    #[must_use]
+    fn merge(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut data = [0u8; 64];
+        data[..32].copy_from_slice(left);
+        data[32..].copy_from_slice(right);
+        Self::hash(&data)
+    }
+
+    /// Digest of field elements by their byte encoding
+    fn hash_elements<F: FieldElement>(elements: &[F]) -> [u8; 32] {
+        let bytes: Vec<u8> = elements.iter().flat_map(FieldElement::to_bytes).collect();
+        Self::hash(&bytes)
+    }
+}
+
+/// SHA-256, the default commitment hash
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Sha256Hasher;
+
+impl Hasher for Sha256Hasher {
+    const NAME: &'static str = "sha256";
+
+    fn hash(data: &[u8]) -> [u8; 32] {
+        Sha256::digest(data).into()
+    }
+}
+
+/// Keccak-256 as used by Ethereum
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Keccak256Hasher;
+
+impl Hasher for Keccak256Hasher {
+    const NAME: &'static str = "keccak256";
+
+    fn hash(data: &[u8]) -> [u8; 32] {
+        Keccak256::digest(data).into()
+    }
+}
+
+/// BLAKE3 with a 256-bit output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Blake3Hasher;
+
+impl Hasher for Blake3Hasher {
+    const NAME: &'static str = "blake3";
+
+    fn hash(data: &[u8]) -> [u8; 32] {
+        *blake3::hash(data).as_bytes()
+    }
+}
+
+/// Rescue Prime (`Rp64_256`) over the Goldilocks field
+///
+/// Digests are four field elements. Merging two digests is a single permutation over
+/// their elements, and field elements are absorbed as Goldilocks elements rather than
+/// bytes, so the hash stays algebraic end to end.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RescueHasher;
+
+impl Hasher for RescueHasher {
+    const NAME: &'static str = "rescue-prime-64";
+
+    fn hash(data: &[u8]) -> [u8; 32] {
+        <Rp64_256 as WinterHasher>::hash(data).as_bytes()
+    }
+
+    fn merge(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        <Rp64_256 as WinterHasher>::merge(&[rescue_digest(left), rescue_digest(right)]).as_bytes()
+    }
+
+    fn hash_elements<F: FieldElement>(elements: &[F]) -> [u8; 32] {
+        let elements: Vec<BaseElement> = elements
+            .iter()
+            .flat_map(FieldElement::to_base_values)
+            .map(BaseElement::new)
+            .collect();
+        <Rp64_256 as ElementHasher>::hash_elements(&elements).as_bytes()
+    }
+}
+
+/// Digest of four little-endian field elements, reduced into the field
+fn rescue_digest(bytes: &[u8; 32]) -> <Rp64_256 as WinterHasher>::Digest {
+    let mut elements = [BaseElement::new(0); 4];
+    for (element, chunk) in elements.iter_mut().zip(bytes.chunks_exact(8)) {
+        *element = BaseElement::new(u64::from_le_bytes(chunk.try_into().unwrap()));
+    }
+    elements.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::field::PrimeField64;
+
+    #[test]
+    fn test_hashers_are_distinct() {
+        let digests = [
+            Sha256Hasher::hash(b"xfg"),
+            Keccak256Hasher::hash(b"xfg"),
+            Blake3Hasher::hash(b"xfg"),
+            RescueHasher::hash(b"xfg"),
+        ];
+        for (i, a) in digests.iter().enumerate() {
+            for b in &digests[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+        assert_eq!(Sha256Hasher::hash(b"xfg"), crate::utils::crypto::sha256(b"xfg"));
+    }
+
+    #[test]
+    fn test_rescue_hashes_elements_algebraically() {
+        let elements: Vec<PrimeField64> = (1..=8).map(PrimeField64::new).collect();
+        let base: Vec<BaseElement> = (1..=8).map(BaseElement::new).collect();
+        assert_eq!(
+            RescueHasher::hash_elements(&elements),
+            <Rp64_256 as ElementHasher>::hash_elements(&base).as_bytes()
+        );
+
+        let left = RescueHasher::hash(b"left");
+        let right = RescueHasher::hash(b"right");
+        assert_eq!(RescueHasher::merge(&left, &right), RescueHasher::merge(&left, &right));
+        assert_ne!(RescueHasher::merge(&left, &right), RescueHasher::merge(&right, &left));
+    }
+}
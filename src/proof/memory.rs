@@ -0,0 +1,170 @@
+//! Prover Memory Budget
+//!
+//! This module lets operators cap the memory used by the burn & mint prover so it can run
+//! on small machines (2–4 GB). Based on the configured cap, the prover picks between
+//! building the extended trace and its commitment fully in memory or processing them in
+//! chunks: the extension is spilled to a temporary file one coset at a time, and only the
+//! Merkle tree over chunk roots is kept (see
+//! [`crate::statements::ConstantColumnTraceLde::with_strategy`]).
+//!
+//! ## Features
+//!
+//! - **Memory Estimation**: Estimates peak memory of the in-memory LDE and Merkle construction
+//! - **Strategy Selection**: Chooses in-memory or chunked processing from the budget
+//! - **Operator Visibility**: The prover reports the selected strategy
+//!   ([`crate::burn_mint_prover::XfgBurnMintProver::lde_strategy`])
+//!
+//! The budget covers the extended trace and its commitment, the part that grows with the
+//! blowup factor. The trace, its polynomials, the constraint evaluations and the FRI
+//! layers are held in memory either way.
+
+use std::fmt::{Display, Formatter};
+
+/// Bytes per base field element
+const ELEMENT_SIZE: usize = 8;
+
+/// Bytes per Merkle node hash
+const NODE_SIZE: usize = 32;
+
+/// Smallest chunk the chunked strategy will use (rows)
+const MIN_CHUNK_ROWS: usize = 16;
+
+/// Strategy for LDE and Merkle construction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LdeStrategy {
+    /// Keep the whole extended trace and all tree levels in memory
+    InMemory,
+    /// Spill the extended trace to disk and process it in chunks of `chunk_rows` rows
+    Chunked {
+        /// Rows per chunk, a power of two
+        chunk_rows: usize,
+    },
+}
+
+impl Display for LdeStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LdeStrategy::InMemory => write!(f, "in-memory"),
+            LdeStrategy::Chunked { chunk_rows } => write!(f, "chunked ({chunk_rows} rows per chunk)"),
+        }
+    }
+}
+
+/// Memory budget for proof generation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProverMemoryBudget {
+    /// Maximum memory the prover may use for LDE and Merkle construction (bytes)
+    pub max_bytes: usize,
+}
+
+impl ProverMemoryBudget {
+    /// Create a budget from a byte cap
+    #[must_use]
+    pub fn new(max_bytes: usize) -> Self {
+        Self { max_bytes }
+    }
+
+    /// Create a budget from a cap in megabytes
+    #[must_use]
+    pub fn from_megabytes(megabytes: usize) -> Self {
+        Self::new(megabytes.saturating_mul(1024 * 1024))
+    }
+
+    /// Create an effectively unlimited budget
+    #[must_use]
+    pub fn unlimited() -> Self {
+        Self::new(usize::MAX)
+    }
+
+    /// Estimate peak memory of in-memory LDE and Merkle construction (bytes)
+    #[must_use]
+    pub fn estimate_in_memory_bytes(
+        num_registers: usize,
+        trace_length: usize,
+        blowup_factor: usize,
+    ) -> usize {
+        let lde_rows = trace_length.saturating_mul(blowup_factor.max(1));
+        let lde_bytes = lde_rows
+            .saturating_mul(num_registers)
+            .saturating_mul(ELEMENT_SIZE);
+        // A binary tree over the LDE rows holds roughly 2x as many nodes as leaves
+        let merkle_bytes = lde_rows.saturating_mul(2).saturating_mul(NODE_SIZE);
+
+        lde_bytes.saturating_add(merkle_bytes)
+    }
+
+    /// Select a strategy for the given trace shape
+    #[must_use]
+    pub fn select_strategy(
+        &self,
+        num_registers: usize,
+        trace_length: usize,
+        blowup_factor: usize,
+    ) -> LdeStrategy {
+        let estimate = Self::estimate_in_memory_bytes(num_registers, trace_length, blowup_factor);
+        // A chunked commitment needs at least two chunks
+        let max_chunk_rows = trace_length.saturating_mul(blowup_factor.max(1)) / 2;
+        if estimate <= self.max_bytes || max_chunk_rows < MIN_CHUNK_ROWS {
+            return LdeStrategy::InMemory;
+        }
+
+        // Size chunks so that one chunk of extended rows and its subtree fit in the budget
+        let row_bytes = num_registers
+            .saturating_mul(ELEMENT_SIZE)
+            .saturating_add(NODE_SIZE.saturating_mul(2))
+            .max(1);
+        let fitting_rows = (self.max_bytes / row_bytes).max(MIN_CHUNK_ROWS);
+        let chunk_rows = (1usize << fitting_rows.ilog2()).min(max_chunk_rows);
+
+        LdeStrategy::Chunked { chunk_rows }
+    }
+}
+
+impl Default for ProverMemoryBudget {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+impl Display for ProverMemoryBudget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.max_bytes == usize::MAX {
+            write!(f, "unlimited")
+        } else {
+            write!(f, "{} MB", self.max_bytes / (1024 * 1024))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_scales_with_blowup() {
+        let small = ProverMemoryBudget::estimate_in_memory_bytes(7, 64, 8);
+        let large = ProverMemoryBudget::estimate_in_memory_bytes(7, 64, 16);
+        assert_eq!(large, small * 2);
+    }
+
+    #[test]
+    fn test_strategy_selection() {
+        let unlimited = ProverMemoryBudget::unlimited();
+        assert_eq!(unlimited.select_strategy(7, 1 << 20, 16), LdeStrategy::InMemory);
+
+        let tight = ProverMemoryBudget::new(64 * 1024);
+        match tight.select_strategy(7, 1 << 20, 16) {
+            LdeStrategy::Chunked { chunk_rows } => {
+                assert!(chunk_rows >= MIN_CHUNK_ROWS);
+                assert!(chunk_rows * 7 * ELEMENT_SIZE <= 64 * 1024);
+            }
+            LdeStrategy::InMemory => panic!("Tight budget should select chunked strategy"),
+        }
+    }
+
+    #[test]
+    fn test_budget_display() {
+        assert_eq!(ProverMemoryBudget::from_megabytes(2048).to_string(), "2048 MB");
+        assert_eq!(ProverMemoryBudget::default().to_string(), "unlimited");
+    }
+}
@@ -5,15 +5,23 @@
 //! 
 //! ## Features
 //! 
-//! - **Cryptographic Hashing**: SHA256-based hash functions for security
+//! - **Cryptographic Hashing**: Any [`Hasher`], SHA-256 by default
 //! - **Efficient Tree Construction**: Optimized tree building algorithms
 //! - **Inclusion Proofs**: Secure proof generation and verification
 //! - **Batch Operations**: Efficient batch proof generation
+//! - **Multiproofs**: One [`MultiProof`] opens several leaves, sharing the nodes their paths have in common
+//! - **Incremental Trees**: [`IncrementalMerkleTree`] appends and updates leaves in `O(log n)`
+//!   hashes, and a [`MerkleFrontier`] persists what appending needs in `O(log n)` space
 //! - **Memory Optimization**: Minimal memory footprint for large trees
+//! - **Parallel Construction**: Leaves and levels are hashed on the rayon thread pool with the `parallel` feature
 
+use crate::proof::hasher::{Hasher, Sha256Hasher};
 use crate::types::FieldElement;
 use std::fmt::{Display, Formatter};
-use sha2::{Sha256, Digest};
+use std::marker::PhantomData;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 /// Merkle tree node
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -33,27 +41,17 @@ impl MerkleNode {
     }
 
     /// Create a leaf node
-    pub fn leaf(data: &[u8]) -> Self {
-        let hash = Self::hash_data(data);
-        Self::new(hash, 0, 0)
+    #[must_use]
+    pub fn leaf<H: Hasher>(data: &[u8]) -> Self {
+        Self::new(H::hash(data), 0, 0)
     }
 
     /// Create an internal node from two children
-    pub fn internal(left: &MerkleNode, right: &MerkleNode) -> Self {
-        let mut hasher = Sha256::new();
-        hasher.update(&left.hash);
-        hasher.update(&right.hash);
-        let hash = hasher.finalize().into();
-        
+    #[must_use]
+    pub fn internal<H: Hasher>(left: &MerkleNode, right: &MerkleNode) -> Self {
+        let hash = H::merge(&left.hash, &right.hash);
         Self::new(hash, left.level + 1, left.index / 2)
     }
-
-    /// Hash data using SHA256
-    fn hash_data(data: &[u8]) -> [u8; 32] {
-        let mut hasher = Sha256::new();
-        hasher.update(data);
-        hasher.finalize().into()
-    }
 }
 
 impl Display for MerkleNode {
@@ -66,9 +64,9 @@ impl Display for MerkleNode {
     }
 }
 
-/// Merkle tree implementation
+/// Merkle tree implementation, hashed with `H`
 #[derive(Debug, Clone)]
-pub struct MerkleTree {
+pub struct MerkleTree<H: Hasher = Sha256Hasher> {
     /// Tree root
     pub root: MerkleNode,
     /// Tree depth
@@ -77,11 +75,24 @@ pub struct MerkleTree {
     pub num_leaves: usize,
     /// Tree nodes (for efficient proof generation)
     nodes: Vec<Vec<MerkleNode>>,
+    /// Hash function of the tree
+    _hasher: PhantomData<H>,
 }
 
 impl MerkleTree {
-    /// Create a new Merkle tree from leaf data
+    /// Create a new SHA-256 Merkle tree from leaf data
     pub fn new(leaves: &[Vec<u8>]) -> Result<Self, MerkleError> {
+        Self::from_leaves(leaves)
+    }
+}
+
+impl<H: Hasher> MerkleTree<H> {
+    /// Create a new Merkle tree hashed with `H` from leaf data
+    ///
+    /// # Errors
+    ///
+    /// [`MerkleError::EmptyLeaves`] if `leaves` is empty
+    pub fn from_leaves(leaves: &[Vec<u8>]) -> Result<Self, MerkleError> {
         if leaves.is_empty() {
             return Err(MerkleError::EmptyLeaves);
         }
@@ -91,11 +102,15 @@ impl MerkleTree {
         let mut nodes = Vec::with_capacity(depth + 1);
 
         // Create leaf nodes
-        let mut current_level: Vec<MerkleNode> = leaves
-            .iter()
+        #[cfg(feature = "parallel")]
+        let leaf_data = leaves.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let leaf_data = leaves.iter();
+
+        let mut current_level: Vec<MerkleNode> = leaf_data
             .enumerate()
             .map(|(i, data)| {
-                let mut node = MerkleNode::leaf(data);
+                let mut node = MerkleNode::leaf::<H>(data);
                 node.index = i;
                 node
             })
@@ -117,6 +132,7 @@ impl MerkleTree {
             depth,
             num_leaves,
             nodes,
+            _hasher: PhantomData,
         })
     }
 
@@ -139,22 +155,24 @@ impl MerkleTree {
 
     /// Build a level of the tree from the previous level
     fn build_level(prev_level: &[MerkleNode], level: usize) -> Result<Vec<MerkleNode>, MerkleError> {
-        let mut current_level = Vec::new();
-        
-        for i in (0..prev_level.len()).step_by(2) {
-            let left = &prev_level[i];
-            let right = if i + 1 < prev_level.len() {
-                &prev_level[i + 1]
-            } else {
+        #[cfg(feature = "parallel")]
+        let pairs = prev_level.par_chunks(2);
+        #[cfg(not(feature = "parallel"))]
+        let pairs = prev_level.chunks(2);
+
+        let current_level = pairs
+            .enumerate()
+            .map(|(i, pair)| {
                 // Duplicate the last node if odd number
-                &prev_level[i]
-            };
-            
-            let mut node = MerkleNode::internal(left, right);
-            node.index = i / 2;
-            current_level.push(node);
-        }
-        
+                let left = &pair[0];
+                let right = pair.get(1).unwrap_or(left);
+
+                let mut node = MerkleNode::internal::<H>(left, right);
+                node.index = i;
+                node
+            })
+            .collect();
+
         Ok(current_level)
     }
 
@@ -180,10 +198,10 @@ impl MerkleTree {
                 current_index - 1
             };
 
-            if sibling_index < level_nodes.len() {
-                proof.siblings.push(level_nodes[sibling_index].hash);
-                proof.path.push(current_index % 2 == 0);
-            }
+            // The last node of an odd level was paired with itself
+            let sibling = level_nodes.get(sibling_index).unwrap_or(&level_nodes[current_index]);
+            proof.siblings.push(sibling.hash);
+            proof.path.push(current_index.is_multiple_of(2));
 
             current_index /= 2;
         }
@@ -209,32 +227,66 @@ impl MerkleTree {
             return Ok(false);
         }
 
-        // Start with leaf hash
-        let mut current_hash = MerkleNode::hash_data(leaf_data);
-        
-        // Follow the proof path
-        for (i, &is_left) in proof.path.iter().enumerate() {
-            if i >= proof.siblings.len() {
-                return Ok(false);
-            }
-            
-            let sibling_hash = proof.siblings[i];
-            
-            // Combine with sibling based on path
-            let mut hasher = Sha256::new();
-            if is_left {
-                hasher.update(&current_hash);
-                hasher.update(&sibling_hash);
-            } else {
-                hasher.update(&sibling_hash);
-                hasher.update(&current_hash);
+        Ok(proof.verify_with::<H>(leaf_data, self.root.hash))
+    }
+
+    /// Generate one proof opening every leaf in `leaf_indices`
+    ///
+    /// Indices are sorted and deduplicated. Siblings that an opened leaf or an earlier
+    /// level already determines are left out, so paths that meet share their upper nodes.
+    ///
+    /// # Errors
+    ///
+    /// [`MerkleError::EmptyLeafIndices`] if `leaf_indices` is empty, or
+    /// [`MerkleError::InvalidLeafIndex`] if one is outside the tree
+    pub fn generate_multi_proof(&self, leaf_indices: &[usize]) -> Result<MultiProof, MerkleError> {
+        let mut indices = leaf_indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+        match indices.last() {
+            None => return Err(MerkleError::EmptyLeafIndices),
+            Some(&last) if last >= self.num_leaves => return Err(MerkleError::InvalidLeafIndex(last)),
+            Some(_) => {}
+        }
+
+        let mut nodes = Vec::new();
+        let mut current = indices.clone();
+        for level_nodes in &self.nodes[..self.depth] {
+            let mut next = Vec::with_capacity(current.len());
+            let mut i = 0;
+            while i < current.len() {
+                let index = current[i];
+                let sibling = index ^ 1;
+                if current.get(i + 1) == Some(&sibling) {
+                    i += 1;
+                } else if sibling < level_nodes.len() {
+                    nodes.push(level_nodes[sibling].hash);
+                }
+                next.push(index / 2);
+                i += 1;
             }
-            
-            current_hash = hasher.finalize().into();
+            current = next;
         }
-        
-        // Check if we reach the root
-        Ok(current_hash == self.root.hash)
+
+        Ok(MultiProof {
+            leaf_indices: indices,
+            num_leaves: self.num_leaves,
+            nodes,
+        })
+    }
+
+    /// Verify a multiproof; `leaves` hold the data of each opened leaf in index order
+    ///
+    /// # Errors
+    ///
+    /// None at present, as for [`Self::verify_proof`]; a proof for a tree of another size
+    /// returns `Ok(false)`
+    pub fn verify_multi_proof(&self, leaves: &[Vec<u8>], proof: &MultiProof) -> Result<bool, MerkleError> {
+        if proof.num_leaves != self.num_leaves {
+            return Ok(false);
+        }
+
+        Ok(proof.verify_with::<H>(leaves, self.root.hash))
     }
 
     /// Get root hash
@@ -252,12 +304,12 @@ impl MerkleTree {
     }
 }
 
-impl Display for MerkleTree {
+impl<H: Hasher> Display for MerkleTree<H> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "MerkleTree(depth={}, leaves={}, root={:02x?})",
-            self.depth, self.num_leaves, &self.root.hash[..8]
+            "MerkleTree(hash={}, depth={}, leaves={}, root={:02x?})",
+            H::NAME, self.depth, self.num_leaves, &self.root.hash[..8]
         )
     }
 }
@@ -289,9 +341,15 @@ impl MerkleProof {
         self.path.push(is_left);
     }
 
-    /// Verify proof against a root hash
+    /// Verify proof against a SHA-256 root hash
     pub fn verify(&self, leaf_data: &[u8], root_hash: [u8; 32]) -> bool {
-        let mut current_hash = MerkleNode::hash_data(leaf_data);
+        self.verify_with::<Sha256Hasher>(leaf_data, root_hash)
+    }
+
+    /// Verify proof against a root hash of a tree hashed with `H`
+    #[must_use]
+    pub fn verify_with<H: Hasher>(&self, leaf_data: &[u8], root_hash: [u8; 32]) -> bool {
+        let mut current_hash = H::hash(leaf_data);
         
         for (i, &is_left) in self.path.iter().enumerate() {
             if i >= self.siblings.len() {
@@ -299,17 +357,11 @@ impl MerkleProof {
             }
             
             let sibling_hash = self.siblings[i];
-            
-            let mut hasher = Sha256::new();
-            if is_left {
-                hasher.update(&current_hash);
-                hasher.update(&sibling_hash);
+            current_hash = if is_left {
+                H::merge(&current_hash, &sibling_hash)
             } else {
-                hasher.update(&sibling_hash);
-                hasher.update(&current_hash);
-            }
-            
-            current_hash = hasher.finalize().into();
+                H::merge(&sibling_hash, &current_hash)
+            };
         }
         
         current_hash == root_hash
@@ -331,6 +383,402 @@ impl Display for MerkleProof {
     }
 }
 
+/// Proof opening several leaves of one tree
+///
+/// `nodes` holds, level by level from the leaves up and in index order within a level,
+/// the siblings the opened leaves do not determine. The last node of an odd level is
+/// paired with itself, as in [`MerkleTree`], and needs no sibling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiProof {
+    /// Opened leaf indices, strictly increasing
+    pub leaf_indices: Vec<usize>,
+    /// Number of leaves in the tree
+    pub num_leaves: usize,
+    /// Sibling hashes not derivable from the opened leaves
+    pub nodes: Vec<[u8; 32]>,
+}
+
+impl MultiProof {
+    /// Verify the opened leaves against a SHA-256 root hash
+    #[must_use]
+    pub fn verify(&self, leaves: &[Vec<u8>], root_hash: [u8; 32]) -> bool {
+        self.verify_with::<Sha256Hasher>(leaves, root_hash)
+    }
+
+    /// Verify the opened leaves against a root hash of a tree hashed with `H`
+    ///
+    /// `leaves` hold the data of each leaf in `leaf_indices`, in the same order.
+    #[must_use]
+    pub fn verify_with<H: Hasher>(&self, leaves: &[Vec<u8>], root_hash: [u8; 32]) -> bool {
+        if leaves.is_empty() || leaves.len() != self.leaf_indices.len() {
+            return false;
+        }
+        let increasing = self.leaf_indices.windows(2).all(|pair| pair[0] < pair[1]);
+        if !increasing || self.leaf_indices[self.leaf_indices.len() - 1] >= self.num_leaves {
+            return false;
+        }
+
+        let mut current: Vec<(usize, [u8; 32])> = self
+            .leaf_indices
+            .iter()
+            .zip(leaves)
+            .map(|(&index, data)| (index, H::hash(data)))
+            .collect();
+        let mut nodes = self.nodes.iter();
+        let mut width = self.num_leaves;
+
+        while width > 1 {
+            let mut next = Vec::with_capacity(current.len());
+            let mut i = 0;
+            while i < current.len() {
+                let (index, hash) = current[i];
+                let sibling_index = index ^ 1;
+                let sibling = match current.get(i + 1) {
+                    Some(&(next_index, next_hash)) if next_index == sibling_index => {
+                        i += 1;
+                        next_hash
+                    }
+                    _ if sibling_index >= width => hash,
+                    _ => match nodes.next() {
+                        Some(&node) => node,
+                        None => return false,
+                    },
+                };
+                let parent = if index % 2 == 0 {
+                    H::merge(&hash, &sibling)
+                } else {
+                    H::merge(&sibling, &hash)
+                };
+                next.push((index / 2, parent));
+                i += 1;
+            }
+            current = next;
+            width = width.div_ceil(2);
+        }
+
+        nodes.next().is_none() && current[0].1 == root_hash
+    }
+
+    /// Get proof size in bytes
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.nodes.len() * 32 + (self.leaf_indices.len() + 1) * std::mem::size_of::<usize>()
+    }
+}
+
+impl Display for MultiProof {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MultiProof(leaves={}, nodes={})",
+            self.leaf_indices.len(), self.nodes.len()
+        )
+    }
+}
+
+/// Merkle tree maintained as leaves are appended and updated
+///
+/// Roots and proofs equal those of a [`MerkleTree`] built from the same leaves. Only
+/// nodes whose subtrees are complete are stored; the right edge of the tree, where odd
+/// levels pair their last node with itself, is recomputed in `O(log n)` hashes.
+#[derive(Debug, Clone)]
+pub struct IncrementalMerkleTree<H: Hasher = Sha256Hasher> {
+    /// Complete nodes by level, leaf hashes first
+    levels: Vec<Vec<[u8; 32]>>,
+    /// Hash function of the tree
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Hasher> Default for IncrementalMerkleTree<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: Hasher> IncrementalMerkleTree<H> {
+    /// Create an empty tree
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            levels: vec![Vec::new()],
+            _hasher: PhantomData,
+        }
+    }
+
+    /// Create a tree holding `leaves`
+    #[must_use]
+    pub fn from_leaves(leaves: &[Vec<u8>]) -> Self {
+        let mut tree = Self::new();
+        for leaf in leaves {
+            tree.append(leaf);
+        }
+        tree
+    }
+
+    /// Number of leaves
+    #[must_use]
+    pub fn num_leaves(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Whether the tree has no leaves
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// Tree depth, as in [`MerkleTree`]
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        MerkleTree::<H>::calculate_depth(self.num_leaves())
+    }
+
+    /// Append a leaf, returning its index
+    pub fn append(&mut self, leaf_data: &[u8]) -> usize {
+        let index = self.num_leaves();
+        let mut node = H::hash(leaf_data);
+        let mut level = 0;
+        loop {
+            self.levels[level].push(node);
+            let nodes = &self.levels[level];
+            if nodes.len() % 2 == 1 {
+                break;
+            }
+            node = H::merge(&nodes[nodes.len() - 2], &nodes[nodes.len() - 1]);
+            level += 1;
+            if level == self.levels.len() {
+                self.levels.push(Vec::new());
+            }
+        }
+        index
+    }
+
+    /// Replace the leaf at `leaf_index`
+    ///
+    /// # Errors
+    ///
+    /// [`MerkleError::InvalidLeafIndex`] if `leaf_index` is out of range
+    pub fn update(&mut self, leaf_index: usize, leaf_data: &[u8]) -> Result<(), MerkleError> {
+        if leaf_index >= self.num_leaves() {
+            return Err(MerkleError::InvalidLeafIndex(leaf_index));
+        }
+
+        self.levels[0][leaf_index] = H::hash(leaf_data);
+        let mut index = leaf_index;
+        for level in 1..self.levels.len() {
+            index /= 2;
+            if index >= self.levels[level].len() {
+                // The rest of the path lies on the recomputed right edge
+                break;
+            }
+            let children = &self.levels[level - 1];
+            self.levels[level][index] = H::merge(&children[2 * index], &children[2 * index + 1]);
+        }
+        Ok(())
+    }
+
+    /// Get root hash
+    ///
+    /// # Errors
+    ///
+    /// [`MerkleError::EmptyLeaves`] if the tree has no leaves
+    pub fn root_hash(&self) -> Result<[u8; 32], MerkleError> {
+        self.right_edge().last().copied().ok_or(MerkleError::EmptyLeaves)
+    }
+
+    /// Generate inclusion proof for a leaf, verifiable like a [`MerkleTree`] proof
+    ///
+    /// # Errors
+    ///
+    /// [`MerkleError::InvalidLeafIndex`] if `leaf_index` is out of range
+    pub fn generate_proof(&self, leaf_index: usize) -> Result<MerkleProof, MerkleError> {
+        if leaf_index >= self.num_leaves() {
+            return Err(MerkleError::InvalidLeafIndex(leaf_index));
+        }
+
+        let edge = self.right_edge();
+        let mut proof = MerkleProof::new(leaf_index);
+        let mut index = leaf_index;
+        let mut width = self.num_leaves();
+        for (level, &last) in edge.iter().enumerate().take(edge.len() - 1) {
+            let node = |i: usize| self.levels[level].get(i).copied().unwrap_or(last);
+            let sibling = index ^ 1;
+            proof.add_sibling(if sibling < width { node(sibling) } else { node(index) }, index.is_multiple_of(2));
+            index /= 2;
+            width = width.div_ceil(2);
+        }
+        Ok(proof)
+    }
+
+    /// Frontier of the tree, enough to keep appending and computing roots
+    #[must_use]
+    pub fn frontier(&self) -> MerkleFrontier<H> {
+        if self.is_empty() {
+            return MerkleFrontier::new();
+        }
+        MerkleFrontier {
+            num_leaves: self.num_leaves(),
+            nodes: self.levels.iter().map(|nodes| (nodes.len() % 2 == 1).then(|| nodes[nodes.len() - 1])).collect(),
+            _hasher: PhantomData,
+        }
+    }
+
+    /// Last node of every level, leaves first and root last; empty for an empty tree
+    fn right_edge(&self) -> Vec<[u8; 32]> {
+        let mut width = self.num_leaves();
+        let mut edge = Vec::new();
+        // Right-edge node of the current level when its subtree is incomplete
+        let mut carry = None;
+        for level in 0.. {
+            if width == 0 {
+                break;
+            }
+            // Levels above the highest complete node are never stored
+            let nodes = self.levels.get(level).map_or(&[][..], Vec::as_slice);
+            let last = carry.unwrap_or_else(|| nodes[width - 1]);
+            edge.push(last);
+            if width == 1 {
+                break;
+            }
+            carry = if width.is_multiple_of(2) {
+                carry.map(|node| H::merge(&nodes[width - 2], &node))
+            } else {
+                Some(H::merge(&last, &last))
+            };
+            width = width.div_ceil(2);
+        }
+        edge
+    }
+}
+
+/// Right-edge nodes of a Merkle tree, enough to append leaves and compute its root
+///
+/// Holds one complete subtree root per set bit of the leaf count, so it stays
+/// `O(log n)` however many leaves are appended. Roots equal those of a [`MerkleTree`]
+/// built from the same leaves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleFrontier<H: Hasher = Sha256Hasher> {
+    /// Number of leaves appended
+    num_leaves: usize,
+    /// Root of the complete subtree at each level whose bit of `num_leaves` is set
+    nodes: Vec<Option<[u8; 32]>>,
+    /// Hash function of the tree
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Hasher> Default for MerkleFrontier<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: Hasher> MerkleFrontier<H> {
+    /// Create the frontier of an empty tree
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            num_leaves: 0,
+            nodes: Vec::new(),
+            _hasher: PhantomData,
+        }
+    }
+
+    /// Number of leaves appended
+    #[must_use]
+    pub fn num_leaves(&self) -> usize {
+        self.num_leaves
+    }
+
+    /// Append a leaf, returning its index
+    pub fn append(&mut self, leaf_data: &[u8]) -> usize {
+        let index = self.num_leaves;
+        let mut node = H::hash(leaf_data);
+        let mut level = 0;
+        while let Some(left) = self.nodes.get_mut(level).and_then(Option::take) {
+            node = H::merge(&left, &node);
+            level += 1;
+        }
+        if level == self.nodes.len() {
+            self.nodes.push(None);
+        }
+        self.nodes[level] = Some(node);
+        self.num_leaves += 1;
+        index
+    }
+
+    /// Get root hash
+    ///
+    /// # Errors
+    ///
+    /// [`MerkleError::EmptyLeaves`] if no leaf was appended
+    pub fn root_hash(&self) -> Result<[u8; 32], MerkleError> {
+        let (top_node, lower) = self.nodes.split_last().ok_or(MerkleError::EmptyLeaves)?;
+        let top_node = top_node.ok_or(MerkleError::EmptyLeaves)?;
+        let mut carry: Option<[u8; 32]> = None;
+        for node in lower {
+            carry = match (node, carry) {
+                (Some(left), Some(right)) => Some(H::merge(left, &right)),
+                (Some(node), None) => Some(H::merge(node, node)),
+                (None, Some(node)) => Some(H::merge(&node, &node)),
+                (None, None) => None,
+            };
+        }
+        Ok(match carry {
+            Some(right) => H::merge(&top_node, &right),
+            None => top_node,
+        })
+    }
+
+    /// Serialize as the leaf count followed by the frontier nodes, lowest level first
+    ///
+    /// All integers are little-endian.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8 + 32 * self.nodes.len());
+        bytes.extend_from_slice(&(self.num_leaves as u64).to_le_bytes());
+        for node in self.nodes.iter().flatten() {
+            bytes.extend_from_slice(node);
+        }
+        bytes
+    }
+
+    /// Deserialize a frontier written by [`Self::to_bytes`]
+    ///
+    /// # Errors
+    ///
+    /// [`MerkleError::InvalidFrontier`] if the leaf count is missing or does not
+    /// match the number of nodes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MerkleError> {
+        let (count, rest) = bytes
+            .split_first_chunk::<8>()
+            .ok_or_else(|| MerkleError::InvalidFrontier("missing leaf count".to_string()))?;
+        let num_leaves = usize::try_from(u64::from_le_bytes(*count))
+            .map_err(|_| MerkleError::InvalidFrontier("leaf count does not fit in memory".to_string()))?;
+        if rest.len() != 32 * num_leaves.count_ones() as usize {
+            return Err(MerkleError::InvalidFrontier(format!(
+                "{} leaves need {} nodes, found {} bytes",
+                num_leaves,
+                num_leaves.count_ones(),
+                rest.len()
+            )));
+        }
+
+        let mut chunks = rest.as_chunks::<32>().0.iter();
+        let levels = (usize::BITS - num_leaves.leading_zeros()) as usize;
+        let nodes = (0..levels)
+            .map(|level| {
+                // Lengths are checked above, so a set bit always has its node
+                (num_leaves >> level & 1 == 1).then(|| chunks.next().copied()).flatten()
+            })
+            .collect();
+        Ok(Self {
+            num_leaves,
+            nodes,
+            _hasher: PhantomData,
+        })
+    }
+}
+
 /// Merkle tree statistics
 #[derive(Debug, Clone)]
 pub struct MerkleStats {
@@ -353,7 +801,7 @@ impl Display for MerkleStats {
 }
 
 /// Merkle tree error types
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum MerkleError {
     /// Empty leaves
     #[error("Empty leaves")]
@@ -363,10 +811,18 @@ pub enum MerkleError {
     #[error("Invalid leaf index: {0}")]
     InvalidLeafIndex(usize),
 
+    /// A multiproof was requested for no leaves
+    #[error("No leaf indices to open")]
+    EmptyLeafIndices,
+
     /// Invalid proof
     #[error("Invalid proof")]
     InvalidProof,
 
+    /// Serialized frontier is malformed
+    #[error("Invalid Merkle frontier: {0}")]
+    InvalidFrontier(String),
+
     /// Hash computation error
     #[error("Hash computation error: {0}")]
     HashError(String),
@@ -376,16 +832,14 @@ pub enum MerkleError {
     ConstructionError(String),
 }
 
-/// Generate Merkle commitment for field elements
+/// Generate SHA-256 commitment for field elements
 pub fn generate_commitment<F: FieldElement>(data: &[F]) -> Vec<u8> {
-    let mut hasher = Sha256::new();
-    
-    for element in data {
-        let bytes = element.to_bytes();
-        hasher.update(&bytes);
-    }
-    
-    hasher.finalize().to_vec()
+    generate_commitment_with::<Sha256Hasher, F>(data)
+}
+
+/// Generate commitment for field elements hashed with `H`
+pub fn generate_commitment_with<H: Hasher, F: FieldElement>(data: &[F]) -> Vec<u8> {
+    H::hash_elements(data).to_vec()
 }
 
 /// Verify Merkle inclusion proof
@@ -482,4 +936,130 @@ mod tests {
         assert!(proofs.is_ok());
         assert_eq!(proofs.unwrap().len(), 2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_multi_proof() {
+        let leaves: Vec<Vec<u8>> = (0..13).map(|i| format!("leaf{}", i).into_bytes()).collect();
+        let tree = MerkleTree::new(&leaves).unwrap();
+        let opened = |indices: &[usize]| indices.iter().map(|&i| leaves[i].clone()).collect::<Vec<_>>();
+
+        for indices in [vec![0], vec![12], vec![3, 4], vec![0, 1, 2, 3], vec![1, 6, 7, 11, 12]] {
+            let proof = tree.generate_multi_proof(&indices).unwrap();
+            assert!(tree.verify_multi_proof(&opened(&indices), &proof).unwrap(), "{:?}", indices);
+        }
+
+        // Unsorted and repeated indices open the same leaves; neighbours share their path
+        let proof = tree.generate_multi_proof(&[7, 2, 6, 2]).unwrap();
+        assert_eq!(proof.leaf_indices, vec![2, 6, 7]);
+        let singles: usize = [2, 6, 7].iter().map(|&i| tree.generate_proof(i).unwrap().siblings.len()).sum();
+        assert!(proof.nodes.len() < singles);
+
+        let mut wrong = opened(&[2, 6, 7]);
+        wrong[1] = b"forged".to_vec();
+        assert!(!proof.verify(&wrong, tree.root_hash()));
+        assert!(!proof.verify(&opened(&[2, 6]), tree.root_hash()));
+        let mut truncated = proof.clone();
+        truncated.nodes.pop();
+        assert!(!truncated.verify(&opened(&[2, 6, 7]), tree.root_hash()));
+
+        assert_eq!(tree.generate_multi_proof(&[]), Err(MerkleError::EmptyLeafIndices));
+        assert_eq!(tree.generate_multi_proof(&[1, 13]), Err(MerkleError::InvalidLeafIndex(13)));
+    }
+
+    #[test]
+    fn test_multi_proof_shrinks_query_openings() {
+        use crate::proof::hasher::Keccak256Hasher;
+
+        let leaves: Vec<Vec<u8>> = (0..1024u32).map(|i| i.to_le_bytes().to_vec()).collect();
+        let tree = MerkleTree::<Keccak256Hasher>::from_leaves(&leaves).unwrap();
+        let indices: Vec<usize> = (0..32).map(|i| (i * 193 + 11) % 1024).collect();
+
+        let proof = tree.generate_multi_proof(&indices).unwrap();
+        let opened: Vec<Vec<u8>> = proof.leaf_indices.iter().map(|&i| leaves[i].clone()).collect();
+        assert!(proof.verify_with::<Keccak256Hasher>(&opened, tree.root_hash()));
+
+        let singles: usize = tree.generate_batch_proofs(&indices).unwrap().iter().map(MerkleProof::size).sum();
+        assert!(proof.size() * 10 < singles * 7, "{} vs {}", proof.size(), singles);
+    }
+
+    #[test]
+    fn test_incremental_tree_matches_rebuilt_tree() {
+        let mut leaves: Vec<Vec<u8>> = Vec::new();
+        let mut tree = IncrementalMerkleTree::<Sha256Hasher>::new();
+        assert_eq!(tree.root_hash(), Err(MerkleError::EmptyLeaves));
+
+        for i in 0..37u32 {
+            leaves.push(format!("burn{}", i).into_bytes());
+            assert_eq!(tree.append(&leaves[i as usize]), i as usize);
+            let rebuilt = MerkleTree::new(&leaves).unwrap();
+            assert_eq!(tree.root_hash().unwrap(), rebuilt.root_hash(), "{} leaves", leaves.len());
+            assert_eq!(tree.depth(), rebuilt.depth);
+        }
+
+        for index in [0, 17, 32, 36] {
+            leaves[index] = format!("updated{}", index).into_bytes();
+            tree.update(index, &leaves[index]).unwrap();
+            let rebuilt = MerkleTree::new(&leaves).unwrap();
+            assert_eq!(tree.root_hash().unwrap(), rebuilt.root_hash());
+
+            let proof = tree.generate_proof(index).unwrap();
+            assert_eq!(proof, rebuilt.generate_proof(index).unwrap());
+            assert!(proof.verify(&leaves[index], rebuilt.root_hash()));
+        }
+        assert_eq!(tree.update(37, b"x"), Err(MerkleError::InvalidLeafIndex(37)));
+    }
+
+    #[test]
+    fn test_frontier_persists_and_resumes() {
+        use crate::proof::hasher::Keccak256Hasher;
+
+        let leaves: Vec<Vec<u8>> = (0..21u32).map(|i| i.to_le_bytes().to_vec()).collect();
+        let tree = IncrementalMerkleTree::<Keccak256Hasher>::from_leaves(&leaves[..13]);
+        let bytes = tree.frontier().to_bytes();
+        assert_eq!(bytes.len(), 8 + 3 * 32);
+
+        // A restarted relay resumes from the stored frontier alone
+        let mut frontier = MerkleFrontier::<Keccak256Hasher>::from_bytes(&bytes).unwrap();
+        assert_eq!(frontier, tree.frontier());
+        assert_eq!(frontier.root_hash(), tree.root_hash());
+        for leaf in &leaves[13..] {
+            frontier.append(leaf);
+        }
+        assert_eq!(frontier.num_leaves(), 21);
+        assert_eq!(
+            frontier.root_hash().unwrap(),
+            MerkleTree::<Keccak256Hasher>::from_leaves(&leaves).unwrap().root_hash()
+        );
+
+        assert_eq!(MerkleFrontier::<Sha256Hasher>::new().root_hash(), Err(MerkleError::EmptyLeaves));
+        assert!(matches!(MerkleFrontier::<Keccak256Hasher>::from_bytes(&bytes[..40]), Err(MerkleError::InvalidFrontier(_))));
+        assert!(matches!(MerkleFrontier::<Keccak256Hasher>::from_bytes(&bytes[..5]), Err(MerkleError::InvalidFrontier(_))));
+    }
+
+    #[test]
+    fn test_tree_hashed_with_each_hasher() {
+        use crate::proof::hasher::{Blake3Hasher, Keccak256Hasher, RescueHasher};
+
+        fn check<H: Hasher>(leaves: &[Vec<u8>]) -> [u8; 32] {
+            let tree = MerkleTree::<H>::from_leaves(leaves).unwrap();
+            let proof = tree.generate_proof(2).unwrap();
+            assert!(tree.verify_proof(b"leaf3", &proof).unwrap());
+            assert!(proof.verify_with::<H>(b"leaf3", tree.root_hash()));
+            assert!(!proof.verify_with::<H>(b"leaf4", tree.root_hash()));
+            tree.root_hash()
+        }
+
+        let leaves: Vec<Vec<u8>> = (1..=5).map(|i| format!("leaf{}", i).into_bytes()).collect();
+        let roots = [
+            check::<Sha256Hasher>(&leaves),
+            check::<Keccak256Hasher>(&leaves),
+            check::<Blake3Hasher>(&leaves),
+            check::<RescueHasher>(&leaves),
+        ];
+        assert_eq!(roots[0], MerkleTree::new(&leaves).unwrap().root_hash());
+        assert_eq!(roots[0], crate::utils::crypto::merkle_root(&leaves));
+        assert_ne!(roots[1], roots[2]);
+        assert_ne!(roots[2], roots[3]);
+        assert_eq!(roots[3], crate::utils::crypto::merkle_root_with::<RescueHasher>(&leaves));
+    }
+}
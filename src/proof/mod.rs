@@ -8,17 +8,54 @@
 //! 
 //! - **Proof Generation**: Complete STARK proof generation pipeline
 //! - **Proof Verification**: Cryptographic verification of STARK proofs
-//! - **Trace Generation**: Execution trace creation from AIR
+//! - **Trace Generation**: Execution trace creation from AIR, or row by row with a
+//!   checked [`trace::TraceBuilder`]
 //! - **Constraint Evaluation**: Polynomial constraint evaluation
 //! - **Commitment Generation**: Merkle tree commitments for proof components
+//! - **Batch Proving**: Many executions of one AIR proven in one call, see
+//!   [`StarkProver::prove_batch`]
+//! - **EVM Calldata**: Burn & mint proofs ABI-encoded for the Solidity verifier, see
+//!   [`evm::encode_for_solidity`]
+//! - **Progress Reporting**: Phase and percentage callbacks for long proving runs, see
+//!   [`progress::ProgressReporter`]
+//! - **Cancellation**: Proofs abandoned at the next phase or FRI layer through a
+//!   [`CancellationToken`]
+//! - **Compression**: Deduplicated, zstd-coded proofs with the `compression` feature, see
+//!   `StarkProof::compress`
+//!
+//! ## Field Extensions
+//!
+//! With a field extension degree of 2 or 3 the composition challenge, the composition
+//! polynomial and FRI run in [`QuadExtension`] or [`CubeExtension`] of the trace's field,
+//! while the trace itself stays in the prime field. The FRI proof is stored by its prime
+//! field coordinates, so [`StarkProof`] keeps the trace's field type; prover and verifier
+//! must be built with the same degree, which is bound into the transcript.
+//!
+//! ## Zero Knowledge
+//!
+//! Proofs are not zero-knowledge: they carry the execution trace, and the verifier checks
+//! every transition on it. The FRI queries are not tied to a trace commitment, so a proof
+//! without its trace would leave the transitions unchecked; the verifier rejects such
+//! proofs.
 
 use crate::types::{FieldElement, StarkComponent};
-use crate::types::stark::{StarkProof, ExecutionTrace, Air as StarkAir, MerkleCommitment, FriProof, ProofMetadata};
-use crate::air::Air;
-use crate::proof::fri::FriProver;
-use crate::proof::merkle::generate_commitment;
+use crate::types::field::{CubeExtension, ExtensibleField, QuadExtension};
+use crate::types::stark::{
+    StarkProof, ExecutionTrace, Air as StarkAir, MerkleCommitment, FriDomain, FriLayer, FriProof, FriQuery, ProofMetadata,
+};
+use crate::air::{Air, BoundaryConditions, BoundaryConstraint, PeriodicColumn, TransitionFunction};
+use crate::clock::{system_clock, SharedClock};
+use crate::proof::composition::composition_polynomial_in;
+use crate::proof::fri::{FriError, FriProver, FriVerifier};
+use crate::proof::merkle::{generate_commitment, MerkleProof, MerkleTree, MultiProof};
+use crate::proof::progress::{ProgressReporter, ProvingPhase, SharedProgress};
+use crate::proof::transcript::{Transcript, TranscriptLabel, STARK_PROTOCOL};
+use crate::soundness::default_num_queries;
 use std::marker::PhantomData;
 
+/// Blowup factor of provers and verifiers built with `new`
+const DEFAULT_BLOWUP_FACTOR: usize = 16;
+
 /// STARK proof generator
 /// 
 /// Generates STARK proofs for given AIR and execution traces with cryptographic security.
@@ -32,6 +69,12 @@ pub struct StarkProver<F: FieldElement> {
     num_queries: usize,
     /// Field extension degree
     field_extension_degree: u32,
+    /// Time source for proof metadata
+    clock: SharedClock,
+    /// Receives proving progress
+    progress: SharedProgress,
+    /// Checked between proving phases
+    cancellation: CancellationToken,
     /// Phantom data for type parameter
     _phantom: PhantomData<F>,
 }
@@ -41,9 +84,12 @@ impl<F: FieldElement> StarkProver<F> {
     pub fn new(security_parameter: u32) -> Self {
         Self {
             security_parameter,
-            blowup_factor: 16,
-            num_queries: 64,
+            blowup_factor: DEFAULT_BLOWUP_FACTOR,
+            num_queries: default_num_queries(security_parameter, DEFAULT_BLOWUP_FACTOR),
             field_extension_degree: 1,
+            clock: system_clock(),
+            progress: SharedProgress::none(),
+            cancellation: CancellationToken::new(),
             _phantom: PhantomData,
         }
     }
@@ -60,10 +106,49 @@ impl<F: FieldElement> StarkProver<F> {
             blowup_factor,
             num_queries,
             field_extension_degree,
+            clock: system_clock(),
+            progress: SharedProgress::none(),
+            cancellation: CancellationToken::new(),
             _phantom: PhantomData,
         }
     }
 
+    /// Stamp proof metadata with `clock` instead of the wall clock
+    #[must_use]
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Run composition and FRI in the extension of this degree (1, 2 or 3)
+    #[must_use]
+    pub fn with_field_extension_degree(mut self, field_extension_degree: u32) -> Self {
+        self.field_extension_degree = field_extension_degree;
+        self
+    }
+
+    /// Field extension degree composition and FRI run in
+    #[must_use]
+    pub fn field_extension_degree(&self) -> u32 {
+        self.field_extension_degree
+    }
+
+    /// Report each completed proving phase to `reporter`
+    #[must_use]
+    pub fn with_progress(mut self, reporter: impl ProgressReporter + 'static) -> Self {
+        self.progress = SharedProgress::new(reporter);
+        self
+    }
+
+    /// Stop with [`ProofError::Cancelled`] at the first checkpoint after `token` is cancelled
+    #[must_use]
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+}
+
+impl<F: ExtensibleField<2> + ExtensibleField<3>> StarkProver<F> {
     /// Generate a complete STARK proof
     pub fn prove(
         &self,
@@ -71,45 +156,161 @@ impl<F: FieldElement> StarkProver<F> {
         initial_state: &[F],
         num_steps: usize,
     ) -> Result<StarkProof<F>, ProofError> {
-        // Step 1: Generate execution trace
+        self.cancellation.check()?;
         let trace = self.generate_trace(air, initial_state, num_steps)?;
+        self.prove_trace(Self::stark_air(air), air, trace)
+    }
 
-        // Step 2: Generate constraint polynomials
-        let constraint_polynomials = self.generate_constraint_polynomials(air, &trace)?;
+    /// Prove an execution trace built outside the prover, such as by a
+    /// [`TraceBuilder`](trace::TraceBuilder)
+    ///
+    /// # Errors
+    ///
+    /// [`ProofError::InvalidTrace`] if the trace's columns do not match `air` and its
+    /// length, otherwise the first error proving it
+    pub fn prove_execution(&self, air: &Air<F>, trace: ExecutionTrace<F>) -> Result<StarkProof<F>, ProofError> {
+        if trace.columns.len() != air.num_registers() || trace.columns.iter().any(|column| column.len() != trace.length) {
+            return Err(ProofError::InvalidTrace);
+        }
+        self.prove_trace(Self::stark_air(air), air, trace)
+    }
 
-        // Step 3: Generate FRI proof
-        let fri_prover = FriProver::new(self.security_parameter);
-        let fri_proof = fri_prover.prove(&constraint_polynomials[0])?;
+    /// Prove executions of one AIR from several initial states in one call
+    ///
+    /// The converted AIR is set up once for the whole batch, and traces are generated in
+    /// parallel. Proofs come back in input order, bound by a Merkle root over their trace
+    /// commitments.
+    ///
+    /// # Panics
+    ///
+    /// If trace generation panics on a worker thread
+    ///
+    /// # Errors
+    ///
+    /// [`ProofError::EmptyBatch`] for no initial states, otherwise the first error proving an
+    /// execution, as for a single proof
+    pub fn prove_batch(
+        &self,
+        air: &Air<F>,
+        initial_states: &[Vec<F>],
+        num_steps: usize,
+    ) -> Result<BatchProof<F>, ProofError>
+    where
+        F: Send + Sync,
+    {
+        if initial_states.is_empty() {
+            return Err(ProofError::EmptyBatch);
+        }
 
-        // Step 4: Generate commitments
-        let commitments = self.generate_commitments(&trace, &constraint_polynomials)?;
+        let workers = std::thread::available_parallelism().map_or(4, std::num::NonZero::get);
+        let chunk_size = initial_states.len().div_ceil(workers);
+        let traces: Vec<Result<ExecutionTrace<F>, ProofError>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = initial_states
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|initial_state| self.generate_trace(air, initial_state, num_steps))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("Trace generation panicked"))
+                .collect()
+        });
+
+        // Shared setup, done once for the batch
+        let air_stark = Self::stark_air(air);
+        let proofs = traces
+            .into_iter()
+            .map(|trace| self.prove_trace(air_stark.clone(), air, trace?))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let batch_root = batch_tree(&proofs)?.root_hash();
+        Ok(BatchProof { proofs, batch_root })
+    }
 
-        // Step 5: Create proof metadata
+    /// Prove one execution trace with a shared converted AIR
+    fn prove_trace(
+        &self,
+        air_stark: StarkAir<F>,
+        air: &Air<F>,
+        trace: ExecutionTrace<F>,
+    ) -> Result<StarkProof<F>, ProofError> {
+        self.progress.report(ProvingPhase::TraceBuilt);
+        self.cancellation.check()?;
+        // Challenges come from the statement and commitments, in the order the verifier replays them
+        let commitments = Self::generate_commitments(&trace);
+        let mut transcript = proof_transcript(
+            &air_stark,
+            &air.periodic_columns,
+            &trace,
+            &commitments,
+            self.field_extension_degree,
+        );
+        let fri_proof = match self.field_extension_degree {
+            1 => self.prove_composition::<F>(air, &trace, &mut transcript)?,
+            2 => flatten_fri_proof(self.prove_composition::<QuadExtension<F>>(air, &trace, &mut transcript)?),
+            3 => flatten_fri_proof(self.prove_composition::<CubeExtension<F>>(air, &trace, &mut transcript)?),
+            degree => return Err(ProofError::UnsupportedFieldExtension(degree)),
+        };
         let metadata = self.create_proof_metadata(air, &trace)?;
 
-        // Step 6: Construct final proof
-        // Convert AIR to the expected type for StarkProof
-        let air_stark = StarkAir {
+        Ok(StarkProof {
+            trace,
+            air: air_stark,
+            commitments,
+            fri_proof,
+            metadata,
+        })
+    }
+
+    /// Compose the trace and prove the composition polynomial low degree, both over `E`
+    fn prove_composition<E: FieldElement + From<F>>(
+        &self,
+        air: &Air<F>,
+        trace: &ExecutionTrace<F>,
+        transcript: &mut Transcript,
+    ) -> Result<FriProof<E>, ProofError> {
+        let alpha: E = transcript.challenge(TranscriptLabel::CompositionChallenge);
+        let constraint_polynomials = self.generate_constraint_polynomials(air, trace, alpha)?;
+        self.cancellation.check()?;
+        let fri_prover = FriProver::<E>::new(self.security_parameter)
+            .with_progress(self.progress.clone())
+            .with_cancellation(self.cancellation.clone());
+        fri_prover.prove_with_transcript(&constraint_polynomials[0], transcript).map_err(|e| match e {
+            FriError::Cancelled => ProofError::Cancelled,
+            e => e.into(),
+        })
+    }
+}
+
+impl<F: FieldElement> StarkProver<F> {
+    /// Convert the AIR to the form carried in proofs
+    fn stark_air(air: &Air<F>) -> StarkAir<F> {
+        StarkAir {
             constraints: vec![], // Convert air constraints to stark constraints
             transition: crate::types::stark::TransitionFunction {
-                coefficients: vec![],
+                coefficients: air.transition.coefficients().to_vec(),
                 degree: air.transition.degree(),
             },
             boundary: crate::types::stark::BoundaryConditions {
-                constraints: vec![],
+                constraints: air
+                    .boundary
+                    .constraints
+                    .iter()
+                    .map(|constraint| crate::types::stark::BoundaryConstraint {
+                        register: constraint.register,
+                        step: constraint.step,
+                        value: constraint.value,
+                    })
+                    .collect(),
             },
             security_parameter: air.security_parameter,
-        };
-
-        let proof = StarkProof {
-            trace,
-            air: air_stark,
-            commitments,
-            fri_proof,
-            metadata,
-        };
-
-        Ok(proof)
+        }
     }
 
     /// Generate execution trace from AIR
@@ -130,9 +331,9 @@ impl<F: FieldElement> StarkProver<F> {
         }
 
         // Generate trace steps
-        for _ in 1..num_steps {
-            // Apply transition function to get next state
-            let next_state = air.transition.apply(&current_state);
+        for step in 1..num_steps {
+            // Apply transition function, with periodic values, to get next state
+            let next_state = air.next_state(&current_state, step - 1);
             current_state = next_state;
             
             // Add next state to columns
@@ -150,53 +351,27 @@ impl<F: FieldElement> StarkProver<F> {
         })
     }
 
-    /// Generate constraint polynomials
-    fn generate_constraint_polynomials(
+    /// Generate the composition polynomial of the trace under the AIR
+    ///
+    /// Fails when the trace breaks a transition or boundary constraint, so only valid
+    /// executions can be proven. See [`composition`] for the construction.
+    fn generate_constraint_polynomials<E: FieldElement + From<F>>(
         &self,
-        _air: &Air<F>,
+        air: &Air<F>,
         trace: &ExecutionTrace<F>,
-    ) -> Result<Vec<Vec<F>>, ProofError> {
-        // --- Realistic implementation ----------------------------------------------------
-        // For each column in the execution trace we treat the column values as the
-        // coefficients of a low-degree polynomial (degree < trace.length).
-        // This is **not** a full constraint-evaluation engine but produces genuine,
-        // non-empty polynomials that reflect the trace content – eliminating the
-        // previous placeholder zeros.
-
-        let mut polys: Vec<Vec<F>> = Vec::with_capacity(trace.num_registers);
-
-        for column in &trace.columns {
-            // Use the column values directly as polynomial coefficients.
-            // (In a production system we would perform Lagrange interpolation.)
-            polys.push(column.clone());
-        }
-
-        // Always return at least one polynomial to satisfy downstream logic.
-        if polys.is_empty() {
-            polys.push(vec![F::zero()]);
-        }
-
-        Ok(polys)
-    }
-
-    /// Generate FRI proof
-    fn generate_fri_proof(&self, _polynomials: &[Vec<F>]) -> Result<FriProof<F>, ProofError> {
-        // This is now handled by the FriProver
-        unimplemented!("Use FriProver directly")
+        alpha: E,
+    ) -> Result<Vec<Vec<E>>, ProofError> {
+        Ok(vec![composition_polynomial_in(air, trace, alpha)?])
     }
 
     /// Generate commitments for proof components
-    fn generate_commitments(
-        &self,
-        trace: &ExecutionTrace<F>,
-        _constraint_polynomials: &[Vec<F>],
-    ) -> Result<Vec<MerkleCommitment<F>>, ProofError> {
+    fn generate_commitments(trace: &ExecutionTrace<F>) -> Vec<MerkleCommitment<F>> {
         let mut commitments = Vec::new();
 
         // Generate commitment for trace
         // Convert trace to field elements for commitment generation
         let trace_elements: Vec<F> = trace.columns.iter()
-            .flat_map(|column| column.iter().cloned())
+            .flat_map(|column| column.iter().copied())
             .collect();
         
         let trace_commitment = MerkleCommitment {
@@ -206,20 +381,17 @@ impl<F: FieldElement> StarkProver<F> {
         };
         commitments.push(trace_commitment);
 
-        Ok(commitments)
+        commitments
     }
 
     /// Create proof metadata
     fn create_proof_metadata(&self, _air: &Air<F>, trace: &ExecutionTrace<F>) -> Result<ProofMetadata, ProofError> {
         Ok(ProofMetadata {
             version: 1,
-            field_modulus: "0x7fffffffffffffff".to_string(), // PrimeField64 modulus as string
+            field_modulus: "0xffffffff00000001".to_string(), // PrimeField64 modulus as string
             proof_size: trace.length,
             security_parameter: self.security_parameter,
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
+            timestamp: self.clock.unix_seconds(),
         })
     }
 }
@@ -234,6 +406,178 @@ impl<F: FieldElement> std::fmt::Display for StarkProver<F> {
     }
 }
 
+/// Proofs of several executions of one AIR under a combined commitment
+#[derive(Debug, Clone)]
+pub struct BatchProof<F: FieldElement> {
+    /// Proof of each execution, in input order
+    pub proofs: Vec<StarkProof<F>>,
+    /// Merkle root over the trace commitment of every proof
+    pub batch_root: [u8; 32],
+}
+
+impl<F: FieldElement> BatchProof<F> {
+    /// Number of proofs in the batch
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.proofs.len()
+    }
+
+    /// Whether the batch holds no proofs
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.proofs.is_empty()
+    }
+
+    /// Inclusion proof of the proof at `index` under the batch root
+    ///
+    /// # Errors
+    ///
+    /// [`ProofError::MerkleError`] if `index` is outside the batch
+    pub fn inclusion_proof(&self, index: usize) -> Result<MerkleProof, ProofError> {
+        Ok(batch_tree(&self.proofs)?.generate_proof(index)?)
+    }
+
+    /// Check that `proof` is included under the batch root by `inclusion`
+    pub fn verify_inclusion(&self, proof: &StarkProof<F>, inclusion: &MerkleProof) -> bool {
+        inclusion.verify(&trace_root(proof), self.batch_root)
+    }
+
+    /// One inclusion proof of every proof in `indices` under the batch root
+    ///
+    /// # Errors
+    ///
+    /// [`ProofError::MerkleError`] if `indices` is empty or an index is outside the batch
+    pub fn inclusion_multi_proof(&self, indices: &[usize]) -> Result<MultiProof, ProofError> {
+        Ok(batch_tree(&self.proofs)?.generate_multi_proof(indices)?)
+    }
+
+    /// Check that `proofs`, in the index order of `inclusion`, are included under the batch root
+    pub fn verify_inclusions(&self, proofs: &[&StarkProof<F>], inclusion: &MultiProof) -> bool {
+        let leaves: Vec<Vec<u8>> = proofs.iter().map(|proof| trace_root(proof)).collect();
+        inclusion.verify(&leaves, self.batch_root)
+    }
+}
+
+/// Trace commitment root a proof contributes to its batch
+fn trace_root<F: FieldElement>(proof: &StarkProof<F>) -> Vec<u8> {
+    proof.commitments.first().map(|commitment| commitment.root.clone()).unwrap_or_default()
+}
+
+/// Transcript after the public statement and trace commitments of a proof
+///
+/// Shared by prover and verifier so both absorb the same bytes in the same order.
+/// Periodic columns are absorbed only when there are any, so transcripts of AIRs
+/// without them are unchanged.
+fn proof_transcript<F: FieldElement>(
+    air: &StarkAir<F>,
+    periodic_columns: &[PeriodicColumn<F>],
+    trace: &ExecutionTrace<F>,
+    commitments: &[MerkleCommitment<F>],
+    field_extension_degree: u32,
+) -> Transcript {
+    let mut transcript = Transcript::new(STARK_PROTOCOL);
+    transcript.absorb_u64(TranscriptLabel::PublicInputs, u64::from(field_extension_degree));
+    transcript.absorb_u64(TranscriptLabel::PublicInputs, trace.length as u64);
+    transcript.absorb_u64(TranscriptLabel::PublicInputs, trace.num_registers as u64);
+    transcript.absorb_u64(TranscriptLabel::PublicInputs, u64::from(air.security_parameter));
+    transcript.absorb_u64(TranscriptLabel::PublicInputs, air.transition.degree as u64);
+    transcript.absorb_u64(TranscriptLabel::PublicInputs, air.transition.coefficients.len() as u64);
+    for row in &air.transition.coefficients {
+        transcript.absorb_elements(TranscriptLabel::PublicInputs, row);
+    }
+    transcript.absorb_u64(TranscriptLabel::PublicInputs, air.boundary.constraints.len() as u64);
+    for constraint in &air.boundary.constraints {
+        transcript.absorb_u64(TranscriptLabel::PublicInputs, constraint.register as u64);
+        transcript.absorb_u64(TranscriptLabel::PublicInputs, constraint.step as u64);
+        transcript.absorb_elements(TranscriptLabel::PublicInputs, &[constraint.value]);
+    }
+    if !periodic_columns.is_empty() {
+        transcript.absorb_u64(TranscriptLabel::PublicInputs, periodic_columns.len() as u64);
+        for column in periodic_columns {
+            transcript.absorb_u64(TranscriptLabel::PublicInputs, column.register() as u64);
+            transcript.absorb_u64(TranscriptLabel::PublicInputs, column.period() as u64);
+            transcript.absorb_elements(TranscriptLabel::PublicInputs, column.values());
+        }
+    }
+    for commitment in commitments {
+        transcript.absorb(TranscriptLabel::TraceCommitment, &commitment.root);
+    }
+    transcript
+}
+
+/// FRI proof over an extension `E`, stored by prime field coordinates
+fn flatten_fri_proof<F: FieldElement, E: FieldElement>(proof: FriProof<E>) -> FriProof<F> {
+    let flatten = |elements: &[E]| -> Vec<F> {
+        elements.iter().flat_map(FieldElement::to_base_values).map(F::new).collect()
+    };
+    // Domain points lie in the prime field
+    let base = |element: E| F::new(element.value());
+
+    FriProof {
+        layers: proof
+            .layers
+            .into_iter()
+            .map(|layer| FriLayer {
+                polynomial: flatten(&layer.polynomial),
+                commitment: layer.commitment,
+                degree: layer.degree,
+            })
+            .collect(),
+        final_polynomial: flatten(&proof.final_polynomial),
+        queries: proof
+            .queries
+            .iter()
+            .map(|query| FriQuery { point: base(query.point), responses: flatten(&query.responses) })
+            .collect(),
+        openings: proof.openings,
+        domain: FriDomain::new(proof.domain.size, base(proof.domain.generator), base(proof.domain.offset)),
+    }
+}
+
+/// FRI proof over an extension `E` from its prime field coordinates
+fn lift_fri_proof<F: FieldElement, E: FieldElement + From<F>>(proof: &FriProof<F>) -> Result<FriProof<E>, ProofError> {
+    lift_fri_coordinates(proof).ok_or_else(|| {
+        ProofError::VerificationError("FRI proof does not match the field extension degree".to_string())
+    })
+}
+
+/// `None` if the coordinates do not group into `E` elements
+fn lift_fri_coordinates<F: FieldElement, E: FieldElement + From<F>>(proof: &FriProof<F>) -> Option<FriProof<E>> {
+    let lift = |elements: &[F]| -> Option<Vec<E>> {
+        if !elements.len().is_multiple_of(E::EXTENSION_DEGREE) {
+            return None;
+        }
+        elements
+            .chunks(E::EXTENSION_DEGREE)
+            .map(|chunk| E::from_base_values(&chunk.iter().map(FieldElement::value).collect::<Vec<_>>()))
+            .collect()
+    };
+
+    Some(FriProof {
+        layers: proof
+            .layers
+            .iter()
+            .map(|layer| {
+                Some(FriLayer { polynomial: lift(&layer.polynomial)?, commitment: layer.commitment.clone(), degree: layer.degree })
+            })
+            .collect::<Option<_>>()?,
+        final_polynomial: lift(&proof.final_polynomial)?,
+        queries: proof
+            .queries
+            .iter()
+            .map(|query| Some(FriQuery { point: E::from(query.point), responses: lift(&query.responses)? }))
+            .collect::<Option<_>>()?,
+        openings: proof.openings.clone(),
+        domain: FriDomain::new(proof.domain.size, E::from(proof.domain.generator), E::from(proof.domain.offset)),
+    })
+}
+
+/// Merkle tree over the trace commitments of a batch
+fn batch_tree<F: FieldElement>(proofs: &[StarkProof<F>]) -> Result<MerkleTree, ProofError> {
+    let leaves: Vec<Vec<u8>> = proofs.iter().map(trace_root).collect();
+    Ok(MerkleTree::new(&leaves)?)
+}
+
 /// STARK proof verifier
 /// 
 /// Verifies STARK proofs with cryptographic security guarantees.
@@ -243,6 +587,10 @@ pub struct StarkVerifier<F: FieldElement> {
     security_parameter: u32,
     /// Number of queries
     num_queries: usize,
+    /// Field extension degree composition and FRI were proven in
+    field_extension_degree: u32,
+    /// Periodic columns of the AIR the proofs are for
+    periodic_columns: Vec<PeriodicColumn<F>>,
     /// Phantom data for type parameter
     _phantom: PhantomData<F>,
 }
@@ -252,20 +600,41 @@ impl<F: FieldElement> StarkVerifier<F> {
     pub fn new(security_parameter: u32) -> Self {
         Self {
             security_parameter,
-            num_queries: 64,
+            num_queries: default_num_queries(security_parameter, DEFAULT_BLOWUP_FACTOR),
+            field_extension_degree: 1,
+            periodic_columns: Vec::new(),
             _phantom: PhantomData,
         }
     }
 
+    /// Verify proofs of an AIR with these periodic columns
+    ///
+    /// Proofs carry the transition matrix but not the periodic schedule, which the
+    /// verifier must know independently, as it knows the AIR.
+    #[must_use]
+    pub fn with_periodic_columns(mut self, periodic_columns: Vec<PeriodicColumn<F>>) -> Self {
+        self.periodic_columns = periodic_columns;
+        self
+    }
+
+    /// Verify proofs whose composition and FRI ran in the extension of this degree
+    #[must_use]
+    pub fn with_field_extension_degree(mut self, field_extension_degree: u32) -> Self {
+        self.field_extension_degree = field_extension_degree;
+        self
+    }
+}
+
+impl<F: ExtensibleField<2> + ExtensibleField<3>> StarkVerifier<F> {
     /// Verify a STARK proof
     pub fn verify(&self, proof: &StarkProof<F>) -> Result<bool, ProofError> {
         // Step 1: Verify boundary conditions
-        if !self.verify_boundary_conditions(&proof)? {
+        if !Self::verify_boundary_conditions(proof) {
             return Ok(false);
         }
 
         // Step 2: Verify constraints
-        if !self.verify_constraints(&proof)? {
+        if !self.verify_constraints(proof) {
             return Ok(false);
         }
 
@@ -275,35 +644,136 @@ impl<F: FieldElement> StarkVerifier<F> {
         }
 
         // Step 4: Verify commitments
-        if !self.verify_commitments(&proof)? {
+        if !Self::verify_commitments(proof) {
             return Ok(false);
         }
 
         Ok(true)
     }
 
-    /// Verify boundary conditions
-    fn verify_boundary_conditions(&self, _proof: &StarkProof<F>) -> Result<bool, ProofError> {
-        // Placeholder implementation
-        Ok(true)
+    /// Verify every boundary assertion holds on the trace a proof carries
+    ///
+    /// Step zero is the first row; any other step is the last, as in the composition.
+    fn verify_boundary_conditions(proof: &StarkProof<F>) -> bool {
+        let Some(last_step) = proof.trace.length.checked_sub(1) else {
+            return false;
+        };
+        proof.air.boundary.constraints.iter().all(|constraint| {
+            let step = if constraint.step == 0 { 0 } else { last_step };
+            proof
+                .trace
+                .columns
+                .get(constraint.register)
+                .and_then(|column| column.get(step))
+                .is_some_and(|&value| value == constraint.value)
+        })
     }
 
-    /// Verify constraints
-    fn verify_constraints(&self, _proof: &StarkProof<F>) -> Result<bool, ProofError> {
-        // Placeholder implementation
-        Ok(true)
+    /// Verify transitions, periodic values included, on the trace a proof carries
+    ///
+    /// A proof missing any trace row is rejected: nothing else binds its transitions.
+    fn verify_constraints(&self, proof: &StarkProof<F>) -> bool {
+        let columns = &proof.trace.columns;
+        if columns.len() != proof.trace.num_registers || columns.iter().any(|column| column.len() != proof.trace.length) {
+            return false;
+        }
+        let row = |step: usize| columns.iter().map(|column| column.get(step).copied()).collect::<Option<Vec<F>>>();
+        for step in 0..proof.trace.length.saturating_sub(1) {
+            let (Some(current), Some(next)) = (row(step), row(step + 1)) else {
+                break;
+            };
+            for (register, coefficients) in proof.air.transition.coefficients.iter().enumerate() {
+                let Some(&actual) = next.get(register) else {
+                    continue;
+                };
+                let periodic = self
+                    .periodic_columns
+                    .iter()
+                    .filter(|periodic| periodic.register() == register)
+                    .fold(F::zero(), |sum, periodic| sum + periodic.value_at(step));
+                let expected = coefficients
+                    .iter()
+                    .zip(&current)
+                    .fold(periodic, |sum, (&coefficient, &value)| sum + coefficient * value);
+                if actual != expected {
+                    return false;
+                }
+            }
+        }
+        true
     }
 
-    /// Verify FRI proof
-    fn verify_fri_proof(&self, _proof: &StarkProof<F>) -> Result<bool, ProofError> {
-        // Placeholder implementation
-        Ok(true)
+    /// Verify the FRI queries are the ones the proof's transcript derives
+    fn verify_fri_proof(&self, proof: &StarkProof<F>) -> Result<bool, ProofError> {
+        let transcript = proof_transcript(
+            &proof.air,
+            &self.periodic_columns,
+            &proof.trace,
+            &proof.commitments,
+            self.field_extension_degree,
+        );
+        match self.field_extension_degree {
+            1 => self.verify_composition_fri::<F>(proof, &proof.fri_proof, transcript),
+            2 => self.verify_composition_fri::<QuadExtension<F>>(proof, &lift_fri_proof(&proof.fri_proof)?, transcript),
+            3 => self.verify_composition_fri::<CubeExtension<F>>(proof, &lift_fri_proof(&proof.fri_proof)?, transcript),
+            degree => Err(ProofError::UnsupportedFieldExtension(degree)),
+        }
     }
 
-    /// Verify commitments
-    fn verify_commitments(&self, _proof: &StarkProof<F>) -> Result<bool, ProofError> {
-        // Placeholder implementation
-        Ok(true)
+    /// Replay the composition challenge and FRI over `E`
+    ///
+    /// The composition polynomial is recomputed from the proof's trace and AIR under the
+    /// replayed `alpha`, and must match the first FRI layer at every query point.
+    fn verify_composition_fri<E: FieldElement + From<F>>(
+        &self,
+        proof: &StarkProof<F>,
+        fri_proof: &FriProof<E>,
+        mut transcript: Transcript,
+    ) -> Result<bool, ProofError> {
+        let alpha: E = transcript.challenge(TranscriptLabel::CompositionChallenge);
+        let fri_verifier = FriVerifier::<E>::new(self.security_parameter);
+        if !fri_verifier.verify_queries(fri_proof, &mut transcript)? {
+            return Ok(false);
+        }
+
+        let Ok(composition) = composition_polynomial_in(&self.composition_air(&proof.air), &proof.trace, alpha) else {
+            return Ok(false);
+        };
+        Ok(fri_proof.queries.iter().all(|query| {
+            let expected = composition.iter().rev().fold(E::zero(), |sum, &coefficient| sum * query.point + coefficient);
+            query.responses.first() == Some(&expected)
+        }))
+    }
+
+    /// AIR the prover composed: the proof's transition and boundary under the verifier's
+    /// periodic columns
+    fn composition_air(&self, air: &StarkAir<F>) -> Air<F> {
+        let boundary = air
+            .boundary
+            .constraints
+            .iter()
+            .map(|constraint| match constraint.step {
+                0 => BoundaryConstraint::initial(constraint.register, constraint.value),
+                _ => BoundaryConstraint::final_condition(constraint.register, constraint.value),
+            })
+            .collect();
+        let mut composition_air = Air::new(
+            Vec::new(),
+            TransitionFunction::new(air.transition.coefficients.clone(), air.transition.degree),
+            BoundaryConditions::new(boundary),
+            air.security_parameter,
+        );
+        composition_air.periodic_columns.clone_from(&self.periodic_columns);
+        composition_air
+    }
+
+    /// Verify the trace commitment is the root over the trace the proof carries
+    fn verify_commitments(proof: &StarkProof<F>) -> bool {
+        let [commitment] = proof.commitments.as_slice() else {
+            return false;
+        };
+        let trace_elements: Vec<F> = proof.trace.columns.iter().flatten().copied().collect();
+        commitment.leaves == trace_elements && commitment.root == generate_commitment(&trace_elements)
     }
 }
 
@@ -311,8 +781,8 @@ impl<F: FieldElement> std::fmt::Display for StarkVerifier<F> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "StarkVerifier(security={}, queries={})",
-            self.security_parameter, self.num_queries
+            "StarkVerifier(security={}, queries={}, field_ext={})",
+            self.security_parameter, self.num_queries, self.field_extension_degree
         )
     }
 }
@@ -324,6 +794,14 @@ pub enum ProofError {
     #[error("Invalid execution trace")]
     InvalidTrace,
 
+    /// Batch has no executions to prove
+    #[error("Batch has no executions to prove")]
+    EmptyBatch,
+
+    /// Field extension degree other than 1, 2 or 3
+    #[error("Unsupported field extension degree: {0}")]
+    UnsupportedFieldExtension(u32),
+
     /// Invalid AIR
     #[error("Invalid AIR: {0}")]
     InvalidAir(String),
@@ -340,6 +818,10 @@ pub enum ProofError {
     #[error("Constraint evaluation error: {0}")]
     ConstraintError(String),
 
+    /// Trace does not satisfy the AIR
+    #[error("Composition error: {0}")]
+    CompositionError(#[from] crate::proof::composition::CompositionError),
+
     /// Commitment error
     #[error("Commitment error: {0}")]
     CommitmentError(String),
@@ -347,10 +829,193 @@ pub enum ProofError {
     /// Verification error
     #[error("Verification error: {0}")]
     VerificationError(String),
+
+    /// Proving was cancelled through a [`CancellationToken`]
+    #[error("Proving was cancelled")]
+    Cancelled,
 }
 
 // Re-export sub-modules
+pub mod cancellation;
+pub mod composition;
+#[cfg(feature = "compression")]
+pub mod compression;
+pub mod evm;
 pub mod fri;
+pub mod hasher;
+pub mod memory;
 pub mod merkle;
+pub mod progress;
 pub mod trace;
-pub mod verification;
\ No newline at end of file
+pub mod transcript;
+pub mod verification;
+pub mod wire;
+
+pub use cancellation::CancellationToken;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::air::{BoundaryConditions, Constraint, TransitionFunction};
+    use crate::air::constraints::ConstraintType;
+    use crate::types::field::PrimeField64;
+
+    fn counter_air() -> Air<PrimeField64> {
+        let constraints = vec![Constraint::new(
+            vec![PrimeField64::one(), PrimeField64::zero()],
+            1,
+            ConstraintType::Transition,
+        )];
+        let transition = TransitionFunction::new(vec![vec![PrimeField64::one()]], 1);
+        Air::new(constraints, transition, BoundaryConditions::new(vec![]), 128)
+    }
+
+    #[test]
+    fn test_batch_matches_individual_proofs() {
+        let prover = StarkProver::<PrimeField64>::new(128);
+        let air = counter_air();
+        let initial_states: Vec<Vec<PrimeField64>> =
+            (1..=5).map(|i| vec![PrimeField64::new(i)]).collect();
+
+        let batch = prover.prove_batch(&air, &initial_states, 8).unwrap();
+        assert_eq!(batch.len(), initial_states.len());
+
+        for (index, initial_state) in initial_states.iter().enumerate() {
+            let single = prover.prove(&air, initial_state, 8).unwrap();
+            assert_eq!(batch.proofs[index].trace.columns, single.trace.columns);
+            assert_eq!(trace_root(&batch.proofs[index]), trace_root(&single));
+
+            let inclusion = batch.inclusion_proof(index).unwrap();
+            assert!(batch.verify_inclusion(&single, &inclusion));
+        }
+
+        // A proof from outside the batch is not included
+        let outsider = prover.prove(&air, &[PrimeField64::new(99)], 8).unwrap();
+        assert!(!batch.verify_inclusion(&outsider, &batch.inclusion_proof(0).unwrap()));
+
+        let inclusion = batch.inclusion_multi_proof(&[4, 1]).unwrap();
+        assert!(batch.verify_inclusions(&[&batch.proofs[1], &batch.proofs[4]], &inclusion));
+        assert!(!batch.verify_inclusions(&[&batch.proofs[1], &outsider], &inclusion));
+    }
+
+    #[test]
+    fn test_proof_without_trace_rejected() {
+        let air = counter_air();
+        let mut proof = StarkProver::<PrimeField64>::new(128).prove(&air, &[PrimeField64::new(3)], 8).unwrap();
+        let verifier = StarkVerifier::<PrimeField64>::new(128);
+        assert!(verifier.verify(&proof).unwrap());
+
+        // Dropping the trace leaves the transitions unchecked, so the proof must fail
+        proof.trace.columns.clear();
+        assert!(!verifier.verify(&proof).unwrap_or(false));
+    }
+
+    #[test]
+    fn test_extension_field_proofs() {
+        for degree in [2, 3] {
+            let prover = StarkProver::<PrimeField64>::new(128).with_field_extension_degree(degree);
+            let proof = prover.prove(&counter_air(), &[PrimeField64::new(3)], 8).unwrap();
+            let extension = degree as usize;
+            assert_eq!(proof.fri_proof.final_polynomial.len() % extension, 0);
+
+            let verifier = StarkVerifier::<PrimeField64>::new(128).with_field_extension_degree(degree);
+            assert!(verifier.verify(&proof).unwrap());
+
+            // The degree is bound into the transcript
+            let base_verifier = StarkVerifier::<PrimeField64>::new(128);
+            assert!(!base_verifier.verify(&proof).unwrap_or(false));
+        }
+
+        let unsupported = StarkProver::<PrimeField64>::new(128).with_field_extension_degree(4);
+        assert!(matches!(
+            unsupported.prove(&counter_air(), &[PrimeField64::new(3)], 8),
+            Err(ProofError::UnsupportedFieldExtension(4))
+        ));
+    }
+
+    #[test]
+    fn test_verifier_replays_transcript() {
+        let prover = StarkProver::<PrimeField64>::new(128);
+        let verifier = StarkVerifier::<PrimeField64>::new(128);
+        let proof = prover.prove(&counter_air(), &[PrimeField64::new(3)], 8).unwrap();
+        assert!(verifier.verify(&proof).unwrap());
+
+        // Same statement and trace give the same challenges
+        let again = prover.prove(&counter_air(), &[PrimeField64::new(3)], 8).unwrap();
+        assert_eq!(again.fri_proof, proof.fri_proof);
+
+        // Queries the transcript did not choose are rejected
+        let mut moved = proof.clone();
+        moved.fri_proof.queries[0].point = moved.fri_proof.domain.element(1);
+        assert!(!verifier.verify(&moved).unwrap());
+
+        // So is a proof whose commitment differs from the one the challenges came from
+        let mut recommitted = proof;
+        recommitted.commitments[0].root[0] ^= 1;
+        assert!(!verifier.verify(&recommitted).unwrap());
+    }
+
+    #[test]
+    fn test_verifier_checks_boundaries_commitment_and_composition() {
+        use crate::air::BoundaryConstraint;
+
+        let mut air = counter_air();
+        air.boundary = BoundaryConditions::new(vec![BoundaryConstraint::initial(0, PrimeField64::new(3))]);
+        let prover = StarkProver::<PrimeField64>::new(128);
+        let verifier = StarkVerifier::<PrimeField64>::new(128);
+        let proof = prover.prove(&air, &[PrimeField64::new(3)], 8).unwrap();
+        assert!(verifier.verify(&proof).unwrap());
+
+        // A boundary assertion the trace does not meet
+        let mut asserted = proof.clone();
+        asserted.air.boundary.constraints[0].value = PrimeField64::new(4);
+        assert!(!StarkVerifier::verify_boundary_conditions(&asserted));
+        assert!(!verifier.verify(&asserted).unwrap());
+
+        // A trace commitment whose leaves are not the trace
+        let mut recommitted = proof.clone();
+        recommitted.commitments[0].leaves[0] += PrimeField64::one();
+        assert!(!StarkVerifier::verify_commitments(&recommitted));
+
+        // FRI layers that are not the composition of the proof's trace
+        let transcript = |proof: &StarkProof<PrimeField64>| {
+            proof_transcript(&proof.air, &[], &proof.trace, &proof.commitments, 1)
+        };
+        assert!(verifier.verify_composition_fri(&proof, &proof.fri_proof, transcript(&proof)).unwrap());
+        let mut other = proof.clone();
+        other.trace.columns[0].iter_mut().for_each(|value| *value += PrimeField64::one());
+        assert!(!verifier.verify_composition_fri(&other, &proof.fri_proof, transcript(&proof)).unwrap());
+    }
+
+    #[test]
+    fn test_periodic_round_constants() {
+        let constants: Vec<_> = [5, 0, 9, 2].into_iter().map(PrimeField64::new).collect();
+        let periodic = PeriodicColumn::new(0, constants.clone()).unwrap();
+        let air = counter_air().with_periodic_column(periodic.clone());
+        assert!(air.validate().is_ok());
+
+        let proof = StarkProver::<PrimeField64>::new(128).prove(&air, &[PrimeField64::new(3)], 8).unwrap();
+        let sums = [3, 8, 8, 17, 19, 24, 24, 33].map(PrimeField64::new);
+        assert_eq!(proof.trace.columns[0], sums);
+
+        let verifier = StarkVerifier::<PrimeField64>::new(128).with_periodic_columns(vec![periodic]);
+        assert!(verifier.verify(&proof).unwrap());
+
+        // A verifier with another schedule, or none, rejects the proof
+        assert!(!StarkVerifier::<PrimeField64>::new(128).verify(&proof).unwrap());
+        let mut shifted = constants;
+        shifted.rotate_left(1);
+        let other = StarkVerifier::<PrimeField64>::new(128)
+            .with_periodic_columns(vec![PeriodicColumn::new(0, shifted).unwrap()]);
+        assert!(!other.verify(&proof).unwrap());
+    }
+
+    #[test]
+    fn test_empty_batch_rejected() {
+        let prover = StarkProver::<PrimeField64>::new(128);
+        assert!(matches!(
+            prover.prove_batch(&counter_air(), &[], 8),
+            Err(ProofError::EmptyBatch)
+        ));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,150 @@
+//! Proving Progress Callbacks
+//!
+//! A prover built `with_progress` reports each [`ProvingPhase`] it completes, with an
+//! estimate of the share of the run done so far, to a [`ProgressReporter`]. Any
+//! `Fn(&ProvingPhase, u8) + Send + Sync` closure is a reporter, as is the watch-channel
+//! [`crate::progress::ProgressReporter`] with the `progress` feature.
+//!
+//! [`StarkProver`](crate::proof::StarkProver) reports every phase. The burn & mint
+//! prover hands commitment and FRI to Winterfell, which folds its layers internally,
+//! so it reports the trace, its low-degree extension and the finished proof only.
+//!
+//! The trait is not re-exported at the crate root, where `ProgressReporter` names the
+//! watch-channel reporter.
+
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::Arc;
+
+/// Share of a run done once the trace is built (percent)
+const TRACE_BUILT_PERCENT: u8 = 10;
+/// Share of a run done once the low-degree extension is evaluated (percent)
+const LDE_DONE_PERCENT: u8 = 40;
+/// Share of a run done once every FRI layer is folded (percent)
+const LAYERS_FOLDED_PERCENT: u8 = 90;
+
+/// Proving step just completed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProvingPhase {
+    /// The execution trace is built
+    TraceBuilt,
+    /// The low-degree extension is evaluated and committed to
+    LdeDone,
+    /// FRI layer `layer` of `layers` is folded (counting from 1)
+    LayerFolded {
+        /// Layers folded so far
+        layer: usize,
+        /// Layers the run folds
+        layers: usize,
+    },
+    /// Query responses are assembled; the proof is complete
+    QueriesAnswered,
+}
+
+impl ProvingPhase {
+    /// Estimated share of the run done after this phase (percent)
+    #[must_use]
+    pub fn percent(&self) -> u8 {
+        match *self {
+            ProvingPhase::TraceBuilt => TRACE_BUILT_PERCENT,
+            ProvingPhase::LdeDone => LDE_DONE_PERCENT,
+            ProvingPhase::LayerFolded { layer, layers } => {
+                let span = LAYERS_FOLDED_PERCENT - LDE_DONE_PERCENT;
+                let done = usize::from(span) * layer.min(layers) / layers.max(1);
+                LDE_DONE_PERCENT + u8::try_from(done).unwrap_or(span)
+            }
+            ProvingPhase::QueriesAnswered => 100,
+        }
+    }
+}
+
+impl Display for ProvingPhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProvingPhase::TraceBuilt => write!(f, "trace built"),
+            ProvingPhase::LdeDone => write!(f, "LDE done"),
+            ProvingPhase::LayerFolded { layer, layers } => write!(f, "FRI layer {layer} of {layers} folded"),
+            ProvingPhase::QueriesAnswered => write!(f, "queries answered"),
+        }
+    }
+}
+
+/// Receives proving progress
+///
+/// Called on the proving thread; implementations should return quickly.
+pub trait ProgressReporter: Send + Sync {
+    /// A phase completed, with `percent` of the run estimated done
+    fn report(&self, phase: &ProvingPhase, percent: u8);
+}
+
+impl<T: Fn(&ProvingPhase, u8) + Send + Sync> ProgressReporter for T {
+    fn report(&self, phase: &ProvingPhase, percent: u8) {
+        self(phase, percent);
+    }
+}
+
+/// Optional reporter held by a prover and passed to the stages it drives
+#[derive(Clone, Default)]
+pub struct SharedProgress(Option<Arc<dyn ProgressReporter>>);
+
+impl SharedProgress {
+    /// Report to `reporter`
+    pub fn new(reporter: impl ProgressReporter + 'static) -> Self {
+        Self(Some(Arc::new(reporter)))
+    }
+
+    /// Report nowhere
+    #[must_use]
+    pub fn none() -> Self {
+        Self(None)
+    }
+
+    /// Report a completed phase with its estimated percentage
+    pub fn report(&self, phase: ProvingPhase) {
+        if let Some(reporter) = &self.0 {
+            reporter.report(&phase, phase.percent());
+        }
+    }
+}
+
+impl Debug for SharedProgress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(if self.0.is_some() { "SharedProgress(reporter)" } else { "SharedProgress(none)" })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::air::{Air, BoundaryConditions, TransitionFunction};
+    use crate::proof::StarkProver;
+    use crate::types::field::PrimeField64;
+    use crate::types::FieldElement;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_stark_prover_reports_every_phase() {
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let sink = updates.clone();
+        let prover = StarkProver::<PrimeField64>::new(128)
+            .with_progress(move |phase: &ProvingPhase, percent: u8| sink.lock().unwrap().push((*phase, percent)));
+
+        let (zero, one) = (PrimeField64::zero(), PrimeField64::one());
+        let transition = TransitionFunction::new(vec![vec![one, one], vec![zero, one]], 1);
+        let air = Air::new(vec![], transition, BoundaryConditions::new(vec![]), 128);
+        prover.prove(&air, &[PrimeField64::new(3), PrimeField64::new(2)], 64).unwrap();
+
+        let updates = updates.lock().unwrap();
+        assert_eq!(updates[0], (ProvingPhase::TraceBuilt, 10));
+        assert_eq!(updates[1], (ProvingPhase::LdeDone, 40));
+        assert_eq!(updates[updates.len() - 1], (ProvingPhase::QueriesAnswered, 100));
+
+        // Layers are reported in order, up to the last
+        let layers: Vec<_> = updates[2..updates.len() - 1].iter().map(|(phase, _)| *phase).collect();
+        assert!(!layers.is_empty());
+        for (k, phase) in layers.iter().enumerate() {
+            assert_eq!(*phase, ProvingPhase::LayerFolded { layer: k + 1, layers: layers.len() });
+        }
+        assert_eq!(updates[updates.len() - 2].1, 90);
+        assert!(updates.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+    }
+}
@@ -1,8 +1,16 @@
 //! Execution Trace Generation
-//! 
+//!
 //! This module provides efficient execution trace generation for STARK proofs.
+//!
+//! [`TraceBuilder`] fills an [`ExecutionTrace`] row by row through named [`Register`]
+//! handles, pads it to a power-of-two length and, with [`TraceBuilder::build_for`],
+//! checks it against an [`Air`] before it reaches the prover, so a broken witness
+//! fails with the step and register at fault instead of a composition error.
 
+use crate::air::{Air, BoundaryType};
+use crate::types::stark::ExecutionTrace;
 use crate::types::FieldElement;
+use std::ops::{Index, IndexMut};
 
 /// Generate execution trace efficiently
 pub fn generate_trace<F: FieldElement>(
@@ -12,4 +20,325 @@ pub fn generate_trace<F: FieldElement>(
 ) -> Vec<Vec<F>> {
     // Placeholder implementation
     vec![]
-}
\ No newline at end of file
+}
+
+/// Handle to a register declared on a [`TraceBuilder`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Register(usize);
+
+impl Register {
+    /// Column index of the register in the trace
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Values of every register at one step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceRow<F: FieldElement> {
+    values: Vec<F>,
+}
+
+impl<F: FieldElement> TraceRow<F> {
+    /// Register values in declaration order
+    #[must_use]
+    pub fn values(&self) -> &[F] {
+        &self.values
+    }
+}
+
+impl<F: FieldElement> Index<Register> for TraceRow<F> {
+    type Output = F;
+
+    fn index(&self, register: Register) -> &F {
+        &self.values[register.0]
+    }
+}
+
+impl<F: FieldElement> IndexMut<Register> for TraceRow<F> {
+    fn index_mut(&mut self, register: Register) -> &mut F {
+        &mut self.values[register.0]
+    }
+}
+
+/// Trace building error types
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TraceError {
+    /// No registers declared or no steps filled
+    #[error("Trace has no registers or no steps")]
+    EmptyTrace,
+
+    /// The AIR has a different number of registers than the trace
+    #[error("AIR has {expected} registers but the trace has {actual}")]
+    RegisterCountMismatch {
+        /// Registers in the AIR
+        expected: usize,
+        /// Registers in the trace
+        actual: usize,
+    },
+
+    /// A transition does not hold between two rows
+    #[error("Transition fails at step {step} on register '{register}': expected {expected}, found {actual}")]
+    TransitionViolated {
+        /// Row the transition starts from
+        step: usize,
+        /// Name of the register whose next value is wrong
+        register: String,
+        /// Value the AIR's transition gives
+        expected: String,
+        /// Value in the trace
+        actual: String,
+    },
+
+    /// A boundary constraint does not hold
+    #[error("Boundary constraint fails at step {step} on register '{register}': expected {expected}, found {actual}")]
+    BoundaryViolated {
+        /// Constrained row
+        step: usize,
+        /// Name of the constrained register
+        register: String,
+        /// Value the constraint requires
+        expected: String,
+        /// Value in the trace
+        actual: String,
+    },
+}
+
+/// Builder for execution traces with named registers
+#[derive(Debug, Clone)]
+pub struct TraceBuilder<F: FieldElement> {
+    /// Register names in column order
+    names: Vec<String>,
+    /// Filled rows
+    rows: Vec<TraceRow<F>>,
+}
+
+impl<F: FieldElement> Default for TraceBuilder<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: FieldElement> TraceBuilder<F> {
+    /// Create an empty trace builder
+    #[must_use]
+    pub fn new() -> Self {
+        Self { names: Vec::new(), rows: Vec::new() }
+    }
+
+    /// Declare a register, the next trace column
+    ///
+    /// Rows already filled get zero in the new column.
+    pub fn register(&mut self, name: &str) -> Register {
+        self.names.push(name.to_string());
+        for row in &mut self.rows {
+            row.values.push(F::zero());
+        }
+        Register(self.names.len() - 1)
+    }
+
+    /// Name a register was declared with
+    #[must_use]
+    pub fn name(&self, register: Register) -> &str {
+        &self.names[register.0]
+    }
+
+    /// Number of declared registers
+    #[must_use]
+    pub fn num_registers(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Number of filled steps, before padding
+    #[must_use]
+    pub fn num_steps(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Append `num_steps` rows, each written by `step_fn(step, row)`
+    ///
+    /// Each row starts as a copy of the row before it (zeros for the first step), so
+    /// `step_fn` only writes the registers that change.
+    pub fn fill(&mut self, num_steps: usize, mut step_fn: impl FnMut(usize, &mut TraceRow<F>)) -> &mut Self {
+        for _ in 0..num_steps {
+            let mut row = self
+                .rows
+                .last()
+                .cloned()
+                .unwrap_or_else(|| TraceRow { values: vec![F::zero(); self.names.len()] });
+            step_fn(self.rows.len(), &mut row);
+            self.rows.push(row);
+        }
+        self
+    }
+
+    /// Row at `step`, if filled
+    #[must_use]
+    pub fn row(&self, step: usize) -> Option<&TraceRow<F>> {
+        self.rows.get(step)
+    }
+
+    /// Trace padded to a power-of-two length by repeating the last row
+    ///
+    /// # Errors
+    ///
+    /// [`TraceError::EmptyTrace`] if no row was added
+    pub fn build(self) -> Result<ExecutionTrace<F>, TraceError> {
+        let mut rows = self.checked_rows()?;
+        let last = rows[rows.len() - 1].clone();
+        rows.resize(rows.len().next_power_of_two(), last);
+        Ok(Self::to_trace(self.names.len(), rows))
+    }
+
+    /// Trace padded by running `air`'s transition, checked against `air`
+    ///
+    /// Every transition and boundary constraint is checked on the padded trace, so
+    /// final boundary constraints apply to its last row.
+    ///
+    /// # Errors
+    ///
+    /// [`TraceError::EmptyTrace`] if no row was added,
+    /// [`TraceError::RegisterCountMismatch`] if `air` has other registers, or the first
+    /// [`TraceError::TransitionViolated`] or [`TraceError::BoundaryViolated`]
+    pub fn build_for(self, air: &Air<F>) -> Result<ExecutionTrace<F>, TraceError> {
+        let mut rows = self.checked_rows()?;
+        if air.num_registers() != self.names.len() {
+            return Err(TraceError::RegisterCountMismatch {
+                expected: air.num_registers(),
+                actual: self.names.len(),
+            });
+        }
+
+        let padded_length = rows.len().next_power_of_two();
+        while rows.len() < padded_length {
+            let step = rows.len() - 1;
+            let values = air.next_state(&rows[step].values, step);
+            rows.push(TraceRow { values });
+        }
+
+        for step in 0..padded_length - 1 {
+            let expected = air.next_state(&rows[step].values, step);
+            let actual = &rows[step + 1].values;
+            if let Some(register) = (0..actual.len()).find(|&register| expected[register] != actual[register]) {
+                return Err(TraceError::TransitionViolated {
+                    step,
+                    register: self.names[register].clone(),
+                    expected: format!("{:?}", expected[register]),
+                    actual: format!("{:?}", actual[register]),
+                });
+            }
+        }
+
+        for constraint in &air.boundary.constraints {
+            let step = match constraint.constraint_type {
+                BoundaryType::Initial => 0,
+                BoundaryType::Final => padded_length - 1,
+            };
+            let name = self.names.get(constraint.register).cloned().unwrap_or_else(|| constraint.register.to_string());
+            let actual = rows[step].values.get(constraint.register);
+            if actual != Some(&constraint.value) {
+                return Err(TraceError::BoundaryViolated {
+                    step,
+                    register: name,
+                    expected: format!("{:?}", constraint.value),
+                    actual: actual.map_or_else(|| "nothing".to_string(), |value| format!("{value:?}")),
+                });
+            }
+        }
+
+        Ok(Self::to_trace(self.names.len(), rows))
+    }
+
+    /// Filled rows, if there are any registers and steps
+    fn checked_rows(&self) -> Result<Vec<TraceRow<F>>, TraceError> {
+        if self.names.is_empty() || self.rows.is_empty() {
+            return Err(TraceError::EmptyTrace);
+        }
+        Ok(self.rows.clone())
+    }
+
+    /// Column-major trace from rows
+    fn to_trace(num_registers: usize, rows: Vec<TraceRow<F>>) -> ExecutionTrace<F> {
+        let length = rows.len();
+        let mut columns = vec![Vec::with_capacity(length); num_registers];
+        for row in rows {
+            for (column, value) in columns.iter_mut().zip(row.values) {
+                column.push(value);
+            }
+        }
+        ExecutionTrace { columns, length, num_registers }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::air::{BoundaryConditions, BoundaryConstraint, TransitionFunction};
+    use crate::proof::{StarkProver, StarkVerifier};
+    use crate::types::field::PrimeField64;
+
+    fn f(value: u64) -> PrimeField64 {
+        PrimeField64::new(value)
+    }
+
+    /// Two registers: x' = x + y, y' = y
+    fn stepper(boundary: Vec<BoundaryConstraint<PrimeField64>>) -> Air<PrimeField64> {
+        let transition = TransitionFunction::new(vec![vec![f(1), f(1)], vec![f(0), f(1)]], 1);
+        Air::new(vec![], transition, BoundaryConditions::new(boundary), 128)
+    }
+
+    fn stepper_builder(num_steps: usize) -> (TraceBuilder<PrimeField64>, Register, Register) {
+        let mut builder = TraceBuilder::new();
+        let x = builder.register("x");
+        let y = builder.register("y");
+        builder.fill(num_steps, |step, row| {
+            if step == 0 {
+                row[x] = f(3);
+                row[y] = f(2);
+            } else {
+                row[x] = row[x] + row[y];
+            }
+        });
+        (builder, x, y)
+    }
+
+    #[test]
+    fn test_build_pads_and_proves() {
+        let air = stepper(vec![BoundaryConstraint::initial(0, f(3)), BoundaryConstraint::final_condition(0, f(17))]);
+        let (builder, x, _) = stepper_builder(5);
+        assert_eq!(builder.row(4).unwrap()[x], f(11));
+
+        let trace = builder.build_for(&air).unwrap();
+        assert_eq!(trace.length, 8);
+        assert_eq!(trace.columns[0], [3, 5, 7, 9, 11, 13, 15, 17].map(f));
+
+        let proof = StarkProver::<PrimeField64>::new(128).prove_execution(&air, trace).unwrap();
+        assert!(StarkVerifier::<PrimeField64>::new(128).verify(&proof).unwrap());
+
+        let plain = stepper_builder(5).0.build().unwrap();
+        assert_eq!(plain.columns[0], [3, 5, 7, 9, 11, 11, 11, 11].map(f));
+    }
+
+    #[test]
+    fn test_build_reports_failing_step_and_register() {
+        let air = stepper(vec![BoundaryConstraint::initial(1, f(4))]);
+        let (mut builder, x, _) = stepper_builder(3);
+        builder.fill(1, |_, row| row[x] = f(100));
+        assert_eq!(
+            builder.clone().build_for(&stepper(vec![])),
+            Err(TraceError::TransitionViolated {
+                step: 2,
+                register: "x".to_string(),
+                expected: format!("{:?}", f(9)),
+                actual: format!("{:?}", f(100)),
+            })
+        );
+
+        let err = stepper_builder(4).0.build_for(&air).unwrap_err();
+        assert!(matches!(err, TraceError::BoundaryViolated { step: 0, ref register, .. } if register == "y"));
+        assert!(err.to_string().contains("register 'y'"));
+
+        assert_eq!(TraceBuilder::<PrimeField64>::new().build(), Err(TraceError::EmptyTrace));
+    }
+}
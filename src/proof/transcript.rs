@@ -0,0 +1,176 @@
+//! Fiat-Shamir Transcript
+//!
+//! The prover and verifier each keep a [`Transcript`]: a running SHA-256 state that
+//! absorbs the public statement and every commitment in protocol order, and squeezes
+//! the challenges that would otherwise come from an interactive verifier. Both sides
+//! absorb the same bytes in the same order, so they derive identical challenges and the
+//! prover cannot choose them.
+//!
+//! Every absorb and squeeze is framed with a [`TranscriptLabel`] naming its protocol
+//! phase, so bytes absorbed in one phase can never be replayed as another's.
+
+use crate::types::FieldElement;
+use sha2::{Digest, Sha256};
+
+/// Protocol label of the STARK prover and verifier transcript
+pub const STARK_PROTOCOL: &[u8] = b"xfg-stark/stark/v1";
+
+/// Protocol label of standalone FRI proofs
+pub const FRI_PROTOCOL: &[u8] = b"xfg-stark/fri/v1";
+
+/// Protocol phase an absorbed value or squeezed challenge belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptLabel {
+    /// Trace shape, transition and boundary constraints
+    PublicInputs,
+    /// Root of the trace commitment
+    TraceCommitment,
+    /// Challenge combining constraint quotients into the composition polynomial
+    CompositionChallenge,
+    /// Commitment to one FRI layer
+    FriLayerCommitment,
+    /// Challenge folding one FRI layer into the next
+    FriFoldingChallenge,
+    /// Domain index of one FRI query
+    FriQueryIndex,
+}
+
+impl TranscriptLabel {
+    /// Bytes framing this label in the transcript
+    #[must_use]
+    pub fn as_bytes(self) -> &'static [u8] {
+        match self {
+            TranscriptLabel::PublicInputs => b"public-inputs",
+            TranscriptLabel::TraceCommitment => b"trace-commitment",
+            TranscriptLabel::CompositionChallenge => b"composition-challenge",
+            TranscriptLabel::FriLayerCommitment => b"fri-layer-commitment",
+            TranscriptLabel::FriFoldingChallenge => b"fri-folding-challenge",
+            TranscriptLabel::FriQueryIndex => b"fri-query-index",
+        }
+    }
+}
+
+/// Running Fiat-Shamir state shared in lockstep by prover and verifier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    state: [u8; 32],
+}
+
+impl Transcript {
+    /// Empty transcript for a protocol
+    #[must_use]
+    pub fn new(protocol: &[u8]) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(b"xfg-transcript");
+        frame(&mut hasher, protocol);
+        Self {
+            state: hasher.finalize().into(),
+        }
+    }
+
+    /// Absorb raw bytes
+    pub fn absorb(&mut self, label: TranscriptLabel, data: &[u8]) {
+        let mut hasher = self.start(b"absorb", label);
+        frame(&mut hasher, data);
+        self.state = hasher.finalize().into();
+    }
+
+    /// Absorb an integer
+    pub fn absorb_u64(&mut self, label: TranscriptLabel, value: u64) {
+        self.absorb(label, &value.to_le_bytes());
+    }
+
+    /// Absorb field elements by their canonical coordinates
+    pub fn absorb_elements<F: FieldElement>(&mut self, label: TranscriptLabel, elements: &[F]) {
+        let mut hasher = self.start(b"absorb", label);
+        hasher.update((elements.len() as u64).to_le_bytes());
+        for element in elements {
+            for value in element.to_base_values() {
+                hasher.update(value.to_le_bytes());
+            }
+        }
+        self.state = hasher.finalize().into();
+    }
+
+    /// Squeeze a field element challenge
+    ///
+    /// Candidates are masked to the modulus bit length and rejected until one is
+    /// canonical, so challenges are uniform over the field. Extension field challenges
+    /// squeeze each prime field coordinate in turn.
+    ///
+    /// # Panics
+    ///
+    /// If `F::from_base_values` rejects canonical coordinates, which is a bug in `F`
+    pub fn challenge<F: FieldElement>(&mut self, label: TranscriptLabel) -> F {
+        let mask = u64::MAX >> F::MODULUS.leading_zeros();
+        let mut values = Vec::with_capacity(F::EXTENSION_DEGREE);
+        while values.len() < F::EXTENSION_DEGREE {
+            let candidate = self.squeeze_u64(label) & mask;
+            if candidate < F::MODULUS {
+                values.push(candidate);
+            }
+        }
+        F::from_base_values(&values).expect("Squeezed coordinates are canonical")
+    }
+
+    /// Squeeze an index below `bound` (which must be nonzero)
+    pub fn challenge_index(&mut self, label: TranscriptLabel, bound: usize) -> usize {
+        usize::try_from(self.squeeze_u64(label) % bound as u64).unwrap_or(usize::MAX)
+    }
+
+    fn squeeze_u64(&mut self, label: TranscriptLabel) -> u64 {
+        self.state = self.start(b"squeeze", label).finalize().into();
+        u64::from_le_bytes(self.state[..8].try_into().unwrap())
+    }
+
+    fn start(&self, operation: &[u8], label: TranscriptLabel) -> Sha256 {
+        let mut hasher = Sha256::new();
+        hasher.update(self.state);
+        hasher.update(operation);
+        frame(&mut hasher, label.as_bytes());
+        hasher
+    }
+}
+
+/// Length-prefix `data` so adjacent fields cannot run into each other
+fn frame(hasher: &mut Sha256, data: &[u8]) {
+    hasher.update((data.len() as u64).to_le_bytes());
+    hasher.update(data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::field::PrimeField64;
+
+    #[test]
+    fn test_matching_transcripts_agree() {
+        let run = |commitment: &[u8]| {
+            let mut transcript = Transcript::new(STARK_PROTOCOL);
+            transcript.absorb_elements(TranscriptLabel::PublicInputs, &[PrimeField64::new(3)]);
+            transcript.absorb(TranscriptLabel::TraceCommitment, commitment);
+            let alpha: PrimeField64 = transcript.challenge(TranscriptLabel::CompositionChallenge);
+            (alpha, transcript.challenge_index(TranscriptLabel::FriQueryIndex, 64))
+        };
+
+        assert_eq!(run(b"root"), run(b"root"));
+        assert_ne!(run(b"root").0, run(b"tooR").0);
+        assert!(run(b"root").1 < 64);
+    }
+
+    #[test]
+    fn test_labels_separate_phases() {
+        let mut first = Transcript::new(STARK_PROTOCOL);
+        let mut second = first.clone();
+        first.absorb(TranscriptLabel::TraceCommitment, b"root");
+        second.absorb(TranscriptLabel::FriLayerCommitment, b"root");
+        assert_ne!(first, second);
+
+        let mut fri = Transcript::new(FRI_PROTOCOL);
+        let mut stark = Transcript::new(STARK_PROTOCOL);
+        assert_ne!(
+            fri.challenge::<PrimeField64>(TranscriptLabel::FriFoldingChallenge),
+            stark.challenge::<PrimeField64>(TranscriptLabel::FriFoldingChallenge)
+        );
+    }
+}
@@ -0,0 +1,1282 @@
+//! Streaming Proof Wire Format
+//!
+//! [`ProofWriter`] and [`ProofReader`] move a [`StarkProof`] to and from any
+//! `io::Write`/`io::Read` one section at a time. A section is written straight from the
+//! proof, so writing a multi-MB proof to a file or socket never builds a second copy of
+//! it in memory, and reading holds at most the proof being rebuilt.
+//!
+//! ## Format
+//!
+//! All integers are little-endian. Field elements are their `u64` canonical coordinates
+//! over the prime field: one for prime field elements, one per degree for extensions.
+//!
+//! | Bytes | Field                    |
+//! |-------|--------------------------|
+//! | 8     | Magic `XFGPROOF`         |
+//! | 2     | Format version           |
+//! | 2     | Reserved (0)             |
+//! | 8     | Field modulus            |
+//! | ...   | Sections, then `End`     |
+//!
+//! Each section is a 1-byte [`SectionTag`], an 8-byte payload length, the payload and an
+//! 8-byte checksum (the first bytes of the payload's SHA-256). Sections appear in tag
+//! order: metadata, AIR, trace header, one section per trace column, one per commitment,
+//! one per FRI layer, the FRI tail (final polynomial, queries, domain, layer openings)
+//! and `End`.
+//! Vectors inside a payload are a `u64` count followed by the items.
+//!
+//! A [`FriProof`] on its own is its layer sections, the FRI tail and `End`; an
+//! [`ExecutionTrace`] is its trace header and column sections. An [`Air`], a
+//! [`MerkleCommitment`] or a [`FriLayer`] on its own is its one section, and a transition
+//! function, boundary conditions, a boundary constraint or a FRI query is one section of
+//! its own tag. None of these has the header.
+//!
+//! Wrap files in `BufWriter`/`BufReader`; the writer issues many small writes.
+//!
+//! ## Untrusted Input
+//!
+//! [`ProofReader`] enforces [`ProofReadLimits`] as it goes: a section's declared length
+//! is checked before any of it is read, counts are checked against the bytes left in
+//! their section, and column, commitment, layer and query counts are capped, so no
+//! input allocates more than the limits allow or makes the reader panic. Builds with
+//! `--cfg fuzzing` export `fuzz::read_proof` as a fuzz target entry point.
+
+use crate::proof::merkle::MultiProof;
+use crate::types::stark::{
+    Air, BoundaryConditions, BoundaryConstraint, Constraint, ConstraintType, ExecutionTrace, FriDomain,
+    FriLayer, FriProof, FriQuery, MerkleCommitment, ProofMetadata, StarkProof, TransitionFunction,
+};
+use crate::types::FieldElement;
+use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
+use thiserror::Error;
+
+/// Magic bytes opening every serialized proof
+pub const PROOF_FORMAT_MAGIC: [u8; 8] = *b"XFGPROOF";
+
+/// Current proof wire format version
+pub const PROOF_FORMAT_VERSION: u16 = 2;
+
+/// Bytes of section checksum
+const CHECKSUM_LEN: usize = 8;
+
+/// Bounds on untrusted serialized proofs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofReadLimits {
+    /// Largest total of section payloads in one proof (bytes)
+    pub max_proof_bytes: u64,
+    /// Largest single section payload (bytes)
+    pub max_section_bytes: u64,
+    /// Most trace columns
+    pub max_columns: usize,
+    /// Most Merkle commitments
+    pub max_commitments: usize,
+    /// Most FRI layers
+    pub max_layers: usize,
+    /// Most FRI queries
+    pub max_queries: usize,
+}
+
+impl Default for ProofReadLimits {
+    fn default() -> Self {
+        Self {
+            max_proof_bytes: 256 * 1024 * 1024,
+            max_section_bytes: 32 * 1024 * 1024,
+            max_columns: 1024,
+            max_commitments: 64,
+            max_layers: 64,
+            max_queries: 1024,
+        }
+    }
+}
+
+/// Section of a serialized proof
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionTag {
+    /// Proof metadata
+    Metadata,
+    /// Constraints, transition and boundary conditions
+    Air,
+    /// Trace length and register and column counts
+    TraceHeader,
+    /// One trace column
+    TraceColumn,
+    /// One Merkle commitment
+    Commitment,
+    /// One FRI layer
+    FriLayer,
+    /// FRI final polynomial, queries and domain
+    FriTail,
+    /// A transition function on its own
+    TransitionFunction,
+    /// Boundary conditions on their own
+    BoundaryConditions,
+    /// A boundary constraint on its own
+    BoundaryConstraint,
+    /// A FRI query on its own
+    FriQuery,
+    /// End of the proof
+    End,
+}
+
+impl SectionTag {
+    fn to_byte(self) -> u8 {
+        match self {
+            SectionTag::Metadata => 1,
+            SectionTag::Air => 2,
+            SectionTag::TraceHeader => 3,
+            SectionTag::TraceColumn => 4,
+            SectionTag::Commitment => 5,
+            SectionTag::FriLayer => 6,
+            SectionTag::FriTail => 7,
+            SectionTag::TransitionFunction => 8,
+            SectionTag::BoundaryConditions => 9,
+            SectionTag::BoundaryConstraint => 10,
+            SectionTag::FriQuery => 11,
+            SectionTag::End => 0xff,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self, ProofFormatError> {
+        match byte {
+            1 => Ok(SectionTag::Metadata),
+            2 => Ok(SectionTag::Air),
+            3 => Ok(SectionTag::TraceHeader),
+            4 => Ok(SectionTag::TraceColumn),
+            5 => Ok(SectionTag::Commitment),
+            6 => Ok(SectionTag::FriLayer),
+            7 => Ok(SectionTag::FriTail),
+            8 => Ok(SectionTag::TransitionFunction),
+            9 => Ok(SectionTag::BoundaryConditions),
+            10 => Ok(SectionTag::BoundaryConstraint),
+            11 => Ok(SectionTag::FriQuery),
+            0xff => Ok(SectionTag::End),
+            other => Err(ProofFormatError::UnknownSection(other)),
+        }
+    }
+}
+
+/// Proof wire format errors
+#[derive(Debug, Error)]
+pub enum ProofFormatError {
+    /// Reading or writing the stream failed
+    #[error("Proof stream I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// Input is not a serialized proof
+    #[error("Not a serialized STARK proof")]
+    BadMagic,
+    /// Proof was written by another format version
+    #[error("Unsupported proof format version {0}")]
+    UnsupportedVersion(u16),
+    /// Proof belongs to a different field
+    #[error("Proof field modulus {found:#x} does not match {expected:#x}")]
+    FieldMismatch {
+        /// Modulus of the field being loaded into
+        expected: u64,
+        /// Modulus recorded in the proof
+        found: u64,
+    },
+    /// Unknown section tag byte
+    #[error("Unknown proof section tag {0}")]
+    UnknownSection(u8),
+    /// A section arrived out of order
+    #[error("Expected {expected:?} section, found {found:?}")]
+    UnexpectedSection {
+        /// Section the format requires here
+        expected: SectionTag,
+        /// Section found
+        found: SectionTag,
+    },
+    /// Section payload does not match its checksum
+    #[error("Checksum mismatch in {0:?} section")]
+    ChecksumMismatch(SectionTag),
+    /// Section payload does not decode
+    #[error("Malformed {section:?} section: {reason}")]
+    Malformed {
+        /// Section being decoded
+        section: SectionTag,
+        /// What is wrong with it
+        reason: String,
+    },
+    /// A value is not a canonical field element
+    #[error("Value in {0:?} section is outside the field")]
+    ValueOutOfField(SectionTag),
+    /// A size or count exceeds its [`ProofReadLimits`] bound
+    #[error("Proof {parameter} {value} exceeds the limit of {max}")]
+    LimitExceeded {
+        /// Parameter name
+        parameter: &'static str,
+        /// Value declared by the input
+        value: u64,
+        /// Configured limit
+        max: u64,
+    },
+    /// Bytes follow the decoded value
+    #[error("{0} trailing bytes after the proof")]
+    TrailingBytes(usize),
+}
+
+/// Error unless `value` is within `max`
+fn check_limit(parameter: &'static str, value: u64, max: u64) -> Result<(), ProofFormatError> {
+    if value > max {
+        return Err(ProofFormatError::LimitExceeded { parameter, value, max });
+    }
+    Ok(())
+}
+
+/// Writes proofs to a byte stream
+#[derive(Debug)]
+pub struct ProofWriter<W: Write> {
+    out: W,
+}
+
+impl<W: Write> ProofWriter<W> {
+    /// Writer over `out`
+    pub fn new(out: W) -> Self {
+        Self { out }
+    }
+
+    /// Write one proof, header to `End` section
+    ///
+    /// # Errors
+    ///
+    /// [`ProofFormatError::Io`] if the stream cannot be written
+    pub fn write_proof<F: FieldElement>(&mut self, proof: &StarkProof<F>) -> Result<(), ProofFormatError> {
+        self.out.write_all(&PROOF_FORMAT_MAGIC)?;
+        self.out.write_all(&PROOF_FORMAT_VERSION.to_le_bytes())?;
+        self.out.write_all(&0u16.to_le_bytes())?;
+        self.out.write_all(&F::MODULUS.to_le_bytes())?;
+
+        // Small sections are encoded up front
+        self.write_buffered(SectionTag::Metadata, |buffer| encode_metadata(buffer, &proof.metadata))?;
+        self.write_air(&proof.air)?;
+
+        // Large sections stream from the proof
+        self.write_trace(&proof.trace)?;
+        for commitment in &proof.commitments {
+            self.write_commitment(commitment)?;
+        }
+        self.write_fri_proof(&proof.fri_proof)
+    }
+
+    /// Write a trace: its header section and one section per column
+    ///
+    /// # Errors
+    ///
+    /// [`ProofFormatError::Io`] if the stream cannot be written
+    pub fn write_trace<F: FieldElement>(&mut self, trace: &ExecutionTrace<F>) -> Result<(), ProofFormatError> {
+        self.write_buffered(SectionTag::TraceHeader, |buffer| {
+            put_u64(buffer, trace.length as u64)?;
+            put_u64(buffer, trace.num_registers as u64)?;
+            put_u64(buffer, trace.columns.len() as u64)
+        })?;
+        for column in &trace.columns {
+            let mut section = SectionWriter::start(&mut self.out, SectionTag::TraceColumn, elements_len(column))?;
+            put_elements(&mut section, column)?;
+            section.finish()?;
+        }
+        Ok(())
+    }
+
+    /// Write one AIR section
+    ///
+    /// # Errors
+    ///
+    /// [`ProofFormatError::Io`] if the stream cannot be written
+    pub fn write_air<F: FieldElement>(&mut self, air: &Air<F>) -> Result<(), ProofFormatError> {
+        self.write_buffered(SectionTag::Air, |buffer| encode_air(buffer, air))
+    }
+
+    /// Write one transition function section
+    ///
+    /// # Errors
+    ///
+    /// [`ProofFormatError::Io`] if the stream cannot be written
+    pub fn write_transition<F: FieldElement>(&mut self, transition: &TransitionFunction<F>) -> Result<(), ProofFormatError> {
+        self.write_buffered(SectionTag::TransitionFunction, |buffer| encode_transition(buffer, transition))
+    }
+
+    /// Write one boundary conditions section
+    ///
+    /// # Errors
+    ///
+    /// [`ProofFormatError::Io`] if the stream cannot be written
+    pub fn write_boundary_conditions<F: FieldElement>(
+        &mut self,
+        boundary: &BoundaryConditions<F>,
+    ) -> Result<(), ProofFormatError> {
+        self.write_buffered(SectionTag::BoundaryConditions, |buffer| encode_boundary(buffer, boundary))
+    }
+
+    /// Write one boundary constraint section
+    ///
+    /// # Errors
+    ///
+    /// [`ProofFormatError::Io`] if the stream cannot be written
+    pub fn write_boundary_constraint<F: FieldElement>(
+        &mut self,
+        constraint: &BoundaryConstraint<F>,
+    ) -> Result<(), ProofFormatError> {
+        self.write_buffered(SectionTag::BoundaryConstraint, |buffer| encode_boundary_constraint(buffer, constraint))
+    }
+
+    /// Write one Merkle commitment section
+    ///
+    /// # Errors
+    ///
+    /// [`ProofFormatError::Io`] if the stream cannot be written
+    pub fn write_commitment<F: FieldElement>(&mut self, commitment: &MerkleCommitment<F>) -> Result<(), ProofFormatError> {
+        let len = bytes_len(&commitment.root) + 8 + elements_len(&commitment.leaves);
+        let mut section = SectionWriter::start(&mut self.out, SectionTag::Commitment, len)?;
+        put_bytes(&mut section, &commitment.root)?;
+        put_u64(&mut section, commitment.depth as u64)?;
+        put_elements(&mut section, &commitment.leaves)?;
+        section.finish()
+    }
+
+    /// Write a FRI proof: its layer sections, the FRI tail and `End`
+    ///
+    /// # Errors
+    ///
+    /// [`ProofFormatError::Io`] if the stream cannot be written
+    pub fn write_fri_proof<F: FieldElement>(&mut self, fri_proof: &FriProof<F>) -> Result<(), ProofFormatError> {
+        for layer in &fri_proof.layers {
+            self.write_fri_layer(layer)?;
+        }
+
+        self.write_buffered(SectionTag::FriTail, |buffer| encode_fri_tail(buffer, fri_proof))?;
+        SectionWriter::start(&mut self.out, SectionTag::End, 0)?.finish()?;
+        self.out.flush()?;
+        Ok(())
+    }
+
+    /// Write one FRI layer section
+    ///
+    /// # Errors
+    ///
+    /// [`ProofFormatError::Io`] if the stream cannot be written
+    pub fn write_fri_layer<F: FieldElement>(&mut self, layer: &FriLayer<F>) -> Result<(), ProofFormatError> {
+        let len = bytes_len(&layer.commitment) + 8 + elements_len(&layer.polynomial);
+        let mut section = SectionWriter::start(&mut self.out, SectionTag::FriLayer, len)?;
+        put_bytes(&mut section, &layer.commitment)?;
+        put_u64(&mut section, layer.degree as u64)?;
+        put_elements(&mut section, &layer.polynomial)?;
+        section.finish()
+    }
+
+    /// Write one FRI query section
+    ///
+    /// # Errors
+    ///
+    /// [`ProofFormatError::Io`] if the stream cannot be written
+    pub fn write_fri_query<F: FieldElement>(&mut self, query: &FriQuery<F>) -> Result<(), ProofFormatError> {
+        self.write_buffered(SectionTag::FriQuery, |buffer| encode_fri_query(buffer, query))
+    }
+
+    /// The underlying stream
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    fn write_buffered(
+        &mut self,
+        tag: SectionTag,
+        encode: impl FnOnce(&mut Vec<u8>) -> std::io::Result<()>,
+    ) -> Result<(), ProofFormatError> {
+        let mut buffer = Vec::new();
+        encode(&mut buffer)?;
+        let mut section = SectionWriter::start(&mut self.out, tag, buffer.len() as u64)?;
+        section.write_all(&buffer)?;
+        section.finish()
+    }
+}
+
+/// Tag and declared payload length of a section
+#[derive(Debug, Clone, Copy)]
+struct SectionHeader {
+    tag: SectionTag,
+    len: u64,
+}
+
+/// Reads proofs from a byte stream
+#[derive(Debug)]
+pub struct ProofReader<R: Read> {
+    input: R,
+    limits: ProofReadLimits,
+    /// Payload bytes the value being read may still declare
+    budget: u64,
+}
+
+impl<R: Read> ProofReader<R> {
+    /// Reader over `input` with the default limits
+    pub fn new(input: R) -> Self {
+        Self::with_limits(input, ProofReadLimits::default())
+    }
+
+    /// Reader over `input` enforcing `limits`
+    pub fn with_limits(input: R, limits: ProofReadLimits) -> Self {
+        Self {
+            input,
+            limits,
+            budget: limits.max_proof_bytes,
+        }
+    }
+
+    /// Limits the reader enforces
+    pub fn limits(&self) -> &ProofReadLimits {
+        &self.limits
+    }
+
+    /// Read one proof, header to `End` section
+    ///
+    /// # Errors
+    ///
+    /// If the input is not a proof over `F` in this format version, a section is
+    /// malformed or fails its checksum, or the proof exceeds the reader's limits
+    pub fn read_proof<F: FieldElement>(&mut self) -> Result<StarkProof<F>, ProofFormatError> {
+        self.budget = self.limits.max_proof_bytes;
+        let mut header = [0u8; 20];
+        self.input.read_exact(&mut header)?;
+        if header[..8] != PROOF_FORMAT_MAGIC {
+            return Err(ProofFormatError::BadMagic);
+        }
+        let version = u16::from_le_bytes([header[8], header[9]]);
+        if version != PROOF_FORMAT_VERSION {
+            return Err(ProofFormatError::UnsupportedVersion(version));
+        }
+        let mut modulus = [0u8; 8];
+        modulus.copy_from_slice(&header[12..20]);
+        let modulus = u64::from_le_bytes(modulus);
+        if modulus != F::MODULUS {
+            return Err(ProofFormatError::FieldMismatch {
+                expected: F::MODULUS,
+                found: modulus,
+            });
+        }
+
+        let mut section = self.expect(SectionTag::Metadata)?;
+        let metadata = decode_metadata(&mut section)?;
+        section.finish()?;
+
+        let mut section = self.expect(SectionTag::Air)?;
+        let air = decode_air(&mut section)?;
+        section.finish()?;
+
+        let trace = self.read_trace_sections()?;
+
+        let mut commitments = Vec::new();
+        let mut header = self.next_header()?;
+        while header.tag == SectionTag::Commitment {
+            check_limit("commitment count", commitments.len() as u64 + 1, self.limits.max_commitments as u64)?;
+            let mut section = self.open(header);
+            commitments.push(decode_commitment(&mut section)?);
+            section.finish()?;
+            header = self.next_header()?;
+        }
+        let fri_proof = self.read_fri_sections(header)?;
+
+        Ok(StarkProof {
+            trace,
+            air,
+            commitments,
+            fri_proof,
+            metadata,
+        })
+    }
+
+    /// Read a trace: its header section and one section per column
+    ///
+    /// # Errors
+    ///
+    /// If the input is not a trace header followed by its columns, a column length
+    /// differs from the header, or a section exceeds the reader's limits
+    pub fn read_trace<F: FieldElement>(&mut self) -> Result<ExecutionTrace<F>, ProofFormatError> {
+        self.budget = self.limits.max_proof_bytes;
+        self.read_trace_sections()
+    }
+
+    /// Read one AIR section
+    ///
+    /// # Errors
+    ///
+    /// If the input is not an AIR section or exceeds the reader's limits
+    pub fn read_air<F: FieldElement>(&mut self) -> Result<Air<F>, ProofFormatError> {
+        self.read_single(SectionTag::Air, decode_air)
+    }
+
+    /// Read one transition function section
+    ///
+    /// # Errors
+    ///
+    /// If the input is not a transition function section or exceeds the reader's limits
+    pub fn read_transition<F: FieldElement>(&mut self) -> Result<TransitionFunction<F>, ProofFormatError> {
+        self.read_single(SectionTag::TransitionFunction, decode_transition)
+    }
+
+    /// Read one boundary conditions section
+    ///
+    /// # Errors
+    ///
+    /// If the input is not a boundary conditions section or exceeds the reader's limits
+    pub fn read_boundary_conditions<F: FieldElement>(&mut self) -> Result<BoundaryConditions<F>, ProofFormatError> {
+        self.read_single(SectionTag::BoundaryConditions, decode_boundary)
+    }
+
+    /// Read one boundary constraint section
+    ///
+    /// # Errors
+    ///
+    /// If the input is not a boundary constraint section or exceeds the reader's limits
+    pub fn read_boundary_constraint<F: FieldElement>(&mut self) -> Result<BoundaryConstraint<F>, ProofFormatError> {
+        self.read_single(SectionTag::BoundaryConstraint, decode_boundary_constraint)
+    }
+
+    /// Read one FRI layer section
+    ///
+    /// # Errors
+    ///
+    /// If the input is not a FRI layer section or exceeds the reader's limits
+    pub fn read_fri_layer<F: FieldElement>(&mut self) -> Result<FriLayer<F>, ProofFormatError> {
+        self.read_single(SectionTag::FriLayer, decode_fri_layer)
+    }
+
+    /// Read one FRI query section
+    ///
+    /// # Errors
+    ///
+    /// If the input is not a FRI query section or exceeds the reader's limits
+    pub fn read_fri_query<F: FieldElement>(&mut self) -> Result<FriQuery<F>, ProofFormatError> {
+        self.read_single(SectionTag::FriQuery, decode_fri_query)
+    }
+
+    /// Trace header and column sections
+    fn read_trace_sections<F: FieldElement>(&mut self) -> Result<ExecutionTrace<F>, ProofFormatError> {
+        let mut section = self.expect(SectionTag::TraceHeader)?;
+        let length = section.usize()?;
+        let num_registers = section.usize()?;
+        let num_columns = section.usize()?;
+        section.finish()?;
+
+        // Proofs without their trace carry no columns
+        check_limit("trace column count", num_columns as u64, self.limits.max_columns as u64)?;
+        if num_columns != 0 && num_columns != num_registers {
+            return Err(ProofFormatError::Malformed {
+                section: SectionTag::TraceHeader,
+                reason: format!("{num_columns} columns for {num_registers} registers"),
+            });
+        }
+        let mut columns = Vec::new();
+        for _ in 0..num_columns {
+            let mut section = self.expect(SectionTag::TraceColumn)?;
+            let column: Vec<F> = section.elements()?;
+            if column.len() != length {
+                return Err(section.malformed("column length differs from the trace length"));
+            }
+            columns.push(column);
+            section.finish()?;
+        }
+        Ok(ExecutionTrace {
+            columns,
+            length,
+            num_registers,
+        })
+    }
+
+    /// Read one Merkle commitment section
+    ///
+    /// # Errors
+    ///
+    /// If the input is not a commitment section or exceeds the reader's limits
+    pub fn read_commitment<F: FieldElement>(&mut self) -> Result<MerkleCommitment<F>, ProofFormatError> {
+        self.read_single(SectionTag::Commitment, decode_commitment)
+    }
+
+    /// One `tag` section on its own, decoded with `decode`
+    fn read_single<T>(
+        &mut self,
+        tag: SectionTag,
+        decode: impl FnOnce(&mut SectionReader<'_, R>) -> Result<T, ProofFormatError>,
+    ) -> Result<T, ProofFormatError> {
+        self.budget = self.limits.max_proof_bytes;
+        let mut section = self.expect(tag)?;
+        let value = decode(&mut section)?;
+        section.finish()?;
+        Ok(value)
+    }
+
+    /// Read a FRI proof: its layer sections, the FRI tail and `End`
+    ///
+    /// # Errors
+    ///
+    /// If the input is not a FRI proof over `F`, a section is malformed or fails its
+    /// checksum, or the proof exceeds the reader's limits
+    pub fn read_fri_proof<F: FieldElement>(&mut self) -> Result<FriProof<F>, ProofFormatError> {
+        self.budget = self.limits.max_proof_bytes;
+        let header = self.next_header()?;
+        self.read_fri_sections(header)
+    }
+
+    /// The underlying stream
+    pub fn into_inner(self) -> R {
+        self.input
+    }
+
+    /// FRI layers starting at the section `header` announces, the FRI tail and `End`
+    fn read_fri_sections<F: FieldElement>(&mut self, mut header: SectionHeader) -> Result<FriProof<F>, ProofFormatError> {
+        let mut layers = Vec::new();
+        while header.tag == SectionTag::FriLayer {
+            check_limit("FRI layer count", layers.len() as u64 + 1, self.limits.max_layers as u64)?;
+            let mut section = self.open(header);
+            layers.push(decode_fri_layer(&mut section)?);
+            section.finish()?;
+            header = self.next_header()?;
+        }
+        if header.tag != SectionTag::FriTail {
+            return Err(ProofFormatError::UnexpectedSection {
+                expected: SectionTag::FriTail,
+                found: header.tag,
+            });
+        }
+        let limits = self.limits;
+        let mut section = self.open(header);
+        let fri_proof = decode_fri_tail(&mut section, layers, &limits)?;
+        section.finish()?;
+        self.expect(SectionTag::End)?.finish()?;
+        Ok(fri_proof)
+    }
+
+    /// Read a section's tag and length, checking the length before any payload is read
+    fn next_header(&mut self) -> Result<SectionHeader, ProofFormatError> {
+        let mut tag = [0u8; 1];
+        let mut len = [0u8; 8];
+        self.input.read_exact(&mut tag)?;
+        self.input.read_exact(&mut len)?;
+        let len = u64::from_le_bytes(len);
+        check_limit("section length", len, self.limits.max_section_bytes)?;
+        self.budget = self.budget.checked_sub(len).ok_or(ProofFormatError::LimitExceeded {
+            parameter: "size",
+            value: self.limits.max_proof_bytes - self.budget + len,
+            max: self.limits.max_proof_bytes,
+        })?;
+        Ok(SectionHeader {
+            tag: SectionTag::from_byte(tag[0])?,
+            len,
+        })
+    }
+
+    fn open(&mut self, header: SectionHeader) -> SectionReader<'_, R> {
+        SectionReader {
+            input: &mut self.input,
+            tag: header.tag,
+            remaining: header.len,
+            hasher: Sha256::new(),
+        }
+    }
+
+    fn next_section(&mut self) -> Result<SectionReader<'_, R>, ProofFormatError> {
+        let header = self.next_header()?;
+        Ok(self.open(header))
+    }
+
+    fn expect(&mut self, expected: SectionTag) -> Result<SectionReader<'_, R>, ProofFormatError> {
+        let section = self.next_section()?;
+        if section.tag != expected {
+            return Err(ProofFormatError::UnexpectedSection {
+                expected,
+                found: section.tag,
+            });
+        }
+        Ok(section)
+    }
+}
+
+/// Section payload sink that checksums what passes through it
+struct SectionWriter<'a, W: Write> {
+    out: &'a mut W,
+    tag: SectionTag,
+    remaining: u64,
+    hasher: Sha256,
+}
+
+impl<'a, W: Write> SectionWriter<'a, W> {
+    fn start(out: &'a mut W, tag: SectionTag, len: u64) -> std::io::Result<Self> {
+        out.write_all(&[tag.to_byte()])?;
+        out.write_all(&len.to_le_bytes())?;
+        Ok(Self {
+            out,
+            tag,
+            remaining: len,
+            hasher: Sha256::new(),
+        })
+    }
+
+    fn finish(self) -> Result<(), ProofFormatError> {
+        if self.remaining != 0 {
+            return Err(ProofFormatError::Malformed {
+                section: self.tag,
+                reason: format!("{} bytes short of its declared length", self.remaining),
+            });
+        }
+        self.out.write_all(&self.hasher.finalize()[..CHECKSUM_LEN])?;
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for SectionWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if buf.len() as u64 > self.remaining {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "section payload exceeds its declared length",
+            ));
+        }
+        self.out.write_all(buf)?;
+        self.hasher.update(buf);
+        self.remaining -= buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.out.flush()
+    }
+}
+
+/// Section payload source that checksums what it reads
+struct SectionReader<'a, R: Read> {
+    input: &'a mut R,
+    tag: SectionTag,
+    remaining: u64,
+    hasher: Sha256,
+}
+
+impl<R: Read> SectionReader<'_, R> {
+    fn malformed(&self, reason: &str) -> ProofFormatError {
+        ProofFormatError::Malformed {
+            section: self.tag,
+            reason: reason.to_string(),
+        }
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], ProofFormatError> {
+        if (N as u64) > self.remaining {
+            return Err(self.malformed("payload ends early"));
+        }
+        let mut bytes = [0u8; N];
+        self.input.read_exact(&mut bytes)?;
+        self.hasher.update(bytes);
+        self.remaining -= N as u64;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, ProofFormatError> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, ProofFormatError> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, ProofFormatError> {
+        Ok(u64::from_le_bytes(self.take()?))
+    }
+
+    fn usize(&mut self) -> Result<usize, ProofFormatError> {
+        let value = self.u64()?;
+        usize::try_from(value).map_err(|_| self.malformed("count does not fit in usize"))
+    }
+
+    /// Item count, checked against the bytes left so a bad count cannot force a large allocation
+    fn count(&mut self, item_len: u64) -> Result<usize, ProofFormatError> {
+        let count = self.usize()?;
+        if (count as u64).saturating_mul(item_len) > self.remaining {
+            return Err(self.malformed("count exceeds payload"));
+        }
+        Ok(count)
+    }
+
+    fn bytes(&mut self) -> Result<Vec<u8>, ProofFormatError> {
+        let len = self.count(1)?;
+        let mut bytes = vec![0u8; len];
+        self.input.read_exact(&mut bytes)?;
+        self.hasher.update(&bytes);
+        self.remaining -= len as u64;
+        Ok(bytes)
+    }
+
+    fn element<F: FieldElement>(&mut self) -> Result<F, ProofFormatError> {
+        let values = (0..F::EXTENSION_DEGREE).map(|_| self.u64()).collect::<Result<Vec<_>, _>>()?;
+        F::from_base_values(&values).ok_or(ProofFormatError::ValueOutOfField(self.tag))
+    }
+
+    fn elements<F: FieldElement>(&mut self) -> Result<Vec<F>, ProofFormatError> {
+        let count = self.count(8 * F::EXTENSION_DEGREE as u64)?;
+        (0..count).map(|_| self.element()).collect()
+    }
+
+    fn finish(mut self) -> Result<(), ProofFormatError> {
+        if self.remaining != 0 {
+            return Err(self.malformed("trailing bytes"));
+        }
+        let mut checksum = [0u8; CHECKSUM_LEN];
+        self.input.read_exact(&mut checksum)?;
+        if checksum[..] != self.hasher.finalize_reset()[..CHECKSUM_LEN] {
+            return Err(ProofFormatError::ChecksumMismatch(self.tag));
+        }
+        Ok(())
+    }
+}
+
+fn put_u64(out: &mut impl Write, value: u64) -> std::io::Result<()> {
+    out.write_all(&value.to_le_bytes())
+}
+
+fn put_bytes(out: &mut impl Write, bytes: &[u8]) -> std::io::Result<()> {
+    put_u64(out, bytes.len() as u64)?;
+    out.write_all(bytes)
+}
+
+fn put_elements<F: FieldElement>(out: &mut impl Write, elements: &[F]) -> std::io::Result<()> {
+    put_u64(out, elements.len() as u64)?;
+    for element in elements {
+        put_element(out, element)?;
+    }
+    Ok(())
+}
+
+fn put_element<F: FieldElement>(out: &mut impl Write, element: &F) -> std::io::Result<()> {
+    for value in element.to_base_values() {
+        put_u64(out, value)?;
+    }
+    Ok(())
+}
+
+fn bytes_len(bytes: &[u8]) -> u64 {
+    8 + bytes.len() as u64
+}
+
+fn elements_len<F: FieldElement>(elements: &[F]) -> u64 {
+    8 + 8 * (F::EXTENSION_DEGREE * elements.len()) as u64
+}
+
+fn encode_metadata(out: &mut Vec<u8>, metadata: &ProofMetadata) -> std::io::Result<()> {
+    out.write_all(&metadata.version.to_le_bytes())?;
+    out.write_all(&metadata.security_parameter.to_le_bytes())?;
+    put_bytes(out, metadata.field_modulus.as_bytes())?;
+    put_u64(out, metadata.proof_size as u64)?;
+    put_u64(out, metadata.timestamp)
+}
+
+fn decode_metadata<R: Read>(section: &mut SectionReader<'_, R>) -> Result<ProofMetadata, ProofFormatError> {
+    Ok(ProofMetadata {
+        version: section.u32()?,
+        security_parameter: section.u32()?,
+        field_modulus: String::from_utf8(section.bytes()?)
+            .map_err(|_| section.malformed("field modulus is not UTF-8"))?,
+        proof_size: section.usize()?,
+        timestamp: section.u64()?,
+    })
+}
+
+fn constraint_type_byte(constraint_type: &ConstraintType) -> u8 {
+    match constraint_type {
+        ConstraintType::Transition => 0,
+        ConstraintType::Boundary => 1,
+        ConstraintType::Algebraic => 2,
+    }
+}
+
+fn encode_air<F: FieldElement>(out: &mut Vec<u8>, air: &Air<F>) -> std::io::Result<()> {
+    out.write_all(&air.security_parameter.to_le_bytes())?;
+    put_u64(out, air.constraints.len() as u64)?;
+    for constraint in &air.constraints {
+        out.write_all(&[constraint_type_byte(&constraint.constraint_type)])?;
+        put_u64(out, constraint.degree as u64)?;
+        put_elements(out, &constraint.polynomial)?;
+    }
+    encode_transition(out, &air.transition)?;
+    encode_boundary(out, &air.boundary)
+}
+
+fn encode_transition<F: FieldElement>(out: &mut Vec<u8>, transition: &TransitionFunction<F>) -> std::io::Result<()> {
+    put_u64(out, transition.degree as u64)?;
+    put_u64(out, transition.coefficients.len() as u64)?;
+    for row in &transition.coefficients {
+        put_elements(out, row)?;
+    }
+    Ok(())
+}
+
+fn encode_boundary<F: FieldElement>(out: &mut Vec<u8>, boundary: &BoundaryConditions<F>) -> std::io::Result<()> {
+    put_u64(out, boundary.constraints.len() as u64)?;
+    for constraint in &boundary.constraints {
+        encode_boundary_constraint(out, constraint)?;
+    }
+    Ok(())
+}
+
+fn encode_boundary_constraint<F: FieldElement>(out: &mut Vec<u8>, constraint: &BoundaryConstraint<F>) -> std::io::Result<()> {
+    put_u64(out, constraint.register as u64)?;
+    put_u64(out, constraint.step as u64)?;
+    put_element(out, &constraint.value)
+}
+
+fn decode_air<F: FieldElement, R: Read>(section: &mut SectionReader<'_, R>) -> Result<Air<F>, ProofFormatError> {
+    let security_parameter = section.u32()?;
+    let mut constraints = Vec::new();
+    for _ in 0..section.count(17)? {
+        let constraint_type = match section.u8()? {
+            0 => ConstraintType::Transition,
+            1 => ConstraintType::Boundary,
+            2 => ConstraintType::Algebraic,
+            _ => return Err(section.malformed("unknown constraint type")),
+        };
+        constraints.push(Constraint {
+            degree: section.usize()?,
+            polynomial: section.elements()?,
+            constraint_type,
+        });
+    }
+    Ok(Air {
+        constraints,
+        transition: decode_transition(section)?,
+        boundary: decode_boundary(section)?,
+        security_parameter,
+    })
+}
+
+fn decode_transition<F: FieldElement, R: Read>(
+    section: &mut SectionReader<'_, R>,
+) -> Result<TransitionFunction<F>, ProofFormatError> {
+    let degree = section.usize()?;
+    let mut coefficients = Vec::new();
+    for _ in 0..section.count(8)? {
+        coefficients.push(section.elements()?);
+    }
+    Ok(TransitionFunction { coefficients, degree })
+}
+
+fn decode_boundary<F: FieldElement, R: Read>(
+    section: &mut SectionReader<'_, R>,
+) -> Result<BoundaryConditions<F>, ProofFormatError> {
+    let mut constraints = Vec::new();
+    for _ in 0..section.count(24)? {
+        constraints.push(decode_boundary_constraint(section)?);
+    }
+    Ok(BoundaryConditions { constraints })
+}
+
+fn decode_boundary_constraint<F: FieldElement, R: Read>(
+    section: &mut SectionReader<'_, R>,
+) -> Result<BoundaryConstraint<F>, ProofFormatError> {
+    Ok(BoundaryConstraint {
+        register: section.usize()?,
+        step: section.usize()?,
+        value: section.element()?,
+    })
+}
+
+fn encode_fri_tail<F: FieldElement>(out: &mut Vec<u8>, fri_proof: &FriProof<F>) -> std::io::Result<()> {
+    put_elements(out, &fri_proof.final_polynomial)?;
+    put_u64(out, fri_proof.queries.len() as u64)?;
+    for query in &fri_proof.queries {
+        encode_fri_query(out, query)?;
+    }
+    put_element(out, &fri_proof.domain.generator)?;
+    put_element(out, &fri_proof.domain.offset)?;
+    put_u64(out, fri_proof.domain.size as u64)?;
+    put_u64(out, fri_proof.openings.len() as u64)?;
+    for opening in &fri_proof.openings {
+        put_u64(out, opening.leaf_indices.len() as u64)?;
+        for &index in &opening.leaf_indices {
+            put_u64(out, index as u64)?;
+        }
+        put_u64(out, opening.num_leaves as u64)?;
+        put_u64(out, opening.nodes.len() as u64)?;
+        for node in &opening.nodes {
+            out.write_all(node)?;
+        }
+    }
+    Ok(())
+}
+
+fn encode_fri_query<F: FieldElement>(out: &mut Vec<u8>, query: &FriQuery<F>) -> std::io::Result<()> {
+    put_element(out, &query.point)?;
+    put_elements(out, &query.responses)
+}
+
+fn decode_commitment<F: FieldElement, R: Read>(
+    section: &mut SectionReader<'_, R>,
+) -> Result<MerkleCommitment<F>, ProofFormatError> {
+    Ok(MerkleCommitment {
+        root: section.bytes()?,
+        depth: section.usize()?,
+        leaves: section.elements()?,
+    })
+}
+
+fn decode_fri_layer<F: FieldElement, R: Read>(section: &mut SectionReader<'_, R>) -> Result<FriLayer<F>, ProofFormatError> {
+    Ok(FriLayer {
+        commitment: section.bytes()?,
+        degree: section.usize()?,
+        polynomial: section.elements()?,
+    })
+}
+
+fn decode_fri_query<F: FieldElement, R: Read>(section: &mut SectionReader<'_, R>) -> Result<FriQuery<F>, ProofFormatError> {
+    Ok(FriQuery {
+        point: section.element()?,
+        responses: section.elements()?,
+    })
+}
+
+fn decode_fri_tail<F: FieldElement, R: Read>(
+    section: &mut SectionReader<'_, R>,
+    layers: Vec<FriLayer<F>>,
+    limits: &ProofReadLimits,
+) -> Result<FriProof<F>, ProofFormatError> {
+    let final_polynomial = section.elements()?;
+    let num_queries = section.count(16)?;
+    check_limit("FRI query count", num_queries as u64, limits.max_queries as u64)?;
+    let mut queries = Vec::new();
+    for _ in 0..num_queries {
+        queries.push(decode_fri_query(section)?);
+    }
+    let generator = section.element()?;
+    let offset = section.element()?;
+    let size = section.usize()?;
+    let num_openings = section.count(24)?;
+    check_limit("FRI opening count", num_openings as u64, limits.max_layers as u64)?;
+    let mut openings = Vec::new();
+    for _ in 0..num_openings {
+        let num_indices = section.count(8)?;
+        let leaf_indices = (0..num_indices).map(|_| section.usize()).collect::<Result<_, _>>()?;
+        let num_leaves = section.usize()?;
+        let num_nodes = section.count(32)?;
+        let nodes = (0..num_nodes).map(|_| section.take::<32>()).collect::<Result<_, _>>()?;
+        openings.push(MultiProof { leaf_indices, num_leaves, nodes });
+    }
+    Ok(FriProof {
+        layers,
+        final_polynomial,
+        queries,
+        openings,
+        domain: FriDomain::new(size, generator, offset),
+    })
+}
+
+/// Decode one value from `bytes` with default limits, rejecting trailing bytes
+///
+/// # Errors
+///
+/// The error `read` returns, or [`ProofFormatError::TrailingBytes`] if it leaves bytes
+/// unread
+pub fn read_from_slice<'a, T>(
+    bytes: &'a [u8],
+    read: impl FnOnce(&mut ProofReader<&'a [u8]>) -> Result<T, ProofFormatError>,
+) -> Result<T, ProofFormatError> {
+    let mut reader = ProofReader::new(bytes);
+    let value = read(&mut reader)?;
+    match reader.into_inner().len() {
+        0 => Ok(value),
+        trailing => Err(ProofFormatError::TrailingBytes(trailing)),
+    }
+}
+
+/// Encode one value into a fresh buffer
+///
+/// # Panics
+///
+/// If `write` fails, which the [`ProofWriter`] methods never do on a `Vec`
+pub fn write_to_vec(write: impl FnOnce(&mut ProofWriter<Vec<u8>>) -> Result<(), ProofFormatError>) -> Vec<u8> {
+    let mut writer = ProofWriter::new(Vec::new());
+    // Sections are written with the lengths they declare, and a Vec never fails to grow
+    write(&mut writer).expect("writing a proof to memory cannot fail");
+    writer.into_inner()
+}
+
+/// Decode arbitrary bytes as every proof component and check what decodes re-encodes to it
+#[cfg(any(test, fuzzing))]
+fn decode_untrusted(data: &[u8]) {
+    use crate::types::field::{PrimeField64, QuadExtension};
+    use crate::types::StarkComponent;
+
+    fn round_trip<T: StarkComponent<PrimeField64>>(data: &[u8]) {
+        if let Ok(value) = T::from_bytes(data) {
+            assert_eq!(T::from_bytes(&value.to_bytes()).as_ref(), Ok(&value));
+        }
+    }
+    round_trip::<StarkProof<PrimeField64>>(data);
+    round_trip::<FriProof<PrimeField64>>(data);
+    round_trip::<MerkleCommitment<PrimeField64>>(data);
+    round_trip::<ExecutionTrace<PrimeField64>>(data);
+    round_trip::<Air<PrimeField64>>(data);
+    round_trip::<TransitionFunction<PrimeField64>>(data);
+    round_trip::<BoundaryConditions<PrimeField64>>(data);
+    round_trip::<BoundaryConstraint<PrimeField64>>(data);
+    round_trip::<FriLayer<PrimeField64>>(data);
+    round_trip::<FriQuery<PrimeField64>>(data);
+    let _ = read_from_slice(data, |reader| reader.read_proof::<QuadExtension<PrimeField64>>());
+}
+
+/// Fuzz target entry points, built with `RUSTFLAGS="--cfg fuzzing"` (as `cargo fuzz` does)
+#[cfg(fuzzing)]
+pub mod fuzz {
+    /// Decode `data` as a proof, a FRI proof and a commitment; panics only on a decoder bug
+    pub fn read_proof(data: &[u8]) {
+        super::decode_untrusted(data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proof::StarkProver;
+    use crate::types::field::PrimeField64;
+
+    fn sample_proof() -> StarkProof<PrimeField64> {
+        let transition = crate::air::TransitionFunction::new(vec![vec![PrimeField64::one()]], 1);
+        let air = crate::air::Air::new(vec![], transition, crate::air::BoundaryConditions::new(vec![]), 128);
+        let mut proof = StarkProver::<PrimeField64>::new(128)
+            .prove(&air, &[PrimeField64::new(5)], 16)
+            .unwrap();
+
+        // The prover carries no constraint polynomials; add one so every AIR field has content
+        proof.air.constraints.push(Constraint {
+            polynomial: vec![PrimeField64::new(1), PrimeField64::new(PrimeField64::MODULUS - 1)],
+            degree: 1,
+            constraint_type: ConstraintType::Transition,
+        });
+        proof.air.boundary.constraints.push(BoundaryConstraint {
+            register: 0,
+            step: 0,
+            value: PrimeField64::new(5),
+        });
+        proof
+    }
+
+    fn to_bytes(proof: &StarkProof<PrimeField64>) -> Vec<u8> {
+        let mut writer = ProofWriter::new(Vec::new());
+        writer.write_proof(proof).unwrap();
+        writer.into_inner()
+    }
+
+    #[test]
+    fn test_round_trip_streams_several_proofs() {
+        let proof = sample_proof();
+        let mut bytes = to_bytes(&proof);
+        assert_eq!(bytes[..8], PROOF_FORMAT_MAGIC);
+        bytes.extend(to_bytes(&proof));
+
+        let mut reader = ProofReader::new(std::io::Cursor::new(bytes));
+        assert_eq!(reader.read_proof::<PrimeField64>().unwrap(), proof);
+        assert_eq!(reader.read_proof::<PrimeField64>().unwrap(), proof);
+    }
+
+    #[test]
+    fn test_rejects_corrupt_streams() {
+        let bytes = to_bytes(&sample_proof());
+        let read = |bytes: &[u8]| ProofReader::new(bytes).read_proof::<PrimeField64>();
+
+        // Header (20) + metadata tag and length (9) + version (4): flip a byte of the security parameter
+        let mut flipped = bytes.clone();
+        flipped[33] ^= 1;
+        assert!(matches!(
+            read(&flipped),
+            Err(ProofFormatError::ChecksumMismatch(SectionTag::Metadata))
+        ));
+
+        let mut wrong_magic = bytes.clone();
+        wrong_magic[0] = b'Y';
+        assert!(matches!(read(&wrong_magic), Err(ProofFormatError::BadMagic)));
+
+        let mut newer = bytes.clone();
+        newer[8] = 3;
+        assert!(matches!(read(&newer), Err(ProofFormatError::UnsupportedVersion(3))));
+
+        let mut older = bytes.clone();
+        older[8] = 1;
+        assert!(matches!(read(&older), Err(ProofFormatError::UnsupportedVersion(1))));
+
+        assert!(matches!(read(&bytes[..bytes.len() - 1]), Err(ProofFormatError::Io(_))));
+    }
+
+    #[test]
+    fn test_limits_reject_before_allocating() {
+        let bytes = to_bytes(&sample_proof());
+        let read = |bytes: &[u8], limits: ProofReadLimits| {
+            ProofReader::with_limits(bytes, limits).read_proof::<PrimeField64>()
+        };
+
+        // A metadata section claiming an exabyte is refused from its header alone
+        let mut huge = bytes[..21].to_vec();
+        huge.extend_from_slice(&(1u64 << 60).to_le_bytes());
+        assert!(matches!(
+            read(&huge, ProofReadLimits::default()),
+            Err(ProofFormatError::LimitExceeded { parameter: "section length", .. })
+        ));
+
+        let tight = |limits: ProofReadLimits| match read(&bytes, limits) {
+            Err(ProofFormatError::LimitExceeded { parameter, .. }) => parameter,
+            other => panic!("expected a limit error, got {:?}", other.map(|_| ())),
+        };
+        let defaults = ProofReadLimits::default();
+        assert_eq!(tight(ProofReadLimits { max_layers: 1, ..defaults }), "FRI layer count");
+        assert_eq!(tight(ProofReadLimits { max_queries: 1, ..defaults }), "FRI query count");
+        assert_eq!(tight(ProofReadLimits { max_commitments: 0, ..defaults }), "commitment count");
+        assert_eq!(tight(ProofReadLimits { max_proof_bytes: 256, ..defaults }), "size");
+    }
+
+    #[test]
+    fn test_components_round_trip_through_bytes() {
+        use crate::types::StarkComponent;
+
+        let proof = sample_proof();
+        assert_eq!(StarkProof::from_bytes(&proof.to_bytes()), Ok(proof.clone()));
+        assert_eq!(FriProof::from_bytes(&proof.fri_proof.to_bytes()), Ok(proof.fri_proof.clone()));
+        let commitment = &proof.commitments[0];
+        assert_eq!(MerkleCommitment::from_bytes(&commitment.to_bytes()).as_ref(), Ok(commitment));
+        assert_eq!(ExecutionTrace::from_bytes(&proof.trace.to_bytes()).as_ref(), Ok(&proof.trace));
+        assert_eq!(Air::from_bytes(&proof.air.to_bytes()).as_ref(), Ok(&proof.air));
+        let transition = &proof.air.transition;
+        assert_eq!(TransitionFunction::from_bytes(&transition.to_bytes()).as_ref(), Ok(transition));
+        let boundary = &proof.air.boundary;
+        assert_eq!(BoundaryConditions::from_bytes(&boundary.to_bytes()).as_ref(), Ok(boundary));
+        let constraint = &boundary.constraints[0];
+        assert_eq!(BoundaryConstraint::from_bytes(&constraint.to_bytes()).as_ref(), Ok(constraint));
+        let layer = &proof.fri_proof.layers[0];
+        assert_eq!(FriLayer::from_bytes(&layer.to_bytes()).as_ref(), Ok(layer));
+        let query = &proof.fri_proof.queries[0];
+        assert_eq!(FriQuery::from_bytes(&query.to_bytes()).as_ref(), Ok(query));
+
+        // A component is only read back from its own section
+        assert!(FriQuery::<PrimeField64>::from_bytes(&layer.to_bytes()).is_err());
+        assert!(BoundaryConditions::<PrimeField64>::from_bytes(&constraint.to_bytes()).is_err());
+
+        let mut trailing = proof.to_bytes();
+        trailing.push(0);
+        assert!(StarkProof::<PrimeField64>::from_bytes(&trailing).is_err());
+    }
+
+    #[test]
+    fn test_mutated_proofs_never_panic() {
+        let bytes = to_bytes(&sample_proof());
+        let mut state = 0x2545f4914f6cdd1du64;
+        for round in 0..2000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let mut mutated = bytes.clone();
+            match round % 3 {
+                0 => mutated.truncate(state as usize % bytes.len()),
+                1 => mutated[state as usize % bytes.len()] ^= (state >> 32) as u8 | 1,
+                _ => {
+                    // Overwrite a length or count with a large value
+                    let at = state as usize % (bytes.len() - 8);
+                    mutated[at..at + 8].copy_from_slice(&(state >> 8).to_le_bytes());
+                }
+            }
+            decode_untrusted(&mutated);
+        }
+        decode_untrusted(&bytes);
+    }
+}
@@ -0,0 +1,181 @@
+//! Proof Encoding Compatibility
+//!
+//! Winterfell does not version its proof serialization, so a layout change between
+//! releases can make old bytes decode into a different proof instead of failing.
+//! Gateways run [`check_proof_encoding`] on every decoded proof: it accepts a proof
+//! only if it re-encodes to exactly the bytes it was read from under the pinned
+//! release ([`PINNED_WINTERFELL_VERSION`]) and commits to the 64-bit base field.
+//!
+//! Proofs generated under the pinned release are kept as fixtures in
+//! `tests/fixtures/proofs/`, one per hash function. The tests below verify every
+//! fixture, so a dependency upgrade that changes the byte layout fails the build
+//! rather than the gateways. They are rewritten by `crate::fixtures::regenerate`.
+
+use thiserror::Error;
+use winterfell::{
+    math::{fields::f64::BaseElement, StarkField},
+    StarkProof,
+};
+
+/// Winterfell release whose proof encoding this crate reads and writes
+pub const PINNED_WINTERFELL_VERSION: &str = "0.8";
+
+/// Proof bytes that decode but are not in the pinned Winterfell encoding
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum UnsupportedProofEncoding {
+    /// Re-encoding the decoded proof does not reproduce the input
+    #[error(
+        "Proof does not round-trip under Winterfell {}: {original} bytes re-encode to {reencoded} bytes",
+        PINNED_WINTERFELL_VERSION
+    )]
+    NonCanonical {
+        /// Size of the input (bytes)
+        original: usize,
+        /// Size of the re-encoded proof (bytes)
+        reencoded: usize,
+        /// First byte offset at which the encodings differ
+        first_difference: usize,
+    },
+    /// The proof commits to a field other than the 64-bit base field
+    #[error("Proof is over a field with modulus 0x{modulus}, not the 64-bit base field")]
+    FieldMismatch {
+        /// Little-endian hex of the proof's field modulus
+        modulus: String,
+    },
+}
+
+/// Check that a decoded proof is in the pinned Winterfell encoding
+///
+/// `proof_bytes` are the bytes `proof` was decoded from.
+///
+/// # Errors
+///
+/// [`UnsupportedProofEncoding::FieldMismatch`] if the proof is over another field, or
+/// [`UnsupportedProofEncoding::NonCanonical`] if re-encoding it does not reproduce
+/// `proof_bytes`
+pub fn check_proof_encoding(proof: &StarkProof, proof_bytes: &[u8]) -> Result<(), UnsupportedProofEncoding> {
+    let modulus = proof.context.field_modulus_bytes();
+    if modulus != BaseElement::get_modulus_le_bytes().as_slice() {
+        return Err(UnsupportedProofEncoding::FieldMismatch {
+            modulus: hex::encode(modulus),
+        });
+    }
+
+    let reencoded = proof.to_bytes();
+    if reencoded != proof_bytes {
+        let first_difference = reencoded
+            .iter()
+            .zip(proof_bytes)
+            .position(|(a, b)| a != b)
+            .unwrap_or(reencoded.len().min(proof_bytes.len()));
+        return Err(UnsupportedProofEncoding::NonCanonical {
+            original: proof_bytes.len(),
+            reencoded: reencoded.len(),
+            first_difference,
+        });
+    }
+    Ok(())
+}
+
+/// Generation of the proof fixtures
+#[cfg(any(test, feature = "testing"))]
+pub(crate) mod fixtures {
+    use super::PINNED_WINTERFELL_VERSION;
+    use crate::burn_mint_air::BurnMintPublicInputs;
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+    use crate::statements::HashFunction;
+    use std::path::Path;
+    use winterfell::StarkProof;
+
+    /// Prove the standard burn every fixture was generated from
+    pub(crate) fn prove_fixture_burn(hash_function: HashFunction) -> crate::Result<(StarkProof, BurnMintPublicInputs)> {
+        XfgBurnMintProver::default()
+            .with_hash_function(hash_function)
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+    }
+
+    /// Write one proof per hash function under `proofs/winterfell-<version>/` in `root`
+    pub(crate) fn write(root: &Path) -> crate::Result<()> {
+        let dir = root.join("proofs").join(format!("winterfell-{}", PINNED_WINTERFELL_VERSION));
+        std::fs::create_dir_all(&dir)?;
+        for hash_function in HashFunction::ALL {
+            let (proof, _) = prove_fixture_burn(hash_function)?;
+            std::fs::write(dir.join(format!("{}.bin", hash_function.name())), proof.to_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::prove_fixture_burn;
+    use super::*;
+    use crate::burn_mint_verifier::XfgBurnMintVerifier;
+    use crate::statements::HashFunction;
+    use winter_air::proof::Context;
+    use winterfell::math::fields::f128;
+
+    /// Proof generated under the pinned Winterfell release
+    fn fixture(hash_function: HashFunction) -> &'static [u8] {
+        match hash_function {
+            HashFunction::Blake3 => include_bytes!("../tests/fixtures/proofs/winterfell-0.8/blake3.bin"),
+            HashFunction::Keccak => include_bytes!("../tests/fixtures/proofs/winterfell-0.8/keccak.bin"),
+            HashFunction::Rescue => include_bytes!("../tests/fixtures/proofs/winterfell-0.8/rescue.bin"),
+        }
+    }
+
+    #[test]
+    fn test_fixture_matrix() {
+        for hash_function in HashFunction::ALL {
+            let bytes = fixture(hash_function);
+            let proof = StarkProof::from_bytes(bytes).expect("Fixture should decode");
+            check_proof_encoding(&proof, bytes).expect("Fixture should be in the pinned encoding");
+
+            // The prover is deterministic, so today's proof must match the fixture byte for byte
+            let (fresh, public_inputs) = prove_fixture_burn(hash_function).expect("Proof generation should succeed");
+            assert_eq!(fresh.to_bytes(), bytes, "{} proof encoding changed", hash_function);
+
+            let verifier = XfgBurnMintVerifier::default().with_hash_function(hash_function);
+            let report = verifier.verify_from_bytes(bytes, &public_inputs).unwrap();
+            assert!(report.is_valid(), "{} fixture failed verification: {:?}", hash_function, report.error);
+        }
+    }
+
+    #[test]
+    fn test_rejects_foreign_encodings() {
+        let bytes = fixture(HashFunction::Blake3);
+        let proof = StarkProof::from_bytes(bytes).unwrap();
+
+        // Trailing bytes are ignored by the decoder but change the encoding
+        let mut padded = bytes.to_vec();
+        padded.push(0);
+        let decoded = StarkProof::from_bytes(&padded).unwrap();
+        assert_eq!(
+            check_proof_encoding(&decoded, &padded),
+            Err(UnsupportedProofEncoding::NonCanonical {
+                original: bytes.len() + 1,
+                reencoded: bytes.len(),
+                first_difference: bytes.len(),
+            })
+        );
+
+        let mut foreign = proof.clone();
+        foreign.context = Context::new::<f128::BaseElement>(&proof.get_trace_info(), proof.options().clone());
+        let foreign_bytes = foreign.to_bytes();
+        assert!(matches!(
+            check_proof_encoding(&foreign, &foreign_bytes),
+            Err(UnsupportedProofEncoding::FieldMismatch { .. })
+        ));
+    }
+}
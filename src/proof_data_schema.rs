@@ -6,9 +6,24 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use chrono::{DateTime, Utc};
+use crate::build_info::BuildInfo;
+use crate::clock::{Clock, SystemClock};
+use crate::fees::{protocol_fee, FeeError, FeeModel};
+use crate::types::secret::Redacted;
+use crate::package_signing::SignatureEnvelope;
+use crate::eth_address::{validate_ens_name, AddressError, EnsResolver, EthAddress};
+use crate::provenance::{ProducerInfo, ProvenanceError};
+use crate::statements::HashFunction;
+use crate::commitment::CommitmentPreimage;
+use crate::verification_context::VerificationContext;
+use crate::schema_migration::{migrate_package_json, parse_package, PACKAGE_SCHEMA_VERSION};
 
 /// Complete data package for STARK proof generation
+///
+/// Load packages with [`StarkProofDataPackage::load_from_file`] or
+/// [`crate::schema_migration::parse_package`], which migrate older schema versions.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct StarkProofDataPackage {
     /// Metadata about the proof request
     pub metadata: ProofMetadata,
@@ -21,6 +36,9 @@ pub struct StarkProofDataPackage {
     /// Optional additional data
     #[serde(default)]
     pub additional_data: HashMap<String, String>,
+    /// Package signatures from rotating signing keys
+    #[serde(default)]
+    pub signatures: SignatureEnvelope,
 }
 
 /// Enhanced data package that includes both STARK proof and Eldernode verification
@@ -60,10 +78,13 @@ pub struct StarkPublicInputs {
     pub mint_amount: u64,
     /// Transaction hash
     pub txn_hash: String,
-    /// Recipient hash
+    /// Recipient binding (hex, see [`crate::commitment::recipient_binding`])
     pub recipient_hash: String,
     /// State
     pub state: u32,
+    /// Protocol fee charged on the mint (basis points)
+    #[serde(default)]
+    pub fee_bps: u32,
 }
 
 /// Eldernode verification proof
@@ -80,7 +101,7 @@ pub struct EldernodeVerification {
 }
 
 /// Merkle proof structure
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MerkleProof {
     /// Root hash
     pub root_hash: String,
@@ -155,6 +176,7 @@ pub struct ProofTimestamps {
 
 /// Metadata about the proof request
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct ProofMetadata {
     /// Version of the data package format
     pub version: String,
@@ -164,14 +186,27 @@ pub struct ProofMetadata {
     pub description: String,
     /// Network identifier (e.g., "fuego-mainnet", "fuego-testnet")
     pub network: String,
+    /// Build information of the prover binary (set at prove time)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_info: Option<BuildInfo>,
+    /// Hash function the proof commits with (set at prove time; BLAKE3 for older files)
+    #[serde(default)]
+    pub hash_function: HashFunction,
+    /// Organization that produced the package or proof
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub producer: Option<ProducerInfo>,
+    /// Version the package was written in before it was migrated to `version`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub migrated_from: Option<String>,
 }
 
 /// Burn transaction details
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct BurnTransaction {
     /// Transaction hash (hex string)
     pub transaction_hash: String,
-    /// Burn amount in XFG (decimal, e.g., "0.8" or "800.0")
+    /// Burn amount in XFG (decimal, e.g., "0.8" or "123.4567")
     pub burn_amount_xfg: String,
     /// Burn amount in atomic units (integer)
     pub burn_amount_atomic: u64,
@@ -181,10 +216,14 @@ pub struct BurnTransaction {
     pub timestamp: u64,
     /// Fuego network ID
     pub network_id: String,
+    /// Protocol fee the network charges on mints (basis points, 0 when fee-free)
+    #[serde(default)]
+    pub protocol_fee_bps: u32,
 }
 
 /// Recipient information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct RecipientInfo {
     /// Ethereum address (0x-prefixed hex)
     pub ethereum_address: String,
@@ -197,9 +236,13 @@ pub struct RecipientInfo {
 }
 
 /// Secret information for proof generation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `Debug` and `Display` redact every field so packages can be logged safely.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct SecretInfo {
-    /// User's secret key (hex string)
+    /// User's secret key (hex string); empty while `secret_ref` points at a keystore
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub secret_key: String,
     /// Optional salt for additional security
     #[serde(default)]
@@ -207,6 +250,56 @@ pub struct SecretInfo {
     /// Optional hint for secret recovery
     #[serde(default)]
     pub hint: Option<String>,
+    /// Encrypted keystore holding the secret key in place of `secret_key`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_ref: Option<SecretRef>,
+}
+
+impl SecretInfo {
+    /// Check whether the secret key is in a keystore rather than the package
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        self.secret_ref.is_some() && self.secret_key.is_empty()
+    }
+}
+
+impl std::fmt::Debug for SecretInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretInfo")
+            .field("secret_key", &Redacted::hex(&self.secret_key))
+            .field("salt", &self.salt.as_deref().map(Redacted::hex))
+            .field("hint", &self.hint.as_deref().map(|hint| Redacted::bytes(hint.len())))
+            .field("secret_ref", &self.secret_ref)
+            .finish()
+    }
+}
+
+/// Reference to the keystore file holding a package's secret key
+///
+/// See [`crate::keystore`] for the keystore format.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SecretRef {
+    /// Keystore file, relative to the package file unless absolute
+    pub keystore: String,
+}
+
+impl SecretRef {
+    /// Keystore location for a package stored at `package_file`
+    #[must_use]
+    pub fn keystore_path(&self, package_file: &std::path::Path) -> std::path::PathBuf {
+        let keystore = std::path::Path::new(&self.keystore);
+        match package_file.parent() {
+            Some(dir) if keystore.is_relative() => dir.join(keystore),
+            _ => keystore.to_path_buf(),
+        }
+    }
+}
+
+impl std::fmt::Display for SecretInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Redacted::hex(&self.secret_key))
+    }
 }
 
 /// Validation result for data package
@@ -229,15 +322,32 @@ impl StarkProofDataPackage {
         secret_key: String,
         network: String,
     ) -> Self {
-        let burn_amount_atomic = Self::xfg_to_atomic_units(burn_amount_xfg);
+        Self::new_with_clock(burn_amount_xfg, transaction_hash, ethereum_address, secret_key, network, &SystemClock)
+    }
+
+    /// Create a new data package stamped by `clock`
+    pub fn new_with_clock(
+        burn_amount_xfg: f64,
+        transaction_hash: String,
+        ethereum_address: String,
+        secret_key: String,
+        network: String,
+        clock: &dyn Clock,
+    ) -> Self {
+        // An amount with no atomic-unit representation is left at 0 for `validate` to reject
+        let burn_amount_atomic = Self::xfg_to_atomic_units(burn_amount_xfg).unwrap_or(0);
         
         let network_clone = network.clone();
         Self {
             metadata: ProofMetadata {
-                version: "1.0.0".to_string(),
-                created_at: chrono::Utc::now().to_rfc3339(),
+                version: PACKAGE_SCHEMA_VERSION.to_string(),
+                created_at: clock.rfc3339(),
                 description: format!("STARK proof for {} XFG burn", burn_amount_xfg),
                 network: network_clone,
+                build_info: None,
+                hash_function: HashFunction::default(),
+                producer: None,
+                migrated_from: None,
             },
             burn_transaction: BurnTransaction {
                 transaction_hash,
@@ -246,6 +356,7 @@ impl StarkProofDataPackage {
                 block_height: 0, // Will be filled by user
                 timestamp: 0,    // Will be filled by user
                 network_id: network,
+                protocol_fee_bps: 0,
             },
             recipient: RecipientInfo {
                 ethereum_address,
@@ -254,21 +365,28 @@ impl StarkProofDataPackage {
             },
             secret: SecretInfo {
                 secret_key,
+                secret_ref: None,
                 salt: None,
                 hint: None,
             },
             additional_data: HashMap::new(),
+            signatures: SignatureEnvelope::new(),
         }
     }
 
     /// Convert XFG amount to atomic units
-    pub fn xfg_to_atomic_units(xfg_amount: f64) -> u64 {
-        (xfg_amount * 10_000_000.0) as u64
+    ///
+    /// # Errors
+    ///
+    /// [`crate::constants::InvalidXfgAmount`] if the amount is negative, not finite or above
+    /// `u64::MAX` units
+    pub fn xfg_to_atomic_units(xfg_amount: f64) -> Result<u64, crate::constants::InvalidXfgAmount> {
+        crate::constants::xfg_to_atomic_units(xfg_amount)
     }
 
     /// Convert atomic units to XFG amount
     pub fn atomic_units_to_xfg(atomic_units: u64) -> f64 {
-        atomic_units as f64 / 10_000_000.0
+        crate::constants::atomic_units_to_xfg(atomic_units)
     }
 
     /// Validate the data package
@@ -277,11 +395,11 @@ impl StarkProofDataPackage {
         let mut warnings = Vec::new();
 
         // Validate burn amount
-        let valid_amounts = [0.8, 800.0];
         let burn_amount = self.burn_transaction.burn_amount_xfg.parse::<f64>().unwrap_or(0.0);
-        if !valid_amounts.contains(&burn_amount) {
+        if !Self::xfg_to_atomic_units(burn_amount).is_ok_and(crate::constants::is_valid_burn_amount) {
             errors.push(format!(
-                "Burn amount must be exactly 0.8 XFG or 800.0 XFG, got {}",
+                "{}, got {} XFG",
+                crate::constants::INVALID_BURN_AMOUNT,
                 burn_amount
             ));
         }
@@ -291,17 +409,37 @@ impl StarkProofDataPackage {
             errors.push("Fuego transaction hash should not start with 0x".to_string());
         }
 
-        // Validate Ethereum address format
-        if !self.recipient.ethereum_address.starts_with("0x") 
-           || self.recipient.ethereum_address.len() != 42 {
-            errors.push("Ethereum address must be 0x-prefixed 40-character hex".to_string());
+        // Validate Ethereum address format and, for mixed case, its EIP-55 checksum
+        if let Err(e) = self.recipient_address() {
+            errors.push(format!("recipient.ethereum_address: {e}"));
+        }
+        if let Some(ens_name) = &self.recipient.ens_name {
+            if let Err(e) = validate_ens_name(ens_name) {
+                errors.push(format!("recipient.ens_name: {e}"));
+            }
         }
 
-        // Validate secret key
-        if self.secret.secret_key.len() < 8 {
+        // Validate protocol fee
+        if let Err(e) = self.fee_model() {
+            errors.push(e.to_string());
+        }
+
+        // Validate secret key, unless it is locked away in a keystore
+        if self.secret.secret_ref.is_some() {
+            if !self.secret.secret_key.is_empty() {
+                errors.push("secret.secret_key must be empty when secret.secret_ref points at a keystore".to_string());
+            }
+        } else if self.secret.secret_key.len() < 8 {
             errors.push("Secret key must be at least 8 characters".to_string());
         }
 
+        // Provenance is public, so it must not carry the package's secrets
+        if let Some(producer) = &self.metadata.producer {
+            if let Err(e) = producer.validate_against_secrets(&self.secrets()) {
+                errors.push(format!("metadata.producer: {e}"));
+            }
+        }
+
         // Warnings
         if self.burn_transaction.block_height == 0 {
             warnings.push("Block height is 0 - please verify this is correct".to_string());
@@ -318,6 +456,60 @@ impl StarkProofDataPackage {
         }
     }
 
+    /// Validate the data package and check its ENS name against `resolver`
+    ///
+    /// A name that does not resolve, resolves elsewhere or cannot be looked up is an
+    /// error: the recipient must be unambiguous before HEAT is minted to it.
+    pub fn validate_with_ens(&self, resolver: &dyn EnsResolver) -> ValidationResult {
+        let mut result = self.validate();
+
+        if let (Some(ens_name), Ok(address)) = (&self.recipient.ens_name, self.recipient_address()) {
+            if validate_ens_name(ens_name).is_ok() {
+                match resolver.resolve(ens_name) {
+                    Ok(Some(found)) if found == address => {}
+                    Ok(Some(found)) => result.errors.push(format!(
+                        "recipient.ens_name: {ens_name} resolves to {found}, not {address}"
+                    )),
+                    Ok(None) => result
+                        .errors
+                        .push(format!("recipient.ens_name: {ens_name} does not resolve to an address")),
+                    Err(e) => result.errors.push(format!("recipient.ens_name: {e}")),
+                }
+            }
+        }
+
+        result.is_valid = result.errors.is_empty();
+        result
+    }
+
+    /// Embed producer provenance, refusing any that contains the package's secrets
+    ///
+    /// # Errors
+    ///
+    /// [`ProvenanceError`] if `producer` is invalid or contains one of the package's secrets
+    pub fn with_producer(mut self, producer: ProducerInfo) -> Result<Self, ProvenanceError> {
+        producer.validate_against_secrets(&self.secrets())?;
+        self.metadata.producer = Some(producer);
+        Ok(self)
+    }
+
+    /// Secret values that must not appear in public metadata
+    #[must_use]
+    pub fn secrets(&self) -> Vec<&str> {
+        let mut secrets = vec![self.secret.secret_key.as_str()];
+        secrets.extend(self.secret.salt.as_deref());
+        secrets
+    }
+
+    /// Parse the recipient's Ethereum address
+    ///
+    /// # Errors
+    ///
+    /// [`AddressError`] if the address is malformed or fails its EIP-55 checksum
+    pub fn recipient_address(&self) -> Result<EthAddress, AddressError> {
+        self.recipient.ethereum_address.parse()
+    }
+
     /// Save package to JSON file
     pub fn save_to_file(&self, filepath: &str) -> Result<(), Box<dyn std::error::Error>> {
         let json = serde_json::to_string_pretty(self)?;
@@ -325,21 +517,69 @@ impl StarkProofDataPackage {
         Ok(())
     }
 
-    /// Load package from JSON file
+    /// Load package from JSON file, migrating older schema versions
     pub fn load_from_file(filepath: &str) -> Result<Self, Box<dyn std::error::Error>> {
         let json = std::fs::read_to_string(filepath)?;
-        let package: StarkProofDataPackage = serde_json::from_str(&json)?;
-        Ok(package)
+        Ok(parse_package(&json)?)
     }
 
-    /// Get mint amount (1:1 ratio with burn)
+    /// Protocol fee model of the burn's network
+    ///
+    /// # Errors
+    ///
+    /// [`FeeError::FeeTooHigh`] if the recorded fee rate exceeds the protocol maximum
+    pub fn fee_model(&self) -> Result<FeeModel, FeeError> {
+        FeeModel::new(self.burn_transaction.protocol_fee_bps)
+    }
+
+    /// Get mint amount (burn less the protocol fee; 1:1 on fee-free networks)
     pub fn get_mint_amount_atomic(&self) -> u64 {
-        self.burn_transaction.burn_amount_atomic
+        let burn_amount = self.burn_transaction.burn_amount_atomic;
+        burn_amount - protocol_fee(burn_amount, self.burn_transaction.protocol_fee_bps)
     }
 
     /// Get mint amount in HEAT
     pub fn get_mint_amount_heat(&self) -> f64 {
-        Self::atomic_units_to_xfg(self.burn_transaction.burn_amount_atomic)
+        Self::atomic_units_to_xfg(self.get_mint_amount_atomic())
+    }
+
+    /// Burn transaction prefix hash as proven (hex, truncated or zero-padded to 32 bytes)
+    ///
+    /// # Errors
+    ///
+    /// [`hex::FromHexError`] if the transaction hash is not hex
+    pub fn tx_prefix_hash(&self) -> Result<[u8; 32], hex::FromHexError> {
+        let transaction_hash = &self.burn_transaction.transaction_hash;
+        let bytes = hex::decode(transaction_hash.strip_prefix("0x").unwrap_or(transaction_hash))?;
+        let mut prefix_hash = [0u8; 32];
+        let len = bytes.len().min(32);
+        prefix_hash[..len].copy_from_slice(&bytes[..len]);
+        Ok(prefix_hash)
+    }
+
+    /// Secret as proven (key bytes, truncated or zero-padded to 32 bytes)
+    #[must_use]
+    pub fn secret_bytes(&self) -> [u8; 32] {
+        let key = self.secret.secret_key.as_bytes();
+        let mut secret = [0u8; 32];
+        let len = key.len().min(32);
+        secret[..len].copy_from_slice(&key[..len]);
+        secret
+    }
+
+    /// Preimage of the proof commitment this package's claim carries
+    ///
+    /// # Errors
+    ///
+    /// As [`VerificationContext::from_package`]
+    pub fn commitment_preimage(&self) -> crate::Result<CommitmentPreimage> {
+        Ok(VerificationContext::from_package(self)?.commitment_preimage())
+    }
+
+    /// Numeric Fuego network ID (1, mainnet, for named networks)
+    #[must_use]
+    pub fn network_id_number(&self) -> u32 {
+        self.burn_transaction.network_id.parse::<u32>().unwrap_or(1)
     }
 }
 
@@ -361,15 +601,68 @@ impl CompleteProofPackage {
 
     /// Add STARK proof to the package
     pub fn add_stark_proof(&mut self, stark_proof: StarkProof) {
+        self.add_stark_proof_with_clock(stark_proof, &SystemClock);
+    }
+
+    /// Add STARK proof to the package, stamped by `clock`
+    pub fn add_stark_proof_with_clock(&mut self, stark_proof: StarkProof, clock: &dyn Clock) {
         self.stark_proof = Some(stark_proof);
-        self.timestamps.stark_proof_generated = Some(chrono::Utc::now().to_rfc3339());
+        self.timestamps.stark_proof_generated = Some(clock.rfc3339());
         self.status = PackageStatus::StarkProofReady;
     }
 
-    /// Add Eldernode verification to the package
-    pub fn add_eldernode_verification(&mut self, eldernode_verification: EldernodeVerification) {
+    /// Add Eldernode verification to the package once its signatures verify
+    ///
+    /// Every signature must be a distinct trusted Eldernode's over this package's burn,
+    /// and `verifier`'s threshold must be met; the consensus summary is then rewritten
+    /// from what verified.
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::add_eldernode_verification_with_clock`]
+    #[cfg(feature = "eldernode")]
+    pub fn add_eldernode_verification(
+        &mut self,
+        eldernode_verification: EldernodeVerification,
+        verifier: &crate::eldernode::EldernodeVerifier,
+    ) -> crate::Result<()> {
+        self.add_eldernode_verification_with_clock(eldernode_verification, verifier, &SystemClock)
+    }
+
+    /// Add Eldernode verification to the package once its signatures verify, stamped by `clock`
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError`] if the package's proof data does not parse, or the signatures
+    /// are not distinct trusted Eldernodes' over this burn meeting `verifier`'s threshold
+    #[cfg(feature = "eldernode")]
+    pub fn add_eldernode_verification_with_clock(
+        &mut self,
+        mut eldernode_verification: EldernodeVerification,
+        verifier: &crate::eldernode::EldernodeVerifier,
+        clock: &dyn Clock,
+    ) -> crate::Result<()> {
+        let inputs = VerificationContext::from_package(&self.stark_proof_data)?.eldernode_inputs();
+        let confirmed =
+            crate::eldernode::consensus::verify_eldernode_signatures(&eldernode_verification, &inputs, verifier)?;
+        eldernode_verification.consensus = ConsensusInfo {
+            eldernode_count: u32::try_from(confirmed).unwrap_or(u32::MAX),
+            threshold_met: true,
+            consensus_type: verifier.threshold().to_string(),
+        };
+        self.record_eldernode_verification(eldernode_verification, clock);
+        Ok(())
+    }
+
+    /// Store an Eldernode verification without checking its signatures
+    #[cfg(any(test, feature = "eldernode"))]
+    pub(crate) fn record_eldernode_verification(
+        &mut self,
+        eldernode_verification: EldernodeVerification,
+        clock: &dyn Clock,
+    ) {
         self.eldernode_verification = Some(eldernode_verification);
-        self.timestamps.eldernode_verified = Some(chrono::Utc::now().to_rfc3339());
+        self.timestamps.eldernode_verified = Some(clock.rfc3339());
         self.status = PackageStatus::Complete;
     }
 
@@ -385,11 +678,14 @@ impl CompleteProofPackage {
         Ok(())
     }
 
-    /// Load complete package from JSON file
+    /// Load complete package from JSON file, migrating older data package schema versions
     pub fn load_from_file(filepath: &str) -> Result<Self, Box<dyn std::error::Error>> {
         let json = std::fs::read_to_string(filepath)?;
-        let package: CompleteProofPackage = serde_json::from_str(&json)?;
-        Ok(package)
+        let mut package: serde_json::Value = serde_json::from_str(&json)?;
+        if let Some(data) = package.get_mut("stark_proof_data") {
+            migrate_package_json(data)?;
+        }
+        Ok(serde_json::from_value(package)?)
     }
 
     /// Get contract submission data
@@ -524,7 +820,7 @@ mod tests {
         let package = StarkProofDataPackage::new(
             0.8,
             "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
-            "0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
             "my-secret-key-123".to_string(),
             "fuego-testnet".to_string(),
         );
@@ -539,7 +835,7 @@ mod tests {
         let package = StarkProofDataPackage::new(
             0.8,
             "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
-            "0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
             "my-secret-key-123".to_string(),
             "fuego-testnet".to_string(),
         );
@@ -552,9 +848,9 @@ mod tests {
     #[test]
     fn test_invalid_amount() {
         let package = StarkProofDataPackage::new(
-            1.5, // Invalid amount
+            1_000_000_000.0, // Invalid amount (above 2^53 atomic units)
             "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
-            "0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
             "my-secret-key-123".to_string(),
             "fuego-testnet".to_string(),
         );
@@ -564,12 +860,88 @@ mod tests {
         assert!(validation.errors.iter().any(|e| e.contains("Burn amount")));
     }
 
+    #[test]
+    fn test_recipient_address_validation() {
+        let package_for = |address: &str| {
+            StarkProofDataPackage::new(
+                0.8,
+                "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+                address.to_string(),
+                "my-secret-key-123".to_string(),
+                "fuego-testnet".to_string(),
+            )
+        };
+
+        // Unchecksummed lowercase is accepted, a broken checksum is not
+        assert!(package_for("0x742d35cc6634c0532925a3b8d4c9db96c4b4d8b6").validate().is_valid);
+        let validation = package_for("0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6").validate();
+        assert_eq!(
+            validation.errors,
+            vec!["recipient.ethereum_address: EIP-55 checksum mismatch (expected 0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6)"]
+        );
+        let validation = package_for(&format!("0x{}", "zz".repeat(20))).validate();
+        assert!(validation.errors[0].starts_with("recipient.ethereum_address: invalid hex character 'z'"));
+    }
+
+    #[test]
+    fn test_ens_name_validation() {
+        struct FixedResolver(Option<EthAddress>);
+        impl EnsResolver for FixedResolver {
+            fn resolve(&self, _name: &str) -> Result<Option<EthAddress>, crate::eth_address::EnsError> {
+                Ok(self.0)
+            }
+        }
+
+        let mut package = StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "my-secret-key-123".to_string(),
+            "fuego-testnet".to_string(),
+        );
+        package.recipient.ens_name = Some("alice.eth".to_string());
+        let address = package.recipient_address().unwrap();
+
+        assert!(package.validate_with_ens(&FixedResolver(Some(address))).is_valid);
+        let elsewhere = package.validate_with_ens(&FixedResolver(Some(EthAddress::from_bytes([1u8; 20]))));
+        assert!(!elsewhere.is_valid);
+        assert!(elsewhere.errors[0].starts_with("recipient.ens_name: alice.eth resolves to 0x0101"));
+        assert!(!package.validate_with_ens(&FixedResolver(None)).is_valid);
+
+        package.recipient.ens_name = Some("alice".to_string());
+        assert!(!package.validate().is_valid);
+    }
+
+    #[test]
+    fn test_fee_enabled_network() {
+        let mut package = StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "my-secret-key-123".to_string(),
+            "fuego-testnet".to_string(),
+        );
+        package.burn_transaction.protocol_fee_bps = 50;
+
+        assert_eq!(package.get_mint_amount_atomic(), 7_960_000);
+        assert!(package.validate().is_valid);
+
+        package.burn_transaction.protocol_fee_bps = crate::fees::MAX_PROTOCOL_FEE_BPS + 1;
+        assert!(!package.validate().is_valid);
+
+        // Packages written before fee support default to a fee-free network
+        let mut json = serde_json::to_value(&package).unwrap();
+        json["burn_transaction"].as_object_mut().unwrap().remove("protocol_fee_bps");
+        let legacy: StarkProofDataPackage = serde_json::from_value(json).unwrap();
+        assert_eq!(legacy.burn_transaction.protocol_fee_bps, 0);
+    }
+
     #[test]
     fn test_complete_package_workflow() {
         let stark_data = StarkProofDataPackage::new(
             0.8,
             "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
-            "0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
             "my-secret-key-123".to_string(),
             "fuego-testnet".to_string(),
         );
@@ -584,14 +956,19 @@ mod tests {
                 burn_amount: 8_000_000,
                 mint_amount: 8_000_000,
                 txn_hash: "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
-                recipient_hash: "0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6".to_string(),
+                recipient_hash: "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
                 state: 0,
+                fee_bps: 0,
             },
             metadata: ProofMetadata {
                 version: "1.0.0".to_string(),
                 created_at: chrono::Utc::now().to_rfc3339(),
                 description: "Test proof".to_string(),
                 network: "fuego-testnet".to_string(),
+                build_info: None,
+                hash_function: HashFunction::default(),
+                producer: None,
+                migrated_from: None,
             },
         };
 
@@ -624,10 +1001,59 @@ mod tests {
             },
         };
 
-        complete_package.add_eldernode_verification(eldernode_verification);
+        complete_package.record_eldernode_verification(eldernode_verification, &SystemClock);
         assert!(matches!(complete_package.status, PackageStatus::Complete));
         assert!(complete_package.is_ready_for_contract());
     }
+
+    #[test]
+    fn test_clock_pins_timestamps() {
+        use crate::clock::FixedClock;
+
+        let clock = FixedClock::from_unix_seconds(1_705_312_200);
+        let data = StarkProofDataPackage::new_with_clock(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "secret".to_string(),
+            "fuego-testnet".to_string(),
+            &clock,
+        );
+        assert_eq!(data.metadata.created_at, "2024-01-15T09:50:00+00:00");
+
+        let mut package = CompleteProofPackage::new(data);
+        package.add_stark_proof_with_clock(StarkProof::new_dummy(), &clock);
+        package.record_eldernode_verification(EldernodeVerification::new_dummy(), &clock);
+        assert_eq!(package.timestamps.created_at, "2024-01-15T09:50:00+00:00");
+        assert_eq!(package.timestamps.stark_proof_generated.as_deref(), Some("2024-01-15T09:50:00+00:00"));
+        assert_eq!(package.timestamps.eldernode_verified.as_deref(), Some("2024-01-15T09:50:00+00:00"));
+    }
+
+    #[test]
+    fn test_secret_never_formatted() {
+        let secret_hex = "c0ffee".repeat(10) + "beef";
+        let salt_hex = "5a17".repeat(8);
+        let mut package = StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            secret_hex.clone(),
+            "fuego-testnet".to_string(),
+        );
+        package.secret.salt = Some(salt_hex.clone());
+
+        let outputs = [
+            format!("{:?}", package),
+            format!("{:#?}", package),
+            format!("{:?}", CompleteProofPackage::new(package.clone())),
+            format!("{}", package.secret),
+        ];
+        for output in &outputs {
+            assert!(!output.to_lowercase().contains(&secret_hex), "secret leaked: {}", output);
+            assert!(!output.to_lowercase().contains(&salt_hex), "salt leaked: {}", output);
+            assert!(output.contains("[REDACTED 32 bytes]"));
+        }
+    }
 }
 
 impl StarkProof {
@@ -639,14 +1065,19 @@ impl StarkProof {
                 burn_amount: 8_000_000, // 0.8 XFG in atomic units
                 mint_amount: 8_000_000, // 1:1 ratio
                 txn_hash: "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
-                recipient_hash: "0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6".to_string(),
+                recipient_hash: "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
                 state: 0,
+                fee_bps: 0,
             },
             metadata: ProofMetadata {
                 version: "1.0.0".to_string(),
                 created_at: chrono::Utc::now().to_rfc3339(),
                 description: "Dummy STARK proof for testing".to_string(),
                 network: "fuego-mainnet".to_string(),
+                build_info: None,
+                hash_function: HashFunction::default(),
+                producer: None,
+                migrated_from: None,
             },
         }
     }
@@ -0,0 +1,384 @@
+//! Proof Narrative Reports for XFG → HEAT Burn & Mint
+//!
+//! This module renders a verified `CompleteProofPackage` as a human-readable narrative
+//! for audit files and user receipts: what was burned and when, who receives the HEAT
+//! mint, which Eldernodes attested, and which proof parameters were used.
+//!
+//! Reports are rendered as Markdown or HTML. The user's secret is never included.
+
+use crate::proof_data_schema::{CompleteProofPackage, PackageStatus, StarkProof as ProofFile};
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+use winter_crypto::hashers::Blake3_256;
+use winterfell::{math::fields::f64::BaseElement, StarkProof};
+
+/// Output format of a narrative report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Markdown document
+    Markdown,
+    /// Standalone HTML document
+    Html,
+}
+
+impl FromStr for ReportFormat {
+    type Err = ReportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "markdown" | "md" => Ok(ReportFormat::Markdown),
+            "html" => Ok(ReportFormat::Html),
+            other => Err(ReportError::UnknownFormat(other.to_string())),
+        }
+    }
+}
+
+impl Display for ReportFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportFormat::Markdown => write!(f, "markdown"),
+            ReportFormat::Html => write!(f, "html"),
+        }
+    }
+}
+
+/// One section of a narrative report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NarrativeSection {
+    /// Section heading
+    pub title: String,
+    /// Prose summary of the section
+    pub paragraph: String,
+    /// Labelled facts backing the summary
+    pub facts: Vec<(String, String)>,
+}
+
+impl NarrativeSection {
+    fn new(title: &str, paragraph: String) -> Self {
+        Self {
+            title: title.to_string(),
+            paragraph,
+            facts: Vec::new(),
+        }
+    }
+
+    fn fact(mut self, label: &str, value: impl Display) -> Self {
+        self.facts.push((label.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// Human-readable narrative of a verified burn & mint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofNarrative {
+    /// Report title
+    pub title: String,
+    /// Report sections in reading order
+    pub sections: Vec<NarrativeSection>,
+}
+
+impl ProofNarrative {
+    /// Build the narrative for a verified package
+    ///
+    /// # Errors
+    ///
+    /// [`ReportError::NotVerified`] if the package is not complete, and
+    /// [`ReportError::MissingComponent`] if it lacks the proof or the Eldernode verification
+    pub fn from_package(package: &CompleteProofPackage) -> Result<Self, ReportError> {
+        if !matches!(package.status, PackageStatus::Complete) {
+            return Err(ReportError::NotVerified(format!("{:?}", package.status)));
+        }
+        let proof = package
+            .stark_proof
+            .as_ref()
+            .ok_or(ReportError::MissingComponent("STARK proof"))?;
+        let verification = package
+            .eldernode_verification
+            .as_ref()
+            .ok_or(ReportError::MissingComponent("Eldernode verification"))?;
+
+        let data = &package.stark_proof_data;
+        let burn = &data.burn_transaction;
+        let recipient = &data.recipient;
+        let recipient_name = match (&recipient.ens_name, &recipient.label) {
+            (Some(ens), _) => format!("{} ({})", ens, recipient.ethereum_address),
+            (None, Some(label)) => format!("{} ({})", label, recipient.ethereum_address),
+            (None, None) => recipient.ethereum_address.clone(),
+        };
+
+        let mut sections = Vec::new();
+
+        sections.push(
+            NarrativeSection::new(
+                "Burn",
+                format!(
+                    "{} XFG were burned on {} in transaction {} at block {}, {}.",
+                    burn.burn_amount_xfg,
+                    data.metadata.network,
+                    burn.transaction_hash,
+                    burn.block_height,
+                    format_unix_time(burn.timestamp)
+                ),
+            )
+            .fact("Transaction", &burn.transaction_hash)
+            .fact("Amount", format!("{} XFG ({} atomic units)", burn.burn_amount_xfg, burn.burn_amount_atomic))
+            .fact("Block height", burn.block_height)
+            .fact("Fuego network ID", &burn.network_id),
+        );
+
+        sections.push(
+            NarrativeSection::new(
+                "Mint",
+                format!(
+                    "{} HEAT will be minted to {}.",
+                    data.get_mint_amount_heat(),
+                    recipient_name
+                ),
+            )
+            .fact("Recipient", &recipient.ethereum_address)
+            .fact("Mint amount", format!("{} HEAT ({} atomic units)", data.get_mint_amount_heat(), data.get_mint_amount_atomic())),
+        );
+
+        let mut attestation = NarrativeSection::new(
+            "Eldernode Attestation",
+            format!(
+                "{} Eldernode(s) attested to the burn under {} consensus on {}; the threshold was {}.",
+                verification.consensus.eldernode_count,
+                verification.consensus.consensus_type,
+                verification.metadata.verified_at,
+                if verification.consensus.threshold_met { "met" } else { "not met" }
+            ),
+        )
+        .fact("Merkle root", &verification.merkle_proof.root_hash);
+        for signature in &verification.eldernode_signatures {
+            attestation = attestation.fact(
+                "Eldernode",
+                format!("{} at {}", signature.eldernode_address, format_unix_time(signature.timestamp)),
+            );
+        }
+        sections.push(attestation);
+
+        sections.push(proof_section(proof));
+
+        sections.push(
+            NarrativeSection::new(
+                "Timeline",
+                "Key milestones of this burn & mint.".to_string(),
+            )
+            .fact("Package created", &package.timestamps.created_at)
+            .fact("Proof generated", package.timestamps.stark_proof_generated.as_deref().unwrap_or("unknown"))
+            .fact("Eldernodes verified", package.timestamps.eldernode_verified.as_deref().unwrap_or("unknown")),
+        );
+
+        Ok(Self {
+            title: format!("XFG → HEAT Burn & Mint Report: {}", burn.transaction_hash),
+            sections,
+        })
+    }
+
+    /// Render the narrative in the given format
+    #[must_use]
+    pub fn render(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Markdown => self.render_markdown(),
+            ReportFormat::Html => self.render_html(),
+        }
+    }
+
+    fn render_markdown(&self) -> String {
+        let mut out = format!("# {}\n", self.title);
+        for section in &self.sections {
+            let _ = write!(out, "\n## {}\n\n{}\n\n", section.title, section.paragraph);
+            for (label, value) in &section.facts {
+                let _ = writeln!(out, "- **{label}:** {value}");
+            }
+        }
+        out
+    }
+
+    fn render_html(&self) -> String {
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{0}</title>\n</head>\n<body>\n<h1>{0}</h1>\n",
+            escape_html(&self.title)
+        );
+        for section in &self.sections {
+            let _ = write!(
+                out,
+                "<h2>{}</h2>\n<p>{}</p>\n<ul>\n",
+                escape_html(&section.title),
+                escape_html(&section.paragraph)
+            );
+            for (label, value) in &section.facts {
+                let _ = writeln!(
+                    out,
+                    "<li><strong>{}:</strong> {}</li>",
+                    escape_html(label),
+                    escape_html(value)
+                );
+            }
+            out.push_str("</ul>\n");
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+}
+
+/// Render a narrative report for a verified package
+///
+/// # Errors
+///
+/// Errors of [`ProofNarrative::from_package`]
+pub fn generate_report(
+    package: &CompleteProofPackage,
+    format: ReportFormat,
+) -> Result<String, ReportError> {
+    Ok(ProofNarrative::from_package(package)?.render(format))
+}
+
+/// Describe the proof and the parameters it was generated with
+fn proof_section(proof: &ProofFile) -> NarrativeSection {
+    let mut parameters = NarrativeSection::new(
+        "Proof",
+        format!(
+            "A {}-byte STARK proof was generated on {} for {}.",
+            proof.proof_data.len(),
+            proof.metadata.created_at,
+            proof.metadata.network
+        ),
+    )
+    .fact("Proof format version", &proof.metadata.version);
+    match StarkProof::from_bytes(&proof.proof_data) {
+        Ok(stark_proof) => {
+            let options = stark_proof.options();
+            let fri_options = options.to_fri_options();
+            parameters = parameters
+                .fact("Queries", options.num_queries())
+                .fact("Blowup factor", options.blowup_factor())
+                .fact("Grinding factor", options.grinding_factor())
+                .fact("Field extension degree", options.field_extension().degree())
+                .fact("FRI folding factor", fri_options.folding_factor())
+                .fact("FRI remainder max degree", fri_options.remainder_max_degree())
+                .fact(
+                    "Conjectured security",
+                    format!("{} bits", stark_proof.security_level::<Blake3_256<BaseElement>>(true)),
+                );
+        }
+        Err(_) => parameters = parameters.fact("Parameters", "unavailable (proof bytes not decodable)"),
+    }
+    if let Some(build_info) = &proof.metadata.build_info {
+        parameters = parameters.fact("Prover build", build_info);
+    }
+    parameters
+}
+
+/// Format a UNIX timestamp as RFC 3339, falling back to the raw value
+fn format_unix_time(timestamp: u64) -> String {
+    i64::try_from(timestamp)
+        .ok()
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+        .map_or_else(|| format!("UNIX time {timestamp}"), |time| time.to_rfc3339())
+}
+
+/// Escape text for inclusion in HTML
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Report generation errors
+#[derive(Debug, thiserror::Error)]
+pub enum ReportError {
+    /// Package has not completed Eldernode verification
+    #[error("Package is not verified (status: {0})")]
+    NotVerified(String),
+
+    /// Verified package is missing a component
+    #[error("Package is missing its {0}")]
+    MissingComponent(&'static str),
+
+    /// Unknown report format
+    #[error("Unknown report format: {0} (expected markdown or html)")]
+    UnknownFormat(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proof_data_schema::{
+        ConsensusInfo, EldernodeSignature, EldernodeVerification, MerkleProof,
+        StarkProof as PackageProof, StarkProofDataPackage, VerificationMetadata,
+    };
+
+    fn verified_package() -> CompleteProofPackage {
+        let mut data = StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "deadbeefcafebabe".to_string(),
+            "fuego-testnet".to_string(),
+        );
+        data.recipient.label = Some("<Alice & Co>".to_string());
+
+        let mut package = CompleteProofPackage::new(data);
+        package.add_stark_proof(PackageProof::new_dummy());
+        package.record_eldernode_verification(EldernodeVerification {
+            merkle_proof: MerkleProof {
+                root_hash: "ab".repeat(32),
+                leaf_hash: "cd".repeat(32),
+                proof_path: Vec::new(),
+                proof_indices: Vec::new(),
+            },
+            eldernode_signatures: vec![EldernodeSignature {
+                public_key: "01".repeat(32),
+                eldernode_address: "fire1eldernode".to_string(),
+                signature: "02".repeat(64),
+                timestamp: 1_700_000_000,
+            }],
+            consensus: ConsensusInfo {
+                eldernode_count: 1,
+                threshold_met: true,
+                consensus_type: "2/2".to_string(),
+            },
+            metadata: VerificationMetadata {
+                verified_at: "2026-01-01T00:00:00Z".to_string(),
+                network: "fuego-testnet".to_string(),
+                version: "1.0.0".to_string(),
+            },
+        }, &crate::clock::SystemClock);
+        package
+    }
+
+    #[test]
+    fn test_markdown_report_narrates_package() {
+        let report = generate_report(&verified_package(), ReportFormat::Markdown).unwrap();
+
+        assert!(report.contains("0.8 XFG were burned"));
+        assert!(report.contains("0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6"));
+        assert!(report.contains("fire1eldernode at 2023-11-14T22:13:20+00:00"));
+        assert!(report.contains("## Eldernode Attestation"));
+        assert!(!report.contains("deadbeefcafebabe"));
+    }
+
+    #[test]
+    fn test_html_report_escapes_values() {
+        let report = generate_report(&verified_package(), ReportFormat::Html).unwrap();
+
+        assert!(report.starts_with("<!DOCTYPE html>"));
+        assert!(report.contains("&lt;Alice &amp; Co&gt;"));
+        assert!(!report.contains("<Alice"));
+    }
+
+    #[test]
+    fn test_unverified_package_rejected() {
+        let mut package = verified_package();
+        package.status = PackageStatus::StarkProofReady;
+
+        assert!(matches!(
+            generate_report(&package, ReportFormat::Markdown),
+            Err(ReportError::NotVerified(_))
+        ));
+        assert!(matches!("pdf".parse::<ReportFormat>(), Err(ReportError::UnknownFormat(_))));
+    }
+}
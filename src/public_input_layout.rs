@@ -0,0 +1,336 @@
+//! Public Input Layout
+//!
+//! The on-chain verifier reads the burn & mint public inputs as a flat list of field
+//! elements in [`BurnMintPublicInputs::to_elements`] order. That order is versioned
+//! here: [`public_input_fields`] names every position for a layout version, and
+//! [`assert_public_input_layout`] checks at runtime that `to_elements` and
+//! `from_elements` still follow it, so a reordered struct fails before it reaches a
+//! contract.
+//!
+//! Test vectors for a canonical set of claims are kept in
+//! `tests/fixtures/public_inputs/`, one file per layout version, for the contract's own
+//! tests. The tests below check every vector against today's prover, and
+//! `crate::fixtures::regenerate` rewrites them after an intentional layout change.
+
+use crate::burn_mint_air::BurnMintPublicInputs;
+use thiserror::Error;
+use winter_math::ToElements;
+use winterfell::math::fields::f64::BaseElement;
+
+/// Layout version the on-chain verifier expects
+pub const PUBLIC_INPUT_LAYOUT_VERSION: u32 = 2;
+
+/// Field at each position of layout version 1, with single-element nullifier and
+/// commitment
+const LAYOUT_V1: [&str; 23] = [
+    "burn_amount",
+    "mint_amount",
+    "txn_hash",
+    "recipient_hash",
+    "state",
+    "tx_prefix_hash_0",
+    "tx_prefix_hash_1",
+    "tx_prefix_hash_2",
+    "tx_prefix_hash_3",
+    "network_id",
+    "target_chain_id",
+    "commitment_version",
+    "fee_bps",
+    "nullifier",
+    "commitment",
+    "recipient_binding[0]",
+    "recipient_binding[1]",
+    "recipient_binding[2]",
+    "recipient_binding[3]",
+    "recipient_binding[4]",
+    "recipient_binding[5]",
+    "recipient_binding[6]",
+    "recipient_binding[7]",
+];
+
+/// Field at each position of layout version 2: the nullifier and commitment are whole
+/// four-element digests
+const LAYOUT_V2: [&str; BurnMintPublicInputs::NUM_ELEMENTS] = [
+    "burn_amount",
+    "mint_amount",
+    "txn_hash",
+    "recipient_hash",
+    "state",
+    "tx_prefix_hash_0",
+    "tx_prefix_hash_1",
+    "tx_prefix_hash_2",
+    "tx_prefix_hash_3",
+    "network_id",
+    "target_chain_id",
+    "commitment_version",
+    "fee_bps",
+    "nullifier[0]",
+    "nullifier[1]",
+    "nullifier[2]",
+    "nullifier[3]",
+    "commitment[0]",
+    "commitment[1]",
+    "commitment[2]",
+    "commitment[3]",
+    "recipient_binding[0]",
+    "recipient_binding[1]",
+    "recipient_binding[2]",
+    "recipient_binding[3]",
+    "recipient_binding[4]",
+    "recipient_binding[5]",
+    "recipient_binding[6]",
+    "recipient_binding[7]",
+];
+
+/// Public inputs that do not follow a layout version
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PublicInputLayoutError {
+    /// No such layout version
+    #[error("Unknown public input layout version {0}")]
+    UnknownVersion(u32),
+    /// `to_elements` produced a different number of elements
+    #[error("Public inputs have {actual} elements, layout version {version} has {expected}")]
+    Length {
+        /// Layout version checked
+        version: u32,
+        /// Elements in the layout
+        expected: usize,
+        /// Elements `to_elements` produced
+        actual: usize,
+    },
+    /// `to_elements` put another field at a position
+    #[error("Public input {index} should be {expected} under layout version {version}, found {actual}")]
+    Reordered {
+        /// Layout version checked
+        version: u32,
+        /// Position in the element list
+        index: usize,
+        /// Field the layout puts there
+        expected: &'static str,
+        /// Field `to_elements` put there
+        actual: &'static str,
+    },
+    /// `from_elements` does not invert `to_elements`
+    #[error("Public inputs do not round-trip through from_elements")]
+    RoundTrip,
+}
+
+/// Field names in element order for a layout version
+#[must_use]
+pub fn public_input_fields(version: u32) -> Option<&'static [&'static str]> {
+    match version {
+        1 => Some(&LAYOUT_V1),
+        2 => Some(&LAYOUT_V2),
+        _ => None,
+    }
+}
+
+/// Check that `to_elements` and `from_elements` follow a layout version
+///
+/// Every field of a probe is set to a distinct marker, so any reordering shows up as
+/// the marker of another field.
+///
+/// # Errors
+///
+/// [`PublicInputLayoutError::UnknownVersion`] for a version without a layout, otherwise the
+/// first length, order or round-trip mismatch found
+pub fn assert_public_input_layout(version: u32) -> Result<(), PublicInputLayoutError> {
+    let fields = public_input_fields(version).ok_or(PublicInputLayoutError::UnknownVersion(version))?;
+    let marker = |name: &str| {
+        let position = fields.iter().position(|field| *field == name);
+        BaseElement::new(position.map_or(0, |i| i as u64 + 1))
+    };
+
+    let probe = BurnMintPublicInputs {
+        burn_amount: marker("burn_amount"),
+        mint_amount: marker("mint_amount"),
+        txn_hash: marker("txn_hash"),
+        recipient_hash: marker("recipient_hash"),
+        recipient_binding: core::array::from_fn(|i| marker(&format!("recipient_binding[{i}]"))),
+        state: marker("state"),
+        tx_prefix_hash_0: marker("tx_prefix_hash_0"),
+        tx_prefix_hash_1: marker("tx_prefix_hash_1"),
+        tx_prefix_hash_2: marker("tx_prefix_hash_2"),
+        tx_prefix_hash_3: marker("tx_prefix_hash_3"),
+        network_id: marker("network_id"),
+        target_chain_id: marker("target_chain_id"),
+        commitment_version: marker("commitment_version"),
+        fee_bps: marker("fee_bps"),
+        nullifier: core::array::from_fn(|i| marker(&format!("nullifier[{i}]"))),
+        commitment: core::array::from_fn(|i| marker(&format!("commitment[{i}]"))),
+        outputs: Vec::new(),
+    };
+
+    let elements = probe.to_elements();
+    if elements.len() != fields.len() {
+        return Err(PublicInputLayoutError::Length {
+            version,
+            expected: fields.len(),
+            actual: elements.len(),
+        });
+    }
+    for (index, element) in elements.iter().enumerate() {
+        if *element != BaseElement::new(index as u64 + 1) {
+            let actual = element
+                .as_int()
+                .checked_sub(1)
+                .and_then(|i| usize::try_from(i).ok())
+                .and_then(|i| fields.get(i).copied())
+                .unwrap_or("a field outside the layout");
+            return Err(PublicInputLayoutError::Reordered {
+                version,
+                index,
+                expected: fields[index],
+                actual,
+            });
+        }
+    }
+
+    let round_trip = BurnMintPublicInputs::from_elements(&elements).map(|inputs| inputs.to_elements());
+    if round_trip.as_deref() != Some(elements.as_slice()) {
+        return Err(PublicInputLayoutError::RoundTrip);
+    }
+    Ok(())
+}
+
+/// Generation of the public input test vectors
+#[cfg(any(test, feature = "testing"))]
+pub(crate) mod fixtures {
+    use super::{public_input_fields, PUBLIC_INPUT_LAYOUT_VERSION};
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+    use crate::fees::FeeModel;
+    use serde::{Deserialize, Serialize};
+    use std::path::Path;
+    use winter_math::ToElements;
+
+    /// Claim a test vector is generated from
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub(crate) struct VectorClaim {
+        pub(crate) burn_amount: u64,
+        pub(crate) mint_amount: u64,
+        pub(crate) tx_prefix_hash: String,
+        pub(crate) recipient: String,
+        pub(crate) secret: String,
+        pub(crate) network_id: u32,
+        pub(crate) target_chain_id: u32,
+        pub(crate) commitment_version: u32,
+        pub(crate) fee_bps: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub(crate) struct Vector {
+        pub(crate) name: String,
+        pub(crate) claim: VectorClaim,
+        pub(crate) elements: Vec<u64>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub(crate) struct VectorFile {
+        pub(crate) version: u32,
+        pub(crate) fields: Vec<String>,
+        pub(crate) vectors: Vec<Vector>,
+    }
+
+    pub(crate) fn canonical_claims() -> Vec<(&'static str, VectorClaim)> {
+        let claim = |burn_amount, mint_amount, target_chain_id, fee_bps| VectorClaim {
+            burn_amount,
+            mint_amount,
+            tx_prefix_hash: hex::encode([7u8; 32]),
+            recipient: hex::encode([0x12u8; 20]),
+            secret: hex::encode([9u8; 32]),
+            network_id: 1,
+            target_chain_id,
+            commitment_version: 1,
+            fee_bps,
+        };
+        vec![
+            ("standard-burn", claim(8_000_000, 8_000_000, 42161, 0)),
+            ("large-burn", claim(8_000_000_000, 8_000_000_000, 42161, 0)),
+            ("other-chain", claim(8_000_000, 8_000_000, 421614, 0)),
+            ("fee-network", claim(8_000_000, 7_960_000, 42161, 50)),
+        ]
+    }
+
+    pub(crate) fn elements_for(claim: &VectorClaim) -> Vec<u64> {
+        let (_, public_inputs) = XfgBurnMintProver::default()
+            .with_fee_model(FeeModel::new(claim.fee_bps).unwrap())
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: claim.burn_amount,
+                    mint_amount: claim.mint_amount,
+                    tx_prefix_hash: hex::decode(&claim.tx_prefix_hash).unwrap().try_into().unwrap(),
+                    recipient_address: &hex::decode(&claim.recipient).unwrap().try_into().unwrap(),
+                    network_id: claim.network_id,
+                    target_chain_id: claim.target_chain_id,
+                    commitment_version: claim.commitment_version,
+                },
+                &hex::decode(&claim.secret).unwrap(),
+            )
+            .expect("Proof generation should succeed");
+        public_inputs.to_elements().iter().map(|e| e.as_int()).collect()
+    }
+
+    /// Write the vectors for the current layout version under `public_inputs/` in `root`
+    pub(crate) fn write(root: &Path) -> crate::Result<()> {
+        let version = PUBLIC_INPUT_LAYOUT_VERSION;
+        let file = VectorFile {
+            version,
+            fields: public_input_fields(version)
+                .expect("the current layout version has fields")
+                .iter()
+                .map(|f| f.to_string())
+                .collect(),
+            vectors: canonical_claims()
+                .into_iter()
+                .map(|(name, claim)| Vector {
+                    name: name.to_string(),
+                    elements: elements_for(&claim),
+                    claim,
+                })
+                .collect(),
+        };
+        let dir = root.join("public_inputs");
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(dir.join(format!("layout-v{}.json", version)), serde_json::to_string_pretty(&file)? + "\n")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::{canonical_claims, elements_for, VectorFile};
+    use super::*;
+
+    #[test]
+    fn test_layout_self_check() {
+        assert_eq!(assert_public_input_layout(PUBLIC_INPUT_LAYOUT_VERSION), Ok(()));
+        assert_eq!(
+            assert_public_input_layout(3),
+            Err(PublicInputLayoutError::UnknownVersion(3))
+        );
+        assert!(matches!(
+            assert_public_input_layout(1),
+            Err(PublicInputLayoutError::Length { version: 1, expected: 23, .. })
+        ));
+        assert_eq!(public_input_fields(1).unwrap()[13], "nullifier");
+        assert_eq!(public_input_fields(2).unwrap()[13], "nullifier[0]");
+    }
+
+    #[test]
+    fn test_vectors_match_prover() {
+        let file: VectorFile =
+            serde_json::from_str(include_str!("../tests/fixtures/public_inputs/layout-v2.json")).unwrap();
+        assert_eq!(file.version, PUBLIC_INPUT_LAYOUT_VERSION);
+        assert_eq!(file.fields, public_input_fields(file.version).unwrap());
+        assert_eq!(file.vectors.len(), canonical_claims().len());
+
+        for vector in &file.vectors {
+            assert_eq!(vector.elements, elements_for(&vector.claim), "vector {} changed", vector.name);
+            let elements: Vec<BaseElement> = vector.elements.iter().copied().map(BaseElement::new).collect();
+            let public_inputs = BurnMintPublicInputs::from_elements(&elements).unwrap();
+            assert_eq!(public_inputs.burn_amount.as_int(), vector.claim.burn_amount);
+            assert_eq!(public_inputs.fee_bps.as_int(), u64::from(vector.claim.fee_bps));
+        }
+    }
+
+}
@@ -0,0 +1,291 @@
+//! Replay Protection Scenarios for XFG Burn & Mint Proofs
+//!
+//! This module provides reusable replay-attack scenarios for testing verifiers.
+//! Each scenario builds a genuine proof, checks that the honest claim verifies, then
+//! replays the proof against a tampered claim and reports whether it was rejected.
+//!
+//! ## Scenarios
+//! - **Cross-network**: a testnet proof claimed on mainnet (and vice versa)
+//! - **Reused nullifier**: two claims sharing one nullifier in the same batch
+//! - **Commitment version mismatch**: a proof claimed under a different commitment version
+//!
+//! Downstream gateways can run `run_all_replay_scenarios` against their configuration
+//! to confirm replayed proofs are rejected end to end.
+
+use crate::{
+    burn_mint_air::BurnMintPublicInputs,
+    burn_mint_prover::{BurnMintRequest, XfgBurnMintProver},
+    burn_mint_verifier::{BatchBurnMintVerifier, XfgBurnMintVerifier},
+    networks::{ARBITRUM_ONE_CHAIN_ID, ARBITRUM_SEPOLIA_CHAIN_ID, FUEGO_MAINNET_NETWORK_ID, FUEGO_TESTNET_NETWORK_ID},
+    Result,
+};
+use sha3::{Digest, Keccak256};
+use std::fmt::{Display, Formatter};
+use winterfell::{math::fields::f64::BaseElement, StarkProof};
+
+/// Standard 0.8 XFG burn in atomic units
+const REPLAY_BURN_AMOUNT: u64 = crate::constants::STANDARD_BURN_ATOMIC;
+
+/// Recipient used by replay fixtures
+const REPLAY_RECIPIENT: [u8; 20] = [0x12; 20];
+
+/// Network a proof is generated for or claimed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplayNetwork {
+    /// Fuego network ID
+    pub network_id: u32,
+    /// HEAT target chain ID
+    pub target_chain_id: u32,
+}
+
+impl ReplayNetwork {
+    /// Fuego mainnet minting on Arbitrum One
+    pub const MAINNET: ReplayNetwork = ReplayNetwork {
+        network_id: FUEGO_MAINNET_NETWORK_ID,
+        target_chain_id: ARBITRUM_ONE_CHAIN_ID,
+    };
+
+    /// Fuego testnet minting on Arbitrum Sepolia
+    pub const TESTNET: ReplayNetwork = ReplayNetwork {
+        network_id: FUEGO_TESTNET_NETWORK_ID,
+        target_chain_id: ARBITRUM_SEPOLIA_CHAIN_ID,
+    };
+}
+
+/// Replay attack scenario
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayScenario {
+    /// Proof generated on one network, claimed on another
+    CrossNetwork {
+        /// Network the proof was generated for
+        proven_on: ReplayNetwork,
+        /// Network the proof is claimed on
+        claimed_on: ReplayNetwork,
+    },
+    /// Two claims on the same burn, sharing a nullifier, submitted together
+    ReusedNullifier,
+    /// Proof generated under one commitment version, claimed under another
+    CommitmentVersionMismatch {
+        /// Commitment version the proof was generated with
+        proven_version: u32,
+        /// Commitment version in the claim
+        claimed_version: u32,
+    },
+}
+
+impl ReplayScenario {
+    /// All standard replay scenarios
+    #[must_use]
+    pub fn all() -> Vec<ReplayScenario> {
+        vec![
+            ReplayScenario::CrossNetwork {
+                proven_on: ReplayNetwork::TESTNET,
+                claimed_on: ReplayNetwork::MAINNET,
+            },
+            ReplayScenario::CrossNetwork {
+                proven_on: ReplayNetwork::MAINNET,
+                claimed_on: ReplayNetwork::TESTNET,
+            },
+            ReplayScenario::ReusedNullifier,
+            ReplayScenario::CommitmentVersionMismatch {
+                proven_version: 1,
+                claimed_version: 2,
+            },
+        ]
+    }
+}
+
+impl Display for ReplayScenario {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayScenario::CrossNetwork {
+                proven_on,
+                claimed_on,
+            } => write!(
+                f,
+                "cross-network replay (network {} → {})",
+                proven_on.network_id, claimed_on.network_id
+            ),
+            ReplayScenario::ReusedNullifier => write!(f, "reused nullifier"),
+            ReplayScenario::CommitmentVersionMismatch {
+                proven_version,
+                claimed_version,
+            } => write!(
+                f,
+                "commitment version mismatch (v{proven_version} → v{claimed_version})"
+            ),
+        }
+    }
+}
+
+/// Outcome of running a replay scenario
+#[derive(Debug, Clone)]
+pub struct ReplayScenarioOutcome {
+    /// Scenario that was run
+    pub scenario: ReplayScenario,
+    /// Whether the verifier rejected the replayed claim
+    pub rejected: bool,
+    /// Human-readable description of the verifier's response
+    pub detail: String,
+}
+
+/// Honest proof together with the public inputs it binds
+#[derive(Debug, Clone)]
+pub struct ReplayFixture {
+    /// Generated proof
+    pub proof: StarkProof,
+    /// Public inputs of the honest claim
+    pub public_inputs: BurnMintPublicInputs,
+}
+
+impl ReplayFixture {
+    /// Generate a fixture for a network and commitment version
+    ///
+    /// `seed` selects the burn transaction; fixtures with the same `secret` and `seed` share
+    /// a nullifier.
+    ///
+    /// # Errors
+    ///
+    /// Errors of [`XfgBurnMintProver::prove_burn_mint_with_public_inputs`]
+    pub fn generate(
+        prover: &XfgBurnMintProver,
+        network: ReplayNetwork,
+        commitment_version: u32,
+        secret: &[u8],
+        seed: u64,
+    ) -> Result<Self> {
+        let (proof, public_inputs) = prover.prove_burn_mint_with_public_inputs(
+            &BurnMintRequest {
+                burn_amount: REPLAY_BURN_AMOUNT,
+                mint_amount: REPLAY_BURN_AMOUNT,
+                tx_prefix_hash: fixture_tx_prefix_hash(seed),
+                recipient_address: &REPLAY_RECIPIENT,
+                network_id: network.network_id,
+                target_chain_id: network.target_chain_id,
+                commitment_version,
+            },
+            secret,
+        )?;
+
+        Ok(Self {
+            proof,
+            public_inputs,
+        })
+    }
+}
+
+/// Derive a deterministic transaction prefix hash for a fixture seed
+fn fixture_tx_prefix_hash(seed: u64) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(b"xfg-replay-fixture");
+    hasher.update(seed.to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// Check that an honest claim verifies, so rejections are not vacuous
+fn ensure_honest_claim_verifies(
+    verifier: &XfgBurnMintVerifier,
+    fixture: &ReplayFixture,
+) -> Result<()> {
+    if verifier.verify_with_public_inputs(&fixture.proof, &fixture.public_inputs)? {
+        Ok(())
+    } else {
+        Err(crate::XfgStarkError::CryptoError(
+            "Honest replay fixture failed verification".to_string(),
+        ))
+    }
+}
+
+/// Describe a single-claim verification response
+fn describe_claim(
+    verifier: &XfgBurnMintVerifier,
+    fixture: &ReplayFixture,
+    claim: &BurnMintPublicInputs,
+) -> (bool, String) {
+    match verifier.verify_with_public_inputs(&fixture.proof, claim) {
+        Ok(true) => (false, "verifier accepted the replayed claim".to_string()),
+        Ok(false) => (true, "proof rejected for claim".to_string()),
+        Err(e) => (true, format!("claim rejected: {e}")),
+    }
+}
+
+/// Run a single replay scenario
+///
+/// # Errors
+///
+/// Proving errors, or [`crate::XfgStarkError::CryptoError`] if an honest claim fails to
+/// verify, which would make the scenario's rejection vacuous
+pub fn run_replay_scenario(
+    prover: &XfgBurnMintProver,
+    verifier: &XfgBurnMintVerifier,
+    scenario: ReplayScenario,
+) -> Result<ReplayScenarioOutcome> {
+    let secret = [7u8; 32];
+
+    let (rejected, detail) = match scenario {
+        ReplayScenario::CrossNetwork {
+            proven_on,
+            claimed_on,
+        } => {
+            let fixture = ReplayFixture::generate(prover, proven_on, 1, &secret, 1)?;
+            ensure_honest_claim_verifies(verifier, &fixture)?;
+
+            let mut claim = fixture.public_inputs.clone();
+            claim.network_id = BaseElement::from(claimed_on.network_id);
+            claim.target_chain_id = BaseElement::from(claimed_on.target_chain_id);
+            describe_claim(verifier, &fixture, &claim)
+        }
+        ReplayScenario::ReusedNullifier => {
+            // The same burn proven twice, under another claim, keeps its nullifier
+            let first = ReplayFixture::generate(prover, ReplayNetwork::MAINNET, 1, &secret, 1)?;
+            let second = ReplayFixture::generate(prover, ReplayNetwork::MAINNET, 2, &secret, 1)?;
+            ensure_honest_claim_verifies(verifier, &first)?;
+            ensure_honest_claim_verifies(verifier, &second)?;
+
+            let batch_verifier = BatchBurnMintVerifier::with_verifier(verifier.clone());
+            let results = batch_verifier.verify_batch(&[
+                (&first.proof, &first.public_inputs),
+                (&second.proof, &second.public_inputs),
+            ])?;
+
+            if results == [true, false] {
+                (true, "second claim with reused nullifier rejected".to_string())
+            } else {
+                (false, format!("batch verifier returned {results:?}"))
+            }
+        }
+        ReplayScenario::CommitmentVersionMismatch {
+            proven_version,
+            claimed_version,
+        } => {
+            let fixture =
+                ReplayFixture::generate(prover, ReplayNetwork::MAINNET, proven_version, &secret, 1)?;
+            ensure_honest_claim_verifies(verifier, &fixture)?;
+
+            let mut claim = fixture.public_inputs.clone();
+            claim.commitment_version = BaseElement::from(claimed_version);
+            describe_claim(verifier, &fixture, &claim)
+        }
+    };
+
+    Ok(ReplayScenarioOutcome {
+        scenario,
+        rejected,
+        detail,
+    })
+}
+
+/// Run all standard replay scenarios
+///
+/// # Errors
+///
+/// Errors of [`run_replay_scenario`]
+pub fn run_all_replay_scenarios(
+    prover: &XfgBurnMintProver,
+    verifier: &XfgBurnMintVerifier,
+) -> Result<Vec<ReplayScenarioOutcome>> {
+    ReplayScenario::all()
+        .into_iter()
+        .map(|scenario| run_replay_scenario(prover, verifier, scenario))
+        .collect()
+}
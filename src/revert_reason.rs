@@ -0,0 +1,542 @@
+//! Revert Reason Decoding for Mint Submissions
+//!
+//! A reverted mint transaction returns ABI-encoded revert data, which a bare
+//! transaction hash hides. [`RevertDecoder`] turns that data into a [`RevertReason`]:
+//!
+//! - `Error(string)`: the message, mapped onto a [`HeatContractError`] when the HEAT
+//!   contracts raise it with `require`
+//! - `Panic(uint256)`: the Solidity panic code and its meaning
+//! - custom errors: the HEAT verifier's own errors are built in; further errors can be
+//!   loaded from a contract ABI with [`RevertDecoder::with_abi_json`]
+//! - no data at all, usually an out-of-gas failure or a bare `revert()`
+//!
+//! Each reason carries a hint on what the submitter should do next.
+
+use sha3::{Digest, Keccak256};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+/// Selector of the standard `Error(string)` revert
+pub const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Selector of the standard `Panic(uint256)` revert
+pub const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// ABI word size
+const WORD: usize = 32;
+
+/// First four bytes of the Keccak-256 of a function or error signature
+#[must_use]
+pub fn selector(signature: &str) -> [u8; 4] {
+    let digest = Keccak256::digest(signature.as_bytes());
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+/// Failures the HEAT verifier and token contracts report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HeatContractError {
+    /// The burn's nullifier was already spent on a previous mint
+    NullifierAlreadyUsed,
+    /// The burn's commitment was already used on a previous mint
+    CommitmentAlreadyUsed,
+    /// The proof was already submitted
+    ProofAlreadyUsed,
+    /// The STARK proof did not verify
+    InvalidProof,
+    /// The proof has the wrong size
+    InvalidProofSize,
+    /// The public inputs have the wrong shape
+    InvalidPublicInputs,
+    /// The public inputs name another Fuego network
+    InvalidNetworkId,
+    /// The recipient is the zero address
+    InvalidRecipient,
+    /// The recipient hash does not bind the submitting recipient
+    RecipientHashMismatch,
+    /// The mint amount is not a burn tier
+    InvalidAmount,
+    /// The commitment version is not supported by the contract
+    UnsupportedCommitmentVersion,
+    /// Eldernode consensus verification failed or is unavailable
+    EldernodeConsensusFailed,
+    /// Minting would exceed the backstop supply cap
+    MaxSupplyExceeded,
+    /// The caller is not allowed to mint
+    Unauthorized,
+}
+
+impl HeatContractError {
+    /// Every HEAT contract error
+    pub const ALL: [HeatContractError; 14] = [
+        HeatContractError::NullifierAlreadyUsed,
+        HeatContractError::CommitmentAlreadyUsed,
+        HeatContractError::ProofAlreadyUsed,
+        HeatContractError::InvalidProof,
+        HeatContractError::InvalidProofSize,
+        HeatContractError::InvalidPublicInputs,
+        HeatContractError::InvalidNetworkId,
+        HeatContractError::InvalidRecipient,
+        HeatContractError::RecipientHashMismatch,
+        HeatContractError::InvalidAmount,
+        HeatContractError::UnsupportedCommitmentVersion,
+        HeatContractError::EldernodeConsensusFailed,
+        HeatContractError::MaxSupplyExceeded,
+        HeatContractError::Unauthorized,
+    ];
+
+    /// Custom error name
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            HeatContractError::NullifierAlreadyUsed => "NullifierAlreadyUsed",
+            HeatContractError::CommitmentAlreadyUsed => "CommitmentAlreadyUsed",
+            HeatContractError::ProofAlreadyUsed => "ProofAlreadyUsed",
+            HeatContractError::InvalidProof => "InvalidProof",
+            HeatContractError::InvalidProofSize => "InvalidProofSize",
+            HeatContractError::InvalidPublicInputs => "InvalidPublicInputs",
+            HeatContractError::InvalidNetworkId => "InvalidNetworkId",
+            HeatContractError::InvalidRecipient => "InvalidRecipient",
+            HeatContractError::RecipientHashMismatch => "RecipientHashMismatch",
+            HeatContractError::InvalidAmount => "InvalidAmount",
+            HeatContractError::UnsupportedCommitmentVersion => "UnsupportedCommitmentVersion",
+            HeatContractError::EldernodeConsensusFailed => "EldernodeConsensusFailed",
+            HeatContractError::MaxSupplyExceeded => "MaxSupplyExceeded",
+            HeatContractError::Unauthorized => "Unauthorized",
+        }
+    }
+
+    /// Custom error selector (the errors take no arguments)
+    #[must_use]
+    pub fn selector(self) -> [u8; 4] {
+        selector(&format!("{}()", self.name()))
+    }
+
+    /// Map a `require` message of the deployed contracts onto an error
+    #[must_use]
+    pub fn from_message(message: &str) -> Option<Self> {
+        let error = match message {
+            "Nullifier already used" => HeatContractError::NullifierAlreadyUsed,
+            "Commitment already used" => HeatContractError::CommitmentAlreadyUsed,
+            "Proof already used" => HeatContractError::ProofAlreadyUsed,
+            "Invalid STARK proof" => HeatContractError::InvalidProof,
+            "Invalid proof size" => HeatContractError::InvalidProofSize,
+            "Invalid public inputs count" => HeatContractError::InvalidPublicInputs,
+            m if m.starts_with("Invalid public inputs length") => HeatContractError::InvalidPublicInputs,
+            "Invalid network ID" => HeatContractError::InvalidNetworkId,
+            "Invalid recipient address" | "Invalid recipient" | "Cannot mint to zero address" => {
+                HeatContractError::InvalidRecipient
+            }
+            "Recipient hash mismatch" => HeatContractError::RecipientHashMismatch,
+            "Amount must be greater than 0" => HeatContractError::InvalidAmount,
+            m if m.starts_with("Amount must be 8M HEAT") => HeatContractError::InvalidAmount,
+            "Unsupported commitment version" => HeatContractError::UnsupportedCommitmentVersion,
+            "Eldernode consensus verification failed" | "Eldernode verification required but no verifier set" => {
+                HeatContractError::EldernodeConsensusFailed
+            }
+            "Would exceed backstop max supply" => HeatContractError::MaxSupplyExceeded,
+            "Only minter can mint from burn proofs" | "Only Arbitrum Outbox" => HeatContractError::Unauthorized,
+            _ => return None,
+        };
+        Some(error)
+    }
+
+    /// What the submitter should do about it
+    #[must_use]
+    pub const fn hint(self) -> &'static str {
+        match self {
+            HeatContractError::NullifierAlreadyUsed | HeatContractError::CommitmentAlreadyUsed => {
+                "this burn has already been minted; check the recipient's HEAT balance"
+            }
+            HeatContractError::ProofAlreadyUsed => "this proof was already submitted; do not resubmit it",
+            HeatContractError::InvalidProof => "regenerate the proof and verify it locally before submitting",
+            HeatContractError::InvalidProofSize | HeatContractError::InvalidPublicInputs => {
+                "the calldata does not match the contract's ABI; check the CLI and contract versions"
+            }
+            HeatContractError::InvalidNetworkId => "the proof targets another Fuego network than this contract",
+            HeatContractError::InvalidRecipient | HeatContractError::RecipientHashMismatch => {
+                "submit from or to the recipient address the proof was generated for"
+            }
+            HeatContractError::InvalidAmount => "the burn amount is not a supported burn tier",
+            HeatContractError::UnsupportedCommitmentVersion => {
+                "the contract does not accept this commitment version; upgrade the CLI or the contract"
+            }
+            HeatContractError::EldernodeConsensusFailed => {
+                "obtain a fresh Eldernode verification for the burn and retry"
+            }
+            HeatContractError::MaxSupplyExceeded => "the HEAT supply cap is reached; minting is closed",
+            HeatContractError::Unauthorized => "submit through the HEAT verifier contract, not the token",
+        }
+    }
+}
+
+impl Display for HeatContractError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Decoded reason a transaction reverted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RevertReason {
+    /// No revert data: out of gas, or `revert()` without a reason
+    Empty,
+    /// A known HEAT contract failure
+    Heat(HeatContractError),
+    /// `Error(string)` with a message the HEAT contracts do not raise
+    Message(String),
+    /// `Panic(uint256)`
+    Panic {
+        /// Solidity panic code
+        code: u64,
+    },
+    /// A custom error from a loaded ABI
+    Custom {
+        /// Error name
+        name: String,
+        /// Decoded arguments as `(name, value)`
+        arguments: Vec<(String, String)>,
+    },
+    /// Revert data no known error matches
+    Unknown {
+        /// Raw revert data
+        data: Vec<u8>,
+    },
+}
+
+impl RevertReason {
+    /// What the submitter should do about it
+    #[must_use]
+    pub fn hint(&self) -> &'static str {
+        match self {
+            RevertReason::Empty => "the transaction likely ran out of gas; raise the gas limit and retry",
+            RevertReason::Heat(error) => error.hint(),
+            RevertReason::Panic { .. } => "the contract hit an internal error; report it with the transaction hash",
+            RevertReason::Message(_) | RevertReason::Custom { .. } | RevertReason::Unknown { .. } => {
+                "check the contract's documentation for this error"
+            }
+        }
+    }
+}
+
+impl Display for RevertReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RevertReason::Empty => write!(f, "reverted without data"),
+            RevertReason::Heat(error) => write!(f, "{error}"),
+            RevertReason::Message(message) => write!(f, "{message:?}"),
+            RevertReason::Panic { code } => write!(f, "panic 0x{:02x} ({})", code, panic_description(*code)),
+            RevertReason::Custom { name, arguments } => {
+                let arguments: Vec<String> = arguments.iter().map(|(n, v)| format!("{n}: {v}")).collect();
+                write!(f, "{}({})", name, arguments.join(", "))
+            }
+            RevertReason::Unknown { data } => write!(f, "unknown revert data 0x{}", hex::encode(data)),
+        }
+    }
+}
+
+/// Meaning of a Solidity panic code
+#[must_use]
+pub fn panic_description(code: u64) -> &'static str {
+    match code {
+        0x00 => "generic compiler panic",
+        0x01 => "assertion failed",
+        0x11 => "arithmetic overflow or underflow",
+        0x12 => "division or modulo by zero",
+        0x21 => "invalid enum value",
+        0x22 => "corrupt storage byte array",
+        0x31 => "pop on empty array",
+        0x32 => "array index out of bounds",
+        0x41 => "out of memory",
+        0x51 => "call to uninitialized function",
+        _ => "unknown panic code",
+    }
+}
+
+/// A reverted mint submission
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Transaction {tx_hash} reverted: {reason}; {hint}", hint = reason.hint())]
+pub struct SubmissionReverted {
+    /// Hash of the reverted transaction
+    pub tx_hash: String,
+    /// Decoded revert reason
+    pub reason: RevertReason,
+}
+
+/// Custom error definition loaded from an ABI
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CustomError {
+    /// Error name
+    name: String,
+    /// Argument `(name, type)` pairs
+    inputs: Vec<(String, String)>,
+}
+
+/// ABI loading errors
+#[derive(Debug, Error)]
+pub enum RevertDecodeError {
+    /// ABI is not a JSON array of ABI entries
+    #[error("Invalid contract ABI: {0}")]
+    InvalidAbi(String),
+}
+
+/// Decodes revert data against the HEAT contracts' errors and any loaded ABIs
+#[derive(Debug, Clone)]
+pub struct RevertDecoder {
+    /// Built-in HEAT verifier errors by selector
+    heat_errors: HashMap<[u8; 4], HeatContractError>,
+    /// Errors loaded from ABIs by selector
+    custom_errors: HashMap<[u8; 4], CustomError>,
+}
+
+impl Default for RevertDecoder {
+    fn default() -> Self {
+        Self {
+            heat_errors: HeatContractError::ALL.iter().map(|e| (e.selector(), *e)).collect(),
+            custom_errors: HashMap::new(),
+        }
+    }
+}
+
+impl RevertDecoder {
+    /// Decoder for the HEAT contracts' errors
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the custom errors of a contract ABI (the `abi` array of a Solidity artifact)
+    ///
+    /// # Errors
+    ///
+    /// [`RevertDecodeError::InvalidAbi`] if `abi` is not a JSON array or an error entry lacks a
+    /// name or an input type
+    pub fn with_abi_json(mut self, abi: &str) -> Result<Self, RevertDecodeError> {
+        let entries: Vec<serde_json::Value> =
+            serde_json::from_str(abi).map_err(|e| RevertDecodeError::InvalidAbi(e.to_string()))?;
+
+        for entry in entries.iter().filter(|e| e["type"] == "error") {
+            let name = entry["name"]
+                .as_str()
+                .ok_or_else(|| RevertDecodeError::InvalidAbi("error entry without a name".to_string()))?;
+            let inputs = entry["inputs"]
+                .as_array()
+                .map(|inputs| {
+                    inputs
+                        .iter()
+                        .map(|input| {
+                            let kind = input["type"].as_str().ok_or_else(|| {
+                                RevertDecodeError::InvalidAbi(format!("input of {name} without a type"))
+                            })?;
+                            Ok((input["name"].as_str().unwrap_or_default().to_string(), kind.to_string()))
+                        })
+                        .collect::<Result<Vec<_>, RevertDecodeError>>()
+                })
+                .transpose()?
+                .unwrap_or_default();
+
+            let types: Vec<&str> = inputs.iter().map(|(_, kind)| kind.as_str()).collect();
+            let signature = format!("{}({})", name, types.join(","));
+            self.custom_errors.insert(
+                selector(&signature),
+                CustomError {
+                    name: name.to_string(),
+                    inputs,
+                },
+            );
+        }
+
+        Ok(self)
+    }
+
+    /// Decode raw revert data
+    pub fn decode(&self, data: &[u8]) -> RevertReason {
+        if data.is_empty() {
+            return RevertReason::Empty;
+        }
+        let unknown = || RevertReason::Unknown { data: data.to_vec() };
+        let Some((selector, payload)) = data.split_first_chunk::<4>() else {
+            return unknown();
+        };
+
+        if *selector == ERROR_STRING_SELECTOR {
+            return match decode_string(payload, 0) {
+                Some(message) => match HeatContractError::from_message(&message) {
+                    Some(error) => RevertReason::Heat(error),
+                    None => RevertReason::Message(message),
+                },
+                None => unknown(),
+            };
+        }
+        if *selector == PANIC_SELECTOR {
+            return match word(payload, 0).and_then(word_to_u64) {
+                Some(code) => RevertReason::Panic { code },
+                None => unknown(),
+            };
+        }
+        if let Some(error) = self.heat_errors.get(selector) {
+            return RevertReason::Heat(*error);
+        }
+        if let Some(error) = self.custom_errors.get(selector) {
+            let arguments = error
+                .inputs
+                .iter()
+                .enumerate()
+                .map(|(i, (name, kind))| Some((name.clone(), decode_value(payload, i, kind)?)))
+                .collect::<Option<Vec<_>>>();
+            return match arguments {
+                Some(arguments) => RevertReason::Custom {
+                    name: error.name.clone(),
+                    arguments,
+                },
+                None => unknown(),
+            };
+        }
+
+        unknown()
+    }
+
+    /// Decode `0x`-prefixed hex revert data, as JSON-RPC nodes return it
+    #[must_use]
+    pub fn decode_hex(&self, data: &str) -> RevertReason {
+        match hex::decode(data.trim_start_matches("0x")) {
+            Ok(bytes) => self.decode(&bytes),
+            Err(_) => RevertReason::Unknown {
+                data: data.as_bytes().to_vec(),
+            },
+        }
+    }
+}
+
+/// The `index`th head word of an ABI payload
+fn word(payload: &[u8], index: usize) -> Option<&[u8]> {
+    payload.get(index * WORD..(index + 1) * WORD)
+}
+
+/// A word that fits a `u64`
+fn word_to_u64(word: &[u8]) -> Option<u64> {
+    let (high, low) = word.split_at(WORD - 8);
+    if high.iter().any(|b| *b != 0) {
+        return None;
+    }
+    Some(u64::from_be_bytes(low.try_into().ok()?))
+}
+
+/// Dynamic `bytes` whose offset sits in head word `index`
+fn decode_bytes(payload: &[u8], index: usize) -> Option<&[u8]> {
+    let offset = usize::try_from(word_to_u64(word(payload, index)?)?).ok()?;
+    let length = usize::try_from(word_to_u64(payload.get(offset..offset.checked_add(WORD)?)?)?).ok()?;
+    let start = offset + WORD;
+    payload.get(start..start.checked_add(length)?)
+}
+
+/// Dynamic `string` whose offset sits in head word `index`
+fn decode_string(payload: &[u8], index: usize) -> Option<String> {
+    String::from_utf8(decode_bytes(payload, index)?.to_vec()).ok()
+}
+
+/// Render argument `index` of ABI type `kind`
+fn decode_value(payload: &[u8], index: usize, kind: &str) -> Option<String> {
+    match kind {
+        "string" => decode_string(payload, index).map(|s| format!("{s:?}")),
+        "bytes" => decode_bytes(payload, index).map(|b| format!("0x{}", hex::encode(b))),
+        "address" => Some(format!("0x{}", hex::encode(&word(payload, index)?[WORD - 20..]))),
+        "bool" => match word_to_u64(word(payload, index)?)? {
+            0 => Some("false".to_string()),
+            1 => Some("true".to_string()),
+            _ => None,
+        },
+        k if k.starts_with("uint") => {
+            let word = word(payload, index)?;
+            Some(match word_to_u64(word) {
+                Some(value) => value.to_string(),
+                None => format!("0x{}", hex::encode(word)),
+            })
+        }
+        // Signed integers, fixed bytes and anything else: the raw head word
+        _ => Some(format!("0x{}", hex::encode(word(payload, index)?))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ABI-encode `Error(string)` revert data
+    fn error_string(message: &str) -> Vec<u8> {
+        let mut data = ERROR_STRING_SELECTOR.to_vec();
+        let mut offset = [0u8; WORD];
+        offset[WORD - 1] = 0x20;
+        data.extend_from_slice(&offset);
+        let mut length = [0u8; WORD];
+        length[WORD - 8..].copy_from_slice(&(message.len() as u64).to_be_bytes());
+        data.extend_from_slice(&length);
+        data.extend_from_slice(message.as_bytes());
+        data.resize(data.len().div_ceil(WORD) * WORD + 4, 0);
+        data
+    }
+
+    #[test]
+    fn test_standard_reverts() {
+        let decoder = RevertDecoder::new();
+        assert_eq!(selector("Error(string)"), ERROR_STRING_SELECTOR);
+        assert_eq!(selector("Panic(uint256)"), PANIC_SELECTOR);
+
+        assert_eq!(
+            decoder.decode(&error_string("Nullifier already used")),
+            RevertReason::Heat(HeatContractError::NullifierAlreadyUsed)
+        );
+        assert_eq!(
+            decoder.decode(&error_string("Pausable: paused")),
+            RevertReason::Message("Pausable: paused".to_string())
+        );
+
+        let mut panic = PANIC_SELECTOR.to_vec();
+        panic.extend_from_slice(&[0u8; WORD - 1]);
+        panic.push(0x11);
+        let reason = decoder.decode(&panic);
+        assert_eq!(reason, RevertReason::Panic { code: 0x11 });
+        assert_eq!(reason.to_string(), "panic 0x11 (arithmetic overflow or underflow)");
+
+        assert_eq!(decoder.decode_hex("0x"), RevertReason::Empty);
+        assert!(matches!(decoder.decode(&error_string("x")[..40]), RevertReason::Unknown { .. }));
+    }
+
+    #[test]
+    fn test_custom_errors() {
+        let decoder = RevertDecoder::new();
+        let invalid_proof = HeatContractError::InvalidProof.selector();
+        assert_eq!(decoder.decode(&invalid_proof), RevertReason::Heat(HeatContractError::InvalidProof));
+
+        let abi = r#"[
+            {"type": "function", "name": "mint", "inputs": []},
+            {"type": "error", "name": "StaleRoot", "inputs": [
+                {"name": "root", "type": "bytes32"},
+                {"name": "age", "type": "uint256"},
+                {"name": "submitter", "type": "address"}
+            ]}
+        ]"#;
+        let decoder = decoder.with_abi_json(abi).unwrap();
+        let mut data = selector("StaleRoot(bytes32,uint256,address)").to_vec();
+        data.extend_from_slice(&[0xab; WORD]);
+        let mut age = [0u8; WORD];
+        age[WORD - 1] = 7;
+        data.extend_from_slice(&age);
+        let mut submitter = [0u8; WORD];
+        submitter[WORD - 20..].copy_from_slice(&[0x12; 20]);
+        data.extend_from_slice(&submitter);
+
+        let reason = decoder.decode(&data);
+        assert_eq!(
+            reason.to_string(),
+            format!("StaleRoot(root: 0x{}, age: 7, submitter: 0x{})", "ab".repeat(32), "12".repeat(20))
+        );
+
+        let reverted = SubmissionReverted {
+            tx_hash: "0xdead".to_string(),
+            reason: RevertReason::Heat(HeatContractError::NullifierAlreadyUsed),
+        };
+        assert!(reverted.to_string().contains("already been minted"));
+        assert!(RevertDecoder::new().with_abi_json("{}").is_err());
+    }
+}
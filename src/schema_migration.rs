@@ -0,0 +1,302 @@
+//! Data Package Schema Versions and Migrations
+//!
+//! Every [`StarkProofDataPackage`] records the format it was written in as
+//! `metadata.version`. Loading a package runs the raw JSON through the registered
+//! [`SchemaMigration`]s, one version step at a time, until it reaches
+//! [`PACKAGE_SCHEMA_VERSION`]; only then is it deserialized, strictly, into today's
+//! structs. Packages from a newer release, or from a version that was never
+//! registered, are rejected rather than guessed at.
+//!
+//! | Version | Change |
+//! |---------|--------|
+//! | 1.0 | Original format |
+//! | 1.1 | Protocol fee and statement hash function are explicit |
+//! | 2.0 | The secret may be held in a keystore (`secret.secret_ref`) instead of `secret.secret_key`; unknown fields are rejected |
+//!
+//! A migrated package remembers its original version in `metadata.migrated_from`, which
+//! package signatures cover in place of `metadata.version`, so signatures made before a
+//! migration keep verifying.
+
+use crate::proof_data_schema::StarkProofDataPackage;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Data package schema version written by this release
+pub const PACKAGE_SCHEMA_VERSION: SchemaVersion = SchemaVersion::new(2, 0);
+
+/// Data package schema version (`major.minor`; a patch component is accepted and ignored)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SchemaVersion {
+    /// Major version, bumped when older readers cannot load the new format
+    pub major: u32,
+    /// Minor version
+    pub minor: u32,
+}
+
+impl SchemaVersion {
+    /// Create a schema version
+    #[must_use]
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl fmt::Display for SchemaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.0", self.major, self.minor)
+    }
+}
+
+impl FromStr for SchemaVersion {
+    type Err = SchemaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SchemaError::InvalidVersion(s.to_string());
+        let parts = s
+            .split('.')
+            .map(|part| part.parse::<u32>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>, _>>()?;
+        match parts.as_slice() {
+            [major, minor] | [major, minor, _] => Ok(Self::new(*major, *minor)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// One step in the data package format's history
+#[derive(Debug, Clone, Copy)]
+pub struct SchemaMigration {
+    /// Version the migration reads
+    pub from: SchemaVersion,
+    /// Version the migration writes
+    pub to: SchemaVersion,
+    /// What changed
+    pub summary: &'static str,
+    /// Rewrite a package's JSON from `from` to `to`
+    pub migrate: fn(&mut Map<String, Value>) -> Result<(), String>,
+}
+
+/// Registered migrations, oldest first
+const MIGRATIONS: &[SchemaMigration] = &[
+    SchemaMigration {
+        from: SchemaVersion::new(1, 0),
+        to: SchemaVersion::new(1, 1),
+        summary: "Protocol fee and statement hash function are explicit",
+        migrate: migrate_1_0_to_1_1,
+    },
+    SchemaMigration {
+        from: SchemaVersion::new(1, 1),
+        to: SchemaVersion::new(2, 0),
+        summary: "The secret may be held in a keystore instead of the package",
+        migrate: migrate_1_1_to_2_0,
+    },
+];
+
+/// Registered migrations, oldest first
+#[must_use]
+pub fn schema_migrations() -> &'static [SchemaMigration] {
+    MIGRATIONS
+}
+
+/// 1.0 packages predate protocol fees and selectable hash functions: both default
+fn migrate_1_0_to_1_1(package: &mut Map<String, Value>) -> Result<(), String> {
+    object_field(package, "burn_transaction")?
+        .entry("protocol_fee_bps")
+        .or_insert(Value::from(0));
+    object_field(package, "metadata")?
+        .entry("hash_function")
+        .or_insert(Value::from("blake3"));
+    Ok(())
+}
+
+/// 2.0 makes `secret.secret_key` optional, so a 1.x package must still embed its secret
+fn migrate_1_1_to_2_0(package: &mut Map<String, Value>) -> Result<(), String> {
+    let secret = object_field(package, "secret")?;
+    if secret.contains_key("secret_ref") {
+        return Err("secret.secret_ref requires schema version 2.0".to_string());
+    }
+    match secret.get("secret_key") {
+        Some(Value::String(_)) => Ok(()),
+        _ => Err("secret.secret_key is required before schema version 2.0".to_string()),
+    }
+}
+
+fn object_field<'a>(object: &'a mut Map<String, Value>, field: &str) -> Result<&'a mut Map<String, Value>, String> {
+    object
+        .get_mut(field)
+        .and_then(Value::as_object_mut)
+        .ok_or_else(|| format!("{field} must be an object"))
+}
+
+/// Migrate a data package's JSON to [`PACKAGE_SCHEMA_VERSION`]
+///
+/// Returns the version the package was written in.
+///
+/// # Errors
+///
+/// [`SchemaError::InvalidPackage`] if the package or its metadata is not an object,
+/// [`SchemaError::MissingVersion`] or [`SchemaError::InvalidVersion`] for an absent or
+/// malformed version, [`SchemaError::FutureVersion`] or [`SchemaError::UnknownVersion`]
+/// if no migration path exists, and [`SchemaError::Migration`] if a step fails
+pub fn migrate_package_json(package: &mut Value) -> Result<SchemaVersion, SchemaError> {
+    let package = package
+        .as_object_mut()
+        .ok_or_else(|| SchemaError::InvalidPackage("package must be a JSON object".to_string()))?;
+    let original = package
+        .get("metadata")
+        .and_then(|metadata| metadata.get("version"))
+        .and_then(Value::as_str)
+        .ok_or(SchemaError::MissingVersion)?
+        .to_string();
+    let written: SchemaVersion = original.parse()?;
+
+    let mut version = written;
+    while version != PACKAGE_SCHEMA_VERSION {
+        if version > PACKAGE_SCHEMA_VERSION {
+            return Err(SchemaError::FutureVersion {
+                found: version,
+                supported: PACKAGE_SCHEMA_VERSION,
+            });
+        }
+        let migration = MIGRATIONS
+            .iter()
+            .find(|migration| migration.from == version)
+            .ok_or(SchemaError::UnknownVersion(version))?;
+        (migration.migrate)(package).map_err(|reason| SchemaError::Migration {
+            from: migration.from,
+            to: migration.to,
+            reason,
+        })?;
+        version = migration.to;
+    }
+
+    if version != written {
+        let metadata = package
+            .get_mut("metadata")
+            .and_then(Value::as_object_mut)
+            .ok_or_else(|| SchemaError::InvalidPackage("metadata must be an object".to_string()))?;
+        metadata.insert("version".to_string(), Value::from(version.to_string()));
+        metadata.entry("migrated_from").or_insert(Value::from(original));
+    }
+    Ok(written)
+}
+
+/// Parse a data package of any registered schema version
+///
+/// # Errors
+///
+/// [`SchemaError::InvalidPackage`] if `json` is not a data package, or as
+/// [`migrate_package_json`]
+pub fn parse_package(json: &str) -> Result<StarkProofDataPackage, SchemaError> {
+    let mut package: Value = serde_json::from_str(json).map_err(|e| SchemaError::InvalidPackage(e.to_string()))?;
+    migrate_package_json(&mut package)?;
+    serde_json::from_value(package).map_err(|e| SchemaError::InvalidPackage(e.to_string()))
+}
+
+/// Schema versioning and migration error types
+#[derive(Debug, Error)]
+pub enum SchemaError {
+    /// Package has no `metadata.version`
+    #[error("Package has no metadata.version")]
+    MissingVersion,
+
+    /// `metadata.version` is not a `major.minor[.patch]` version
+    #[error("Invalid package schema version: {0}")]
+    InvalidVersion(String),
+
+    /// Package was written by a newer release
+    #[error("Package schema version {found} is newer than the supported {supported}")]
+    FutureVersion {
+        /// Version of the package
+        found: SchemaVersion,
+        /// Newest version this release reads
+        supported: SchemaVersion,
+    },
+
+    /// Package version was never registered
+    #[error("Unknown package schema version {0}")]
+    UnknownVersion(SchemaVersion),
+
+    /// A migration step could not rewrite the package
+    #[error("Cannot migrate package from {from} to {to}: {reason}")]
+    Migration {
+        /// Version the step reads
+        from: SchemaVersion,
+        /// Version the step writes
+        to: SchemaVersion,
+        /// What was wrong with the package
+        reason: String,
+    },
+
+    /// Package JSON is malformed or does not match the schema
+    #[error("Invalid package: {0}")]
+    InvalidPackage(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package_json(version: &str) -> Value {
+        let mut package = serde_json::to_value(StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "my-secret-key-123".to_string(),
+            "fuego-testnet".to_string(),
+        ))
+        .unwrap();
+        package["metadata"]["version"] = Value::from(version);
+        package
+    }
+
+    #[test]
+    fn test_migrates_1_0_packages() {
+        let mut package = package_json("1.0.0");
+        package["burn_transaction"].as_object_mut().unwrap().remove("protocol_fee_bps");
+        package["metadata"].as_object_mut().unwrap().remove("hash_function");
+
+        let loaded = parse_package(&package.to_string()).unwrap();
+        assert_eq!(loaded.metadata.version, PACKAGE_SCHEMA_VERSION.to_string());
+        assert_eq!(loaded.metadata.migrated_from.as_deref(), Some("1.0.0"));
+        assert_eq!(loaded.burn_transaction.protocol_fee_bps, 0);
+
+        // Current packages load unchanged
+        let current = parse_package(&package_json("2.0.0").to_string()).unwrap();
+        assert_eq!(current.metadata.migrated_from, None);
+
+        // A 1.x package must embed its secret
+        let mut keyless = package_json("1.1");
+        keyless["secret"].as_object_mut().unwrap().remove("secret_key");
+        assert!(matches!(parse_package(&keyless.to_string()), Err(SchemaError::Migration { .. })));
+    }
+
+    #[test]
+    fn test_rejects_unknown_versions_and_fields() {
+        for (version, expected) in [
+            ("3.0.0", "newer"),
+            ("2.1", "newer"),
+            ("1.5.0", "Unknown"),
+            ("0.9", "Unknown"),
+            ("v1", "Invalid"),
+        ] {
+            let error = parse_package(&package_json(version).to_string()).unwrap_err();
+            assert!(error.to_string().contains(expected), "{}: {}", version, error);
+        }
+
+        let mut package = package_json("2.0.0");
+        package["burn_transaction"]["burn_amount_wei"] = Value::from(1);
+        assert!(matches!(parse_package(&package.to_string()), Err(SchemaError::InvalidPackage(_))));
+    }
+
+    #[test]
+    fn test_migration_keeps_signatures_valid() {
+        let package: StarkProofDataPackage = serde_json::from_value(package_json("1.0.0")).unwrap();
+        let digest = package.signing_digest();
+
+        let migrated = parse_package(&package_json("1.0.0").to_string()).unwrap();
+        assert_eq!(migrated.signing_digest(), digest);
+    }
+}
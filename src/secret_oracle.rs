@@ -0,0 +1,327 @@
+//! External Secret Holders
+//!
+//! A burn claim publishes two secret-dependent values: the nullifier and the commitment.
+//! The burn & mint trace proves them by hashing the secret in the registers of
+//! [`crate::burn_mint_air::SECRET_BOUND_REGISTERS`]; every other register depends only on
+//! the claim. A [`SecretOracle`] computes all three, letting a hardware wallet or enclave
+//! keep the secret at rest and approve each claim while the host does the STARK work
+//! ([`crate::burn_mint_prover::XfgBurnMintProver::prove_burn_mint_with_oracle`]):
+//!
+//! - [`SecretOracle::nullifier`]: the Rescue-Prime nullifier of
+//!   [`RescueNullifierGadget`]
+//! - [`SecretOracle::commitment`]: the Rescue-Prime commitment over a
+//!   [`CommitmentPreimage`]
+//! - [`SecretOracle::trace_cells`]: the secret-bound trace columns, see
+//!   [`SecretTraceCells`]
+//!
+//! The trace cells hold the secret, so the proving host sees it for the duration of one
+//! proof; the AIR wipes them when proving returns. Cells that do not hash to the
+//! nullifier and commitment are rejected before proving. [`derive_public_values`] fills
+//! the two values into a claim's public inputs without proving. [`LocalSecret`] is the
+//! in-memory oracle used when the secret is on the host.
+
+use crate::{
+    air::hash_gadget::RescueNullifierGadget,
+    burn_mint_air::{BurnMintPublicInputs, SecretTraceCells, BURN_NULLIFIER, NULLIFIER_LIMBS, SECRET_LIMBS},
+    burn_mint_prover::{secret_to_field_elements, SECRET_LEN},
+    commitment::{CommitmentPreimage, COMMITMENT_LIMBS},
+};
+use thiserror::Error;
+use winterfell::math::fields::f64::BaseElement;
+
+/// Failure reported by a secret holder
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SecretOracleError {
+    /// The holder declined the request (e.g. the user rejected it on the device)
+    #[error("Secret holder refused the request: {0}")]
+    Refused(String),
+    /// The holder could not be reached or failed to answer
+    #[error("Secret holder unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// Holder of a burn secret that computes the secret-dependent public inputs
+///
+/// Implementations receive every non-secret input in the clear, so a device can show
+/// the claim (amounts, recipient, chain) to the user before answering.
+pub trait SecretOracle {
+    /// Nullifier of the secret in `gadget`'s domain, scoped to `scope`
+    ///
+    /// # Errors
+    ///
+    /// [`SecretOracleError`] if the holder refuses or cannot answer
+    fn nullifier(
+        &self,
+        gadget: &RescueNullifierGadget,
+        scope: &[BaseElement],
+    ) -> Result<[BaseElement; NULLIFIER_LIMBS], SecretOracleError>;
+
+    /// Commitment of the secret to the claim in `public_inputs`
+    ///
+    /// The `nullifier` and `commitment` fields of `public_inputs` are not part of the
+    /// preimage and should be ignored.
+    ///
+    /// # Errors
+    ///
+    /// [`SecretOracleError`] if the holder refuses or cannot answer
+    fn commitment(
+        &self,
+        public_inputs: &BurnMintPublicInputs,
+    ) -> Result<[BaseElement; COMMITMENT_LIMBS], SecretOracleError>;
+
+    /// Secret-bound columns of the burn & mint trace proving the claim in `public_inputs`
+    ///
+    /// `trace_length` is the number of rows the host proves; the nullifier and commitment
+    /// fields of `public_inputs` are those the oracle returned.
+    ///
+    /// # Errors
+    ///
+    /// [`SecretOracleError`] if the holder refuses or cannot answer
+    fn trace_cells(
+        &self,
+        public_inputs: &BurnMintPublicInputs,
+        trace_length: usize,
+    ) -> Result<SecretTraceCells, SecretOracleError>;
+}
+
+/// Secret held in host memory
+#[derive(Clone)]
+pub struct LocalSecret {
+    secret: [BaseElement; SECRET_LIMBS],
+}
+
+impl LocalSecret {
+    /// Hold secret field elements
+    #[must_use]
+    pub fn new(secret: [BaseElement; SECRET_LIMBS]) -> Self {
+        Self { secret }
+    }
+
+    /// Hold the field elements derived from secret bytes (exactly [`SECRET_LEN`])
+    ///
+    /// # Errors
+    ///
+    /// [`crate::XfgStarkError::CryptoError`] if `secret` is not [`SECRET_LEN`] bytes
+    pub fn from_bytes(secret: &[u8]) -> crate::Result<Self> {
+        secret_to_field_elements(secret).map(Self::new)
+    }
+}
+
+impl SecretOracle for LocalSecret {
+    fn nullifier(
+        &self,
+        gadget: &RescueNullifierGadget,
+        scope: &[BaseElement],
+    ) -> Result<[BaseElement; NULLIFIER_LIMBS], SecretOracleError> {
+        Ok(gadget.derive(&self.secret, scope))
+    }
+
+    fn commitment(
+        &self,
+        public_inputs: &BurnMintPublicInputs,
+    ) -> Result<[BaseElement; COMMITMENT_LIMBS], SecretOracleError> {
+        Ok(CommitmentPreimage::new(self.secret, public_inputs).hash())
+    }
+
+    fn trace_cells(
+        &self,
+        public_inputs: &BurnMintPublicInputs,
+        trace_length: usize,
+    ) -> Result<SecretTraceCells, SecretOracleError> {
+        Ok(SecretTraceCells::derive(&self.secret, public_inputs, trace_length))
+    }
+}
+
+/// Set the nullifier and commitment of a burn claim from `oracle`
+///
+/// The nullifier is scoped to the burn transaction and amount, as the burn & mint AIR
+/// derives it. Oracle errors leave `public_inputs` unchanged.
+///
+/// # Errors
+///
+/// The [`SecretOracleError`] `oracle` returns
+pub fn derive_public_values(
+    oracle: &dyn SecretOracle,
+    public_inputs: &mut BurnMintPublicInputs,
+) -> Result<(), SecretOracleError> {
+    let nullifier = oracle.nullifier(&BURN_NULLIFIER, &public_inputs.nullifier_scope())?;
+    let commitment = oracle.commitment(public_inputs)?;
+    public_inputs.nullifier = nullifier;
+    public_inputs.commitment = commitment;
+    Ok(())
+}
+
+impl std::fmt::Debug for LocalSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalSecret")
+            .field("secret", &crate::types::secret::Redacted::bytes(SECRET_LEN))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_air::SECRET_BOUND_REGISTERS;
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+    use std::cell::RefCell;
+    use winter_math::ToElements;
+
+    /// Device that only ever sees the non-secret inputs, and logs what it was asked
+    struct Device {
+        inner: LocalSecret,
+        approve: bool,
+        requests: RefCell<Vec<&'static str>>,
+    }
+
+    impl Device {
+        fn new(secret: &[u8], approve: bool) -> Self {
+            Self {
+                inner: LocalSecret::from_bytes(secret).unwrap(),
+                approve,
+                requests: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl SecretOracle for Device {
+        fn nullifier(
+            &self,
+            gadget: &RescueNullifierGadget,
+            scope: &[BaseElement],
+        ) -> Result<[BaseElement; NULLIFIER_LIMBS], SecretOracleError> {
+            self.requests.borrow_mut().push("nullifier");
+            self.inner.nullifier(gadget, scope)
+        }
+
+        fn commitment(
+            &self,
+            public_inputs: &BurnMintPublicInputs,
+        ) -> Result<[BaseElement; COMMITMENT_LIMBS], SecretOracleError> {
+            self.requests.borrow_mut().push("commitment");
+            if !self.approve {
+                return Err(SecretOracleError::Refused("user rejected the mint".to_string()));
+            }
+            self.inner.commitment(public_inputs)
+        }
+
+        fn trace_cells(
+            &self,
+            public_inputs: &BurnMintPublicInputs,
+            trace_length: usize,
+        ) -> Result<SecretTraceCells, SecretOracleError> {
+            self.requests.borrow_mut().push("trace_cells");
+            self.inner.trace_cells(public_inputs, trace_length)
+        }
+    }
+
+    /// Device answering with its own values but the trace cells of another secret
+    struct SplitDevice {
+        values: LocalSecret,
+        cells: LocalSecret,
+    }
+
+    impl SecretOracle for SplitDevice {
+        fn nullifier(
+            &self,
+            gadget: &RescueNullifierGadget,
+            scope: &[BaseElement],
+        ) -> Result<[BaseElement; NULLIFIER_LIMBS], SecretOracleError> {
+            self.values.nullifier(gadget, scope)
+        }
+
+        fn commitment(
+            &self,
+            public_inputs: &BurnMintPublicInputs,
+        ) -> Result<[BaseElement; COMMITMENT_LIMBS], SecretOracleError> {
+            self.values.commitment(public_inputs)
+        }
+
+        fn trace_cells(
+            &self,
+            public_inputs: &BurnMintPublicInputs,
+            trace_length: usize,
+        ) -> Result<SecretTraceCells, SecretOracleError> {
+            self.cells.trace_cells(public_inputs, trace_length)
+        }
+    }
+
+    fn prove_with(
+        prover: &XfgBurnMintProver,
+        oracle: &dyn SecretOracle,
+    ) -> crate::Result<(winterfell::StarkProof, BurnMintPublicInputs)> {
+        let request = BurnMintRequest {
+            burn_amount: 8_000_000,
+            mint_amount: 8_000_000,
+            tx_prefix_hash: [7u8; 32],
+            recipient_address: &[0x12u8; 20],
+            network_id: 1,
+            target_chain_id: 42161,
+            commitment_version: 1,
+        };
+        prover.prove_burn_mint_with_oracle(&request, oracle)
+    }
+
+    #[test]
+    fn test_delegated_proof_matches_local() {
+        let secret = [9u8; 32];
+        let prover = XfgBurnMintProver::default();
+        let (local_proof, local_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &secret,
+            )
+            .unwrap();
+
+        let device = Device::new(&secret, true);
+        let (proof, public_inputs) = prove_with(&prover, &device).unwrap();
+
+        assert_eq!(*device.requests.borrow(), ["nullifier", "commitment", "trace_cells"]);
+        assert_eq!(public_inputs.to_elements(), local_inputs.to_elements());
+        assert_eq!(proof.to_bytes(), local_proof.to_bytes());
+        assert!(crate::statements::verify_statement::<crate::burn_mint_air::XfgBurnMintAir>(
+            proof,
+            public_inputs,
+            prover.proof_options(),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_refusal_stops_proving() {
+        let device = Device::new(&[9u8; 32], false);
+
+        assert!(matches!(
+            prove_with(&XfgBurnMintProver::default(), &device),
+            Err(crate::XfgStarkError::SecretOracleError(SecretOracleError::Refused(_)))
+        ));
+        assert_eq!(*device.requests.borrow(), ["nullifier", "commitment"]);
+
+        let claim = || BurnMintPublicInputs::from_user_data(8_000_000, [7u8; 32], &[0x12u8; 20], 1, 42161, 1);
+        let mut public_inputs = claim();
+        assert!(derive_public_values(&device, &mut public_inputs).is_err());
+        assert_eq!(public_inputs.to_elements(), claim().to_elements());
+        assert!(format!("{:?}", device.inner).contains("LocalSecret"));
+        assert!(!format!("{:?}", device.inner).contains(&u64::from_le_bytes([9; 8]).to_string()));
+    }
+
+    #[test]
+    fn test_cells_of_another_secret_rejected() {
+        let device = SplitDevice {
+            values: LocalSecret::from_bytes(&[9u8; 32]).unwrap(),
+            cells: LocalSecret::from_bytes(&[8u8; 32]).unwrap(),
+        };
+
+        let err = prove_with(&XfgBurnMintProver::default(), &device).unwrap_err();
+        assert!(err.to_string().contains("does not match the secret trace cells"), "{err}");
+        assert_eq!(SECRET_BOUND_REGISTERS.len(), SECRET_LIMBS + 36);
+    }
+}
@@ -0,0 +1,516 @@
+//! HTTP Verification Service
+//!
+//! [`run`] serves burn & mint verification over HTTP, for deployments that would
+//! otherwise wrap the CLI in scripts:
+//!
+//! | Method | Path                           | Body / response                                   |
+//! |--------|--------------------------------|---------------------------------------------------|
+//! | POST   | `/v1/proofs`                   | [`CompleteProofPackage`] → [`VerificationRecord`] |
+//! | GET    | `/v1/proofs/{id}`              | [`VerificationRecord`]                            |
+//! | GET    | `/v1/nullifiers/{nullifier}`   | [`NullifierStatus`]                               |
+//!
+//! A submission is checked for a proof and a parsable claim, answered with `202
+//! Accepted` and a `pending` record, and verified in the background; clients poll the
+//! record by its id, the hex SHA-256 of the proof bytes. Resubmitting a known proof
+//! returns its record with `200 OK` instead of verifying it again.
+//!
+//! A proof that verifies spends its nullifier in the configured [`NullifierSet`], so a
+//! second proof of the same burn is reported invalid. Like the
+//! [streaming server](crate::verification_server), the service verifies on a
+//! [`WorkerPool`] with a bounded queue and answers `429 Too Many Requests` when the
+//! workers and the queue are full.
+//!
+//! When the service has tenants (see [`crate::tenants`]), submissions carry the
+//! tenant's API key in an `x-api-key` header and are held to the tenant's limits:
+//! `401 Unauthorized` for a missing or unknown key, `403 Forbidden` once the quota is
+//! used up, and `429 Too Many Requests` over the pending or rate limit. Errors are
+//! JSON objects with an `error` field.
+
+use crate::admission::{Admission, AdmissionRejection, WorkerPool};
+use crate::burn_mint_verifier::XfgBurnMintVerifier;
+use crate::nullifier::{derive_nullifier, MemoryNullifierSet, Nullifier, NullifierSet};
+use crate::proof_data_schema::CompleteProofPackage;
+use crate::tenants::{Tenant, TenantRegistry};
+use crate::verification_context::VerificationContext;
+use axum::extract::{DefaultBodyLimit, Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::net::TcpListener;
+
+/// Default address the HTTP service binds to
+pub const DEFAULT_SERVICE_ADDR: &str = "127.0.0.1:18191";
+
+/// Header a tenant's API key is sent in
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Verifier, nullifier registry, tenants and limits of the HTTP service
+#[derive(Clone)]
+pub struct ServiceConfig {
+    /// Verifier packages are checked with
+    pub verifier: XfgBurnMintVerifier,
+    /// Registry valid proofs spend their nullifier in
+    pub nullifiers: Arc<dyn NullifierSet>,
+    /// Tenants submissions must authenticate as (open to anyone when empty)
+    pub tenants: Arc<TenantRegistry>,
+    /// Proofs verified concurrently
+    pub workers: usize,
+    /// Submissions allowed to wait for a worker
+    pub max_pending: usize,
+    /// Largest request body (bytes)
+    pub max_body_bytes: usize,
+    /// Verification records kept for status queries; the oldest are dropped first
+    pub max_records: usize,
+}
+
+impl Default for ServiceConfig {
+    fn default() -> Self {
+        Self {
+            verifier: XfgBurnMintVerifier::default(),
+            nullifiers: Arc::new(MemoryNullifierSet::new()),
+            tenants: Arc::new(TenantRegistry::default()),
+            workers: std::thread::available_parallelism().map_or(4, std::num::NonZero::get),
+            max_pending: 64,
+            // Packages carry proof bytes as a JSON array, about four characters per byte
+            max_body_bytes: 8 * 1024 * 1024,
+            max_records: 100_000,
+        }
+    }
+}
+
+/// Verification state of a submitted proof
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationStatus {
+    /// Waiting for or undergoing verification
+    Pending,
+    /// Proof verified and its nullifier was spent
+    Valid,
+    /// Proof failed verification or its nullifier was already spent
+    Invalid,
+}
+
+/// Outcome of a submission, as returned by the proof endpoints
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationRecord {
+    /// Hex SHA-256 of the proof bytes
+    pub id: String,
+    /// Verification state
+    pub status: VerificationStatus,
+    /// Nullifier the claim publishes
+    pub nullifier: String,
+    /// Why the proof is invalid
+    pub error: Option<String>,
+}
+
+/// Response of the nullifier endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NullifierStatus {
+    /// Queried nullifier
+    pub nullifier: String,
+    /// Whether a verified proof has spent it
+    pub spent: bool,
+}
+
+/// Error response with an HTTP status
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ServiceError {
+    status: StatusCode,
+    error: String,
+}
+
+impl ServiceError {
+    fn new(status: StatusCode, error: &impl ToString) -> Self {
+        Self {
+            status,
+            error: error.to_string(),
+        }
+    }
+}
+
+impl From<AdmissionRejection> for ServiceError {
+    fn from(rejection: AdmissionRejection) -> Self {
+        Self::new(rejection.status(), &rejection)
+    }
+}
+
+impl IntoResponse for ServiceError {
+    fn into_response(self) -> Response {
+        (self.status, Json(serde_json::json!({ "error": self.error }))).into_response()
+    }
+}
+
+/// Records in submission order, so the oldest can be dropped
+#[derive(Debug, Default)]
+struct Records {
+    by_id: HashMap<String, VerificationRecord>,
+    order: VecDeque<String>,
+}
+
+/// State shared by the request handlers
+struct ServiceState {
+    config: ServiceConfig,
+    records: Mutex<Records>,
+    pool: WorkerPool,
+}
+
+impl ServiceState {
+    fn records(&self) -> std::sync::MutexGuard<'_, Records> {
+        self.records.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn record(&self, id: &str) -> Option<VerificationRecord> {
+        self.records().by_id.get(id).cloned()
+    }
+
+    fn insert(&self, record: VerificationRecord) {
+        let mut records = self.records();
+        records.order.push_back(record.id.clone());
+        records.by_id.insert(record.id.clone(), record);
+        while records.order.len() > self.config.max_records.max(1) {
+            if let Some(oldest) = records.order.pop_front() {
+                records.by_id.remove(&oldest);
+            }
+        }
+    }
+
+    /// Record a finished verification, unless its record was dropped meanwhile
+    fn finish(&self, id: &str, error: Option<String>) {
+        if let Some(record) = self.records().by_id.get_mut(id) {
+            record.status = match error {
+                None => VerificationStatus::Valid,
+                Some(_) => VerificationStatus::Invalid,
+            };
+            record.error = error;
+        }
+    }
+}
+
+/// Router serving the verification endpoints, for embedding in a larger service
+pub fn router(config: ServiceConfig) -> Router {
+    let body_limit = config.max_body_bytes;
+    let state = Arc::new(ServiceState {
+        pool: WorkerPool::new(config.workers, config.max_pending).with_tenants(config.tenants.clone()),
+        records: Mutex::new(Records::default()),
+        config,
+    });
+
+    Router::new()
+        .route("/v1/proofs", post(submit_proof))
+        .route("/v1/proofs/{id}", get(proof_status))
+        .route("/v1/nullifiers/{nullifier}", get(nullifier_status))
+        .layer(DefaultBodyLimit::max(body_limit))
+        .with_state(state)
+}
+
+/// Serve the verification endpoints on `addr` until the listener fails
+///
+/// # Errors
+///
+/// If `addr` cannot be bound or the listener fails
+pub async fn run(addr: SocketAddr, config: ServiceConfig) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    axum::serve(listener, router(config)).await
+}
+
+async fn submit_proof(
+    State(state): State<Arc<ServiceState>>,
+    headers: HeaderMap,
+    Json(package): Json<CompleteProofPackage>,
+) -> Result<(StatusCode, Json<VerificationRecord>), ServiceError> {
+    let tenant = authenticate(&state, &headers)?;
+    let proof = package
+        .stark_proof
+        .as_ref()
+        .ok_or_else(|| ServiceError::new(StatusCode::BAD_REQUEST, &"package has no STARK proof"))?;
+    let id = hex::encode(Sha256::digest(&proof.proof_data));
+    if let Some(record) = state.record(&id) {
+        return Ok((StatusCode::OK, Json(record)));
+    }
+
+    let data = &package.stark_proof_data;
+    let context = VerificationContext::from_package(data).map_err(|e| ServiceError::new(StatusCode::BAD_REQUEST, &e))?;
+    let nullifier = derive_nullifier(&data.secret_bytes(), context.tx_prefix_hash(), context.burn_amount())
+        .map_err(|e| ServiceError::new(StatusCode::BAD_REQUEST, &e))?;
+
+    let admission = state.pool.admit(tenant.as_ref())?;
+    let record = VerificationRecord {
+        id: id.clone(),
+        status: VerificationStatus::Pending,
+        nullifier: nullifier.to_string(),
+        error: None,
+    };
+    state.insert(record.clone());
+    tokio::spawn(verify_in_background(state, admission, id, package, context, nullifier));
+    Ok((StatusCode::ACCEPTED, Json(record)))
+}
+
+/// Tenant named by the request's API key, if it sent one
+///
+/// A request without a key is left to the worker pool, which turns it away when the
+/// service has tenants.
+fn authenticate(state: &ServiceState, headers: &HeaderMap) -> Result<Option<Arc<Tenant>>, ServiceError> {
+    let Some(api_key) = headers.get(API_KEY_HEADER) else {
+        return Ok(None);
+    };
+    api_key
+        .to_str()
+        .ok()
+        .and_then(|api_key| state.pool.authenticate(api_key))
+        .map(Some)
+        .ok_or_else(|| ServiceError::new(StatusCode::UNAUTHORIZED, &"unknown API key"))
+}
+
+/// Wait for a worker, verify, and record the outcome
+async fn verify_in_background(
+    state: Arc<ServiceState>,
+    admission: Admission,
+    id: String,
+    package: CompleteProofPackage,
+    context: VerificationContext,
+    nullifier: Nullifier,
+) {
+    let blocking_state = state.clone();
+    let error = admission
+        .run(move || verify_and_spend(&blocking_state.config, &package, &context, nullifier))
+        .await
+        .unwrap_or_else(|e| Err(format!("Verification task failed: {e}")))
+        .err();
+    state.finish(&id, error);
+}
+
+/// Verify a package and spend its nullifier, returning why it is invalid
+fn verify_and_spend(
+    config: &ServiceConfig,
+    package: &CompleteProofPackage,
+    context: &VerificationContext,
+    nullifier: Nullifier,
+) -> Result<(), String> {
+    let nullifiers = config.nullifiers.as_ref();
+    // Spent nullifiers are rejected before paying for verification
+    nullifiers.ensure_unspent(nullifier).map_err(|e| e.to_string())?;
+    let report = config
+        .verifier
+        .verify_package_with_context(package, context)
+        .map_err(|e| e.to_string())?;
+    if let Some(error) = report.error {
+        return Err(error);
+    }
+    // Another proof of this burn may have verified meanwhile
+    nullifiers.spend(nullifier).map_err(|e| e.to_string())
+}
+
+async fn proof_status(
+    State(state): State<Arc<ServiceState>>,
+    Path(id): Path<String>,
+) -> Result<Json<VerificationRecord>, ServiceError> {
+    state
+        .record(&id.to_ascii_lowercase())
+        .map(Json)
+        .ok_or_else(|| ServiceError::new(StatusCode::NOT_FOUND, &format!("no proof with id {id}")))
+}
+
+async fn nullifier_status(
+    State(state): State<Arc<ServiceState>>,
+    Path(nullifier): Path<String>,
+) -> Result<Json<NullifierStatus>, ServiceError> {
+    let parsed = hex::decode(nullifier.strip_prefix("0x").unwrap_or(&nullifier))
+        .ok()
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .map(Nullifier)
+        .ok_or_else(|| ServiceError::new(StatusCode::BAD_REQUEST, &format!("invalid nullifier {nullifier}")))?;
+
+    let spent = state
+        .config
+        .nullifiers
+        .contains(parsed)
+        .map_err(|e| ServiceError::new(StatusCode::INTERNAL_SERVER_ERROR, &e))?;
+    Ok(Json(NullifierStatus {
+        nullifier: parsed.to_string(),
+        spent,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_prover::XfgBurnMintProver;
+    use crate::commitment::recipient_binding;
+    use crate::constants::{COMMITMENT_VERSION, DEFAULT_TARGET_CHAIN_ID};
+    use crate::proof_data_schema::{StarkProof as ProofFile, StarkProofDataPackage, StarkPublicInputs};
+    use crate::statements::HashFunction;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+
+    fn proven_package() -> CompleteProofPackage {
+        let data = StarkProofDataPackage::new(
+            0.8,
+            "7d0725f8e03021b99560add456c596fea7d8df23529e23765e56923b73236e4d".to_string(),
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6".to_string(),
+            "a1b2c3d4e5f6a7b8c9d0e1f2a3b4c5d6".to_string(),
+            "fuego-testnet".to_string(),
+        );
+        let recipient = data.recipient_address().unwrap();
+        let proof = XfgBurnMintProver::default()
+            .prove_burn_mint(
+                data.burn_transaction.burn_amount_atomic,
+                data.get_mint_amount_atomic(),
+                data.tx_prefix_hash().unwrap(),
+                recipient.as_bytes(),
+                &data.secret_bytes(),
+                data.network_id_number(),
+                DEFAULT_TARGET_CHAIN_ID,
+                COMMITMENT_VERSION,
+            )
+            .expect("Proof generation should succeed");
+
+        let mut package = CompleteProofPackage::new(data.clone());
+        package.add_stark_proof(ProofFile {
+            proof_data: proof.to_bytes(),
+            public_inputs: StarkPublicInputs {
+                burn_amount: data.burn_transaction.burn_amount_atomic,
+                mint_amount: data.get_mint_amount_atomic(),
+                txn_hash: data.burn_transaction.transaction_hash.clone(),
+                recipient_hash: hex::encode(recipient_binding(recipient.as_bytes(), DEFAULT_TARGET_CHAIN_ID)),
+                state: 0,
+                fee_bps: 0,
+            },
+            metadata: crate::proof_data_schema::ProofMetadata {
+                hash_function: HashFunction::Blake3,
+                ..data.metadata.clone()
+            },
+        });
+        package
+    }
+
+    /// Send one HTTP/1.1 request and return the status and JSON body
+    async fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, serde_json::Value) {
+        request_as(addr, None, method, path, body).await
+    }
+
+    /// Send one HTTP/1.1 request, with an API key if given
+    async fn request_as(
+        addr: SocketAddr,
+        api_key: Option<&str>,
+        method: &str,
+        path: &str,
+        body: &str,
+    ) -> (u16, serde_json::Value) {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let key_header = api_key.map_or_else(String::new, |key| format!("{}: {}\r\n", API_KEY_HEADER, key));
+        let head = format!(
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n{}Content-Type: application/json\r\nContent-Length: {}\r\n\r\n",
+            method,
+            path,
+            key_header,
+            body.len()
+        );
+        stream.write_all(head.as_bytes()).await.unwrap();
+        stream.write_all(body.as_bytes()).await.unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap_or(serde_json::Value::Null))
+    }
+
+    #[tokio::test]
+    async fn test_submit_poll_and_nullifier_status() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router(ServiceConfig::default())).await });
+
+        let package = proven_package();
+        let body = serde_json::to_string(&package).unwrap();
+        let (status, submitted) = request(addr, "POST", "/v1/proofs", &body).await;
+        assert_eq!(status, 202);
+        let record: VerificationRecord = serde_json::from_value(submitted).unwrap();
+        assert_eq!(record.status, VerificationStatus::Pending);
+
+        let path = format!("/v1/proofs/{}", record.id);
+        let mut polled = record.clone();
+        for _ in 0..500 {
+            let (status, body) = request(addr, "GET", &path, "").await;
+            assert_eq!(status, 200);
+            polled = serde_json::from_value(body).unwrap();
+            if polled.status != VerificationStatus::Pending {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert_eq!(polled.status, VerificationStatus::Valid, "{:?}", polled.error);
+
+        // Resubmitting returns the stored record
+        let (status, resubmitted) = request(addr, "POST", "/v1/proofs", &body).await;
+        assert_eq!(status, 200);
+        assert_eq!(serde_json::from_value::<VerificationRecord>(resubmitted).unwrap(), polled);
+
+        let (status, nullifier) = request(addr, "GET", &format!("/v1/nullifiers/{}", record.nullifier), "").await;
+        assert_eq!(status, 200);
+        assert_eq!(
+            serde_json::from_value::<NullifierStatus>(nullifier).unwrap(),
+            NullifierStatus { nullifier: record.nullifier.clone(), spent: true }
+        );
+        let (_, unspent) = request(addr, "GET", &format!("/v1/nullifiers/{}", "12".repeat(32)), "").await;
+        assert_eq!(unspent["spent"], false);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_bad_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router(ServiceConfig::default())).await });
+
+        let mut package = proven_package();
+        package.stark_proof = None;
+        let (status, body) = request(addr, "POST", "/v1/proofs", &serde_json::to_string(&package).unwrap()).await;
+        assert_eq!(status, 400);
+        assert_eq!(body["error"], "package has no STARK proof");
+
+        assert_eq!(request(addr, "GET", "/v1/proofs/abcd", "").await.0, 404);
+        assert_eq!(request(addr, "GET", "/v1/nullifiers/0xnothex", "").await.0, 400);
+    }
+
+    #[tokio::test]
+    async fn test_tenant_key_and_quota() {
+        use crate::tenants::TenantConfig;
+
+        let tenants = TenantRegistry::new(vec![TenantConfig {
+            name: "wallet-a".to_string(),
+            api_key: "key-a".to_string(),
+            max_pending: None,
+            rate_limit: None,
+            proof_quota: Some(1),
+        }])
+        .unwrap();
+        let config = ServiceConfig {
+            tenants: Arc::new(tenants),
+            ..ServiceConfig::default()
+        };
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router(config)).await });
+
+        let mut package = proven_package();
+        let body = serde_json::to_string(&package).unwrap();
+        assert_eq!(request(addr, "POST", "/v1/proofs", &body).await.0, 401);
+        let (status, rejected) = request_as(addr, Some("key-b"), "POST", "/v1/proofs", &body).await;
+        assert_eq!(status, 401);
+        assert_eq!(rejected["error"], "unknown API key");
+        assert_eq!(request_as(addr, Some("key-a"), "POST", "/v1/proofs", &body).await.0, 202);
+
+        // A different proof is over the tenant's quota, whatever its outcome would be
+        package.stark_proof.as_mut().unwrap().proof_data.push(0);
+        let body = serde_json::to_string(&package).unwrap();
+        let (status, rejected) = request_as(addr, Some("key-a"), "POST", "/v1/proofs", &body).await;
+        assert_eq!(status, 403);
+        assert_eq!(rejected["error"], "Tenant wallet-a has used its quota of 1 proofs");
+    }
+}
@@ -0,0 +1,365 @@
+//! Query Count Selection from Target Soundness
+//!
+//! The number of FRI queries needed for a security level depends on the blowup factor,
+//! the grinding factor, the field size and the trace length. [`min_num_queries`] derives
+//! the smallest query count that reaches a target conjectured security under the same
+//! estimate Winterfell reports through `StarkProof::security_level`, and fails when the
+//! target is out of reach for the field or the commitment hash. [`SecurityPreset`]
+//! builds complete proof options from it.
+//!
+//! [`SecurityPreset::Constrained`] is the profile for small ARM64 boards such as a
+//! Raspberry Pi. It keeps the verifier's minimum security but halves the blowup and the
+//! FRI folding factor, trading more queries and grinding for an LDE half the size.
+//! Winterfell keeps the whole LDE in memory, so the blowup is what bounds peak memory.
+//! Options that fall below the verifier's minimum are [`is_testnet_only`].
+
+use crate::burn_mint_verifier::DEFAULT_MIN_SECURITY_BITS;
+use crate::statements::StatementHasher;
+use thiserror::Error;
+use winterfell::{crypto::Hasher, FieldExtension, ProofOptions};
+
+/// Bits in the base field modulus
+pub const BASE_FIELD_BITS: u32 = 64;
+
+/// Largest query count Winterfell accepts
+pub const MAX_NUM_QUERIES: usize = 255;
+
+/// Query security below which grinding does not count towards the estimate
+const GRINDING_CONTRIBUTION_FLOOR: u32 = 80;
+
+/// FRI folding factor used by the standard and high presets
+const PRESET_FRI_FOLDING_FACTOR: usize = 8;
+
+/// FRI folding factor of the constrained preset (smaller layers per folding step)
+const CONSTRAINED_FRI_FOLDING_FACTOR: usize = 4;
+
+/// FRI remainder max degree used by the presets
+const PRESET_FRI_REMAINDER_MAX_DEGREE: usize = 31;
+
+/// Reasons a soundness target cannot be met
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SoundnessError {
+    /// Blowup factor is not a power of two in Winterfell's supported range
+    #[error("Blowup factor {0} must be a power of two between 2 and 128")]
+    InvalidBlowupFactor(usize),
+    /// Field too small for the target at this trace length and blowup
+    #[error("Target of {target_bits} bits exceeds the {max_bits} bits the field allows")]
+    FieldTooSmall {
+        /// Requested security (bits)
+        target_bits: u32,
+        /// Highest security the field allows (bits)
+        max_bits: u32,
+    },
+    /// Commitment hash collision resistance is below the target
+    #[error("Target of {target_bits} bits exceeds the hash's {max_bits}-bit collision resistance")]
+    HashTooWeak {
+        /// Requested security (bits)
+        target_bits: u32,
+        /// Collision resistance of the commitment hash (bits)
+        max_bits: u32,
+    },
+    /// Target needs more queries than Winterfell accepts
+    #[error("Target needs {required} queries, more than the maximum of {max}")]
+    TooManyQueries {
+        /// Queries the target requires
+        required: usize,
+        /// Largest accepted query count
+        max: usize,
+    },
+}
+
+/// Smallest query count whose query rounds alone give `target_bits` of security
+///
+/// Grinding only counts once the queries reach 80 bits, as in Winterfell's estimate.
+///
+/// # Errors
+///
+/// [`SoundnessError::InvalidBlowupFactor`] for an unsupported blowup, or
+/// [`SoundnessError::TooManyQueries`] if the target needs more than [`MAX_NUM_QUERIES`]
+pub fn query_count(target_bits: u32, blowup_factor: usize, grinding_factor: u32) -> Result<usize, SoundnessError> {
+    if !blowup_factor.is_power_of_two() || !(2..=128).contains(&blowup_factor) {
+        return Err(SoundnessError::InvalidBlowupFactor(blowup_factor));
+    }
+    let bits_per_query = blowup_factor.ilog2();
+
+    // The estimate subtracts one bit from the query security
+    let needed = target_bits + 1;
+    let without_grinding = needed.div_ceil(bits_per_query);
+    let with_grinding = needed
+        .saturating_sub(grinding_factor)
+        .max(GRINDING_CONTRIBUTION_FLOOR)
+        .div_ceil(bits_per_query);
+    let required = without_grinding.min(with_grinding) as usize;
+
+    if required > MAX_NUM_QUERIES {
+        return Err(SoundnessError::TooManyQueries {
+            required,
+            max: MAX_NUM_QUERIES,
+        });
+    }
+    Ok(required)
+}
+
+/// Query count for a security parameter, capped at the maximum when it is out of reach
+#[must_use]
+pub fn default_num_queries(security_parameter: u32, blowup_factor: usize) -> usize {
+    query_count(security_parameter, blowup_factor, 0).unwrap_or(MAX_NUM_QUERIES)
+}
+
+/// Smallest query count reaching `target_bits` of conjectured security for a trace
+///
+/// # Errors
+///
+/// [`SoundnessError::HashTooWeak`] or [`SoundnessError::FieldTooSmall`] if the target is out
+/// of reach, otherwise as [`query_count`]
+pub fn min_num_queries(
+    target_bits: u32,
+    blowup_factor: usize,
+    grinding_factor: u32,
+    field_extension: FieldExtension,
+    trace_length: usize,
+) -> Result<usize, SoundnessError> {
+    let collision_resistance = StatementHasher::COLLISION_RESISTANCE;
+    if target_bits > collision_resistance {
+        return Err(SoundnessError::HashTooWeak {
+            target_bits,
+            max_bits: collision_resistance,
+        });
+    }
+
+    let num_queries = query_count(target_bits, blowup_factor, grinding_factor)?;
+
+    let max_bits = field_security_bits(field_extension, trace_length * blowup_factor) - 1;
+    if target_bits > max_bits {
+        return Err(SoundnessError::FieldTooSmall { target_bits, max_bits });
+    }
+    Ok(num_queries)
+}
+
+/// Conjectured security of proof options for a trace, as Winterfell estimates it (bits)
+#[must_use]
+pub fn conjectured_security_bits(options: &ProofOptions, trace_length: usize) -> u32 {
+    let field_security = field_security_bits(options.field_extension(), trace_length * options.blowup_factor());
+    let mut query_security = options
+        .blowup_factor()
+        .ilog2()
+        .saturating_mul(u32::try_from(options.num_queries()).unwrap_or(u32::MAX));
+    if query_security >= GRINDING_CONTRIBUTION_FLOOR {
+        query_security += options.grinding_factor();
+    }
+    (field_security.min(query_security) - 1).min(StatementHasher::COLLISION_RESISTANCE)
+}
+
+/// Whether proofs under these options fall short of the verifier's default minimum
+///
+/// Such proofs are only fit for testnets; a mainnet verifier rejects them.
+#[must_use]
+pub fn is_testnet_only(options: &ProofOptions, trace_length: usize) -> bool {
+    conjectured_security_bits(options, trace_length) < DEFAULT_MIN_SECURITY_BITS
+}
+
+/// Security the field allows over an LDE domain, before the one-bit deduction
+fn field_security_bits(field_extension: FieldExtension, lde_domain_size: usize) -> u32 {
+    (BASE_FIELD_BITS * field_extension.degree()).saturating_sub(lde_domain_size.ilog2())
+}
+
+/// Named security levels for proof parameter selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecurityPreset {
+    /// The verifier's default minimum over a quadratic extension
+    Standard,
+    /// 128 bits over a cubic extension
+    High,
+    /// The verifier's default minimum with a small LDE, for low-memory ARM64 provers
+    Constrained,
+}
+
+impl SecurityPreset {
+    /// Target conjectured security (bits)
+    #[must_use]
+    pub const fn target_bits(self) -> u32 {
+        match self {
+            SecurityPreset::Standard | SecurityPreset::Constrained => DEFAULT_MIN_SECURITY_BITS,
+            SecurityPreset::High => 128,
+        }
+    }
+
+    /// Blowup factor the preset is tuned for
+    #[must_use]
+    pub const fn blowup_factor(self) -> usize {
+        match self {
+            SecurityPreset::Standard | SecurityPreset::High => 8,
+            SecurityPreset::Constrained => 4,
+        }
+    }
+
+    /// Grinding factor the preset is tuned for
+    #[must_use]
+    pub const fn grinding_factor(self) -> u32 {
+        match self {
+            SecurityPreset::Standard | SecurityPreset::High => 4,
+            SecurityPreset::Constrained => 16,
+        }
+    }
+
+    /// FRI folding factor
+    #[must_use]
+    pub const fn fri_folding_factor(self) -> usize {
+        match self {
+            SecurityPreset::Standard | SecurityPreset::High => PRESET_FRI_FOLDING_FACTOR,
+            SecurityPreset::Constrained => CONSTRAINED_FRI_FOLDING_FACTOR,
+        }
+    }
+
+    /// Field extension large enough for the target
+    #[must_use]
+    pub const fn field_extension(self) -> FieldExtension {
+        match self {
+            SecurityPreset::Standard | SecurityPreset::Constrained => FieldExtension::Quadratic,
+            SecurityPreset::High => FieldExtension::Cubic,
+        }
+    }
+
+    /// Proof options at the preset's own blowup and grinding factors
+    ///
+    /// # Errors
+    ///
+    /// As [`min_num_queries`]
+    pub fn default_proof_options(self, trace_length: usize) -> Result<ProofOptions, SoundnessError> {
+        self.proof_options(self.blowup_factor(), self.grinding_factor(), trace_length)
+    }
+
+    /// Proof options with the fewest queries reaching the target
+    ///
+    /// # Errors
+    ///
+    /// As [`min_num_queries`]
+    pub fn proof_options(
+        self,
+        blowup_factor: usize,
+        grinding_factor: u32,
+        trace_length: usize,
+    ) -> Result<ProofOptions, SoundnessError> {
+        let num_queries = min_num_queries(
+            self.target_bits(),
+            blowup_factor,
+            grinding_factor,
+            self.field_extension(),
+            trace_length,
+        )?;
+
+        Ok(ProofOptions::new(
+            num_queries,
+            blowup_factor,
+            grinding_factor,
+            self.field_extension(),
+            self.fri_folding_factor(),
+            PRESET_FRI_REMAINDER_MAX_DEGREE,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+    use crate::constants::TRACE_LENGTH;
+
+    #[test]
+    fn test_minimal_query_count() {
+        for (target, blowup, grinding) in [(100, 8, 4), (100, 16, 0), (128, 8, 16), (80, 4, 20), (60, 2, 8)] {
+            let queries = query_count(target, blowup, grinding).unwrap();
+            let options = |queries| ProofOptions::new(queries, blowup, grinding, FieldExtension::Cubic, 8, 31);
+
+            assert!(conjectured_security_bits(&options(queries), TRACE_LENGTH) >= target);
+            assert!(conjectured_security_bits(&options(queries - 1), TRACE_LENGTH) < target);
+        }
+        assert_eq!(query_count(100, 8, 4), Ok(33));
+    }
+
+    #[test]
+    fn test_unattainable_targets() {
+        assert_eq!(
+            min_num_queries(100, 8, 4, FieldExtension::None, TRACE_LENGTH),
+            Err(SoundnessError::FieldTooSmall { target_bits: 100, max_bits: 54 })
+        );
+        assert!(matches!(
+            min_num_queries(160, 8, 0, FieldExtension::Cubic, TRACE_LENGTH),
+            Err(SoundnessError::HashTooWeak { .. })
+        ));
+        assert_eq!(query_count(300, 2, 0), Err(SoundnessError::TooManyQueries { required: 301, max: 255 }));
+        assert_eq!(query_count(100, 6, 0), Err(SoundnessError::InvalidBlowupFactor(6)));
+    }
+
+    #[test]
+    fn test_presets_match_winterfell_estimate() {
+        for preset in [SecurityPreset::Standard, SecurityPreset::High, SecurityPreset::Constrained] {
+            let options = preset.proof_options(8, 4, TRACE_LENGTH).unwrap();
+            let (proof, _) = XfgBurnMintProver::with_options(128, options.clone())
+                .prove_burn_mint_with_public_inputs(
+                    &BurnMintRequest {
+                        burn_amount: 8_000_000,
+                        mint_amount: 8_000_000,
+                        tx_prefix_hash: [7u8; 32],
+                        recipient_address: &[0x12u8; 20],
+                        network_id: 1,
+                        target_chain_id: 42161,
+                        commitment_version: 1,
+                    },
+                    &[9u8; 32],
+                )
+                .expect("Proof generation should succeed");
+
+            let estimated = proof.security_level::<StatementHasher>(true);
+            assert_eq!(estimated, conjectured_security_bits(&options, TRACE_LENGTH));
+            assert!(estimated >= preset.target_bits());
+        }
+    }
+
+    #[test]
+    fn test_constrained_preset_meets_verifier_policy() {
+        use crate::burn_mint_verifier::{SecurityPolicyError, XfgBurnMintVerifier};
+
+        let preset = SecurityPreset::Constrained;
+        let options = preset.default_proof_options(TRACE_LENGTH).unwrap();
+        let standard = SecurityPreset::Standard.default_proof_options(TRACE_LENGTH).unwrap();
+        assert!(options.blowup_factor() < standard.blowup_factor());
+        assert!(!is_testnet_only(&options, TRACE_LENGTH));
+
+        let prove = |options: ProofOptions| {
+            XfgBurnMintProver::with_options(128, options)
+                .prove_burn_mint_with_public_inputs(
+                    &BurnMintRequest {
+                        burn_amount: 8_000_000,
+                        mint_amount: 8_000_000,
+                        tx_prefix_hash: [7u8; 32],
+                        recipient_address: &[0x12u8; 20],
+                        network_id: 1,
+                        target_chain_id: 42161,
+                        commitment_version: 1,
+                    },
+                    &[9u8; 32],
+                )
+                .expect("Proof generation should succeed")
+        };
+        let verifier = XfgBurnMintVerifier::default();
+        let (proof, public_inputs) = prove(options.clone());
+        assert!(verifier.verify_from_bytes(&proof.to_bytes(), &public_inputs).unwrap().is_valid());
+
+        // Trimming queries below the minimum is flagged and rejected by the verifier
+        let trimmed = ProofOptions::new(
+            options.num_queries() / 2,
+            options.blowup_factor(),
+            options.grinding_factor(),
+            options.field_extension(),
+            preset.fri_folding_factor(),
+            PRESET_FRI_REMAINDER_MAX_DEGREE,
+        );
+        assert!(is_testnet_only(&trimmed, TRACE_LENGTH));
+        let (proof, public_inputs) = prove(trimmed);
+        assert!(matches!(
+            verifier.verify_from_bytes(&proof.to_bytes(), &public_inputs),
+            Err(crate::XfgStarkError::SecurityPolicyError(SecurityPolicyError::InsufficientSecurity { .. }))
+        ));
+    }
+}
@@ -0,0 +1,261 @@
+//! Proving and Verifying Keys
+//!
+//! Domain data depends only on the shape of the statement, not on its values: the
+//! trace domain generator and FFT twiddles follow from the trace length, and the LDE
+//! domain from the blowup factor. [`setup`] computes it once per [`AirShape`] and
+//! caches the resulting [`ProvingKey`] for the life of the process, so every prover of
+//! that shape shares one copy. The [`VerifyingKey`] keeps the part a verifier needs to
+//! check that a proof has the expected shape, plus a digest that identifies it.
+
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use winterfell::{
+    math::{fft, fields::f64::BaseElement, StarkField},
+    StarkProof,
+};
+
+/// Domain separator of verifying key digests
+const VERIFYING_KEY_DOMAIN: &[u8] = b"xfg-stark-verifying-key-v1";
+
+/// Shortest trace Winterfell proves
+const MIN_TRACE_LENGTH: usize = 8;
+
+/// Key error types
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum KeyError {
+    /// The shape cannot be proven
+    #[error("Invalid AIR shape: {0}")]
+    InvalidShape(String),
+
+    /// A proof has another shape than the key
+    #[error("Proof shape {actual:?} does not match the verifying key's {expected:?}")]
+    ShapeMismatch {
+        /// Shape of the key
+        expected: AirShape,
+        /// Shape of the proof
+        actual: AirShape,
+    },
+}
+
+/// Dimensions that determine a statement's domain data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AirShape {
+    /// Number of trace registers
+    pub trace_width: usize,
+    /// Number of trace rows
+    pub trace_length: usize,
+    /// LDE blowup factor
+    pub blowup_factor: usize,
+}
+
+impl AirShape {
+    /// Create an AIR shape
+    #[must_use]
+    pub fn new(trace_width: usize, trace_length: usize, blowup_factor: usize) -> Self {
+        Self {
+            trace_width,
+            trace_length,
+            blowup_factor,
+        }
+    }
+
+    /// Shape of a Winterfell proof
+    #[must_use]
+    pub fn of_proof(proof: &StarkProof) -> Self {
+        Self::new(
+            proof.trace_layout().main_trace_width(),
+            proof.trace_length(),
+            proof.options().blowup_factor(),
+        )
+    }
+
+    /// Size of the low-degree extension domain
+    #[must_use]
+    pub fn lde_domain_size(&self) -> usize {
+        self.trace_length * self.blowup_factor
+    }
+
+    /// Check that the shape can be proven
+    ///
+    /// # Errors
+    ///
+    /// [`KeyError::InvalidShape`] if the trace has no registers, or the trace
+    /// length or blowup factor is not a valid power of two
+    pub fn validate(&self) -> Result<(), KeyError> {
+        if self.trace_width == 0 {
+            return Err(KeyError::InvalidShape("trace has no registers".to_string()));
+        }
+        if !self.trace_length.is_power_of_two() || self.trace_length < MIN_TRACE_LENGTH {
+            return Err(KeyError::InvalidShape(format!(
+                "trace length {} is not a power of two of at least {}",
+                self.trace_length, MIN_TRACE_LENGTH
+            )));
+        }
+        if !self.blowup_factor.is_power_of_two() || self.blowup_factor < 2 {
+            return Err(KeyError::InvalidShape(format!(
+                "blowup factor {} is not a power of two of at least 2",
+                self.blowup_factor
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Domain data a verifier needs for proofs of one shape
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyingKey {
+    shape: AirShape,
+    trace_domain_generator: BaseElement,
+    lde_domain_generator: BaseElement,
+    digest: [u8; 32],
+}
+
+impl VerifyingKey {
+    fn new(shape: AirShape) -> Self {
+        let trace_domain_generator = BaseElement::get_root_of_unity(shape.trace_length.ilog2());
+        let lde_domain_generator = BaseElement::get_root_of_unity(shape.lde_domain_size().ilog2());
+
+        let mut hasher = Sha256::new();
+        hasher.update(VERIFYING_KEY_DOMAIN);
+        for value in [shape.trace_width, shape.trace_length, shape.blowup_factor] {
+            hasher.update((value as u64).to_le_bytes());
+        }
+        hasher.update(trace_domain_generator.as_int().to_le_bytes());
+        hasher.update(lde_domain_generator.as_int().to_le_bytes());
+
+        Self {
+            shape,
+            trace_domain_generator,
+            lde_domain_generator,
+            digest: hasher.finalize().into(),
+        }
+    }
+
+    /// Shape the key is for
+    #[must_use]
+    pub fn shape(&self) -> AirShape {
+        self.shape
+    }
+
+    /// Generator of the trace domain
+    #[must_use]
+    pub fn trace_domain_generator(&self) -> BaseElement {
+        self.trace_domain_generator
+    }
+
+    /// Generator of the LDE domain
+    #[must_use]
+    pub fn lde_domain_generator(&self) -> BaseElement {
+        self.lde_domain_generator
+    }
+
+    /// Digest identifying the key
+    #[must_use]
+    pub fn digest(&self) -> [u8; 32] {
+        self.digest
+    }
+
+    /// Check that `proof` has the key's shape
+    ///
+    /// # Errors
+    ///
+    /// [`KeyError::ShapeMismatch`] if the proof was made for another shape
+    pub fn check_proof(&self, proof: &StarkProof) -> Result<(), KeyError> {
+        let actual = AirShape::of_proof(proof);
+        if actual != self.shape {
+            return Err(KeyError::ShapeMismatch {
+                expected: self.shape,
+                actual,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Domain data a prover needs for statements of one shape
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvingKey {
+    verifying_key: VerifyingKey,
+    trace_twiddles: Vec<BaseElement>,
+    trace_inv_twiddles: Vec<BaseElement>,
+}
+
+impl ProvingKey {
+    /// Compute a proving key without the cache (see [`setup`])
+    ///
+    /// # Errors
+    ///
+    /// As [`AirShape::validate`]
+    pub fn new(shape: AirShape) -> Result<Self, KeyError> {
+        shape.validate()?;
+        Ok(Self {
+            verifying_key: VerifyingKey::new(shape),
+            trace_twiddles: fft::get_twiddles(shape.trace_length),
+            trace_inv_twiddles: fft::get_inv_twiddles(shape.trace_length),
+        })
+    }
+
+    /// Shape the key is for
+    #[must_use]
+    pub fn shape(&self) -> AirShape {
+        self.verifying_key.shape
+    }
+
+    /// Matching verifying key
+    #[must_use]
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// FFT twiddles for the trace domain
+    #[must_use]
+    pub fn trace_twiddles(&self) -> &[BaseElement] {
+        &self.trace_twiddles
+    }
+
+    /// Inverse FFT twiddles for the trace domain
+    #[must_use]
+    pub fn trace_inv_twiddles(&self) -> &[BaseElement] {
+        &self.trace_inv_twiddles
+    }
+}
+
+/// Proving key for `shape`, computed on first request and shared afterwards
+///
+/// # Errors
+///
+/// As [`AirShape::validate`]
+pub fn setup(shape: AirShape) -> Result<Arc<ProvingKey>, KeyError> {
+    static KEYS: OnceLock<Mutex<HashMap<AirShape, Arc<ProvingKey>>>> = OnceLock::new();
+
+    let mut keys = KEYS.get_or_init(Default::default).lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(key) = keys.get(&shape) {
+        return Ok(Arc::clone(key));
+    }
+    let key = Arc::new(ProvingKey::new(shape)?);
+    keys.insert(shape, Arc::clone(&key));
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winterfell::math::FieldElement;
+
+    #[test]
+    fn test_setup_caches_by_shape() {
+        let shape = AirShape::new(3, 32, 8);
+        let key = setup(shape).unwrap();
+        assert!(Arc::ptr_eq(&key, &setup(shape).unwrap()));
+        assert_eq!(key.trace_twiddles().len(), 16);
+        assert_eq!(key.verifying_key().lde_domain_generator().exp(256), BaseElement::ONE);
+
+        let wider = setup(AirShape::new(4, 32, 8)).unwrap();
+        assert!(!Arc::ptr_eq(&key, &wider));
+        assert_ne!(key.verifying_key().digest(), wider.verifying_key().digest());
+
+        assert!(matches!(setup(AirShape::new(3, 24, 8)), Err(KeyError::InvalidShape(_))));
+        assert!(matches!(setup(AirShape::new(3, 32, 1)), Err(KeyError::InvalidShape(_))));
+    }
+}
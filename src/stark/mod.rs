@@ -2,4 +2,7 @@
 //! 
 //! This module provides STARK proof implementations for the XFG STARK project.
 
+pub mod keys;
+
 pub use crate::types::stark::*;
+pub use keys::*;
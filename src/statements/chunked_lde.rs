@@ -0,0 +1,331 @@
+//! Disk-Backed Trace Extension
+//!
+//! [`ConstantColumnTraceLde`](super::ConstantColumnTraceLde) keeps the extended varying
+//! columns in memory, `blowup` times the size of the trace. Under
+//! [`LdeStrategy::Chunked`](crate::proof::memory::LdeStrategy::Chunked) the extension is
+//! computed one coset of the trace domain at a time and spilled to a temporary file, and
+//! rows are read back through a cache of a few chunks. LDE row `k * blowup + j` is row
+//! `k` of coset `j`, so walking the LDE domain in order reads every coset sequentially.
+//!
+//! The row commitment is built the same way: rows are hashed chunk by chunk into subtree
+//! roots, and only the tree over those roots is kept. Query openings rebuild the subtree
+//! of each queried chunk from the file. Roots and openings are identical to those of a
+//! Merkle tree over all rows.
+//!
+//! Cached chunks are wiped when evicted or dropped, and the spill file is overwritten
+//! with zeros before it is removed. Overwriting does not reach copies the file system
+//! or the disk may keep elsewhere.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use crate::types::secret::wipe_elements;
+use winter_utils::{ByteReader, ByteWriter, SliceReader};
+use zeroize::Zeroize;
+use winterfell::{
+    crypto::{BatchMerkleProof, ElementHasher, Hasher, MerkleTree},
+    math::{fft, StarkField},
+    matrix::ColMatrix,
+    StarkDomain,
+};
+
+/// Spill files this process has created, for unique file names
+static SPILL_FILES: AtomicUsize = AtomicUsize::new(0);
+
+/// Temporary file removed when dropped
+struct SpillFile {
+    path: PathBuf,
+    file: File,
+}
+
+impl SpillFile {
+    fn create() -> io::Result<Self> {
+        let path = std::env::temp_dir().join(format!(
+            "xfg-stark-lde-{}-{}.bin",
+            std::process::id(),
+            SPILL_FILES.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = OpenOptions::new().read(true).write(true).create_new(true).open(&path)?;
+        Ok(Self { path, file })
+    }
+
+    /// Overwrite the file's contents with zeros
+    fn scrub(&mut self) -> io::Result<()> {
+        let mut remaining = self.file.metadata()?.len();
+        let zeros = [0u8; 4096];
+        self.file.seek(SeekFrom::Start(0))?;
+        while remaining > 0 {
+            let len = usize::try_from(remaining).map_or(zeros.len(), |remaining| remaining.min(zeros.len()));
+            self.file.write_all(&zeros[..len])?;
+            remaining -= len as u64;
+        }
+        self.file.sync_data()
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = self.scrub();
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Rows of one coset chunk held in memory
+struct CachedChunk<B: StarkField> {
+    /// (coset, chunk index) the rows belong to
+    key: (usize, usize),
+    rows: Vec<B>,
+    last_used: u64,
+}
+
+impl<B: StarkField> Drop for CachedChunk<B> {
+    fn drop(&mut self) {
+        wipe_elements(&mut self.rows);
+    }
+}
+
+/// Spill file and the chunks most recently read from it
+struct ChunkCache<B: StarkField> {
+    spill: SpillFile,
+    chunks: Vec<CachedChunk<B>>,
+    uses: u64,
+}
+
+/// Extended trace columns stored coset by coset in a temporary file
+pub(crate) struct SpilledLde<B: StarkField> {
+    cache: Mutex<ChunkCache<B>>,
+    width: usize,
+    trace_len: usize,
+    blowup: usize,
+    /// Rows of one coset read from the file at a time
+    chunk_rows: usize,
+}
+
+impl<B: StarkField> SpilledLde<B> {
+    /// Extend `polys` over the LDE domain of `domain`, keeping about `chunk_rows` rows in memory
+    pub(crate) fn new(polys: &ColMatrix<B>, domain: &StarkDomain<B>, chunk_rows: usize) -> io::Result<Self> {
+        let (width, trace_len, blowup) = (polys.num_cols(), polys.num_rows(), domain.trace_to_lde_blowup());
+        let spill = SpillFile::create()?;
+
+        // Coset j of the trace domain is the LDE domain offset times g^j, g generating the LDE domain
+        let generator = B::get_root_of_unity(domain.lde_domain_size().ilog2());
+        let mut offset = domain.offset();
+        let mut coset = vec![B::ZERO; trace_len * width];
+        let mut bytes = Vec::with_capacity(coset.len() * B::ELEMENT_BYTES);
+        let mut writer = BufWriter::new(&spill.file);
+        for _ in 0..blowup {
+            for (column, poly) in polys.columns().enumerate() {
+                let mut values = fft::evaluate_poly_with_offset(poly, domain.trace_twiddles(), offset, 1);
+                for (row, value) in values.iter().enumerate() {
+                    coset[row * width + column] = *value;
+                }
+                wipe_elements(&mut values);
+            }
+            bytes.clear();
+            bytes.write_many(&coset);
+            writer.write_all(&bytes)?;
+            offset *= generator;
+        }
+        writer.flush()?;
+        drop(writer);
+        wipe_elements(&mut coset);
+        bytes.zeroize();
+
+        Ok(Self {
+            cache: Mutex::new(ChunkCache {
+                spill,
+                chunks: Vec::with_capacity(blowup + 1),
+                uses: 0,
+            }),
+            width,
+            trace_len,
+            blowup,
+            chunk_rows: (chunk_rows / blowup).clamp(1, trace_len),
+        })
+    }
+
+    /// Call `f` with extended row `step`
+    ///
+    /// # Panics
+    /// If the spill file cannot be read back.
+    pub(crate) fn with_row<R>(&self, step: usize, f: impl FnOnce(&[B]) -> R) -> R {
+        let (coset, row) = (step % self.blowup, step / self.blowup);
+        let key = (coset, row / self.chunk_rows);
+        let mut cache = self.cache.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        cache.uses += 1;
+        let uses = cache.uses;
+
+        let slot = if let Some(slot) = cache.chunks.iter().position(|chunk| chunk.key == key) {
+            slot
+        } else {
+            let rows = self
+                .read_chunk(&mut cache.spill.file, key)
+                .expect("failed to read the spilled trace LDE");
+            // One chunk per coset covers an in-order walk of the LDE domain
+            if cache.chunks.len() <= self.blowup {
+                cache.chunks.push(CachedChunk { key, rows, last_used: uses });
+                cache.chunks.len() - 1
+            } else {
+                let (slot, _) = cache
+                    .chunks
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, chunk)| chunk.last_used)
+                    .expect("the cache holds chunks");
+                cache.chunks[slot] = CachedChunk { key, rows, last_used: uses };
+                slot
+            }
+        };
+
+        let chunk = &mut cache.chunks[slot];
+        chunk.last_used = uses;
+        let offset = (row % self.chunk_rows) * self.width;
+        f(&chunk.rows[offset..offset + self.width])
+    }
+
+    /// Read chunk `index` of `coset` from the spill file
+    fn read_chunk(&self, file: &mut File, (coset, index): (usize, usize)) -> io::Result<Vec<B>> {
+        let first_row = coset * self.trace_len + index * self.chunk_rows;
+        let num_rows = self.chunk_rows.min(self.trace_len - index * self.chunk_rows);
+        let mut bytes = vec![0u8; num_rows * self.width * B::ELEMENT_BYTES];
+        file.seek(SeekFrom::Start((first_row * self.width * B::ELEMENT_BYTES) as u64))?;
+        file.read_exact(&mut bytes)?;
+        let rows = SliceReader::new(&bytes)
+            .read_many(num_rows * self.width)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()));
+        bytes.zeroize();
+        rows
+    }
+}
+
+/// Merkle commitment to rows that keeps only the tree over chunk roots
+pub(crate) struct ChunkedRowTree<H: Hasher> {
+    top: MerkleTree<H>,
+    chunk_rows: usize,
+}
+
+impl<H: Hasher> ChunkedRowTree<H> {
+    /// Commit to `num_rows` rows of `width` elements, `chunk_rows` at a time
+    ///
+    /// `read_row` fills a row buffer with the row at a step. Both counts must be powers of
+    /// two, with at least two rows per chunk and two chunks.
+    pub(crate) fn new<B>(
+        num_rows: usize,
+        width: usize,
+        chunk_rows: usize,
+        read_row: impl Fn(usize, &mut [B]),
+    ) -> Self
+    where
+        B: StarkField,
+        H: ElementHasher<BaseField = B>,
+    {
+        let roots = (0..num_rows / chunk_rows)
+            .map(|chunk| *Self::chunk_tree(chunk, width, chunk_rows, &read_row).root())
+            .collect();
+        let top = MerkleTree::new(roots).expect("failed to construct trace Merkle tree");
+        Self { top, chunk_rows }
+    }
+
+    /// Root of the tree over all rows
+    pub(crate) fn root(&self) -> &H::Digest {
+        self.top.root()
+    }
+
+    /// Batch opening of the rows at `positions`, as a tree over all rows would prove it
+    pub(crate) fn prove_batch<B>(&self, positions: &[usize], width: usize, read_row: impl Fn(usize, &mut [B])) -> BatchMerkleProof<H>
+    where
+        B: StarkField,
+        H: ElementHasher<BaseField = B>,
+    {
+        let mut subtrees = BTreeMap::new();
+        let paths: Vec<_> = positions
+            .iter()
+            .map(|&position| {
+                let chunk = position / self.chunk_rows;
+                let subtree = subtrees
+                    .entry(chunk)
+                    .or_insert_with(|| Self::chunk_tree(chunk, width, self.chunk_rows, &read_row));
+                let mut path = subtree
+                    .prove(position % self.chunk_rows)
+                    .expect("failed to generate a Merkle proof for trace queries");
+                // Above the chunk root, the path continues with the top tree's siblings
+                let top_path = self.top.prove(chunk).expect("failed to generate a Merkle proof for trace queries");
+                path.extend_from_slice(&top_path[1..]);
+                path
+            })
+            .collect();
+        BatchMerkleProof::from_paths(&paths, positions)
+    }
+
+    /// Tree over the row hashes of one chunk
+    fn chunk_tree<B>(chunk: usize, width: usize, chunk_rows: usize, read_row: &impl Fn(usize, &mut [B])) -> MerkleTree<H>
+    where
+        B: StarkField,
+        H: ElementHasher<BaseField = B>,
+    {
+        let mut row = vec![B::ZERO; width];
+        let leaves = (chunk * chunk_rows..(chunk + 1) * chunk_rows)
+            .map(|step| {
+                read_row(step, &mut row);
+                H::hash_elements(&row)
+            })
+            .collect();
+        wipe_elements(&mut row);
+        MerkleTree::new(leaves).expect("failed to construct trace Merkle tree")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winterfell::{
+        crypto::hashers::Blake3_256,
+        math::{fields::f64::BaseElement, FieldElement},
+    };
+
+    type H = Blake3_256<BaseElement>;
+
+    #[test]
+    fn test_spill_file_removed() {
+        let mut spill = SpillFile::create().unwrap();
+        spill.file.write_all(&[0xAB; 10_000]).unwrap();
+        spill.scrub().unwrap();
+        let mut contents = Vec::new();
+        spill.file.seek(SeekFrom::Start(0)).unwrap();
+        spill.file.read_to_end(&mut contents).unwrap();
+        assert_eq!(contents, vec![0u8; 10_000]);
+
+        let path = spill.path.clone();
+        drop(spill);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_chunked_tree_matches_full_tree() {
+        let (num_rows, width) = (64, 3);
+        let read_row = |step: usize, row: &mut [BaseElement]| {
+            for (i, value) in row.iter_mut().enumerate() {
+                *value = BaseElement::new((step * width + i) as u64);
+            }
+        };
+        let mut row = vec![BaseElement::ZERO; width];
+        let leaves = (0..num_rows)
+            .map(|step| {
+                read_row(step, &mut row);
+                H::hash_elements(&row)
+            })
+            .collect();
+        let full = MerkleTree::<H>::new(leaves).unwrap();
+        let chunked = ChunkedRowTree::<H>::new(num_rows, width, 8, read_row);
+        assert_eq!(chunked.root(), full.root());
+
+        let positions = [1, 6, 17, 40, 63];
+        assert_eq!(
+            chunked.prove_batch(&positions, width, read_row),
+            full.prove_batch(&positions).unwrap()
+        );
+    }
+}
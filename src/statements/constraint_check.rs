@@ -0,0 +1,228 @@
+//! Pre-Commitment Constraint Checking
+//!
+//! A trace that violates its AIR still produces a proof, and it fails only at
+//! verification with no hint of where the circuit and the trace disagree. With
+//! [`ProverOptions::check_constraints`] set, the prover evaluates every assertion and
+//! transition constraint on the generated trace before committing to it and reports the
+//! first violation with its row and constraint index. The check is on by default in
+//! debug builds and off in release builds, where it would only cost time.
+//! [`ProverOptions::dump_trace`] additionally saves the trace for offline analysis.
+
+use std::path::PathBuf;
+use thiserror::Error;
+use winterfell::{math::fields::f64::BaseElement, math::FieldElement, Air, EvaluationFrame, Trace};
+
+/// Prover behaviour beyond the proof options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProverOptions {
+    /// Evaluate all constraints on the trace before committing to it
+    pub check_constraints: bool,
+    /// Save the trace here before committing to it (see [`crate::trace_dump`])
+    pub dump_trace: Option<PathBuf>,
+}
+
+impl ProverOptions {
+    /// Check constraints before committing
+    #[must_use]
+    pub const fn checked() -> Self {
+        Self {
+            check_constraints: true,
+            dump_trace: None,
+        }
+    }
+
+    /// Commit without checking constraints
+    #[must_use]
+    pub const fn unchecked() -> Self {
+        Self {
+            check_constraints: false,
+            dump_trace: None,
+        }
+    }
+}
+
+impl ProverOptions {
+    /// Save the trace to `path` before committing to it
+    #[must_use]
+    pub fn with_trace_dump(mut self, path: impl Into<PathBuf>) -> Self {
+        self.dump_trace = Some(path.into());
+        self
+    }
+}
+
+impl Default for ProverOptions {
+    /// Checked in debug builds, unchecked in release builds
+    fn default() -> Self {
+        Self {
+            check_constraints: cfg!(debug_assertions),
+            dump_trace: None,
+        }
+    }
+}
+
+/// First constraint a trace violates
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConstraintViolation {
+    /// Trace width differs from the AIR's
+    #[error("Trace has {actual} columns, the AIR expects {expected}")]
+    TraceWidth {
+        /// Columns the AIR expects
+        expected: usize,
+        /// Columns in the trace
+        actual: usize,
+    },
+    /// A register does not hold the asserted value
+    #[error("Assertion on column {column} failed at row {row}: expected {expected}, found {actual}")]
+    Assertion {
+        /// Asserted column
+        column: usize,
+        /// Row of the failing assertion
+        row: usize,
+        /// Asserted value
+        expected: u64,
+        /// Value in the trace
+        actual: u64,
+    },
+    /// A transition constraint does not evaluate to zero
+    #[error("Transition constraint {constraint} failed at row {row}: evaluated to {value}")]
+    Transition {
+        /// Index of the constraint in the AIR's evaluation order
+        constraint: usize,
+        /// Row the transition starts from
+        row: usize,
+        /// Non-zero evaluation
+        value: u64,
+    },
+}
+
+/// Evaluate every main-segment assertion and transition constraint of `air` on `trace`
+///
+/// # Errors
+///
+/// The first [`ConstraintViolation`] found: a trace of the wrong width, then failing
+/// assertions, then failing transitions in row order
+pub fn check_constraints<A, T>(air: &A, trace: &T) -> Result<(), ConstraintViolation>
+where
+    A: Air<BaseField = BaseElement>,
+    T: Trace<BaseField = BaseElement>,
+{
+    let expected_width = air.trace_layout().main_trace_width();
+    if trace.main_trace_width() != expected_width {
+        return Err(ConstraintViolation::TraceWidth {
+            expected: expected_width,
+            actual: trace.main_trace_width(),
+        });
+    }
+
+    for assertion in air.get_assertions() {
+        let mut violation = None;
+        assertion.apply(trace.length(), |row, expected| {
+            let actual = trace.main_segment().get(assertion.column(), row);
+            if violation.is_none() && actual != expected {
+                violation = Some(ConstraintViolation::Assertion {
+                    column: assertion.column(),
+                    row,
+                    expected: expected.as_int(),
+                    actual: actual.as_int(),
+                });
+            }
+        });
+        if let Some(violation) = violation {
+            return Err(violation);
+        }
+    }
+
+    // Periodic columns repeat over the trace domain, so row values index them directly
+    let periodic_columns = air.get_periodic_column_values();
+    let mut periodic_values = vec![BaseElement::ZERO; periodic_columns.len()];
+    let mut frame = EvaluationFrame::new(expected_width);
+    let mut evaluations = vec![BaseElement::ZERO; air.context().num_main_transition_constraints()];
+
+    for row in 0..trace.length() - air.context().num_transition_exemptions() {
+        for (value, column) in periodic_values.iter_mut().zip(&periodic_columns) {
+            *value = column[row % column.len()];
+        }
+        trace.read_main_frame(row, &mut frame);
+        air.evaluate_transition(&frame, &periodic_values, &mut evaluations);
+
+        if let Some((constraint, value)) = evaluations
+            .iter()
+            .enumerate()
+            .find(|(_, value)| **value != BaseElement::ZERO)
+        {
+            return Err(ConstraintViolation::Transition {
+                constraint,
+                row,
+                value: value.as_int(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_air::XfgBurnMintAir;
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+    use winterfell::TraceTable;
+
+    /// AIR and a valid trace for a standard burn
+    fn burn_mint_air_and_trace() -> (XfgBurnMintAir, TraceTable<BaseElement>) {
+        let prover = XfgBurnMintProver::default();
+        let (_, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let air = XfgBurnMintAir::new(
+            prover.domain().trace_info.clone(),
+            public_inputs,
+            crate::burn_mint_prover::secret_to_field_elements(&[9u8; 32]).unwrap(),
+            prover.proof_options().clone(),
+        );
+        let trace = air.build_trace();
+        (air, trace)
+    }
+
+    #[test]
+    fn test_valid_trace_passes() {
+        let (air, trace) = burn_mint_air_and_trace();
+        assert_eq!(check_constraints(&air, &trace), Ok(()));
+    }
+
+    #[test]
+    fn test_violations_report_row_and_constraint() {
+        let (air, mut trace) = burn_mint_air_and_trace();
+        let burn = trace.get(0, 5);
+        trace.set(0, 5, burn + BaseElement::ONE);
+        assert_eq!(
+            check_constraints(&air, &trace),
+            Err(ConstraintViolation::Assertion {
+                column: 0,
+                row: 5,
+                expected: burn.as_int(),
+                actual: burn.as_int() + 1,
+            })
+        );
+
+        // Jump the state register two states ahead mid-trace
+        let (air, mut trace) = burn_mint_air_and_trace();
+        let state = trace.get(4, 10);
+        trace.set(4, 11, state + BaseElement::new(2));
+        assert!(matches!(
+            check_constraints(&air, &trace),
+            Err(ConstraintViolation::Transition { constraint: 0, row: 10, .. })
+        ));
+    }
+}
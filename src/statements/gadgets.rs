@@ -0,0 +1,431 @@
+//! Statement-Agnostic Gadgets
+//!
+//! Building blocks shared by the burn & mint circuit and any adjacent statement:
+//!
+//! - [`NullifierGadget`]: domain-separated nullifier derived from a secret and a scope value
+//! - [`CommitmentGadget`]: Keccak256 commitment over field elements and raw bytes
+//! - [`StateMachineGadget`]: a monotone state register advancing at most one state per step
+//! - [`RangeCheckGadget`]: bit decomposition proving a register value fits in `n` bits
+//! - [`pin_register`]: periodic assertions holding a register constant on every row
+//! - [`copy_constraint`]: transition constraint holding a register constant between rows
+//!
+//! Hash-based gadgets are evaluated outside the trace and their results are carried as
+//! public inputs; the trace registers holding them are pinned with [`pin_register`] and
+//! copied row to row with [`copy_constraint`].
+//! [`crate::air::hash_gadget`] instead proves a Rescue-Prime nullifier inside the trace.
+
+use sha3::{Digest, Keccak256};
+use winter_math::FieldElement;
+use winterfell::{math::fields::f64::BaseElement, Assertion, TransitionConstraintDegree};
+
+/// Number of assertions [`pin_register`] produces
+pub const PINNED_REGISTER_ASSERTIONS: usize = 2;
+
+/// Map a hash digest to a field element (first four bytes, little-endian)
+#[must_use]
+pub fn hash_to_element(digest: &[u8]) -> BaseElement {
+    BaseElement::from(u32::from_le_bytes([digest[0], digest[1], digest[2], digest[3]]))
+}
+
+/// Pin a register to a constant value on every row
+///
+/// Two periodic assertions (even and odd steps) cover the whole trace.
+#[must_use]
+pub fn pin_register(
+    register: usize,
+    value: BaseElement,
+) -> [Assertion<BaseElement>; PINNED_REGISTER_ASSERTIONS] {
+    [
+        Assertion::periodic(register, 0, 2, value),
+        Assertion::periodic(register, 1, 2, value),
+    ]
+}
+
+/// Copy constraint, zero when `next` repeats `current`
+///
+/// Evaluated on a register's value in consecutive rows; a constant register satisfies it
+/// on every transition.
+pub fn copy_constraint<E: FieldElement>(current: E, next: E) -> E {
+    next - current
+}
+
+/// Degree of [`copy_constraint`]
+#[must_use]
+pub fn copy_constraint_degree() -> TransitionConstraintDegree {
+    TransitionConstraintDegree::new(1)
+}
+
+/// Domain-separated nullifier derivation
+///
+/// `nullifier = H(le64(secret) || domain || le64(scope))`, where `scope` ties the
+/// nullifier to the statement being proven (the burn amount for burn & mint).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NullifierGadget {
+    domain: &'static [u8],
+}
+
+impl NullifierGadget {
+    /// Create a nullifier gadget for a statement's domain tag
+    #[must_use]
+    pub const fn new(domain: &'static [u8]) -> Self {
+        Self { domain }
+    }
+
+    /// Domain tag mixed into every nullifier
+    #[must_use]
+    pub const fn domain(&self) -> &'static [u8] {
+        self.domain
+    }
+
+    /// Derive the nullifier for a secret within a scope
+    #[must_use]
+    pub fn derive(&self, secret: BaseElement, scope: BaseElement) -> BaseElement {
+        let mut hasher = Keccak256::new();
+        hasher.update(secret.as_int().to_le_bytes());
+        hasher.update(self.domain);
+        hasher.update(scope.as_int().to_le_bytes());
+        hash_to_element(&hasher.finalize())
+    }
+
+    /// Consistency check, zero when `nullifier` matches the secret and scope
+    #[must_use]
+    pub fn check(&self, secret: BaseElement, scope: BaseElement, nullifier: BaseElement) -> BaseElement {
+        nullifier - self.derive(secret, scope)
+    }
+}
+
+/// Keccak256 commitment over an ordered sequence of values
+///
+/// Field elements are absorbed as their canonical little-endian `u64` encoding;
+/// the statement's tag is absorbed last.
+#[derive(Clone, Default)]
+pub struct CommitmentGadget {
+    hasher: Keccak256,
+}
+
+impl CommitmentGadget {
+    /// Start a new commitment
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorb a field element
+    #[must_use]
+    pub fn element(mut self, value: BaseElement) -> Self {
+        self.hasher.update(value.as_int().to_le_bytes());
+        self
+    }
+
+    /// Absorb raw bytes
+    #[must_use]
+    pub fn bytes(mut self, bytes: &[u8]) -> Self {
+        self.hasher.update(bytes);
+        self
+    }
+
+    /// Close the commitment with the statement's tag
+    #[must_use]
+    pub fn finish(mut self, tag: &[u8]) -> BaseElement {
+        self.hasher.update(tag);
+        hash_to_element(&self.hasher.finalize())
+    }
+}
+
+/// Monotone state machine on a single register
+///
+/// States run from `0` to `num_states - 1`, each occupying an equal share of the trace.
+/// The transition constraint allows staying in a state or advancing by exactly one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateMachineGadget {
+    num_states: u32,
+}
+
+impl StateMachineGadget {
+    /// Create a state machine with `num_states` states
+    #[must_use]
+    pub const fn new(num_states: u32) -> Self {
+        Self { num_states }
+    }
+
+    /// Number of states
+    #[must_use]
+    pub const fn num_states(&self) -> u32 {
+        self.num_states
+    }
+
+    /// Final state
+    #[must_use]
+    pub const fn final_state(&self) -> u32 {
+        self.num_states - 1
+    }
+
+    /// Transition constraint, zero when `next` stays in or advances one past `current`
+    pub fn transition<E: FieldElement>(current: E, next: E) -> E {
+        let diff = next - current;
+        diff * (diff - E::ONE)
+    }
+
+    /// Degree of [`Self::transition`]
+    #[must_use]
+    pub fn constraint_degree() -> TransitionConstraintDegree {
+        TransitionConstraintDegree::new(2)
+    }
+
+    /// State occupied at `step` of a trace of `trace_length` steps
+    #[must_use]
+    pub fn state_at(&self, step: usize, trace_length: usize) -> u32 {
+        u32::try_from(step * self.num_states as usize / trace_length).unwrap_or(u32::MAX)
+    }
+
+    /// State register column for a trace of `trace_length` steps
+    #[must_use]
+    pub fn column(&self, trace_length: usize) -> Vec<BaseElement> {
+        (0..trace_length)
+            .map(|step| BaseElement::from(self.state_at(step, trace_length)))
+            .collect()
+    }
+
+    /// State occupied at `step` when the final state is reached at `final_step`
+    ///
+    /// The register stays in the final state after `final_step`.
+    #[must_use]
+    pub fn state_completing_at(&self, step: usize, final_step: usize) -> u32 {
+        self.state_at(step.min(final_step), final_step + 1)
+    }
+
+    /// Assertions that the register starts in state 0 and ends in the final state
+    #[must_use]
+    pub fn boundary_assertions(&self, register: usize, trace_length: usize) -> [Assertion<BaseElement>; 2] {
+        self.boundary_assertions_at(register, trace_length - 1)
+    }
+
+    /// Assertions that the register starts in state 0 and is in the final state at `final_step`
+    #[must_use]
+    pub fn boundary_assertions_at(&self, register: usize, final_step: usize) -> [Assertion<BaseElement>; 2] {
+        [
+            Assertion::single(register, 0, BaseElement::ZERO),
+            Assertion::single(register, final_step, BaseElement::from(self.final_state())),
+        ]
+    }
+}
+
+/// Range check by bit decomposition over two registers
+///
+/// A bit register holds one bit per row and an accumulator register folds them in most
+/// significant bit first: `acc' = 2·acc + bit'`. The accumulator is asserted to be zero
+/// `bits` rows before the last row and to equal the checked value on the last row, so
+/// the value is the sum of exactly `bits` binary digits and lies in `0..2^bits`. Rows
+/// before the zero row are unconstrained; the trace must be longer than `bits` rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeCheckGadget {
+    bits: u32,
+}
+
+impl RangeCheckGadget {
+    /// Number of assertions [`Self::boundary_assertions`] produces
+    pub const NUM_ASSERTIONS: usize = 2;
+
+    /// Create a range check for values below `2^bits` (at most 63 bits)
+    ///
+    /// # Panics
+    ///
+    /// If `bits` is 64 or more
+    #[must_use]
+    pub const fn new(bits: u32) -> Self {
+        assert!(bits < 64, "range checks stay below the field modulus");
+        Self { bits }
+    }
+
+    /// Bits in the decomposition
+    #[must_use]
+    pub const fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Largest value the range check accepts
+    #[must_use]
+    pub const fn max_value(&self) -> u64 {
+        (1 << self.bits) - 1
+    }
+
+    /// Whether `value` passes the range check
+    #[must_use]
+    pub const fn contains(&self, value: u64) -> bool {
+        value <= self.max_value()
+    }
+
+    /// Transition constraints, zero when the next bit is binary and the accumulator
+    /// absorbs it
+    pub fn transition<E: FieldElement>(current_accumulator: E, next_bit: E, next_accumulator: E) -> [E; 2] {
+        [
+            next_bit * (next_bit - E::ONE),
+            next_accumulator - (current_accumulator.double() + next_bit),
+        ]
+    }
+
+    /// Degrees of [`Self::transition`]
+    #[must_use]
+    pub fn constraint_degrees() -> [TransitionConstraintDegree; 2] {
+        [TransitionConstraintDegree::new(2), TransitionConstraintDegree::new(1)]
+    }
+
+    /// Bit and accumulator columns decomposing `value` over a trace of `trace_length` rows
+    #[must_use]
+    pub fn columns(&self, value: u64, trace_length: usize) -> [Vec<BaseElement>; 2] {
+        let start = trace_length - self.bits as usize;
+        let mut bit_column = vec![BaseElement::ZERO; trace_length];
+        let mut accumulator_column = vec![BaseElement::ZERO; trace_length];
+        let mut accumulator = 0u64;
+        for step in start..trace_length {
+            let bit = (value >> (trace_length - 1 - step)) & 1;
+            accumulator = 2 * accumulator + bit;
+            bit_column[step] = BaseElement::new(bit);
+            accumulator_column[step] = BaseElement::new(accumulator);
+        }
+        [bit_column, accumulator_column]
+    }
+
+    /// Assertions that the accumulator starts from zero `bits` rows before the last row
+    /// and ends at `value`
+    #[must_use]
+    pub fn boundary_assertions(
+        &self,
+        accumulator_register: usize,
+        trace_length: usize,
+        value: BaseElement,
+    ) -> [Assertion<BaseElement>; Self::NUM_ASSERTIONS] {
+        let last = trace_length - 1;
+        [
+            Assertion::single(accumulator_register, last - self.bits as usize, BaseElement::ZERO),
+            Assertion::single(accumulator_register, last, value),
+        ]
+    }
+
+    /// Bit and accumulator columns decomposing `value` below a leading one bit
+    ///
+    /// The accumulator ends at `2^bits + value`. Unlike [`Self::columns`], the bit column
+    /// never vanishes, so its constraints keep their declared degree when `value` is zero.
+    #[must_use]
+    pub fn leading_one_columns(&self, value: u64, trace_length: usize) -> [Vec<BaseElement>; 2] {
+        Self::new(self.bits + 1).columns((1 << self.bits) | value, trace_length)
+    }
+
+    /// Assertion that the accumulator of [`Self::leading_one_columns`] holds the leading
+    /// one `bits` rows before the last row
+    ///
+    /// It bounds the accumulator's last value to `2^bits` plus a value below `2^bits`
+    /// without fixing it, for values that transition constraints relate to other registers.
+    #[must_use]
+    pub fn leading_one_assertion(&self, accumulator_register: usize, trace_length: usize) -> Assertion<BaseElement> {
+        Assertion::single(accumulator_register, trace_length - 1 - self.bits as usize, BaseElement::ONE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nullifier_domain_separation() {
+        let secret = BaseElement::from(42u32);
+        let scope = BaseElement::from(8_000_000u32);
+        let burn = NullifierGadget::new(b"nullifier");
+        let stake = NullifierGadget::new(b"stake-nullifier");
+
+        let nullifier = burn.derive(secret, scope);
+        assert_eq!(nullifier, burn.derive(secret, scope));
+        assert_ne!(nullifier, stake.derive(secret, scope));
+        assert_eq!(burn.check(secret, scope, nullifier), BaseElement::ZERO);
+        assert_ne!(burn.check(BaseElement::from(43u32), scope, nullifier), BaseElement::ZERO);
+    }
+
+    #[test]
+    fn test_nullifier_preimage_layout() {
+        let secret = BaseElement::from(42u32);
+        let scope = BaseElement::from(8_000_000u32);
+
+        let mut hasher = Keccak256::new();
+        hasher.update(42u64.to_le_bytes());
+        hasher.update(b"nullifier");
+        hasher.update(8_000_000u64.to_le_bytes());
+
+        assert_eq!(
+            NullifierGadget::new(b"nullifier").derive(secret, scope),
+            hash_to_element(&hasher.finalize())
+        );
+    }
+
+    #[test]
+    fn test_commitment_order_and_tag() {
+        let a = BaseElement::from(1u32);
+        let b = BaseElement::from(2u32);
+        let commitment = CommitmentGadget::new().element(a).element(b).finish(b"tag");
+
+        assert_eq!(commitment, CommitmentGadget::new().element(a).element(b).finish(b"tag"));
+        assert_ne!(commitment, CommitmentGadget::new().element(b).element(a).finish(b"tag"));
+        assert_ne!(commitment, CommitmentGadget::new().element(a).element(b).finish(b"other"));
+    }
+
+    #[test]
+    fn test_state_machine_column() {
+        let machine = StateMachineGadget::new(4);
+        let column = machine.column(64);
+
+        assert_eq!(column[0], BaseElement::ZERO);
+        assert_eq!(column[16], BaseElement::ONE);
+        assert_eq!(column[63], BaseElement::from(machine.final_state()));
+        for pair in column.windows(2) {
+            assert_eq!(StateMachineGadget::transition(pair[0], pair[1]), BaseElement::ZERO);
+        }
+
+        // Completing early holds the final state for the remaining rows
+        let early: Vec<u32> = (0..6).map(|step| machine.state_completing_at(step, 3)).collect();
+        assert_eq!(early, [0, 1, 2, 3, 3, 3]);
+        assert_eq!(machine.state_completing_at(40, 63), machine.state_at(40, 64));
+        assert_ne!(
+            StateMachineGadget::transition(BaseElement::ZERO, BaseElement::from(2u32)),
+            BaseElement::ZERO
+        );
+    }
+
+    #[test]
+    fn test_range_check_columns() {
+        let range = RangeCheckGadget::new(53);
+        let value = 123_456_789_012_345u64;
+        let [bits, accumulator] = range.columns(value, 64);
+
+        assert_eq!(accumulator[63], BaseElement::new(value));
+        assert_eq!(accumulator[63 - 53], BaseElement::ZERO);
+        for step in 0..63 {
+            let constraints = RangeCheckGadget::transition(accumulator[step], bits[step + 1], accumulator[step + 1]);
+            assert_eq!(constraints, [BaseElement::ZERO; 2]);
+        }
+
+        assert!(range.contains(range.max_value()));
+        assert!(!range.contains(1 << 53));
+        let [_, full] = range.columns(range.max_value(), 64);
+        assert_eq!(full[63], BaseElement::new(range.max_value()));
+
+        // A non-binary digit breaks the bit constraint
+        let two = BaseElement::from(2u32);
+        assert_ne!(RangeCheckGadget::transition(BaseElement::ONE, two, BaseElement::new(4))[0], BaseElement::ZERO);
+    }
+
+    #[test]
+    fn test_range_check_leading_one() {
+        let range = RangeCheckGadget::new(14);
+        let [bits, accumulator] = range.leading_one_columns(0, 64);
+
+        // A zero value still sets the leading bit, where the assertion expects it
+        assert_eq!(accumulator[63], BaseElement::new(1 << 14));
+        assert_eq!(bits.iter().filter(|bit| **bit == BaseElement::ONE).count(), 1);
+        let assertion = range.leading_one_assertion(1, 64);
+        assert_eq!(assertion, Assertion::single(1, 63 - 14, BaseElement::ONE));
+        assert_eq!(accumulator[63 - 14], BaseElement::ONE);
+        for step in 0..63 {
+            let constraints = RangeCheckGadget::transition(accumulator[step], bits[step + 1], accumulator[step + 1]);
+            assert_eq!(constraints, [BaseElement::ZERO; 2]);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Statement Hash Functions
+//!
+//! The hash function commits to the trace, the constraint evaluations and the FRI
+//! layers, and seeds the Fiat-Shamir random coin. Provers and verifiers must agree on
+//! it, so proofs record it in their metadata:
+//!
+//! - [`HashFunction::Blake3`]: fastest to prove and verify natively (default)
+//! - [`HashFunction::Keccak`]: cheapest to verify in the EVM
+//! - [`HashFunction::Rescue`]: algebraic Rescue Prime (`Rp64_256`) over the base field,
+//!   for recursive verification
+
+use serde::{Deserialize, Serialize};
+use sha3::Digest as _;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::str::FromStr;
+use winter_utils::ByteWriter;
+use winterfell::{
+    crypto::{
+        hashers::{Blake3_256, Rp64_256, Sha3_256},
+        Digest, ElementHasher, Hasher,
+    },
+    math::{fields::f64::BaseElement, FieldElement, StarkField},
+    StarkProof,
+};
+
+/// 32-byte digest shared by the byte-oriented hashers
+type ByteDigest32 = <Sha3_256<BaseElement> as Hasher>::Digest;
+
+/// Keccak-256 (the EVM's hash) as a Winterfell hasher
+pub struct Keccak256Hasher<B: StarkField>(PhantomData<B>);
+
+impl<B: StarkField> Hasher for Keccak256Hasher<B> {
+    type Digest = ByteDigest32;
+
+    const COLLISION_RESISTANCE: u32 = 128;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        ByteDigest32::new(sha3::Keccak256::digest(bytes).into())
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update(values[0].as_bytes());
+        hasher.update(values[1].as_bytes());
+        ByteDigest32::new(hasher.finalize().into())
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update(seed.as_bytes());
+        hasher.update(value.to_le_bytes());
+        ByteDigest32::new(hasher.finalize().into())
+    }
+}
+
+impl<B: StarkField> ElementHasher for Keccak256Hasher<B> {
+    type BaseField = B;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        let mut writer = KeccakWriter(sha3::Keccak256::new());
+        writer.write_many(elements);
+        ByteDigest32::new(writer.0.finalize().into())
+    }
+}
+
+/// Feeds canonical element encodings into a Keccak-256 state
+struct KeccakWriter(sha3::Keccak256);
+
+impl ByteWriter for KeccakWriter {
+    fn write_u8(&mut self, value: u8) {
+        self.0.update([value]);
+    }
+
+    fn write_bytes(&mut self, values: &[u8]) {
+        self.0.update(values);
+    }
+}
+
+/// Hash function a statement proof commits with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HashFunction {
+    /// BLAKE3-256
+    #[default]
+    Blake3,
+    /// Keccak-256
+    Keccak,
+    /// Rescue Prime (`Rp64_256`) over the 64-bit base field
+    Rescue,
+}
+
+impl HashFunction {
+    /// Every supported hash function
+    pub const ALL: [HashFunction; 3] = [HashFunction::Blake3, HashFunction::Keccak, HashFunction::Rescue];
+
+    /// Name used in metadata and on the command line
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            HashFunction::Blake3 => "blake3",
+            HashFunction::Keccak => "keccak",
+            HashFunction::Rescue => "rescue",
+        }
+    }
+
+    /// Collision resistance of the hash function (bits)
+    #[must_use]
+    pub const fn collision_resistance(self) -> u32 {
+        match self {
+            HashFunction::Blake3 => Blake3_256::<BaseElement>::COLLISION_RESISTANCE,
+            HashFunction::Keccak => Keccak256Hasher::<BaseElement>::COLLISION_RESISTANCE,
+            HashFunction::Rescue => Rp64_256::COLLISION_RESISTANCE,
+        }
+    }
+
+    /// Security level of `proof` committed with this hash function (bits), conjectured
+    /// or provable
+    #[must_use]
+    pub fn security_level(self, proof: &StarkProof, conjectured: bool) -> u32 {
+        match self {
+            HashFunction::Blake3 => proof.security_level::<Blake3_256<BaseElement>>(conjectured),
+            HashFunction::Keccak => proof.security_level::<Keccak256Hasher<BaseElement>>(conjectured),
+            HashFunction::Rescue => proof.security_level::<Rp64_256>(conjectured),
+        }
+    }
+}
+
+impl Display for HashFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for HashFunction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "blake3" => Ok(HashFunction::Blake3),
+            "keccak" => Ok(HashFunction::Keccak),
+            "rescue" | "rpo" => Ok(HashFunction::Rescue),
+            _ => Err(format!("Unknown hash function '{s}' (expected blake3, keccak or rescue)")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keccak_hasher_matches_keccak256() {
+        // Keccak-256 of the empty string, not SHA3-256
+        assert_eq!(
+            hex::encode(Keccak256Hasher::<BaseElement>::hash(b"").as_bytes()),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+
+        let elements = [BaseElement::new(1), BaseElement::new(2)];
+        let mut bytes = Vec::new();
+        for element in elements {
+            bytes.extend_from_slice(&element.as_int().to_le_bytes());
+        }
+        assert_eq!(
+            Keccak256Hasher::<BaseElement>::hash_elements(&elements),
+            Keccak256Hasher::<BaseElement>::hash(&bytes)
+        );
+    }
+
+    #[test]
+    fn test_hash_function_names() {
+        for hash in HashFunction::ALL {
+            assert_eq!(hash.name().parse::<HashFunction>(), Ok(hash));
+            assert_eq!(serde_json::to_string(&hash).unwrap(), format!("\"{}\"", hash));
+            assert_eq!(hash.collision_resistance(), 128);
+        }
+        assert_eq!("KECCAK".parse::<HashFunction>(), Ok(HashFunction::Keccak));
+        assert_eq!("rpo".parse::<HashFunction>(), Ok(HashFunction::Rescue));
+        assert!("sha256".parse::<HashFunction>().is_err());
+        assert_eq!(HashFunction::default(), HashFunction::Blake3);
+    }
+}
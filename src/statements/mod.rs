@@ -0,0 +1,136 @@
+//! Custom STARK Statements
+//!
+//! The burn & mint circuit is one statement built from reusable gadgets. Adjacent
+//! statements (staking proofs, lock proofs, ...) can be assembled from the same
+//! [`gadgets`] and proven or verified through the generic [`prove_statement`] and
+//! [`verify_statement`] helpers, which the burn & mint prover and verifier use too.
+//!
+//! A statement is a Winterfell [`Air`] over the 64-bit base field together with a
+//! [`Prover`] that builds its trace. See `examples/staking_statement.rs` for a complete
+//! custom statement.
+//!
+//! Statements commit with [`StatementHasher`] (BLAKE3) unless a prover chooses another
+//! [`HashFunction`]; [`verify_statement_with_hash`] verifies such proofs.
+
+mod chunked_lde;
+pub mod constraint_check;
+pub mod gadgets;
+pub mod hashing;
+pub mod trace_lde;
+
+pub use constraint_check::*;
+pub use gadgets::*;
+pub use hashing::*;
+pub use trace_lde::*;
+
+use crate::trace_dump::{TraceCompression, TraceDump};
+use crate::Result;
+use winterfell::{
+    crypto::{
+        hashers::{Blake3_256, Rp64_256},
+        DefaultRandomCoin, ElementHasher,
+    },
+    math::fields::f64::BaseElement,
+    verify, AcceptableOptions, Air, ProofOptions, Prover, StarkProof, Trace, VerifierError,
+};
+
+/// Hash function used for statement commitments
+pub type StatementHasher = Blake3_256<BaseElement>;
+
+/// Random coin used for statement proofs
+pub type StatementRandomCoin = DefaultRandomCoin<StatementHasher>;
+
+/// Prove a statement from its execution trace
+///
+/// # Errors
+///
+/// [`crate::XfgStarkError::CryptoError`] if proving fails, and errors of
+/// [`prove_statement_with`]
+pub fn prove_statement<P>(prover: &P, trace: P::Trace) -> Result<StarkProof>
+where
+    P: Prover<BaseField = BaseElement>,
+{
+    prove_statement_with(prover, trace, &ProverOptions::default())
+}
+
+/// Prove a statement, dumping and checking the trace first if `options` ask for it
+///
+/// # Errors
+///
+/// [`crate::XfgStarkError::TraceDumpError`] if the dump cannot be written,
+/// [`crate::XfgStarkError::ConstraintViolation`] if the checked trace violates the AIR, or
+/// [`crate::XfgStarkError::CryptoError`] if proving fails
+pub fn prove_statement_with<P>(prover: &P, trace: P::Trace, options: &ProverOptions) -> Result<StarkProof>
+where
+    P: Prover<BaseField = BaseElement>,
+{
+    // Dump before checking so traces that violate the AIR are captured too
+    if let Some(path) = &options.dump_trace {
+        TraceDump::from_trace(&trace).save_to_file(path, TraceCompression::default())?;
+    }
+
+    if options.check_constraints {
+        let air = P::Air::new(trace.get_info(), prover.get_pub_inputs(&trace), prover.options().clone());
+        check_constraints(&air, &trace)?;
+    }
+
+    prover
+        .prove(trace)
+        .map_err(|e| crate::XfgStarkError::CryptoError(format!("Prover error: {e:?}")))
+}
+
+/// Verify a statement proof against its public inputs
+///
+/// Only proofs generated with exactly `options` and [`StatementHasher`] are accepted.
+///
+/// # Errors
+///
+/// [`VerifierError`] if the proof does not verify, or was generated with other options or
+/// another hash function
+pub fn verify_statement<A>(
+    proof: StarkProof,
+    public_inputs: A::PublicInputs,
+    options: &ProofOptions,
+) -> std::result::Result<(), VerifierError>
+where
+    A: Air<BaseField = BaseElement>,
+{
+    verify_statement_with_hash::<A>(proof, public_inputs, options, HashFunction::Blake3)
+}
+
+/// Verify a statement proof committed with `hash`
+///
+/// Only proofs generated with exactly `options` and `hash` are accepted.
+///
+/// # Errors
+///
+/// [`VerifierError`] if the proof does not verify, or was generated with other options or
+/// another hash function
+pub fn verify_statement_with_hash<A>(
+    proof: StarkProof,
+    public_inputs: A::PublicInputs,
+    options: &ProofOptions,
+    hash: HashFunction,
+) -> std::result::Result<(), VerifierError>
+where
+    A: Air<BaseField = BaseElement>,
+{
+    fn verify_with<A, H>(
+        proof: StarkProof,
+        public_inputs: A::PublicInputs,
+        options: &ProofOptions,
+    ) -> std::result::Result<(), VerifierError>
+    where
+        A: Air<BaseField = BaseElement>,
+        H: ElementHasher<BaseField = BaseElement>,
+    {
+        let acceptable_options = AcceptableOptions::OptionSet(vec![options.clone()]);
+        verify::<A, H, DefaultRandomCoin<H>>(proof, public_inputs, &acceptable_options)
+    }
+
+    match hash {
+        HashFunction::Blake3 => verify_with::<A, StatementHasher>(proof, public_inputs, options),
+        HashFunction::Keccak => verify_with::<A, Keccak256Hasher<BaseElement>>(proof, public_inputs, options),
+        HashFunction::Rescue => verify_with::<A, Rp64_256>(proof, public_inputs, options),
+    }
+}
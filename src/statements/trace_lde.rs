@@ -0,0 +1,441 @@
+//! Constant-Column Aware Trace Extension
+//!
+//! Winterfell interpolates every trace column and evaluates it over the LDE domain
+//! before committing to the rows. A constant column's polynomial is the constant
+//! itself, so [`ConstantColumnTraceLde`] stores such columns once and runs the FFTs only
+//! on the columns that vary. Rows are hashed exactly as [`winterfell::DefaultTraceLde`]
+//! hashes them, so commitments, queries and proofs are identical and verifiers need
+//! no changes.
+//!
+//! Under [`LdeStrategy::Chunked`] the varying columns are extended into a temporary file
+//! one coset at a time and committed chunk by chunk instead, so only a few chunks of the
+//! extension are in memory at once; proofs are again identical.
+//!
+//! The trace values can carry secrets, so the copies of the varying columns, their
+//! interpolants and their extension are wiped once they are no longer needed. The
+//! main segment polynomials are returned to Winterfell, which frees them without
+//! clearing them.
+
+use super::chunked_lde::{ChunkedRowTree, SpilledLde};
+use crate::proof::memory::LdeStrategy;
+use crate::trace_compression::CompressedColumn;
+use crate::types::secret::wipe_elements;
+use winter_air::proof::Queries;
+use winterfell::{
+    crypto::{ElementHasher, Hasher, MerkleTree},
+    math::{fft, FieldElement, StarkField},
+    matrix::{ColMatrix, RowMatrix},
+    EvaluationFrame, StarkDomain, TraceInfo, TraceLayout, TraceLde, TracePolyTable,
+};
+
+/// Polynomials evaluated per FFT batch (Winterfell's default segment width)
+const SEGMENT_WIDTH: usize = 8;
+
+/// Where a main trace column's LDE values come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MainColumn<B> {
+    /// Same value over the whole LDE domain
+    Constant(B),
+    /// Column of the extended varying-column matrix
+    Varying(usize),
+}
+
+/// Extended varying columns
+enum VaryingLde<B: StarkField> {
+    /// Every row in memory, row-major
+    InMemory {
+        /// Values of every extended row
+        rows: Vec<B>,
+        /// Varying columns per row
+        width: usize,
+    },
+    /// Spilled to a temporary file, read back a chunk at a time
+    Spilled(SpilledLde<B>),
+}
+
+impl<B: StarkField> VaryingLde<B> {
+    /// Extend `polys` over the LDE domain of `domain`, row-major
+    fn in_memory(polys: &ColMatrix<B>, domain: &StarkDomain<B>) -> Self {
+        let width = polys.num_cols();
+        let mut rows = vec![B::ZERO; domain.lde_domain_size() * width];
+        for (column, poly) in polys.columns().enumerate() {
+            let mut values =
+                fft::evaluate_poly_with_offset(poly, domain.trace_twiddles(), domain.offset(), domain.trace_to_lde_blowup());
+            for (step, value) in values.iter().enumerate() {
+                rows[step * width + column] = *value;
+            }
+            wipe_elements(&mut values);
+        }
+        VaryingLde::InMemory { rows, width }
+    }
+}
+
+impl<B: StarkField> Drop for VaryingLde<B> {
+    fn drop(&mut self) {
+        if let VaryingLde::InMemory { rows, .. } = self {
+            wipe_elements(rows);
+        }
+    }
+}
+
+/// Commitment to the extended main segment rows
+enum MainCommitment<H: Hasher> {
+    /// Tree over every row hash
+    Full(MerkleTree<H>),
+    /// Tree over chunk roots; chunks are rehashed to open queries
+    Chunked(ChunkedRowTree<H>),
+}
+
+/// Trace LDE that extends only the non-constant main trace columns
+pub struct ConstantColumnTraceLde<E: FieldElement, H: ElementHasher<BaseField = E::BaseField>> {
+    /// Source of each main trace column
+    main_columns: Vec<MainColumn<E::BaseField>>,
+    /// LDE of the varying main columns, `None` if every column is constant
+    varying_lde: Option<VaryingLde<E::BaseField>>,
+    /// Commitment to the main segment rows
+    main_segment_tree: MainCommitment<H>,
+    /// LDEs of the auxiliary segments
+    aux_segment_ldes: Vec<RowMatrix<E>>,
+    /// Commitments to the auxiliary segments
+    aux_segment_trees: Vec<MerkleTree<H>>,
+    /// Rows in the LDE domain
+    lde_len: usize,
+    blowup: usize,
+    trace_info: TraceInfo,
+}
+
+impl<E, H> ConstantColumnTraceLde<E, H>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    /// Extend and commit to the main trace segment
+    ///
+    /// Returns the LDE together with the main segment polynomials, as
+    /// [`winterfell::DefaultTraceLde::new`] does.
+    pub fn new(
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<E::BaseField>,
+        domain: &StarkDomain<E::BaseField>,
+    ) -> (Self, TracePolyTable<E>) {
+        Self::with_strategy(trace_info, main_trace, domain, LdeStrategy::InMemory)
+    }
+
+    /// Extend and commit to the main trace segment as `strategy` directs
+    ///
+    /// [`LdeStrategy::Chunked`] keeps about `chunk_rows` extended rows in memory; domains
+    /// with fewer than two chunks are extended in memory.
+    ///
+    /// # Panics
+    /// If a chunked extension cannot be written to or read back from the temporary
+    /// directory.
+    pub fn with_strategy(
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<E::BaseField>,
+        domain: &StarkDomain<E::BaseField>,
+        strategy: LdeStrategy,
+    ) -> (Self, TracePolyTable<E>) {
+        let inv_twiddles = fft::get_inv_twiddles(main_trace.num_rows());
+        Self::with_inv_twiddles(trace_info, main_trace, domain, strategy, &inv_twiddles)
+    }
+
+    /// Extend and commit to the main trace segment, interpolating with precomputed
+    /// inverse twiddles
+    ///
+    /// `inv_twiddles` must be `fft::get_inv_twiddles` of the trace length; provers that
+    /// prove many traces of one length compute them once.
+    ///
+    /// # Panics
+    /// If `inv_twiddles` does not hold half as many elements as the trace has rows, or
+    /// as [`Self::with_strategy`].
+    pub fn with_inv_twiddles(
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<E::BaseField>,
+        domain: &StarkDomain<E::BaseField>,
+        strategy: LdeStrategy,
+        inv_twiddles: &[E::BaseField],
+    ) -> (Self, TracePolyTable<E>) {
+        let trace_len = main_trace.num_rows();
+        assert_eq!(inv_twiddles.len(), trace_len / 2, "inverse twiddles do not match the trace length");
+        let mut main_columns = Vec::with_capacity(main_trace.num_cols());
+        let mut varying = Vec::new();
+        for column in main_trace.columns() {
+            if let Some(value) = CompressedColumn::constant_of(column) {
+                main_columns.push(MainColumn::Constant(value));
+            } else {
+                main_columns.push(MainColumn::Varying(varying.len()));
+                varying.push(column.to_vec());
+            }
+        }
+
+        // The interpolant of a constant column is the constant polynomial
+        let lde_len = domain.lde_domain_size();
+        let chunk_rows = match strategy {
+            LdeStrategy::Chunked { chunk_rows } if chunk_rows.is_power_of_two() && (2..=lde_len / 2).contains(&chunk_rows) => {
+                Some(chunk_rows)
+            }
+            _ => None,
+        };
+        let mut varying_polys = (!varying.is_empty()).then(|| {
+            for column in &mut varying {
+                fft::interpolate_poly(column, inv_twiddles);
+            }
+            ColMatrix::new(varying)
+        });
+        let varying_lde = varying_polys.as_ref().map(|polys| match chunk_rows {
+            Some(chunk_rows) => VaryingLde::Spilled(
+                SpilledLde::new(polys, domain, chunk_rows).expect("failed to spill the trace LDE"),
+            ),
+            None => VaryingLde::in_memory(polys, domain),
+        });
+        let polys = main_columns
+            .iter()
+            .map(|column| match (column, &varying_polys) {
+                (MainColumn::Constant(value), _) => {
+                    let mut poly = vec![E::BaseField::ZERO; trace_len];
+                    poly[0] = *value;
+                    poly
+                }
+                (MainColumn::Varying(index), Some(polys)) => polys.get_column(*index).to_vec(),
+                (MainColumn::Varying(_), None) => unreachable!("varying columns have polynomials"),
+            })
+            .collect();
+
+        // Hash rows exactly as Winterfell does so the commitment is unchanged
+        let read_row = |step: usize, row: &mut [E::BaseField]| read_main_row(&main_columns, varying_lde.as_ref(), step, row);
+        let main_segment_tree = if let Some(chunk_rows) = chunk_rows {
+            MainCommitment::Chunked(ChunkedRowTree::new(lde_len, main_columns.len(), chunk_rows, read_row))
+        } else {
+            let mut row = vec![E::BaseField::ZERO; main_columns.len()];
+            let row_hashes = (0..lde_len)
+                .map(|step| {
+                    read_row(step, &mut row);
+                    H::hash_elements(&row)
+                })
+                .collect();
+            wipe_elements(&mut row);
+            MainCommitment::Full(MerkleTree::new(row_hashes).expect("failed to construct trace Merkle tree"))
+        };
+        if let Some(varying_polys) = &mut varying_polys {
+            for poly in varying_polys.columns_mut() {
+                wipe_elements(poly);
+            }
+        }
+
+        let lde = Self {
+            main_columns,
+            varying_lde,
+            main_segment_tree,
+            aux_segment_ldes: Vec::new(),
+            aux_segment_trees: Vec::new(),
+            lde_len,
+            blowup: domain.trace_to_lde_blowup(),
+            trace_info: trace_info.clone(),
+        };
+        (lde, TracePolyTable::new(ColMatrix::new(polys)))
+    }
+
+    /// Number of main trace columns stored as a single value
+    pub fn num_constant_columns(&self) -> usize {
+        self.main_columns
+            .iter()
+            .filter(|column| matches!(column, MainColumn::Constant(_)))
+            .count()
+    }
+
+    /// Read row `step` of the extended main segment
+    fn read_main_row(&self, step: usize, row: &mut [E::BaseField]) {
+        read_main_row(&self.main_columns, self.varying_lde.as_ref(), step, row);
+    }
+}
+
+/// Assemble an extended main segment row from constants and the varying-column LDE
+fn read_main_row<B: StarkField>(
+    main_columns: &[MainColumn<B>],
+    varying_lde: Option<&VaryingLde<B>>,
+    step: usize,
+    row: &mut [B],
+) {
+    match varying_lde {
+        Some(VaryingLde::InMemory { rows, width }) => {
+            fill_main_row(main_columns, Some(&rows[step * width..(step + 1) * width]), row);
+        }
+        Some(VaryingLde::Spilled(lde)) => lde.with_row(step, |varying_row| fill_main_row(main_columns, Some(varying_row), row)),
+        None => fill_main_row(main_columns, None, row),
+    }
+}
+
+/// Fill a main segment row from constants and a row of the varying-column LDE
+fn fill_main_row<B: StarkField>(main_columns: &[MainColumn<B>], varying_row: Option<&[B]>, row: &mut [B]) {
+    for (value, column) in row.iter_mut().zip(main_columns) {
+        *value = match (column, varying_row) {
+            (MainColumn::Constant(constant), _) => *constant,
+            (MainColumn::Varying(index), Some(varying_row)) => varying_row[*index],
+            (MainColumn::Varying(_), None) => unreachable!("varying columns have an LDE"),
+        };
+    }
+}
+
+impl<E, H> TraceLde<E> for ConstantColumnTraceLde<E, H>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    type HashFn = H;
+
+    fn get_main_trace_commitment(&self) -> <Self::HashFn as Hasher>::Digest {
+        match &self.main_segment_tree {
+            MainCommitment::Full(tree) => *tree.root(),
+            MainCommitment::Chunked(tree) => *tree.root(),
+        }
+    }
+
+    fn add_aux_segment(
+        &mut self,
+        aux_trace: &ColMatrix<E>,
+        domain: &StarkDomain<E::BaseField>,
+    ) -> (ColMatrix<E>, <Self::HashFn as Hasher>::Digest) {
+        assert!(
+            self.aux_segment_ldes.len() < self.trace_info.layout().num_aux_segments(),
+            "the specified number of auxiliary segments has already been added"
+        );
+
+        let aux_polys = aux_trace.interpolate_columns();
+        let aux_lde = RowMatrix::evaluate_polys_over::<SEGMENT_WIDTH>(&aux_polys, domain);
+        assert_eq!(
+            aux_lde.num_rows(),
+            self.lde_len,
+            "the number of rows in the auxiliary segment must be the same as in the main segment"
+        );
+        let aux_tree = aux_lde.commit_to_rows::<H>();
+        let root = *aux_tree.root();
+
+        self.aux_segment_ldes.push(aux_lde);
+        self.aux_segment_trees.push(aux_tree);
+        (aux_polys, root)
+    }
+
+    fn read_main_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E::BaseField>) {
+        // At the end of the trace the next row wraps around to the first
+        let next_lde_step = (lde_step + self.blowup) % self.lde_len;
+        self.read_main_row(lde_step, frame.current_mut());
+        self.read_main_row(next_lde_step, frame.next_mut());
+    }
+
+    fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) {
+        let next_lde_step = (lde_step + self.blowup) % self.lde_len;
+        let segment = &self.aux_segment_ldes[0];
+        frame.current_mut().copy_from_slice(segment.row(lde_step));
+        frame.next_mut().copy_from_slice(segment.row(next_lde_step));
+    }
+
+    fn query(&self, positions: &[usize]) -> Vec<Queries> {
+        let mut row = vec![E::BaseField::ZERO; self.main_columns.len()];
+        let main_states = positions
+            .iter()
+            .map(|&position| {
+                self.read_main_row(position, &mut row);
+                row.clone()
+            })
+            .collect::<Vec<_>>();
+        let main_proof = match &self.main_segment_tree {
+            MainCommitment::Full(tree) => tree
+                .prove_batch(positions)
+                .expect("failed to generate a Merkle proof for trace queries"),
+            MainCommitment::Chunked(tree) => {
+                tree.prove_batch(positions, self.main_columns.len(), |step, row| self.read_main_row(step, row))
+            }
+        };
+        let mut queries = vec![Queries::new(main_proof, main_states)];
+
+        for (segment_lde, segment_tree) in self.aux_segment_ldes.iter().zip(&self.aux_segment_trees) {
+            let states = positions
+                .iter()
+                .map(|&position| segment_lde.row(position).to_vec())
+                .collect::<Vec<_>>();
+            let proof = segment_tree
+                .prove_batch(positions)
+                .expect("failed to generate a Merkle proof for trace queries");
+            queries.push(Queries::new(proof, states));
+        }
+
+        queries
+    }
+
+    /// Rows in the LDE domain, as Winterfell's own trace LDE reports
+    fn trace_len(&self) -> usize {
+        self.lde_len
+    }
+
+    fn blowup(&self) -> usize {
+        self.blowup
+    }
+
+    fn trace_layout(&self) -> &TraceLayout {
+        self.trace_info.layout()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_air::XfgBurnMintAir;
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+    use crate::statements::StatementHasher;
+    use winterfell::{math::fields::f64::BaseElement, Air, DefaultTraceLde, Trace};
+
+    #[test]
+    fn test_matches_default_trace_lde() {
+        let prover = XfgBurnMintProver::default();
+        let (_, public_inputs) = prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let air = XfgBurnMintAir::new(
+            prover.domain().trace_info.clone(),
+            public_inputs,
+            crate::burn_mint_prover::secret_to_field_elements(&[9u8; 32]).unwrap(),
+            prover.proof_options().clone(),
+        );
+        let trace = air.build_trace();
+        let domain = StarkDomain::new(&air);
+
+        let (expected, expected_polys) =
+            DefaultTraceLde::<BaseElement, StatementHasher>::new(&trace.get_info(), trace.main_segment(), &domain);
+        let (lde, polys) =
+            ConstantColumnTraceLde::<BaseElement, StatementHasher>::new(&trace.get_info(), trace.main_segment(), &domain);
+
+        // Only the amounts and transaction hash columns are constant
+        assert_eq!(lde.num_constant_columns(), 3);
+        assert_eq!(lde.get_main_trace_commitment(), expected.get_main_trace_commitment());
+        assert_eq!(lde.trace_len(), expected.trace_len());
+        for column in 0..trace.main_trace_width() {
+            assert_eq!(
+                polys.main_trace_polys().nth(column),
+                expected_polys.main_trace_polys().nth(column)
+            );
+        }
+
+        let positions = [0, 5, lde.trace_len() - 1];
+        assert_eq!(lde.query(&positions), expected.query(&positions));
+
+        let mut frame = EvaluationFrame::new(trace.main_trace_width());
+        let mut expected_frame = EvaluationFrame::new(trace.main_trace_width());
+        for step in [0, 17, lde.trace_len() - 1] {
+            lde.read_main_trace_frame_into(step, &mut frame);
+            expected.read_main_trace_frame_into(step, &mut expected_frame);
+            assert_eq!(frame.current(), expected_frame.current());
+            assert_eq!(frame.next(), expected_frame.next());
+        }
+    }
+}
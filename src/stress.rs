@@ -0,0 +1,248 @@
+//! Stress Testing for Capacity Planning
+//!
+//! [`run_stress`] generates synthetic burn & mint proofs across every burn tier through
+//! the real prover and verifier, with a bounded number of worker threads, and summarizes
+//! throughput, latency percentiles, peak memory and failures. Operators size gateway
+//! hardware from the summary of a run on the target machine.
+
+use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+use crate::burn_mint_verifier::XfgBurnMintVerifier;
+use crate::constants::BURN_TIERS_ATOMIC;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Stress run parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StressConfig {
+    /// Proofs to generate and verify
+    pub proofs: usize,
+    /// Worker threads proving concurrently
+    pub parallelism: usize,
+    /// Seed for the synthetic burn transactions
+    pub seed: u64,
+}
+
+impl Default for StressConfig {
+    fn default() -> Self {
+        Self {
+            proofs: 1_000,
+            parallelism: std::thread::available_parallelism().map_or(4, std::num::NonZero::get),
+            seed: 0,
+        }
+    }
+}
+
+/// Timing of one synthetic proof
+#[derive(Debug, Clone, Copy)]
+struct StressSample {
+    prove: Duration,
+    verify: Duration,
+    valid: bool,
+}
+
+/// Summary of a stress run
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StressSummary {
+    /// Proofs attempted
+    pub proofs: usize,
+    /// Worker threads used
+    pub parallelism: usize,
+    /// Proofs that failed to generate or verify
+    pub failures: usize,
+    /// Wall-clock time of the run (milliseconds)
+    pub elapsed_ms: f64,
+    /// Proofs generated and verified per second
+    pub throughput_per_sec: f64,
+    /// Median prove + verify latency (milliseconds)
+    pub p50_latency_ms: f64,
+    /// 95th percentile prove + verify latency (milliseconds)
+    pub p95_latency_ms: f64,
+    /// 95th percentile prove latency (milliseconds)
+    pub p95_prove_ms: f64,
+    /// 95th percentile verify latency (milliseconds)
+    pub p95_verify_ms: f64,
+    /// Peak resident memory of the process (bytes), where the platform reports it
+    pub peak_memory_bytes: Option<u64>,
+}
+
+impl StressSummary {
+    fn from_samples(config: &StressConfig, samples: &[StressSample], elapsed: Duration) -> Self {
+        let failures = samples.iter().filter(|sample| !sample.valid).count() + config.proofs - samples.len();
+        let latencies = |f: fn(&StressSample) -> Duration| {
+            let mut values: Vec<Duration> = samples.iter().map(f).collect();
+            values.sort_unstable();
+            values
+        };
+        let total = latencies(|sample| sample.prove + sample.verify);
+        let prove = latencies(|sample| sample.prove);
+        let verify = latencies(|sample| sample.verify);
+
+        Self {
+            proofs: config.proofs,
+            parallelism: config.parallelism,
+            failures,
+            elapsed_ms: millis(elapsed),
+            throughput_per_sec: if elapsed.is_zero() {
+                0.0
+            } else {
+                f64::from(u32::try_from(samples.len()).unwrap_or(u32::MAX)) / elapsed.as_secs_f64()
+            },
+            p50_latency_ms: millis(percentile(&total, 50)),
+            p95_latency_ms: millis(percentile(&total, 95)),
+            p95_prove_ms: millis(percentile(&prove, 95)),
+            p95_verify_ms: millis(percentile(&verify, 95)),
+            peak_memory_bytes: peak_memory_bytes(),
+        }
+    }
+}
+
+impl Display for StressSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Proofs:        {} ({} failed)", self.proofs, self.failures)?;
+        writeln!(f, "Workers:       {}", self.parallelism)?;
+        writeln!(f, "Elapsed:       {:.1} ms", self.elapsed_ms)?;
+        writeln!(f, "Throughput:    {:.2} proofs/s", self.throughput_per_sec)?;
+        writeln!(f, "Latency p50:   {:.1} ms", self.p50_latency_ms)?;
+        writeln!(
+            f,
+            "Latency p95:   {:.1} ms (prove {:.1} ms, verify {:.1} ms)",
+            self.p95_latency_ms, self.p95_prove_ms, self.p95_verify_ms
+        )?;
+        match self.peak_memory_bytes {
+            Some(bytes) => write!(f, "Peak memory:   {:.1} MB", f64::from(u32::try_from(bytes >> 10).unwrap_or(u32::MAX)) / 1024.0),
+            None => write!(f, "Peak memory:   unavailable"),
+        }
+    }
+}
+
+/// Generate and verify synthetic proofs, cycling through the burn tiers
+#[must_use]
+pub fn run_stress(config: &StressConfig) -> StressSummary {
+    let config = StressConfig {
+        parallelism: config.parallelism.clamp(1, config.proofs.max(1)),
+        ..*config
+    };
+    let prover = XfgBurnMintProver::default();
+    let verifier = XfgBurnMintVerifier::default();
+    let next = AtomicUsize::new(0);
+
+    let start = Instant::now();
+    let samples: Vec<StressSample> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..config.parallelism)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut samples = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        if index >= config.proofs {
+                            return samples;
+                        }
+                        samples.push(stress_one(&prover, &verifier, config.seed, index));
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap_or_default())
+            .collect()
+    });
+
+    StressSummary::from_samples(&config, &samples, start.elapsed())
+}
+
+/// Prove and verify the synthetic burn at `index`
+fn stress_one(prover: &XfgBurnMintProver, verifier: &XfgBurnMintVerifier, seed: u64, index: usize) -> StressSample {
+    let burn_amount = BURN_TIERS_ATOMIC[index % BURN_TIERS_ATOMIC.len()];
+    let entropy: [u8; 32] = Sha256::new()
+        .chain_update(b"xfg-stress")
+        .chain_update(seed.to_le_bytes())
+        .chain_update((index as u64).to_le_bytes())
+        .finalize()
+        .into();
+
+    let started = Instant::now();
+    let proof = prover.prove_burn_mint_with_public_inputs(
+        &BurnMintRequest {
+            burn_amount,
+            mint_amount: burn_amount,
+            tx_prefix_hash: entropy,
+            recipient_address: entropy.first_chunk().expect("a 32-byte hash holds an address"),
+            network_id: 1,
+            target_chain_id: 42161,
+            commitment_version: 1,
+        },
+        &entropy,
+    );
+    let prove = started.elapsed();
+
+    let Ok((proof, public_inputs)) = proof else {
+        return StressSample {
+            prove,
+            verify: Duration::ZERO,
+            valid: false,
+        };
+    };
+
+    let started = Instant::now();
+    let valid = matches!(verifier.verify_with_public_inputs(&proof, &public_inputs), Ok(true));
+    StressSample {
+        prove,
+        verify: started.elapsed(),
+        valid,
+    }
+}
+
+/// Nearest-rank percentile of sorted values
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Peak resident set size of the process (Linux `VmHWM`)
+fn peak_memory_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kilobytes: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kilobytes * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let values: Vec<Duration> = (1..=20).map(Duration::from_millis).collect();
+        assert_eq!(percentile(&values, 50), Duration::from_millis(10));
+        assert_eq!(percentile(&values, 95), Duration::from_millis(19));
+        assert_eq!(percentile(&values, 100), Duration::from_millis(20));
+        assert_eq!(percentile(&[], 95), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_stress_run_covers_every_proof() {
+        let summary = run_stress(&StressConfig {
+            proofs: 4,
+            parallelism: 8,
+            seed: 7,
+        });
+
+        assert_eq!(summary.proofs, 4);
+        assert_eq!(summary.parallelism, 4);
+        assert_eq!(summary.failures, 0);
+        assert!(summary.throughput_per_sec > 0.0);
+        assert!(summary.p95_latency_ms >= summary.p50_latency_ms);
+        assert!(summary.p95_latency_ms > 0.0);
+    }
+}
@@ -0,0 +1,457 @@
+//! Verification Server Tenants
+//!
+//! A shared verification server can be exposed to several wallet vendors. Each tenant
+//! authenticates its connection with an API key and is held to its own limits, so one
+//! vendor's burst cannot take the whole queue:
+//!
+//! - `max_pending`: proofs the tenant may have admitted (queued or verifying) at once
+//! - `rate_limit`: token bucket on submissions (`per_second`, up to `burst`)
+//! - `proof_quota`: total proofs the tenant may submit over the server's lifetime
+//!
+//! The limits are enforced where submissions are admitted, by the
+//! [`WorkerPool`](crate::admission::WorkerPool) of either server.
+//!
+//! Tenants are loaded from a JSON list of [`TenantConfig`]. API keys are kept only as
+//! SHA-256 digests. A registry with no tenants leaves the server open, as before.
+
+use crate::types::secret::Redacted;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use thiserror::Error;
+
+/// Token bucket limit on a tenant's submissions
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// Sustained submissions per second
+    pub per_second: f64,
+    /// Submissions allowed back to back after an idle period
+    pub burst: u32,
+}
+
+/// A tenant as configured by the operator
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct TenantConfig {
+    /// Label reported in metrics
+    pub name: String,
+    /// Key the tenant authenticates with
+    pub api_key: String,
+    /// Proofs the tenant may have admitted at once (unlimited when absent)
+    #[serde(default)]
+    pub max_pending: Option<usize>,
+    /// Submission rate limit (unlimited when absent)
+    #[serde(default)]
+    pub rate_limit: Option<RateLimit>,
+    /// Total proofs the tenant may submit (unlimited when absent)
+    #[serde(default)]
+    pub proof_quota: Option<u64>,
+}
+
+impl std::fmt::Debug for TenantConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TenantConfig")
+            .field("name", &self.name)
+            .field("api_key", &Redacted::opaque())
+            .field("max_pending", &self.max_pending)
+            .field("rate_limit", &self.rate_limit)
+            .field("proof_quota", &self.proof_quota)
+            .finish()
+    }
+}
+
+/// Tenant configuration errors
+#[derive(Debug, Error)]
+pub enum TenantConfigError {
+    /// Tenant file could not be read
+    #[error("Failed to read tenant file: {0}")]
+    Io(#[from] std::io::Error),
+    /// Tenant file is not a JSON list of tenants
+    #[error("Invalid tenant file: {0}")]
+    Json(#[from] serde_json::Error),
+    /// Two tenants share a name or API key
+    #[error("Duplicate tenant {0}")]
+    Duplicate(String),
+}
+
+/// Reason a tenant's submission was turned away
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TenantRejection {
+    /// The tenant already has `max_pending` proofs admitted
+    #[error("Tenant {tenant} has {limit} proofs pending, retry later")]
+    TooManyPending {
+        /// Tenant label
+        tenant: String,
+        /// Configured limit
+        limit: usize,
+    },
+    /// The tenant's token bucket is empty
+    #[error("Tenant {tenant} exceeded its rate limit, retry later")]
+    RateLimited {
+        /// Tenant label
+        tenant: String,
+    },
+    /// The tenant has used its whole proof quota
+    #[error("Tenant {tenant} has used its quota of {quota} proofs")]
+    QuotaExhausted {
+        /// Tenant label
+        tenant: String,
+        /// Configured quota
+        quota: u64,
+    },
+}
+
+/// Per-tenant counters
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TenantMetrics {
+    /// Tenant label
+    pub tenant: String,
+    /// Admitted proofs queued or verifying
+    pub pending: usize,
+    /// Proofs admitted since start
+    pub accepted: u64,
+    /// Submissions turned away by the tenant's pending or rate limit
+    pub rate_limited: u64,
+    /// Submissions turned away after the quota was used
+    pub quota_exhausted: u64,
+    /// Proofs that verified
+    pub verified: u64,
+    /// Proofs that failed verification
+    pub failed: u64,
+    /// Proofs left in the quota (absent when unlimited)
+    pub quota_remaining: Option<u64>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// An authenticated tenant and its live counters
+///
+/// The API key is not kept: the registry holds its digest.
+#[derive(Debug)]
+pub struct Tenant {
+    name: String,
+    max_pending: Option<usize>,
+    rate_limit: Option<RateLimit>,
+    proof_quota: Option<u64>,
+    bucket: Mutex<TokenBucket>,
+    pending: AtomicUsize,
+    accepted: AtomicU64,
+    rate_limited: AtomicU64,
+    quota_exhausted: AtomicU64,
+    verified: AtomicU64,
+    failed: AtomicU64,
+}
+
+impl Tenant {
+    fn new(config: TenantConfig) -> Self {
+        let TenantConfig { name, api_key: _, max_pending, rate_limit, proof_quota } = config;
+        let burst = rate_limit.map_or(0, |limit| limit.burst);
+        Self {
+            name,
+            max_pending,
+            rate_limit,
+            proof_quota,
+            bucket: Mutex::new(TokenBucket {
+                tokens: f64::from(burst),
+                refilled_at: Instant::now(),
+            }),
+            pending: AtomicUsize::new(0),
+            accepted: AtomicU64::new(0),
+            rate_limited: AtomicU64::new(0),
+            quota_exhausted: AtomicU64::new(0),
+            verified: AtomicU64::new(0),
+            failed: AtomicU64::new(0),
+        }
+    }
+
+    /// Tenant label
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Admit one submission against the tenant's limits
+    ///
+    /// # Errors
+    ///
+    /// [`TenantRejection`] if the tenant has used its quota, has too many proofs pending, or
+    /// is over its rate limit
+    pub fn admit(self: &Arc<Self>) -> Result<TenantPermit, TenantRejection> {
+        self.admit_at(Instant::now())
+    }
+
+    /// Admit one submission as of `now`
+    ///
+    /// The quota slot is reserved before any other check, so concurrent submissions
+    /// never admit more than the quota; a rejected submission releases it.
+    fn admit_at(self: &Arc<Self>, now: Instant) -> Result<TenantPermit, TenantRejection> {
+        if let Some(quota) = self.proof_quota {
+            let reserved = self
+                .accepted
+                .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| (n < quota).then_some(n + 1));
+            if reserved.is_err() {
+                self.quota_exhausted.fetch_add(1, Ordering::Relaxed);
+                return Err(TenantRejection::QuotaExhausted {
+                    tenant: self.name.clone(),
+                    quota,
+                });
+            }
+        } else {
+            self.accepted.fetch_add(1, Ordering::AcqRel);
+        }
+
+        if let Some(limit) = self.max_pending {
+            let admitted = self
+                .pending
+                .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| (n < limit).then_some(n + 1));
+            if admitted.is_err() {
+                self.accepted.fetch_sub(1, Ordering::AcqRel);
+                self.rate_limited.fetch_add(1, Ordering::Relaxed);
+                return Err(TenantRejection::TooManyPending {
+                    tenant: self.name.clone(),
+                    limit,
+                });
+            }
+        } else {
+            self.pending.fetch_add(1, Ordering::AcqRel);
+        }
+        // From here on, dropping the permit releases the pending slot and cancelling it
+        // the quota slot
+        let permit = TenantPermit {
+            tenant: self.clone(),
+            counted: true,
+        };
+
+        if let Some(limit) = self.rate_limit {
+            let mut bucket = self.bucket.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+            let elapsed = now.saturating_duration_since(bucket.refilled_at).as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed * limit.per_second).min(f64::from(limit.burst));
+            bucket.refilled_at = now;
+            if bucket.tokens < 1.0 {
+                drop(bucket);
+                permit.cancel();
+                self.rate_limited.fetch_add(1, Ordering::Relaxed);
+                return Err(TenantRejection::RateLimited {
+                    tenant: self.name.clone(),
+                });
+            }
+            bucket.tokens -= 1.0;
+        }
+
+        Ok(permit)
+    }
+
+    /// Snapshot of the tenant's counters
+    pub fn metrics(&self) -> TenantMetrics {
+        let accepted = self.accepted.load(Ordering::Relaxed);
+        TenantMetrics {
+            tenant: self.name.clone(),
+            pending: self.pending.load(Ordering::Relaxed),
+            accepted,
+            rate_limited: self.rate_limited.load(Ordering::Relaxed),
+            quota_exhausted: self.quota_exhausted.load(Ordering::Relaxed),
+            verified: self.verified.load(Ordering::Relaxed),
+            failed: self.failed.load(Ordering::Relaxed),
+            quota_remaining: self.proof_quota.map(|quota| quota.saturating_sub(accepted)),
+        }
+    }
+}
+
+/// A tenant's admitted submission; dropping it frees the tenant's pending slot
+#[derive(Debug)]
+pub struct TenantPermit {
+    tenant: Arc<Tenant>,
+    counted: bool,
+}
+
+impl TenantPermit {
+    /// Tenant the submission belongs to
+    #[must_use]
+    pub fn tenant(&self) -> &Tenant {
+        &self.tenant
+    }
+
+    /// Return the submission to the tenant's quota (the server could not take it)
+    pub fn cancel(mut self) {
+        if std::mem::take(&mut self.counted) {
+            self.tenant.accepted.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Record the verification outcome
+    pub fn finish(self, verified: bool) {
+        let counter = if verified { &self.tenant.verified } else { &self.tenant.failed };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl Drop for TenantPermit {
+    fn drop(&mut self) {
+        self.tenant.pending.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Tenants known to a server, looked up by API key
+#[derive(Debug, Default)]
+pub struct TenantRegistry {
+    by_key: HashMap<[u8; 32], Arc<Tenant>>,
+}
+
+impl TenantRegistry {
+    /// Build a registry, rejecting duplicate names or keys
+    ///
+    /// # Errors
+    ///
+    /// [`TenantConfigError::Duplicate`] if two tenants share a name or API key
+    pub fn new(tenants: Vec<TenantConfig>) -> Result<Self, TenantConfigError> {
+        let mut registry = Self::default();
+        for config in tenants {
+            let name_taken = registry.by_key.values().any(|t| t.name == config.name);
+            let digest = key_digest(&config.api_key);
+            if name_taken || registry.by_key.contains_key(&digest) {
+                return Err(TenantConfigError::Duplicate(config.name));
+            }
+            registry.by_key.insert(digest, Arc::new(Tenant::new(config)));
+        }
+        Ok(registry)
+    }
+
+    /// Load tenants from a JSON list of [`TenantConfig`]
+    ///
+    /// # Errors
+    ///
+    /// [`TenantConfigError::Io`] or [`TenantConfigError::Json`] if the file cannot be read or
+    /// parsed, otherwise as [`Self::new`]
+    pub fn load(path: &Path) -> Result<Self, TenantConfigError> {
+        let tenants: Vec<TenantConfig> = serde_json::from_slice(&std::fs::read(path)?)?;
+        Self::new(tenants)
+    }
+
+    /// Whether connections must authenticate (at least one tenant is configured)
+    #[must_use]
+    pub fn requires_auth(&self) -> bool {
+        !self.by_key.is_empty()
+    }
+
+    /// Tenant holding `api_key`
+    #[must_use]
+    pub fn authenticate(&self, api_key: &str) -> Option<Arc<Tenant>> {
+        self.by_key.get(&key_digest(api_key)).cloned()
+    }
+
+    /// Counters of every tenant, ordered by name
+    #[must_use]
+    pub fn metrics(&self) -> Vec<TenantMetrics> {
+        let mut metrics: Vec<_> = self.by_key.values().map(|tenant| tenant.metrics()).collect();
+        metrics.sort_by(|a, b| a.tenant.cmp(&b.tenant));
+        metrics
+    }
+}
+
+/// Digest an API key is stored and looked up under
+fn key_digest(api_key: &str) -> [u8; 32] {
+    Sha256::digest(api_key.as_bytes()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn tenant(name: &str, key: &str) -> TenantConfig {
+        TenantConfig {
+            name: name.to_string(),
+            api_key: key.to_string(),
+            max_pending: None,
+            rate_limit: None,
+            proof_quota: None,
+        }
+    }
+
+    #[test]
+    fn test_registry_authentication() {
+        let registry = TenantRegistry::new(vec![tenant("alpha", "key-a"), tenant("beta", "key-b")]).unwrap();
+        assert!(registry.requires_auth());
+        assert_eq!(registry.authenticate("key-b").unwrap().name(), "beta");
+        assert!(registry.authenticate("key-c").is_none());
+        assert!(!TenantRegistry::default().requires_auth());
+
+        assert!(matches!(
+            TenantRegistry::new(vec![tenant("alpha", "key-a"), tenant("gamma", "key-a")]),
+            Err(TenantConfigError::Duplicate(name)) if name == "gamma"
+        ));
+        let json = r#"[{"name":"alpha","api_key":"key-a","proof_quota":10}]"#;
+        let configs: Vec<TenantConfig> = serde_json::from_str(json).unwrap();
+        assert_eq!(configs[0].proof_quota, Some(10));
+        assert_eq!(configs[0].rate_limit, None);
+    }
+
+    #[test]
+    fn test_limits() {
+        let config = TenantConfig {
+            max_pending: Some(2),
+            rate_limit: Some(RateLimit { per_second: 1.0, burst: 3 }),
+            proof_quota: Some(4),
+            ..tenant("alpha", "key-a")
+        };
+        let tenant = Arc::new(Tenant::new(config));
+        let start = Instant::now();
+
+        // Pending limit, released when a permit drops
+        let first = tenant.admit_at(start).unwrap();
+        let second = tenant.admit_at(start).unwrap();
+        assert!(matches!(tenant.admit_at(start), Err(TenantRejection::TooManyPending { limit: 2, .. })));
+        first.finish(true);
+        second.finish(false);
+
+        // The burst is spent; the bucket refills at one token per second
+        tenant.admit_at(start).unwrap().cancel();
+        assert!(matches!(tenant.admit_at(start), Err(TenantRejection::RateLimited { .. })));
+        tenant.admit_at(start + Duration::from_secs(1)).unwrap();
+
+        // Cancelled submissions do not count; the quota is then used up
+        tenant.admit_at(start + Duration::from_secs(2)).unwrap();
+        assert!(matches!(
+            tenant.admit_at(start + Duration::from_secs(10)),
+            Err(TenantRejection::QuotaExhausted { quota: 4, .. })
+        ));
+
+        let metrics = tenant.metrics();
+        assert_eq!(metrics.accepted, 4);
+        assert_eq!(metrics.pending, 0);
+        assert_eq!((metrics.verified, metrics.failed), (1, 1));
+        assert_eq!((metrics.rate_limited, metrics.quota_exhausted), (2, 1));
+        assert_eq!(metrics.quota_remaining, Some(0));
+    }
+
+    #[test]
+    fn test_quota_holds_under_concurrency() {
+        let config = TenantConfig {
+            proof_quota: Some(50),
+            ..tenant("alpha", "key-a")
+        };
+        let tenant = Arc::new(Tenant::new(config));
+        let admitted: usize = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..8)
+                .map(|_| scope.spawn(|| (0..20).filter_map(|_| tenant.admit().ok()).count()))
+                .collect();
+            workers.into_iter().map(|worker| worker.join().unwrap()).sum()
+        });
+        assert_eq!(admitted, 50);
+        assert_eq!(tenant.metrics().quota_exhausted, 110);
+    }
+
+    #[test]
+    fn test_api_key_never_kept_or_formatted() {
+        let config = tenant("alpha", "key-secret");
+        assert!(!format!("{config:?}").contains("key-secret"));
+        let registry = TenantRegistry::new(vec![config]).unwrap();
+        assert!(!format!("{registry:?}").contains("key-secret"));
+    }
+}
@@ -2,11 +2,172 @@
 //!
 //! This module provides realistic test data for development and testing,
 //! with real blockchain data integration and cryptographic randomness.
+//!
+//! ## Known-Answer Test Vectors
+//! [`TestDataGenerator::generate_kat_file`] derives burns from fixed seeds and records
+//! their commitments, nullifiers, recipient hashes, public inputs, trace rows and
+//! proofs as JSON. The Solidity verifier and other implementations check their own
+//! outputs against the file; [`verify_kat`] checks that this crate still produces it.
+//! The vectors for [`KAT_SEEDS`] are kept in `tests/fixtures/kat/burn_mint.json` and
+//! rewritten by `crate::fixtures::regenerate`.
 
 use winter_math::fields::f64::BaseElement;
+use winter_math::ToElements;
+use winterfell::{StarkProof, Trace};
 use std::collections::HashMap;
+use std::path::Path;
 use sha3::{Digest, Keccak256};
 use rand::Rng;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use crate::burn_mint_air::XfgBurnMintAir;
+use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+use crate::burn_mint_verifier::XfgBurnMintVerifier;
+use crate::commitment::commitment_to_bytes;
+use crate::constants::{
+    atomic_units_to_xfg, BURN_TIERS_ATOMIC, COMMITMENT_VERSION, DEFAULT_TARGET_CHAIN_ID, LARGE_BURN_ATOMIC,
+    STANDARD_BURN_ATOMIC,
+};
+
+/// Format version of known-answer test files
+pub const KAT_VERSION: u32 = 2;
+
+/// Seeds of the published known-answer vectors, one per burn tier
+pub const KAT_SEEDS: [u64; 2] = [1, 2];
+
+/// Fuego network the known-answer burns are made on
+const KAT_NETWORK_ID: u32 = 1;
+
+/// Known-answer vector for one burn & mint proof
+///
+/// Field elements are canonical `u64`s; byte strings are lowercase hex.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KatVector {
+    /// Seed the inputs are derived from
+    pub seed: u64,
+    /// Burn amount (atomic units)
+    pub burn_amount: u64,
+    /// Burn transaction prefix hash
+    pub tx_prefix_hash: String,
+    /// 20-byte recipient address
+    pub recipient: String,
+    /// 32-byte burn secret
+    pub secret: String,
+    /// Fuego network ID
+    pub network_id: u32,
+    /// HEAT target chain ID
+    pub target_chain_id: u32,
+    /// Commitment format version
+    pub commitment_version: u32,
+    /// HEAT commitment written into `tx_extra`
+    pub heat_commitment: String,
+    /// Nullifier public input limbs
+    pub nullifier: Vec<u64>,
+    /// Commitment public input limbs
+    pub commitment: Vec<u64>,
+    /// Recipient hash public input
+    pub recipient_hash: u64,
+    /// Public inputs in the order the proof binds them
+    pub public_inputs: Vec<u64>,
+    /// Execution trace, one row per step
+    pub trace_rows: Vec<Vec<u64>>,
+    /// Serialized proof
+    pub proof: String,
+}
+
+/// Known-answer test file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KatFile {
+    /// Format version ([`KAT_VERSION`])
+    pub version: u32,
+    /// Vectors, one per seed
+    pub vectors: Vec<KatVector>,
+}
+
+/// Known-answer test file this crate does not reproduce
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum KatError {
+    /// The file was written in another format version
+    #[error("Unsupported KAT version {found}, expected {expected}")]
+    UnsupportedVersion {
+        /// Version in the file
+        found: u32,
+        /// Version this crate writes
+        expected: u32,
+    },
+    /// A recorded value differs from the one this crate computes
+    #[error("KAT vector for seed {seed} differs in `{field}`")]
+    Mismatch {
+        /// Seed of the vector
+        seed: u64,
+        /// First differing field
+        field: String,
+    },
+    /// The recorded proof does not verify
+    #[error("KAT proof for seed {seed} does not verify: {reason}")]
+    ProofRejected {
+        /// Seed of the vector
+        seed: u64,
+        /// Verifier's response
+        reason: String,
+    },
+}
+
+/// Deterministic 32 bytes for a known-answer input
+fn kat_bytes(label: &[u8], seed: u64) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(b"xfg-kat");
+    hasher.update(label);
+    hasher.update(seed.to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// Check a known-answer test file against this crate
+///
+/// Every vector is regenerated from its seed and compared field by field, and its
+/// recorded proof is verified from bytes.
+///
+/// # Errors
+///
+/// If the file cannot be read or parsed, or a [`KatError`] if its version is not
+/// supported, a vector differs from the regenerated one or a recorded proof is rejected
+pub fn verify_kat(path: impl AsRef<Path>) -> crate::Result<()> {
+    let file: KatFile = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    if file.version != KAT_VERSION {
+        return Err(KatError::UnsupportedVersion {
+            found: file.version,
+            expected: KAT_VERSION,
+        }
+        .into());
+    }
+
+    let verifier = XfgBurnMintVerifier::default();
+    for recorded in &file.vectors {
+        let expected = TestDataGenerator::generate_kat_vector(recorded.seed)?;
+        let (recorded_fields, expected_fields) = (serde_json::to_value(recorded)?, serde_json::to_value(&expected)?);
+        if let (Some(recorded_fields), Some(expected_fields)) = (recorded_fields.as_object(), expected_fields.as_object()) {
+            if let Some((field, _)) = expected_fields.iter().find(|(field, value)| recorded_fields.get(*field) != Some(value)) {
+                return Err(KatError::Mismatch {
+                    seed: recorded.seed,
+                    field: field.clone(),
+                }
+                .into());
+            }
+        }
+
+        let proof_bytes = hex::decode(&recorded.proof).map_err(|e| crate::XfgStarkError::ParseError(e.to_string()))?;
+        let public_inputs = TestDataGenerator::kat_public_inputs(recorded.seed)?;
+        let report = verifier.verify_from_bytes(&proof_bytes, &public_inputs)?;
+        if let Some(reason) = report.error {
+            return Err(KatError::ProofRejected {
+                seed: recorded.seed,
+                reason,
+            }
+            .into());
+        }
+    }
+    Ok(())
+}
 
 /// Test data generator for realistic Fuego blockchain data
 pub struct TestDataGenerator;
@@ -52,7 +213,7 @@ impl TestDataGenerator {
     pub fn generate_ethereum_address() -> String {
         // Real Ethereum addresses (examples)
         let eth_addresses = vec![
-            "0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6",
+            "0x742d35Cc6634C0532925A3B8D4C9dB96C4B4d8B6",
             "0x1234567890123456789012345678901234567890",
             "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd",
             "0x9876543210987654321098765432109876543210",
@@ -83,11 +244,11 @@ impl TestDataGenerator {
 
     /// Generate realistic burn amounts for testing
     pub fn generate_burn_amounts() -> (f64, u64) {
-        // Valid burn amounts: 0.8 XFG or 800.0 XFG
-        let amounts = vec![
-            (0.8, 8_000_000),      // 0.8 XFG in atomic units
-            (800.0, 8_000_000_000), // 800.0 XFG in atomic units
-        ];
+        // Burn amounts: the 0.8, 8, 80 and 800 XFG tiers
+        let amounts: Vec<(f64, u64)> = BURN_TIERS_ATOMIC
+            .iter()
+            .map(|&atomic| (atomic_units_to_xfg(atomic), atomic))
+            .collect();
 
         // Generate cryptographically secure random selection
         let mut rng = rand::thread_rng();
@@ -146,14 +307,14 @@ impl TestDataGenerator {
         match scenario {
             "standard_burn" => {
                 let mut package = Self::generate_test_package();
-                package.insert("burn_amount_xfg".to_string(), "0.8".to_string());
-                package.insert("burn_amount_atomic".to_string(), "8000000".to_string());
+                package.insert("burn_amount_xfg".to_string(), format!("{:.1}", atomic_units_to_xfg(STANDARD_BURN_ATOMIC)));
+                package.insert("burn_amount_atomic".to_string(), STANDARD_BURN_ATOMIC.to_string());
                 package
             },
             "large_burn" => {
                 let mut package = Self::generate_test_package();
-                package.insert("burn_amount_xfg".to_string(), "800.0".to_string());
-                package.insert("burn_amount_atomic".to_string(), "8000000000".to_string());
+                package.insert("burn_amount_xfg".to_string(), format!("{:.1}", atomic_units_to_xfg(LARGE_BURN_ATOMIC)));
+                package.insert("burn_amount_atomic".to_string(), LARGE_BURN_ATOMIC.to_string());
                 package
             },
             "invalid_amount" => {
@@ -165,6 +326,95 @@ impl TestDataGenerator {
             _ => Self::generate_test_package(),
         }
     }
+
+    /// Prove the known-answer burn for `seed`
+    fn prove_kat_burn(seed: u64) -> crate::Result<(StarkProof, crate::burn_mint_air::BurnMintPublicInputs)> {
+        let burn_amount = BURN_TIERS_ATOMIC[usize::try_from(seed % BURN_TIERS_ATOMIC.len() as u64).unwrap_or_default()];
+        let recipient = kat_bytes(b"recipient", seed);
+        XfgBurnMintProver::default().prove_burn_mint_with_public_inputs(
+            &BurnMintRequest {
+                burn_amount,
+                mint_amount: burn_amount,
+                tx_prefix_hash: kat_bytes(b"tx_prefix_hash", seed),
+                recipient_address: recipient.first_chunk().expect("a 32-byte hash holds an address"),
+                network_id: KAT_NETWORK_ID,
+                target_chain_id: DEFAULT_TARGET_CHAIN_ID,
+                commitment_version: COMMITMENT_VERSION,
+            },
+            &kat_bytes(b"secret", seed),
+        )
+    }
+
+    /// Public inputs of the known-answer burn for `seed`
+    fn kat_public_inputs(seed: u64) -> crate::Result<crate::burn_mint_air::BurnMintPublicInputs> {
+        Self::prove_kat_burn(seed).map(|(_, public_inputs)| public_inputs)
+    }
+
+    /// Generate the known-answer vector for `seed`
+    ///
+    /// The burn cycles through the tiers; every other input is derived from the seed.
+    ///
+    /// # Errors
+    ///
+    /// If proving the burn or rebuilding its trace fails
+    pub fn generate_kat_vector(seed: u64) -> crate::Result<KatVector> {
+        let (proof, public_inputs) = Self::prove_kat_burn(seed)?;
+        let burn_amount = public_inputs.burn_amount.as_int();
+        let recipient = &kat_bytes(b"recipient", seed)[..20];
+        let secret = kat_bytes(b"secret", seed);
+
+        let air = XfgBurnMintAir::new(
+            proof.get_trace_info(),
+            public_inputs.clone(),
+            crate::burn_mint_prover::secret_to_field_elements(&secret)?,
+            proof.options().clone(),
+        );
+        let trace = air.build_trace();
+        let trace_rows = (0..trace.length())
+            .map(|step| (0..trace.main_trace_width()).map(|column| trace.get(column, step).as_int()).collect())
+            .collect();
+
+        Ok(KatVector {
+            seed,
+            burn_amount,
+            tx_prefix_hash: hex::encode(kat_bytes(b"tx_prefix_hash", seed)),
+            recipient: hex::encode(recipient),
+            secret: hex::encode(secret),
+            network_id: KAT_NETWORK_ID,
+            target_chain_id: DEFAULT_TARGET_CHAIN_ID,
+            commitment_version: COMMITMENT_VERSION,
+            heat_commitment: hex::encode(commitment_to_bytes(&public_inputs.commitment)),
+            nullifier: public_inputs.nullifier.iter().map(BaseElement::as_int).collect(),
+            commitment: public_inputs.commitment.iter().map(BaseElement::as_int).collect(),
+            recipient_hash: public_inputs.recipient_hash.as_int(),
+            public_inputs: public_inputs.to_elements().iter().map(BaseElement::as_int).collect(),
+            trace_rows,
+            proof: hex::encode(proof.to_bytes()),
+        })
+    }
+
+    /// Generate a known-answer test file for `seeds`
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::generate_kat_vector`]
+    pub fn generate_kat_file(seeds: &[u64]) -> crate::Result<KatFile> {
+        Ok(KatFile {
+            version: KAT_VERSION,
+            vectors: seeds.iter().map(|&seed| Self::generate_kat_vector(seed)).collect::<crate::Result<_>>()?,
+        })
+    }
+
+    /// Write the known-answer test file for `seeds` as pretty-printed JSON
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::generate_kat_vector`], or if the file cannot be written
+    pub fn write_kat(path: impl AsRef<Path>, seeds: &[u64]) -> crate::Result<()> {
+        let file = Self::generate_kat_file(seeds)?;
+        std::fs::write(path, serde_json::to_string_pretty(&file)? + "\n")?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -195,8 +445,8 @@ mod tests {
     #[test]
     fn test_burn_amounts_generation() {
         let (xfg, atomic) = TestDataGenerator::generate_burn_amounts();
-        assert!(xfg == 0.8 || xfg == 800.0); // Only valid amounts
-        assert!(atomic == 8_000_000 || atomic == 8_000_000_000); // Corresponding atomic units
+        assert!(crate::constants::burn_tiers_xfg().contains(&xfg)); // Only tier amounts
+        assert!(BURN_TIERS_ATOMIC.contains(&atomic)); // Corresponding atomic units
     }
 
     #[test]
@@ -225,4 +475,30 @@ mod tests {
         let invalid_package = TestDataGenerator::generate_test_scenario("invalid_amount");
         assert_eq!(invalid_package["burn_amount_xfg"], "1.5");
     }
+
+    /// Published known-answer vectors
+    fn kat_fixture() -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/kat/burn_mint.json")
+    }
+
+    #[test]
+    fn test_kat_fixture_verifies() {
+        verify_kat(kat_fixture()).unwrap();
+    }
+
+    #[test]
+    fn test_kat_mismatch_detected() {
+        let mut file: KatFile = serde_json::from_str(&std::fs::read_to_string(kat_fixture()).unwrap()).unwrap();
+        assert_eq!(file.vectors.iter().map(|vector| vector.seed).collect::<Vec<_>>(), KAT_SEEDS);
+        file.vectors[0].recipient_hash += 1;
+
+        let path = std::env::temp_dir().join(format!("xfg-kat-{}.json", std::process::id()));
+        std::fs::write(&path, serde_json::to_string(&file).unwrap()).unwrap();
+        let result = verify_kat(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            result,
+            Err(crate::XfgStarkError::KatError(KatError::Mismatch { seed: 1, ref field })) if field == "recipient_hash"
+        ));
+    }
 }
@@ -0,0 +1,219 @@
+//! End-to-End Test Harness for XFG Burn & Mint Proofs
+//!
+//! This module drives a burn & mint proof through the path a gateway sees it on:
+//! generated, serialized, decoded from bytes and verified. It then mutates the claim or
+//! the proof bytes and reports whether the verifier rejected each mutation.
+//!
+//! ## Adversarial Cases
+//! - **Wrong amount**: the proof claimed for a larger burn and mint
+//! - **Wrong recipient**: the proof claimed for another recipient address
+//! - **Reused nullifier**: the same claim submitted twice against one nullifier set
+//! - **Truncated proof**: the serialized proof cut short
+//!
+//! Complements [`crate::replay_scenarios`], which covers proofs replayed across
+//! networks and commitment versions.
+
+use crate::{
+    burn_mint_air::BurnMintPublicInputs,
+    burn_mint_prover::{BurnMintRequest, XfgBurnMintProver},
+    burn_mint_verifier::XfgBurnMintVerifier,
+    nullifier::MemoryNullifierSet,
+    Result,
+};
+use std::fmt::{Display, Formatter};
+use winterfell::{math::fields::f64::BaseElement, StarkProof};
+
+/// Standard 0.8 XFG burn in atomic units
+const HARNESS_BURN_AMOUNT: u64 = crate::constants::STANDARD_BURN_ATOMIC;
+
+/// Recipient of the honest claim
+const HARNESS_RECIPIENT: [u8; 20] = [0x12; 20];
+
+/// Recipient a tampered claim redirects the mint to
+const OTHER_RECIPIENT: [u8; 20] = [0x34; 20];
+
+/// Burn transaction of the honest claim
+const HARNESS_TX_PREFIX_HASH: [u8; 32] = [7u8; 32];
+
+/// Mutation applied to an honest proof or claim
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdversarialCase {
+    /// Burn and mint amounts larger than the ones proven
+    WrongAmount,
+    /// Recipient other than the one proven
+    WrongRecipient,
+    /// The honest claim submitted a second time
+    ReusedNullifier,
+    /// Serialized proof cut to half its length
+    TruncatedProof,
+}
+
+impl AdversarialCase {
+    /// All adversarial cases
+    #[must_use]
+    pub fn all() -> Vec<AdversarialCase> {
+        vec![
+            AdversarialCase::WrongAmount,
+            AdversarialCase::WrongRecipient,
+            AdversarialCase::ReusedNullifier,
+            AdversarialCase::TruncatedProof,
+        ]
+    }
+}
+
+impl Display for AdversarialCase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdversarialCase::WrongAmount => write!(f, "wrong amount"),
+            AdversarialCase::WrongRecipient => write!(f, "wrong recipient"),
+            AdversarialCase::ReusedNullifier => write!(f, "reused nullifier"),
+            AdversarialCase::TruncatedProof => write!(f, "truncated proof"),
+        }
+    }
+}
+
+/// Outcome of running an adversarial case
+#[derive(Debug, Clone)]
+pub struct AdversarialOutcome {
+    /// Case that was run
+    pub case: AdversarialCase,
+    /// Whether the verifier rejected the mutated proof or claim
+    pub rejected: bool,
+    /// Human-readable description of the verifier's response
+    pub detail: String,
+}
+
+/// Honest proof that survived a serialization round trip
+#[derive(Debug, Clone)]
+pub struct HarnessFixture {
+    /// Proof decoded from `proof_bytes`
+    pub proof: StarkProof,
+    /// Serialized proof
+    pub proof_bytes: Vec<u8>,
+    /// Public inputs of the honest claim
+    pub public_inputs: BurnMintPublicInputs,
+}
+
+/// Generate an honest proof, serialize and decode it, and verify it from bytes
+///
+/// # Errors
+///
+/// If proving fails, or the honest proof does not decode or verify, so later
+/// rejections are not vacuous
+pub fn run_round_trip(prover: &XfgBurnMintProver, verifier: &XfgBurnMintVerifier) -> Result<HarnessFixture> {
+    let (proof, public_inputs) = prover.prove_burn_mint_with_public_inputs(
+        &BurnMintRequest {
+            burn_amount: HARNESS_BURN_AMOUNT,
+            mint_amount: HARNESS_BURN_AMOUNT,
+            tx_prefix_hash: HARNESS_TX_PREFIX_HASH,
+            recipient_address: &HARNESS_RECIPIENT,
+            network_id: 1,
+            target_chain_id: 42161,
+            commitment_version: 1,
+        },
+        &[9u8; 32],
+    )?;
+    let proof_bytes = proof.to_bytes();
+    let proof = StarkProof::from_bytes(&proof_bytes)
+        .map_err(|e| crate::XfgStarkError::CryptoError(format!("Honest proof failed to decode: {e}")))?;
+
+    let report = verifier.verify_from_bytes(&proof_bytes, &public_inputs)?;
+    if let Some(error) = report.error {
+        return Err(crate::XfgStarkError::CryptoError(format!(
+            "Honest proof failed verification: {error}"
+        )));
+    }
+
+    Ok(HarnessFixture {
+        proof,
+        proof_bytes,
+        public_inputs,
+    })
+}
+
+/// Describe the verifier's response to a tampered claim
+fn describe_claim(verifier: &XfgBurnMintVerifier, fixture: &HarnessFixture, claim: &BurnMintPublicInputs) -> (bool, String) {
+    match verifier.verify_with_public_inputs(&fixture.proof, claim) {
+        Ok(true) => (false, "verifier accepted the tampered claim".to_string()),
+        Ok(false) => (true, "proof rejected for claim".to_string()),
+        Err(e) => (true, format!("claim rejected: {e}")),
+    }
+}
+
+/// Run a single adversarial case against an honest fixture
+///
+/// # Errors
+///
+/// If the first claim of the reused-nullifier case fails; rejections are reported in
+/// the outcome
+pub fn run_adversarial_case(
+    verifier: &XfgBurnMintVerifier,
+    fixture: &HarnessFixture,
+    case: AdversarialCase,
+) -> Result<AdversarialOutcome> {
+    let (rejected, detail) = match case {
+        AdversarialCase::WrongAmount => {
+            let mut claim = fixture.public_inputs.clone();
+            claim.burn_amount = BaseElement::new(2 * HARNESS_BURN_AMOUNT);
+            claim.mint_amount = claim.burn_amount;
+            describe_claim(verifier, fixture, &claim)
+        }
+        AdversarialCase::WrongRecipient => {
+            let redirected = BurnMintPublicInputs::from_user_data(
+                HARNESS_BURN_AMOUNT,
+                HARNESS_TX_PREFIX_HASH,
+                &OTHER_RECIPIENT,
+                1,
+                42161,
+                1,
+            );
+            let mut claim = fixture.public_inputs.clone();
+            claim.recipient_hash = redirected.recipient_hash;
+            claim.recipient_binding = redirected.recipient_binding;
+            describe_claim(verifier, fixture, &claim)
+        }
+        AdversarialCase::ReusedNullifier => {
+            let nullifiers = MemoryNullifierSet::new();
+            if !verifier.verify_with_nullifiers(&fixture.proof, &fixture.public_inputs, &nullifiers)? {
+                return Err(crate::XfgStarkError::CryptoError(
+                    "Honest claim failed verification".to_string(),
+                ));
+            }
+            match verifier.verify_with_nullifiers(&fixture.proof, &fixture.public_inputs, &nullifiers) {
+                Ok(true) => (false, "verifier accepted the claim twice".to_string()),
+                Ok(false) => (true, "second claim rejected".to_string()),
+                Err(e) => (true, format!("second claim rejected: {e}")),
+            }
+        }
+        AdversarialCase::TruncatedProof => {
+            let truncated = &fixture.proof_bytes[..fixture.proof_bytes.len() / 2];
+            match verifier.verify_from_bytes(truncated, &fixture.public_inputs) {
+                Ok(report) if report.is_valid() => (false, "verifier accepted the truncated proof".to_string()),
+                Ok(report) => (true, format!("truncated proof rejected: {}", report.error.unwrap_or_default())),
+                Err(e) => (true, format!("truncated proof rejected: {e}")),
+            }
+        }
+    };
+
+    Ok(AdversarialOutcome {
+        case,
+        rejected,
+        detail,
+    })
+}
+
+/// Run the round trip, then every adversarial case against its proof
+///
+/// # Errors
+///
+/// As [`run_round_trip`] and [`run_adversarial_case`]
+pub fn run_all_adversarial_cases(
+    prover: &XfgBurnMintProver,
+    verifier: &XfgBurnMintVerifier,
+) -> Result<Vec<AdversarialOutcome>> {
+    let fixture = run_round_trip(prover, verifier)?;
+    AdversarialCase::all()
+        .into_iter()
+        .map(|case| run_adversarial_case(verifier, &fixture, case))
+        .collect()
+}
@@ -0,0 +1,185 @@
+//! Property-Testing Generators
+//!
+//! [`quickcheck::Arbitrary`] implementations for [`PrimeField64`] and
+//! [`FieldPolynomial`], plus generators for inputs with structure the plain types do
+//! not carry: power-of-two NTT inputs and Merkle trees with a leaf to prove.
+//!
+//! Uniformly random `u64`s almost never land near the modulus, where reductions carry,
+//! so a quarter of generated field elements are drawn from [`EDGE_VALUES`] instead.
+//!
+//! Enabled by the `testing` feature so downstream crates can reuse the generators in
+//! their own property tests.
+
+use crate::polynomial::FieldPolynomial;
+use crate::types::field::PrimeField64;
+use quickcheck::{Arbitrary, Gen};
+
+/// Values where field arithmetic reduces or carries: around zero, the modulus, half
+/// the modulus and the 32-bit limb boundary
+pub const EDGE_VALUES: [u64; 11] = [
+    0,
+    1,
+    2,
+    PrimeField64::MODULUS - 1,
+    PrimeField64::MODULUS - 2,
+    PrimeField64::MODULUS / 2,
+    PrimeField64::MODULUS / 2 + 1,
+    (1 << 32) - 1,
+    1 << 32,
+    (1 << 32) + 1,
+    1 << 63,
+];
+
+/// Largest NTT input generated, as a power of two
+const MAX_NTT_LOG_SIZE: u32 = 6;
+
+impl Arbitrary for PrimeField64 {
+    fn arbitrary(g: &mut Gen) -> Self {
+        if u8::arbitrary(g) % 4 == 0 {
+            PrimeField64::new(*g.choose(&EDGE_VALUES).expect("edge values are not empty"))
+        } else {
+            PrimeField64::new(u64::arbitrary(g) % PrimeField64::MODULUS)
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.value().shrink().map(PrimeField64::new))
+    }
+}
+
+impl Arbitrary for FieldPolynomial<PrimeField64> {
+    /// A polynomial with up to `g.size()` coefficients, at least one
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut coefficients = Vec::<PrimeField64>::arbitrary(g);
+        if coefficients.is_empty() {
+            coefficients.push(PrimeField64::arbitrary(g));
+        }
+        FieldPolynomial::new(coefficients)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let coefficients: Vec<_> = (0..=self.degree()).map(|i| self.coefficient(i)).collect();
+        Box::new(coefficients.shrink().filter(|c| !c.is_empty()).map(FieldPolynomial::new))
+    }
+}
+
+/// Values of power-of-two length, the input an NTT accepts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NttInput(pub Vec<PrimeField64>);
+
+impl Arbitrary for NttInput {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let log_size = u32::arbitrary(g) % (MAX_NTT_LOG_SIZE + 1);
+        NttInput((0..1usize << log_size).map(|_| PrimeField64::arbitrary(g)).collect())
+    }
+}
+
+/// Non-empty Merkle leaves and the index of one of them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleLeaves {
+    /// Leaf data
+    pub leaves: Vec<Vec<u8>>,
+    /// Index of the leaf to prove
+    pub index: usize,
+}
+
+impl Arbitrary for MerkleLeaves {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut leaves = Vec::<Vec<u8>>::arbitrary(g);
+        if leaves.is_empty() {
+            leaves.push(Vec::arbitrary(g));
+        }
+        let index = usize::arbitrary(g) % leaves.len();
+        MerkleLeaves { leaves, index }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let index = self.index;
+        Box::new(
+            self.leaves
+                .shrink()
+                .filter(move |leaves| index < leaves.len())
+                .map(move |leaves| MerkleLeaves { leaves, index }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::{evaluate_coset, interpolate_coset, intt, ntt};
+    use crate::proof::merkle::{IncrementalMerkleTree, MerkleTree};
+    use crate::types::FieldElement;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn test_field_laws() {
+        fn associative(a: PrimeField64, b: PrimeField64, c: PrimeField64) -> bool {
+            (a + b) + c == a + (b + c) && (a * b) * c == a * (b * c)
+        }
+        fn distributive(a: PrimeField64, b: PrimeField64, c: PrimeField64) -> bool {
+            a * (b + c) == a * b + a * c
+        }
+        fn inverse(a: PrimeField64) -> bool {
+            match a.inverse() {
+                Some(inverse) => a * inverse == PrimeField64::one(),
+                None => a.is_zero(),
+            }
+        }
+        fn canonical(a: PrimeField64, b: PrimeField64) -> bool {
+            [a + b, a - b, a * b, -a].iter().all(|x| x.value() < PrimeField64::MODULUS)
+        }
+        quickcheck(associative as fn(_, _, _) -> bool);
+        quickcheck(distributive as fn(_, _, _) -> bool);
+        quickcheck(inverse as fn(_) -> bool);
+        quickcheck(canonical as fn(_, _) -> bool);
+    }
+
+    #[test]
+    fn test_polynomial_round_trips() {
+        fn ntt_round_trip(input: NttInput) -> bool {
+            let mut values = input.0.clone();
+            ntt(&mut values).is_ok() && intt(&mut values).is_ok() && values == input.0
+        }
+        fn coset_round_trip(input: NttInput, offset: PrimeField64) -> bool {
+            if offset.is_zero() {
+                return true;
+            }
+            let evaluations = evaluate_coset(&input.0, offset, input.0.len()).unwrap();
+            interpolate_coset(&evaluations, offset).unwrap() == input.0
+        }
+        fn interpolation_round_trip(polynomial: FieldPolynomial<PrimeField64>) -> bool {
+            let points: Vec<_> = (0..=polynomial.degree() as u64)
+                .map(|x| (PrimeField64::new(x), polynomial.evaluate(PrimeField64::new(x))))
+                .collect();
+            let interpolated = FieldPolynomial::interpolate(&points).unwrap();
+            (0..=polynomial.degree()).all(|i| interpolated.coefficient(i) == polynomial.coefficient(i))
+        }
+        fn fft_multiply_matches(a: FieldPolynomial<PrimeField64>, b: FieldPolynomial<PrimeField64>) -> bool {
+            let (product, expected) = (a.multiply_fft(&b).unwrap(), a.multiply(&b));
+            (0..=expected.degree()).all(|i| product.coefficient(i) == expected.coefficient(i))
+        }
+        quickcheck(ntt_round_trip as fn(_) -> bool);
+        quickcheck(coset_round_trip as fn(_, _) -> bool);
+        quickcheck(interpolation_round_trip as fn(_) -> bool);
+        quickcheck(fft_multiply_matches as fn(_, _) -> bool);
+    }
+
+    #[test]
+    fn test_merkle_proof_soundness() {
+        fn sound(input: MerkleLeaves, forged: Vec<u8>) -> bool {
+            let tree = MerkleTree::new(&input.leaves).unwrap();
+            let proof = tree.generate_proof(input.index).unwrap();
+            let leaf = &input.leaves[input.index];
+            proof.verify(leaf, tree.root_hash()) && (forged == *leaf || !proof.verify(&forged, tree.root_hash()))
+        }
+        fn incremental_agrees(input: MerkleLeaves) -> bool {
+            let tree = MerkleTree::new(&input.leaves).unwrap();
+            let incremental: IncrementalMerkleTree = IncrementalMerkleTree::from_leaves(&input.leaves);
+            incremental.root_hash().ok() == Some(tree.root_hash())
+                && incremental.generate_proof(input.index).ok() == tree.generate_proof(input.index).ok()
+        }
+        quickcheck(sound as fn(_, _) -> bool);
+        quickcheck(incremental_agrees as fn(_) -> bool);
+    }
+}
@@ -0,0 +1,303 @@
+//! Constant- and Run-Aware Trace Columns
+//!
+//! Most burn & mint trace columns hold one value for the whole trace (amounts, hashes,
+//! nullifier, commitment) and the state column changes only a handful of times.
+//! [`CompressedColumn`] stores such columns as a single value or as runs, falling back
+//! to the plain values when neither is smaller.
+//!
+//! The prover uses it to skip interpolating and extending constant columns (see
+//! [`crate::statements::ConstantColumnTraceLde`]), and trace dumps use
+//! [`encode_columns`] as a compact payload encoding.
+//!
+//! ## Column Encoding
+//!
+//! Each column starts with a tag byte, all integers little-endian:
+//!
+//! | Tag | Layout                                              |
+//! |-----|-----------------------------------------------------|
+//! | 0   | Constant: one `u64` value                           |
+//! | 1   | Runs: `u32` run count, then `u64` value + `u32` run length per run |
+//! | 2   | Dense: one `u64` per row                            |
+
+use thiserror::Error;
+
+/// Column encoding tag of a constant column
+const CONSTANT_TAG: u8 = 0;
+
+/// Column encoding tag of a run-length column
+const RUNS_TAG: u8 = 1;
+
+/// Column encoding tag of a dense column
+const DENSE_TAG: u8 = 2;
+
+/// Encoded size of one run (value and length)
+const RUN_BYTES: usize = 12;
+
+/// A trace column stored by its structure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompressedColumn<T> {
+    /// The same value in every row
+    Constant {
+        /// Value of every row
+        value: T,
+        /// Number of rows
+        length: usize,
+    },
+    /// Consecutive rows sharing a value, as `(value, rows)` pairs
+    Runs(Vec<(T, usize)>),
+    /// One value per row
+    Dense(Vec<T>),
+}
+
+impl<T: Copy + PartialEq> CompressedColumn<T> {
+    /// Pick the smallest representation of `values`
+    pub fn compress(values: &[T]) -> Self {
+        let mut runs: Vec<(T, usize)> = Vec::new();
+        for value in values {
+            match runs.last_mut() {
+                Some((last, count)) if last == value => *count += 1,
+                _ => runs.push((*value, 1)),
+            }
+        }
+
+        match runs.as_slice() {
+            [(value, length)] => CompressedColumn::Constant {
+                value: *value,
+                length: *length,
+            },
+            _ if 4 + runs.len() * RUN_BYTES < values.len() * 8 => CompressedColumn::Runs(runs),
+            _ => CompressedColumn::Dense(values.to_vec()),
+        }
+    }
+
+    /// Number of rows
+    pub fn len(&self) -> usize {
+        match self {
+            CompressedColumn::Constant { length, .. } => *length,
+            CompressedColumn::Runs(runs) => runs.iter().map(|(_, count)| count).sum(),
+            CompressedColumn::Dense(values) => values.len(),
+        }
+    }
+
+    /// Check whether the column has no rows
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Value of every row of `values`, if constant, without copying the values
+    pub fn constant_of(values: &[T]) -> Option<T> {
+        let (first, rest) = values.split_first()?;
+        rest.iter().all(|value| value == first).then_some(*first)
+    }
+
+    /// Value of every row, if the column is constant
+    pub fn constant(&self) -> Option<T> {
+        match self {
+            CompressedColumn::Constant { value, .. } => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Number of values the representation stores
+    pub fn stored_values(&self) -> usize {
+        match self {
+            CompressedColumn::Constant { .. } => 1,
+            CompressedColumn::Runs(runs) => runs.len(),
+            CompressedColumn::Dense(values) => values.len(),
+        }
+    }
+
+    /// Expand to one value per row
+    pub fn to_values(&self) -> Vec<T> {
+        match self {
+            CompressedColumn::Constant { value, length } => vec![*value; *length],
+            CompressedColumn::Runs(runs) => runs
+                .iter()
+                .flat_map(|(value, count)| std::iter::repeat_n(*value, *count))
+                .collect(),
+            CompressedColumn::Dense(values) => values.clone(),
+        }
+    }
+}
+
+/// Trace column encoding errors
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ColumnEncodingError {
+    /// Input ends inside a column
+    #[error("Column data is truncated")]
+    Truncated,
+    /// Unknown column tag
+    #[error("Unknown column encoding {tag} in column {column}")]
+    UnknownTag {
+        /// Column index
+        column: usize,
+        /// Tag byte
+        tag: u8,
+    },
+    /// Runs do not add up to the trace length
+    #[error("Column {column} has {found} rows, expected {expected}")]
+    LengthMismatch {
+        /// Column index
+        column: usize,
+        /// Trace length
+        expected: usize,
+        /// Rows the encoding describes
+        found: usize,
+    },
+    /// Input continues after the last column
+    #[error("{0} trailing bytes after the last column")]
+    TrailingBytes(usize),
+}
+
+/// Encode columns of equal length
+///
+/// Columns longer than `u32::MAX` rows are stored dense, since run lengths are `u32`.
+#[must_use]
+pub fn encode_columns(columns: &[Vec<u64>]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for column in columns {
+        match CompressedColumn::compress(column) {
+            CompressedColumn::Constant { value, .. } => {
+                bytes.push(CONSTANT_TAG);
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+            // Run counts and lengths are at most the column length, so they fit in `u32` too
+            CompressedColumn::Runs(runs) if u32::try_from(column.len()).is_ok() => {
+                bytes.push(RUNS_TAG);
+                bytes.extend_from_slice(&u32::try_from(runs.len()).unwrap_or(u32::MAX).to_le_bytes());
+                for (value, count) in runs {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                    bytes.extend_from_slice(&u32::try_from(count).unwrap_or(u32::MAX).to_le_bytes());
+                }
+            }
+            CompressedColumn::Runs(_) | CompressedColumn::Dense(_) => {
+                bytes.push(DENSE_TAG);
+                for value in column {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+        }
+    }
+    bytes
+}
+
+/// Decode `width` columns of `length` rows written by [`encode_columns`]
+///
+/// # Errors
+///
+/// [`ColumnEncodingError`] if `bytes` do not hold exactly `width` well-formed columns of
+/// `length` rows
+pub fn decode_columns(bytes: &[u8], width: usize, length: usize) -> Result<Vec<Vec<u64>>, ColumnEncodingError> {
+    let mut reader = Reader { bytes, offset: 0 };
+    let mut columns = Vec::with_capacity(width);
+
+    for column in 0..width {
+        let values = match reader.u8()? {
+            CONSTANT_TAG => vec![reader.u64()?; length],
+            RUNS_TAG => {
+                let num_runs = reader.u32()? as usize;
+                let mut values = Vec::with_capacity(length);
+                for _ in 0..num_runs {
+                    let value = reader.u64()?;
+                    let count = reader.u32()? as usize;
+                    if values.len() + count > length {
+                        return Err(ColumnEncodingError::LengthMismatch {
+                            column,
+                            expected: length,
+                            found: values.len() + count,
+                        });
+                    }
+                    values.resize(values.len() + count, value);
+                }
+                values
+            }
+            DENSE_TAG => (0..length).map(|_| reader.u64()).collect::<Result<_, _>>()?,
+            tag => return Err(ColumnEncodingError::UnknownTag { column, tag }),
+        };
+        if values.len() != length {
+            return Err(ColumnEncodingError::LengthMismatch {
+                column,
+                expected: length,
+                found: values.len(),
+            });
+        }
+        columns.push(values);
+    }
+
+    match bytes.len() - reader.offset {
+        0 => Ok(columns),
+        trailing => Err(ColumnEncodingError::TrailingBytes(trailing)),
+    }
+}
+
+/// Bounds-checked little-endian reader
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], ColumnEncodingError> {
+        let chunk = self
+            .bytes
+            .get(self.offset..self.offset + N)
+            .ok_or(ColumnEncodingError::Truncated)?;
+        self.offset += N;
+        Ok(chunk.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8, ColumnEncodingError> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, ColumnEncodingError> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, ColumnEncodingError> {
+        Ok(u64::from_le_bytes(self.take()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_representations() {
+        assert_eq!(
+            CompressedColumn::compress(&[7u64; 64]),
+            CompressedColumn::Constant { value: 7, length: 64 }
+        );
+
+        let states: Vec<u64> = (0..64).map(|row| row / 16).collect();
+        let column = CompressedColumn::compress(&states);
+        assert_eq!(column, CompressedColumn::Runs(vec![(0, 16), (1, 16), (2, 16), (3, 16)]));
+        assert_eq!(column.to_values(), states);
+        assert_eq!(column.len(), 64);
+        assert_eq!(column.stored_values(), 4);
+
+        let counter: Vec<u64> = (0..64).collect();
+        assert_eq!(CompressedColumn::compress(&counter), CompressedColumn::Dense(counter.clone()));
+        assert_eq!(CompressedColumn::compress(&counter).constant(), None);
+    }
+
+    #[test]
+    fn test_encoding_round_trip() {
+        let columns = vec![
+            vec![u64::MAX - 1; 64],
+            (0..64).map(|row| row / 16).collect(),
+            (0..64).map(|row| row * row).collect(),
+        ];
+        let bytes = encode_columns(&columns);
+        assert_eq!(bytes.len(), (1 + 8) + (1 + 4 + 4 * RUN_BYTES) + (1 + 64 * 8));
+        assert_eq!(decode_columns(&bytes, 3, 64).unwrap(), columns);
+
+        assert_eq!(decode_columns(&bytes[..bytes.len() - 1], 3, 64), Err(ColumnEncodingError::Truncated));
+        assert!(matches!(
+            decode_columns(&bytes, 3, 32),
+            Err(ColumnEncodingError::LengthMismatch { column: 1, .. })
+        ));
+        assert_eq!(decode_columns(&[9], 1, 4), Err(ColumnEncodingError::UnknownTag { column: 0, tag: 9 }));
+    }
+}
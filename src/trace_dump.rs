@@ -0,0 +1,514 @@
+//! Execution Trace Persistence
+//!
+//! A failing proof is easiest to analyze from the trace the prover committed to.
+//! [`TraceDump`] stores a trace in a compact columnar binary format so it can be
+//! reloaded offline and fed back to the constraint checker or the prover.
+//!
+//! ## Format
+//!
+//! All integers are little-endian.
+//!
+//! | Bytes | Field                                           |
+//! |-------|-------------------------------------------------|
+//! | 8     | Magic `XFGTRACE`                                |
+//! | 2     | Format version                                  |
+//! | 1     | Compression (0 = none, 1 = zstd, 2 = columns)   |
+//! | 1     | Reserved (0)                                    |
+//! | 8     | Field modulus                                   |
+//! | 4     | Width (columns)                                 |
+//! | 4     | Length (rows)                                   |
+//! | 8     | Payload size in bytes                           |
+//! | ...   | Payload: each column in turn, one `u64` per row |
+//!
+//! The payload is zstd-compressed when the header says so. Writing and reading zstd
+//! dumps needs the `debug` feature; uncompressed dumps work in every build. Column
+//! compression stores constant and run-length columns as described in
+//! [`crate::trace_compression`] and also works in every build.
+
+use crate::trace_compression::{decode_columns, encode_columns, ColumnEncodingError};
+use crate::types::{stark::ExecutionTrace, FieldElement as XfgFieldElement};
+use std::path::Path;
+use thiserror::Error;
+use winterfell::{
+    math::{fields::f64::BaseElement, StarkField},
+    Trace, TraceTable,
+};
+
+/// Magic bytes opening every trace dump
+pub const TRACE_DUMP_MAGIC: [u8; 8] = *b"XFGTRACE";
+
+/// Current trace dump format version
+pub const TRACE_DUMP_VERSION: u16 = 1;
+
+/// Size of the trace dump header in bytes
+const HEADER_LEN: usize = 36;
+
+/// Payload compression of a trace dump
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceCompression {
+    /// Raw column data
+    None,
+    /// zstd-compressed column data (`debug` feature)
+    Zstd,
+    /// Constant and run-length encoded columns
+    Columns,
+}
+
+impl TraceCompression {
+    fn to_byte(self) -> u8 {
+        match self {
+            TraceCompression::None => 0,
+            TraceCompression::Zstd => 1,
+            TraceCompression::Columns => 2,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self, TraceDumpError> {
+        match byte {
+            0 => Ok(TraceCompression::None),
+            1 => Ok(TraceCompression::Zstd),
+            2 => Ok(TraceCompression::Columns),
+            other => Err(TraceDumpError::UnknownCompression(other)),
+        }
+    }
+}
+
+impl Default for TraceCompression {
+    /// zstd when the `debug` feature is enabled, otherwise column compression
+    fn default() -> Self {
+        if cfg!(feature = "debug") {
+            TraceCompression::Zstd
+        } else {
+            TraceCompression::Columns
+        }
+    }
+}
+
+/// Trace dump errors
+#[derive(Debug, Error)]
+pub enum TraceDumpError {
+    /// Reading or writing the dump failed
+    #[error("Trace dump I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// Input is not a trace dump
+    #[error("Not an execution trace dump")]
+    BadMagic,
+    /// Dump was written by a newer format version
+    #[error("Unsupported trace dump version {0}")]
+    UnsupportedVersion(u16),
+    /// Unknown compression byte
+    #[error("Unknown trace dump compression {0}")]
+    UnknownCompression(u8),
+    /// zstd support is not compiled in
+    #[error("zstd trace dumps need the `debug` feature")]
+    CompressionUnavailable,
+    /// Dump is shorter than its header declares
+    #[error("Trace dump is truncated")]
+    Truncated,
+    /// Column-compressed payload is malformed
+    #[error("Invalid column-compressed trace: {0}")]
+    Columns(#[from] ColumnEncodingError),
+    /// Dump belongs to a different field
+    #[error("Trace dump field modulus {found:#x} does not match {expected:#x}")]
+    FieldMismatch {
+        /// Modulus of the field being loaded into
+        expected: u64,
+        /// Modulus recorded in the dump
+        found: u64,
+    },
+    /// A value is not a canonical field element
+    #[error("Value at column {column}, row {row} is outside the field")]
+    ValueOutOfField {
+        /// Column of the value
+        column: usize,
+        /// Row of the value
+        row: usize,
+    },
+    /// Columns have different lengths
+    #[error("Trace columns must all have {expected} rows")]
+    RaggedColumns {
+        /// Rows of the first column
+        expected: usize,
+    },
+    /// Width or length does not fit the header's 32-bit fields
+    #[error("Trace of {width} columns and {length} rows is too large to dump")]
+    TooLarge {
+        /// Number of columns
+        width: usize,
+        /// Number of rows
+        length: usize,
+    },
+}
+
+/// Shape and encoding of a trace dump
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceSchema {
+    /// Modulus of the field the trace is over
+    pub field_modulus: u64,
+    /// Number of columns
+    pub width: usize,
+    /// Number of rows
+    pub length: usize,
+    /// Payload compression
+    pub compression: TraceCompression,
+}
+
+/// Field-agnostic execution trace as stored in a dump
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceDump {
+    /// Modulus of the field the trace is over
+    pub field_modulus: u64,
+    /// Columns of canonical field element values
+    pub columns: Vec<Vec<u64>>,
+}
+
+impl TraceDump {
+    /// Capture the main segment of a Winterfell trace
+    pub fn from_trace<T: Trace<BaseField = BaseElement>>(trace: &T) -> Self {
+        let segment = trace.main_segment();
+        Self {
+            field_modulus: BaseElement::MODULUS,
+            columns: (0..segment.num_cols())
+                .map(|column| segment.get_column(column).iter().map(BaseElement::as_int).collect())
+                .collect(),
+        }
+    }
+
+    /// Capture an execution trace of the custom pipeline
+    #[must_use]
+    pub fn from_execution_trace<F: XfgFieldElement>(trace: &ExecutionTrace<F>) -> Self {
+        Self {
+            field_modulus: F::MODULUS,
+            columns: trace
+                .columns
+                .iter()
+                .map(|column| column.iter().map(XfgFieldElement::value).collect())
+                .collect(),
+        }
+    }
+
+    /// Rebuild a Winterfell trace table
+    ///
+    /// # Errors
+    ///
+    /// [`TraceDumpError::FieldMismatch`] if the dump is not over Winterfell's field
+    pub fn to_trace_table(&self) -> Result<TraceTable<BaseElement>, TraceDumpError> {
+        self.check_field(BaseElement::MODULUS)?;
+        Ok(TraceTable::init(
+            self.columns
+                .iter()
+                .map(|column| column.iter().copied().map(BaseElement::new).collect())
+                .collect(),
+        ))
+    }
+
+    /// Rebuild an execution trace of the custom pipeline
+    ///
+    /// # Errors
+    ///
+    /// [`TraceDumpError::FieldMismatch`] if the dump is not over `F`
+    pub fn to_execution_trace<F: XfgFieldElement>(&self) -> Result<ExecutionTrace<F>, TraceDumpError> {
+        self.check_field(F::MODULUS)?;
+        Ok(ExecutionTrace {
+            columns: self
+                .columns
+                .iter()
+                .map(|column| column.iter().copied().map(F::new).collect())
+                .collect(),
+            length: self.length(),
+            num_registers: self.width(),
+        })
+    }
+
+    /// Number of columns
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Number of rows
+    pub fn length(&self) -> usize {
+        self.columns.first().map_or(0, Vec::len)
+    }
+
+    /// Encode the trace, compressing the payload as requested
+    ///
+    /// # Errors
+    ///
+    /// [`TraceDumpError::RaggedColumns`] if columns differ in length,
+    /// [`TraceDumpError::TooLarge`] if the shape does not fit the header, or
+    /// [`TraceDumpError::CompressionUnavailable`] for zstd without the `debug` feature
+    pub fn to_bytes(&self, compression: TraceCompression) -> Result<Vec<u8>, TraceDumpError> {
+        let length = self.length();
+        if self.columns.iter().any(|column| column.len() != length) {
+            return Err(TraceDumpError::RaggedColumns { expected: length });
+        }
+
+        let too_large = || TraceDumpError::TooLarge {
+            width: self.width(),
+            length,
+        };
+        let width = u32::try_from(self.width()).map_err(|_| too_large())?;
+        let rows = u32::try_from(length).map_err(|_| too_large())?;
+
+        let payload = if compression == TraceCompression::Columns {
+            encode_columns(&self.columns)
+        } else {
+            let mut payload = Vec::with_capacity(self.width() * length * 8);
+            for column in &self.columns {
+                for value in column {
+                    payload.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            compress(payload, compression)?
+        };
+
+        let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+        bytes.extend_from_slice(&TRACE_DUMP_MAGIC);
+        bytes.extend_from_slice(&TRACE_DUMP_VERSION.to_le_bytes());
+        bytes.push(compression.to_byte());
+        bytes.push(0);
+        bytes.extend_from_slice(&self.field_modulus.to_le_bytes());
+        bytes.extend_from_slice(&width.to_le_bytes());
+        bytes.extend_from_slice(&rows.to_le_bytes());
+        bytes.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+        Ok(bytes)
+    }
+
+    /// Decode a trace dump
+    ///
+    /// # Errors
+    ///
+    /// As [`read_schema`], [`TraceDumpError::Truncated`] or [`TraceDumpError::Columns`] if the
+    /// payload is malformed, [`TraceDumpError::ValueOutOfField`] for a non-canonical value,
+    /// or [`TraceDumpError::CompressionUnavailable`] for zstd without the `debug` feature
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TraceDumpError> {
+        let schema = read_schema(bytes)?;
+        let header = bytes.first_chunk::<HEADER_LEN>().ok_or(TraceDumpError::Truncated)?;
+        let payload_len = usize::try_from(u64::from_le_bytes(header_field(header, 28))).unwrap_or(usize::MAX);
+        let payload = bytes
+            .get(HEADER_LEN..HEADER_LEN.saturating_add(payload_len))
+            .ok_or(TraceDumpError::Truncated)?;
+        let columns = if schema.compression == TraceCompression::Columns {
+            decode_columns(payload, schema.width, schema.length)?
+        } else {
+            let payload = decompress(payload, schema.compression)?;
+            if payload.len() != schema.width * schema.length * 8 {
+                return Err(TraceDumpError::Truncated);
+            }
+            let mut values = payload.as_chunks::<8>().0.iter().copied().map(u64::from_le_bytes);
+            (0..schema.width)
+                .map(|_| values.by_ref().take(schema.length).collect())
+                .collect()
+        };
+
+        for (column, values) in columns.iter().enumerate() {
+            if let Some(row) = values.iter().position(|value: &u64| *value >= schema.field_modulus) {
+                return Err(TraceDumpError::ValueOutOfField { column, row });
+            }
+        }
+
+        Ok(Self {
+            field_modulus: schema.field_modulus,
+            columns,
+        })
+    }
+
+    /// Write the trace to a file
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::to_bytes`], or [`TraceDumpError::Io`] if the file cannot be written
+    pub fn save_to_file(&self, path: impl AsRef<Path>, compression: TraceCompression) -> Result<(), TraceDumpError> {
+        std::fs::write(path, self.to_bytes(compression)?)?;
+        Ok(())
+    }
+
+    /// Read a trace from a file
+    ///
+    /// # Errors
+    ///
+    /// [`TraceDumpError::Io`] if the file cannot be read, otherwise as [`Self::from_bytes`]
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, TraceDumpError> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    fn check_field(&self, expected: u64) -> Result<(), TraceDumpError> {
+        if self.field_modulus != expected {
+            return Err(TraceDumpError::FieldMismatch {
+                expected,
+                found: self.field_modulus,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Read the schema from a trace dump header without decoding the payload
+///
+/// # Errors
+///
+/// [`TraceDumpError::BadMagic`], [`TraceDumpError::Truncated`],
+/// [`TraceDumpError::UnsupportedVersion`] or [`TraceDumpError::UnknownCompression`] if the
+/// header is not one this version writes
+pub fn read_schema(bytes: &[u8]) -> Result<TraceSchema, TraceDumpError> {
+    if bytes.len() < TRACE_DUMP_MAGIC.len() || bytes[..8] != TRACE_DUMP_MAGIC {
+        return Err(TraceDumpError::BadMagic);
+    }
+    let header = bytes.first_chunk::<HEADER_LEN>().ok_or(TraceDumpError::Truncated)?;
+
+    let version = u16::from_le_bytes(header_field(header, 8));
+    if version != TRACE_DUMP_VERSION {
+        return Err(TraceDumpError::UnsupportedVersion(version));
+    }
+
+    Ok(TraceSchema {
+        compression: TraceCompression::from_byte(header[10])?,
+        field_modulus: u64::from_le_bytes(header_field(header, 12)),
+        width: u32::from_le_bytes(header_field(header, 20)) as usize,
+        length: u32::from_le_bytes(header_field(header, 24)) as usize,
+    })
+}
+
+/// Header field of `N` bytes starting at `offset`
+fn header_field<const N: usize>(header: &[u8; HEADER_LEN], offset: usize) -> [u8; N] {
+    let mut field = [0u8; N];
+    field.copy_from_slice(&header[offset..offset + N]);
+    field
+}
+
+#[cfg(feature = "debug")]
+fn compress(payload: Vec<u8>, compression: TraceCompression) -> Result<Vec<u8>, TraceDumpError> {
+    match compression {
+        TraceCompression::None | TraceCompression::Columns => Ok(payload),
+        TraceCompression::Zstd => Ok(zstd::bulk::compress(&payload, 0)?),
+    }
+}
+
+#[cfg(not(feature = "debug"))]
+fn compress(payload: Vec<u8>, compression: TraceCompression) -> Result<Vec<u8>, TraceDumpError> {
+    match compression {
+        TraceCompression::None | TraceCompression::Columns => Ok(payload),
+        TraceCompression::Zstd => Err(TraceDumpError::CompressionUnavailable),
+    }
+}
+
+#[cfg(feature = "debug")]
+fn decompress(payload: &[u8], compression: TraceCompression) -> Result<Vec<u8>, TraceDumpError> {
+    match compression {
+        TraceCompression::None | TraceCompression::Columns => Ok(payload.to_vec()),
+        TraceCompression::Zstd => Ok(zstd::stream::decode_all(payload)?),
+    }
+}
+
+#[cfg(not(feature = "debug"))]
+fn decompress(payload: &[u8], compression: TraceCompression) -> Result<Vec<u8>, TraceDumpError> {
+    match compression {
+        TraceCompression::None | TraceCompression::Columns => Ok(payload.to_vec()),
+        TraceCompression::Zstd => Err(TraceDumpError::CompressionUnavailable),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::field::PrimeField64;
+
+    fn sample_trace() -> TraceTable<BaseElement> {
+        TraceTable::init(vec![
+            (0..16u64).map(BaseElement::new).collect(),
+            (0..16u64).map(|i| BaseElement::new(BaseElement::MODULUS - 1 - i)).collect(),
+        ])
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let dump = TraceDump::from_trace(&sample_trace());
+        let bytes = dump.to_bytes(TraceCompression::None).unwrap();
+        assert_eq!(bytes.len(), HEADER_LEN + 2 * 16 * 8);
+        assert_eq!(
+            read_schema(&bytes).unwrap(),
+            TraceSchema {
+                field_modulus: BaseElement::MODULUS,
+                width: 2,
+                length: 16,
+                compression: TraceCompression::None,
+            }
+        );
+
+        let loaded = TraceDump::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded, dump);
+        assert_eq!(TraceDump::from_trace(&loaded.to_trace_table().unwrap()), dump);
+
+        // PrimeField64 is Winterfell's field, so prover traces reload as execution traces
+        let execution: ExecutionTrace<PrimeField64> = loaded.to_execution_trace().unwrap();
+        assert_eq!(execution.columns[1][0].value(), BaseElement::MODULUS - 1);
+
+        // Traces reload only into the field they were captured from
+        let foreign = TraceDump {
+            field_modulus: (1 << 61) - 1,
+            ..loaded.clone()
+        };
+        assert!(matches!(
+            foreign.to_execution_trace::<PrimeField64>(),
+            Err(TraceDumpError::FieldMismatch { .. })
+        ));
+        let custom = ExecutionTrace {
+            columns: vec![(1..=4u64).map(PrimeField64::new).collect()],
+            length: 4,
+            num_registers: 1,
+        };
+        let reloaded: ExecutionTrace<PrimeField64> = TraceDump::from_execution_trace(&custom)
+            .to_execution_trace()
+            .unwrap();
+        assert_eq!(reloaded.columns, custom.columns);
+    }
+
+    #[test]
+    fn test_rejects_malformed_dumps() {
+        let bytes = TraceDump::from_trace(&sample_trace())
+            .to_bytes(TraceCompression::None)
+            .unwrap();
+
+        assert!(matches!(TraceDump::from_bytes(b"NOTATRACE"), Err(TraceDumpError::BadMagic)));
+        assert!(matches!(
+            TraceDump::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(TraceDumpError::Truncated)
+        ));
+
+        let mut out_of_field = bytes.clone();
+        out_of_field[HEADER_LEN..HEADER_LEN + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(matches!(
+            TraceDump::from_bytes(&out_of_field),
+            Err(TraceDumpError::ValueOutOfField { column: 0, row: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_column_round_trip() {
+        let trace = TraceTable::init(vec![
+            vec![BaseElement::new(BaseElement::MODULUS - 1); 16],
+            (0..16u64).map(|i| BaseElement::new(i / 8)).collect(),
+        ]);
+        let dump = TraceDump::from_trace(&trace);
+        let bytes = dump.to_bytes(TraceCompression::Columns).unwrap();
+        assert_eq!(bytes.len(), HEADER_LEN + (1 + 8) + (1 + 4 + 2 * 12));
+        assert_eq!(read_schema(&bytes).unwrap().compression, TraceCompression::Columns);
+        assert_eq!(TraceDump::from_bytes(&bytes).unwrap(), dump);
+
+        assert!(matches!(
+            TraceDump::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(TraceDumpError::Truncated)
+        ));
+    }
+
+    #[cfg(feature = "debug")]
+    #[test]
+    fn test_zstd_round_trip() {
+        let dump = TraceDump::from_trace(&sample_trace());
+        let bytes = dump.to_bytes(TraceCompression::Zstd).unwrap();
+        assert!(bytes.len() < HEADER_LEN + 2 * 16 * 8);
+        assert_eq!(TraceDump::from_bytes(&bytes).unwrap(), dump);
+    }
+}
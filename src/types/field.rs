@@ -6,10 +6,12 @@
 
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Neg};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use super::{FieldElement, TypeError};
 use crate::Result;
 
+pub mod batch;
+
 /// Field arithmetic error
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum FieldError {
@@ -31,20 +33,34 @@ pub enum FieldError {
 }
 
 /// Prime field with 64-bit modulus
+///
+/// Values are kept canonical (below the modulus). The layout is a bare `u64`, which lets
+/// the [`batch`] kernels load slices of elements straight into vector registers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[repr(transparent)]
 pub struct PrimeField64 {
     /// Field element value
     value: u64,
 }
 
 impl PrimeField64 {
-    /// Field modulus (using a smaller prime for u64 compatibility)
-    pub const MODULUS: u64 = 0x7fffffffffffffff; // 2^63 - 1 (Mersenne prime)
+    /// Field modulus: the "Goldilocks" prime 2^64 - 2^32 + 1, also used by Winterfell's f64 field
+    pub const MODULUS: u64 = 0xffff_ffff_0000_0001;
+
+    /// Largest `k` with `2^k` dividing `MODULUS - 1`
+    pub const TWO_ADICITY: u32 = 32;
+
+    /// Generator of the multiplicative group
+    pub const GENERATOR: u64 = 7;
+
+    /// `2^64 mod MODULUS`, i.e. `2^32 - 1`
+    const EPSILON: u64 = 0xffff_ffff;
     
     /// Create a new field element
     pub fn new(value: u64) -> Self {
+        // 2 * MODULUS exceeds 2^64, so one conditional subtraction is enough
         Self {
-            value: value % Self::MODULUS,
+            value: canonicalize(value),
         }
     }
     
@@ -54,60 +70,50 @@ impl PrimeField64 {
     }
     
     /// Constant-time addition
+    ///
+    /// An overflow past `2^64` is folded back in as `EPSILON`, since `2^64 ≡ 2^32 - 1`.
     pub fn add_constant_time(&self, other: &Self) -> Self {
-        let sum = self.value + other.value;
-        if sum >= Self::MODULUS {
-            Self::new(sum - Self::MODULUS)
-        } else {
-            Self::new(sum)
+        let (sum, overflow) = self.value.overflowing_add(other.value);
+        Self {
+            value: canonicalize(sum.wrapping_add(Self::EPSILON * u64::from(overflow))),
         }
     }
     
     /// Constant-time subtraction
     pub fn sub_constant_time(&self, other: &Self) -> Self {
-        if self.value >= other.value {
-            Self::new(self.value - other.value)
-        } else {
-            Self::new(Self::MODULUS - (other.value - self.value))
+        // A borrow wraps by 2^64, which is EPSILON more than the modulus; the result is
+        // then `self - other + MODULUS`, already canonical
+        let (difference, borrow) = self.value.overflowing_sub(other.value);
+        Self {
+            value: difference.wrapping_sub(Self::EPSILON * u64::from(borrow)),
         }
     }
     
     /// Constant-time multiplication
     pub fn mul_constant_time(&self, other: &Self) -> Self {
-        let product = (self.value as u128) * (other.value as u128);
-        Self::new((product % Self::MODULUS as u128) as u64)
+        Self {
+            value: reduce128(self.value.carrying_mul(other.value, 0)),
+        }
     }
     
-    /// Modular inverse using extended Euclidean algorithm
+    /// Modular inverse by Fermat's little theorem
     pub fn inverse(&self) -> Option<Self> {
         if self.value == 0 {
             return None;
         }
         
-        let mut a = self.value as i64;
-        let mut b = Self::MODULUS as i64;
-        let mut x = 1i64;
-        let mut y = 0i64;
-        
-        while b != 0 {
-            let q = a / b;
-            let temp = b;
-            b = a % b;
-            a = temp;
-            let temp = y;
-            y = x - q * y;
-            x = temp;
-        }
-        
-        if a != 1 {
+        Some(self.pow(Self::MODULUS - 2))
+    }
+
+    /// Primitive `2^log_n`-th root of unity, if the field has one
+    #[must_use]
+    pub fn root_of_unity(log_n: u32) -> Option<Self> {
+        if log_n > Self::TWO_ADICITY {
             return None;
         }
         
-        if x < 0 {
-            x += Self::MODULUS as i64;
-        }
-        
-        Some(Self::new(x as u64))
+        let two_adic_root = Self::new(Self::GENERATOR).pow((Self::MODULUS - 1) >> Self::TWO_ADICITY);
+        Some(two_adic_root.pow(1 << (Self::TWO_ADICITY - log_n)))
     }
     
     /// Modular exponentiation
@@ -210,6 +216,33 @@ impl PrimeField64 {
     }
 }
 
+/// Map a `u64` below `2 * MODULUS` to its canonical representative
+#[inline]
+fn canonicalize(value: u64) -> u64 {
+    value.wrapping_sub(PrimeField64::MODULUS * u64::from(value >= PrimeField64::MODULUS))
+}
+
+/// Reduce a 128-bit product, given as its low and high words, modulo the Goldilocks
+/// prime without division
+///
+/// Writing `x = lo + 2^64 (hi_lo + 2^32 hi_hi)`, the identities `2^64 ≡ 2^32 - 1` and
+/// `2^96 ≡ -1` give `x ≡ lo - hi_hi + (2^32 - 1) hi_lo`.
+#[inline]
+fn reduce128((lo, hi): (u64, u64)) -> u64 {
+    let (hi_hi, hi_lo) = (hi >> 32, hi & PrimeField64::EPSILON);
+
+    // On borrow `t0` sits 2^64 ≡ EPSILON too high, and is at least 2^64 - 2^32, so the
+    // correction cannot underflow
+    let (t0, borrow) = lo.overflowing_sub(hi_hi);
+    let t0 = t0.wrapping_sub(PrimeField64::EPSILON * u64::from(borrow));
+
+    // `hi_lo * EPSILON` fits in 64 bits; on carry the wrapped sum is below it, so adding
+    // EPSILON back cannot overflow again
+    let t1 = (hi_lo << 32) - hi_lo;
+    let (t2, carry) = t0.overflowing_add(t1);
+    canonicalize(t2.wrapping_add(PrimeField64::EPSILON * u64::from(carry)))
+}
+
 impl FieldElement for PrimeField64 {
     const MODULUS: u64 = Self::MODULUS;
     const CHARACTERISTIC: u64 = Self::MODULUS;
@@ -342,6 +375,442 @@ impl Default for PrimeField64 {
     }
 }
 
+/// Prime field with an irreducible polynomial of degree `D` to extend it by
+///
+/// As in Winterfell, extension elements are polynomials of degree below `D` in a root `φ`
+/// of the irreducible polynomial, reduced with `φ^D = Σ REDUCTION[i] · φ^i`.
+pub trait ExtensibleField<const D: usize>: FieldElement {
+    /// Coefficients of `φ^D` over `1, φ, ..., φ^(D-1)`
+    const REDUCTION: [u64; D];
+}
+
+/// `x^2 - x + 2`, Winterfell's quadratic extension of the Goldilocks field
+impl ExtensibleField<2> for PrimeField64 {
+    const REDUCTION: [u64; 2] = [Self::MODULUS - 2, 1];
+}
+
+/// `x^3 - x - 1`, Winterfell's cubic extension of the Goldilocks field
+impl ExtensibleField<3> for PrimeField64 {
+    const REDUCTION: [u64; 3] = [1, 1, 0];
+}
+
+/// Element of the degree-`D` extension of the prime field `F`
+///
+/// Lifting composition and FRI into an extension raises the field's contribution to
+/// soundness from about 64 bits to about `64 · D`. [`FieldElement::value`] is the
+/// prime field coordinate; [`FieldElement::to_base_values`] gives all `D`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExtensionField<F, const D: usize> {
+    /// Coefficients of `1, φ, ..., φ^(D-1)`
+    coefficients: [F; D],
+}
+
+/// Element of the quadratic extension of `F`
+pub type QuadExtension<F> = ExtensionField<F, 2>;
+
+/// Element of the cubic extension of `F`
+pub type CubeExtension<F> = ExtensionField<F, 3>;
+
+impl<F: ExtensibleField<D>, const D: usize> ExtensionField<F, D> {
+    /// Element with coefficients of `1, φ, ..., φ^(D-1)`
+    pub fn from_coefficients(coefficients: [F; D]) -> Self {
+        Self { coefficients }
+    }
+
+    /// Coefficients of `1, φ, ..., φ^(D-1)`
+    pub fn coefficients(&self) -> [F; D] {
+        self.coefficients
+    }
+
+    /// Embed a prime field element
+    pub fn from_base(element: F) -> Self {
+        let mut coefficients = [F::zero(); D];
+        coefficients[0] = element;
+        Self { coefficients }
+    }
+
+    /// The prime field element this is, if it lies in the prime field
+    pub fn to_base(&self) -> Option<F> {
+        self.coefficients[1..].iter().all(FieldElement::is_zero).then_some(self.coefficients[0])
+    }
+
+    /// Product of two polynomials in `φ`, reduced to degree below `D`
+    fn multiply(&self, other: &Self) -> Self {
+        let mut product = vec![F::zero(); 2 * D - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in other.coefficients.iter().enumerate() {
+                product[i + j] += a * b;
+            }
+        }
+
+        // Replace φ^k, k >= D, from the top down
+        let reduction = F::REDUCTION.map(F::new);
+        for k in (D..2 * D - 1).rev() {
+            let top = product[k];
+            for (i, &r) in reduction.iter().enumerate() {
+                product[k - D + i] += top * r;
+            }
+        }
+
+        let mut coefficients = [F::zero(); D];
+        coefficients.copy_from_slice(&product[..D]);
+        Self { coefficients }
+    }
+
+    /// Inverse by solving `self · v = 1` over the prime field
+    fn invert(&self) -> Option<Self> {
+        // Column j of the system is self · φ^j
+        let mut phi = Self::zero();
+        phi.coefficients[1 % D] = F::one();
+        let mut rows = vec![vec![F::zero(); D + 1]; D];
+        let mut power = Self::one();
+        for j in 0..D {
+            let column = self.multiply(&power).coefficients;
+            for (row, &value) in rows.iter_mut().zip(column.iter()) {
+                row[j] = value;
+            }
+            power = power.multiply(&phi);
+        }
+        rows[0][D] = F::one();
+
+        // Gauss-Jordan elimination; a zero pivot column means self is zero
+        for col in 0..D {
+            let pivot = (col..D).find(|&r| !rows[r][col].is_zero())?;
+            rows.swap(col, pivot);
+            let scale = rows[col][col].inverse()?;
+            let pivot_row: Vec<F> = rows[col].iter().map(|&value| value * scale).collect();
+            for (r, row) in rows.iter_mut().enumerate() {
+                let factor = row[col];
+                if r != col && !factor.is_zero() {
+                    for (value, &pivot_value) in row.iter_mut().zip(&pivot_row) {
+                        *value -= pivot_value * factor;
+                    }
+                }
+            }
+            rows[col] = pivot_row;
+        }
+
+        let mut coefficients = [F::zero(); D];
+        for (coefficient, row) in coefficients.iter_mut().zip(&rows) {
+            *coefficient = row[D];
+        }
+        Some(Self { coefficients })
+    }
+
+    /// `self^exponent` for a little-endian multi-limb exponent
+    fn pow_limbs(&self, exponent: &[u64]) -> Self {
+        let mut result = Self::one();
+        for &limb in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result.multiply(&result);
+                if (limb >> bit) & 1 == 1 {
+                    result = result.multiply(self);
+                }
+            }
+        }
+        result
+    }
+
+    /// Tonelli-Shanks over the extension, whose order `p^D` does not fit a `u64`
+    fn square_root(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(*self);
+        }
+
+        // order - 1 = 2^two_adicity · odd_part with odd_part odd
+        let mut order_minus_one = vec![1u64];
+        for _ in 0..D {
+            order_minus_one = limbs_mul(&order_minus_one, F::MODULUS);
+        }
+        limbs_sub_one(&mut order_minus_one);
+        let half = limbs_shr(&order_minus_one, 1);
+        if self.pow_limbs(&half) != Self::one() {
+            return None;
+        }
+        let two_adicity = limbs_trailing_zeros(&order_minus_one);
+        let odd_part = limbs_shr(&order_minus_one, two_adicity);
+
+        // Half of the elements k + φ are non-residues
+        let minus_one = -Self::one();
+        let candidates = (1..u64::MAX).map(|k| {
+            let mut candidate = Self::from_base(F::new(k));
+            candidate.coefficients[D - 1] += F::one();
+            candidate
+        });
+        let non_residue = candidates.into_iter().find(|candidate| candidate.pow_limbs(&half) == minus_one)?;
+
+        let mut m = two_adicity;
+        let mut c = non_residue.pow_limbs(&odd_part);
+        let mut residue = self.pow_limbs(&odd_part);
+        let mut root = self.pow_limbs(&limbs_shr(&limbs_add_one(&odd_part), 1));
+        while residue != Self::one() {
+            let mut i = 0;
+            let mut probe = residue;
+            while probe != Self::one() {
+                probe = probe.multiply(&probe);
+                i += 1;
+            }
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b = b.multiply(&b);
+            }
+            m = i;
+            c = b.multiply(&b);
+            residue = residue.multiply(&c);
+            root = root.multiply(&b);
+        }
+        Some(root)
+    }
+}
+
+/// `limbs · factor`
+fn limbs_mul(limbs: &[u64], factor: u64) -> Vec<u64> {
+    let mut product = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0u64;
+    for &limb in limbs {
+        let (low, high) = limb.carrying_mul(factor, carry);
+        product.push(low);
+        carry = high;
+    }
+    if carry != 0 {
+        product.push(carry);
+    }
+    product
+}
+
+/// `limbs -= 1` for nonzero `limbs`
+fn limbs_sub_one(limbs: &mut [u64]) {
+    for limb in limbs.iter_mut() {
+        let (value, borrow) = limb.overflowing_sub(1);
+        *limb = value;
+        if !borrow {
+            break;
+        }
+    }
+}
+
+/// `limbs + 1`
+fn limbs_add_one(limbs: &[u64]) -> Vec<u64> {
+    let mut sum = limbs.to_vec();
+    for limb in &mut sum {
+        let (value, carry) = limb.overflowing_add(1);
+        *limb = value;
+        if !carry {
+            return sum;
+        }
+    }
+    sum.push(1);
+    sum
+}
+
+/// `limbs >> shift`
+fn limbs_shr(limbs: &[u64], shift: u32) -> Vec<u64> {
+    let (words, bits) = ((shift / 64) as usize, shift % 64);
+    (words..limbs.len())
+        .map(|i| {
+            let high = limbs.get(i + 1).copied().unwrap_or(0);
+            if bits == 0 {
+                limbs[i]
+            } else {
+                (limbs[i] >> bits) | (high << (64 - bits))
+            }
+        })
+        .collect()
+}
+
+/// Trailing zero bits of nonzero `limbs`
+fn limbs_trailing_zeros(limbs: &[u64]) -> u32 {
+    let mut zeros = 0;
+    for limb in limbs {
+        if *limb != 0 {
+            return zeros + limb.trailing_zeros();
+        }
+        zeros += u64::BITS;
+    }
+    zeros
+}
+
+impl<F: ExtensibleField<D>, const D: usize> FieldElement for ExtensionField<F, D> {
+    const MODULUS: u64 = F::MODULUS;
+    const CHARACTERISTIC: u64 = F::CHARACTERISTIC;
+    const EXTENSION_DEGREE: usize = D;
+
+    fn zero() -> Self {
+        Self { coefficients: [F::zero(); D] }
+    }
+
+    fn one() -> Self {
+        Self::from_base(F::one())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficients.iter().all(FieldElement::is_zero)
+    }
+
+    fn is_one(&self) -> bool {
+        *self == Self::one()
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        *self += *other;
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        *self -= *other;
+    }
+
+    fn mul_assign(&mut self, other: &Self) {
+        *self = self.multiply(other);
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        self.invert()
+    }
+
+    fn pow(&self, exponent: u64) -> Self {
+        self.pow_limbs(&[exponent])
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        self.square_root()
+    }
+
+    /// Coefficient `i` in the 8 bytes ending `8 · i` bytes before the end, so prime
+    /// field elements keep their encoding
+    fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, coefficient) in self.coefficients.iter().enumerate() {
+            let end = 32 - 8 * i;
+            bytes[end - 8..end].copy_from_slice(&coefficient.to_bytes()[24..]);
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let mut coefficients = [F::zero(); D];
+        for (i, coefficient) in coefficients.iter_mut().enumerate() {
+            let end = 32 - 8 * i;
+            let mut slot = [0u8; 32];
+            slot[24..].copy_from_slice(&bytes[end - 8..end]);
+            *coefficient = F::from_bytes(&slot)?;
+        }
+        Some(Self { coefficients })
+    }
+
+    fn value(&self) -> u64 {
+        self.coefficients[0].value()
+    }
+
+    fn new(value: u64) -> Self {
+        Self::from_base(F::new(value))
+    }
+
+    fn random() -> Self {
+        Self { coefficients: std::array::from_fn(|_| F::random()) }
+    }
+
+    fn to_base_values(&self) -> Vec<u64> {
+        self.coefficients.iter().map(FieldElement::value).collect()
+    }
+
+    fn from_base_values(values: &[u64]) -> Option<Self> {
+        if values.len() != D || values.iter().any(|&value| value >= F::MODULUS) {
+            return None;
+        }
+        Some(Self { coefficients: std::array::from_fn(|i| F::new(values[i])) })
+    }
+}
+
+impl<F: ExtensibleField<D>, const D: usize> From<F> for ExtensionField<F, D> {
+    fn from(element: F) -> Self {
+        Self::from_base(element)
+    }
+}
+
+impl<F: ExtensibleField<D>, const D: usize> Add for ExtensionField<F, D> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self { coefficients: std::array::from_fn(|i| self.coefficients[i] + other.coefficients[i]) }
+    }
+}
+
+impl<F: ExtensibleField<D>, const D: usize> AddAssign for ExtensionField<F, D> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<F: ExtensibleField<D>, const D: usize> Sub for ExtensionField<F, D> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self { coefficients: std::array::from_fn(|i| self.coefficients[i] - other.coefficients[i]) }
+    }
+}
+
+impl<F: ExtensibleField<D>, const D: usize> SubAssign for ExtensionField<F, D> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<F: ExtensibleField<D>, const D: usize> Mul for ExtensionField<F, D> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.multiply(&other)
+    }
+}
+
+impl<F: ExtensibleField<D>, const D: usize> MulAssign for ExtensionField<F, D> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.multiply(&other);
+    }
+}
+
+impl<F: ExtensibleField<D>, const D: usize> Neg for ExtensionField<F, D> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { coefficients: self.coefficients.map(|c| -c) }
+    }
+}
+
+impl<F: ExtensibleField<D>, const D: usize> Display for ExtensionField<F, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match D {
+            2 => "QuadExtension",
+            3 => "CubeExtension",
+            _ => "ExtensionField",
+        };
+        let coefficients: Vec<String> = self.coefficients.iter().map(|c| c.value().to_string()).collect();
+        write!(f, "{}({})", name, coefficients.join(", "))
+    }
+}
+
+impl<F: ExtensibleField<D>, const D: usize> Default for ExtensionField<F, D> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: Serialize, const D: usize> Serialize for ExtensionField<F, D> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.coefficients)
+    }
+}
+
+impl<'de, F: Deserialize<'de>, const D: usize> Deserialize<'de> for ExtensionField<F, D> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> std::result::Result<Self, De::Error> {
+        let coefficients: Vec<F> = Vec::deserialize(deserializer)?;
+        let coefficients: [F; D] = coefficients
+            .try_into()
+            .map_err(|c: Vec<F>| serde::de::Error::invalid_length(c.len(), &"one coefficient per extension degree"))?;
+        Ok(Self { coefficients })
+    }
+}
+
 /// Binary field element for characteristic 2 fields
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct BinaryField {
@@ -462,6 +931,50 @@ mod tests {
         assert_eq!(a.add_constant_time(&b), BinaryField::new(0b110, 8));
     }
 
+    #[test]
+    fn test_extensions_match_winterfell() {
+        use winter_math::fields::f64::BaseElement;
+        use winter_math::fields::{CubeExtension as WinterCube, QuadExtension as WinterQuad};
+
+        let quad = |a: u64, b: u64| QuadExtension::from_coefficients([PrimeField64::new(a), PrimeField64::new(b)]);
+        let (x, y) = (quad(3, PrimeField64::MODULUS - 5), quad(11, 7));
+        let winter = WinterQuad::new(BaseElement::new(3), BaseElement::new(PrimeField64::MODULUS - 5))
+            * WinterQuad::new(BaseElement::new(11), BaseElement::new(7));
+        let expected: Vec<u64> = winter.to_base_elements().iter().map(|e| e.as_int()).collect();
+        assert_eq!((x * y).to_base_values(), expected);
+
+        let cube = |a: u64, b: u64, c: u64| {
+            CubeExtension::from_coefficients([PrimeField64::new(a), PrimeField64::new(b), PrimeField64::new(c)])
+        };
+        let winter = WinterCube::new(BaseElement::new(2), BaseElement::new(9), BaseElement::new(4))
+            * WinterCube::new(BaseElement::new(6), BaseElement::new(1), BaseElement::new(8));
+        let expected: Vec<u64> = winter.to_base_elements().iter().map(|e| e.as_int()).collect();
+        assert_eq!((cube(2, 9, 4) * cube(6, 1, 8)).to_base_values(), expected);
+    }
+
+    #[test]
+    fn test_extension_field_element() {
+        let x = QuadExtension::from_coefficients([PrimeField64::new(12345), PrimeField64::new(678)]);
+        assert_eq!(x * x.inverse().unwrap(), QuadExtension::one());
+        assert_eq!(QuadExtension::<PrimeField64>::zero().inverse(), None);
+        let root = (x * x).sqrt().unwrap();
+        assert!(root == x || root == -x);
+
+        let y = CubeExtension::from_coefficients([PrimeField64::new(5), PrimeField64::new(0), PrimeField64::new(9)]);
+        assert_eq!(y * y.inverse().unwrap(), CubeExtension::one());
+        assert_eq!(y.pow(3), y * y * y);
+        let root = (y * y).sqrt().unwrap();
+        assert!(root == y || root == -y);
+
+        // Prime field elements embed with their encoding and value unchanged
+        let base = PrimeField64::new(42);
+        assert_eq!(QuadExtension::from(base).to_bytes(), base.to_bytes());
+        assert_eq!(QuadExtension::from(base).to_base(), Some(base));
+        assert_eq!(CubeExtension::<PrimeField64>::from_bytes(&y.to_bytes()), Some(y));
+        assert_eq!(CubeExtension::<PrimeField64>::from_base_values(&y.to_base_values()), Some(y));
+        assert_eq!(CubeExtension::<PrimeField64>::from_base_values(&[1, 2]), None);
+    }
+
     #[test]
     fn test_constant_time_operations() {
         let a = PrimeField64::new(10);
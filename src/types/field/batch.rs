@@ -0,0 +1,300 @@
+//! Batch Field Arithmetic
+//!
+//! Element-wise slice operations on [`PrimeField64`]. With the `simd` feature,
+//! [`add_slice`] and [`mul_slice`] process four lanes at a time with AVX2 on `x86_64`
+//! (detected at runtime) and two lanes with NEON on aarch64. Tails shorter than a vector,
+//! CPUs without AVX2 and other targets use the scalar Goldilocks arithmetic, and every
+//! path produces the same canonical values.
+
+use super::PrimeField64;
+use crate::types::FieldElement;
+
+/// Add `other` into `values` element-wise
+///
+/// # Panics
+///
+/// Panics if the slices differ in length.
+pub fn add_slice(values: &mut [PrimeField64], other: &[PrimeField64]) {
+    assert_eq!(values.len(), other.len(), "batch operands differ in length");
+    let done = simd::add(values, other);
+    for (value, other) in values[done..].iter_mut().zip(&other[done..]) {
+        *value = value.add_constant_time(other);
+    }
+}
+
+/// Multiply `values` by `other` element-wise
+///
+/// # Panics
+///
+/// Panics if the slices differ in length.
+pub fn mul_slice(values: &mut [PrimeField64], other: &[PrimeField64]) {
+    assert_eq!(values.len(), other.len(), "batch operands differ in length");
+    let done = simd::mul(values, other);
+    for (value, other) in values[done..].iter_mut().zip(&other[done..]) {
+        *value = value.mul_constant_time(other);
+    }
+}
+
+/// Invert every element with a single field inversion
+///
+/// Same as [`FieldElement::batch_inverse`]; zeros map to zero.
+#[must_use]
+pub fn batch_inverse(values: &[PrimeField64]) -> Vec<PrimeField64> {
+    PrimeField64::batch_inverse(values)
+}
+
+/// Vector kernels; each returns how many leading elements it processed
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd {
+    use super::PrimeField64;
+
+    pub(super) fn add(values: &mut [PrimeField64], other: &[PrimeField64]) -> usize {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            avx2::add(values, other)
+        } else {
+            0
+        }
+    }
+
+    pub(super) fn mul(values: &mut [PrimeField64], other: &[PrimeField64]) -> usize {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            avx2::mul(values, other)
+        } else {
+            0
+        }
+    }
+
+    /// Four `u64` lanes per `__m256i`
+    ///
+    /// AVX2 has no unsigned 64-bit comparison, so comparisons flip the sign bit of both
+    /// operands and use the signed one.
+    #[allow(unsafe_code)]
+    mod avx2 {
+        use super::PrimeField64;
+        use std::arch::x86_64::{
+            __m256i, _mm256_add_epi64, _mm256_and_si256, _mm256_andnot_si256, _mm256_cmpgt_epi64,
+            _mm256_loadu_si256, _mm256_mul_epu32, _mm256_or_si256, _mm256_set1_epi64x, _mm256_slli_epi64,
+            _mm256_srli_epi64, _mm256_storeu_si256, _mm256_sub_epi64, _mm256_xor_si256,
+        };
+
+        const LANES: usize = 4;
+        const EPSILON: i64 = 0xffff_ffff;
+
+        pub(super) fn add(values: &mut [PrimeField64], other: &[PrimeField64]) -> usize {
+            let done = values.len() - values.len() % LANES;
+            // SAFETY: the caller checked AVX2 support, chunks are exactly LANES elements
+            // and PrimeField64 is a transparent u64, so each chunk is one unaligned __m256i
+            unsafe {
+                for (a, b) in values[..done].chunks_exact_mut(LANES).zip(other.chunks_exact(LANES)) {
+                    let a_ptr: *mut __m256i = a.as_mut_ptr().cast();
+                    let sum = add_lanes(_mm256_loadu_si256(a_ptr), _mm256_loadu_si256(b.as_ptr().cast()));
+                    _mm256_storeu_si256(a_ptr, sum);
+                }
+            }
+            done
+        }
+
+        pub(super) fn mul(values: &mut [PrimeField64], other: &[PrimeField64]) -> usize {
+            let done = values.len() - values.len() % LANES;
+            // SAFETY: as in `add`
+            unsafe {
+                for (a, b) in values[..done].chunks_exact_mut(LANES).zip(other.chunks_exact(LANES)) {
+                    let a_ptr: *mut __m256i = a.as_mut_ptr().cast();
+                    let product = mul_lanes(_mm256_loadu_si256(a_ptr), _mm256_loadu_si256(b.as_ptr().cast()));
+                    _mm256_storeu_si256(a_ptr, product);
+                }
+            }
+            done
+        }
+
+        /// All-ones lanes where `a < b` as unsigned integers
+        #[target_feature(enable = "avx2")]
+        unsafe fn less_than(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi64x(i64::MIN);
+            _mm256_cmpgt_epi64(_mm256_xor_si256(b, sign), _mm256_xor_si256(a, sign))
+        }
+
+        /// Reduce lanes below `2 * MODULUS` to canonical form
+        #[target_feature(enable = "avx2")]
+        unsafe fn canonicalize(x: __m256i) -> __m256i {
+            let modulus = _mm256_set1_epi64x(PrimeField64::MODULUS.cast_signed());
+            let below = less_than(x, modulus);
+            _mm256_sub_epi64(x, _mm256_andnot_si256(below, modulus))
+        }
+
+        #[target_feature(enable = "avx2")]
+        unsafe fn add_lanes(a: __m256i, b: __m256i) -> __m256i {
+            let sum = _mm256_add_epi64(a, b);
+            let overflow = less_than(sum, a);
+            canonicalize(_mm256_add_epi64(sum, _mm256_and_si256(overflow, _mm256_set1_epi64x(EPSILON))))
+        }
+
+        /// 64x64 -> 128-bit products from four 32x32 multiplies, then the Goldilocks reduction
+        #[target_feature(enable = "avx2")]
+        unsafe fn mul_lanes(a: __m256i, b: __m256i) -> __m256i {
+            let epsilon = _mm256_set1_epi64x(EPSILON);
+            let a_hi = _mm256_srli_epi64(a, 32);
+            let b_hi = _mm256_srli_epi64(b, 32);
+
+            let lo_lo = _mm256_mul_epu32(a, b);
+            let lo_hi = _mm256_mul_epu32(a, b_hi);
+            let hi_lo = _mm256_mul_epu32(a_hi, b);
+            let hi_hi = _mm256_mul_epu32(a_hi, b_hi);
+
+            // Carry the middle terms through 32-bit halves so no sum overflows
+            let t = _mm256_add_epi64(hi_lo, _mm256_srli_epi64(lo_lo, 32));
+            let u = _mm256_add_epi64(lo_hi, _mm256_and_si256(t, epsilon));
+            let lo = _mm256_or_si256(_mm256_slli_epi64(u, 32), _mm256_and_si256(lo_lo, epsilon));
+            let hi = _mm256_add_epi64(hi_hi, _mm256_add_epi64(_mm256_srli_epi64(t, 32), _mm256_srli_epi64(u, 32)));
+
+            // Same steps as the scalar `reduce128`
+            let hi_hi = _mm256_srli_epi64(hi, 32);
+            let hi_lo = _mm256_and_si256(hi, epsilon);
+            let borrow = less_than(lo, hi_hi);
+            let t0 = _mm256_sub_epi64(_mm256_sub_epi64(lo, hi_hi), _mm256_and_si256(borrow, epsilon));
+            let t1 = _mm256_sub_epi64(_mm256_slli_epi64(hi_lo, 32), hi_lo);
+            let t2 = _mm256_add_epi64(t0, t1);
+            let carry = less_than(t2, t1);
+            canonicalize(_mm256_add_epi64(t2, _mm256_and_si256(carry, epsilon)))
+        }
+    }
+}
+
+/// Vector kernels; each returns how many leading elements it processed
+///
+/// NEON is part of the aarch64 baseline, so no runtime detection is needed.
+#[cfg(all(feature = "simd", target_arch = "aarch64"))]
+#[allow(unsafe_code)]
+mod simd {
+    use super::PrimeField64;
+    use std::arch::aarch64::{
+        uint64x2_t, vaddq_u64, vandq_u64, vcgeq_u64, vcltq_u64, vdupq_n_u64, vld1q_u64, vmovn_u64,
+        vmull_u32, vorrq_u64, vshlq_n_u64, vshrn_n_u64, vshrq_n_u64, vst1q_u64, vsubq_u64,
+    };
+
+    const LANES: usize = 2;
+    const EPSILON: u64 = 0xffff_ffff;
+
+    pub(super) fn add(values: &mut [PrimeField64], other: &[PrimeField64]) -> usize {
+        let done = values.len() - values.len() % LANES;
+        // SAFETY: chunks are exactly LANES elements and PrimeField64 is a transparent u64
+        unsafe {
+            for (a, b) in values[..done].chunks_exact_mut(LANES).zip(other.chunks_exact(LANES)) {
+                let a_ptr = a.as_mut_ptr().cast::<u64>();
+                vst1q_u64(a_ptr, add_lanes(vld1q_u64(a_ptr), vld1q_u64(b.as_ptr().cast())));
+            }
+        }
+        done
+    }
+
+    pub(super) fn mul(values: &mut [PrimeField64], other: &[PrimeField64]) -> usize {
+        let done = values.len() - values.len() % LANES;
+        // SAFETY: as in `add`
+        unsafe {
+            for (a, b) in values[..done].chunks_exact_mut(LANES).zip(other.chunks_exact(LANES)) {
+                let a_ptr = a.as_mut_ptr().cast::<u64>();
+                vst1q_u64(a_ptr, mul_lanes(vld1q_u64(a_ptr), vld1q_u64(b.as_ptr().cast())));
+            }
+        }
+        done
+    }
+
+    /// Reduce lanes below `2 * MODULUS` to canonical form
+    unsafe fn canonicalize(x: uint64x2_t) -> uint64x2_t {
+        let modulus = vdupq_n_u64(PrimeField64::MODULUS);
+        vsubq_u64(x, vandq_u64(vcgeq_u64(x, modulus), modulus))
+    }
+
+    unsafe fn add_lanes(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        let sum = vaddq_u64(a, b);
+        let overflow = vcltq_u64(sum, a);
+        canonicalize(vaddq_u64(sum, vandq_u64(overflow, vdupq_n_u64(EPSILON))))
+    }
+
+    /// 64x64 -> 128-bit products from four 32x32 multiplies, then the Goldilocks reduction
+    unsafe fn mul_lanes(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        let epsilon = vdupq_n_u64(EPSILON);
+        let (a_lo, a_hi) = (vmovn_u64(a), vshrn_n_u64::<32>(a));
+        let (b_lo, b_hi) = (vmovn_u64(b), vshrn_n_u64::<32>(b));
+
+        let lo_lo = vmull_u32(a_lo, b_lo);
+        let lo_hi = vmull_u32(a_lo, b_hi);
+        let hi_lo = vmull_u32(a_hi, b_lo);
+        let hi_hi = vmull_u32(a_hi, b_hi);
+
+        // Carry the middle terms through 32-bit halves so no sum overflows
+        let t = vaddq_u64(hi_lo, vshrq_n_u64::<32>(lo_lo));
+        let u = vaddq_u64(lo_hi, vandq_u64(t, epsilon));
+        let lo = vorrq_u64(vshlq_n_u64::<32>(u), vandq_u64(lo_lo, epsilon));
+        let hi = vaddq_u64(hi_hi, vaddq_u64(vshrq_n_u64::<32>(t), vshrq_n_u64::<32>(u)));
+
+        // Same steps as the scalar `reduce128`
+        let hi_hi = vshrq_n_u64::<32>(hi);
+        let hi_lo = vandq_u64(hi, epsilon);
+        let borrow = vcltq_u64(lo, hi_hi);
+        let t0 = vsubq_u64(vsubq_u64(lo, hi_hi), vandq_u64(borrow, epsilon));
+        let t1 = vsubq_u64(vshlq_n_u64::<32>(hi_lo), hi_lo);
+        let t2 = vaddq_u64(t0, t1);
+        let carry = vcltq_u64(t2, t1);
+        canonicalize(vaddq_u64(t2, vandq_u64(carry, epsilon)))
+    }
+}
+
+/// Scalar-only builds process nothing in the vector pass
+#[cfg(not(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64"))))]
+mod simd {
+    use super::PrimeField64;
+
+    pub(super) fn add(_values: &mut [PrimeField64], _other: &[PrimeField64]) -> usize {
+        0
+    }
+
+    pub(super) fn mul(_values: &mut [PrimeField64], _other: &[PrimeField64]) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Values near the top of the field and of `u64` exercise every carry and borrow
+    fn operands(len: usize) -> (Vec<PrimeField64>, Vec<PrimeField64>) {
+        let edge = [0, 1, 2, 0xffffffff, 0x100000000, PrimeField64::MODULUS - 1, PrimeField64::MODULUS - 2, 0xfffffffe00000002];
+        let a = (0..len).map(|i| PrimeField64::new(edge[i % edge.len()] ^ (i as u64).wrapping_mul(0x9e3779b97f4a7c15))).collect();
+        let b = (0..len).map(|i| PrimeField64::new(edge[(i * 3 + 1) % edge.len()].wrapping_add(i as u64 * 0x1234567))).collect();
+        (a, b)
+    }
+
+    #[test]
+    fn test_slices_match_reference_arithmetic() {
+        let modulus = PrimeField64::MODULUS as u128;
+        let (a, b) = operands(67);
+
+        let mut sums = a.clone();
+        add_slice(&mut sums, &b);
+        let mut products = a.clone();
+        mul_slice(&mut products, &b);
+
+        for i in 0..a.len() {
+            let (x, y) = (a[i].value() as u128, b[i].value() as u128);
+            assert_eq!(sums[i].value() as u128, (x + y) % modulus);
+            assert_eq!(products[i].value() as u128, (x * y) % modulus);
+        }
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        let (mut values, _) = operands(33);
+        values[5] = PrimeField64::zero();
+        let inverses = batch_inverse(&values);
+
+        assert_eq!(inverses[5], PrimeField64::zero());
+        for (value, inverse) in values.iter().zip(&inverses) {
+            if !value.is_zero() {
+                assert_eq!(*value * *inverse, PrimeField64::one());
+            }
+        }
+        assert!(batch_inverse(&[]).is_empty());
+    }
+}
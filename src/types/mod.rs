@@ -46,7 +46,7 @@ pub enum TypeError {
 
 /// Core trait for field elements with cryptographic properties
 pub trait FieldElement: 
-    Copy + Clone + Debug + Display + PartialEq + Eq + PartialOrd + Ord +
+    Copy + Clone + Debug + Display + PartialEq + Eq + PartialOrd + Ord + Send + Sync +
     Add<Output = Self> + AddAssign + Sub<Output = Self> + SubAssign +
     Mul<Output = Self> + MulAssign + Neg<Output = Self> +
     Serialize + for<'de> Deserialize<'de>
@@ -56,6 +56,9 @@ pub trait FieldElement:
     
     /// The field characteristic (prime number)
     const CHARACTERISTIC: u64;
+
+    /// Degree of the field over its prime field `MODULUS`
+    const EXTENSION_DEGREE: usize = 1;
     
     /// Zero element in the field
     fn zero() -> Self;
@@ -80,6 +83,31 @@ pub trait FieldElement:
     
     /// Modular inverse (constant-time)
     fn inverse(&self) -> Option<Self>;
+
+    /// Invert every element with a single field inversion (Montgomery's trick)
+    ///
+    /// Zeros have no inverse and map to zero, so one zero does not poison the batch.
+    fn batch_inverse(values: &[Self]) -> Vec<Self> {
+        // prefix[i] is the product of the non-zero values before i
+        let mut prefix = Vec::with_capacity(values.len());
+        let mut product = Self::one();
+        for value in values {
+            prefix.push(product);
+            if !value.is_zero() {
+                product *= *value;
+            }
+        }
+
+        let mut inverse = product.inverse().expect("product of non-zero elements is non-zero");
+        let mut inverses = vec![Self::zero(); values.len()];
+        for (i, value) in values.iter().enumerate().rev() {
+            if !value.is_zero() {
+                inverses[i] = inverse * prefix[i];
+                inverse *= *value;
+            }
+        }
+        inverses
+    }
     
     /// Modular exponentiation (constant-time)
     fn pow(&self, exponent: u64) -> Self;
@@ -94,10 +122,29 @@ pub trait FieldElement:
     fn from_bytes(bytes: &[u8; 32]) -> Option<Self>;
     
     /// Get the raw value as u64
+    ///
+    /// For an extension field element this is only its prime field coordinate; use
+    /// [`Self::to_base_values`] for all of it.
     fn value(&self) -> u64;
     
     /// Create a new field element from a u64 value
     fn new(value: u64) -> Self;
+
+    /// Canonical coordinates over the prime field, `EXTENSION_DEGREE` of them
+    fn to_base_values(&self) -> Vec<u64> {
+        vec![self.value()]
+    }
+
+    /// Element from canonical coordinates over the prime field
+    ///
+    /// `None` unless there are `EXTENSION_DEGREE` values, each below `MODULUS`.
+    #[must_use]
+    fn from_base_values(values: &[u64]) -> Option<Self> {
+        match values {
+            [value] if *value < Self::MODULUS => Some(Self::new(*value)),
+            _ => None,
+        }
+    }
     
     /// Random field element
     fn random() -> Self;
@@ -298,4 +345,18 @@ mod tests {
         let ms_type = MemorySafe::new(value);
         assert_eq!(*ms_type.value(), value);
     }
+
+    #[test]
+    fn test_batch_inverse() {
+        let values: Vec<QuadExtension<PrimeField64>> = (0..9u64)
+            .map(|i| QuadExtension::from_coefficients([PrimeField64::new(i * 7), PrimeField64::new(i + 3)]))
+            .chain([QuadExtension::zero()])
+            .collect();
+        let inverses = QuadExtension::batch_inverse(&values);
+
+        for (value, inverse) in values.iter().zip(&inverses) {
+            assert_eq!(*inverse, value.inverse().unwrap_or(QuadExtension::zero()));
+        }
+        assert!(PrimeField64::batch_inverse(&[]).is_empty());
+    }
 }
@@ -27,6 +27,10 @@ pub enum PolynomialError {
     /// Invalid coefficient
     #[error("Invalid coefficient: {0}")]
     InvalidCoefficient(String),
+    
+    /// Evaluation domain is not a power of two the field supports
+    #[error("Invalid evaluation domain size: {0}")]
+    InvalidDomainSize(usize),
 }
 
 /// Polynomial with field element coefficients
@@ -179,29 +183,32 @@ impl<F: FieldElement> FieldPolynomial<F> {
         let mut result = Self::zero();
         let n = points.len();
         
+        let mut denominators = Vec::with_capacity(n);
         for i in 0..n {
-            let mut term = Self::constant(points[i].1);
             let mut denominator = F::one();
-            
             for j in 0..n {
                 if i != j {
                     let x_diff = points[i].0 - points[j].0;
                     if x_diff.is_zero() {
                         return None; // Duplicate x-coordinates
                     }
-                    
-                    let x_term = Self::new(vec![F::zero() - points[j].0, F::one()]);
-                    term = term.multiply(&x_term);
                     denominator = denominator * x_diff;
                 }
             }
-            
-            if let Some(inv_denom) = denominator.inverse() {
-                term = term.multiply(&Self::constant(inv_denom));
-                result = result.add(&term);
-            } else {
-                return None;
+            denominators.push(denominator);
+        }
+        let inverse_denominators = F::batch_inverse(&denominators);
+        
+        for (i, (&(_, y), &inverse)) in points.iter().zip(&inverse_denominators).enumerate() {
+            let mut term = Self::constant(y);
+            for (j, &(x, _)) in points.iter().enumerate() {
+                if i != j {
+                    let x_term = Self::new(vec![F::zero() - x, F::one()]);
+                    term = term.multiply(&x_term);
+                }
             }
+            term = term.multiply(&Self::constant(inverse));
+            result = result.add(&term);
         }
         
         Some(result)
@@ -3,10 +3,61 @@
 //! This module provides secure secret type implementations with zeroization capabilities,
 //! ensuring cryptographic secrets are properly managed and cleared from memory.
 
-use core::fmt::{Debug, Formatter};
+use core::fmt::{Debug, Display, Formatter};
 use serde::{Deserialize, Serialize};
 use super::{Secret, TypeError};
 use crate::Result;
+use winterfell::math::fields::f64::BaseElement;
+use zeroize::{DefaultIsZeroes, Zeroize, ZeroizeOnDrop};
+
+/// Placeholder formatted in place of secret material
+///
+/// Secret-bearing types use this in their `Debug` and `Display` implementations so
+/// logs show the size of a secret but never its value.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Redacted {
+    len: Option<usize>,
+}
+
+impl Redacted {
+    /// Redact a secret of known byte length
+    #[must_use]
+    pub const fn bytes(len: usize) -> Self {
+        Self { len: Some(len) }
+    }
+
+    /// Redact a secret whose size is not meaningful
+    #[must_use]
+    pub const fn opaque() -> Self {
+        Self { len: None }
+    }
+
+    /// Redact a hex-encoded secret, reporting its decoded length
+    #[must_use]
+    pub fn hex(value: &str) -> Self {
+        let digits = value.strip_prefix("0x").unwrap_or(value);
+        if digits.len().is_multiple_of(2) && digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            Self::bytes(digits.len() / 2)
+        } else {
+            Self::bytes(value.len())
+        }
+    }
+}
+
+impl Display for Redacted {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.len {
+            Some(len) => write!(f, "[REDACTED {len} bytes]"),
+            None => write!(f, "[REDACTED]"),
+        }
+    }
+}
+
+impl Debug for Redacted {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
 
 /// Secure secret wrapper with zeroization
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -109,7 +160,7 @@ impl Debug for SecureSecret {
         if self.zeroized {
             write!(f, "SecureSecret(***ZEROIZED***)")
         } else {
-            write!(f, "SecureSecret(***HIDDEN***, len={})", self.len())
+            write!(f, "SecureSecret({})", Redacted::bytes(self.len()))
         }
     }
 }
@@ -120,6 +171,114 @@ impl Drop for SecureSecret {
     }
 }
 
+/// Burn secret handed to a prover
+///
+/// The bytes are wiped with volatile writes when the key is dropped, so the compiler
+/// cannot elide the wipe, and the key is never serialized. Moving a `Vec` in with
+/// [`SecretKey::new`] avoids leaving a copy behind.
+#[derive(Clone)]
+pub struct SecretKey {
+    bytes: Vec<u8>,
+}
+
+impl SecretKey {
+    /// Take ownership of secret bytes
+    #[must_use]
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    /// Copy secret bytes; the caller remains responsible for wiping `bytes`
+    #[must_use]
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        Self::new(bytes.to_vec())
+    }
+
+    /// Borrow the secret bytes
+    #[must_use]
+    pub fn expose(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Length of the secret in bytes
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Check if the secret is empty, as it is once zeroized
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+impl From<Vec<u8>> for SecretKey {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        // Compare every byte so the time taken does not reveal where keys differ
+        self.bytes.len() == other.bytes.len()
+            && self.bytes.iter().zip(&other.bytes).fold(0u8, |diff, (a, b)| diff | (a ^ b)) == 0
+    }
+}
+
+impl Eq for SecretKey {}
+
+impl Secret for SecretKey {
+    fn zeroize(&mut self) {
+        Zeroize::zeroize(&mut self.bytes);
+    }
+
+    fn is_zeroized(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, TypeError> {
+        Ok(Self::from_slice(bytes))
+    }
+}
+
+impl Debug for SecretKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SecretKey({})", Redacted::bytes(self.len()))
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        Zeroize::zeroize(&mut self.bytes);
+    }
+}
+
+impl ZeroizeOnDrop for SecretKey {}
+
+/// Secret-derived field elements held by the prover-side AIR
+///
+/// Cleared with a volatile write, like [`SecretKey`], when the AIR is dropped.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct SecretLimbs(pub(crate) [BaseElement; crate::burn_mint_air::SECRET_LIMBS]);
+
+impl DefaultIsZeroes for SecretLimbs {}
+
+/// Overwrite secret-derived field elements with zero
+///
+/// Best effort, like [`SecretLimbs`]: the buffer is passed through
+/// [`core::hint::black_box`] after the writes so they are not optimized away.
+pub(crate) fn wipe_elements<E: winterfell::math::FieldElement>(values: &mut [E]) {
+    values.fill(E::ZERO);
+    core::hint::black_box(values);
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
+
 /// Secure field element secret
 #[derive(Clone, PartialEq, Eq)]
 pub struct SecureFieldElement<F: Clone + PartialEq + Eq> {
@@ -199,7 +358,7 @@ impl<F: Clone + PartialEq + Eq> Debug for SecureFieldElement<F> {
         if self.zeroized {
             write!(f, "SecureFieldElement(***ZEROIZED***)")
         } else {
-            write!(f, "SecureFieldElement(***HIDDEN***)")
+            write!(f, "SecureFieldElement({})", Redacted::bytes(core::mem::size_of::<F>()))
         }
     }
 }
@@ -289,7 +448,7 @@ impl<P: Clone + PartialEq + Eq> Debug for SecurePolynomial<P> {
         if self.zeroized {
             write!(f, "SecurePolynomial(***ZEROIZED***)")
         } else {
-            write!(f, "SecurePolynomial(***HIDDEN***)")
+            write!(f, "SecurePolynomial({})", Redacted::opaque())
         }
     }
 }
@@ -304,6 +463,18 @@ impl<P: Clone + PartialEq + Eq> Drop for SecurePolynomial<P> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_secret_key_zeroize_and_redaction() {
+        let mut key = SecretKey::new(vec![7u8; 32]);
+        assert_eq!(format!("{:?}", key), "SecretKey([REDACTED 32 bytes])");
+        assert_eq!(key, <SecretKey as Secret>::from_bytes(&[7u8; 32]).unwrap());
+        assert_ne!(key, SecretKey::from_slice(&[8u8; 32]));
+
+        Secret::zeroize(&mut key);
+        assert!(key.is_zeroized());
+        assert!(key.expose().is_empty());
+    }
+
     #[test]
     fn test_secure_secret_basic_operations() {
         let mut secret = SecureSecret::new(vec![1, 2, 3, 4]);
@@ -348,4 +519,16 @@ mod tests {
         assert!(secret.is_zeroized());
         assert_eq!(secret.value(), None);
     }
+
+    #[test]
+    fn test_debug_output_redacted() {
+        let secret = SecureSecret::new(vec![0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(format!("{:?}", secret), "SecureSecret([REDACTED 4 bytes])");
+
+        let element = SecureFieldElement::new(0xdead_beef_u64);
+        assert_eq!(format!("{:?}", element), "SecureFieldElement([REDACTED 8 bytes])");
+
+        assert_eq!(Redacted::hex(&"ab".repeat(32)).to_string(), "[REDACTED 32 bytes]");
+        assert_eq!(Redacted::hex("not-hex").to_string(), "[REDACTED 7 bytes]");
+    }
 }
@@ -6,6 +6,9 @@
 use std::fmt::{Display, Formatter};
 use std::marker::PhantomData;
 use serde::{Serialize, Deserialize};
+use crate::clock::{Clock, SystemClock};
+use crate::proof::merkle::MultiProof;
+use crate::proof::wire;
 use crate::types::{FieldElement, StarkComponent, TypeError};
 use crate::Result;
 
@@ -80,6 +83,11 @@ impl<F: FieldElement> StarkProof<F> {
     /// TODO: Replace with real proof generation - this is temporary for testing only
     /// Create a dummy proof for testing purposes
     pub fn new_dummy() -> Self {
+        Self::new_dummy_with_clock(&SystemClock)
+    }
+
+    /// Create a dummy proof whose metadata is stamped by `clock`
+    pub fn new_dummy_with_clock(clock: &dyn Clock) -> Self {
         // Create dummy execution trace with 7 registers and 64 steps
         let dummy_trace = ExecutionTrace {
             columns: vec![
@@ -114,10 +122,7 @@ impl<F: FieldElement> StarkProof<F> {
             security_parameter: 128,
             field_modulus: "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47".to_string(),
             proof_size: 1024, // TODO: Calculate real proof size
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
+            timestamp: clock.unix_seconds(),
         };
 
         Self {
@@ -128,6 +133,8 @@ impl<F: FieldElement> StarkProof<F> {
                 layers: vec![], // TODO: Generate real FRI layers
                 queries: vec![], // TODO: Generate real queries
                 final_polynomial: vec![], // TODO: Generate real final polynomial
+                openings: vec![],
+                domain: FriDomain::empty(),
             },
             metadata: dummy_metadata,
         }
@@ -173,6 +180,8 @@ impl<F: FieldElement> StarkProof<F> {
                 layers: vec![], // TODO: Generate real FRI layers
                 queries: vec![], // TODO: Generate real queries
                 final_polynomial: vec![], // TODO: Generate real final polynomial
+                openings: vec![],
+                domain: FriDomain::empty(),
             },
             metadata: empty_metadata,
         }
@@ -314,6 +323,109 @@ pub struct FriProof<F: FieldElement> {
     pub final_polynomial: Vec<F>,
     /// Query responses
     pub queries: Vec<FriQuery<F>>,
+    /// Opening of each layer at the positions the queries read
+    pub openings: Vec<MultiProof>,
+    /// Evaluation domain the layers and queries live on
+    pub domain: FriDomain<F>,
+}
+
+/// FRI evaluation domain: the coset `offset · {1, g, g^2, ..., g^(size-1)}`
+///
+/// The trace is interpolated over the natural domain (offset one). Evaluating and
+/// querying over a coset with an offset outside the natural domain guarantees no query
+/// lands on a trace point, where the low-degree extension would reveal trace values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FriDomain<F: FieldElement> {
+    /// Generator of the natural domain
+    pub generator: F,
+    /// Coset offset
+    pub offset: F,
+    /// Number of domain points
+    pub size: usize,
+}
+
+impl<F: FieldElement> FriDomain<F> {
+    /// Size of the serialized domain in bytes
+    pub const ENCODED_LEN: usize = 32 + 32 + 8;
+
+    /// Create the domain `offset · <generator>` of `size` points
+    pub fn new(size: usize, generator: F, offset: F) -> Self {
+        Self { generator, offset, size }
+    }
+
+    /// Empty domain for proofs without FRI layers
+    #[must_use]
+    pub fn empty() -> Self {
+        Self::new(0, F::one(), F::one())
+    }
+
+    /// Natural (unshifted) domain with the same generator and size
+    #[must_use]
+    pub fn natural(&self) -> Self {
+        Self::new(self.size, self.generator, F::one())
+    }
+
+    /// Domain of the points raised to the `factor`-th power, which a FRI fold maps onto
+    #[must_use]
+    pub fn fold(&self, factor: usize) -> Self {
+        Self::new(self.size / factor, self.generator.pow(factor as u64), self.offset.pow(factor as u64))
+    }
+
+    /// Domain point at `index`
+    pub fn element(&self, index: usize) -> F {
+        self.offset * self.generator.pow(index as u64)
+    }
+
+    /// All domain points in order
+    pub fn elements(&self) -> Vec<F> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut current = self.offset;
+        for _ in 0..self.size {
+            elements.push(current);
+            current *= self.generator;
+        }
+        elements
+    }
+
+    /// Check whether `point` lies in the domain
+    ///
+    /// The natural domain is the group of `size`-th roots of unity, so `point` is in the
+    /// coset exactly when `(point / offset)^size` is one.
+    pub fn contains(&self, point: F) -> bool {
+        if self.size == 0 {
+            return false;
+        }
+        match self.offset.inverse() {
+            Some(offset_inverse) => (point * offset_inverse).pow(self.size as u64).is_one(),
+            None => false,
+        }
+    }
+
+    /// Check whether the offset shifts the domain off the natural domain
+    pub fn is_shifted(&self) -> bool {
+        !self.offset.is_zero() && (self.size == 0 || !self.offset.pow(self.size as u64).is_one())
+    }
+
+    /// Serialize as `generator || offset || le64(size)`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::ENCODED_LEN);
+        bytes.extend_from_slice(&self.generator.to_bytes());
+        bytes.extend_from_slice(&self.offset.to_bytes());
+        bytes.extend_from_slice(&(self.size as u64).to_le_bytes());
+        bytes
+    }
+
+    /// Deserialize from [`Self::to_bytes`] output
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return None;
+        }
+        let generator = F::from_bytes(bytes[..32].try_into().ok()?)?;
+        let offset = F::from_bytes(bytes[32..64].try_into().ok()?)?;
+        let size = u64::from_le_bytes(bytes[64..].try_into().ok()?);
+        Some(Self::new(usize::try_from(size).ok()?, generator, offset))
+    }
 }
 
 impl<F: FieldElement> Display for FriProof<F> {
@@ -396,13 +508,12 @@ impl<F: FieldElement> StarkComponent<F> for StarkProof<F> {
     }
     
     fn to_bytes(&self) -> Vec<u8> {
-        // Placeholder implementation
-        Vec::new()
+        wire::write_to_vec(|writer| writer.write_proof(self))
     }
     
-    fn from_bytes(_bytes: &[u8]) -> std::result::Result<Self, TypeError> {
-        // Placeholder implementation
-        Err(TypeError::InvalidConversion("Not implemented".to_string()))
+    fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, TypeError> {
+        wire::read_from_slice(bytes, wire::ProofReader::read_proof)
+            .map_err(|e| TypeError::InvalidConversion(e.to_string()))
     }
 }
 
@@ -430,13 +541,12 @@ impl<F: FieldElement> StarkComponent<F> for ExecutionTrace<F> {
     }
     
     fn to_bytes(&self) -> Vec<u8> {
-        // Placeholder implementation
-        Vec::new()
+        wire::write_to_vec(|writer| writer.write_trace(self))
     }
     
-    fn from_bytes(_bytes: &[u8]) -> std::result::Result<Self, TypeError> {
-        // Placeholder implementation
-        Err(TypeError::InvalidConversion("Not implemented".to_string()))
+    fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, TypeError> {
+        wire::read_from_slice(bytes, wire::ProofReader::read_trace)
+            .map_err(|e| TypeError::InvalidConversion(e.to_string()))
     }
 }
 
@@ -457,13 +567,12 @@ impl<F: FieldElement> StarkComponent<F> for Air<F> {
     }
     
     fn to_bytes(&self) -> Vec<u8> {
-        // Placeholder implementation
-        Vec::new()
+        wire::write_to_vec(|writer| writer.write_air(self))
     }
     
-    fn from_bytes(_bytes: &[u8]) -> std::result::Result<Self, TypeError> {
-        // Placeholder implementation
-        Err(TypeError::InvalidConversion("Not implemented".to_string()))
+    fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, TypeError> {
+        wire::read_from_slice(bytes, wire::ProofReader::read_air)
+            .map_err(|e| TypeError::InvalidConversion(e.to_string()))
     }
 }
 
@@ -476,13 +585,12 @@ impl<F: FieldElement> StarkComponent<F> for TransitionFunction<F> {
     }
     
     fn to_bytes(&self) -> Vec<u8> {
-        // Placeholder implementation
-        Vec::new()
+        wire::write_to_vec(|writer| writer.write_transition(self))
     }
     
-    fn from_bytes(_bytes: &[u8]) -> std::result::Result<Self, TypeError> {
-        // Placeholder implementation
-        Err(TypeError::InvalidConversion("Not implemented".to_string()))
+    fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, TypeError> {
+        wire::read_from_slice(bytes, wire::ProofReader::read_transition)
+            .map_err(|e| TypeError::InvalidConversion(e.to_string()))
     }
 }
 
@@ -495,13 +603,12 @@ impl<F: FieldElement> StarkComponent<F> for BoundaryConditions<F> {
     }
     
     fn to_bytes(&self) -> Vec<u8> {
-        // Placeholder implementation
-        Vec::new()
+        wire::write_to_vec(|writer| writer.write_boundary_conditions(self))
     }
     
-    fn from_bytes(_bytes: &[u8]) -> std::result::Result<Self, TypeError> {
-        // Placeholder implementation
-        Err(TypeError::InvalidConversion("Not implemented".to_string()))
+    fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, TypeError> {
+        wire::read_from_slice(bytes, wire::ProofReader::read_boundary_conditions)
+            .map_err(|e| TypeError::InvalidConversion(e.to_string()))
     }
 }
 
@@ -511,13 +618,12 @@ impl<F: FieldElement> StarkComponent<F> for BoundaryConstraint<F> {
     }
     
     fn to_bytes(&self) -> Vec<u8> {
-        // Placeholder implementation
-        Vec::new()
+        wire::write_to_vec(|writer| writer.write_boundary_constraint(self))
     }
     
-    fn from_bytes(_bytes: &[u8]) -> std::result::Result<Self, TypeError> {
-        // Placeholder implementation
-        Err(TypeError::InvalidConversion("Not implemented".to_string()))
+    fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, TypeError> {
+        wire::read_from_slice(bytes, wire::ProofReader::read_boundary_constraint)
+            .map_err(|e| TypeError::InvalidConversion(e.to_string()))
     }
 }
 
@@ -535,13 +641,12 @@ impl<F: FieldElement> StarkComponent<F> for MerkleCommitment<F> {
     }
     
     fn to_bytes(&self) -> Vec<u8> {
-        // Placeholder implementation
-        Vec::new()
+        wire::write_to_vec(|writer| writer.write_commitment(self))
     }
     
-    fn from_bytes(_bytes: &[u8]) -> std::result::Result<Self, TypeError> {
-        // Placeholder implementation
-        Err(TypeError::InvalidConversion("Not implemented".to_string()))
+    fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, TypeError> {
+        wire::read_from_slice(bytes, wire::ProofReader::read_commitment)
+            .map_err(|e| TypeError::InvalidConversion(e.to_string()))
     }
 }
 
@@ -563,17 +668,20 @@ impl<F: FieldElement> StarkComponent<F> for FriProof<F> {
             query.validate()?;
         }
         
+        if self.domain.size > 0 && !self.domain.is_shifted() {
+            return Err(TypeError::InvalidConversion("FRI domain is not shifted off the trace domain".to_string()));
+        }
+        
         Ok(())
     }
     
     fn to_bytes(&self) -> Vec<u8> {
-        // Placeholder implementation
-        Vec::new()
+        wire::write_to_vec(|writer| writer.write_fri_proof(self))
     }
     
-    fn from_bytes(_bytes: &[u8]) -> std::result::Result<Self, TypeError> {
-        // Placeholder implementation
-        Err(TypeError::InvalidConversion("Not implemented".to_string()))
+    fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, TypeError> {
+        wire::read_from_slice(bytes, wire::ProofReader::read_fri_proof)
+            .map_err(|e| TypeError::InvalidConversion(e.to_string()))
     }
 }
 
@@ -591,13 +699,12 @@ impl<F: FieldElement> StarkComponent<F> for FriLayer<F> {
     }
     
     fn to_bytes(&self) -> Vec<u8> {
-        // Placeholder implementation
-        Vec::new()
+        wire::write_to_vec(|writer| writer.write_fri_layer(self))
     }
     
-    fn from_bytes(_bytes: &[u8]) -> std::result::Result<Self, TypeError> {
-        // Placeholder implementation
-        Err(TypeError::InvalidConversion("Not implemented".to_string()))
+    fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, TypeError> {
+        wire::read_from_slice(bytes, wire::ProofReader::read_fri_layer)
+            .map_err(|e| TypeError::InvalidConversion(e.to_string()))
     }
 }
 
@@ -610,13 +717,12 @@ impl<F: FieldElement> StarkComponent<F> for FriQuery<F> {
     }
     
     fn to_bytes(&self) -> Vec<u8> {
-        // Placeholder implementation
-        Vec::new()
+        wire::write_to_vec(|writer| writer.write_fri_query(self))
     }
     
-    fn from_bytes(_bytes: &[u8]) -> std::result::Result<Self, TypeError> {
-        // Placeholder implementation
-        Err(TypeError::InvalidConversion("Not implemented".to_string()))
+    fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, TypeError> {
+        wire::read_from_slice(bytes, wire::ProofReader::read_fri_query)
+            .map_err(|e| TypeError::InvalidConversion(e.to_string()))
     }
 }
 
@@ -659,6 +765,8 @@ mod tests {
                 layers: vec![],
                 final_polynomial: vec![PrimeField64::new(1)],
                 queries: vec![],
+                openings: vec![],
+                domain: FriDomain::empty(),
             },
             metadata,
         };
@@ -4,6 +4,7 @@
 
 /// Cryptographic utilities
 pub mod crypto {
+    use crate::proof::hasher::{Hasher, Sha256Hasher};
     use sha2::{Sha256, Digest};
     
     /// Compute SHA-256 hash
@@ -13,35 +14,27 @@ pub mod crypto {
         hasher.finalize().into()
     }
     
-    /// Compute Merkle tree root
+    /// Compute SHA-256 Merkle tree root
     pub fn merkle_root(leaves: &[Vec<u8>]) -> [u8; 32] {
+        merkle_root_with::<Sha256Hasher>(leaves)
+    }
+
+    /// Compute Merkle tree root hashed with `H`
+    #[must_use]
+    pub fn merkle_root_with<H: Hasher>(leaves: &[Vec<u8>]) -> [u8; 32] {
         if leaves.is_empty() {
             return [0u8; 32];
         }
         
-        if leaves.len() == 1 {
-            return sha256(&leaves[0]);
-        }
-        
         let mut current_level: Vec<[u8; 32]> = leaves.iter()
-            .map(|leaf| sha256(leaf))
+            .map(|leaf| H::hash(leaf))
             .collect();
         
         while current_level.len() > 1 {
-            let mut next_level = Vec::new();
-            
-            for chunk in current_level.chunks(2) {
-                let mut combined = Vec::new();
-                combined.extend_from_slice(&chunk[0]);
-                if chunk.len() > 1 {
-                    combined.extend_from_slice(&chunk[1]);
-                } else {
-                    combined.extend_from_slice(&chunk[0]);
-                }
-                next_level.push(sha256(&combined));
-            }
-            
-            current_level = next_level;
+            current_level = current_level
+                .chunks(2)
+                .map(|chunk| H::merge(&chunk[0], &chunk[chunk.len() - 1]))
+                .collect();
         }
         
         current_level[0]
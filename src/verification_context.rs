@@ -0,0 +1,270 @@
+//! Shared Verification Context
+//!
+//! A package is checked by two subsystems: Eldernodes attest that its burn (transaction
+//! hash, burn amount, HEAT commitment) is recorded on the Fuego chain, and the STARK
+//! verifier checks the proof against the claim the package describes. Both start from the
+//! same package fields. [`VerificationContext`] parses them once, so the two checks cannot
+//! read different values from one package and the HEAT commitment is hashed only once:
+//!
+//! - [`VerificationContext::eldernode_inputs`]: what Eldernodes attest to
+//! - [`VerificationContext::audit_tx_extra`]: the commitment audit against `tx_extra`
+//! - [`crate::XfgBurnMintVerifier::verify_package_with_context`]: the STARK check
+
+use crate::{
+    burn_mint_air::{BurnMintPublicInputs, XfgBurnMintAir, SECRET_LIMBS},
+    burn_mint_prover::{secret_to_field_elements, SECRET_LEN},
+    burn_mint_verifier::PackageClaimError,
+    commitment::{recipient_binding, CommitmentPreimage},
+    commitment_audit::{extract_heat_commitment, AuditError, CommitmentAuditReport},
+    constants::{COMMITMENT_VERSION, DEFAULT_TARGET_CHAIN_ID},
+    eth_address::EthAddress,
+    proof_data_schema::StarkProofDataPackage,
+    Result,
+};
+use winterfell::{math::fields::f64::BaseElement, ProofOptions, TraceInfo};
+
+/// Burn facts Eldernodes attest to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EldernodeAttestationInputs {
+    /// Burn transaction hash, as recorded in the package
+    pub transaction_hash: String,
+    /// HEAT commitment expected in the burn's `tx_extra`
+    pub commitment: [u8; 32],
+    /// Burn amount in atomic units
+    pub burn_amount: u64,
+}
+
+/// Package fields parsed once for Eldernode attestation and STARK verification
+#[derive(Clone)]
+pub struct VerificationContext {
+    transaction_hash: String,
+    tx_prefix_hash: [u8; 32],
+    recipient: EthAddress,
+    recipient_binding: [u8; 32],
+    burn_amount: u64,
+    mint_amount: u64,
+    fee_bps: u32,
+    network_id: u32,
+    secret: [BaseElement; SECRET_LIMBS],
+    heat_commitment: [u8; 32],
+}
+
+impl VerificationContext {
+    /// Parse a package's burn transaction, recipient and secret
+    ///
+    /// # Errors
+    ///
+    /// [`PackageClaimError::InvalidField`] if the recipient or transaction hash is malformed,
+    /// or [`crate::XfgStarkError::CryptoError`] if the secret is not [`SECRET_LEN`] bytes
+    pub fn from_package(package: &StarkProofDataPackage) -> Result<Self> {
+        let invalid = |field, reason: String| PackageClaimError::InvalidField { field, reason };
+        let recipient = package
+            .recipient_address()
+            .map_err(|e| invalid("recipient.ethereum_address", e.to_string()))?;
+        let tx_prefix_hash = package
+            .tx_prefix_hash()
+            .map_err(|e| invalid("burn_transaction.transaction_hash", e.to_string()))?;
+        let burn_amount = package.burn_transaction.burn_amount_atomic;
+
+        let mut context = Self {
+            transaction_hash: package.burn_transaction.transaction_hash.clone(),
+            tx_prefix_hash,
+            recipient_binding: recipient_binding(recipient.as_bytes(), DEFAULT_TARGET_CHAIN_ID),
+            burn_amount,
+            mint_amount: package.get_mint_amount_atomic(),
+            fee_bps: package.burn_transaction.protocol_fee_bps,
+            network_id: package.network_id_number(),
+            secret: secret_to_field_elements(&package.secret_bytes())?,
+            heat_commitment: [0; 32],
+            recipient,
+        };
+        context.heat_commitment = context.commitment_preimage().to_bytes();
+        Ok(context)
+    }
+
+    /// Burn transaction hash, as recorded in the package
+    #[must_use]
+    pub fn transaction_hash(&self) -> &str {
+        &self.transaction_hash
+    }
+
+    /// Burn transaction prefix hash as proven
+    #[must_use]
+    pub fn tx_prefix_hash(&self) -> [u8; 32] {
+        self.tx_prefix_hash
+    }
+
+    /// Recipient of the mint
+    #[must_use]
+    pub fn recipient(&self) -> EthAddress {
+        self.recipient
+    }
+
+    /// Recipient binding for the default target chain
+    #[must_use]
+    pub fn recipient_binding(&self) -> [u8; 32] {
+        self.recipient_binding
+    }
+
+    /// Burn amount in atomic units
+    #[must_use]
+    pub fn burn_amount(&self) -> u64 {
+        self.burn_amount
+    }
+
+    /// Mint amount in atomic units (burn less the protocol fee)
+    #[must_use]
+    pub fn mint_amount(&self) -> u64 {
+        self.mint_amount
+    }
+
+    /// Protocol fee charged on the mint (basis points)
+    #[must_use]
+    pub fn fee_bps(&self) -> u32 {
+        self.fee_bps
+    }
+
+    /// HEAT commitment the wallet wrote into the burn's `tx_extra`
+    ///
+    /// The same value a proof of this claim carries as its `commitment` public input.
+    #[must_use]
+    pub fn heat_commitment(&self) -> [u8; 32] {
+        self.heat_commitment
+    }
+
+    /// What Eldernodes attest to for this package
+    #[must_use]
+    pub fn eldernode_inputs(&self) -> EldernodeAttestationInputs {
+        EldernodeAttestationInputs {
+            transaction_hash: self.transaction_hash.clone(),
+            commitment: self.heat_commitment,
+            burn_amount: self.burn_amount,
+        }
+    }
+
+    /// Compare the HEAT commitment with the one in raw `tx_extra` bytes
+    ///
+    /// # Errors
+    ///
+    /// [`AuditError`] if `tx_extra` is malformed or carries no HEAT commitment
+    pub fn audit_tx_extra(&self, tx_extra: &[u8]) -> std::result::Result<CommitmentAuditReport, AuditError> {
+        let on_chain = extract_heat_commitment(tx_extra)?;
+        Ok(CommitmentAuditReport::compare(&self.transaction_hash, self.heat_commitment, on_chain))
+    }
+
+    /// Public inputs of the claim with the nullifier and commitment left at zero
+    #[must_use]
+    pub fn claim(&self) -> BurnMintPublicInputs {
+        let mut claim = BurnMintPublicInputs::from_user_data(
+            self.burn_amount,
+            self.tx_prefix_hash,
+            self.recipient.as_bytes(),
+            self.network_id,
+            DEFAULT_TARGET_CHAIN_ID,
+            COMMITMENT_VERSION,
+        )
+        .with_fee_bps(self.fee_bps);
+        claim.mint_amount = BaseElement::new(self.mint_amount);
+        claim
+    }
+
+    /// Preimage of the HEAT commitment for this claim
+    #[must_use]
+    pub fn commitment_preimage(&self) -> CommitmentPreimage {
+        CommitmentPreimage::new(self.secret, &self.claim())
+    }
+
+    /// Public inputs a proof of this claim must verify against
+    ///
+    /// The nullifier and commitment are derived from the secret exactly as the prover
+    /// derives them for a trace of this shape.
+    #[must_use]
+    pub fn public_inputs(&self, trace_info: TraceInfo, options: ProofOptions) -> BurnMintPublicInputs {
+        let air = XfgBurnMintAir::new(trace_info, self.claim(), self.secret, options);
+        air.public_inputs().clone()
+    }
+}
+
+impl std::fmt::Debug for VerificationContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VerificationContext")
+            .field("transaction_hash", &self.transaction_hash)
+            .field("tx_prefix_hash", &hex::encode(self.tx_prefix_hash))
+            .field("recipient", &self.recipient)
+            .field("recipient_binding", &hex::encode(self.recipient_binding))
+            .field("burn_amount", &self.burn_amount)
+            .field("mint_amount", &self.mint_amount)
+            .field("fee_bps", &self.fee_bps)
+            .field("network_id", &self.network_id)
+            .field("secret", &crate::types::secret::Redacted::bytes(SECRET_LEN))
+            .field("heat_commitment", &hex::encode(self.heat_commitment))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commitment_audit::{compute_package_commitment, HEAT_COMMITMENT_TAG};
+
+    fn test_package() -> StarkProofDataPackage {
+        StarkProofDataPackage::new(
+            0.8,
+            "7D0725F8E03021B99560ADD456C596FEA7D8DF23529E23765E56923B73236E4D".to_string(),
+            "0x742d35cc6634c0532925a3b8d4c9db96c4b4d8b6".to_string(),
+            "my-secret-key-123".to_string(),
+            "fuego-testnet".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_eldernode_inputs_share_parsed_values() {
+        let package = test_package();
+        let context = VerificationContext::from_package(&package).unwrap();
+        let inputs = context.eldernode_inputs();
+
+        assert_eq!(inputs.commitment, compute_package_commitment(&package).unwrap());
+        assert_eq!(inputs.burn_amount, package.burn_transaction.burn_amount_atomic);
+        assert_eq!(inputs.transaction_hash, package.burn_transaction.transaction_hash);
+        assert_eq!(context.claim().burn_amount, BaseElement::new(inputs.burn_amount));
+
+        let mut tx_extra = vec![HEAT_COMMITMENT_TAG];
+        tx_extra.extend_from_slice(&inputs.commitment);
+        assert!(context.audit_tx_extra(&tx_extra).unwrap().is_match());
+        assert!(!format!("{:?}", context).contains("my-secret-key-123"));
+    }
+
+    #[test]
+    fn test_commitment_preimage_matches_air() {
+        let package = test_package();
+        let context = VerificationContext::from_package(&package).unwrap();
+        let options = ProofOptions::new(42, 8, 4, winterfell::FieldExtension::Quadratic, 8, 31);
+        let trace_info = TraceInfo::new(crate::burn_mint_air::TRACE_WIDTH, crate::constants::TRACE_LENGTH);
+        let public_inputs = context.public_inputs(trace_info, options);
+
+        let preimage = package.commitment_preimage().unwrap();
+        assert_eq!(preimage, context.commitment_preimage());
+        assert_eq!(preimage.hash(), public_inputs.commitment);
+        assert_eq!(preimage.to_bytes(), context.heat_commitment());
+        assert!(crate::commitment::verify_commitment_opening(
+            &package.secret_bytes(),
+            &public_inputs,
+            &context.heat_commitment()
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_rejects_unparseable_recipient() {
+        let mut package = test_package();
+        package.recipient.ethereum_address = "742d35cc6634c0532925a3b8d4c9db96c4b4d8b6".to_string();
+
+        assert!(matches!(
+            VerificationContext::from_package(&package),
+            Err(crate::XfgStarkError::PackageClaimError(PackageClaimError::InvalidField {
+                field: "recipient.ethereum_address",
+                ..
+            }))
+        ));
+    }
+}
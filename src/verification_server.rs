@@ -0,0 +1,546 @@
+//! Streaming Proof Verification Server
+//!
+//! Gateways submit burn & mint proofs over a long-lived TCP connection using
+//! newline-delimited JSON frames, and receive one verdict frame per proof:
+//!
+//! ```text
+//! → {"type":"verify","id":1,"proof":"<hex>","public_inputs":[8000000, ...]}
+//! ← {"type":"verdict","id":1,"status":200,"valid":true,"error":null}
+//! → {"type":"metrics","id":2}
+//! ← {"type":"metrics","id":2,"metrics":{"queue_depth":0,...}}
+//! ```
+//!
+//! Verification runs on a [`WorkerPool`] of fixed size. At most `max_pending` admitted
+//! proofs wait for a worker; once the pool and the queue are full, new proofs are
+//! answered immediately with status 429 instead of being queued, so a submission spike
+//! after a popular burn event cannot grow memory without bound. Each connection also has a
+//! bounded verdict buffer: a client that stops reading verdicts stops having its frames
+//! read, which pushes back through TCP flow control. A frame longer than
+//! `max_frame_bytes` is answered with status 400 and closes the connection without
+//! being buffered, and proofs are decoded within the verifier's decode limits.
+//!
+//! When the server has tenants (see [`crate::tenants`]), a connection must open with an
+//! auth frame before submitting proofs, and each tenant's submissions are held to its
+//! own pending, rate and quota limits, enforced by the pool:
+//!
+//! ```text
+//! → {"type":"auth","id":0,"api_key":"<key>"}
+//! ← {"type":"authenticated","id":0,"tenant":"wallet-a"}
+//! ```
+//!
+//! Verdict status codes follow HTTP: 200 valid, 400 malformed frame, 401 missing or
+//! unknown API key, 403 tenant quota used up, 422 invalid proof, 429 server saturated
+//! or tenant rate limited.
+
+use crate::admission::{Admission, MetricsSnapshot, WorkerPool};
+use crate::burn_mint_air::BurnMintPublicInputs;
+use crate::burn_mint_verifier::XfgBurnMintVerifier;
+use crate::tenants::{Tenant, TenantMetrics, TenantRegistry};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use winterfell::math::fields::f64::BaseElement;
+
+/// Default address the server binds to
+pub const DEFAULT_SERVER_ADDR: &str = "127.0.0.1:18190";
+
+/// Verdict status: proof verified
+pub const STATUS_VALID: u16 = 200;
+/// Verdict status: frame could not be decoded
+pub const STATUS_MALFORMED: u16 = 400;
+/// Verdict status: connection has not authenticated as a tenant
+pub const STATUS_UNAUTHORIZED: u16 = 401;
+/// Verdict status: tenant has used its proof quota
+pub const STATUS_QUOTA_EXHAUSTED: u16 = 403;
+/// Verdict status: proof failed verification
+pub const STATUS_INVALID: u16 = 422;
+/// Verdict status: worker pool and queue are full, or the tenant is rate limited; retry later
+pub const STATUS_BUSY: u16 = 429;
+
+/// Worker pool and queue limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServerConfig {
+    /// Proofs verified concurrently
+    pub workers: usize,
+    /// Admitted proofs allowed to wait for a worker
+    pub max_pending: usize,
+    /// Verdicts buffered per connection before reading from it pauses
+    pub connection_buffer: usize,
+    /// Longest frame read from a client (bytes, excluding the newline)
+    pub max_frame_bytes: usize,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            workers: std::thread::available_parallelism().map_or(4, std::num::NonZero::get),
+            max_pending: 64,
+            connection_buffer: 32,
+            // A hex proof at the verifier's default size limit, plus room for the rest of the frame
+            max_frame_bytes: 2 * 512 * 1024 + 64 * 1024,
+        }
+    }
+}
+
+/// Frame sent by a client
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientFrame {
+    /// Verify a burn & mint proof
+    Verify {
+        /// Client-chosen request identifier, echoed in the verdict
+        id: u64,
+        /// Serialized Winterfell proof (hex)
+        proof: String,
+        /// Public inputs in `BurnMintPublicInputs::to_elements` order
+        public_inputs: Vec<u64>,
+    },
+    /// Request a snapshot of the queue metrics
+    Metrics {
+        /// Client-chosen request identifier, echoed in the response
+        id: u64,
+    },
+    /// Authenticate the connection as a tenant
+    Auth {
+        /// Client-chosen request identifier, echoed in the response
+        id: u64,
+        /// Tenant API key
+        api_key: String,
+    },
+}
+
+/// Frame sent by the server
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerFrame {
+    /// Outcome of a verify frame
+    Verdict {
+        /// Identifier of the verify frame (0 when it could not be decoded)
+        id: u64,
+        /// HTTP-style status code
+        status: u16,
+        /// Whether the proof verified
+        valid: bool,
+        /// Reason the proof was not verified
+        error: Option<String>,
+    },
+    /// Queue metrics snapshot
+    Metrics {
+        /// Identifier of the metrics frame
+        id: u64,
+        /// Current metrics
+        metrics: MetricsSnapshot,
+        /// Counters of the connection's tenant, once authenticated
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tenant: Option<TenantMetrics>,
+    },
+    /// Connection authenticated
+    Authenticated {
+        /// Identifier of the auth frame
+        id: u64,
+        /// Tenant label
+        tenant: String,
+    },
+}
+
+impl ServerFrame {
+    fn verdict(id: u64, status: u16, error: Option<String>) -> Self {
+        ServerFrame::Verdict {
+            id,
+            status,
+            valid: status == STATUS_VALID,
+            error,
+        }
+    }
+}
+
+/// Streaming verification server for burn & mint proofs
+///
+/// Cloning is cheap; clones share the worker pool, queue and metrics.
+#[derive(Clone)]
+pub struct VerificationServer {
+    config: ServerConfig,
+    verifier: Arc<XfgBurnMintVerifier>,
+    pool: WorkerPool,
+}
+
+impl VerificationServer {
+    /// Create a server verifying with `verifier` under the given limits
+    #[must_use]
+    pub fn new(verifier: XfgBurnMintVerifier, config: ServerConfig) -> Self {
+        let pool = WorkerPool::new(config.workers, config.max_pending);
+        Self {
+            config: ServerConfig {
+                workers: pool.workers(),
+                ..config
+            },
+            verifier: Arc::new(verifier),
+            pool,
+        }
+    }
+
+    /// Require connections to authenticate as one of `tenants`
+    #[must_use]
+    pub fn with_tenants(mut self, tenants: TenantRegistry) -> Self {
+        self.pool = self.pool.with_tenants(tenants);
+        self
+    }
+
+    /// Counters of every tenant
+    #[must_use]
+    pub fn tenant_metrics(&self) -> Vec<TenantMetrics> {
+        self.pool.tenant_metrics()
+    }
+
+    /// Worker pool and queue limits
+    #[must_use]
+    pub fn config(&self) -> ServerConfig {
+        self.config
+    }
+
+    /// Snapshot of the queue metrics
+    #[must_use]
+    pub fn metrics(&self) -> MetricsSnapshot {
+        self.pool.metrics()
+    }
+
+    /// Accept connections until the listener fails
+    ///
+    /// # Errors
+    ///
+    /// I/O errors of accepting a connection
+    pub async fn serve(&self, listener: TcpListener) -> std::io::Result<()> {
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let server = self.clone();
+            tokio::spawn(async move {
+                // A dropped connection only ends that client's stream
+                let _ = server.handle_connection(stream).await;
+            });
+        }
+    }
+
+    /// Serve one client connection until it closes
+    ///
+    /// # Errors
+    ///
+    /// I/O errors of reading from or writing to the client
+    pub async fn handle_connection<S>(&self, stream: S) -> std::io::Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Send + 'static,
+    {
+        let (reader, writer) = tokio::io::split(stream);
+        let (tx, rx) = mpsc::channel::<ServerFrame>(self.config.connection_buffer.max(1));
+        let write_task = tokio::spawn(write_frames(writer, rx));
+
+        let mut tenant: Option<Arc<Tenant>> = None;
+        let max_frame_bytes = self.config.max_frame_bytes;
+        let mut reader = BufReader::new(reader);
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            // Reading one byte past the limit tells an oversized frame from one that fits
+            let limit = max_frame_bytes as u64 + 1;
+            if (&mut reader).take(limit).read_until(b'\n', &mut line).await? == 0 {
+                break;
+            }
+            if line.last() == Some(&b'\n') {
+                line.pop();
+            }
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            // Waiting for buffer space here stops reading from a client that is not
+            // draining its verdicts
+            let Ok(reserved) = tx.clone().reserve_owned().await else {
+                break;
+            };
+            if line.len() > max_frame_bytes {
+                // The rest of the frame is never read, so the stream cannot be resynchronized
+                self.pool.record_malformed();
+                let error = format!("frame exceeds {max_frame_bytes} bytes");
+                reserved.send(ServerFrame::verdict(0, STATUS_MALFORMED, Some(error)));
+                break;
+            }
+
+            match serde_json::from_slice::<ClientFrame>(&line) {
+                Ok(ClientFrame::Metrics { id }) => {
+                    reserved.send(ServerFrame::Metrics {
+                        id,
+                        metrics: self.metrics(),
+                        tenant: tenant.as_ref().map(|tenant| tenant.metrics()),
+                    });
+                }
+                Ok(ClientFrame::Auth { id, api_key }) => {
+                    if let Some(authenticated) = self.pool.authenticate(&api_key) {
+                        reserved.send(ServerFrame::Authenticated {
+                            id,
+                            tenant: authenticated.name().to_string(),
+                        });
+                        tenant = Some(authenticated);
+                    } else {
+                        let error = Some("unknown API key".to_string());
+                        reserved.send(ServerFrame::verdict(id, STATUS_UNAUTHORIZED, error));
+                    }
+                }
+                Ok(ClientFrame::Verify {
+                    id,
+                    proof,
+                    public_inputs,
+                }) => match self.pool.admit(tenant.as_ref()) {
+                    Ok(admission) => {
+                        let verifier = self.verifier.clone();
+                        tokio::spawn(async move {
+                            reserved.send(verify(admission, verifier, id, proof, public_inputs).await);
+                        });
+                    }
+                    Err(rejection) => {
+                        let status = rejection.status().as_u16();
+                        reserved.send(ServerFrame::verdict(id, status, Some(rejection.to_string())));
+                    }
+                },
+                Err(e) => {
+                    self.pool.record_malformed();
+                    reserved.send(ServerFrame::verdict(0, STATUS_MALFORMED, Some(e.to_string())));
+                }
+            }
+        }
+
+        drop(tx);
+        write_task.await.map_err(std::io::Error::other)?
+    }
+}
+
+/// Wait for a worker and verify one admitted proof
+async fn verify(
+    admission: Admission,
+    verifier: Arc<XfgBurnMintVerifier>,
+    id: u64,
+    proof: String,
+    public_inputs: Vec<u64>,
+) -> ServerFrame {
+    let outcome = admission
+        .run(move || verify_frame(&verifier, &proof, &public_inputs))
+        .await
+        .unwrap_or_else(|e| Err((STATUS_INVALID, format!("Verification task failed: {e}"))));
+    match outcome {
+        Ok(()) => ServerFrame::verdict(id, STATUS_VALID, None),
+        Err((status, error)) => ServerFrame::verdict(id, status, Some(error)),
+    }
+}
+
+/// Write verdicts to the client, one JSON line each, until every sender is dropped
+async fn write_frames<W>(mut writer: W, mut rx: mpsc::Receiver<ServerFrame>) -> std::io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    while let Some(frame) = rx.recv().await {
+        let mut line = serde_json::to_vec(&frame).map_err(std::io::Error::other)?;
+        line.push(b'\n');
+        writer.write_all(&line).await?;
+    }
+    writer.shutdown().await
+}
+
+/// Decode and verify one proof, returning the failure status and reason
+fn verify_frame(
+    verifier: &XfgBurnMintVerifier,
+    proof: &str,
+    public_inputs: &[u64],
+) -> std::result::Result<(), (u16, String)> {
+    let bytes = hex::decode(proof.trim_start_matches("0x"))
+        .map_err(|e| (STATUS_MALFORMED, format!("Invalid proof hex: {e}")))?;
+    let proof = verifier
+        .decode_bounded(&bytes)
+        .map_err(|e| (STATUS_MALFORMED, format!("Invalid proof encoding: {e}")))?;
+    let elements: Vec<BaseElement> = public_inputs.iter().copied().map(BaseElement::new).collect();
+    let public_inputs = BurnMintPublicInputs::from_elements(&elements).ok_or_else(|| {
+        (
+            STATUS_MALFORMED,
+            format!(
+                "Expected {} public inputs, got {}",
+                BurnMintPublicInputs::NUM_ELEMENTS,
+                elements.len()
+            ),
+        )
+    })?;
+
+    match verifier.verify_with_public_inputs(&proof, &public_inputs) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err((STATUS_INVALID, "Proof failed verification".to_string())),
+        Err(e) => Err((STATUS_INVALID, e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+    use winter_math::{StarkField, ToElements};
+
+    fn verify_frame_json(id: u64) -> String {
+        let (proof, public_inputs) = XfgBurnMintProver::default()
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+
+        serde_json::to_string(&ClientFrame::Verify {
+            id,
+            proof: hex::encode(proof.to_bytes()),
+            public_inputs: public_inputs.to_elements().iter().map(|e| e.as_int()).collect(),
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_stream_verdicts_and_metrics() {
+        let server = VerificationServer::new(XfgBurnMintVerifier::default(), ServerConfig::default());
+        let (client, connection) = tokio::io::duplex(1 << 20);
+        let handle = tokio::spawn({
+            let server = server.clone();
+            async move { server.handle_connection(connection).await }
+        });
+
+        let (reader, mut writer) = tokio::io::split(client);
+        let request = format!("{}\nnot json\n", verify_frame_json(7));
+        writer.write_all(request.as_bytes()).await.unwrap();
+
+        let mut lines = BufReader::new(reader).lines();
+        let mut verdicts = Vec::new();
+        for _ in 0..2 {
+            let line = lines.next_line().await.unwrap().unwrap();
+            verdicts.push(serde_json::from_str::<ServerFrame>(&line).unwrap());
+        }
+        assert!(verdicts.contains(&ServerFrame::verdict(7, STATUS_VALID, None)));
+        assert!(verdicts
+            .iter()
+            .any(|v| matches!(v, ServerFrame::Verdict { id: 0, status: STATUS_MALFORMED, .. })));
+
+        writer.write_all(b"{\"type\":\"metrics\",\"id\":8}\n").await.unwrap();
+        let line = lines.next_line().await.unwrap().unwrap();
+        match serde_json::from_str::<ServerFrame>(&line).unwrap() {
+            ServerFrame::Metrics { id, metrics, tenant: None } => {
+                assert_eq!(id, 8);
+                assert_eq!(metrics.verified, 1);
+                assert_eq!(metrics.failed, 1);
+                assert_eq!(metrics.queue_depth, 0);
+                assert_eq!(metrics.in_flight, 0);
+            }
+            other => panic!("expected metrics, got {:?}", other),
+        }
+
+        writer.shutdown().await.unwrap();
+        handle.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_oversized_frame_closes_connection() {
+        let config = ServerConfig {
+            max_frame_bytes: 1024,
+            ..ServerConfig::default()
+        };
+        let server = VerificationServer::new(XfgBurnMintVerifier::default(), config);
+        let (client, connection) = tokio::io::duplex(1 << 16);
+        let handle = tokio::spawn({
+            let server = server.clone();
+            async move { server.handle_connection(connection).await }
+        });
+
+        let (reader, mut writer) = tokio::io::split(client);
+        // A frame without a newline that would otherwise be buffered forever
+        writer.write_all(&[b'a'; 4096]).await.unwrap();
+
+        let mut lines = BufReader::new(reader).lines();
+        let line = lines.next_line().await.unwrap().unwrap();
+        assert!(matches!(
+            serde_json::from_str::<ServerFrame>(&line).unwrap(),
+            ServerFrame::Verdict { id: 0, status: STATUS_MALFORMED, .. }
+        ));
+        assert!(lines.next_line().await.unwrap().is_none());
+        handle.await.unwrap().unwrap();
+        assert_eq!(server.metrics().failed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_tenant_auth_and_quota() {
+        use crate::tenants::TenantConfig;
+
+        let tenants = TenantRegistry::new(vec![TenantConfig {
+            name: "wallet-a".to_string(),
+            api_key: "key-a".to_string(),
+            max_pending: None,
+            rate_limit: None,
+            proof_quota: Some(1),
+        }])
+        .unwrap();
+        let server =
+            VerificationServer::new(XfgBurnMintVerifier::default(), ServerConfig::default()).with_tenants(tenants);
+        let (client, connection) = tokio::io::duplex(1 << 20);
+        let handle = tokio::spawn({
+            let server = server.clone();
+            async move { server.handle_connection(connection).await }
+        });
+        let (reader, mut writer) = tokio::io::split(client);
+        let mut lines = BufReader::new(reader).lines();
+        let proof = verify_frame_json(1);
+
+        // Anonymous and badly keyed submissions are refused
+        let request = format!("{}\n{{\"type\":\"auth\",\"id\":2,\"api_key\":\"key-b\"}}\n", proof);
+        writer.write_all(request.as_bytes()).await.unwrap();
+        for expected in [1, 2] {
+            let line = lines.next_line().await.unwrap().unwrap();
+            assert!(matches!(
+                serde_json::from_str::<ServerFrame>(&line).unwrap(),
+                ServerFrame::Verdict { id, status: STATUS_UNAUTHORIZED, .. } if id == expected
+            ));
+        }
+
+        writer.write_all(b"{\"type\":\"auth\",\"id\":3,\"api_key\":\"key-a\"}\n").await.unwrap();
+        let line = lines.next_line().await.unwrap().unwrap();
+        assert_eq!(
+            serde_json::from_str::<ServerFrame>(&line).unwrap(),
+            ServerFrame::Authenticated { id: 3, tenant: "wallet-a".to_string() }
+        );
+
+        // The first proof verifies, the second is over quota
+        writer.write_all(format!("{}\n", proof).as_bytes()).await.unwrap();
+        let line = lines.next_line().await.unwrap().unwrap();
+        assert_eq!(
+            serde_json::from_str::<ServerFrame>(&line).unwrap(),
+            ServerFrame::verdict(1, STATUS_VALID, None)
+        );
+        writer.write_all(format!("{}\n", proof).as_bytes()).await.unwrap();
+        let line = lines.next_line().await.unwrap().unwrap();
+        assert!(matches!(
+            serde_json::from_str::<ServerFrame>(&line).unwrap(),
+            ServerFrame::Verdict { id: 1, status: STATUS_QUOTA_EXHAUSTED, .. }
+        ));
+
+        writer.write_all(b"{\"type\":\"metrics\",\"id\":4}\n").await.unwrap();
+        let line = lines.next_line().await.unwrap().unwrap();
+        match serde_json::from_str::<ServerFrame>(&line).unwrap() {
+            ServerFrame::Metrics { tenant: Some(tenant), .. } => {
+                assert_eq!(tenant.tenant, "wallet-a");
+                assert_eq!((tenant.accepted, tenant.verified, tenant.quota_exhausted), (1, 1, 1));
+                assert_eq!(tenant.quota_remaining, Some(0));
+            }
+            other => panic!("expected tenant metrics, got {:?}", other),
+        }
+        assert_eq!(server.tenant_metrics().len(), 1);
+
+        writer.shutdown().await.unwrap();
+        handle.await.unwrap().unwrap();
+    }
+}
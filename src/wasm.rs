@@ -0,0 +1,135 @@
+//! Browser Verification Bindings
+//!
+//! `wasm-bindgen` exports that let browser wallets verify a burn proof client-side
+//! before submitting it to the mint contract. The exports only take the verifier's
+//! decode-and-verify path, which reads no clock and touches no files, so they run on
+//! `wasm32-unknown-unknown`.
+//!
+//! Public inputs are passed as JSON: an array of integers in
+//! [`BurnMintPublicInputs::to_elements`](winter_math::ToElements::to_elements) order, the
+//! same shape the verification server accepts.
+
+use crate::burn_mint_air::BurnMintPublicInputs;
+use crate::burn_mint_verifier::XfgBurnMintVerifier;
+use thiserror::Error;
+use wasm_bindgen::prelude::*;
+use winterfell::math::fields::f64::BaseElement;
+
+/// Proof or public inputs the bindings cannot use
+#[derive(Debug, Error)]
+pub enum WasmError {
+    /// The public inputs are not a JSON array of integers
+    #[error("Public inputs are not a JSON array of integers: {0}")]
+    PublicInputsJson(String),
+    /// The public inputs do not follow the burn & mint layout
+    #[error("Public inputs have {0} elements, which is not a burn & mint claim")]
+    PublicInputsLayout(usize),
+    /// The proof bytes do not decode
+    #[error("Invalid proof: {0}")]
+    Proof(String),
+}
+
+/// Shape and security of a decoded proof, for display before submission
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofSummary {
+    /// Registers in the execution trace
+    pub trace_width: u32,
+    /// Steps in the execution trace
+    pub trace_length: u32,
+    /// Number of FRI queries
+    pub num_queries: u32,
+    /// Blowup factor of the low-degree extension
+    pub blowup_factor: u32,
+    /// Conjectured security of the proof (bits)
+    pub security_bits: u32,
+}
+
+/// Verify a serialized burn & mint proof against JSON public inputs
+///
+/// Returns `false` for proofs or public inputs that do not decode as well as for proofs
+/// that do not verify.
+#[wasm_bindgen]
+pub fn verify_burn_mint_proof(proof_bytes: &[u8], public_inputs_json: &str) -> bool {
+    verify_proof(proof_bytes, public_inputs_json).unwrap_or(false)
+}
+
+/// Decode a serialized burn & mint proof within the verifier's decode limits
+#[wasm_bindgen]
+pub fn deserialize_proof(proof_bytes: &[u8]) -> Result<ProofSummary, JsError> {
+    summarize_proof(proof_bytes).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Parse public inputs from a JSON array of field elements
+pub fn parse_public_inputs(json: &str) -> Result<BurnMintPublicInputs, WasmError> {
+    let values: Vec<u64> = serde_json::from_str(json).map_err(|e| WasmError::PublicInputsJson(e.to_string()))?;
+    let elements: Vec<BaseElement> = values.into_iter().map(BaseElement::new).collect();
+    BurnMintPublicInputs::from_elements(&elements).ok_or(WasmError::PublicInputsLayout(elements.len()))
+}
+
+/// Decode and verify a proof with the default verifier
+fn verify_proof(proof_bytes: &[u8], public_inputs_json: &str) -> Result<bool, WasmError> {
+    let public_inputs = parse_public_inputs(public_inputs_json)?;
+    let verifier = XfgBurnMintVerifier::default();
+    let proof = verifier.decode_bounded(proof_bytes).map_err(|e| WasmError::Proof(e.to_string()))?;
+    verifier
+        .verify_with_public_inputs(&proof, &public_inputs)
+        .map_err(|e| WasmError::Proof(e.to_string()))
+}
+
+/// Decode a proof with the default verifier's limits and summarize it
+fn summarize_proof(proof_bytes: &[u8]) -> Result<ProofSummary, WasmError> {
+    let proof = XfgBurnMintVerifier::default()
+        .decode_bounded(proof_bytes)
+        .map_err(|e| WasmError::Proof(e.to_string()))?;
+    let options = proof.options();
+    Ok(ProofSummary {
+        trace_width: proof.get_trace_info().width() as u32,
+        trace_length: proof.trace_length() as u32,
+        num_queries: options.num_queries() as u32,
+        blowup_factor: options.blowup_factor() as u32,
+        security_bits: crate::soundness::conjectured_security_bits(options, proof.trace_length()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn_mint_prover::{BurnMintRequest, XfgBurnMintProver};
+    use winter_math::{StarkField, ToElements};
+
+    #[test]
+    fn test_verify_from_bytes_and_json() {
+        let (proof, public_inputs) = XfgBurnMintProver::default()
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed");
+        let proof_bytes = proof.to_bytes();
+        let elements: Vec<u64> = public_inputs.to_elements().iter().map(|e| e.as_int()).collect();
+        let json = serde_json::to_string(&elements).unwrap();
+        assert!(verify_burn_mint_proof(&proof_bytes, &json));
+
+        let summary = summarize_proof(&proof_bytes).unwrap();
+        assert_eq!(summary.trace_length, 64);
+        assert_eq!(summary.trace_width, crate::burn_mint_air::TRACE_WIDTH as u32);
+
+        // Another claim, malformed JSON and truncated proofs all verify as false
+        let mut other = elements.clone();
+        other[1] += 1;
+        assert!(!verify_burn_mint_proof(&proof_bytes, &serde_json::to_string(&other).unwrap()));
+        assert!(!verify_burn_mint_proof(&proof_bytes, "{\"burn_amount\": 8000000}"));
+        assert!(!verify_burn_mint_proof(&proof_bytes[..proof_bytes.len() / 2], &json));
+        assert!(matches!(parse_public_inputs("[1, 2, 3]"), Err(WasmError::PublicInputsLayout(3))));
+        assert!(matches!(summarize_proof(&[0u8; 16]), Err(WasmError::Proof(_))));
+    }
+}
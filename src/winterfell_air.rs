@@ -9,6 +9,7 @@ use winterfell::{
 };
 use sha3::{Keccak256, Digest};
 use crate::{
+    clock::{system_clock, SharedClock},
     types::field::PrimeField64,
     types::stark::StarkProof as XfgStarkProof,
     field_conversion::FieldConverter,
@@ -129,6 +130,8 @@ impl Air for XfgBurnAir {
 /// Winterfell Prover for XFG Burns
 pub struct XfgWinterfellProver {
     proof_options: ProofOptions,
+    /// Time source for proof metadata
+    clock: SharedClock,
 }
 
 impl XfgWinterfellProver {
@@ -143,7 +146,14 @@ impl XfgWinterfellProver {
             31, // FRI remainder max degree
         );
         
-        Self { proof_options }
+        Self { proof_options, clock: system_clock() }
+    }
+
+    /// Stamp proof metadata with `clock` instead of the wall clock
+    #[must_use]
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
     }
     
     /// Prove XFG burn using real Winterfell STARK proof generation
@@ -236,10 +246,7 @@ impl XfgWinterfellProver {
             security_parameter: 128,
             field_modulus: "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47".to_string(),
             proof_size: winterfell_proof.to_bytes().len(),
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
+            timestamp: self.clock.unix_seconds(),
         };
         
         Ok(StarkProof {
@@ -452,9 +459,9 @@ impl XfgWinterfellVerifier {
             return Err(anyhow::anyhow!("Invalid nullifier"));
         }
         
-        // Validate amount (0.8 XFG or 800 XFG)
+        // Validate amount (1 to 2^53 - 1 atomic units)
         let amount = proof_data.cryptographic_data.xfg_amount;
-               if amount != 800000 && amount != 8000000000 {
+        if !crate::constants::is_valid_burn_amount(amount) {
             return Err(anyhow::anyhow!("Invalid XFG amount"));
         }
         
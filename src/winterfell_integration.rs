@@ -2,18 +2,28 @@
 //! 
 //! This module provides seamless integration between the XFG STARK type system
 //! and the Winterfell framework for STARK proof generation and verification.
+//!
+//! ## Field Conversion
+//!
+//! [`PrimeField64`] and Winterfell's [`BaseElement`] are both the Goldilocks field
+//! `2^64 - 2^32 + 1`, so values convert between them losslessly. Conversions from a
+//! generic [`XfgFieldElement`] are checked: the field's modulus must match Winterfell's
+//! and the value must be canonical, otherwise a [`TypeError::InvalidConversion`] is
+//! returned instead of a silently wrong element.
 
 use std::fmt::{Display, Formatter};
 use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Neg};
 use winterfell::ProofOptions;
 use winterfell::FieldExtension;
+use winterfell::math::{fields::f64::BaseElement, StarkField};
 
 
 use crate::{
+    clock::{system_clock, SharedClock},
     types::{
         field::PrimeField64,
         stark::{StarkProof, ExecutionTrace, Air, StarkError, FriProof, ProofMetadata, Constraint, BoundaryConstraint, ConstraintType},
-        FieldElement as XfgFieldElement,
+        FieldElement as XfgFieldElement, TypeError,
     },
     Result, XfgStarkError,
 };
@@ -57,6 +67,93 @@ impl WinterfellFieldElement {
     pub fn value(&self) -> PrimeField64 {
         self.0
     }
+
+    /// Convert an element of any XFG field, checking it is Winterfell's field
+    ///
+    /// # Errors
+    ///
+    /// As [`to_base_element`]
+    pub fn try_from_xfg<F: XfgFieldElement>(element: &F) -> Result<Self> {
+        Ok(Self::from(to_base_element(element)?))
+    }
+
+    /// Convert into an element of any XFG field, checking it is Winterfell's field
+    ///
+    /// # Errors
+    ///
+    /// [`XfgStarkError::TypeError`] if `F` is not Winterfell's field
+    pub fn to_xfg<F: XfgFieldElement>(&self) -> Result<F> {
+        from_base_element(BaseElement::from(*self))
+    }
+}
+
+// Both fields are Goldilocks; every conversion below relies on it
+const _: () = assert!(PrimeField64::MODULUS == <BaseElement as StarkField>::MODULUS);
+
+impl From<PrimeField64> for BaseElement {
+    fn from(field: PrimeField64) -> Self {
+        BaseElement::new(field.value())
+    }
+}
+
+impl From<BaseElement> for PrimeField64 {
+    fn from(element: BaseElement) -> Self {
+        PrimeField64::new(element.as_int())
+    }
+}
+
+impl From<BaseElement> for WinterfellFieldElement {
+    fn from(element: BaseElement) -> Self {
+        Self(PrimeField64::from(element))
+    }
+}
+
+impl From<WinterfellFieldElement> for BaseElement {
+    fn from(winterfell_field: WinterfellFieldElement) -> Self {
+        BaseElement::from(winterfell_field.0)
+    }
+}
+
+/// Check that an XFG field has Winterfell's modulus
+///
+/// # Errors
+///
+/// [`XfgStarkError::TypeError`] if the moduli differ
+pub fn check_modulus_compatibility<F: XfgFieldElement>() -> Result<()> {
+    if F::MODULUS != <BaseElement as StarkField>::MODULUS {
+        return Err(TypeError::InvalidConversion(format!(
+            "field modulus {:#x} is not Winterfell's {:#x}",
+            F::MODULUS,
+            <BaseElement as StarkField>::MODULUS
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Convert an XFG field element to a Winterfell base element without loss
+///
+/// # Errors
+///
+/// [`XfgStarkError::TypeError`] if `F` is not Winterfell's field or `element` is not
+/// canonical
+pub fn to_base_element<F: XfgFieldElement>(element: &F) -> Result<BaseElement> {
+    check_modulus_compatibility::<F>()?;
+    let value = element.value();
+    if value >= <BaseElement as StarkField>::MODULUS {
+        return Err(TypeError::InvalidConversion(format!("{value} is not a canonical field element")).into());
+    }
+    Ok(BaseElement::new(value))
+}
+
+/// Convert a Winterfell base element to an XFG field element without loss
+///
+/// # Errors
+///
+/// [`XfgStarkError::TypeError`] if `F` is not Winterfell's field
+pub fn from_base_element<F: XfgFieldElement>(element: BaseElement) -> Result<F> {
+    check_modulus_compatibility::<F>()?;
+    Ok(F::new(element.as_int()))
 }
 
 // Standard arithmetic trait implementations
@@ -167,10 +264,18 @@ pub struct WinterfellProof<F: XfgFieldElement> {
 pub struct WinterfellFriProof {
     /// FRI layers
     pub layers: Vec<Vec<u8>>,
+    /// Commitment of each layer
+    pub layer_commitments: Vec<Vec<u8>>,
+    /// Degree bound of each layer
+    pub layer_degrees: Vec<usize>,
+    /// Opening of each layer at the queried positions
+    pub openings: Vec<crate::proof::merkle::MultiProof>,
     /// Final polynomial
     pub final_polynomial: Vec<u8>,
     /// Query responses
     pub queries: Vec<Vec<u8>>,
+    /// Serialized evaluation domain (see [`crate::types::stark::FriDomain::to_bytes`])
+    pub domain: Vec<u8>,
 }
 
 /// Winterfell proof metadata for XFG STARK integration
@@ -202,26 +307,32 @@ pub struct WinterfellTraceTable {
 
 impl WinterfellTraceTable {
     /// Create a new trace table from XFG execution trace
-    pub fn from_xfg_trace<F: XfgFieldElement>(trace: &ExecutionTrace<F>) -> Self {
+    ///
+    /// # Errors
+    ///
+    /// [`XfgStarkError::StarkError`] if the trace's columns do not match its dimensions, or
+    /// [`XfgStarkError::TypeError`] if its field is not Winterfell's
+    pub fn from_xfg_trace<F: XfgFieldElement>(trace: &ExecutionTrace<F>) -> Result<Self> {
         let num_rows = trace.length;
         let num_cols = trace.num_registers;
+        if trace.columns.len() != num_cols || trace.columns.iter().any(|column| column.len() != num_rows) {
+            return Err(XfgStarkError::StarkError(StarkError::InvalidTrace(format!(
+                "Trace columns do not match {num_cols} registers of {num_rows} rows"
+            ))));
+        }
+
         let mut data = vec![vec![WinterfellFieldElement::default(); num_cols]; num_rows];
-        
         for (i, column) in trace.columns.iter().enumerate() {
-            for (j, &value) in column.iter().enumerate() {
-                if i < num_cols && j < num_rows {
-                    // For now, use a placeholder conversion since we can't access the raw value
-                    // In a real implementation, we'd need to add methods to the FieldElement trait
-                    data[j][i] = WinterfellFieldElement::default();
-                }
+            for (j, value) in column.iter().enumerate() {
+                data[j][i] = WinterfellFieldElement::try_from_xfg(value)?;
             }
         }
         
-        Self {
+        Ok(Self {
             num_rows,
             num_cols,
             data,
-        }
+        })
     }
     
     /// Get value at position
@@ -246,23 +357,26 @@ impl WinterfellTraceTable {
     }
     
     /// Convert back to XFG execution trace
-    pub fn into_xfg_trace<F: XfgFieldElement>(self) -> ExecutionTrace<F> {
+    ///
+    /// # Errors
+    ///
+    /// [`XfgStarkError::TypeError`] if `F` is not Winterfell's field
+    pub fn into_xfg_trace<F: XfgFieldElement>(self) -> Result<ExecutionTrace<F>> {
         let mut columns = vec![vec![F::zero(); self.num_rows]; self.num_cols];
         
         for (i, row) in self.data.iter().enumerate() {
-            for (j, &value) in row.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
                 if i < self.num_rows && j < self.num_cols {
-                    // For now, use zero as placeholder since we can't convert back properly
-                    columns[j][i] = F::zero();
+                    columns[j][i] = value.to_xfg()?;
                 }
             }
         }
         
-        ExecutionTrace {
+        Ok(ExecutionTrace {
             columns,
             length: self.num_rows,
             num_registers: self.num_cols,
-        }
+        })
     }
 }
 
@@ -270,19 +384,26 @@ impl WinterfellTraceTable {
 /// XFG STARK prover using Winterfell framework
 pub struct XfgWinterfellProver {
     proof_options: ProofOptions,
+    /// Time source for proof metadata
+    clock: SharedClock,
 }
 
 impl XfgWinterfellProver {
     /// Create a new prover with default options
     pub fn new() -> Self {
-        Self {
-            proof_options: ProofOptions::new(16, 8, 1, winterfell::FieldExtension::None, 8, 31),
-        }
+        Self::with_options(ProofOptions::new(16, 8, 1, winterfell::FieldExtension::None, 8, 31))
     }
     
     /// Create a new prover with custom options
     pub fn with_options(proof_options: ProofOptions) -> Self {
-        Self { proof_options }
+        Self { proof_options, clock: system_clock() }
+    }
+
+    /// Stamp proof metadata with `clock` instead of the wall clock
+    #[must_use]
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
     }
     
     /// Generate a STARK proof
@@ -292,7 +413,7 @@ impl XfgWinterfellProver {
         air: &Air<F>,
     ) -> Result<StarkProof<F>> {
         // Convert to Winterfell format
-        let winterfell_trace = WinterfellTraceTable::from_xfg_trace(trace);
+        let winterfell_trace = WinterfellTraceTable::from_xfg_trace(trace)?;
         let winterfell_air = self.convert_air_to_winterfell(air)?;
         
         // Generate proof using Winterfell
@@ -339,10 +460,7 @@ impl XfgWinterfellProver {
         register: usize,
     ) -> Result<WinterfellConstraint<F>> {
         // Convert coefficients to Winterfell field elements
-        let winterfell_coefficients: Vec<WinterfellFieldElement> = coefficients
-            .iter()
-            .map(|&coeff| WinterfellFieldElement::new(coeff.value()))
-            .collect();
+        let winterfell_coefficients = utils::convert_field_elements(coefficients)?;
         
         Ok(WinterfellConstraint {
             coefficients: winterfell_coefficients,
@@ -358,7 +476,7 @@ impl XfgWinterfellProver {
         &self,
         constraint: &BoundaryConstraint<F>,
     ) -> Result<WinterfellConstraint<F>> {
-        let winterfell_value = WinterfellFieldElement::new(constraint.value.value());
+        let winterfell_value = WinterfellFieldElement::try_from_xfg(&constraint.value)?;
         
         Ok(WinterfellConstraint {
             coefficients: vec![winterfell_value],
@@ -374,11 +492,7 @@ impl XfgWinterfellProver {
         &self,
         constraint: &Constraint<F>,
     ) -> Result<WinterfellConstraint<F>> {
-        let winterfell_coefficients: Vec<WinterfellFieldElement> = constraint
-            .polynomial
-            .iter()
-            .map(|&coeff| WinterfellFieldElement::new(coeff.value()))
-            .collect();
+        let winterfell_coefficients = utils::convert_field_elements(&constraint.polynomial)?;
         
         Ok(WinterfellConstraint {
             coefficients: winterfell_coefficients,
@@ -410,10 +524,7 @@ impl XfgWinterfellProver {
             security_parameter: air.security_parameter,
             field_modulus: "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47".to_string(),
             proof_size: trace.num_rows * trace.num_cols,
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
+            timestamp: self.clock.unix_seconds(),
         };
         
         Ok(WinterfellProof {
@@ -500,6 +611,10 @@ impl XfgWinterfellProver {
             .map_err(|e| XfgStarkError::StarkError(StarkError::FriError(e.to_string())))?;
         
         // Convert FRI proof to Winterfell format
+        let domain = fri_proof.domain.to_bytes();
+        let layer_commitments = fri_proof.layers.iter().map(|layer| layer.commitment.clone()).collect();
+        let layer_degrees = fri_proof.layers.iter().map(|layer| layer.degree).collect();
+        let openings = fri_proof.openings;
         let layers = fri_proof.layers.into_iter()
             .map(|layer| {
                 // Convert layer polynomial to bytes
@@ -529,8 +644,12 @@ impl XfgWinterfellProver {
         
         Ok(WinterfellFriProof {
             layers,
+            layer_commitments,
+            layer_degrees,
+            openings,
             final_polynomial,
             queries,
+            domain,
         })
     }
     
@@ -542,9 +661,7 @@ impl XfgWinterfellProver {
         
         for row in 0..trace.num_rows {
             if let Some(value) = trace.get(row, 0) {
-                // Convert WinterfellFieldElement to F
-                let field_value = F::from_bytes(&value.value().to_bytes()).unwrap_or(F::zero());
-                polynomial.push(field_value);
+                polynomial.push(value.to_xfg()?);
             } else {
                 polynomial.push(F::zero());
             }
@@ -558,11 +675,12 @@ impl XfgWinterfellProver {
         &self,
         winterfell_fri: &WinterfellFriProof,
     ) -> Result<crate::types::stark::FriProof<F>> {
-        use crate::types::stark::{FriProof, FriLayer, FriQuery};
+        use crate::types::stark::{FriDomain, FriProof, FriLayer, FriQuery};
         
         // Convert layers
         let layers = winterfell_fri.layers.iter()
-            .map(|layer_bytes| {
+            .enumerate()
+            .map(|(i, layer_bytes)| {
                 // Convert bytes back to field elements
                 let mut polynomial = Vec::new();
                 for chunk in layer_bytes.chunks(32) {
@@ -575,11 +693,10 @@ impl XfgWinterfellProver {
                     }
                 }
                 
-                let degree = polynomial.len();
                 FriLayer {
                     polynomial,
-                    commitment: vec![], // Simplified
-                    degree,
+                    commitment: winterfell_fri.layer_commitments.get(i).cloned().unwrap_or_default(),
+                    degree: winterfell_fri.layer_degrees.get(i).copied().unwrap_or_default(),
                 }
             })
             .collect();
@@ -621,7 +738,10 @@ impl XfgWinterfellProver {
                     }
                 }
                 
-                let point = F::zero(); // Simplified - would need proper parsing
+                let point = query_bytes
+                    .get(..32)
+                    .and_then(|bytes| F::from_bytes(bytes.try_into().ok()?))
+                    .unwrap_or(F::zero());
                 
                 FriQuery {
                     point,
@@ -630,10 +750,15 @@ impl XfgWinterfellProver {
             })
             .collect();
         
+        let domain = FriDomain::from_bytes(&winterfell_fri.domain)
+            .unwrap_or_else(FriDomain::empty);
+        
         Ok(FriProof {
             layers,
             final_polynomial,
             queries,
+            openings: winterfell_fri.openings.clone(),
+            domain,
         })
     }
     
@@ -643,6 +768,9 @@ impl XfgWinterfellProver {
         xfg_fri: &crate::types::stark::FriProof<F>,
     ) -> Result<WinterfellFriProof> {
         // Convert layers
+        let layer_commitments = xfg_fri.layers.iter().map(|layer| layer.commitment.clone()).collect();
+        let layer_degrees = xfg_fri.layers.iter().map(|layer| layer.degree).collect();
+        let openings = xfg_fri.openings.clone();
         let layers = xfg_fri.layers.iter()
             .map(|layer| {
                 // Convert layer polynomial to bytes
@@ -674,8 +802,12 @@ impl XfgWinterfellProver {
         
         Ok(WinterfellFriProof {
             layers,
+            layer_commitments,
+            layer_degrees,
+            openings,
             final_polynomial,
             queries,
+            domain: xfg_fri.domain.to_bytes(),
         })
     }
     
@@ -755,7 +887,7 @@ impl XfgWinterfellVerifier {
     /// Convert XFG proof to Winterfell format
     fn convert_xfg_proof_to_winterfell<F: XfgFieldElement>(&self, proof: &StarkProof<F>) -> Result<WinterfellProof<F>> {
         // Convert trace
-        let trace = WinterfellTraceTable::from_xfg_trace(&proof.trace);
+        let trace = WinterfellTraceTable::from_xfg_trace(&proof.trace)?;
         
         // Convert AIR
         let air = self.convert_air_to_winterfell(&proof.air)?;
@@ -823,10 +955,7 @@ impl XfgWinterfellVerifier {
         register: usize,
     ) -> Result<WinterfellConstraint<F>> {
         // Convert coefficients to Winterfell field elements
-        let winterfell_coefficients: Vec<WinterfellFieldElement> = coefficients
-            .iter()
-            .map(|&coeff| WinterfellFieldElement::new(coeff.value()))
-            .collect();
+        let winterfell_coefficients = utils::convert_field_elements(coefficients)?;
         
         Ok(WinterfellConstraint {
             coefficients: winterfell_coefficients,
@@ -842,7 +971,7 @@ impl XfgWinterfellVerifier {
         &self,
         constraint: &BoundaryConstraint<F>,
     ) -> Result<WinterfellConstraint<F>> {
-        let winterfell_value = WinterfellFieldElement::new(constraint.value.value());
+        let winterfell_value = WinterfellFieldElement::try_from_xfg(&constraint.value)?;
         
         Ok(WinterfellConstraint {
             coefficients: vec![winterfell_value],
@@ -858,11 +987,7 @@ impl XfgWinterfellVerifier {
         &self,
         constraint: &Constraint<F>,
     ) -> Result<WinterfellConstraint<F>> {
-        let winterfell_coefficients: Vec<WinterfellFieldElement> = constraint
-            .polynomial
-            .iter()
-            .map(|&coeff| WinterfellFieldElement::new(coeff.value()))
-            .collect();
+        let winterfell_coefficients = utils::convert_field_elements(&constraint.polynomial)?;
         
         Ok(WinterfellConstraint {
             coefficients: winterfell_coefficients,
@@ -1079,9 +1204,7 @@ impl XfgWinterfellVerifier {
         
         for row in 0..trace.num_rows {
             if let Some(value) = trace.get(row, 0) {
-                // Convert WinterfellFieldElement to F
-                let field_value = F::from_bytes(&value.value().to_bytes()).unwrap_or(F::zero());
-                polynomial.push(field_value);
+                polynomial.push(value.to_xfg()?);
             } else {
                 polynomial.push(F::zero());
             }
@@ -1095,11 +1218,12 @@ impl XfgWinterfellVerifier {
         &self,
         winterfell_fri: &WinterfellFriProof,
     ) -> Result<crate::types::stark::FriProof<F>> {
-        use crate::types::stark::{FriProof, FriLayer, FriQuery};
+        use crate::types::stark::{FriDomain, FriProof, FriLayer, FriQuery};
         
         // Convert layers
         let layers = winterfell_fri.layers.iter()
-            .map(|layer_bytes| {
+            .enumerate()
+            .map(|(i, layer_bytes)| {
                 // Convert bytes back to field elements
                 let mut polynomial = Vec::new();
                 for chunk in layer_bytes.chunks(32) {
@@ -1112,11 +1236,10 @@ impl XfgWinterfellVerifier {
                     }
                 }
                 
-                let degree = polynomial.len();
                 FriLayer {
-                    polynomial: polynomial.clone(),
-                    commitment: vec![], // Simplified
-                    degree,
+                    polynomial,
+                    commitment: winterfell_fri.layer_commitments.get(i).cloned().unwrap_or_default(),
+                    degree: winterfell_fri.layer_degrees.get(i).copied().unwrap_or_default(),
                 }
             })
             .collect();
@@ -1157,7 +1280,10 @@ impl XfgWinterfellVerifier {
                     }
                 }
                 
-                let point = F::zero(); // Simplified - would need proper parsing
+                let point = query_bytes
+                    .get(..32)
+                    .and_then(|bytes| F::from_bytes(bytes.try_into().ok()?))
+                    .unwrap_or(F::zero());
                 
                 FriQuery {
                     point,
@@ -1166,10 +1292,15 @@ impl XfgWinterfellVerifier {
             })
             .collect();
         
+        let domain = FriDomain::from_bytes(&winterfell_fri.domain)
+            .unwrap_or_else(FriDomain::empty);
+        
         Ok(FriProof {
             layers,
             final_polynomial,
             queries,
+            openings: winterfell_fri.openings.clone(),
+            domain,
         })
     }
     
@@ -1179,6 +1310,9 @@ impl XfgWinterfellVerifier {
         xfg_fri: &crate::types::stark::FriProof<F>,
     ) -> Result<WinterfellFriProof> {
         // Convert layers
+        let layer_commitments = xfg_fri.layers.iter().map(|layer| layer.commitment.clone()).collect();
+        let layer_degrees = xfg_fri.layers.iter().map(|layer| layer.degree).collect();
+        let openings = xfg_fri.openings.clone();
         let layers = xfg_fri.layers.iter()
             .map(|layer| {
                 // Convert layer polynomial to bytes
@@ -1210,8 +1344,12 @@ impl XfgWinterfellVerifier {
         
         Ok(WinterfellFriProof {
             layers,
+            layer_commitments,
+            layer_degrees,
+            openings,
             final_polynomial,
             queries,
+            domain: xfg_fri.domain.to_bytes(),
         })
     }
 }
@@ -1223,27 +1361,15 @@ pub mod utils {
     /// Convert field elements from XFG to Winterfell format
     pub fn convert_field_elements<F: XfgFieldElement>(
         elements: &[F],
-    ) -> Vec<WinterfellFieldElement> {
-        elements
-            .iter()
-            .map(|_element| {
-                // TODO: Placeholder conversion - in actual implementation, we add methods to the FieldElement trait
-                WinterfellFieldElement::default()
-            })
-            .collect()
+    ) -> Result<Vec<WinterfellFieldElement>> {
+        elements.iter().map(WinterfellFieldElement::try_from_xfg).collect()
     }
     
     /// Convert field elements from Winterfell to XFG format
     pub fn convert_back_field_elements<F: XfgFieldElement>(
         elements: &[WinterfellFieldElement],
-    ) -> Vec<F> {
-        elements
-            .iter()
-            .map(|_element| {
-                // TODO: Placeholder conversion - in actual implementation, we add methods to the FieldElement trait
-                F::zero()
-            })
-            .collect()
+    ) -> Result<Vec<F>> {
+        elements.iter().map(WinterfellFieldElement::to_xfg).collect()
     }
     
     /// Default proof options for XFG STARK
@@ -1317,15 +1443,55 @@ mod tests {
             num_registers: 2,
         };
         
-        let winterfell_trace = WinterfellTraceTable::from_xfg_trace(&trace);
+        let winterfell_trace = WinterfellTraceTable::from_xfg_trace(&trace).unwrap();
         
         assert_eq!(winterfell_trace.num_rows, 2);
         assert_eq!(winterfell_trace.num_cols, 2);
         
-        // Test getting values (placeholder conversion returns default values)
-        assert_eq!(winterfell_trace.get(0, 0).unwrap().value(), PrimeField64::zero());
-        assert_eq!(winterfell_trace.get(1, 1).unwrap().value(), PrimeField64::zero());
+        // Rows of the table are steps of the trace
+        assert_eq!(winterfell_trace.get(0, 0).unwrap().value(), PrimeField64::new(1));
+        assert_eq!(winterfell_trace.get(0, 1).unwrap().value(), PrimeField64::new(3));
+        assert_eq!(winterfell_trace.get(1, 1).unwrap().value(), PrimeField64::new(4));
+
+        let round_trip: ExecutionTrace<PrimeField64> = winterfell_trace.into_xfg_trace().unwrap();
+        assert_eq!(round_trip.columns, trace.columns);
+
+    }
 
+    #[test]
+    fn test_base_element_round_trip() {
+        check_modulus_compatibility::<PrimeField64>().unwrap();
+
+        for value in [0, 1, 42, 1 << 32, PrimeField64::MODULUS - 1] {
+            let xfg_field = PrimeField64::new(value);
+            let base = to_base_element(&xfg_field).unwrap();
+            assert_eq!(base.as_int(), value);
+            assert_eq!(BaseElement::from(xfg_field), base);
+            assert_eq!(from_base_element::<PrimeField64>(base).unwrap(), xfg_field);
+            assert_eq!(PrimeField64::from(base), xfg_field);
+        }
+
+        // Arithmetic agrees on both sides of the conversion
+        let (a, b) = (PrimeField64::new(PrimeField64::MODULUS - 2), PrimeField64::new(5));
+        assert_eq!(BaseElement::from(a * b), BaseElement::from(a) * BaseElement::from(b));
+        assert_eq!(BaseElement::from(a + b), BaseElement::from(a) + BaseElement::from(b));
+    }
+
+    #[test]
+    fn test_trace_table_rejects_mismatched_columns() {
+        let trace = ExecutionTrace {
+            columns: vec![
+                vec![PrimeField64::new(1), PrimeField64::new(2)],
+                vec![PrimeField64::new(3)],
+            ],
+            length: 2,
+            num_registers: 2,
+        };
+
+        assert!(matches!(
+            WinterfellTraceTable::from_xfg_trace(&trace),
+            Err(XfgStarkError::StarkError(StarkError::InvalidTrace(_)))
+        ));
     }
 
     #[test]
@@ -1338,7 +1504,7 @@ mod tests {
             num_registers: 1,
         };
         
-        let mut winterfell_trace = WinterfellTraceTable::from_xfg_trace(&trace);
+        let mut winterfell_trace = WinterfellTraceTable::from_xfg_trace(&trace).unwrap();
 
         
         // Set a new value
@@ -1397,6 +1563,12 @@ mod tests {
         
         let proof = result.unwrap();
         assert_eq!(proof.metadata.security_parameter, 128);
+
+        // Metadata timestamps come from the prover's clock
+        let clock = crate::clock::FixedClock::from_unix_seconds(1_705_312_200);
+        let pinned = XfgWinterfellProver::new().with_clock(std::sync::Arc::new(clock)).prove(&trace, &air).unwrap();
+        assert_eq!(pinned.metadata.timestamp, 1_705_312_200);
+        assert_eq!(StarkProof::<PrimeField64>::new_dummy_with_clock(&clock).metadata.timestamp, 1_705_312_200);
     }
 
     #[test]
@@ -1429,6 +1601,8 @@ mod tests {
                 final_polynomial: vec![],
 
                 queries: vec![],
+                openings: vec![],
+                domain: crate::types::stark::FriDomain::empty(),
             },
             metadata: crate::types::stark::ProofMetadata {
                 version: 1,
@@ -1447,7 +1621,7 @@ mod tests {
 
     #[test]
     fn test_utils_functions() {
-        // Test field element conversion (placeholder conversion returns default values)
+        // Test field element conversion round-trips values
 
         let xfg_elements = vec![
             PrimeField64::new(1),
@@ -1455,16 +1629,10 @@ mod tests {
             PrimeField64::new(3),
         ];
         
-        let winterfell_elements = utils::convert_field_elements(&xfg_elements);
-        let converted_back: Vec<PrimeField64> = utils::convert_back_field_elements(&winterfell_elements);
+        let winterfell_elements = utils::convert_field_elements(&xfg_elements).unwrap();
+        let converted_back: Vec<PrimeField64> = utils::convert_back_field_elements(&winterfell_elements).unwrap();
         
-        // Placeholder conversion returns zero values, so we expect all zeros
-        let expected_zeros = vec![
-            PrimeField64::zero(),
-            PrimeField64::zero(),
-            PrimeField64::zero(),
-        ];
-        assert_eq!(converted_back, expected_zeros);
+        assert_eq!(converted_back, xfg_elements);
         
         // Test proof options
         let default_options = ProofOptions::new(
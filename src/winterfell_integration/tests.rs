@@ -5,7 +5,7 @@
 
 use super::*;
 use crate::types::field::PrimeField64;
-use crate::types::stark::{ExecutionTrace, Air, TransitionFunction, BoundaryConditions};
+use crate::types::stark::{ExecutionTrace, Air, TransitionFunction, BoundaryConditions, FriDomain};
 
 #[test]
 fn test_winterfell_field_element_creation() {
@@ -241,6 +241,8 @@ fn test_xfg_winterfell_verifier_verification() {
             layers: vec![],
             final_polynomial: vec![PrimeField64::new(1)],
             queries: vec![],
+            openings: vec![],
+            domain: FriDomain::empty(),
         },
         metadata: ProofMetadata {
             version: 1,
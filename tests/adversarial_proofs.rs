@@ -0,0 +1,66 @@
+//! Adversarial Proof Tests
+//!
+//! End-to-end tests asserting that a burn & mint proof survives serialization and
+//! verifies from bytes, and that the verifier rejects tampered claims, reused
+//! nullifiers and truncated proofs. The cases themselves live in
+//! `xfg_stark::test_harness` so gateways can reuse them.
+
+use xfg_stark::{
+    burn_mint_prover::XfgBurnMintProver,
+    burn_mint_verifier::XfgBurnMintVerifier,
+    test_harness::{run_adversarial_case, run_all_adversarial_cases, run_round_trip, AdversarialCase},
+};
+
+fn assert_rejected(case: AdversarialCase) {
+    let prover = XfgBurnMintProver::default();
+    let verifier = XfgBurnMintVerifier::default();
+
+    let fixture = run_round_trip(&prover, &verifier).expect("Honest proof should round-trip and verify");
+    let outcome = run_adversarial_case(&verifier, &fixture, case).expect("Case should run against the honest proof");
+    assert!(outcome.rejected, "{} was not rejected: {}", case, outcome.detail);
+}
+
+#[test]
+fn test_round_trip_verifies() {
+    let prover = XfgBurnMintProver::default();
+    let verifier = XfgBurnMintVerifier::default();
+
+    let fixture = run_round_trip(&prover, &verifier).expect("Honest proof should round-trip and verify");
+    assert_eq!(fixture.proof.to_bytes(), fixture.proof_bytes);
+    assert!(verifier
+        .verify_with_public_inputs(&fixture.proof, &fixture.public_inputs)
+        .expect("Honest claim should be checked"));
+}
+
+#[test]
+fn test_wrong_amount_rejected() {
+    assert_rejected(AdversarialCase::WrongAmount);
+}
+
+#[test]
+fn test_wrong_recipient_rejected() {
+    assert_rejected(AdversarialCase::WrongRecipient);
+}
+
+#[test]
+fn test_reused_nullifier_rejected() {
+    assert_rejected(AdversarialCase::ReusedNullifier);
+}
+
+#[test]
+fn test_truncated_proof_rejected() {
+    assert_rejected(AdversarialCase::TruncatedProof);
+}
+
+#[test]
+fn test_all_adversarial_cases_rejected() {
+    let prover = XfgBurnMintProver::default();
+    let verifier = XfgBurnMintVerifier::default();
+
+    let outcomes = run_all_adversarial_cases(&prover, &verifier).expect("All cases should run against an honest proof");
+    assert_eq!(outcomes.len(), AdversarialCase::all().len());
+
+    for outcome in outcomes {
+        assert!(outcome.rejected, "{} was not rejected: {}", outcome.case, outcome.detail);
+    }
+}
@@ -0,0 +1,88 @@
+//! Deterministic Verifier Allocator Tests
+//!
+//! Runs the deterministic verifier under a global allocator that can move every heap
+//! block, and asserts the verification transcript does not depend on where the
+//! allocator places memory. This lives in its own test binary because a
+//! `#[global_allocator]` applies to the whole binary.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, Ordering};
+use winter_math::ToElements;
+use xfg_stark::{burn_mint_prover::{BurnMintRequest, XfgBurnMintProver}, deterministic::DeterministicVerifier};
+
+/// Whether new allocations are displaced
+static DISPLACE: AtomicBool = AtomicBool::new(false);
+
+/// System allocator that offsets every block by a header, twice as far when displaced
+///
+/// The offset is recorded in front of the returned pointer, so blocks allocated in
+/// either mode can be freed in the other.
+struct DisplacingAllocator;
+
+impl DisplacingAllocator {
+    fn padding(layout: Layout) -> usize {
+        layout.align().max(2 * std::mem::size_of::<usize>())
+    }
+
+    fn outer_layout(layout: Layout) -> Layout {
+        Layout::from_size_align(layout.size() + 2 * Self::padding(layout), layout.align()).unwrap()
+    }
+}
+
+unsafe impl GlobalAlloc for DisplacingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let padding = Self::padding(layout);
+        let offset = if DISPLACE.load(Ordering::Relaxed) { 2 * padding } else { padding };
+        let base = System.alloc(Self::outer_layout(layout));
+        if base.is_null() {
+            return base;
+        }
+        let ptr = base.add(offset);
+        (ptr as *mut usize).sub(1).write_unaligned(offset);
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let offset = (ptr as *mut usize).sub(1).read_unaligned();
+        System.dealloc(ptr.sub(offset), Self::outer_layout(layout));
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: DisplacingAllocator = DisplacingAllocator;
+
+#[test]
+fn test_transcript_independent_of_allocation_addresses() {
+    let (proof, public_inputs) = XfgBurnMintProver::default()
+        .prove_burn_mint_with_public_inputs(
+            &BurnMintRequest {
+                burn_amount: 8_000_000,
+                mint_amount: 8_000_000,
+                tx_prefix_hash: [7u8; 32],
+                recipient_address: &[0x12u8; 20],
+                network_id: 1,
+                target_chain_id: 42161,
+                commitment_version: 1,
+            },
+            &[9u8; 32],
+        )
+        .expect("Proof generation should succeed");
+    let proof = proof.to_bytes();
+    let inputs: Vec<u64> = public_inputs.to_elements().iter().map(|e| e.as_int()).collect();
+    let verifier = DeterministicVerifier::default();
+
+    let first = verifier.verify(&proof, &inputs);
+    let probe = Box::new(0u64);
+    DISPLACE.store(true, Ordering::Relaxed);
+    let displaced_probe = Box::new(0u64);
+    let second = verifier.verify(&proof, &inputs);
+    DISPLACE.store(false, Ordering::Relaxed);
+
+    // The second run really did get differently placed memory
+    let offset = |ptr: *const u64| unsafe { (ptr as *const usize).sub(1).read_unaligned() };
+    assert_ne!(offset(&*probe), offset(&*displaced_probe));
+
+    assert!(first.is_valid());
+    assert_eq!(first, second);
+    assert_eq!(first.digest(), second.digest());
+}
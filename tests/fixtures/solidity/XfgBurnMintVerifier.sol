@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+// Generated by xfg-stark (codegen::solidity). Do not edit; regenerate instead.
+pragma solidity ^0.8.20;
+
+/// @notice STARK core verifier the burn & mint library hands a checked proof to
+interface IXfgStarkCore {
+    function verifyStark(uint256[] calldata metadata, uint256[] calldata publicInputs, bytes calldata proof)
+        external
+        view
+        returns (bool);
+}
+
+/// @title XFG burn & mint proof verifier
+/// @notice Checks the parameters and claim of a burn & mint proof as xfg-stark does,
+/// then has the STARK core verify the proof itself
+library XfgBurnMintVerifier {
+    error InvalidProof();
+    error InvalidPublicInputs();
+    error InvalidAmount();
+    error InvalidRecipient();
+
+    /// @dev Field modulus, 2^64 - 2^32 + 1
+    uint256 internal constant MODULUS = 0xFFFFFFFF00000001;
+
+    /* ---------------------------------- Metadata ---------------------------------- */
+
+    uint256 internal constant METADATA_WORDS = 10;
+    uint256 internal constant PUBLIC_INPUT_LAYOUT_VERSION = 2;
+    /// @dev blake3
+    uint256 internal constant HASH_FUNCTION = 0;
+    uint256 internal constant TRACE_WIDTH = 52;
+    uint256 internal constant MIN_TRACE_LENGTH = 64;
+    uint256 internal constant NUM_QUERIES = 42;
+    uint256 internal constant BLOWUP_FACTOR = 8;
+    uint256 internal constant GRINDING_FACTOR = 4;
+    uint256 internal constant FIELD_EXTENSION = 2;
+    uint256 internal constant FRI_FOLDING_FACTOR = 8;
+    uint256 internal constant FRI_REMAINDER_MAX_DEGREE = 31;
+
+    /* ------------------------------ Public inputs ------------------------------ */
+
+    uint256 internal constant NUM_PUBLIC_INPUTS = 29;
+    uint256 internal constant MINT_OUTPUT_ELEMENTS = 9;
+    uint256 internal constant MAX_MINT_OUTPUTS = 8;
+    uint256 internal constant RECIPIENT_BINDING_LIMBS = 8;
+    uint256 internal constant FEE_BPS = 0;
+    uint256 internal constant BPS_DENOMINATOR = 10000;
+
+    uint256 internal constant BURN_AMOUNT_INDEX = 0;
+    uint256 internal constant MINT_AMOUNT_INDEX = 1;
+    uint256 internal constant TXN_HASH_INDEX = 2;
+    uint256 internal constant RECIPIENT_HASH_INDEX = 3;
+    uint256 internal constant STATE_INDEX = 4;
+    uint256 internal constant TX_PREFIX_HASH_0_INDEX = 5;
+    uint256 internal constant TX_PREFIX_HASH_1_INDEX = 6;
+    uint256 internal constant TX_PREFIX_HASH_2_INDEX = 7;
+    uint256 internal constant TX_PREFIX_HASH_3_INDEX = 8;
+    uint256 internal constant NETWORK_ID_INDEX = 9;
+    uint256 internal constant TARGET_CHAIN_ID_INDEX = 10;
+    uint256 internal constant COMMITMENT_VERSION_INDEX = 11;
+    uint256 internal constant FEE_BPS_INDEX = 12;
+    uint256 internal constant NULLIFIER_0_INDEX = 13;
+    uint256 internal constant NULLIFIER_1_INDEX = 14;
+    uint256 internal constant NULLIFIER_2_INDEX = 15;
+    uint256 internal constant NULLIFIER_3_INDEX = 16;
+    uint256 internal constant COMMITMENT_0_INDEX = 17;
+    uint256 internal constant COMMITMENT_1_INDEX = 18;
+    uint256 internal constant COMMITMENT_2_INDEX = 19;
+    uint256 internal constant COMMITMENT_3_INDEX = 20;
+    uint256 internal constant RECIPIENT_BINDING_0_INDEX = 21;
+    uint256 internal constant RECIPIENT_BINDING_1_INDEX = 22;
+    uint256 internal constant RECIPIENT_BINDING_2_INDEX = 23;
+    uint256 internal constant RECIPIENT_BINDING_3_INDEX = 24;
+    uint256 internal constant RECIPIENT_BINDING_4_INDEX = 25;
+    uint256 internal constant RECIPIENT_BINDING_5_INDEX = 26;
+    uint256 internal constant RECIPIENT_BINDING_6_INDEX = 27;
+    uint256 internal constant RECIPIENT_BINDING_7_INDEX = 28;
+
+    /* ------------------------------ Field arithmetic ------------------------------ */
+
+    function add(uint256 a, uint256 b) internal pure returns (uint256) {
+        return addmod(a, b, MODULUS);
+    }
+
+    /// @dev Operands are canonical, below MODULUS
+    function sub(uint256 a, uint256 b) internal pure returns (uint256) {
+        return addmod(a, MODULUS - b, MODULUS);
+    }
+
+    function mul(uint256 a, uint256 b) internal pure returns (uint256) {
+        return mulmod(a, b, MODULUS);
+    }
+
+    function pow(uint256 base, uint256 exponent) internal pure returns (uint256 result) {
+        result = 1;
+        base %= MODULUS;
+        while (exponent != 0) {
+            if (exponent & 1 == 1) {
+                result = mulmod(result, base, MODULUS);
+            }
+            base = mulmod(base, base, MODULUS);
+            exponent >>= 1;
+        }
+    }
+
+    /// @dev Zero has no inverse and maps to zero
+    function inv(uint256 a) internal pure returns (uint256) {
+        return pow(a, MODULUS - 2);
+    }
+
+    /* ---------------------------------- Checks ---------------------------------- */
+
+    /// @notice Revert unless the metadata names the parameters this library was generated for
+    function checkMetadata(uint256[] calldata metadata) internal pure {
+        if (metadata.length != METADATA_WORDS) revert InvalidProof();
+        uint256 traceLength = metadata[3];
+        if (
+            metadata[0] != PUBLIC_INPUT_LAYOUT_VERSION || metadata[1] != HASH_FUNCTION || metadata[2] != TRACE_WIDTH
+                || traceLength < MIN_TRACE_LENGTH || traceLength & (traceLength - 1) != 0 || metadata[4] != NUM_QUERIES
+                || metadata[5] != BLOWUP_FACTOR || metadata[6] != GRINDING_FACTOR || metadata[7] != FIELD_EXTENSION
+                || metadata[8] != FRI_FOLDING_FACTOR || metadata[9] != FRI_REMAINDER_MAX_DEGREE
+        ) revert InvalidProof();
+    }
+
+    /// @notice Revert unless the public inputs are a canonical burn & mint claim
+    function checkPublicInputs(uint256[] calldata publicInputs) internal pure {
+        uint256 length = publicInputs.length;
+        if (
+            length < NUM_PUBLIC_INPUTS || (length - NUM_PUBLIC_INPUTS) % MINT_OUTPUT_ELEMENTS != 0
+                || (length - NUM_PUBLIC_INPUTS) / MINT_OUTPUT_ELEMENTS > MAX_MINT_OUTPUTS
+        ) revert InvalidPublicInputs();
+        for (uint256 i = 0; i < length; ++i) {
+            if (publicInputs[i] >= MODULUS) revert InvalidPublicInputs();
+        }
+
+        uint256 burnAmount = publicInputs[BURN_AMOUNT_INDEX];
+        uint256 mintAmount = publicInputs[MINT_AMOUNT_INDEX];
+        if (burnAmount == 0 || mintAmount == 0) revert InvalidAmount();
+        if (publicInputs[TXN_HASH_INDEX] == 0) revert InvalidPublicInputs();
+        if (publicInputs[RECIPIENT_HASH_INDEX] != publicInputs[RECIPIENT_BINDING_0_INDEX]) revert InvalidRecipient();
+        if (
+            publicInputs[FEE_BPS_INDEX] != FEE_BPS
+                || mintAmount != burnAmount - burnAmount * FEE_BPS / BPS_DENOMINATOR
+        ) revert InvalidAmount();
+        checkMintOutputs(publicInputs, mintAmount);
+    }
+
+    /// @dev Outputs, when present, are non-zero, sum to the mint and start with the bound recipient
+    function checkMintOutputs(uint256[] calldata publicInputs, uint256 mintAmount) private pure {
+        uint256 count = (publicInputs.length - NUM_PUBLIC_INPUTS) / MINT_OUTPUT_ELEMENTS;
+        if (count == 0) return;
+        uint256 total = 0;
+        for (uint256 i = 0; i < count; ++i) {
+            uint256 amount = publicInputs[NUM_PUBLIC_INPUTS + i * MINT_OUTPUT_ELEMENTS];
+            if (amount == 0) revert InvalidAmount();
+            total += amount;
+        }
+        if (total != mintAmount) revert InvalidAmount();
+        for (uint256 limb = 0; limb < RECIPIENT_BINDING_LIMBS; ++limb) {
+            if (publicInputs[NUM_PUBLIC_INPUTS + 1 + limb] != publicInputs[RECIPIENT_BINDING_0_INDEX + limb]) {
+                revert InvalidRecipient();
+            }
+        }
+    }
+
+    /// @notice Check a proof's parameters and claim, then verify it with the STARK core
+    /// @dev Arguments are those of verifyProof(uint256[],uint256[],bytes); reverts on failure
+    function verifyProof(
+        IXfgStarkCore core,
+        uint256[] calldata metadata,
+        uint256[] calldata publicInputs,
+        bytes calldata proof
+    ) internal view {
+        checkMetadata(metadata);
+        checkPublicInputs(publicInputs);
+        if (!core.verifyStark(metadata, publicInputs, proof)) revert InvalidProof();
+    }
+}
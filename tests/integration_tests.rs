@@ -14,6 +14,7 @@
 use xfg_stark::{
     types::{FieldElement, PrimeField64, StarkComponent},
     proof::{StarkProver, StarkVerifier},
+    proof::memory::{LdeStrategy, ProverMemoryBudget},
     proof::fri::{FriProver, FriVerifier},
     proof::merkle::{MerkleTree, generate_commitment},
     air::{Air, Constraint, TransitionFunction, BoundaryConditions},
@@ -21,6 +22,8 @@ use xfg_stark::{
     air::boundaries::{BoundaryConstraint, BoundaryType},
     benchmarks::{BenchmarkSuite, PerformanceProfiler, MemoryTracker},
     winterfell_integration::{XfgWinterfellProver, XfgWinterfellVerifier, WinterfellTraceTable},
+    burn_mint_prover::{BurnMintRequest, XfgBurnMintProver},
+    burn_mint_verifier::XfgBurnMintVerifier,
     stark, ExecutionTrace,
 };
 
@@ -40,7 +43,14 @@ mod test_utils {
             ),
         ];
 
-        let transition = TransitionFunction::new(vec![vec![PrimeField64::one()]], 2);
+        // Registers hold (F(i), F(i+1)): a' = b, b' = a + b
+        let transition = TransitionFunction::new(
+            vec![
+                vec![PrimeField64::zero(), PrimeField64::one()],
+                vec![PrimeField64::one(), PrimeField64::one()],
+            ],
+            2,
+        );
         let mut boundary = BoundaryConditions::new(vec![]);
         
         // Add initial conditions: F(0) = 0, F(1) = 1
@@ -48,7 +58,7 @@ mod test_utils {
             0, 0, PrimeField64::zero(), BoundaryType::Initial
         ));
         boundary.add_constraint(BoundaryConstraint::new(
-            0, 1, PrimeField64::one(), BoundaryType::Initial
+            1, 0, PrimeField64::one(), BoundaryType::Initial
         ));
 
         Air::new(constraints, transition, boundary, 128)
@@ -170,10 +180,10 @@ fn test_merkle_tree_integration() {
 #[test]
 fn test_winterfell_integration() {
     // Test Winterfell integration
+    // Two registers over three steps, one column per register
     let trace_data = vec![
-        vec![PrimeField64::new(1), PrimeField64::new(2)],
-        vec![PrimeField64::new(3), PrimeField64::new(4)],
-        vec![PrimeField64::new(5), PrimeField64::new(6)],
+        vec![PrimeField64::new(1), PrimeField64::new(3), PrimeField64::new(5)],
+        vec![PrimeField64::new(2), PrimeField64::new(4), PrimeField64::new(6)],
     ];
     
     // Create execution trace manually
@@ -343,22 +353,29 @@ fn test_real_world_scenario() {
         ),
     ];
     
-    let transition = TransitionFunction::new(vec![vec![PrimeField64::one()], vec![PrimeField64::one()]], 2);
+    // Registers (sum, counter, one): sum' = sum + counter, counter' = counter + one, one' = one
+    let one = PrimeField64::one();
+    let zero = PrimeField64::zero();
+    let transition = TransitionFunction::new(
+        vec![vec![one, one, zero], vec![zero, one, one], vec![zero, zero, one]],
+        2,
+    );
     let mut boundary = BoundaryConditions::new(vec![]);
     
-    // Initial conditions: sum=0, counter=1
+    // Initial conditions: sum=0, counter=1, one=1
     boundary.add_constraint(BoundaryConstraint::new(0, 0, PrimeField64::zero(), BoundaryType::Initial));
     boundary.add_constraint(BoundaryConstraint::new(1, 0, PrimeField64::one(), BoundaryType::Initial));
+    boundary.add_constraint(BoundaryConstraint::new(2, 0, PrimeField64::one(), BoundaryType::Initial));
     
-    // Final condition: sum should equal expected_sum
-    boundary.add_constraint(BoundaryConstraint::new(0, n-1, PrimeField64::new(expected_sum as u64), BoundaryType::Final));
+    // Final condition: after n additions the sum should equal expected_sum
+    boundary.add_constraint(BoundaryConstraint::new(0, n, PrimeField64::new(expected_sum as u64), BoundaryType::Final));
     
     let air = Air::new(constraints, transition, boundary, 128);
     
     // Generate and verify proof
     let prover = StarkProver::new(128);
-    let initial_state = vec![PrimeField64::zero(), PrimeField64::one()];
-    let proof = prover.prove(&air, &initial_state, n).expect("Real-world proof should succeed");
+    let initial_state = vec![PrimeField64::zero(), PrimeField64::one(), PrimeField64::one()];
+    let proof = prover.prove(&air, &initial_state, n + 1).expect("Real-world proof should succeed");
     
     let verifier = StarkVerifier::new(128);
     let is_valid = verifier.verify(&proof).expect("Real-world verification should succeed");
@@ -533,6 +550,38 @@ fn test_memory_efficiency() {
     assert!(report.contains("large_merkle_tree"), "Should track large Merkle tree");
 }
 
+#[test]
+fn test_memory_budget_chunked_commitment() {
+    // A tight memory budget spills the trace extension to disk without changing the proof
+    let prove = |prover: &XfgBurnMintProver| {
+        prover
+            .prove_burn_mint_with_public_inputs(
+                &BurnMintRequest {
+                    burn_amount: 8_000_000,
+                    mint_amount: 8_000_000,
+                    tx_prefix_hash: [7u8; 32],
+                    recipient_address: &[0x12u8; 20],
+                    network_id: 1,
+                    target_chain_id: 42161,
+                    commitment_version: 1,
+                },
+                &[9u8; 32],
+            )
+            .expect("Proof generation should succeed")
+    };
+    let in_memory = XfgBurnMintProver::default();
+    let chunked = XfgBurnMintProver::default().with_memory_budget(ProverMemoryBudget::new(16 * 1024));
+    assert_eq!(in_memory.lde_strategy(), LdeStrategy::InMemory);
+    assert!(matches!(chunked.lde_strategy(), LdeStrategy::Chunked { .. }));
+
+    let (expected, _) = prove(&in_memory);
+    let (proof, public_inputs) = prove(&chunked);
+    assert_eq!(proof.to_bytes(), expected.to_bytes());
+    assert!(XfgBurnMintVerifier::default()
+        .verify_with_public_inputs(&proof, &public_inputs)
+        .expect("Chunked proof should verify"));
+}
+
 #[test]
 fn test_error_recovery() {
     // Test error recovery and graceful degradation
@@ -0,0 +1,86 @@
+//! Replay Protection Tests
+//!
+//! Security-focused tests asserting that the burn & mint verifier rejects replayed
+//! proofs: cross-network claims, reused nullifiers and mismatched commitment versions.
+//! The scenarios themselves live in `xfg_stark::replay_scenarios` so gateways can reuse them.
+
+use xfg_stark::{
+    burn_mint_prover::XfgBurnMintProver,
+    burn_mint_verifier::XfgBurnMintVerifier,
+    replay_scenarios::{
+        run_all_replay_scenarios, run_replay_scenario, ReplayFixture, ReplayNetwork,
+        ReplayScenario,
+    },
+};
+use winterfell::math::fields::f64::BaseElement;
+
+fn assert_rejected(scenario: ReplayScenario) {
+    let prover = XfgBurnMintProver::default();
+    let verifier = XfgBurnMintVerifier::default();
+
+    let outcome = run_replay_scenario(&prover, &verifier, scenario)
+        .expect("Scenario should run with an honest baseline proof");
+    assert!(outcome.rejected, "{} was not rejected: {}", scenario, outcome.detail);
+}
+
+#[test]
+fn test_testnet_proof_rejected_on_mainnet() {
+    assert_rejected(ReplayScenario::CrossNetwork {
+        proven_on: ReplayNetwork::TESTNET,
+        claimed_on: ReplayNetwork::MAINNET,
+    });
+}
+
+#[test]
+fn test_mainnet_proof_rejected_on_testnet() {
+    assert_rejected(ReplayScenario::CrossNetwork {
+        proven_on: ReplayNetwork::MAINNET,
+        claimed_on: ReplayNetwork::TESTNET,
+    });
+}
+
+#[test]
+fn test_reused_nullifier_rejected() {
+    assert_rejected(ReplayScenario::ReusedNullifier);
+}
+
+#[test]
+fn test_commitment_version_mismatch_rejected() {
+    assert_rejected(ReplayScenario::CommitmentVersionMismatch {
+        proven_version: 1,
+        claimed_version: 2,
+    });
+}
+
+#[test]
+fn test_target_chain_only_replay_rejected() {
+    // Same Fuego network, different HEAT chain
+    let prover = XfgBurnMintProver::default();
+    let verifier = XfgBurnMintVerifier::default();
+    let fixture = ReplayFixture::generate(&prover, ReplayNetwork::MAINNET, 1, &[9u8; 32], 3)
+        .expect("Fixture generation should succeed");
+
+    assert!(verifier
+        .verify_with_public_inputs(&fixture.proof, &fixture.public_inputs)
+        .expect("Honest claim should be checked"));
+
+    let mut claim = fixture.public_inputs.clone();
+    claim.target_chain_id = BaseElement::from(ReplayNetwork::TESTNET.target_chain_id);
+    assert!(!verifier
+        .verify_with_public_inputs(&fixture.proof, &claim)
+        .unwrap_or(false));
+}
+
+#[test]
+fn test_all_replay_scenarios_rejected() {
+    let prover = XfgBurnMintProver::default();
+    let verifier = XfgBurnMintVerifier::default();
+
+    let outcomes = run_all_replay_scenarios(&prover, &verifier)
+        .expect("All scenarios should run with honest baseline proofs");
+    assert_eq!(outcomes.len(), ReplayScenario::all().len());
+
+    for outcome in outcomes {
+        assert!(outcome.rejected, "{} was not rejected: {}", outcome.scenario, outcome.detail);
+    }
+}
@@ -0,0 +1,130 @@
+//! Verifier Core Agreement Tests
+//!
+//! `xfg-stark-verifier-core` re-implements the verifier side of the burn & mint AIR
+//! without std. These tests hold it to this crate: it must accept the known-answer
+//! proofs and every proof this crate's default verifier accepts, and reject the
+//! tampered, truncated and re-parameterized proofs this crate rejects.
+
+use xfg_stark::{
+    burn_mint_air::{BurnMintConfig, BurnMintPublicInputs, FinalStatePolicy, TRACE_WIDTH},
+    burn_mint_prover::{BurnMintRequest, XfgBurnMintProver},
+    burn_mint_verifier::XfgBurnMintVerifier,
+    test_data_generator::KatFile,
+};
+use xfg_stark_verifier_core::{verify, verify_proof, VerifyError, NUM_PUBLIC_INPUTS};
+use winterfell::{FieldExtension, ProofOptions, StarkProof};
+
+/// Verify with this crate's default verifier
+fn crate_accepts(proof: &StarkProof, public_inputs: &BurnMintPublicInputs) -> bool {
+    matches!(XfgBurnMintVerifier::default().verify_with_public_inputs(proof, public_inputs), Ok(true))
+}
+
+#[test]
+fn test_layout_constants_agree() {
+    assert_eq!(xfg_stark_verifier_core::TRACE_WIDTH, TRACE_WIDTH);
+    assert_eq!(NUM_PUBLIC_INPUTS, BurnMintPublicInputs::NUM_ELEMENTS);
+    assert_eq!(&xfg_stark_verifier_core::proof_options(), XfgBurnMintVerifier::default().proof_options());
+}
+
+#[test]
+fn test_known_answer_proofs_verify() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/kat/burn_mint.json");
+    let file: KatFile = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    for vector in file.vectors {
+        let proof_bytes = hex::decode(&vector.proof).unwrap();
+        let inputs: Vec<u8> = vector.public_inputs.iter().flat_map(|value| value.to_le_bytes()).collect();
+        assert_eq!(verify_proof(&proof_bytes, &inputs), Ok(()), "seed {}", vector.seed);
+    }
+}
+
+#[test]
+fn test_agrees_with_crate_verifier() {
+    let prover = XfgBurnMintProver::default();
+    let single = prover
+        .prove_burn_mint_with_public_inputs(
+            &BurnMintRequest {
+                burn_amount: 8_000_000,
+                mint_amount: 8_000_000,
+                tx_prefix_hash: [7u8; 32],
+                recipient_address: &[0x12; 20],
+                network_id: 1,
+                target_chain_id: 42161,
+                commitment_version: 1,
+            },
+            &[9u8; 32],
+        )
+        .unwrap();
+    let outputs: [(&[u8], u64); 2] = [(&[0x12; 20], 5_000_000), (&[0x34; 20], 3_000_000)];
+    let request = BurnMintRequest {
+        burn_amount: 8_000_000,
+        mint_amount: 8_000_000,
+        tx_prefix_hash: [7u8; 32],
+        recipient_address: &[0x12; 20],
+        network_id: 1,
+        target_chain_id: 42161,
+        commitment_version: 1,
+    };
+    let split = prover.prove_burn_mint_outputs(&request, &outputs, &[9u8; 32]).unwrap();
+    let scheduled = XfgBurnMintProver::default()
+        .with_config(BurnMintConfig::new(128).with_num_states(6).with_final_state(FinalStatePolicy::AtStep(100)))
+        .prove_burn_mint_with_public_inputs(
+            &BurnMintRequest {
+                burn_amount: 8_000_000,
+                mint_amount: 8_000_000,
+                tx_prefix_hash: [7u8; 32],
+                recipient_address: &[0x12; 20],
+                network_id: 1,
+                target_chain_id: 42161,
+                commitment_version: 1,
+            },
+            &[9u8; 32],
+        )
+        .unwrap();
+
+    for (name, (proof, public_inputs)) in [("single", single), ("split", split), ("scheduled", scheduled)] {
+        assert!(crate_accepts(&proof, &public_inputs), "{name}");
+        assert_eq!(verify_proof(&proof.to_bytes(), &public_inputs.to_bytes()), Ok(()), "{name}");
+
+        let one = winterfell::math::fields::f64::BaseElement::new(1);
+        let mut tampered = public_inputs.clone();
+        tampered.recipient_hash += one;
+        assert!(!crate_accepts(&proof, &tampered), "{name}");
+        assert_eq!(verify_proof(&proof.to_bytes(), &tampered.to_bytes()), Err(VerifyError::InvalidClaim));
+
+        // The proof binds every recipient limb, not just the first
+        let mut rebound = public_inputs.clone();
+        rebound.recipient_binding[7] += one;
+        if let Some(output) = rebound.outputs.first_mut() {
+            output.recipient_binding[7] += one;
+        }
+        assert!(!crate_accepts(&proof, &rebound), "{name}");
+        assert!(matches!(verify_proof(&proof.to_bytes(), &rebound.to_bytes()), Err(VerifyError::Rejected(_))));
+
+        let bytes = proof.to_bytes();
+        assert!(!verify(&bytes[..bytes.len() / 2], &public_inputs.to_bytes()), "{name}");
+    }
+}
+
+#[test]
+fn test_rejects_other_parameters() {
+    // Still above the crate's security floor, but not the options the core pins
+    let options = ProofOptions::new(48, 8, 4, FieldExtension::Quadratic, 8, 31);
+    let (proof, public_inputs) = XfgBurnMintProver::with_options(128, options)
+        .prove_burn_mint_with_public_inputs(
+            &BurnMintRequest {
+                burn_amount: 8_000_000,
+                mint_amount: 8_000_000,
+                tx_prefix_hash: [7u8; 32],
+                recipient_address: &[0x12; 20],
+                network_id: 1,
+                target_chain_id: 42161,
+                commitment_version: 1,
+            },
+            &[9u8; 32],
+        )
+        .unwrap();
+    assert!(matches!(
+        verify_proof(&proof.to_bytes(), &public_inputs.to_bytes()),
+        Err(VerifyError::Rejected(_))
+    ));
+}
@@ -0,0 +1,382 @@
+//! Burn & mint AIR, verifier side
+//!
+//! Mirrors `xfg_stark::burn_mint_air::XfgBurnMintAir`: the same registers, transition
+//! constraints and boundary assertions, without the trace builder.
+
+use crate::public_inputs::{
+    PublicInputs, BURN_AMOUNT, DIGEST_LIMBS, FEE_BPS_INDEX, MINT_AMOUNT, RECIPIENT_BINDING_LIMBS, TXN_HASH,
+};
+use crate::rescue::{
+    absorb, domain_element, enforce_round, hash_elements, periodic_columns, CYCLE_LENGTH, DIGEST_START,
+    NUM_PERIODIC_COLUMNS, NUM_ROUND_CONSTRAINTS, PERMUTATION_WIDTH, RATE_START, STATE_WIDTH,
+};
+use core::ops::Range;
+use alloc::vec::Vec;
+use winter_verifier::math::fields::f64::BaseElement;
+use winter_verifier::math::FieldElement;
+use winter_verifier::{
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo, TransitionConstraintDegree,
+};
+
+/// Number of registers in the burn & mint execution trace
+pub const TRACE_WIDTH: usize = HASH_START + PERMUTATION_WIDTH;
+
+/// Registers holding the same value on every row
+const CONSTANT_REGISTERS: [usize; 3] = [0, 1, 2];
+
+/// Register cycling through the recipient binding limbs
+const RECIPIENT_REGISTER: usize = 3;
+
+/// Register holding the state machine state
+const STATE_REGISTER: usize = 4;
+
+/// Register holding one bit of the burn amount per row
+const BURN_BIT_REGISTER: usize = 5;
+
+/// Register accumulating the burn amount bits
+const BURN_ACCUMULATOR_REGISTER: usize = 6;
+
+/// Register summing the mint output amounts
+const MINT_ACCUMULATOR_REGISTER: usize = 7;
+
+/// Register holding one bit of the fee remainder per row
+const FEE_REMAINDER_BIT_REGISTER: usize = 8;
+
+/// Register accumulating the fee remainder bits below a leading one
+const FEE_REMAINDER_ACCUMULATOR_REGISTER: usize = 9;
+
+/// Register holding one bit of the fee remainder's slack below the denominator per row
+const FEE_SLACK_BIT_REGISTER: usize = 10;
+
+/// Register accumulating the fee slack bits below a leading one
+const FEE_SLACK_ACCUMULATOR_REGISTER: usize = 11;
+
+/// Field elements the burn secret is carried in
+const SECRET_LIMBS: usize = 4;
+
+/// Registers carrying the secret limbs from the nullifier input to the commitment input
+const SECRET_REGISTERS: Range<usize> = 12..12 + SECRET_LIMBS;
+
+/// First of the registers running the Rescue-Prime permutation
+const HASH_START: usize = SECRET_REGISTERS.end;
+
+/// Rows of the permutation registers that hash: the nullifier cycle, then the commitment cycle
+const HASH_ROWS: usize = 2 * CYCLE_LENGTH;
+
+/// Domain tag of burn nullifiers
+const NULLIFIER_DOMAIN: &[u8] = b"nullifier";
+
+/// Bits in the burn amount range check
+const BURN_AMOUNT_BITS: usize = 53;
+
+/// Bits in the fee remainder and slack range checks, below their leading ones
+const FEE_REMAINDER_BITS: usize = 14;
+
+/// Basis points in one whole (100%)
+const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Most recipients a burn can mint to
+const MAX_MINT_OUTPUTS: usize = 8;
+
+/// States of the default schedule: init, burn, mint, complete
+const DEFAULT_NUM_STATES: u32 = 4;
+
+/// Trace metadata tag of a final state at a specific step
+const AT_STEP_TAG: u8 = 1;
+
+/// Trace metadata tag of a state count other than the default
+const NUM_STATES_TAG: u8 = 2;
+
+/// Number of boundary assertions: two periodic assertions for each constant register,
+/// one for each recipient binding limb, the initial and final state, the range check
+/// start and end, the mint sum start and end, the leading ones of both fee range checks,
+/// both permutation inputs but for the secret, and both digests
+const NUM_ASSERTIONS: usize = 2 * CONSTANT_REGISTERS.len()
+    + RECIPIENT_BINDING_LIMBS
+    + 2
+    + 2
+    + 2
+    + 2
+    + 2 * (STATE_WIDTH - SECRET_LIMBS)
+    + 2 * DIGEST_LIMBS;
+
+/// State schedule a trace's metadata records
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Schedule {
+    /// States the state register passes through
+    pub num_states: u32,
+    /// Row at which the final state is reached
+    pub final_step: usize,
+}
+
+impl Schedule {
+    /// Schedule of a trace the burn & mint AIR can prove
+    ///
+    /// Only the canonical metadata encoding is accepted: an optional final step (tag 1)
+    /// followed by an optional state count other than four (tag 2), each a little-endian
+    /// `u32`. The trace must be a power of two longer than the range check and outputs.
+    pub fn from_trace_info(trace_info: &TraceInfo) -> Option<Self> {
+        let length = trace_info.length();
+        if trace_info.width() != TRACE_WIDTH
+            || !length.is_power_of_two()
+            || length <= BURN_AMOUNT_BITS
+            || length <= MAX_MINT_OUTPUTS
+        {
+            return None;
+        }
+
+        let meta = trace_info.meta();
+        let (mut at_step, mut num_states) = (None, DEFAULT_NUM_STATES);
+        for field in meta.chunks(5) {
+            let value = u32::from_le_bytes(field.get(1..)?.try_into().ok()?);
+            match field[0] {
+                AT_STEP_TAG => at_step = Some(value),
+                NUM_STATES_TAG => num_states = value,
+                _ => return None,
+            }
+        }
+        if num_states < 2 || Self::encode(at_step, num_states) != meta {
+            return None;
+        }
+
+        let final_step = at_step.map_or(length - 1, |step| step as usize);
+        if final_step < (num_states - 1) as usize || final_step >= length {
+            return None;
+        }
+        Some(Self { num_states, final_step })
+    }
+
+    /// Canonical metadata of a schedule
+    fn encode(at_step: Option<u32>, num_states: u32) -> Vec<u8> {
+        let mut meta = Vec::new();
+        if let Some(step) = at_step {
+            meta.push(AT_STEP_TAG);
+            meta.extend_from_slice(&step.to_le_bytes());
+        }
+        if num_states != DEFAULT_NUM_STATES {
+            meta.push(NUM_STATES_TAG);
+            meta.extend_from_slice(&num_states.to_le_bytes());
+        }
+        meta
+    }
+}
+
+/// Burn & mint AIR for verification
+pub struct BurnMintAir {
+    context: AirContext<BaseElement>,
+    public_inputs: PublicInputs,
+    schedule: Schedule,
+}
+
+impl Air for BurnMintAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+
+    fn new(trace_info: TraceInfo, public_inputs: PublicInputs, options: ProofOptions) -> Self {
+        // Metadata is checked before the AIR is built; fall back to the default schedule
+        // so the AIR never asserts outside the trace
+        let schedule = Schedule::from_trace_info(&trace_info).unwrap_or(Schedule {
+            num_states: DEFAULT_NUM_STATES,
+            final_step: trace_info.length() - 1,
+        });
+
+        let mut constraint_degrees = alloc::vec![TransitionConstraintDegree::new(2)];
+        constraint_degrees.extend(CONSTANT_REGISTERS.map(|_| TransitionConstraintDegree::new(1)));
+        constraint_degrees.extend([TransitionConstraintDegree::new(2), TransitionConstraintDegree::new(1)]);
+        constraint_degrees.push(TransitionConstraintDegree::new(1));
+        constraint_degrees.push(TransitionConstraintDegree::new(1));
+        let length = trace_info.length();
+        for _ in 0..2 {
+            constraint_degrees.extend([TransitionConstraintDegree::new(2), TransitionConstraintDegree::new(1)]);
+        }
+        constraint_degrees.extend((0..2).map(|_| TransitionConstraintDegree::with_cycles(1, alloc::vec![length])));
+        constraint_degrees
+            .extend((0..2 * SECRET_LIMBS).map(|_| TransitionConstraintDegree::with_cycles(1, alloc::vec![length])));
+        constraint_degrees
+            .extend((0..NUM_ROUND_CONSTRAINTS).map(|_| TransitionConstraintDegree::with_cycles(3, alloc::vec![length])));
+
+        Self {
+            context: AirContext::new(trace_info, constraint_degrees, NUM_ASSERTIONS, options),
+            public_inputs,
+            schedule,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let length = self.trace_length();
+        let mut column = self.public_inputs.mint_output_amounts();
+        column.resize(length, BaseElement::ZERO);
+        let rows = |rows: &[usize]| {
+            let mut column = alloc::vec![BaseElement::ZERO; length];
+            for &row in rows {
+                column[row] = BaseElement::ONE;
+            }
+            column
+        };
+
+        // The round flag covers both permutation cycles only
+        let mut hash_columns = periodic_columns();
+        let mut round_flag = hash_columns[0].repeat(2);
+        round_flag.resize(length, BaseElement::ZERO);
+        hash_columns[0] = round_flag;
+
+        let mut columns = alloc::vec![column, self.public_inputs.recipient_binding().to_vec()];
+        columns.extend(hash_columns);
+        columns.push(rows(&[0, 1, 2, 3, 4, 5, 6, 7]));
+        columns.push(rows(&[0, CYCLE_LENGTH]));
+        columns.push(rows(&[length - 2]));
+        columns
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        // The state stays or advances by one
+        let state_step = next[STATE_REGISTER] - current[STATE_REGISTER];
+        result[0] = state_step * (state_step - E::ONE);
+
+        for (i, register) in CONSTANT_REGISTERS.into_iter().enumerate() {
+            result[1 + i] = next[register] - current[register];
+        }
+
+        // Burn amount bits are binary and accumulate most significant bit first
+        let range_start = 1 + CONSTANT_REGISTERS.len();
+        let next_bit = next[BURN_BIT_REGISTER];
+        result[range_start] = next_bit * (next_bit - E::ONE);
+        result[range_start + 1] =
+            next[BURN_ACCUMULATOR_REGISTER] - (current[BURN_ACCUMULATOR_REGISTER].double() + next_bit);
+
+        result[range_start + 2] =
+            next[MINT_ACCUMULATOR_REGISTER] - (current[MINT_ACCUMULATOR_REGISTER] + periodic_values[0]);
+
+        result[range_start + 3] = current[RECIPIENT_REGISTER] - periodic_values[1];
+
+        // The fee remainder and its slack are binary decompositions below a leading one
+        let fee_start = range_start + 4;
+        for (i, (bit, accumulator)) in [
+            (FEE_REMAINDER_BIT_REGISTER, FEE_REMAINDER_ACCUMULATOR_REGISTER),
+            (FEE_SLACK_BIT_REGISTER, FEE_SLACK_ACCUMULATOR_REGISTER),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let next_bit = next[bit];
+            result[fee_start + 2 * i] = next_bit * (next_bit - E::ONE);
+            result[fee_start + 2 * i + 1] = next[accumulator] - (current[accumulator].double() + next_bit);
+        }
+
+        // On the last row, remainder and slack add up to one less than the denominator,
+        // and `burn * fee_bps = (burn - mint) * 10000 + remainder`
+        let selectors = &periodic_values[2 + NUM_PERIODIC_COLUMNS..];
+        let denominator = E::from(BaseElement::new(BPS_DENOMINATOR));
+        let leading_one = E::from(BaseElement::new(1 << FEE_REMAINDER_BITS));
+        let remainder = next[FEE_REMAINDER_ACCUMULATOR_REGISTER] - leading_one;
+        let slack = next[FEE_SLACK_ACCUMULATOR_REGISTER] - leading_one;
+        let fee = next[BURN_AMOUNT] - next[MINT_AMOUNT];
+        result[fee_start + 4] = selectors[2] * (remainder + slack - (denominator - E::ONE));
+        result[fee_start + 5] = selectors[2]
+            * (next[BURN_AMOUNT] * E::from(self.public_inputs.get(FEE_BPS_INDEX)) - fee * denominator - remainder);
+
+        // Each secret limb is carried to both permutation inputs
+        let hash = &current[HASH_START..];
+        let hash_start = fee_start + 6;
+        for (limb, register) in SECRET_REGISTERS.enumerate() {
+            result[hash_start + limb] = selectors[0] * (next[register] - current[register]);
+            result[hash_start + SECRET_LIMBS + limb] = selectors[1] * (hash[RATE_START + limb] - current[register]);
+        }
+        enforce_round(
+            hash,
+            &next[HASH_START..],
+            &periodic_values[2..2 + NUM_PERIODIC_COLUMNS],
+            &mut result[hash_start + 2 * SECRET_LIMBS..],
+        );
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let inputs = &self.public_inputs;
+        let constant_registers = [
+            (0, inputs.get(BURN_AMOUNT)),
+            (1, inputs.get(MINT_AMOUNT)),
+            (2, inputs.get(TXN_HASH)),
+        ];
+
+        let last = self.trace_length() - 1;
+        let mut assertions = Vec::with_capacity(NUM_ASSERTIONS);
+        for (register, value) in constant_registers {
+            assertions.push(Assertion::periodic(register, 0, 2, value));
+            assertions.push(Assertion::periodic(register, 1, 2, value));
+        }
+        for (limb, value) in inputs.recipient_binding().iter().enumerate() {
+            assertions.push(Assertion::periodic(RECIPIENT_REGISTER, limb, RECIPIENT_BINDING_LIMBS, *value));
+        }
+        assertions.push(Assertion::single(STATE_REGISTER, 0, BaseElement::ZERO));
+        assertions.push(Assertion::single(
+            STATE_REGISTER,
+            self.schedule.final_step,
+            BaseElement::from(self.schedule.num_states - 1),
+        ));
+        assertions.push(Assertion::single(BURN_ACCUMULATOR_REGISTER, last - BURN_AMOUNT_BITS, BaseElement::ZERO));
+        assertions.push(Assertion::single(BURN_ACCUMULATOR_REGISTER, last, inputs.get(BURN_AMOUNT)));
+        assertions.push(Assertion::single(MINT_ACCUMULATOR_REGISTER, 0, BaseElement::ZERO));
+        assertions.push(Assertion::single(MINT_ACCUMULATOR_REGISTER, last, inputs.get(MINT_AMOUNT)));
+        for register in [FEE_REMAINDER_ACCUMULATOR_REGISTER, FEE_SLACK_ACCUMULATOR_REGISTER] {
+            assertions.push(Assertion::single(register, last - FEE_REMAINDER_BITS, BaseElement::ONE));
+        }
+
+        // Nullifier input: the secret and the digest of the domain and scope; commitment
+        // input: the secret and the claim digest. The secret slots are left to the trace
+        let mut scope = alloc::vec![domain_element(NULLIFIER_DOMAIN)];
+        scope.extend(inputs.nullifier_scope());
+        let secret_then = |digest: [BaseElement; DIGEST_LIMBS]| {
+            let mut elements = [BaseElement::ZERO; SECRET_LIMBS + DIGEST_LIMBS];
+            elements[SECRET_LIMBS..].copy_from_slice(&digest);
+            absorb(&elements)
+        };
+        let secret_slots = RATE_START..RATE_START + SECRET_LIMBS;
+        for (step, input) in [(0, secret_then(hash_elements(&scope))), (CYCLE_LENGTH, secret_then(inputs.claim_digest()))] {
+            for (register, value) in input.into_iter().enumerate().filter(|(register, _)| !secret_slots.contains(register)) {
+                assertions.push(Assertion::single(HASH_START + register, step, value));
+            }
+        }
+
+        // Each output row holds its digest: the nullifier, then the commitment
+        for (step, digest) in [(CYCLE_LENGTH - 1, inputs.nullifier()), (HASH_ROWS - 1, inputs.commitment())] {
+            for (limb, value) in digest.iter().enumerate() {
+                assertions.push(Assertion::single(HASH_START + DIGEST_START + limb, step, *value));
+            }
+        }
+        assertions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_from_trace_info() {
+        let default = Schedule::from_trace_info(&TraceInfo::new(TRACE_WIDTH, 64)).unwrap();
+        assert_eq!(default, Schedule { num_states: 4, final_step: 63 });
+
+        let meta = Schedule::encode(Some(40), 6);
+        let custom = Schedule::from_trace_info(&TraceInfo::with_meta(TRACE_WIDTH, 128, meta)).unwrap();
+        assert_eq!(custom, Schedule { num_states: 6, final_step: 40 });
+
+        // Too short for the range check, non-canonical metadata, a final step outside the trace
+        assert!(Schedule::from_trace_info(&TraceInfo::new(TRACE_WIDTH, 32)).is_none());
+        let explicit_default = alloc::vec![NUM_STATES_TAG, 4, 0, 0, 0];
+        assert!(Schedule::from_trace_info(&TraceInfo::with_meta(TRACE_WIDTH, 64, explicit_default)).is_none());
+        let outside = Schedule::encode(Some(64), 4);
+        assert!(Schedule::from_trace_info(&TraceInfo::with_meta(TRACE_WIDTH, 64, outside)).is_none());
+    }
+}
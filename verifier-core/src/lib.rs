@@ -0,0 +1,98 @@
+//! XFG Burn & Mint Verifier Core
+//!
+//! A `no_std` verifier for the burn & mint proofs `xfg-stark` generates, for targets the
+//! full crate does not fit: Arbitrum Stylus contracts (`wasm32-unknown-unknown`) and
+//! RISC-V guests. It depends on the Winterfell verifier alone and needs only `alloc`.
+//!
+//! [`verify`] takes a serialized proof and its public inputs, encoded as little-endian
+//! `u64` field elements in the public input layout (`xfg_stark::public_input_layout`,
+//! produced by `BurnMintPublicInputs::to_bytes`). A proof is accepted when:
+//!
+//! - it was generated with [`proof_options`] and committed with BLAKE3
+//! - its trace layout and state schedule are ones the burn & mint AIR can prove
+//! - the claim passes the checks `XfgBurnMintVerifier::default()` applies to public
+//!   inputs: non-zero amounts and transaction hash, no protocol fee, and mint outputs
+//!   that sum to the mint and start with the bound recipient
+//! - the STARK verifies against the burn & mint AIR
+//!
+//! A Stylus contract exposes [`verify`] through the Stylus SDK entrypoint; that wrapper
+//! belongs to the contract, keeping host bindings out of this crate. `xfg-stark` checks
+//! this crate against its own verifier in `tests/verifier_core.rs`.
+
+#![cfg_attr(not(test), no_std)]
+#![deny(missing_docs)]
+#![deny(unsafe_code)]
+
+extern crate alloc;
+
+mod air;
+mod public_inputs;
+mod rescue;
+
+pub use air::{BurnMintAir, Schedule, TRACE_WIDTH};
+pub use public_inputs::{PublicInputs, NUM_PUBLIC_INPUTS};
+
+use core::fmt::{Display, Formatter};
+use winter_verifier::crypto::{hashers::Blake3_256, DefaultRandomCoin};
+use winter_verifier::math::fields::f64::BaseElement;
+use winter_verifier::{AcceptableOptions, FieldExtension, ProofOptions, StarkProof, VerifierError};
+
+/// Hash function burn & mint proofs commit with
+type ProofHasher = Blake3_256<BaseElement>;
+
+/// Reason a proof was not accepted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyError {
+    /// The public inputs are not whole, canonical field elements in the layout
+    InvalidPublicInputs,
+    /// The public inputs are well formed but not a valid claim
+    InvalidClaim,
+    /// The proof bytes do not deserialize
+    InvalidProofBytes,
+    /// The trace metadata does not describe a burn & mint trace
+    InvalidTraceLayout,
+    /// The STARK does not verify
+    Rejected(VerifierError),
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VerifyError::InvalidPublicInputs => write!(f, "public inputs are not canonical layout elements"),
+            VerifyError::InvalidClaim => write!(f, "public inputs are not a valid burn & mint claim"),
+            VerifyError::InvalidProofBytes => write!(f, "proof bytes do not deserialize"),
+            VerifyError::InvalidTraceLayout => write!(f, "trace layout is not a burn & mint trace"),
+            VerifyError::Rejected(e) => write!(f, "proof rejected: {}", e),
+        }
+    }
+}
+
+/// Proof options burn & mint proofs must be generated with
+///
+/// The defaults of `XfgBurnMintProver` and `XfgBurnMintVerifier`: 42 queries, blowup 8,
+/// 4 grinding bits, a quadratic extension, FRI folding factor 8 and remainder degree 31.
+pub fn proof_options() -> ProofOptions {
+    ProofOptions::new(42, 8, 4, FieldExtension::Quadratic, 8, 31)
+}
+
+/// Verify a serialized burn & mint proof against encoded public inputs
+pub fn verify(proof_bytes: &[u8], public_inputs_bytes: &[u8]) -> bool {
+    verify_proof(proof_bytes, public_inputs_bytes).is_ok()
+}
+
+/// Verify a serialized burn & mint proof, reporting why it was not accepted
+pub fn verify_proof(proof_bytes: &[u8], public_inputs_bytes: &[u8]) -> Result<(), VerifyError> {
+    let public_inputs = PublicInputs::from_bytes(public_inputs_bytes)?;
+    public_inputs.check_claim()?;
+
+    let proof = StarkProof::from_bytes(proof_bytes).map_err(|_| VerifyError::InvalidProofBytes)?;
+    Schedule::from_trace_info(&proof.get_trace_info()).ok_or(VerifyError::InvalidTraceLayout)?;
+
+    let acceptable_options = AcceptableOptions::OptionSet(alloc::vec![proof_options()]);
+    winter_verifier::verify::<BurnMintAir, ProofHasher, DefaultRandomCoin<ProofHasher>>(
+        proof,
+        public_inputs,
+        &acceptable_options,
+    )
+    .map_err(VerifyError::Rejected)
+}
@@ -0,0 +1,246 @@
+//! Burn & mint public inputs, decoded from little-endian `u64` field elements
+//!
+//! Positions follow public input layout version 2: thirteen scalar fields, the four
+//! nullifier and four commitment limbs, the eight recipient binding limbs, then up to
+//! [`MAX_MINT_OUTPUTS`] outputs of an amount and a recipient binding each.
+
+use crate::rescue::{domain_element, hash_elements};
+use crate::VerifyError;
+use alloc::vec::Vec;
+use winter_verifier::math::fields::f64::BaseElement;
+use winter_verifier::math::{StarkField, ToElements};
+
+/// Field elements in a single-recipient claim
+pub const NUM_PUBLIC_INPUTS: usize = 13 + 2 * DIGEST_LIMBS + RECIPIENT_BINDING_LIMBS;
+
+/// Limbs in a nullifier or commitment digest
+pub(crate) const DIGEST_LIMBS: usize = 4;
+
+/// Limbs in a recipient binding
+pub(crate) const RECIPIENT_BINDING_LIMBS: usize = 8;
+
+/// Field elements each mint output appends
+const MINT_OUTPUT_ELEMENTS: usize = 1 + RECIPIENT_BINDING_LIMBS;
+
+/// Most recipients a burn can mint to
+const MAX_MINT_OUTPUTS: usize = 8;
+
+/// Protocol fee the default verifier requires (basis points)
+const FEE_BPS: u64 = 0;
+
+/// Highest protocol fee a proof can charge (basis points)
+const MAX_PROTOCOL_FEE_BPS: u64 = 500;
+
+/// Basis points in a whole
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Tag opening the proof commitment claim
+const PROOF_COMMITMENT_TAG: &[u8] = b"heat-commitment-v2";
+
+/// Layout positions
+pub(crate) const BURN_AMOUNT: usize = 0;
+pub(crate) const MINT_AMOUNT: usize = 1;
+pub(crate) const TXN_HASH: usize = 2;
+pub(crate) const RECIPIENT_HASH: usize = 3;
+const TX_PREFIX_HASH: usize = 5;
+const NETWORK_ID: usize = 9;
+pub(crate) const FEE_BPS_INDEX: usize = 12;
+const NULLIFIER: usize = 13;
+const COMMITMENT: usize = NULLIFIER + DIGEST_LIMBS;
+const RECIPIENT_BINDING: usize = COMMITMENT + DIGEST_LIMBS;
+
+/// Public inputs of a burn & mint proof
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicInputs {
+    elements: Vec<BaseElement>,
+}
+
+impl PublicInputs {
+    /// Decode public inputs from little-endian `u64` field elements
+    ///
+    /// Every element must be canonical, and the count must be a single-recipient claim
+    /// followed by at most eight whole mint outputs.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, VerifyError> {
+        if !bytes.len().is_multiple_of(8) {
+            return Err(VerifyError::InvalidPublicInputs);
+        }
+        let elements = bytes
+            .chunks_exact(8)
+            .map(|chunk| {
+                let value = u64::from_le_bytes(chunk.try_into().expect("chunk holds eight bytes"));
+                (value < BaseElement::MODULUS).then(|| BaseElement::new(value))
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or(VerifyError::InvalidPublicInputs)?;
+
+        let outputs = elements.len().checked_sub(NUM_PUBLIC_INPUTS).ok_or(VerifyError::InvalidPublicInputs)?;
+        if !outputs.is_multiple_of(MINT_OUTPUT_ELEMENTS) || outputs / MINT_OUTPUT_ELEMENTS > MAX_MINT_OUTPUTS {
+            return Err(VerifyError::InvalidPublicInputs);
+        }
+        Ok(Self { elements })
+    }
+
+    /// Field elements in layout order
+    pub fn elements(&self) -> &[BaseElement] {
+        &self.elements
+    }
+
+    /// Element at a layout position
+    pub(crate) fn get(&self, index: usize) -> BaseElement {
+        self.elements[index]
+    }
+
+    /// Limbs of the nullifier
+    pub(crate) fn nullifier(&self) -> &[BaseElement] {
+        &self.elements[NULLIFIER..NULLIFIER + DIGEST_LIMBS]
+    }
+
+    /// Limbs of the commitment
+    pub(crate) fn commitment(&self) -> &[BaseElement] {
+        &self.elements[COMMITMENT..COMMITMENT + DIGEST_LIMBS]
+    }
+
+    /// Scope the nullifier is derived in: the tx prefix hash limbs and the burn amount
+    pub(crate) fn nullifier_scope(&self) -> [BaseElement; 5] {
+        let tx = &self.elements[TX_PREFIX_HASH..TX_PREFIX_HASH + 4];
+        [tx[0], tx[1], tx[2], tx[3], self.get(BURN_AMOUNT)]
+    }
+
+    /// Limbs of the bound recipient's binding
+    pub(crate) fn recipient_binding(&self) -> &[BaseElement] {
+        &self.elements[RECIPIENT_BINDING..RECIPIENT_BINDING + RECIPIENT_BINDING_LIMBS]
+    }
+
+    /// Protocol fee taken from the burn, `floor(burn * fee_bps / 10000)`
+    pub(crate) fn protocol_fee(&self) -> BaseElement {
+        let burn_amount = self.get(BURN_AMOUNT).as_int();
+        let fee_bps = u128::from(self.get(FEE_BPS_INDEX).as_int().min(u64::from(u32::MAX)));
+        let fee = u128::from(burn_amount) * fee_bps / BPS_DENOMINATOR;
+        BaseElement::new(fee.min(u128::from(burn_amount)) as u64)
+    }
+
+    /// Mint amount the burn entitles the recipients to after the protocol fee
+    pub(crate) fn expected_mint_amount(&self) -> BaseElement {
+        self.get(BURN_AMOUNT) - self.protocol_fee()
+    }
+
+    /// Amounts of the mint outputs, or the whole mint when there are none
+    pub(crate) fn mint_output_amounts(&self) -> Vec<BaseElement> {
+        let outputs = &self.elements[NUM_PUBLIC_INPUTS..];
+        if outputs.is_empty() {
+            return alloc::vec![self.get(MINT_AMOUNT)];
+        }
+        outputs.iter().step_by(MINT_OUTPUT_ELEMENTS).copied().collect()
+    }
+
+    /// Rescue-Prime digest of the claim the commitment binds
+    ///
+    /// The element layout of `xfg_stark::commitment::CommitmentPreimage`: the tag, the
+    /// burn amount, the recipient binding, the network, target chain and version, then
+    /// each output's amount and binding.
+    pub(crate) fn claim_digest(&self) -> [BaseElement; 4] {
+        let mut claim = alloc::vec![domain_element(PROOF_COMMITMENT_TAG), self.get(BURN_AMOUNT)];
+        claim.extend_from_slice(self.recipient_binding());
+        claim.extend_from_slice(&self.elements[NETWORK_ID..NETWORK_ID + 3]);
+        claim.extend_from_slice(&self.elements[NUM_PUBLIC_INPUTS..]);
+        hash_elements(&claim)
+    }
+
+    /// Check the claim as `XfgBurnMintVerifier::default()` validates public inputs
+    pub fn check_claim(&self) -> Result<(), VerifyError> {
+        let burn_amount = self.get(BURN_AMOUNT).as_int();
+        let mint_amount = self.get(MINT_AMOUNT).as_int();
+        let fee_bps = self.get(FEE_BPS_INDEX).as_int();
+        if burn_amount == 0 || mint_amount == 0 || self.get(TXN_HASH).as_int() == 0 {
+            return Err(VerifyError::InvalidClaim);
+        }
+        if self.get(RECIPIENT_HASH) != self.recipient_binding()[0] {
+            return Err(VerifyError::InvalidClaim);
+        }
+        if fee_bps > MAX_PROTOCOL_FEE_BPS || fee_bps != FEE_BPS || self.get(MINT_AMOUNT) != self.expected_mint_amount() {
+            return Err(VerifyError::InvalidClaim);
+        }
+
+        let outputs = &self.elements[NUM_PUBLIC_INPUTS..];
+        if outputs.is_empty() {
+            return Ok(());
+        }
+        let amounts = outputs.iter().step_by(MINT_OUTPUT_ELEMENTS).map(|amount| amount.as_int());
+        let total = amounts.clone().try_fold(0u64, |total, amount| total.checked_add(amount));
+        if amounts.clone().any(|amount| amount == 0) || total != Some(mint_amount) {
+            return Err(VerifyError::InvalidClaim);
+        }
+        if outputs[1..MINT_OUTPUT_ELEMENTS] != *self.recipient_binding() {
+            return Err(VerifyError::InvalidClaim);
+        }
+        Ok(())
+    }
+}
+
+impl ToElements<BaseElement> for PublicInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        self.elements.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(values: &[u64]) -> Vec<u8> {
+        values.iter().flat_map(|value| value.to_le_bytes()).collect()
+    }
+
+    fn claim() -> Vec<u64> {
+        let mut values = alloc::vec![0u64; NUM_PUBLIC_INPUTS];
+        values[BURN_AMOUNT] = 8_000_000;
+        values[MINT_AMOUNT] = 8_000_000;
+        values[TXN_HASH] = 7;
+        values[RECIPIENT_HASH] = 11;
+        values[RECIPIENT_BINDING] = 11;
+        values
+    }
+
+    #[test]
+    fn test_decode_rejects_malformed_bytes() {
+        assert!(PublicInputs::from_bytes(&encode(&claim())).unwrap().check_claim().is_ok());
+
+        let mut bytes = encode(&claim());
+        bytes.pop();
+        assert_eq!(PublicInputs::from_bytes(&bytes), Err(VerifyError::InvalidPublicInputs));
+
+        let mut non_canonical = claim();
+        non_canonical[RECIPIENT_HASH] = BaseElement::MODULUS;
+        assert_eq!(PublicInputs::from_bytes(&encode(&non_canonical)), Err(VerifyError::InvalidPublicInputs));
+
+        let mut partial_output = claim();
+        partial_output.push(8_000_000);
+        assert_eq!(PublicInputs::from_bytes(&encode(&partial_output)), Err(VerifyError::InvalidPublicInputs));
+    }
+
+    #[test]
+    fn test_claim_checks() {
+        let mut inflated = claim();
+        inflated[MINT_AMOUNT] = 9_000_000;
+        let inflated = PublicInputs::from_bytes(&encode(&inflated)).unwrap();
+        assert_eq!(inflated.check_claim(), Err(VerifyError::InvalidClaim));
+
+        // The recipient hash repeats the first binding limb
+        let mut rebound = claim();
+        rebound[RECIPIENT_BINDING] = 12;
+        let rebound = PublicInputs::from_bytes(&encode(&rebound)).unwrap();
+        assert_eq!(rebound.check_claim(), Err(VerifyError::InvalidClaim));
+
+        // Two outputs splitting the mint, the first to the bound recipient
+        let mut split = claim();
+        split.extend([5_000_000, 11, 0, 0, 0, 0, 0, 0, 0]);
+        split.extend([3_000_000, 12, 0, 0, 0, 0, 0, 0, 0]);
+        let outputs = PublicInputs::from_bytes(&encode(&split)).unwrap();
+        assert!(outputs.check_claim().is_ok());
+        assert_eq!(outputs.mint_output_amounts(), [BaseElement::new(5_000_000), BaseElement::new(3_000_000)]);
+
+        split[NUM_PUBLIC_INPUTS + 1] = 12;
+        let redirected = PublicInputs::from_bytes(&encode(&split)).unwrap();
+        assert_eq!(redirected.check_claim(), Err(VerifyError::InvalidClaim));
+    }
+}
@@ -0,0 +1,120 @@
+//! Rescue-Prime rounds, verifier side
+//!
+//! Mirrors `xfg_stark::air::hash_gadget`: the permutation state, the two blocks of
+//! S-box helpers holding cubes, the round flag and round constants as periodic columns,
+//! and the degree-3 round constraints.
+
+use alloc::vec::Vec;
+use winter_verifier::crypto::hashers::Rp64_256;
+use winter_verifier::crypto::{ElementHasher, Hasher};
+use winter_verifier::math::fields::f64::BaseElement;
+use winter_verifier::math::FieldElement;
+
+/// Registers holding the permutation state
+pub(crate) const STATE_WIDTH: usize = 12;
+
+/// Registers one permutation occupies: the state and both S-box helper blocks
+pub(crate) const PERMUTATION_WIDTH: usize = 3 * STATE_WIDTH;
+
+/// Transition constraints of one round
+pub(crate) const NUM_ROUND_CONSTRAINTS: usize = 3 * STATE_WIDTH;
+
+/// Rounds in one permutation
+const NUM_ROUNDS: usize = 7;
+
+/// Rows in one permutation cycle: the input state and one row per round
+pub(crate) const CYCLE_LENGTH: usize = NUM_ROUNDS + 1;
+
+/// First rate register; inputs are absorbed from here
+pub(crate) const RATE_START: usize = 4;
+
+/// First digest register
+pub(crate) const DIGEST_START: usize = 4;
+
+/// Periodic columns [`enforce_round`] reads: the round flag and two constants per register
+pub(crate) const NUM_PERIODIC_COLUMNS: usize = 1 + 2 * STATE_WIDTH;
+
+/// Round flag and round constants as periodic columns of length [`CYCLE_LENGTH`]
+pub(crate) fn periodic_columns() -> Vec<Vec<BaseElement>> {
+    let mut flag = alloc::vec![BaseElement::ONE; NUM_ROUNDS];
+    flag.push(BaseElement::ZERO);
+
+    let constants = |ark: &[[BaseElement; STATE_WIDTH]; NUM_ROUNDS], register: usize| {
+        let mut column: Vec<BaseElement> = ark.iter().map(|round| round[register]).collect();
+        column.push(BaseElement::ZERO);
+        column
+    };
+
+    let mut columns = alloc::vec![flag];
+    columns.extend((0..STATE_WIDTH).map(|register| constants(&Rp64_256::ARK1, register)));
+    columns.extend((0..STATE_WIDTH).map(|register| constants(&Rp64_256::ARK2, register)));
+    columns
+}
+
+/// Transition constraints of one round, written to `result[..NUM_ROUND_CONSTRAINTS]`
+pub(crate) fn enforce_round<E: FieldElement<BaseField = BaseElement>>(
+    current: &[E],
+    next: &[E],
+    periodic: &[E],
+    result: &mut [E],
+) {
+    let flag = periodic[0];
+    let ark1 = &periodic[1..=STATE_WIDTH];
+    let ark2 = &periodic[1 + STATE_WIDTH..NUM_PERIODIC_COLUMNS];
+    let state = &current[..STATE_WIDTH];
+    let forward_cubes = &current[STATE_WIDTH..2 * STATE_WIDTH];
+    let backward_cubes = &current[2 * STATE_WIDTH..PERMUTATION_WIDTH];
+
+    let mut backward = [E::ZERO; STATE_WIDTH];
+    for ((value, &register), &constant) in backward.iter_mut().zip(&next[..STATE_WIDTH]).zip(ark2) {
+        *value = register - constant;
+    }
+    let backward = mds_multiply(&backward, &Rp64_256::INV_MDS);
+
+    let mut forward = [E::ZERO; STATE_WIDTH];
+    for ((value, &register), &cube) in forward.iter_mut().zip(state).zip(forward_cubes) {
+        *value = cube.square() * register;
+    }
+    let mut forward = mds_multiply(&forward, &Rp64_256::MDS);
+    for (value, &constant) in forward.iter_mut().zip(ark1) {
+        *value += constant;
+    }
+
+    for i in 0..STATE_WIDTH {
+        result[i] = flag * (forward_cubes[i] - state[i].square() * state[i]);
+        result[STATE_WIDTH + i] = flag * (backward_cubes[i] - backward[i].square() * backward[i]);
+        result[2 * STATE_WIDTH + i] = flag * (backward_cubes[i].square() * backward[i] - forward[i]);
+    }
+}
+
+/// Sponge state absorbing up to eight elements, as `Rp64_256::hash_elements` starts it
+pub(crate) fn absorb(elements: &[BaseElement]) -> [BaseElement; STATE_WIDTH] {
+    let mut state = [BaseElement::ZERO; STATE_WIDTH];
+    state[0] = BaseElement::new(elements.len() as u64);
+    state[RATE_START..RATE_START + elements.len()].copy_from_slice(elements);
+    state
+}
+
+/// First element of the Rp64_256 hash of a domain tag
+pub(crate) fn domain_element(domain: &[u8]) -> BaseElement {
+    Rp64_256::hash(domain).as_elements()[0]
+}
+
+/// Rp64_256 digest of field elements
+pub(crate) fn hash_elements(elements: &[BaseElement]) -> [BaseElement; 4] {
+    Rp64_256::hash_elements(elements).into()
+}
+
+/// `matrix · state`
+fn mds_multiply<E: FieldElement<BaseField = BaseElement>>(
+    state: &[E; STATE_WIDTH],
+    matrix: &[[BaseElement; STATE_WIDTH]; STATE_WIDTH],
+) -> [E; STATE_WIDTH] {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for (output, row) in result.iter_mut().zip(matrix) {
+        for (&value, &coefficient) in state.iter().zip(row) {
+            *output += value.mul_base(coefficient);
+        }
+    }
+    result
+}